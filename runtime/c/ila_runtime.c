@@ -0,0 +1,1234 @@
+#include "ila_runtime.h"
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+/* -------------------------------------------------------------------------- */
+/*                                   errors                                   */
+/* -------------------------------------------------------------------------- */
+
+/* most calls in progress at the same time, like in the interpreter */
+#define ILA_MAX_CALL_DEPTH 10000
+
+/* exit status of a program stopped by an error, like `ila executar` */
+#define ILA_EXIT_RUNTIME_ERROR 3
+
+static const char *ila_source_name = "<programa>";
+static int ila_call_depth = 0;
+
+static void ila_fail(const char *code, const char *message, const char *note, int line, int column) {
+
+    fflush(stdout);
+    fprintf(stderr, "erro[%s]: %s\n --> %s:%d:%d\n", code, message, ila_source_name, line, column);
+    if (note != NULL) {
+        fprintf(stderr, "  = nota: %s\n", note);
+    }
+    exit(ILA_EXIT_RUNTIME_ERROR);
+}
+
+static void ila_fail_division_by_zero(int line, int column) {
+    ila_fail("E0701", "divisão por zero", NULL, line, column);
+}
+
+static void *ila_allocate(size_t size) {
+
+    void *memory = malloc(size == 0 ? 1 : size);
+    if (memory == NULL) {
+        fflush(stdout);
+        fprintf(stderr, "erro: não há memória suficiente para continuar a execução\n");
+        exit(ILA_EXIT_RUNTIME_ERROR);
+    }
+    return memory;
+}
+
+void ila_start(const char *source_name) {
+
+    ila_source_name = source_name;
+    ila_call_depth = 1;
+}
+
+int ila_finish(void) {
+
+    fflush(stdout);
+    return 0;
+}
+
+void ila_enter(int line, int column) {
+
+    if (ila_call_depth >= ILA_MAX_CALL_DEPTH) {
+        char message[96];
+        snprintf(message, sizeof(message), "mais de %d chamadas em andamento ao mesmo tempo", ILA_MAX_CALL_DEPTH);
+        ila_fail("E0704", message, "isso acontece quando um subprograma chama a si mesmo sem nunca parar", line, column);
+    }
+    ila_call_depth += 1;
+}
+
+void ila_leave(void) {
+    ila_call_depth -= 1;
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                   builder                                  */
+/* -------------------------------------------------------------------------- */
+
+static void ila_builder_push(ila_builder *out, const char *data, size_t length) {
+
+    if (out->length + length > out->capacity) {
+        size_t capacity = out->capacity == 0 ? 64 : out->capacity;
+        while (capacity < out->length + length) {
+            capacity *= 2;
+        }
+        char *grown = ila_allocate(capacity);
+        if (out->data != NULL) {
+            memcpy(grown, out->data, out->length);
+            free(out->data);
+        }
+        out->data = grown;
+        out->capacity = capacity;
+    }
+    memcpy(out->data + out->length, data, length);
+    out->length += length;
+}
+
+static void ila_builder_push_str(ila_builder *out, const char *text) {
+    ila_builder_push(out, text, strlen(text));
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                  integers                                  */
+/* -------------------------------------------------------------------------- */
+
+/* an `inteiro` outside the 64 bits, whose magnitude has 32 bits in each limb, starting from the
+ * least significant */
+struct ila_bignum {
+    size_t references;
+    bool negative;
+    size_t length;
+    /* the last limb is never 0 */
+    uint32_t limbs[];
+};
+
+/* sign and limbs of an integer, which point to `buffer` when it fits in 64 bits */
+typedef struct ila_view {
+    bool negative;
+    size_t length;
+    const uint32_t *limbs;
+    uint32_t buffer[2];
+} ila_view;
+
+static ila_int ila_int_small(int64_t value) {
+
+    ila_int result = {value, NULL};
+    return result;
+}
+
+static bool ila_int_is_zero(ila_int value) {
+    return value.big == NULL && value.small == 0;
+}
+
+static bool ila_int_is_negative(ila_int value) {
+    return value.big != NULL ? value.big->negative : value.small < 0;
+}
+
+static void ila_view_of(ila_int value, ila_view *view) {
+
+    if (value.big != NULL) {
+        view->negative = value.big->negative;
+        view->length = value.big->length;
+        view->limbs = value.big->limbs;
+        return;
+    }
+
+    uint64_t magnitude = value.small < 0 ? 0 - (uint64_t)value.small : (uint64_t)value.small;
+    view->negative = value.small < 0;
+    view->buffer[0] = (uint32_t)magnitude;
+    view->buffer[1] = (uint32_t)(magnitude >> 32);
+    view->length = view->buffer[1] != 0 ? 2 : view->buffer[0] != 0 ? 1 : 0;
+    view->limbs = view->buffer;
+}
+
+/* the integer with the sign and magnitude, whose last limbs may be zeros */
+static ila_int ila_int_from_limbs(bool negative, const uint32_t *limbs, size_t length) {
+
+    while (length > 0 && limbs[length - 1] == 0) {
+        length--;
+    }
+    if (length <= 2) {
+        uint64_t magnitude = length == 0 ? 0 : limbs[0] | (length == 2 ? (uint64_t)limbs[1] << 32 : 0);
+        if (magnitude <= (uint64_t)INT64_MAX) {
+            return ila_int_small(negative ? -(int64_t)magnitude : (int64_t)magnitude);
+        }
+        if (negative && magnitude == (uint64_t)INT64_MAX + 1) {
+            return ila_int_small(INT64_MIN);
+        }
+    }
+
+    ila_bignum *big = ila_allocate(sizeof(ila_bignum) + length * sizeof(uint32_t));
+    big->references = 1;
+    big->negative = negative;
+    big->length = length;
+    memcpy(big->limbs, limbs, length * sizeof(uint32_t));
+    ila_int result = {0, big};
+    return result;
+}
+
+ila_int ila_int_retain(ila_int value) {
+
+    if (value.big != NULL) {
+        value.big->references += 1;
+    }
+    return value;
+}
+
+void ila_int_release(ila_int value) {
+
+    if (value.big != NULL && --value.big->references == 0) {
+        free(value.big);
+    }
+}
+
+/* ---- magnitudes ---- */
+
+static int ila_limbs_compare(const uint32_t *a, size_t a_length, const uint32_t *b, size_t b_length) {
+
+    if (a_length != b_length) {
+        return a_length < b_length ? -1 : 1;
+    }
+    for (size_t index = a_length; index-- > 0;) {
+        if (a[index] != b[index]) {
+            return a[index] < b[index] ? -1 : 1;
+        }
+    }
+    return 0;
+}
+
+/* a + b, in `result` with room for one limb more than the longest */
+static size_t ila_limbs_add(const uint32_t *a, size_t a_length, const uint32_t *b, size_t b_length, uint32_t *result) {
+
+    if (a_length < b_length) {
+        return ila_limbs_add(b, b_length, a, a_length, result);
+    }
+
+    uint64_t carry = 0;
+    for (size_t index = 0; index < a_length; index++) {
+        uint64_t sum = (uint64_t)a[index] + (index < b_length ? b[index] : 0) + carry;
+        result[index] = (uint32_t)sum;
+        carry = sum >> 32;
+    }
+    result[a_length] = (uint32_t)carry;
+    return a_length + 1;
+}
+
+/* a - b, where `a` is not smaller than `b`, in `result` with room for the limbs of `a` */
+static size_t ila_limbs_sub(const uint32_t *a, size_t a_length, const uint32_t *b, size_t b_length, uint32_t *result) {
+
+    uint64_t borrow = 0;
+    for (size_t index = 0; index < a_length; index++) {
+        uint64_t subtracted = (index < b_length ? b[index] : 0) + borrow;
+        result[index] = (uint32_t)((uint64_t)a[index] - subtracted);
+        borrow = a[index] < subtracted;
+    }
+    return a_length;
+}
+
+/* a * b, in `result` with room for the limbs of both */
+static size_t ila_limbs_mul(const uint32_t *a, size_t a_length, const uint32_t *b, size_t b_length, uint32_t *result) {
+
+    memset(result, 0, (a_length + b_length) * sizeof(uint32_t));
+    for (size_t i = 0; i < a_length; i++) {
+        uint64_t carry = 0;
+        for (size_t j = 0; j < b_length; j++) {
+            uint64_t product = (uint64_t)a[i] * b[j] + result[i + j] + carry;
+            result[i + j] = (uint32_t)product;
+            carry = product >> 32;
+        }
+        result[i + b_length] = (uint32_t)carry;
+    }
+    return a_length + b_length;
+}
+
+/* u / v in `quotient`, with room for `u_length - v_length + 1` limbs, and u % v in `remainder`,
+ * with room for `v_length` limbs, where `u` is not shorter than `v` and the last limb of `v` is
+ * not 0: the algorithm D of "The Art of Computer Programming", by Donald Knuth, section 4.3.1 */
+static void ila_limbs_divide(const uint32_t *u, size_t u_length, const uint32_t *v, size_t v_length, uint32_t *quotient, uint32_t *remainder) {
+
+    if (v_length == 1) {
+        uint64_t rest = 0;
+        for (size_t index = u_length; index-- > 0;) {
+            uint64_t current = rest << 32 | u[index];
+            quotient[index] = (uint32_t)(current / v[0]);
+            rest = current % v[0];
+        }
+        remainder[0] = (uint32_t)rest;
+        return;
+    }
+
+    /* shifted so the last limb of the divisor has its highest bit set, which makes each
+     * estimate of a limb of the quotient at most 2 too large */
+    int shift = 0;
+    while ((v[v_length - 1] << shift & 0x80000000u) == 0) {
+        shift++;
+    }
+    uint32_t *vn = ila_allocate(v_length * sizeof(uint32_t));
+    uint32_t *un = ila_allocate((u_length + 1) * sizeof(uint32_t));
+    for (size_t index = v_length - 1; index > 0; index--) {
+        vn[index] = v[index] << shift | (uint32_t)((uint64_t)v[index - 1] >> (32 - shift));
+    }
+    vn[0] = v[0] << shift;
+    un[u_length] = (uint32_t)((uint64_t)u[u_length - 1] >> (32 - shift));
+    for (size_t index = u_length - 1; index > 0; index--) {
+        un[index] = u[index] << shift | (uint32_t)((uint64_t)u[index - 1] >> (32 - shift));
+    }
+    un[0] = u[0] << shift;
+
+    for (size_t j = u_length - v_length + 1; j-- > 0;) {
+        uint64_t numerator = (uint64_t)un[j + v_length] << 32 | un[j + v_length - 1];
+        uint64_t estimate = numerator / vn[v_length - 1];
+        uint64_t rest = numerator % vn[v_length - 1];
+        while (estimate > UINT32_MAX || estimate * vn[v_length - 2] > (rest << 32 | un[j + v_length - 2])) {
+            estimate -= 1;
+            rest += vn[v_length - 1];
+            if (rest > UINT32_MAX) {
+                break;
+            }
+        }
+
+        /* subtracts the divisor times the estimate */
+        uint64_t borrow = 0;
+        for (size_t index = 0; index < v_length; index++) {
+            uint64_t product = estimate * vn[index] + borrow;
+            borrow = (product >> 32) + (un[index + j] < (uint32_t)product);
+            un[index + j] -= (uint32_t)product;
+        }
+        bool is_negative = un[j + v_length] < borrow;
+        un[j + v_length] = (uint32_t)(un[j + v_length] - borrow);
+
+        /* the estimate was 1 too large, so the divisor is added back */
+        quotient[j] = (uint32_t)estimate;
+        if (is_negative) {
+            quotient[j] -= 1;
+            uint64_t carry = 0;
+            for (size_t index = 0; index < v_length; index++) {
+                uint64_t sum = (uint64_t)un[index + j] + vn[index] + carry;
+                un[index + j] = (uint32_t)sum;
+                carry = sum >> 32;
+            }
+            un[j + v_length] += (uint32_t)carry;
+        }
+    }
+
+    for (size_t index = 0; index + 1 < v_length; index++) {
+        remainder[index] = un[index] >> shift | (uint32_t)((uint64_t)un[index + 1] << (32 - shift));
+    }
+    remainder[v_length - 1] = un[v_length - 1] >> shift;
+    free(vn);
+    free(un);
+}
+
+/* ---- operations ---- */
+
+/* a + b, or a - b when `subtract` is set */
+static ila_int ila_add_big(ila_int a, ila_int b, bool subtract) {
+
+    ila_view x;
+    ila_view y;
+    ila_view_of(a, &x);
+    ila_view_of(b, &y);
+    bool y_negative = y.negative != subtract;
+
+    uint32_t *limbs = ila_allocate(((x.length > y.length ? x.length : y.length) + 1) * sizeof(uint32_t));
+    ila_int result;
+    if (x.negative == y_negative) {
+        size_t length = ila_limbs_add(x.limbs, x.length, y.limbs, y.length, limbs);
+        result = ila_int_from_limbs(x.negative, limbs, length);
+    } else if (ila_limbs_compare(x.limbs, x.length, y.limbs, y.length) >= 0) {
+        size_t length = ila_limbs_sub(x.limbs, x.length, y.limbs, y.length, limbs);
+        result = ila_int_from_limbs(x.negative, limbs, length);
+    } else {
+        size_t length = ila_limbs_sub(y.limbs, y.length, x.limbs, x.length, limbs);
+        result = ila_int_from_limbs(y_negative, limbs, length);
+    }
+    free(limbs);
+    return result;
+}
+
+ila_int ila_add(ila_int a, ila_int b) {
+
+    if (a.big == NULL && b.big == NULL && !((b.small > 0 && a.small > INT64_MAX - b.small) || (b.small < 0 && a.small < INT64_MIN - b.small))) {
+        return ila_int_small(a.small + b.small);
+    }
+    return ila_add_big(a, b, false);
+}
+
+ila_int ila_sub(ila_int a, ila_int b) {
+
+    if (a.big == NULL && b.big == NULL && !((b.small < 0 && a.small > INT64_MAX + b.small) || (b.small > 0 && a.small < INT64_MIN + b.small))) {
+        return ila_int_small(a.small - b.small);
+    }
+    return ila_add_big(a, b, true);
+}
+
+ila_int ila_mul(ila_int a, ila_int b) {
+
+    if (a.big == NULL && b.big == NULL) {
+        bool overflows = false;
+        if (a.small != 0 && b.small != 0) {
+            if (a.small > 0) {
+                overflows = b.small > 0 ? a.small > INT64_MAX / b.small : b.small < INT64_MIN / a.small;
+            } else {
+                overflows = b.small > 0 ? a.small < INT64_MIN / b.small : a.small < INT64_MAX / b.small;
+            }
+        }
+        if (!overflows) {
+            return ila_int_small(a.small * b.small);
+        }
+    }
+
+    ila_view x;
+    ila_view y;
+    ila_view_of(a, &x);
+    ila_view_of(b, &y);
+    uint32_t *limbs = ila_allocate((x.length + y.length) * sizeof(uint32_t));
+    size_t length = ila_limbs_mul(x.limbs, x.length, y.limbs, y.length, limbs);
+    ila_int result = ila_int_from_limbs(x.negative != y.negative, limbs, length);
+    free(limbs);
+    return result;
+}
+
+/* a / b truncated toward zero, and a % b with the sign of `a`, where `b` is not 0 */
+static void ila_divide(ila_int a, ila_int b, ila_int *quotient, ila_int *remainder) {
+
+    if (a.big == NULL && b.big == NULL && !(a.small == INT64_MIN && b.small == -1)) {
+        *quotient = ila_int_small(a.small / b.small);
+        *remainder = ila_int_small(a.small % b.small);
+        return;
+    }
+
+    ila_view x;
+    ila_view y;
+    ila_view_of(a, &x);
+    ila_view_of(b, &y);
+    if (x.length < y.length) {
+        *quotient = ila_int_small(0);
+        *remainder = ila_int_retain(a);
+        return;
+    }
+
+    uint32_t *quotient_limbs = ila_allocate((x.length - y.length + 1) * sizeof(uint32_t));
+    uint32_t *remainder_limbs = ila_allocate(y.length * sizeof(uint32_t));
+    ila_limbs_divide(x.limbs, x.length, y.limbs, y.length, quotient_limbs, remainder_limbs);
+    *quotient = ila_int_from_limbs(x.negative != y.negative, quotient_limbs, x.length - y.length + 1);
+    *remainder = ila_int_from_limbs(x.negative, remainder_limbs, y.length);
+    free(quotient_limbs);
+    free(remainder_limbs);
+}
+
+ila_int ila_div(ila_int a, ila_int b, int line, int column) {
+
+    if (ila_int_is_zero(b)) {
+        ila_fail_division_by_zero(line, column);
+    }
+
+    ila_int quotient;
+    ila_int remainder;
+    ila_divide(a, b, &quotient, &remainder);
+    ila_int_release(remainder);
+    return quotient;
+}
+
+ila_int ila_neg(ila_int a) {
+    return ila_sub(ila_int_small(0), a);
+}
+
+static int ila_int_compare_magnitude(ila_int a, ila_int b) {
+
+    ila_view x;
+    ila_view y;
+    ila_view_of(a, &x);
+    ila_view_of(b, &y);
+    return ila_limbs_compare(x.limbs, x.length, y.limbs, y.length);
+}
+
+int ila_int_compare(ila_int a, ila_int b) {
+
+    if (a.big == NULL && b.big == NULL) {
+        return (a.small > b.small) - (a.small < b.small);
+    }
+
+    bool a_negative = ila_int_is_negative(a);
+    if (a_negative != ila_int_is_negative(b)) {
+        return a_negative ? -1 : 1;
+    }
+    int order = ila_int_compare_magnitude(a, b);
+    return a_negative ? -order : order;
+}
+
+bool ila_int_equals(ila_int a, ila_int b) {
+    return ila_int_compare(a, b) == 0;
+}
+
+/* ---- digits ---- */
+
+/* reads an integer in base 10 like `-123`, for the literals that do not fit in 64 bits */
+ila_int ila_int_parse(const char *text) {
+
+    bool negative = *text == '-';
+    if (negative) {
+        text++;
+    }
+
+    /* each 9 digits fit in a limb */
+    uint32_t *limbs = ila_allocate((strlen(text) / 9 + 1) * sizeof(uint32_t));
+    size_t length = 0;
+    while (*text != '\0') {
+        uint64_t chunk = 0;
+        uint64_t multiplier = 1;
+        for (int digit = 0; digit < 9 && *text != '\0'; digit++, text++) {
+            chunk = chunk * 10 + (uint64_t)(*text - '0');
+            multiplier *= 10;
+        }
+        uint64_t carry = chunk;
+        for (size_t index = 0; index < length; index++) {
+            uint64_t current = limbs[index] * multiplier + carry;
+            limbs[index] = (uint32_t)current;
+            carry = current >> 32;
+        }
+        if (carry != 0) {
+            limbs[length++] = (uint32_t)carry;
+        }
+    }
+
+    ila_int result = ila_int_from_limbs(negative, limbs, length);
+    free(limbs);
+    return result;
+}
+
+/* writes the digits of the magnitude of an integer, without its sign */
+static void ila_write_magnitude(ila_builder *out, ila_int value) {
+
+    if (value.big == NULL) {
+        char digits[32];
+        uint64_t magnitude = value.small < 0 ? 0 - (uint64_t)value.small : (uint64_t)value.small;
+        snprintf(digits, sizeof(digits), "%llu", (unsigned long long)magnitude);
+        ila_builder_push_str(out, digits);
+        return;
+    }
+
+    /* divided by 10^9 until nothing is left, which gives the digits from the least significant */
+    size_t length = value.big->length;
+    uint32_t *limbs = ila_allocate(length * sizeof(uint32_t));
+    memcpy(limbs, value.big->limbs, length * sizeof(uint32_t));
+    char *digits = ila_allocate(length * 10);
+    size_t count = 0;
+    while (length > 0) {
+        uint64_t rest = 0;
+        for (size_t index = length; index-- > 0;) {
+            uint64_t current = rest << 32 | limbs[index];
+            limbs[index] = (uint32_t)(current / 1000000000);
+            rest = current % 1000000000;
+        }
+        while (length > 0 && limbs[length - 1] == 0) {
+            length--;
+        }
+        for (int digit = 0; digit < 9 && (length > 0 || rest > 0); digit++) {
+            digits[count++] = (char)('0' + rest % 10);
+            rest /= 10;
+        }
+    }
+
+    while (count > 0) {
+        ila_builder_push(out, &digits[--count], 1);
+    }
+    free(digits);
+    free(limbs);
+}
+
+static void ila_write_integer(ila_builder *out, ila_int value) {
+
+    if (ila_int_is_negative(value)) {
+        ila_builder_push_str(out, "-");
+    }
+    ila_write_magnitude(out, value);
+}
+
+static size_t ila_digit_count(ila_int value) {
+
+    ila_builder digits = {NULL, 0, 0};
+    ila_write_magnitude(&digits, value);
+    free(digits.data);
+    return digits.length;
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                  decimals                                  */
+/* -------------------------------------------------------------------------- */
+
+/* significant digits kept by a division, like the interpreter */
+#define ILA_DIVISION_PRECISION 30
+
+static ila_int ila_power_of_ten(uint32_t exponent) {
+
+    ila_int result = ila_int_small(1);
+    while (exponent > 0) {
+        uint32_t step = exponent < 18 ? exponent : 18;
+        int64_t factor = 1;
+        for (uint32_t index = 0; index < step; index++) {
+            factor *= 10;
+        }
+        ila_int next = ila_mul(result, ila_int_small(factor));
+        ila_int_release(result);
+        result = next;
+        exponent -= step;
+    }
+    return result;
+}
+
+/* digits * 10^zeros */
+static ila_int ila_shift_digits(ila_int digits, uint32_t zeros) {
+
+    ila_int power = ila_power_of_ten(zeros);
+    ila_int result = ila_mul(digits, power);
+    ila_int_release(power);
+    return result;
+}
+
+/* the decimal with the digits, which it keeps, without the zeros at the end of the fraction */
+static ila_dec ila_dec_new(ila_int digits, uint32_t scale) {
+
+    while (scale > 0 && !ila_int_is_zero(digits)) {
+        if (digits.big == NULL) {
+            if (digits.small % 10 != 0) {
+                break;
+            }
+            digits.small /= 10;
+        } else {
+            ila_int quotient;
+            ila_int remainder;
+            ila_divide(digits, ila_int_small(10), &quotient, &remainder);
+            bool is_multiple = ila_int_is_zero(remainder);
+            ila_int_release(remainder);
+            if (!is_multiple) {
+                ila_int_release(quotient);
+                break;
+            }
+            ila_int_release(digits);
+            digits = quotient;
+        }
+        scale--;
+    }
+    if (ila_int_is_zero(digits)) {
+        scale = 0;
+    }
+
+    ila_dec result = {digits, scale};
+    return result;
+}
+
+ila_dec ila_dec_parse(const char *digits, uint32_t scale) {
+    return ila_dec_new(ila_int_parse(digits), scale);
+}
+
+ila_dec ila_dec_retain(ila_dec value) {
+
+    ila_int_retain(value.digits);
+    return value;
+}
+
+void ila_dec_release(ila_dec value) {
+    ila_int_release(value.digits);
+}
+
+ila_dec ila_dec_of(ila_int value) {
+
+    ila_dec result = {value, 0};
+    return result;
+}
+
+/* the digits of both with the same scale, the largest of the two, which the caller releases */
+static uint32_t ila_dec_align(ila_dec a, ila_dec b, ila_int *left, ila_int *right) {
+
+    uint32_t scale = a.scale > b.scale ? a.scale : b.scale;
+    *left = ila_shift_digits(a.digits, scale - a.scale);
+    *right = ila_shift_digits(b.digits, scale - b.scale);
+    return scale;
+}
+
+ila_dec ila_dec_add(ila_dec a, ila_dec b) {
+
+    ila_int left;
+    ila_int right;
+    uint32_t scale = ila_dec_align(a, b, &left, &right);
+    ila_dec result = ila_dec_new(ila_add(left, right), scale);
+    ila_int_release(left);
+    ila_int_release(right);
+    return result;
+}
+
+ila_dec ila_dec_sub(ila_dec a, ila_dec b) {
+
+    ila_int left;
+    ila_int right;
+    uint32_t scale = ila_dec_align(a, b, &left, &right);
+    ila_dec result = ila_dec_new(ila_sub(left, right), scale);
+    ila_int_release(left);
+    ila_int_release(right);
+    return result;
+}
+
+ila_dec ila_dec_mul(ila_dec a, ila_dec b) {
+    return ila_dec_new(ila_mul(a.digits, b.digits), a.scale + b.scale);
+}
+
+/* division rounded to `ILA_DIVISION_PRECISION` significant digits, half away from zero */
+ila_dec ila_div_decimal(ila_dec a, ila_dec b, int line, int column) {
+
+    if (ila_int_is_zero(b.digits)) {
+        ila_fail_division_by_zero(line, column);
+    }
+
+    /* enough extra digits in the dividend for the quotient to have the precision */
+    size_t a_digits = ila_digit_count(a.digits);
+    size_t b_digits = ila_digit_count(b.digits);
+    uint32_t extra = ILA_DIVISION_PRECISION + b_digits > a_digits ? (uint32_t)(ILA_DIVISION_PRECISION + b_digits - a_digits) : 0;
+    ila_int dividend = ila_shift_digits(a.digits, extra);
+    ila_int quotient;
+    ila_int remainder;
+    ila_divide(dividend, b.digits, &quotient, &remainder);
+
+    ila_int twice = ila_add(remainder, remainder);
+    if (ila_int_compare_magnitude(twice, b.digits) >= 0) {
+        ila_int step = ila_int_small(ila_int_is_negative(dividend) != ila_int_is_negative(b.digits) ? -1 : 1);
+        ila_int rounded = ila_add(quotient, step);
+        ila_int_release(quotient);
+        quotient = rounded;
+    }
+    ila_int_release(twice);
+    ila_int_release(remainder);
+    ila_int_release(dividend);
+
+    /* the quotient has `a.scale + extra - b.scale` digits after the point */
+    int64_t scale = (int64_t)a.scale + extra - b.scale;
+    if (scale < 0) {
+        ila_int shifted = ila_shift_digits(quotient, (uint32_t)-scale);
+        ila_int_release(quotient);
+        return ila_dec_new(shifted, 0);
+    }
+    return ila_dec_new(quotient, (uint32_t)scale);
+}
+
+ila_dec ila_dec_neg(ila_dec a) {
+
+    ila_dec result = {ila_neg(a.digits), a.scale};
+    return result;
+}
+
+int ila_dec_compare(ila_dec a, ila_dec b) {
+
+    ila_int left;
+    ila_int right;
+    ila_dec_align(a, b, &left, &right);
+    int order = ila_int_compare(left, right);
+    ila_int_release(left);
+    ila_int_release(right);
+    return order;
+}
+
+/* writes a decimal always with a fractional part, so `2.0` is not read as `2`, like the interpreter */
+static void ila_write_decimal(ila_builder *out, ila_dec value) {
+
+    /* the digits have at least one before the point */
+    ila_builder digits = {NULL, 0, 0};
+    for (size_t zeros = ila_digit_count(value.digits); zeros < (size_t)value.scale + 1; zeros++) {
+        ila_builder_push_str(&digits, "0");
+    }
+    ila_write_magnitude(&digits, value.digits);
+
+    if (ila_int_is_negative(value.digits)) {
+        ila_builder_push_str(out, "-");
+    }
+    ila_builder_push(out, digits.data, digits.length - value.scale);
+    ila_builder_push_str(out, ".");
+    if (value.scale == 0) {
+        ila_builder_push_str(out, "0");
+    } else {
+        ila_builder_push(out, digits.data + digits.length - value.scale, value.scale);
+    }
+    free(digits.data);
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    texts                                   */
+/* -------------------------------------------------------------------------- */
+
+struct ila_text {
+    size_t references;
+    size_t length;
+    char data[];
+};
+
+ila_text *ila_text_new(const char *data, size_t length) {
+
+    ila_text *text = ila_allocate(sizeof(ila_text) + length);
+    text->references = 1;
+    text->length = length;
+    memcpy(text->data, data, length);
+    return text;
+}
+
+ila_text *ila_text_retain(ila_text *text) {
+
+    if (text != NULL) {
+        text->references += 1;
+    }
+    return text;
+}
+
+void ila_text_release(ila_text *text) {
+
+    if (text != NULL && --text->references == 0) {
+        free(text);
+    }
+}
+
+ila_text *ila_text_concat(const ila_text *a, const ila_text *b) {
+
+    ila_text *text = ila_allocate(sizeof(ila_text) + a->length + b->length);
+    text->references = 1;
+    text->length = a->length + b->length;
+    memcpy(text->data, a->data, a->length);
+    memcpy(text->data + a->length, b->data, b->length);
+    return text;
+}
+
+bool ila_text_equals(const ila_text *a, const ila_text *b) {
+    return a->length == b->length && memcmp(a->data, b->data, a->length) == 0;
+}
+
+/* number of bytes of the UTF-8 character that starts with this byte */
+static size_t ila_char_width(unsigned char first) {
+
+    if (first >= 0xF0) return 4;
+    if (first >= 0xE0) return 3;
+    if (first >= 0xC0) return 2;
+    return 1;
+}
+
+ila_int ila_text_length(const ila_text *text) {
+
+    int64_t count = 0;
+    for (size_t index = 0; index < text->length; index += ila_char_width((unsigned char)text->data[index])) {
+        count += 1;
+    }
+    return ila_int_small(count);
+}
+
+ila_list *ila_text_chars(const ila_text *text) {
+
+    ila_list *chars = ila_list_new(&ila_type_text);
+    size_t index = 0;
+    while (index < text->length) {
+        size_t width = ila_char_width((unsigned char)text->data[index]);
+        if (index + width > text->length) {
+            width = text->length - index;
+        }
+        ila_text *c = ila_text_new(text->data + index, width);
+        ila_list_push(chars, &c);
+        ila_text_release(c);
+        index += width;
+    }
+    return chars;
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    lists                                   */
+/* -------------------------------------------------------------------------- */
+
+struct ila_list {
+    /* the variable that owns the list, and each `para cada` going through its items */
+    size_t references;
+    const ila_type *item_type;
+    size_t length;
+    size_t capacity;
+    /* each item has its own storage, so references to it stay valid while the list grows */
+    void **items;
+};
+
+ila_list *ila_list_new(const ila_type *item_type) {
+
+    ila_list *list = ila_allocate(sizeof(ila_list));
+    list->references = 1;
+    list->item_type = item_type;
+    list->length = 0;
+    list->capacity = 0;
+    list->items = NULL;
+    return list;
+}
+
+ila_list *ila_list_copy(const ila_list *list) {
+
+    if (list == NULL) {
+        return NULL;
+    }
+
+    ila_list *copy = ila_list_new(list->item_type);
+    for (size_t index = 0; index < list->length; index++) {
+        ila_list_push(copy, ila_list_item(list, index));
+    }
+    return copy;
+}
+
+ila_list *ila_list_retain(ila_list *list) {
+
+    if (list != NULL) {
+        list->references += 1;
+    }
+    return list;
+}
+
+void ila_list_free(ila_list *list) {
+
+    if (list == NULL || --list->references > 0) {
+        return;
+    }
+
+    for (size_t index = 0; index < list->length; index++) {
+        list->item_type->drop(list->item_type, list->items[index]);
+        free(list->items[index]);
+    }
+    free(list->items);
+    free(list);
+}
+
+bool ila_list_equals(const ila_list *a, const ila_list *b) {
+
+    if (a->length != b->length) {
+        return false;
+    }
+    for (size_t index = 0; index < a->length; index++) {
+        if (!a->item_type->equals(a->item_type, ila_list_item(a, index), ila_list_item(b, index))) {
+            return false;
+        }
+    }
+    return true;
+}
+
+ila_int ila_list_length(const ila_list *list) {
+    return ila_int_small((int64_t)list->length);
+}
+
+void ila_list_push(ila_list *list, const void *item) {
+
+    if (list->length == list->capacity) {
+        size_t capacity = list->capacity == 0 ? 4 : list->capacity * 2;
+        void **items = ila_allocate(capacity * sizeof(void *));
+        if (list->items != NULL) {
+            memcpy(items, list->items, list->length * sizeof(void *));
+            free(list->items);
+        }
+        list->items = items;
+        list->capacity = capacity;
+    }
+    void *storage = ila_allocate(list->item_type->size);
+    list->item_type->copy(list->item_type, storage, item);
+    list->items[list->length] = storage;
+    list->length += 1;
+}
+
+void *ila_list_item(const ila_list *list, size_t index) {
+    return list->items[index];
+}
+
+void *ila_list_get(const ila_list *list, ila_int index, int line, int column) {
+
+    if (index.big != NULL || index.small < 0 || (uint64_t)index.small >= list->length) {
+        ila_builder message = {NULL, 0, 0};
+        ila_builder_push_str(&message, "a posição ");
+        ila_write_integer(&message, index);
+        char rest[96];
+        snprintf(rest, sizeof(rest), " não existe em uma lista com %zu %s", list->length, list->length == 1 ? "item" : "itens");
+        ila_builder_push(&message, rest, strlen(rest) + 1);
+        ila_fail("E0702", message.data, "as posições de uma lista começam em 0 e vão até o tamanho da lista menos 1", line, column);
+    }
+    return ila_list_item(list, (size_t)index.small);
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                    boxes                                   */
+/* -------------------------------------------------------------------------- */
+
+struct ila_box {
+    size_t references;
+    const ila_type *type;
+    /* aligned for any value */
+    union { ila_int integer; ila_dec decimal; void *pointer; } value[];
+};
+
+ila_box *ila_box_new(const ila_type *type, const void *value) {
+
+    ila_box *box = ila_allocate(sizeof(ila_box) + type->size);
+    box->references = 1;
+    box->type = type;
+    type->copy(type, box->value, value);
+    return box;
+}
+
+ila_box *ila_box_retain(ila_box *box) {
+
+    if (box != NULL) {
+        box->references += 1;
+    }
+    return box;
+}
+
+void ila_box_release(ila_box *box) {
+
+    if (box != NULL && --box->references == 0) {
+        box->type->drop(box->type, box->value);
+        free(box);
+    }
+}
+
+void *ila_box_value(ila_box *box) {
+    return box->value;
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                  writing                                   */
+/* -------------------------------------------------------------------------- */
+
+/* writes a text between quotes, escaping like the interpreter does inside lists */
+static void ila_write_quoted(ila_builder *out, const ila_text *text) {
+
+    ila_builder_push_str(out, "\"");
+    for (size_t index = 0; index < text->length; index++) {
+        char c = text->data[index];
+        switch (c) {
+            case '"': ila_builder_push_str(out, "\\\""); break;
+            case '\\': ila_builder_push_str(out, "\\\\"); break;
+            case '\n': ila_builder_push_str(out, "\\n"); break;
+            case '\t': ila_builder_push_str(out, "\\t"); break;
+            case '\r': ila_builder_push_str(out, "\\r"); break;
+            default: ila_builder_push(out, &c, 1); break;
+        }
+    }
+    ila_builder_push_str(out, "\"");
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                descriptors                                 */
+/* -------------------------------------------------------------------------- */
+
+static void ila_plain_copy(const ila_type *type, void *destination, const void *source) {
+    memcpy(destination, source, type->size);
+}
+
+static void ila_plain_drop(const ila_type *type, void *value) {
+    (void)type;
+    (void)value;
+}
+
+static void ila_integer_copy(const ila_type *type, void *destination, const void *source) {
+    (void)type;
+    *(ila_int *)destination = ila_int_retain(*(const ila_int *)source);
+}
+
+static void ila_integer_drop(const ila_type *type, void *value) {
+    (void)type;
+    ila_int_release(*(ila_int *)value);
+}
+
+static bool ila_integer_equals(const ila_type *type, const void *a, const void *b) {
+    (void)type;
+    return ila_int_equals(*(const ila_int *)a, *(const ila_int *)b);
+}
+
+static void ila_integer_write(const ila_type *type, ila_builder *out, const void *value, bool quoted) {
+    (void)type;
+    (void)quoted;
+    ila_write_integer(out, *(const ila_int *)value);
+}
+
+static void ila_decimal_copy(const ila_type *type, void *destination, const void *source) {
+    (void)type;
+    *(ila_dec *)destination = ila_dec_retain(*(const ila_dec *)source);
+}
+
+static void ila_decimal_drop(const ila_type *type, void *value) {
+    (void)type;
+    ila_dec_release(*(ila_dec *)value);
+}
+
+static bool ila_decimal_equals(const ila_type *type, const void *a, const void *b) {
+    (void)type;
+    return ila_dec_compare(*(const ila_dec *)a, *(const ila_dec *)b) == 0;
+}
+
+static void ila_decimal_write(const ila_type *type, ila_builder *out, const void *value, bool quoted) {
+    (void)type;
+    (void)quoted;
+    ila_write_decimal(out, *(const ila_dec *)value);
+}
+
+static void ila_text_copy(const ila_type *type, void *destination, const void *source) {
+    (void)type;
+    *(ila_text **)destination = ila_text_retain(*(ila_text *const *)source);
+}
+
+static void ila_text_drop(const ila_type *type, void *value) {
+    (void)type;
+    ila_text_release(*(ila_text **)value);
+}
+
+static bool ila_text_equals_value(const ila_type *type, const void *a, const void *b) {
+    (void)type;
+    return ila_text_equals(*(ila_text *const *)a, *(ila_text *const *)b);
+}
+
+static void ila_text_write(const ila_type *type, ila_builder *out, const void *value, bool quoted) {
+
+    (void)type;
+    const ila_text *text = *(ila_text *const *)value;
+    if (quoted) {
+        ila_write_quoted(out, text);
+    } else {
+        ila_builder_push(out, text->data, text->length);
+    }
+}
+
+static bool ila_boolean_equals(const ila_type *type, const void *a, const void *b) {
+    (void)type;
+    return *(const bool *)a == *(const bool *)b;
+}
+
+static void ila_boolean_write(const ila_type *type, ila_builder *out, const void *value, bool quoted) {
+    (void)type;
+    (void)quoted;
+    ila_builder_push_str(out, *(const bool *)value ? "verdadeiro" : "falso");
+}
+
+static bool ila_nothing_equals(const ila_type *type, const void *a, const void *b) {
+    (void)type;
+    (void)a;
+    (void)b;
+    return true;
+}
+
+static void ila_nothing_write(const ila_type *type, ila_builder *out, const void *value, bool quoted) {
+    (void)type;
+    (void)value;
+    (void)quoted;
+    ila_builder_push_str(out, "nada");
+}
+
+static void ila_list_copy_value(const ila_type *type, void *destination, const void *source) {
+    (void)type;
+    *(ila_list **)destination = ila_list_copy(*(ila_list *const *)source);
+}
+
+static void ila_list_drop(const ila_type *type, void *value) {
+    (void)type;
+    ila_list_free(*(ila_list **)value);
+}
+
+static bool ila_list_equals_value(const ila_type *type, const void *a, const void *b) {
+    (void)type;
+    return ila_list_equals(*(ila_list *const *)a, *(ila_list *const *)b);
+}
+
+static void ila_list_write(const ila_type *type, ila_builder *out, const void *value, bool quoted) {
+
+    (void)type;
+    (void)quoted;
+    const ila_list *list = *(ila_list *const *)value;
+    ila_builder_push_str(out, "[");
+    for (size_t index = 0; index < list->length; index++) {
+        if (index > 0) {
+            ila_builder_push_str(out, ", ");
+        }
+        list->item_type->write(list->item_type, out, ila_list_item(list, index), true);
+    }
+    ila_builder_push_str(out, "]");
+}
+
+const ila_type ila_type_integer = {sizeof(ila_int), ila_integer_copy, ila_integer_drop, ila_integer_equals, ila_integer_write, NULL};
+const ila_type ila_type_decimal = {sizeof(ila_dec), ila_decimal_copy, ila_decimal_drop, ila_decimal_equals, ila_decimal_write, NULL};
+const ila_type ila_type_text = {sizeof(ila_text *), ila_text_copy, ila_text_drop, ila_text_equals_value, ila_text_write, NULL};
+const ila_type ila_type_boolean = {sizeof(bool), ila_plain_copy, ila_plain_drop, ila_boolean_equals, ila_boolean_write, NULL};
+const ila_type ila_type_nothing = {sizeof(ila_nothing), ila_plain_copy, ila_plain_drop, ila_nothing_equals, ila_nothing_write, NULL};
+const ila_type ila_type_list = {sizeof(ila_list *), ila_list_copy_value, ila_list_drop, ila_list_equals_value, ila_list_write, NULL};
+
+void ila_ref_copy(const ila_type *type, void *destination, const void *source) {
+    ila_plain_copy(type, destination, source);
+}
+
+void ila_ref_drop(const ila_type *type, void *value) {
+    ila_plain_drop(type, value);
+}
+
+bool ila_ref_equals(const ila_type *type, const void *a, const void *b) {
+    return type->inner->equals(type->inner, *(void *const *)a, *(void *const *)b);
+}
+
+void ila_ref_write(const ila_type *type, ila_builder *out, const void *value, bool quoted) {
+    type->inner->write(type->inner, out, *(void *const *)value, quoted);
+}
+
+void ila_comp_copy(const ila_type *type, void *destination, const void *source) {
+    (void)type;
+    *(ila_box **)destination = ila_box_retain(*(ila_box *const *)source);
+}
+
+void ila_comp_drop(const ila_type *type, void *value) {
+    (void)type;
+    ila_box_release(*(ila_box **)value);
+}
+
+bool ila_comp_equals(const ila_type *type, const void *a, const void *b) {
+    return type->inner->equals(type->inner, ila_box_value(*(ila_box *const *)a), ila_box_value(*(ila_box *const *)b));
+}
+
+void ila_comp_write(const ila_type *type, ila_builder *out, const void *value, bool quoted) {
+    type->inner->write(type->inner, out, ila_box_value(*(ila_box *const *)value), quoted);
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                  builtins                                  */
+/* -------------------------------------------------------------------------- */
+
+void ila_write(const ila_type *type, const void *value) {
+
+    ila_builder out = {NULL, 0, 0};
+    type->write(type, &out, value, false);
+    ila_builder_push_str(&out, "\n");
+    fwrite(out.data, 1, out.length, stdout);
+    free(out.data);
+}
+
+ila_text *ila_read_line(void) {
+
+    fflush(stdout);
+
+    ila_builder line = {NULL, 0, 0};
+    int c;
+    while ((c = fgetc(stdin)) != EOF && c != '\n') {
+        char byte = (char)c;
+        ila_builder_push(&line, &byte, 1);
+    }
+    if (line.length > 0 && line.data[line.length - 1] == '\r') {
+        line.length -= 1;
+    }
+
+    ila_text *text = ila_text_new(line.data, line.length);
+    free(line.data);
+    return text;
+}
+
+ila_text *ila_to_text(const ila_type *type, const void *value) {
+
+    ila_builder out = {NULL, 0, 0};
+    type->write(type, &out, value, false);
+    ila_text *text = ila_text_new(out.data, out.length);
+    free(out.data);
+    return text;
+}
@@ -0,0 +1,148 @@
+/*
+ * Runtime of the programs compiled to C by `ila compilar`.
+ *
+ * Every value of the program has a C type: `inteiro` is `ila_int`, `decimal` is `ila_dec`,
+ * `lógico` is `bool`, `nada` is `ila_nothing`, `texto` is a reference counted `ila_text *`,
+ * `lista<T>` is an `ila_list *` owned by a single variable and shared with the `para cada`
+ * going through its items, `comp T` is a reference counted `ila_box *` and `ref T` is a plain
+ * pointer to a `T`.
+ *
+ * Values that own memory are copied with `copy` and freed with `drop` of their `ila_type`,
+ * and every `drop` accepts a value that was never assigned (`NULL`).
+ */
+
+#ifndef ILA_RUNTIME_H
+#define ILA_RUNTIME_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+typedef uint8_t ila_nothing;
+typedef struct ila_bignum ila_bignum;
+typedef struct ila_text ila_text;
+typedef struct ila_list ila_list;
+typedef struct ila_box ila_box;
+
+/* an `inteiro`: the value itself while it fits in 64 bits, and a reference counted `big` when
+ * it does not, so each number has a single representation */
+typedef struct ila_int {
+    int64_t small;
+    ila_bignum *big;
+} ila_int;
+
+/* a `decimal` without losing digits, `digits * 10^-scale`, where the last digit after the point
+ * is never a 0 */
+typedef struct ila_dec {
+    ila_int digits;
+    uint32_t scale;
+} ila_dec;
+
+/* constants of the generated code that fit in 64 bits */
+#define ILA_INT(value) ((ila_int){(value), NULL})
+#define ILA_DEC(digits, scale) ((ila_dec){{(digits), NULL}, (scale)})
+
+/* text being built, for `escrever` and `para_texto` */
+typedef struct ila_builder {
+    char *data;
+    size_t length;
+    size_t capacity;
+} ila_builder;
+
+/* how the values of a type are copied, freed, compared and written */
+typedef struct ila_type ila_type;
+struct ila_type {
+    size_t size;
+    void (*copy)(const ila_type *type, void *destination, const void *source);
+    void (*drop)(const ila_type *type, void *value);
+    bool (*equals)(const ila_type *type, const void *a, const void *b);
+    void (*write)(const ila_type *type, ila_builder *out, const void *value, bool quoted);
+    /* type pointed to by a `ref` or `comp` */
+    const ila_type *inner;
+};
+
+extern const ila_type ila_type_integer;
+extern const ila_type ila_type_decimal;
+extern const ila_type ila_type_text;
+extern const ila_type ila_type_boolean;
+extern const ila_type ila_type_nothing;
+extern const ila_type ila_type_list;
+
+/* operations of the descriptors of `ref T` and `comp T`, which the generated code defines */
+void ila_ref_copy(const ila_type *type, void *destination, const void *source);
+void ila_ref_drop(const ila_type *type, void *value);
+bool ila_ref_equals(const ila_type *type, const void *a, const void *b);
+void ila_ref_write(const ila_type *type, ila_builder *out, const void *value, bool quoted);
+void ila_comp_copy(const ila_type *type, void *destination, const void *source);
+void ila_comp_drop(const ila_type *type, void *value);
+bool ila_comp_equals(const ila_type *type, const void *a, const void *b);
+void ila_comp_write(const ila_type *type, ila_builder *out, const void *value, bool quoted);
+
+/* execution */
+void ila_start(const char *source_name);
+int ila_finish(void);
+void ila_enter(int line, int column);
+void ila_leave(void);
+
+/* inteiro, where the operands stay with their owners and the result is a new value */
+ila_int ila_int_parse(const char *text);
+ila_int ila_int_retain(ila_int value);
+void ila_int_release(ila_int value);
+ila_int ila_add(ila_int a, ila_int b);
+ila_int ila_sub(ila_int a, ila_int b);
+ila_int ila_mul(ila_int a, ila_int b);
+ila_int ila_div(ila_int a, ila_int b, int line, int column);
+ila_int ila_neg(ila_int a);
+bool ila_int_equals(ila_int a, ila_int b);
+/* negative, zero or positive when `a` is smaller than, equal to or larger than `b` */
+int ila_int_compare(ila_int a, ila_int b);
+
+/* decimal, like inteiro, where a division keeps 30 significant digits like the interpreter */
+ila_dec ila_dec_parse(const char *digits, uint32_t scale);
+ila_dec ila_dec_retain(ila_dec value);
+void ila_dec_release(ila_dec value);
+/* the integer as a decimal, which shares its digits and is not released */
+ila_dec ila_dec_of(ila_int value);
+ila_dec ila_dec_add(ila_dec a, ila_dec b);
+ila_dec ila_dec_sub(ila_dec a, ila_dec b);
+ila_dec ila_dec_mul(ila_dec a, ila_dec b);
+ila_dec ila_div_decimal(ila_dec a, ila_dec b, int line, int column);
+ila_dec ila_dec_neg(ila_dec a);
+int ila_dec_compare(ila_dec a, ila_dec b);
+
+/* texto */
+ila_text *ila_text_new(const char *data, size_t length);
+ila_text *ila_text_retain(ila_text *text);
+void ila_text_release(ila_text *text);
+ila_text *ila_text_concat(const ila_text *a, const ila_text *b);
+bool ila_text_equals(const ila_text *a, const ila_text *b);
+ila_int ila_text_length(const ila_text *text);
+/* list with a text for each character */
+ila_list *ila_text_chars(const ila_text *text);
+
+/* lista */
+ila_list *ila_list_new(const ila_type *item_type);
+ila_list *ila_list_copy(const ila_list *list);
+/* the same list, for a `para cada` that goes through the items it has when the loop starts */
+ila_list *ila_list_retain(ila_list *list);
+void ila_list_free(ila_list *list);
+bool ila_list_equals(const ila_list *a, const ila_list *b);
+ila_int ila_list_length(const ila_list *list);
+/* adds a copy of the item to the end of the list */
+void ila_list_push(ila_list *list, const void *item);
+void *ila_list_item(const ila_list *list, size_t index);
+/* item at a position given by the program, failing when it does not exist */
+void *ila_list_get(const ila_list *list, ila_int index, int line, int column);
+
+/* comp */
+ila_box *ila_box_new(const ila_type *type, const void *value);
+ila_box *ila_box_retain(ila_box *box);
+void ila_box_release(ila_box *box);
+void *ila_box_value(ila_box *box);
+
+/* builtins */
+void ila_write(const ila_type *type, const void *value);
+ila_text *ila_read_line(void);
+ila_text *ila_to_text(const ila_type *type, const void *value);
+
+#endif
@@ -0,0 +1,69 @@
+// Host of the WebAssembly modules generated by `ila compilar --alvo wasm`, for browsers.
+//
+//     import { run } from "./ila.js";
+//     const bytes = await (await fetch("programa.wasm")).arrayBuffer();
+//     await run(bytes, { write: text => saida.textContent += text });
+//
+// `write` receives what the program writes, `readLine` returns the next line of the input
+// (by default asked with `prompt`), and a runtime error is thrown as an `IlaError`.
+
+const encoder = new TextEncoder();
+const decoder = new TextDecoder();
+
+export class IlaError extends Error {
+
+    constructor(code, message, line, column, note) {
+        super(`erro[${code}]: ${message}`);
+        this.code = code;
+        this.line = line;
+        this.column = column;
+        this.note = note;
+    }
+}
+
+function runtimeError(kind, line, column, index, length) {
+
+    switch (kind) {
+        case 1:
+            return new IlaError("E0701", "divisão por zero", line, column);
+        case 2:
+            return new IlaError("E0702", `a posição ${index} não existe em uma lista com ${length} ${length == 1 ? "item" : "itens"}`, line, column,
+                "as posições de uma lista começam em 0 e vão até o tamanho da lista menos 1");
+        default:
+            return new IlaError("E0704", "mais de 10000 chamadas em andamento ao mesmo tempo", line, column,
+                "isso acontece quando um subprograma chama a si mesmo sem nunca parar");
+    }
+}
+
+export async function run(bytes, { write = text => console.log(text), readLine = () => prompt() ?? "" } = {}) {
+
+    let memory;
+    let line = new Uint8Array();
+
+    const imports = {
+        ila: {
+            write(pointer, length) {
+                write(decoder.decode(new Uint8Array(memory.buffer, pointer, length)));
+            },
+            read_line() {
+                line = encoder.encode(readLine().replace(/\r?\n$/, ""));
+                return line.length;
+            },
+            read_bytes(destination) {
+                new Uint8Array(memory.buffer, destination, line.length).set(line);
+            },
+            fail(kind, line, column, index, length) {
+                // the position is a text, {length, bytes}, since it may not fit in 64 bits
+                if (kind == 2) {
+                    const indexLength = new DataView(memory.buffer).getUint32(index, true);
+                    index = decoder.decode(new Uint8Array(memory.buffer, index + 4, indexLength));
+                }
+                throw runtimeError(kind, line, column, index, length);
+            },
+        },
+    };
+
+    const { instance } = await WebAssembly.instantiate(bytes, imports);
+    memory = instance.exports.memory;
+    instance.exports._start();
+}
@@ -0,0 +1,948 @@
+;; Runtime of the WebAssembly modules generated by `ila compilar --alvo wasm`.
+;;
+;; These are the first fields of every generated module, which adds the data and the functions
+;; of the program after them. The host provides the imports of the "ila" module: `ila.js` for
+;; browsers, or the embedded runtime of the tests.
+;;
+;; Every value of the program is stored in 8 bytes: `inteiro` is an i64, and the other types
+;; are an i32 (extended to 8 bytes in memory). `texto` points to its length
+;; followed by its UTF-8 bytes, `lista<T>` to a header {length, capacity, items, item type}
+;; where `items` has the address of the 8 bytes of each item, so references to an item stay
+;; valid while the list grows, and `ref T` and `comp T` point to the 8 bytes of the value.
+;; Integers too large for the i64 and decimals are kept in memory, as described in their sections.
+;; Memory is never released: a program keeps every value it creates until it ends.
+;;
+;; Memory layout:
+;;       8  descriptors of the types, {kind, descriptor of the inner type}
+;;      64  bytes of the texts the runtime writes
+;;     128  scratch buffer used to write numbers
+;;    1024  data of the program, followed by the values created while it runs
+
+  ;; host
+  (import "ila" "write" (func $host_write (param $bytes i32) (param $length i32)))
+  ;; reads a line without its end, returning its length in bytes
+  (import "ila" "read_line" (func $host_read_line (result i32)))
+  ;; copies the bytes of the line read last
+  (import "ila" "read_bytes" (func $host_read_bytes (param $destination i32)))
+  ;; stops the program with a runtime error: 1 division by zero, 2 index out of bounds, with the
+  ;; text of the position and the length of the list, 3 call depth exceeded
+  (import "ila" "fail" (func $host_fail (param $kind i32) (param $line i32) (param $column i32) (param $index i32) (param $length i64)))
+
+  (memory (export "memory") 1)
+  ;; start of the free memory, set by `_start` after the data of the program
+  (global $heap (mut i32) (i32.const 0))
+  (global $depth (mut i32) (i32.const 1))
+
+  ;; kinds: 0 inteiro, 1 decimal, 2 texto, 3 lógico, 4 nada, 5 lista, 6 ref, 7 comp
+  (data (i32.const 8) "\00\00\00\00\00\00\00\00")
+  (data (i32.const 16) "\01\00\00\00\00\00\00\00")
+  (data (i32.const 24) "\02\00\00\00\00\00\00\00")
+  (data (i32.const 32) "\03\00\00\00\00\00\00\00")
+  (data (i32.const 40) "\04\00\00\00\00\00\00\00")
+  (data (i32.const 48) "\05\00\00\00\00\00\00\00")
+  (data (i32.const 64) "verdadeirofalsonada, ")
+
+  ;; ---- memory ----
+
+  (func $alloc (param $size i32) (result i32)
+    (local $address i32)
+    (local $end i32)
+    (local $available i32)
+    (local.set $address (i32.and (i32.add (global.get $heap) (i32.const 7)) (i32.const -8)))
+    (local.set $end (i32.add (local.get $address) (local.get $size)))
+    (local.set $available (i32.mul (memory.size) (i32.const 65536)))
+    (if (i32.gt_u (local.get $end) (local.get $available))
+      (then
+        (if (i32.eq
+              (memory.grow (i32.shr_u (i32.add (i32.sub (local.get $end) (local.get $available)) (i32.const 65535)) (i32.const 16)))
+              (i32.const -1))
+          (then (unreachable)))))
+    (global.set $heap (local.get $end))
+    (local.get $address))
+
+  ;; new storage with a value, for `comp` and for variables that a `ref` points to
+  (func $box_i64 (param $value i64) (result i32)
+    (local $slot i32)
+    (local.set $slot (call $alloc (i32.const 8)))
+    (i64.store (local.get $slot) (local.get $value))
+    (local.get $slot))
+
+  (func $box_i32 (param $value i32) (result i32)
+    (call $box_i64 (i64.extend_i32_u (local.get $value))))
+
+  (func $store_i64 (param $value i64) (param $slot i32)
+    (i64.store (local.get $slot) (local.get $value)))
+
+  (func $store_i32 (param $value i32) (param $slot i32)
+    (i64.store (local.get $slot) (i64.extend_i32_u (local.get $value))))
+
+  ;; ---- execution ----
+
+  (func $fail (param $kind i32) (param $line i32) (param $column i32)
+    (call $host_fail (local.get $kind) (local.get $line) (local.get $column) (i32.const 0) (i64.const 0))
+    (unreachable))
+
+  ;; most calls in progress at the same time, like in the interpreter
+  (func $enter (param $line i32) (param $column i32)
+    (if (i32.ge_u (global.get $depth) (i32.const 10000))
+      (then (call $fail (i32.const 3) (local.get $line) (local.get $column))))
+    (global.set $depth (i32.add (global.get $depth) (i32.const 1))))
+
+  (func $leave
+    (global.set $depth (i32.sub (global.get $depth) (i32.const 1))))
+
+  ;; ---- integers ----
+
+  ;; An `inteiro` is the i64 itself when it is at least -2^63 + 2^32, and otherwise -2^63 plus
+  ;; the address of a big integer {length, negative, limbs}, whose magnitude has 32 bits in each
+  ;; limb, starting from the least significant, and whose last limb is never 0. So each number
+  ;; has a single representation, and the usual numbers are never allocated.
+
+  (func $is_big (param $value i64) (result i32)
+    (i64.lt_s (local.get $value) (i64.const -9223372032559808512)))
+
+  (func $is_negative (param $value i64) (result i32)
+    (if (call $is_big (local.get $value))
+      (then (return (i32.load offset=4 (i32.wrap_i64 (local.get $value))))))
+    (i64.lt_s (local.get $value) (i64.const 0)))
+
+  ;; a big integer with room for `length` limbs, all 0
+  (func $big_new (param $length i32) (param $negative i32) (result i32)
+    (local $big i32)
+    (local.set $big (call $alloc (i32.add (i32.const 8) (i32.shl (local.get $length) (i32.const 2)))))
+    (memory.fill (i32.add (local.get $big) (i32.const 8)) (i32.const 0) (i32.shl (local.get $length) (i32.const 2)))
+    (i32.store (local.get $big) (local.get $length))
+    (i32.store offset=4 (local.get $big) (local.get $negative))
+    (local.get $big))
+
+  ;; a big integer with the value, which is new when the value is small
+  (func $big (param $value i64) (result i32)
+    (local $big i32)
+    (local $magnitude i64)
+    (if (call $is_big (local.get $value))
+      (then (return (i32.wrap_i64 (local.get $value)))))
+    (local.set $magnitude (local.get $value))
+    (if (i64.lt_s (local.get $value) (i64.const 0))
+      (then (local.set $magnitude (i64.sub (i64.const 0) (local.get $value)))))
+    (local.set $big (call $big_new (i32.const 2) (i64.lt_s (local.get $value) (i64.const 0))))
+    (i64.store offset=8 (local.get $big) (local.get $magnitude))
+    (i32.store (local.get $big) (i32.add (i32.const 1) (i64.gt_u (local.get $magnitude) (i64.const 4294967295))))
+    (local.get $big))
+
+  (func $limb_address (param $big i32) (param $index i32) (result i32)
+    (i32.add (i32.add (local.get $big) (i32.const 8)) (i32.shl (local.get $index) (i32.const 2))))
+
+  ;; a limb of the magnitude, which is 0 past its length
+  (func $limb (param $big i32) (param $index i32) (result i64)
+    (if (i32.ge_u (local.get $index) (i32.load (local.get $big)))
+      (then (return (i64.const 0))))
+    (i64.load32_u (call $limb_address (local.get $big) (local.get $index))))
+
+  ;; the integer with the sign and magnitude of a big integer that no one else has, whose last
+  ;; limbs may be zeros
+  (func $integer_of_big (param $big i32) (result i64)
+    (local $length i32)
+    (local $magnitude i64)
+    (local.set $length (i32.load (local.get $big)))
+    (block $done
+      (loop $next
+        (br_if $done (i32.eqz (local.get $length)))
+        (br_if $done (i32.wrap_i64 (call $limb (local.get $big) (i32.sub (local.get $length) (i32.const 1)))))
+        (local.set $length (i32.sub (local.get $length) (i32.const 1)))
+        (br $next)))
+    (i32.store (local.get $big) (local.get $length))
+    (if (i32.le_u (local.get $length) (i32.const 2))
+      (then
+        (local.set $magnitude (i64.or
+          (call $limb (local.get $big) (i32.const 0))
+          (i64.shl (call $limb (local.get $big) (i32.const 1)) (i64.const 32))))
+        (if (i32.load offset=4 (local.get $big))
+          (then
+            (if (i64.le_u (local.get $magnitude) (i64.const 9223372032559808512))
+              (then (return (i64.sub (i64.const 0) (local.get $magnitude))))))
+          (else
+            (if (i64.ge_s (local.get $magnitude) (i64.const 0))
+              (then (return (local.get $magnitude))))))))
+    (i64.add (i64.const -9223372036854775808) (i64.extend_i32_u (local.get $big))))
+
+  ;; ---- magnitudes ----
+
+  (func $magnitude_compare (param $a i32) (param $b i32) (result i32)
+    (local $index i32)
+    (local $x i64)
+    (local $y i64)
+    (local.set $index (select
+      (i32.load (local.get $a))
+      (i32.load (local.get $b))
+      (i32.gt_u (i32.load (local.get $a)) (i32.load (local.get $b)))))
+    (block $done
+      (loop $next
+        (br_if $done (i32.eqz (local.get $index)))
+        (local.set $index (i32.sub (local.get $index) (i32.const 1)))
+        (local.set $x (call $limb (local.get $a) (local.get $index)))
+        (local.set $y (call $limb (local.get $b) (local.get $index)))
+        (if (i64.ne (local.get $x) (local.get $y))
+          (then (return (select (i32.const -1) (i32.const 1) (i64.lt_u (local.get $x) (local.get $y))))))
+        (br $next)))
+    (i32.const 0))
+
+  ;; |a| + |b| in `result`, which has room for the longest plus one limb
+  (func $magnitude_add (param $a i32) (param $b i32) (param $result i32)
+    (local $index i32)
+    (local $sum i64)
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $index) (i32.load (local.get $result))))
+        (local.set $sum (i64.add
+          (i64.add (call $limb (local.get $a) (local.get $index)) (call $limb (local.get $b) (local.get $index)))
+          (i64.shr_u (local.get $sum) (i64.const 32))))
+        (i64.store32 (call $limb_address (local.get $result) (local.get $index)) (local.get $sum))
+        (local.set $index (i32.add (local.get $index) (i32.const 1)))
+        (br $next))))
+
+  ;; |a| - |b| in `result`, where |a| is not smaller than |b|, and `result` may be `a`
+  (func $magnitude_sub (param $a i32) (param $b i32) (param $result i32)
+    (local $index i32)
+    (local $difference i64)
+    (local $borrow i64)
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $index) (i32.load (local.get $result))))
+        (local.set $difference (i64.sub
+          (i64.sub (call $limb (local.get $a) (local.get $index)) (call $limb (local.get $b) (local.get $index)))
+          (local.get $borrow)))
+        (local.set $borrow (i64.extend_i32_u (i64.lt_s (local.get $difference) (i64.const 0))))
+        (i64.store32 (call $limb_address (local.get $result) (local.get $index)) (local.get $difference))
+        (local.set $index (i32.add (local.get $index) (i32.const 1)))
+        (br $next))))
+
+  (func $magnitude_mul (param $a i32) (param $b i32) (param $negative i32) (result i32)
+    (local $result i32)
+    (local $i i32)
+    (local $j i32)
+    (local $carry i64)
+    (local $product i64)
+    (local.set $result (call $big_new (i32.add (i32.load (local.get $a)) (i32.load (local.get $b))) (local.get $negative)))
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $i) (i32.load (local.get $a))))
+        (local.set $carry (i64.const 0))
+        (local.set $j (i32.const 0))
+        (block $row_done
+          (loop $row
+            (br_if $row_done (i32.ge_u (local.get $j) (i32.load (local.get $b))))
+            (local.set $product (i64.add
+              (i64.add
+                (i64.mul (call $limb (local.get $a) (local.get $i)) (call $limb (local.get $b) (local.get $j)))
+                (call $limb (local.get $result) (i32.add (local.get $i) (local.get $j))))
+              (local.get $carry)))
+            (i64.store32 (call $limb_address (local.get $result) (i32.add (local.get $i) (local.get $j))) (local.get $product))
+            (local.set $carry (i64.shr_u (local.get $product) (i64.const 32)))
+            (local.set $j (i32.add (local.get $j) (i32.const 1)))
+            (br $row)))
+        (i64.store32 (call $limb_address (local.get $result) (i32.add (local.get $i) (local.get $j))) (local.get $carry))
+        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+        (br $next)))
+    (local.get $result))
+
+  ;; ---- operations ----
+
+  ;; a + b, or a - b when `subtract` is set
+  (func $big_add (param $a i64) (param $b i64) (param $subtract i32) (result i64)
+    (local $x i32)
+    (local $y i32)
+    (local $y_negative i32)
+    (local $result i32)
+    (local.set $x (call $big (local.get $a)))
+    (local.set $y (call $big (local.get $b)))
+    (local.set $y_negative (i32.xor (i32.load offset=4 (local.get $y)) (local.get $subtract)))
+    (local.set $result (call $big_new
+      (i32.add
+        (select (i32.load (local.get $x)) (i32.load (local.get $y)) (i32.gt_u (i32.load (local.get $x)) (i32.load (local.get $y))))
+        (i32.const 1))
+      (i32.load offset=4 (local.get $x))))
+    (if (i32.eq (i32.load offset=4 (local.get $x)) (local.get $y_negative))
+      (then (call $magnitude_add (local.get $x) (local.get $y) (local.get $result)))
+      (else
+        (if (i32.ge_s (call $magnitude_compare (local.get $x) (local.get $y)) (i32.const 0))
+          (then (call $magnitude_sub (local.get $x) (local.get $y) (local.get $result)))
+          (else
+            (call $magnitude_sub (local.get $y) (local.get $x) (local.get $result))
+            (i32.store offset=4 (local.get $result) (local.get $y_negative))))))
+    (call $integer_of_big (local.get $result)))
+
+  (func $add (param $a i64) (param $b i64) (result i64)
+    (local $result i64)
+    (if (i32.eqz (i32.or (call $is_big (local.get $a)) (call $is_big (local.get $b))))
+      (then
+        (local.set $result (i64.add (local.get $a) (local.get $b)))
+        ;; the sum overflows when its sign differs from the sign of both operands
+        (if (i32.and
+              (i64.ge_s (i64.and (i64.xor (local.get $a) (local.get $result)) (i64.xor (local.get $b) (local.get $result))) (i64.const 0))
+              (i32.eqz (call $is_big (local.get $result))))
+          (then (return (local.get $result))))))
+    (call $big_add (local.get $a) (local.get $b) (i32.const 0)))
+
+  (func $sub (param $a i64) (param $b i64) (result i64)
+    (local $result i64)
+    (if (i32.eqz (i32.or (call $is_big (local.get $a)) (call $is_big (local.get $b))))
+      (then
+        (local.set $result (i64.sub (local.get $a) (local.get $b)))
+        ;; the difference overflows when the operands have different signs and it has the sign of `b`
+        (if (i32.and
+              (i64.ge_s (i64.and (i64.xor (local.get $a) (local.get $b)) (i64.xor (local.get $a) (local.get $result))) (i64.const 0))
+              (i32.eqz (call $is_big (local.get $result))))
+          (then (return (local.get $result))))))
+    (call $big_add (local.get $a) (local.get $b) (i32.const 1)))
+
+  (func $mul (param $a i64) (param $b i64) (result i64)
+    (local $result i64)
+    (local $x i32)
+    (local $y i32)
+    (if (i64.eqz (local.get $a))
+      (then (return (i64.const 0))))
+    (if (i32.eqz (i32.or (call $is_big (local.get $a)) (call $is_big (local.get $b))))
+      (then
+        (local.set $result (i64.mul (local.get $a) (local.get $b)))
+        ;; small integers are never -2^63, so the division does not trap
+        (if (i32.and
+              (i64.eq (i64.div_s (local.get $result) (local.get $a)) (local.get $b))
+              (i32.eqz (call $is_big (local.get $result))))
+          (then (return (local.get $result))))))
+    (local.set $x (call $big (local.get $a)))
+    (local.set $y (call $big (local.get $b)))
+    (call $integer_of_big (call $magnitude_mul (local.get $x) (local.get $y)
+      (i32.xor (i32.load offset=4 (local.get $x)) (i32.load offset=4 (local.get $y))))))
+
+  ;; remainder of the last `$divide`, with the sign of the dividend
+  (global $remainder (mut i64) (i64.const 0))
+
+  ;; a / b truncated toward zero, where `b` is not 0, with a % b in `$remainder`
+  (func $divide (param $a i64) (param $b i64) (result i64)
+    (local $result i64)
+    (local $x i32)
+    (local $y i32)
+    (local $quotient i32)
+    (local $rest i32)
+    (local $bit i32)
+    (local $index i32)
+    (local $carry i64)
+    (local $current i64)
+    (if (i32.eqz (i32.or (call $is_big (local.get $a)) (call $is_big (local.get $b))))
+      (then
+        (local.set $result (i64.div_s (local.get $a) (local.get $b)))
+        (global.set $remainder (i64.rem_s (local.get $a) (local.get $b)))
+        (if (i32.eqz (call $is_big (local.get $result)))
+          (then (return (local.get $result))))))
+
+    (local.set $x (call $big (local.get $a)))
+    (local.set $y (call $big (local.get $b)))
+    (local.set $quotient (call $big_new (i32.load (local.get $x))
+      (i32.xor (i32.load offset=4 (local.get $x)) (i32.load offset=4 (local.get $y)))))
+
+    (if (i32.eq (i32.load (local.get $y)) (i32.const 1))
+      (then
+        ;; a divisor of one limb divides each limb, from the most significant
+        (local.set $index (i32.load (local.get $x)))
+        (block $done
+          (loop $next
+            (br_if $done (i32.eqz (local.get $index)))
+            (local.set $index (i32.sub (local.get $index) (i32.const 1)))
+            (local.set $current (i64.or (i64.shl (local.get $carry) (i64.const 32)) (call $limb (local.get $x) (local.get $index))))
+            (i64.store32 (call $limb_address (local.get $quotient) (local.get $index))
+              (i64.div_u (local.get $current) (call $limb (local.get $y) (i32.const 0))))
+            (local.set $carry (i64.rem_u (local.get $current) (call $limb (local.get $y) (i32.const 0))))
+            (br $next)))
+        (global.set $remainder (select
+          (i64.sub (i64.const 0) (local.get $carry))
+          (local.get $carry)
+          (i32.load offset=4 (local.get $x))))
+        (return (call $integer_of_big (local.get $quotient)))))
+
+    ;; otherwise each bit of `a`, from the most significant, is added to the rest, which is
+    ;; subtracted from when it reaches `b`
+    (local.set $rest (call $big_new (i32.add (i32.load (local.get $y)) (i32.const 1)) (i32.load offset=4 (local.get $x))))
+    (local.set $bit (i32.shl (i32.load (local.get $x)) (i32.const 5)))
+    (block $done
+      (loop $next
+        (br_if $done (i32.eqz (local.get $bit)))
+        (local.set $bit (i32.sub (local.get $bit) (i32.const 1)))
+        (local.set $carry (i64.and
+          (i64.shr_u (call $limb (local.get $x) (i32.shr_u (local.get $bit) (i32.const 5))) (i64.extend_i32_u (i32.and (local.get $bit) (i32.const 31))))
+          (i64.const 1)))
+        (local.set $index (i32.const 0))
+        (block $shifted
+          (loop $shift
+            (br_if $shifted (i32.ge_u (local.get $index) (i32.load (local.get $rest))))
+            (local.set $current (i64.or (i64.shl (call $limb (local.get $rest) (local.get $index)) (i64.const 1)) (local.get $carry)))
+            (i64.store32 (call $limb_address (local.get $rest) (local.get $index)) (local.get $current))
+            (local.set $carry (i64.shr_u (local.get $current) (i64.const 32)))
+            (local.set $index (i32.add (local.get $index) (i32.const 1)))
+            (br $shift)))
+        (if (i32.ge_s (call $magnitude_compare (local.get $rest) (local.get $y)) (i32.const 0))
+          (then
+            (call $magnitude_sub (local.get $rest) (local.get $y) (local.get $rest))
+            (i32.store (call $limb_address (local.get $quotient) (i32.shr_u (local.get $bit) (i32.const 5)))
+              (i32.or
+                (i32.load (call $limb_address (local.get $quotient) (i32.shr_u (local.get $bit) (i32.const 5))))
+                (i32.shl (i32.const 1) (local.get $bit))))))
+        (br $next)))
+    (global.set $remainder (call $integer_of_big (local.get $rest)))
+    (call $integer_of_big (local.get $quotient)))
+
+  (func $div (param $a i64) (param $b i64) (param $line i32) (param $column i32) (result i64)
+    (if (i64.eqz (local.get $b))
+      (then (call $fail (i32.const 1) (local.get $line) (local.get $column))))
+    (call $divide (local.get $a) (local.get $b)))
+
+  (func $neg (param $a i64) (result i64)
+    (call $sub (i64.const 0) (local.get $a)))
+
+  ;; negative, zero or positive when `a` is smaller than, equal to or larger than `b`
+  (func $integer_compare (param $a i64) (param $b i64) (result i32)
+    (local $order i32)
+    (if (i32.eqz (i32.or (call $is_big (local.get $a)) (call $is_big (local.get $b))))
+      (then (return (i32.sub (i64.gt_s (local.get $a) (local.get $b)) (i64.lt_s (local.get $a) (local.get $b))))))
+    (if (i32.ne (call $is_negative (local.get $a)) (call $is_negative (local.get $b)))
+      (then (return (select (i32.const -1) (i32.const 1) (call $is_negative (local.get $a))))))
+    (local.set $order (call $magnitude_compare (call $big (local.get $a)) (call $big (local.get $b))))
+    (select (i32.sub (i32.const 0) (local.get $order)) (local.get $order) (call $is_negative (local.get $a))))
+
+  (func $integer_equals (param $a i64) (param $b i64) (result i32)
+    (i32.eqz (call $integer_compare (local.get $a) (local.get $b))))
+
+  ;; number of digits of the magnitude
+  (func $digit_count (param $value i64) (result i32)
+    (local $count i32)
+    (local $chunk i64)
+    (if (call $is_negative (local.get $value))
+      (then (local.set $value (call $neg (local.get $value)))))
+    ;; each chunk of 9 digits is counted at once, except the most significant one
+    (block $done
+      (loop $next
+        (local.set $value (call $divide (local.get $value) (i64.const 1000000000)))
+        (local.set $chunk (global.get $remainder))
+        (br_if $done (i64.eqz (local.get $value)))
+        (local.set $count (i32.add (local.get $count) (i32.const 9)))
+        (br $next)))
+    (loop $digit
+      (local.set $count (i32.add (local.get $count) (i32.const 1)))
+      (local.set $chunk (i64.div_u (local.get $chunk) (i64.const 10)))
+      (br_if $digit (i64.ne (local.get $chunk) (i64.const 0))))
+    (local.get $count))
+
+  ;; ---- decimals ----
+
+  ;; A `decimal` is the address of {digits, scale}, for `digits * 10^-scale`, where the last
+  ;; digit after the point is never a 0.
+
+  ;; significant digits kept by a division, like the interpreter
+  (global $division_precision i32 (i32.const 30))
+
+  (func $power_of_ten (param $exponent i32) (result i64)
+    (local $result i64)
+    (local $step i32)
+    (local $factor i64)
+    (local.set $result (i64.const 1))
+    (block $done
+      (loop $next
+        (br_if $done (i32.eqz (local.get $exponent)))
+        (local.set $step (select (local.get $exponent) (i32.const 18) (i32.lt_u (local.get $exponent) (i32.const 18))))
+        (local.set $exponent (i32.sub (local.get $exponent) (local.get $step)))
+        (local.set $factor (i64.const 1))
+        (block $factor_done
+          (loop $factor_next
+            (br_if $factor_done (i32.eqz (local.get $step)))
+            (local.set $factor (i64.mul (local.get $factor) (i64.const 10)))
+            (local.set $step (i32.sub (local.get $step) (i32.const 1)))
+            (br $factor_next)))
+        (local.set $result (call $mul (local.get $result) (local.get $factor)))
+        (br $next)))
+    (local.get $result))
+
+  (func $decimal_new (param $digits i64) (param $scale i32) (result i32)
+    (local $decimal i32)
+    (local $quotient i64)
+    (block $done
+      (loop $next
+        (br_if $done (i32.or (i32.eqz (local.get $scale)) (i64.eqz (local.get $digits))))
+        (local.set $quotient (call $divide (local.get $digits) (i64.const 10)))
+        (br_if $done (i32.eqz (i64.eqz (global.get $remainder))))
+        (local.set $digits (local.get $quotient))
+        (local.set $scale (i32.sub (local.get $scale) (i32.const 1)))
+        (br $next)))
+    (if (i64.eqz (local.get $digits))
+      (then (local.set $scale (i32.const 0))))
+    (local.set $decimal (call $alloc (i32.const 16)))
+    (i64.store (local.get $decimal) (local.get $digits))
+    (i32.store offset=8 (local.get $decimal) (local.get $scale))
+    (local.get $decimal))
+
+  (func $decimal_of_integer (param $value i64) (result i32)
+    (call $decimal_new (local.get $value) (i32.const 0)))
+
+  (func $largest_scale (param $a i32) (param $b i32) (result i32)
+    (select
+      (i32.load offset=8 (local.get $a))
+      (i32.load offset=8 (local.get $b))
+      (i32.gt_u (i32.load offset=8 (local.get $a)) (i32.load offset=8 (local.get $b)))))
+
+  ;; the digits of a decimal with more digits after the point
+  (func $digits_at_scale (param $decimal i32) (param $scale i32) (result i64)
+    (call $mul
+      (i64.load (local.get $decimal))
+      (call $power_of_ten (i32.sub (local.get $scale) (i32.load offset=8 (local.get $decimal))))))
+
+  (func $decimal_add (param $a i32) (param $b i32) (result i32)
+    (local $scale i32)
+    (local.set $scale (call $largest_scale (local.get $a) (local.get $b)))
+    (call $decimal_new
+      (call $add (call $digits_at_scale (local.get $a) (local.get $scale)) (call $digits_at_scale (local.get $b) (local.get $scale)))
+      (local.get $scale)))
+
+  (func $decimal_sub (param $a i32) (param $b i32) (result i32)
+    (local $scale i32)
+    (local.set $scale (call $largest_scale (local.get $a) (local.get $b)))
+    (call $decimal_new
+      (call $sub (call $digits_at_scale (local.get $a) (local.get $scale)) (call $digits_at_scale (local.get $b) (local.get $scale)))
+      (local.get $scale)))
+
+  (func $decimal_mul (param $a i32) (param $b i32) (result i32)
+    (call $decimal_new
+      (call $mul (i64.load (local.get $a)) (i64.load (local.get $b)))
+      (i32.add (i32.load offset=8 (local.get $a)) (i32.load offset=8 (local.get $b)))))
+
+  (func $decimal_neg (param $a i32) (result i32)
+    (call $decimal_new (call $neg (i64.load (local.get $a))) (i32.load offset=8 (local.get $a))))
+
+  (func $decimal_compare (param $a i32) (param $b i32) (result i32)
+    (local $scale i32)
+    (local.set $scale (call $largest_scale (local.get $a) (local.get $b)))
+    (call $integer_compare (call $digits_at_scale (local.get $a) (local.get $scale)) (call $digits_at_scale (local.get $b) (local.get $scale))))
+
+  (func $decimal_equals (param $a i32) (param $b i32) (result i32)
+    (i32.eqz (call $decimal_compare (local.get $a) (local.get $b))))
+
+  ;; division rounded to `$division_precision` significant digits, half away from zero
+  (func $div_decimal (param $a i32) (param $b i32) (param $line i32) (param $column i32) (result i32)
+    (local $extra i32)
+    (local $dividend i64)
+    (local $quotient i64)
+    (local $scale i32)
+    (if (i64.eqz (i64.load (local.get $b)))
+      (then (call $fail (i32.const 1) (local.get $line) (local.get $column))))
+
+    ;; enough extra digits in the dividend for the quotient to have the precision
+    (local.set $extra (i32.sub
+      (i32.add (global.get $division_precision) (call $digit_count (i64.load (local.get $b))))
+      (call $digit_count (i64.load (local.get $a)))))
+    (if (i32.lt_s (local.get $extra) (i32.const 0))
+      (then (local.set $extra (i32.const 0))))
+    (local.set $dividend (call $mul (i64.load (local.get $a)) (call $power_of_ten (local.get $extra))))
+    (local.set $quotient (call $divide (local.get $dividend) (i64.load (local.get $b))))
+    (if (i32.ge_s
+          (call $magnitude_compare
+            (call $big (call $add (global.get $remainder) (global.get $remainder)))
+            (call $big (i64.load (local.get $b))))
+          (i32.const 0))
+      (then
+        (local.set $quotient (call $add (local.get $quotient)
+          (select
+            (i64.const -1)
+            (i64.const 1)
+            (i32.ne (call $is_negative (local.get $dividend)) (call $is_negative (i64.load (local.get $b)))))))))
+
+    ;; the quotient has `a.scale + extra - b.scale` digits after the point
+    (local.set $scale (i32.sub
+      (i32.add (i32.load offset=8 (local.get $a)) (local.get $extra))
+      (i32.load offset=8 (local.get $b))))
+    (if (i32.lt_s (local.get $scale) (i32.const 0))
+      (then
+        (return (call $decimal_new
+          (call $mul (local.get $quotient) (call $power_of_ten (i32.sub (i32.const 0) (local.get $scale))))
+          (i32.const 0)))))
+    (call $decimal_new (local.get $quotient) (local.get $scale)))
+
+  ;; ---- texts ----
+
+  (func $text_alloc (param $length i32) (result i32)
+    (local $text i32)
+    (local.set $text (call $alloc (i32.add (local.get $length) (i32.const 4))))
+    (i32.store (local.get $text) (local.get $length))
+    (local.get $text))
+
+  (func $text_concat (param $a i32) (param $b i32) (result i32)
+    (local $text i32)
+    (local.set $text (call $text_alloc (i32.add (i32.load (local.get $a)) (i32.load (local.get $b)))))
+    (memory.copy (i32.add (local.get $text) (i32.const 4)) (i32.add (local.get $a) (i32.const 4)) (i32.load (local.get $a)))
+    (memory.copy
+      (i32.add (i32.add (local.get $text) (i32.const 4)) (i32.load (local.get $a)))
+      (i32.add (local.get $b) (i32.const 4))
+      (i32.load (local.get $b)))
+    (local.get $text))
+
+  (func $text_equals (param $a i32) (param $b i32) (result i32)
+    (local $index i32)
+    (if (i32.ne (i32.load (local.get $a)) (i32.load (local.get $b)))
+      (then (return (i32.const 0))))
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $index) (i32.load (local.get $a))))
+        (if (i32.ne
+              (i32.load8_u offset=4 (i32.add (local.get $a) (local.get $index)))
+              (i32.load8_u offset=4 (i32.add (local.get $b) (local.get $index))))
+          (then (return (i32.const 0))))
+        (local.set $index (i32.add (local.get $index) (i32.const 1)))
+        (br $next)))
+    (i32.const 1))
+
+  ;; number of bytes of the UTF-8 character that starts with this byte
+  (func $char_width (param $first i32) (result i32)
+    (if (i32.ge_u (local.get $first) (i32.const 0xF0)) (then (return (i32.const 4))))
+    (if (i32.ge_u (local.get $first) (i32.const 0xE0)) (then (return (i32.const 3))))
+    (if (i32.ge_u (local.get $first) (i32.const 0xC0)) (then (return (i32.const 2))))
+    (i32.const 1))
+
+  (func $text_length (param $text i32) (result i64)
+    (local $index i32)
+    (local $count i64)
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $index) (i32.load (local.get $text))))
+        (local.set $index (i32.add (local.get $index)
+          (call $char_width (i32.load8_u offset=4 (i32.add (local.get $text) (local.get $index))))))
+        (local.set $count (i64.add (local.get $count) (i64.const 1)))
+        (br $next)))
+    (local.get $count))
+
+  ;; list with a text for each character
+  (func $text_chars (param $text i32) (result i32)
+    (local $chars i32)
+    (local $index i32)
+    (local $width i32)
+    (local $char i32)
+    (local.set $chars (call $list_new (i32.const 24)))
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $index) (i32.load (local.get $text))))
+        (local.set $width (call $char_width (i32.load8_u offset=4 (i32.add (local.get $text) (local.get $index)))))
+        (if (i32.gt_u (i32.add (local.get $index) (local.get $width)) (i32.load (local.get $text)))
+          (then (local.set $width (i32.sub (i32.load (local.get $text)) (local.get $index)))))
+        (local.set $char (call $text_alloc (local.get $width)))
+        (memory.copy
+          (i32.add (local.get $char) (i32.const 4))
+          (i32.add (i32.add (local.get $text) (i32.const 4)) (local.get $index))
+          (local.get $width))
+        (call $list_push (local.get $chars) (i64.extend_i32_u (local.get $char)))
+        (local.set $index (i32.add (local.get $index) (local.get $width)))
+        (br $next)))
+    (local.get $chars))
+
+  (func $read_line (result i32)
+    (local $text i32)
+    (local.set $text (call $text_alloc (call $host_read_line)))
+    (call $host_read_bytes (i32.add (local.get $text) (i32.const 4)))
+    (local.get $text))
+
+  ;; ---- lists ----
+
+  (func $list_new (param $item_type i32) (result i32)
+    (local $list i32)
+    (local.set $list (call $alloc (i32.const 16)))
+    (i32.store offset=12 (local.get $list) (local.get $item_type))
+    (local.get $list))
+
+  (func $list_length (param $list i32) (result i64)
+    (i64.extend_i32_u (i32.load (local.get $list))))
+
+  ;; storage of an item that exists
+  (func $list_slot (param $list i32) (param $index i32) (result i32)
+    (i32.load (i32.add (i32.load offset=8 (local.get $list)) (i32.shl (local.get $index) (i32.const 2)))))
+
+  ;; storage of an item at a position given by the program, failing when it does not exist
+  (func $list_get (param $list i32) (param $index i64) (param $line i32) (param $column i32) (result i32)
+    ;; big integers are encoded as negative i64s
+    (if (i32.or
+          (i64.lt_s (local.get $index) (i64.const 0))
+          (i64.ge_s (local.get $index) (call $list_length (local.get $list))))
+      (then
+        (call $host_fail (i32.const 2) (local.get $line) (local.get $column) (call $to_text (local.get $index) (i32.const 8)) (call $list_length (local.get $list)))
+        (unreachable)))
+    (call $list_slot (local.get $list) (i32.wrap_i64 (local.get $index))))
+
+  (func $list_push (param $list i32) (param $item i64)
+    (local $length i32)
+    (local $capacity i32)
+    (local $items i32)
+    (local.set $length (i32.load (local.get $list)))
+    (local.set $capacity (i32.load offset=4 (local.get $list)))
+    (if (i32.eq (local.get $length) (local.get $capacity))
+      (then
+        (local.set $capacity (select (i32.const 4) (i32.shl (local.get $capacity) (i32.const 1)) (i32.eqz (local.get $capacity))))
+        (local.set $items (call $alloc (i32.shl (local.get $capacity) (i32.const 2))))
+        (memory.copy (local.get $items) (i32.load offset=8 (local.get $list)) (i32.shl (local.get $length) (i32.const 2)))
+        (i32.store offset=8 (local.get $list) (local.get $items))
+        (i32.store offset=4 (local.get $list) (local.get $capacity))))
+    (i32.store
+      (i32.add (i32.load offset=8 (local.get $list)) (i32.shl (local.get $length) (i32.const 2)))
+      (call $box_i64 (local.get $item)))
+    (i32.store (local.get $list) (i32.add (local.get $length) (i32.const 1))))
+
+  ;; copy of a list, where lists inside it are also copied
+  (func $list_copy (param $list i32) (result i32)
+    (local $copy i32)
+    (local $index i32)
+    (local $item i64)
+    (local.set $copy (call $list_new (i32.load offset=12 (local.get $list))))
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $index) (i32.load (local.get $list))))
+        (local.set $item (i64.load (call $list_slot (local.get $list) (local.get $index))))
+        (if (i32.eq (i32.load (i32.load offset=12 (local.get $list))) (i32.const 5))
+          (then (local.set $item (i64.extend_i32_u (call $list_copy (i32.wrap_i64 (local.get $item)))))))
+        (call $list_push (local.get $copy) (local.get $item))
+        (local.set $index (i32.add (local.get $index) (i32.const 1)))
+        (br $next)))
+    (local.get $copy))
+
+  (func $list_equals (param $a i32) (param $b i32) (result i32)
+    (local $index i32)
+    (if (i32.ne (i32.load (local.get $a)) (i32.load (local.get $b)))
+      (then (return (i32.const 0))))
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $index) (i32.load (local.get $a))))
+        (if (i32.eqz (call $equals
+              (i64.load (call $list_slot (local.get $a) (local.get $index)))
+              (i64.load (call $list_slot (local.get $b) (local.get $index)))
+              (i32.load offset=12 (local.get $a))))
+          (then (return (i32.const 0))))
+        (local.set $index (i32.add (local.get $index) (i32.const 1)))
+        (br $next)))
+    (i32.const 1))
+
+  ;; ---- values of any type ----
+
+  (func $equals (param $a i64) (param $b i64) (param $type i32) (result i32)
+    (local $kind i32)
+    (local.set $kind (i32.load (local.get $type)))
+    (if (i32.eqz (local.get $kind))
+      (then (return (call $integer_equals (local.get $a) (local.get $b)))))
+    (if (i32.eq (local.get $kind) (i32.const 1))
+      (then (return (call $decimal_equals (i32.wrap_i64 (local.get $a)) (i32.wrap_i64 (local.get $b))))))
+    (if (i32.eq (local.get $kind) (i32.const 2))
+      (then (return (call $text_equals (i32.wrap_i64 (local.get $a)) (i32.wrap_i64 (local.get $b))))))
+    (if (i32.eq (local.get $kind) (i32.const 5))
+      (then (return (call $list_equals (i32.wrap_i64 (local.get $a)) (i32.wrap_i64 (local.get $b))))))
+    (if (i32.ge_u (local.get $kind) (i32.const 6))
+      (then (return (call $equals
+        (i64.load (i32.wrap_i64 (local.get $a)))
+        (i64.load (i32.wrap_i64 (local.get $b)))
+        (i32.load offset=4 (local.get $type))))))
+    (i64.eq (local.get $a) (local.get $b)))
+
+  ;; ---- writing ----
+
+  ;; a builder is {length, capacity, bytes}
+  (func $builder_new (result i32)
+    (call $alloc (i32.const 12)))
+
+  (func $builder_push_bytes (param $builder i32) (param $bytes i32) (param $length i32)
+    (local $needed i32)
+    (local $capacity i32)
+    (local $data i32)
+    (local.set $needed (i32.add (i32.load (local.get $builder)) (local.get $length)))
+    (local.set $capacity (i32.load offset=4 (local.get $builder)))
+    (if (i32.gt_u (local.get $needed) (local.get $capacity))
+      (then
+        (local.set $capacity (select (i32.const 64) (i32.shl (local.get $capacity) (i32.const 1)) (i32.eqz (local.get $capacity))))
+        (block $enough
+          (loop $grow
+            (br_if $enough (i32.ge_u (local.get $capacity) (local.get $needed)))
+            (local.set $capacity (i32.shl (local.get $capacity) (i32.const 1)))
+            (br $grow)))
+        (local.set $data (call $alloc (local.get $capacity)))
+        (memory.copy (local.get $data) (i32.load offset=8 (local.get $builder)) (i32.load (local.get $builder)))
+        (i32.store offset=8 (local.get $builder) (local.get $data))
+        (i32.store offset=4 (local.get $builder) (local.get $capacity))))
+    (memory.copy
+      (i32.add (i32.load offset=8 (local.get $builder)) (i32.load (local.get $builder)))
+      (local.get $bytes)
+      (local.get $length))
+    (i32.store (local.get $builder) (local.get $needed)))
+
+  (func $builder_push_byte (param $builder i32) (param $byte i32)
+    (i32.store8 (i32.const 128) (local.get $byte))
+    (call $builder_push_bytes (local.get $builder) (i32.const 128) (i32.const 1)))
+
+  (func $builder_push_integer (param $builder i32) (param $value i64)
+    (local $end i32)
+    (local $start i32)
+    (local $chunk i64)
+    (local $count i32)
+    (if (call $is_negative (local.get $value))
+      (then
+        (call $builder_push_byte (local.get $builder) (i32.const 45))
+        (local.set $value (call $neg (local.get $value)))))
+    ;; the digits are written backwards, ending at the end of the first 32 bytes of the scratch
+    ;; buffer, or of new memory with 10 bytes for each limb of a big integer
+    (local.set $end (i32.const 160))
+    (if (call $is_big (local.get $value))
+      (then
+        (local.set $end (i32.mul (i32.load (i32.wrap_i64 (local.get $value))) (i32.const 10)))
+        (local.set $end (i32.add (call $alloc (local.get $end)) (local.get $end)))))
+    (local.set $start (local.get $end))
+    ;; each chunk of 9 digits is written whole, except the most significant one
+    (loop $next
+      (local.set $value (call $divide (local.get $value) (i64.const 1000000000)))
+      (local.set $chunk (global.get $remainder))
+      (local.set $count (i32.const 0))
+      (loop $digit
+        (local.set $start (i32.sub (local.get $start) (i32.const 1)))
+        (i32.store8 (local.get $start) (i32.add (i32.const 48) (i32.wrap_i64 (i64.rem_u (local.get $chunk) (i64.const 10)))))
+        (local.set $chunk (i64.div_u (local.get $chunk) (i64.const 10)))
+        (local.set $count (i32.add (local.get $count) (i32.const 1)))
+        (br_if $digit (select
+          (i32.lt_u (local.get $count) (i32.const 9))
+          (i64.ne (local.get $chunk) (i64.const 0))
+          (i64.ne (local.get $value) (i64.const 0)))))
+      (br_if $next (i64.ne (local.get $value) (i64.const 0))))
+    (call $builder_push_bytes (local.get $builder) (local.get $start) (i32.sub (local.get $end) (local.get $start))))
+
+  ;; a decimal like the interpreter, with at least one digit on each side of the point
+  (func $builder_push_decimal (param $builder i32) (param $decimal i32)
+    (local $digits i32)
+    (local $scale i32)
+    (local $length i32)
+    (local $bytes i32)
+    (local.set $digits (call $builder_new))
+    (if (call $is_negative (i64.load (local.get $decimal)))
+      (then
+        (call $builder_push_byte (local.get $builder) (i32.const 45))
+        (call $builder_push_integer (local.get $digits) (call $neg (i64.load (local.get $decimal)))))
+      (else (call $builder_push_integer (local.get $digits) (i64.load (local.get $decimal)))))
+    (local.set $scale (i32.load offset=8 (local.get $decimal)))
+    (local.set $length (i32.load (local.get $digits)))
+    (local.set $bytes (i32.load offset=8 (local.get $digits)))
+    (if (i32.eqz (local.get $scale))
+      (then
+        (call $builder_push_bytes (local.get $builder) (local.get $bytes) (local.get $length))
+        (call $builder_push_byte (local.get $builder) (i32.const 46))
+        (call $builder_push_byte (local.get $builder) (i32.const 48))
+        (return)))
+    (if (i32.le_u (local.get $length) (local.get $scale))
+      (then
+        (call $builder_push_byte (local.get $builder) (i32.const 48))
+        (call $builder_push_byte (local.get $builder) (i32.const 46))
+        (block $done
+          (loop $next
+            (br_if $done (i32.ge_u (local.get $length) (local.get $scale)))
+            (call $builder_push_byte (local.get $builder) (i32.const 48))
+            (local.set $scale (i32.sub (local.get $scale) (i32.const 1)))
+            (br $next)))
+        (call $builder_push_bytes (local.get $builder) (local.get $bytes) (local.get $length))
+        (return)))
+    (call $builder_push_bytes (local.get $builder) (local.get $bytes) (i32.sub (local.get $length) (local.get $scale)))
+    (call $builder_push_byte (local.get $builder) (i32.const 46))
+    (call $builder_push_bytes (local.get $builder)
+      (i32.add (local.get $bytes) (i32.sub (local.get $length) (local.get $scale)))
+      (local.get $scale)))
+
+  ;; a text between quotes, escaped like the interpreter does inside lists
+  (func $builder_push_quoted (param $builder i32) (param $text i32)
+    (local $index i32)
+    (local $byte i32)
+    (call $builder_push_byte (local.get $builder) (i32.const 34))
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $index) (i32.load (local.get $text))))
+        (local.set $byte (i32.load8_u offset=4 (i32.add (local.get $text) (local.get $index))))
+        (block $written
+          (if (i32.or (i32.eq (local.get $byte) (i32.const 34)) (i32.eq (local.get $byte) (i32.const 92)))
+            (then
+              (call $builder_push_byte (local.get $builder) (i32.const 92))
+              (call $builder_push_byte (local.get $builder) (local.get $byte))
+              (br $written)))
+          (if (i32.eq (local.get $byte) (i32.const 10))
+            (then
+              (call $builder_push_byte (local.get $builder) (i32.const 92))
+              (call $builder_push_byte (local.get $builder) (i32.const 110))
+              (br $written)))
+          (if (i32.eq (local.get $byte) (i32.const 9))
+            (then
+              (call $builder_push_byte (local.get $builder) (i32.const 92))
+              (call $builder_push_byte (local.get $builder) (i32.const 116))
+              (br $written)))
+          (if (i32.eq (local.get $byte) (i32.const 13))
+            (then
+              (call $builder_push_byte (local.get $builder) (i32.const 92))
+              (call $builder_push_byte (local.get $builder) (i32.const 114))
+              (br $written)))
+          (call $builder_push_byte (local.get $builder) (local.get $byte)))
+        (local.set $index (i32.add (local.get $index) (i32.const 1)))
+        (br $next)))
+    (call $builder_push_byte (local.get $builder) (i32.const 34)))
+
+  (func $write_value (param $builder i32) (param $value i64) (param $type i32) (param $quoted i32)
+    (local $kind i32)
+    (local $list i32)
+    (local $index i32)
+    (local.set $kind (i32.load (local.get $type)))
+    (if (i32.eqz (local.get $kind))
+      (then (call $builder_push_integer (local.get $builder) (local.get $value)) (return)))
+    (if (i32.eq (local.get $kind) (i32.const 1))
+      (then (call $builder_push_decimal (local.get $builder) (i32.wrap_i64 (local.get $value))) (return)))
+    (if (i32.eq (local.get $kind) (i32.const 2))
+      (then
+        (if (local.get $quoted)
+          (then (call $builder_push_quoted (local.get $builder) (i32.wrap_i64 (local.get $value))))
+          (else (call $builder_push_bytes (local.get $builder)
+            (i32.add (i32.wrap_i64 (local.get $value)) (i32.const 4))
+            (i32.load (i32.wrap_i64 (local.get $value))))))
+        (return)))
+    (if (i32.eq (local.get $kind) (i32.const 3))
+      (then
+        (if (i64.eqz (local.get $value))
+          (then (call $builder_push_bytes (local.get $builder) (i32.const 74) (i32.const 5)))
+          (else (call $builder_push_bytes (local.get $builder) (i32.const 64) (i32.const 10))))
+        (return)))
+    (if (i32.eq (local.get $kind) (i32.const 4))
+      (then (call $builder_push_bytes (local.get $builder) (i32.const 79) (i32.const 4)) (return)))
+    (if (i32.eq (local.get $kind) (i32.const 5))
+      (then
+        (local.set $list (i32.wrap_i64 (local.get $value)))
+        (call $builder_push_byte (local.get $builder) (i32.const 91))
+        (block $done
+          (loop $next
+            (br_if $done (i32.ge_u (local.get $index) (i32.load (local.get $list))))
+            (if (local.get $index)
+              (then (call $builder_push_bytes (local.get $builder) (i32.const 83) (i32.const 2))))
+            (call $write_value (local.get $builder)
+              (i64.load (call $list_slot (local.get $list) (local.get $index)))
+              (i32.load offset=12 (local.get $list))
+              (i32.const 1))
+            (local.set $index (i32.add (local.get $index) (i32.const 1)))
+            (br $next)))
+        (call $builder_push_byte (local.get $builder) (i32.const 93))
+        (return)))
+    ;; `ref` and `comp` are written as the value they point to
+    (call $write_value (local.get $builder)
+      (i64.load (i32.wrap_i64 (local.get $value)))
+      (i32.load offset=4 (local.get $type))
+      (local.get $quoted)))
+
+  (func $write (param $value i64) (param $type i32)
+    (local $builder i32)
+    (local.set $builder (call $builder_new))
+    (call $write_value (local.get $builder) (local.get $value) (local.get $type) (i32.const 0))
+    (call $builder_push_byte (local.get $builder) (i32.const 10))
+    (call $host_write (i32.load offset=8 (local.get $builder)) (i32.load (local.get $builder))))
+
+  (func $to_text (param $value i64) (param $type i32) (result i32)
+    (local $builder i32)
+    (local $text i32)
+    (local.set $builder (call $builder_new))
+    (call $write_value (local.get $builder) (local.get $value) (local.get $type) (i32.const 0))
+    (local.set $text (call $text_alloc (i32.load (local.get $builder))))
+    (memory.copy (i32.add (local.get $text) (i32.const 4)) (i32.load offset=8 (local.get $builder)) (i32.load (local.get $builder)))
+    (local.get $text))
@@ -0,0 +1,54 @@
+
+// local
+use crate::ast::Ast;
+use crate::builtin_types::TypeCatalogue;
+use crate::diagnostic::Diagnostic;
+use crate::flow_checker::check_control_flow;
+use crate::init_checker::check_initialization;
+use crate::mutability_checker::check_mutability;
+use crate::ownership_checker::check_ownership;
+use crate::purity_checker::check_purity;
+use crate::resolver::{resolve_names, Resolution};
+use crate::type_checker::{check_types, TypeTable};
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  analysis                                  */
+/* -------------------------------------------------------------------------- */
+
+/// Options that change what the semantic passes accept.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AnalysisSettings {
+    /// Accepts the English names of the builtin types, like `int` for `inteiro`.
+    pub english_type_aliases: bool,
+}
+
+/// Everything the semantic passes know about a program.
+pub struct Analysis {
+    pub resolution: Resolution,
+    pub types: TypeTable,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Analysis {
+
+    pub fn has_errors(&self) -> bool {
+        return self.diagnostics.iter().any(|diagnostic| diagnostic.is_error());
+    }
+}
+
+/// Runs every semantic pass over a program without syntax errors.
+pub fn analyze(ast: &Ast, settings: &AnalysisSettings) -> Analysis {
+
+    let mut diagnostics = Vec::new();
+    let resolution = resolve_names(ast, &mut diagnostics);
+    check_initialization(ast, &resolution, &mut diagnostics);
+    let catalogue = TypeCatalogue::new(settings.english_type_aliases);
+    let types = check_types(ast, &resolution, &catalogue, &mut diagnostics);
+    check_ownership(ast, &resolution, &types, &mut diagnostics);
+    check_mutability(ast, &resolution, &types, &mut diagnostics);
+    check_purity(ast, &resolution, &types, &mut diagnostics);
+    check_control_flow(ast, &types, &mut diagnostics);
+
+    return Analysis{resolution, types, diagnostics};
+}
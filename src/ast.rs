@@ -1,30 +1,57 @@
 
 use std::collections::HashMap;
 
+use crate::numbers::{Decimal, Integer};
 
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub struct NodeID { id: usize }
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub struct ScopeDefID(NodeID);
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub struct StmtID(NodeID);
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub struct ExprID(NodeID);
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub struct TypeExprID(NodeID);
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub struct IdentifierID(NodeID);
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub struct FunctionParamID(NodeID);
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub struct ForEachDeclID(NodeID);
 
+impl From<ScopeDefID> for NodeID {
+    fn from(id: ScopeDefID) -> Self { id.0 }
+}
+impl From<StmtID> for NodeID {
+    fn from(id: StmtID) -> Self { id.0 }
+}
+impl From<ExprID> for NodeID {
+    fn from(id: ExprID) -> Self { id.0 }
+}
+impl From<TypeExprID> for NodeID {
+    fn from(id: TypeExprID) -> Self { id.0 }
+}
+impl From<IdentifierID> for NodeID {
+    fn from(id: IdentifierID) -> Self { id.0 }
+}
+impl From<FunctionParamID> for NodeID {
+    fn from(id: FunctionParamID) -> Self { id.0 }
+}
+impl From<ForEachDeclID> for NodeID {
+    fn from(id: ForEachDeclID) -> Self { id.0 }
+}
+
 
 pub struct Ast {
     
     node_id_counter: usize,
     node_lexical_info: HashMap<NodeID, LexicalInfo>,
     node_value: HashMap<NodeID, Node>,
+    node_comments: HashMap<NodeID, Vec<Comment>>,
+    /// Comments of a source without any definition to attach them to.
+    unattached_comments: Vec<Comment>,
     program: Option<Program>,
 }
 
@@ -35,6 +62,8 @@ impl Ast {
             node_id_counter: 0,
             node_lexical_info: HashMap::new(),
             node_value: HashMap::new(),
+            node_comments: HashMap::new(),
+            unattached_comments: Vec::new(),
             program: None,
         }
     }
@@ -92,6 +121,15 @@ impl Ast {
     }
 
 
+    /// Keeps a comment with a statement or a definition, in the order they are added.
+    pub fn add_comment(&mut self, id: Option<NodeID>, comment: Comment) {
+
+        match id {
+            Some(id) => self.node_comments.entry(id).or_default().push(comment),
+            None => self.unattached_comments.push(comment),
+        }
+    }
+
     pub fn set_program(&mut self, program: Program) {
         self.program = Some(program);
     }
@@ -101,10 +139,54 @@ impl Ast {
     /*                         ast manipulation functions                         */
     /* -------------------------------------------------------------------------- */
 
+    pub fn get_program(&self) -> &Program {
+        return self.program.as_ref().expect("the ast does not have a program");
+    }
+
+    /// Functions and procedures of the program, in the order they are defined.
+    pub fn get_subprograms(&self) -> Vec<ScopeDefID> {
+
+        return self.get_program().global_defs.iter()
+            .copied()
+            .filter(|id| self.get_scope_def(*id).is_subprogram())
+            .collect();
+    }
+
+    /// Structures of the program, in the order they are defined.
+    pub fn get_structs(&self) -> Vec<ScopeDefID> {
+
+        return self.get_program().global_defs.iter()
+            .copied()
+            .filter(|id| self.get_scope_def(*id).is_struct())
+            .collect();
+    }
+
+    /// Enumerations of the program, in the order they are defined.
+    pub fn get_enums(&self) -> Vec<ScopeDefID> {
+
+        return self.get_program().global_defs.iter()
+            .copied()
+            .filter(|id| self.get_scope_def(*id).is_enum())
+            .collect();
+    }
+
     pub fn get_node(&self, id: NodeID) -> &Node {
         return self.node_value.get(&id).expect("NodeID does not point to a node");
     }
 
+    pub fn get_lexical_info(&self, id: impl Into<NodeID>) -> &LexicalInfo {
+        return self.node_lexical_info.get(&id.into()).expect("NodeID does not point to a node");
+    }
+
+    /// Comments attached to a statement or a definition, in the order they appear in the source.
+    pub fn get_comments(&self, id: impl Into<NodeID>) -> &[Comment] {
+        return self.node_comments.get(&id.into()).map(Vec::as_slice).unwrap_or(&[]);
+    }
+
+    pub fn get_unattached_comments(&self) -> &[Comment] {
+        return &self.unattached_comments;
+    }
+
     pub fn get_scope_def(&self, id: ScopeDefID) -> &ScopeDef {
         return match self.get_node(id.0) {
             Node::ScopeDef(scope_def) => scope_def,
@@ -157,17 +239,49 @@ impl Ast {
 
 
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LexicalPosition {
     pub line: usize,
     pub column: usize,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct LexicalInfo {
     pub start: LexicalPosition,
     pub end: LexicalPosition,
 }
 
 
+/// A comment of the source. Comments do not change the program, they are kept for tools that
+/// write the source back, like a formatter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comment {
+    /// Text between the markers, `#` or `#{` and `}#`.
+    pub text: String,
+    pub kind: CommentKind,
+    pub placement: CommentPlacement,
+    pub lexical_info: LexicalInfo,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommentKind {
+    /// `# ...` until the end of the line.
+    Line,
+    /// `#{ ... }#`, possibly over several lines.
+    Block,
+}
+
+/// Where a comment is in relation to the node it is attached to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommentPlacement {
+    /// Before the node.
+    Before,
+    /// After the node: on the line where it ends, or after the last node of a block.
+    After,
+    /// Inside a node without statements to attach it to, like an empty subprogram.
+    Inside,
+}
+
 pub struct Program {
     pub global_defs: Vec<ScopeDefID>,
 }
@@ -184,33 +298,164 @@ pub enum Node {
 
 pub enum ScopeDef {
     Function{name: IdentifierID, params: Vec<FunctionParamID>, return_type: TypeExprID, body: Vec<StmtID>},
+    Procedure{name: IdentifierID, params: Vec<FunctionParamID>, return_type: Option<TypeExprID>, body: Vec<StmtID>},
+    Struct{name: IdentifierID, fields: Vec<StructField>},
+    Enum{name: IdentifierID, variants: Vec<EnumVariant>},
+}
+
+impl ScopeDef {
+
+    pub fn name(&self) -> IdentifierID {
+
+        return match self {
+            ScopeDef::Function{name, ..} | ScopeDef::Procedure{name, ..} | ScopeDef::Struct{name, ..} | ScopeDef::Enum{name, ..} => *name,
+        };
+    }
+
+    pub fn params(&self) -> &[FunctionParamID] {
+
+        return match self {
+            ScopeDef::Function{params, ..} | ScopeDef::Procedure{params, ..} => params,
+            ScopeDef::Struct{..} | ScopeDef::Enum{..} => &[],
+        };
+    }
+
+    /// Declared return type, procedures without a return value have none.
+    pub fn return_type(&self) -> Option<TypeExprID> {
+
+        return match self {
+            ScopeDef::Function{return_type, ..} => Some(*return_type),
+            ScopeDef::Procedure{return_type, ..} => *return_type,
+            ScopeDef::Struct{..} | ScopeDef::Enum{..} => None,
+        };
+    }
+
+    pub fn body(&self) -> &[StmtID] {
+
+        return match self {
+            ScopeDef::Function{body, ..} | ScopeDef::Procedure{body, ..} => body,
+            ScopeDef::Struct{..} | ScopeDef::Enum{..} => &[],
+        };
+    }
+
+    pub fn fields(&self) -> &[StructField] {
+
+        return match self {
+            ScopeDef::Struct{fields, ..} => fields,
+            ScopeDef::Function{..} | ScopeDef::Procedure{..} | ScopeDef::Enum{..} => &[],
+        };
+    }
+
+    pub fn variants(&self) -> &[EnumVariant] {
+
+        return match self {
+            ScopeDef::Enum{variants, ..} => variants,
+            ScopeDef::Function{..} | ScopeDef::Procedure{..} | ScopeDef::Struct{..} => &[],
+        };
+    }
+
+    pub fn is_procedure(&self) -> bool {
+        return matches!(self, ScopeDef::Procedure{..});
+    }
+
+    pub fn is_struct(&self) -> bool {
+        return matches!(self, ScopeDef::Struct{..});
+    }
+
+    pub fn is_enum(&self) -> bool {
+        return matches!(self, ScopeDef::Enum{..});
+    }
+
+    /// Whether the definition is a function or a procedure.
+    pub fn is_subprogram(&self) -> bool {
+        return matches!(self, ScopeDef::Function{..} | ScopeDef::Procedure{..});
+    }
+}
+
+pub struct StructField {
+    pub name: IdentifierID,
+    pub type_expr: TypeExprID,
+}
+
+/// A case of an enumeration, with the types of the values it keeps, if any.
+pub struct EnumVariant {
+    pub name: IdentifierID,
+    pub payload: Vec<TypeExprID>,
 }
 
 pub enum Statement {
     Expression{expr: ExprID},
     VarDecl{is_mut: bool, name: IdentifierID, type_expr: TypeExprID, init_expr: Option<ExprID>},
-    If{cond_expr: ExprID, then_block: Vec<StmtID>, else_body: Option<Vec<StmtID>>},
+    /// `se a { ... } senão se b { ... } senão { ... }`: the branches are tried in order, and the
+    /// `senão` body runs when no condition holds. There is always at least one branch.
+    If{branches: Vec<IfBranch>, else_body: Option<Vec<StmtID>>},
     While{cond_expr: ExprID, body_block: Vec<StmtID>},
     ForEach{item: ForEachDeclID, iter_expr: ExprID, body_block: Vec<StmtID>},
+    /// `escolha valor { caso A => { ... } }`, the cases in the order they are written.
+    Match{value: ExprID, cases: Vec<MatchCase>},
     Return{expr: Option<ExprID>},
     Continue,
     Break,
+    Error,
+}
+
+/// A condition of a `se` or `senão se`, and the body that runs when it holds.
+pub struct IfBranch {
+    pub cond_expr: ExprID,
+    pub body: Vec<StmtID>,
+}
+
+/// `caso B(x, y) => { ... }`: runs the body when the value is the variant `B`, with its values
+/// in new variables `x` and `y`.
+pub struct MatchCase {
+    pub variant: IdentifierID,
+    pub bindings: Vec<IdentifierID>,
+    pub body: Vec<StmtID>,
 }
 
 pub enum Expression {
-    IntegerLiteral{value: i64}, // TODO: usar tipo sem limite de precisão
-    DecimalLiteral{value: f64}, // TODO: usar tipo sem limite de precisão
+    IntegerLiteral{value: Integer},
+    DecimalLiteral{value: Decimal},
     StringLiteral{value: String},
+    /// A string with values inside `{}`, like `"Olá, {nome}!"`.
+    InterpolatedString{parts: Vec<StringPart>},
     BooleanLiteral{value: bool},
     ListLiteral{values: Vec<ExprID>},
     Identifier{node_id: IdentifierID},
     Call{callee: ExprID, args: Vec<ExprID>},
+    /// `Ponto { x: 1.0, y: 2.0 }`, the fields in the order they are written.
+    StructLiteral{type_name: IdentifierID, fields: Vec<FieldInit>},
     Access{object: ExprID, field_name: IdentifierID},
     BinaryOprt{oprt: BinaryOprt, left: ExprID, right: ExprID},
     UnaryOprt{oprt: UnaryOprt, operand: ExprID},
     Assign{target: ExprID, value: ExprID},
+    Error,
+}
+
+pub enum StringPart {
+    /// Text with its escape sequences already replaced by the characters they stand for.
+    Text(String),
+    /// A value converted to text like `para_texto` does.
+    Value(ExprID),
+}
+
+impl StringPart {
+
+    pub fn value(&self) -> Option<ExprID> {
+
+        return match self {
+            StringPart::Text(_) => None,
+            StringPart::Value(value) => Some(*value),
+        };
+    }
 }
 
+pub struct FieldInit {
+    pub name: IdentifierID,
+    pub value: ExprID,
+}
+
+#[allow(clippy::enum_variant_names)]
 pub enum TypeExpr {
 
     RawType{type_name: IdentifierID},
@@ -219,12 +464,14 @@ pub enum TypeExpr {
     CompType{is_mut: bool, type_expr: TypeExprID},
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum UnaryOprt {
     Neg,
     Not,
     Deref
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum BinaryOprt {
     Assign,
     And, Or,
@@ -233,6 +480,42 @@ pub enum BinaryOprt {
     Add, Sub, Mul, Div
 }
 
+impl UnaryOprt {
+
+    /// The operator as it is written in the source.
+    pub fn symbol(&self) -> &'static str {
+
+        return match self {
+            UnaryOprt::Neg => "-",
+            UnaryOprt::Not => "não",
+            UnaryOprt::Deref => "*",
+        };
+    }
+}
+
+impl BinaryOprt {
+
+    /// The operator as it is written in the source.
+    pub fn symbol(&self) -> &'static str {
+
+        return match self {
+            BinaryOprt::Assign => "=",
+            BinaryOprt::And => "e",
+            BinaryOprt::Or => "ou",
+            BinaryOprt::Eq => "==",
+            BinaryOprt::Neq => "!=",
+            BinaryOprt::Lt => "<",
+            BinaryOprt::Le => "<=",
+            BinaryOprt::Gt => ">",
+            BinaryOprt::Ge => ">=",
+            BinaryOprt::Add => "+",
+            BinaryOprt::Sub => "-",
+            BinaryOprt::Mul => "*",
+            BinaryOprt::Div => "/",
+        };
+    }
+}
+
 
 pub struct FunctionParam {
     pub is_mut: bool,
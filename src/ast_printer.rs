@@ -0,0 +1,571 @@
+
+// local
+use crate::ast::*;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   printer                                  */
+/* -------------------------------------------------------------------------- */
+
+struct AstPrinter<'a> {
+    ast: &'a Ast,
+    output: String,
+    indent: usize,
+}
+
+impl<'a> AstPrinter<'a> {
+
+    fn new(ast: &'a Ast) -> Self {
+        return AstPrinter{ast, output: String::new(), indent: 0};
+    }
+
+    fn line(&mut self, text: &str) {
+
+        for _ in 0..self.indent {
+            self.output.push_str("  ");
+        }
+
+        self.output.push_str(text);
+        self.output.push('\n');
+    }
+
+    fn node_line(&mut self, text: &str, id: impl Into<NodeID>) {
+
+        let lexical_info = self.ast.get_lexical_info(id);
+        let text = format!("{} [{}]", text, format_lexical_info(lexical_info));
+        self.line(&text);
+    }
+
+    fn indented(&mut self, f: impl FnOnce(&mut Self)) {
+
+        self.indent += 1;
+        f(self);
+        self.indent -= 1;
+    }
+
+    fn identifier(&self, id: IdentifierID) -> &'a str {
+        return self.ast.get_identifier(id);
+    }
+
+    fn print_comments(&mut self, comments: &[Comment], placement: CommentPlacement) {
+
+        for comment in comments.iter().filter(|comment| comment.placement == placement) {
+            let kind = match comment.kind {
+                CommentKind::Line => "LineComment",
+                CommentKind::Block => "BlockComment",
+            };
+            let text = format!("{} {:?} {:?} [{}]", kind, placement, comment.text, format_lexical_info(&comment.lexical_info));
+            self.line(&text);
+        }
+    }
+
+    /// Prints a statement or a definition between the comments attached to it.
+    fn commented(&mut self, id: impl Into<NodeID>, f: impl FnOnce(&mut Self)) {
+
+        let comments = self.ast.get_comments(id);
+        self.print_comments(comments, CommentPlacement::Before);
+        f(self);
+        self.indented(|p| p.print_comments(comments, CommentPlacement::Inside));
+        self.print_comments(comments, CommentPlacement::After);
+    }
+
+    fn print_block(&mut self, label: &str, block: &[StmtID]) {
+
+        self.line(&format!("{}:", label));
+        self.indented(|p| {
+
+            for stmt in block {
+                p.commented(*stmt, |p| p.print_stmt(*stmt));
+            }
+        });
+    }
+
+    fn print_type_expr(&mut self, label: &str, id: TypeExprID) {
+
+        match self.ast.get_type_expr(id) {
+            TypeExpr::RawType{type_name} => {
+                let text = format!("{}RawType {}", label, self.identifier(*type_name));
+                self.node_line(&text, id);
+            },
+            TypeExpr::TemplateType{type_name, type_args} => {
+                let text = format!("{}TemplateType {}", label, self.identifier(*type_name));
+                self.node_line(&text, id);
+                self.indented(|p| {
+
+                    for arg in type_args {
+                        p.print_type_expr("arg: ", *arg);
+                    }
+                });
+            },
+            TypeExpr::RefType{is_mut, type_expr} => {
+                let text = format!("{}RefType{}", label, format_mut(*is_mut));
+                self.node_line(&text, id);
+                self.indented(|p| p.print_type_expr("", *type_expr));
+            },
+            TypeExpr::CompType{is_mut, type_expr} => {
+                let text = format!("{}CompType{}", label, format_mut(*is_mut));
+                self.node_line(&text, id);
+                self.indented(|p| p.print_type_expr("", *type_expr));
+            },
+        }
+    }
+
+    fn print_expr(&mut self, label: &str, id: ExprID) {
+
+        match self.ast.get_expression(id) {
+            Expression::IntegerLiteral{value} => {
+                self.node_line(&format!("{}IntegerLiteral {}", label, value), id);
+            },
+            Expression::DecimalLiteral{value} => {
+                self.node_line(&format!("{}DecimalLiteral {}", label, value), id);
+            },
+            Expression::StringLiteral{value} => {
+                self.node_line(&format!("{}StringLiteral {:?}", label, value), id);
+            },
+            Expression::InterpolatedString{parts} => {
+                self.node_line(&format!("{}InterpolatedString", label), id);
+                self.indented(|p| {
+
+                    for part in parts {
+                        match part {
+                            StringPart::Text(text) => p.line(&format!("text: {:?}", text)),
+                            StringPart::Value(value) => p.print_expr("value: ", *value),
+                        }
+                    }
+                });
+            },
+            Expression::BooleanLiteral{value} => {
+                self.node_line(&format!("{}BooleanLiteral {}", label, value), id);
+            },
+            Expression::ListLiteral{values} => {
+                self.node_line(&format!("{}ListLiteral", label), id);
+                self.indented(|p| {
+
+                    for value in values {
+                        p.print_expr("", *value);
+                    }
+                });
+            },
+            Expression::Identifier{node_id} => {
+                let text = format!("{}Identifier {}", label, self.identifier(*node_id));
+                self.node_line(&text, id);
+            },
+            Expression::Call{callee, args} => {
+                self.node_line(&format!("{}Call", label), id);
+                self.indented(|p| {
+
+                    p.print_expr("callee: ", *callee);
+                    for arg in args {
+                        p.print_expr("arg: ", *arg);
+                    }
+                });
+            },
+            Expression::StructLiteral{type_name, fields} => {
+                let text = format!("{}StructLiteral {}", label, self.identifier(*type_name));
+                self.node_line(&text, id);
+                self.indented(|p| {
+
+                    for field in fields {
+                        let text = format!("field: {}", p.identifier(field.name));
+                        p.node_line(&text, field.name);
+                        p.indented(|p| p.print_expr("value: ", field.value));
+                    }
+                });
+            },
+            Expression::Access{object, field_name} => {
+                let text = format!("{}Access .{}", label, self.identifier(*field_name));
+                self.node_line(&text, id);
+                self.indented(|p| p.print_expr("object: ", *object));
+            },
+            Expression::BinaryOprt{oprt, left, right} => {
+                self.node_line(&format!("{}BinaryOprt {:?}", label, oprt), id);
+                self.indented(|p| {
+
+                    p.print_expr("left: ", *left);
+                    p.print_expr("right: ", *right);
+                });
+            },
+            Expression::UnaryOprt{oprt, operand} => {
+                self.node_line(&format!("{}UnaryOprt {:?}", label, oprt), id);
+                self.indented(|p| p.print_expr("operand: ", *operand));
+            },
+            Expression::Assign{target, value} => {
+                self.node_line(&format!("{}Assign", label), id);
+                self.indented(|p| {
+
+                    p.print_expr("target: ", *target);
+                    p.print_expr("value: ", *value);
+                });
+            },
+            Expression::Error => {
+                self.node_line(&format!("{}Error", label), id);
+            },
+        }
+    }
+
+    fn print_stmt(&mut self, id: StmtID) {
+
+        match self.ast.get_statement(id) {
+            Statement::Expression{expr} => {
+                self.node_line("Expression", id);
+                self.indented(|p| p.print_expr("", *expr));
+            },
+            Statement::VarDecl{is_mut, name, type_expr, init_expr} => {
+                let text = format!("VarDecl{} {}", format_mut(*is_mut), self.identifier(*name));
+                self.node_line(&text, id);
+                self.indented(|p| {
+
+                    p.print_type_expr("type: ", *type_expr);
+                    if let Some(init_expr) = init_expr {
+                        p.print_expr("init: ", *init_expr);
+                    }
+                });
+            },
+            Statement::If{branches, else_body} => {
+                self.node_line("If", id);
+                self.indented(|p| {
+
+                    for (index, branch) in branches.iter().enumerate() {
+                        let label = match index {
+                            0 => "cond: ",
+                            _ => "else if: ",
+                        };
+                        p.print_expr(label, branch.cond_expr);
+                        p.print_block("then", &branch.body);
+                    }
+                    if let Some(else_body) = else_body {
+                        p.print_block("else", else_body);
+                    }
+                });
+            },
+            Statement::While{cond_expr, body_block} => {
+                self.node_line("While", id);
+                self.indented(|p| {
+
+                    p.print_expr("cond: ", *cond_expr);
+                    p.print_block("body", body_block);
+                });
+            },
+            Statement::ForEach{item, iter_expr, body_block} => {
+                self.node_line("ForEach", id);
+                self.indented(|p| {
+
+                    let for_each_decl = p.ast.get_for_each_decl(*item);
+                    let text = format!("item:{}{} {}",
+                        format_mut(for_each_decl.is_mut),
+                        if for_each_decl.is_ref { " ref" } else { "" },
+                        p.identifier(for_each_decl.name));
+                    p.node_line(&text, *item);
+                    p.print_expr("iter: ", *iter_expr);
+                    p.print_block("body", body_block);
+                });
+            },
+            Statement::Match{value, cases} => {
+                self.node_line("Match", id);
+                self.indented(|p| {
+
+                    p.print_expr("value: ", *value);
+                    for case in cases {
+                        let text = format!("case: {}", p.identifier(case.variant));
+                        p.node_line(&text, case.variant);
+                        p.indented(|p| {
+
+                            for binding in &case.bindings {
+                                let text = format!("binding: {}", p.identifier(*binding));
+                                p.node_line(&text, *binding);
+                            }
+                            p.print_block("body", &case.body);
+                        });
+                    }
+                });
+            },
+            Statement::Return{expr} => {
+                self.node_line("Return", id);
+                if let Some(expr) = expr {
+                    self.indented(|p| p.print_expr("", *expr));
+                }
+            },
+            Statement::Continue => {
+                self.node_line("Continue", id);
+            },
+            Statement::Break => {
+                self.node_line("Break", id);
+            },
+            Statement::Error => {
+                self.node_line("Error", id);
+            },
+        }
+    }
+
+    fn print_scope_def(&mut self, id: ScopeDefID) {
+
+        let scope_def = self.ast.get_scope_def(id);
+        if scope_def.is_struct() {
+            return self.print_struct(id);
+        }
+        if scope_def.is_enum() {
+            return self.print_enum(id);
+        }
+
+        let kind = if scope_def.is_procedure() { "Procedure" } else { "Function" };
+        let text = format!("{} {}", kind, self.identifier(scope_def.name()));
+        self.node_line(&text, id);
+        self.indented(|p| {
+
+            for param in scope_def.params() {
+                let function_param = p.ast.get_function_param(*param);
+                let text = format!("param:{} {}",
+                    format_mut(function_param.is_mut),
+                    p.identifier(function_param.name));
+                p.node_line(&text, *param);
+                p.indented(|p| p.print_type_expr("type: ", function_param.type_expr));
+            }
+
+            if let Some(return_type) = scope_def.return_type() {
+                p.print_type_expr("return_type: ", return_type);
+            }
+            p.print_block("body", scope_def.body());
+        });
+    }
+
+    fn print_struct(&mut self, id: ScopeDefID) {
+
+        let scope_def = self.ast.get_scope_def(id);
+        let text = format!("Struct {}", self.identifier(scope_def.name()));
+        self.node_line(&text, id);
+        self.indented(|p| {
+
+            for field in scope_def.fields() {
+                let text = format!("field: {}", p.identifier(field.name));
+                p.node_line(&text, field.name);
+                p.indented(|p| p.print_type_expr("type: ", field.type_expr));
+            }
+        });
+    }
+
+    fn print_enum(&mut self, id: ScopeDefID) {
+
+        let scope_def = self.ast.get_scope_def(id);
+        let text = format!("Enum {}", self.identifier(scope_def.name()));
+        self.node_line(&text, id);
+        self.indented(|p| {
+
+            for variant in scope_def.variants() {
+                let text = format!("variant: {}", p.identifier(variant.name));
+                p.node_line(&text, variant.name);
+                p.indented(|p| {
+
+                    for type_expr in &variant.payload {
+                        p.print_type_expr("payload: ", *type_expr);
+                    }
+                });
+            }
+        });
+    }
+
+    fn print_program(&mut self) {
+
+        self.line("Program");
+        self.indented(|p| {
+
+            for scope_def in &p.ast.get_program().global_defs {
+                p.commented(*scope_def, |p| p.print_scope_def(*scope_def));
+            }
+            p.print_comments(p.ast.get_unattached_comments(), CommentPlacement::Inside);
+        });
+    }
+}
+
+fn format_mut(is_mut: bool) -> &'static str {
+    return if is_mut { " mut" } else { "" };
+}
+
+/// Formats a `LexicalInfo` as `line:column-line:column`, counting from 1 like text editors do.
+pub fn format_lexical_info(lexical_info: &LexicalInfo) -> String {
+
+    return format!("{}:{}-{}:{}",
+        lexical_info.start.line + 1, lexical_info.start.column + 1,
+        lexical_info.end.line + 1, lexical_info.end.column + 1);
+}
+
+/// Renders the whole program as an indented tree, one node per line, with its source span.
+pub fn print_ast(ast: &Ast) -> String {
+
+    let mut printer = AstPrinter::new(ast);
+    printer.print_program();
+    return printer.output;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::tso_parser::parse_source;
+
+
+    fn print_source(source_code: &str) -> String {
+
+        let output = parse_source(source_code).unwrap();
+        assert!(!output.has_errors());
+        return print_ast(&output.ast);
+    }
+
+    #[test]
+    fn test_empty_program() {
+
+        assert_eq!(print_source(""), "Program\n");
+    }
+
+    #[test]
+    fn test_function() {
+
+        let source_code = "func foo(mut a: int) -> int {\n    retornar a + 1;\n}";
+        let expected = [
+            "Program",
+            "  Function foo [1:1-3:2]",
+            "    param: mut a [1:10-1:20]",
+            "      type: RawType int [1:17-1:20]",
+            "    return_type: RawType int [1:25-1:28]",
+            "    body:",
+            "      Return [2:5-2:20]",
+            "        BinaryOprt Add [2:14-2:19]",
+            "          left: Identifier a [2:14-2:15]",
+            "          right: IntegerLiteral 1 [2:18-2:19]",
+            "",
+        ].join("\n");
+
+        assert_eq!(print_source(source_code), expected);
+    }
+
+    #[test]
+    fn test_else_if() {
+
+        let source_code = "func foo(a: int) -> int {\n    se a { 1; } senão se b { 2; } senão { 3; }\n}";
+        let expected = [
+            "Program",
+            "  Function foo [1:1-3:2]",
+            "    param: a [1:10-1:16]",
+            "      type: RawType int [1:13-1:16]",
+            "    return_type: RawType int [1:21-1:24]",
+            "    body:",
+            "      If [2:5-2:49]",
+            "        cond: Identifier a [2:8-2:9]",
+            "        then:",
+            "          Expression [2:12-2:14]",
+            "            IntegerLiteral 1 [2:12-2:13]",
+            "        else if: Identifier b [2:27-2:28]",
+            "        then:",
+            "          Expression [2:31-2:33]",
+            "            IntegerLiteral 2 [2:31-2:32]",
+            "        else:",
+            "          Expression [2:45-2:47]",
+            "            IntegerLiteral 3 [2:45-2:46]",
+            "",
+        ].join("\n");
+
+        assert_eq!(print_source(source_code), expected);
+    }
+
+    #[test]
+    fn test_struct() {
+
+        let source_code = "estrutura Ponto { x: decimal, y: ref decimal }\nfunc origem() -> Ponto {\n    retornar Ponto { y: 0.0, x: 0.0 };\n}";
+        let expected = [
+            "Program",
+            "  Struct Ponto [1:1-1:47]",
+            "    field: x [1:19-1:20]",
+            "      type: RawType decimal [1:22-1:29]",
+            "    field: y [1:31-1:32]",
+            "      type: RefType [1:34-1:45]",
+            "        RawType decimal [1:38-1:45]",
+            "  Function origem [2:1-4:2]",
+            "    return_type: RawType Ponto [2:18-2:23]",
+            "    body:",
+            "      Return [3:5-3:39]",
+            "        StructLiteral Ponto [3:14-3:38]",
+            "          field: y [3:22-3:23]",
+            "            value: DecimalLiteral 0.0 [3:25-3:28]",
+            "          field: x [3:30-3:31]",
+            "            value: DecimalLiteral 0.0 [3:33-3:36]",
+            "",
+        ].join("\n");
+
+        assert_eq!(print_source(source_code), expected);
+    }
+
+    #[test]
+    fn test_enum() {
+
+        let source_code = "enumeração Forma { Círculo(decimal), Vazio }\nprocedimento foo(f: Forma) {\n    escolha f {\n        caso Círculo(r) => { r; }\n        caso Vazio => {}\n    }\n}";
+        let expected = [
+            "Program",
+            "  Enum Forma [1:1-1:48]",
+            "    variant: Círculo [1:22-1:30]",
+            "      payload: RawType decimal [1:31-1:38]",
+            "    variant: Vazio [1:41-1:46]",
+            "  Procedure foo [2:1-7:2]",
+            "    param: f [2:18-2:26]",
+            "      type: RawType Forma [2:21-2:26]",
+            "    body:",
+            "      Match [3:5-6:6]",
+            "        value: Identifier f [3:13-3:14]",
+            "        case: Círculo [4:14-4:22]",
+            "          binding: r [4:23-4:24]",
+            "          body:",
+            "            Expression [4:31-4:33]",
+            "              Identifier r [4:31-4:32]",
+            "        case: Vazio [5:14-5:19]",
+            "          body:",
+            "",
+        ].join("\n");
+
+        assert_eq!(print_source(source_code), expected);
+    }
+
+    #[test]
+    fn test_comments() {
+
+        let source_code = [
+            "# soma um",
+            "func foo(a: int) -> int { # sem efeitos",
+            "    se a > 0 {",
+            "        #{ nada",
+            "           aqui }#",
+            "    }",
+            "    retornar a + 1; # o resultado",
+            "    # fim",
+            "}",
+        ].join("\n");
+        let expected = [
+            "Program",
+            "  LineComment Before \" soma um\" [1:1-1:10]",
+            "  Function foo [2:1-9:2]",
+            "    param: a [2:10-2:16]",
+            "      type: RawType int [2:13-2:16]",
+            "    return_type: RawType int [2:21-2:24]",
+            "    body:",
+            "      If [3:5-6:6]",
+            "        cond: BinaryOprt Gt [3:8-3:13]",
+            "          left: Identifier a [3:8-3:9]",
+            "          right: IntegerLiteral 0 [3:12-3:13]",
+            "        then:",
+            "        BlockComment Inside \" nada\\n           aqui \" [4:9-5:19]",
+            "      Return [7:5-7:20]",
+            "        BinaryOprt Add [7:14-7:19]",
+            "          left: Identifier a [7:14-7:15]",
+            "          right: IntegerLiteral 1 [7:18-7:19]",
+            "      LineComment After \" o resultado\" [7:21-7:34]",
+            "      LineComment After \" fim\" [8:5-8:10]",
+            "    LineComment Inside \" sem efeitos\" [2:27-2:40]",
+            "",
+        ].join("\n");
+
+        assert_eq!(print_source(&source_code), expected);
+        assert_eq!(print_source("# só um comentário"), "Program\n  LineComment Inside \" só um comentário\" [1:1-1:21]\n");
+    }
+}
@@ -0,0 +1,132 @@
+
+// local
+use crate::types::Type;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  catalogue                                 */
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuiltinType {
+    Integer,
+    Decimal,
+    Text,
+    Boolean,
+    Nothing,
+    List,
+}
+
+/// How a builtin type is written in the source.
+pub struct BuiltinTypeInfo {
+    pub builtin: BuiltinType,
+    pub name: &'static str,
+    /// English name, accepted only when `TypeCatalogue::english_aliases` is enabled.
+    pub english_alias: &'static str,
+    /// Other names the type has in English or in other programming languages, never accepted, but
+    /// used to point to the Portuguese name.
+    pub english_spellings: &'static [&'static str],
+    /// Number of type arguments written between `<` and `>`, like the item type of a `lista`.
+    pub type_params: usize,
+}
+
+/// Every type that exists without being defined by the program.
+pub const BUILTIN_TYPES: &[BuiltinTypeInfo] = &[
+    BuiltinTypeInfo{builtin: BuiltinType::Integer, name: "inteiro", english_alias: "int", english_spellings: &["integer", "long", "i32", "i64"], type_params: 0},
+    BuiltinTypeInfo{builtin: BuiltinType::Decimal, name: "decimal", english_alias: "float", english_spellings: &["double", "number", "f32", "f64"], type_params: 0},
+    BuiltinTypeInfo{builtin: BuiltinType::Text, name: "texto", english_alias: "string", english_spellings: &["str", "text"], type_params: 0},
+    BuiltinTypeInfo{builtin: BuiltinType::Boolean, name: "lógico", english_alias: "bool", english_spellings: &["boolean"], type_params: 0},
+    BuiltinTypeInfo{builtin: BuiltinType::Nothing, name: "nada", english_alias: "void", english_spellings: &["none", "unit"], type_params: 0},
+    BuiltinTypeInfo{builtin: BuiltinType::List, name: "lista", english_alias: "list", english_spellings: &["array", "vector", "vec"], type_params: 1},
+];
+
+impl BuiltinType {
+
+    /// Builds the type from its type arguments, which must match `BuiltinTypeInfo::type_params`.
+    pub fn instantiate(self, mut args: Vec<Type>) -> Type {
+
+        return match self {
+            BuiltinType::Integer => Type::Integer,
+            BuiltinType::Decimal => Type::Decimal,
+            BuiltinType::Text => Type::Text,
+            BuiltinType::Boolean => Type::Boolean,
+            BuiltinType::Nothing => Type::Nothing,
+            BuiltinType::List => Type::List(Box::new(args.pop().unwrap_or(Type::Error))),
+        };
+    }
+}
+
+/// Lookup of builtin types by the name used in the source.
+pub struct TypeCatalogue {
+    /// Whether the English names of the types are accepted, for people used to other languages.
+    pub english_aliases: bool,
+}
+
+impl TypeCatalogue {
+
+    pub fn new(english_aliases: bool) -> Self {
+        return TypeCatalogue{english_aliases};
+    }
+
+    pub fn lookup(&self, name: &str) -> Option<&'static BuiltinTypeInfo> {
+
+        return BUILTIN_TYPES.iter()
+            .find(|info| info.name == name || (self.english_aliases && info.english_alias == name));
+    }
+
+    /// The type whose English name is `name`, in any case, even when English names are not
+    /// accepted, so the error can point to the Portuguese name.
+    pub fn lookup_english_name(&self, name: &str) -> Option<&'static BuiltinTypeInfo> {
+
+        return BUILTIN_TYPES.iter().find(|info| {
+            std::iter::once(&info.english_alias).chain(info.english_spellings)
+                .any(|english_name| english_name.eq_ignore_ascii_case(name))
+        });
+    }
+
+    /// Every name accepted for a builtin type.
+    pub fn names(&self) -> Vec<&'static str> {
+
+        let mut names: Vec<&'static str> = BUILTIN_TYPES.iter().map(|info| info.name).collect();
+        if self.english_aliases {
+            names.extend(BUILTIN_TYPES.iter().map(|info| info.english_alias));
+        }
+
+        return names;
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+
+    #[test]
+    fn test_lookup() {
+
+        let catalogue = TypeCatalogue::new(false);
+        assert_eq!(catalogue.lookup("lógico").map(|info| info.builtin), Some(BuiltinType::Boolean));
+        assert!(catalogue.lookup("bool").is_none());
+        assert_eq!(catalogue.lookup_english_name("bool").map(|info| info.name), Some("lógico"));
+        assert_eq!(catalogue.lookup_english_name("Integer").map(|info| info.name), Some("inteiro"));
+        assert!(catalogue.lookup("integer").is_none());
+        assert!(!catalogue.names().contains(&"int"));
+
+        let catalogue = TypeCatalogue::new(true);
+        assert_eq!(catalogue.lookup("int").map(|info| info.builtin), Some(BuiltinType::Integer));
+        assert!(catalogue.names().contains(&"int"));
+    }
+
+    #[test]
+    fn test_instantiate() {
+
+        assert_eq!(BuiltinType::List.instantiate(vec![Type::Text]).to_string(), "lista<texto>");
+        assert_eq!(BuiltinType::Decimal.instantiate(Vec::new()), Type::Decimal);
+    }
+}
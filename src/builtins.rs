@@ -0,0 +1,128 @@
+
+// local
+use crate::types::Type;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  builtins                                  */
+/* -------------------------------------------------------------------------- */
+
+/// Subprograms that exist without being defined by the program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Builtin {
+    Write,
+    Read,
+    Length,
+    Append,
+    Get,
+    ToText,
+}
+
+pub struct BuiltinInfo {
+    pub builtin: Builtin,
+    pub name: &'static str,
+    /// Procedures may change values outside of them, so functions can not call them.
+    pub is_procedure: bool,
+    /// Reads from or writes to the terminal.
+    pub performs_io: bool,
+}
+
+pub const BUILTINS: &[BuiltinInfo] = &[
+    BuiltinInfo{builtin: Builtin::Write, name: "escrever", is_procedure: true, performs_io: true},
+    BuiltinInfo{builtin: Builtin::Read, name: "ler", is_procedure: true, performs_io: true},
+    BuiltinInfo{builtin: Builtin::Length, name: "tamanho", is_procedure: false, performs_io: false},
+    // `adicionar` only changes the list it receives, which may belong to the function
+    BuiltinInfo{builtin: Builtin::Append, name: "adicionar", is_procedure: false, performs_io: false},
+    BuiltinInfo{builtin: Builtin::Get, name: "obter", is_procedure: false, performs_io: false},
+    BuiltinInfo{builtin: Builtin::ToText, name: "para_texto", is_procedure: false, performs_io: false},
+];
+
+impl Builtin {
+
+    pub fn from_name(name: &str) -> Option<Builtin> {
+        return BUILTINS.iter().find(|info| info.name == name).map(|info| info.builtin);
+    }
+
+    pub fn info(self) -> &'static BuiltinInfo {
+        return BUILTINS.iter().find(|info| info.builtin == self).expect("every builtin is in BUILTINS");
+    }
+
+    pub fn name(self) -> &'static str {
+        return self.info().name;
+    }
+
+    /// Signature of a call with arguments of these types. Builtins accept values of many types,
+    /// so the parameter types come from the arguments, and the call is then checked like any other.
+    pub fn signature(self, args: &[Type]) -> Type {
+
+        let arg = |index: usize| args.get(index).map(|arg| arg.dereferenced().clone());
+
+        let (params, return_type) = match self {
+            // `escrever(valor)` shows any value
+            Builtin::Write => (vec![arg(0).unwrap_or(Type::Text)], Type::Nothing),
+            // `ler() -> texto` reads a line
+            Builtin::Read => (Vec::new(), Type::Text),
+            // `tamanho(lista ou texto) -> inteiro`
+            Builtin::Length => {
+                let sized = match arg(0) {
+                    Some(Type::Text) => Type::Text,
+                    Some(Type::List(item)) => Type::List(item),
+                    _ => Type::List(Box::new(Type::Error)),
+                };
+                (vec![Type::Ref{is_mut: false, inner: Box::new(sized)}], Type::Integer)
+            },
+            // `adicionar(lista, item)` adds the item to the end of the list
+            Builtin::Append => {
+                let item = match arg(0) {
+                    Some(Type::List(item)) => *item,
+                    _ => arg(1).unwrap_or(Type::Error),
+                };
+                let list = Type::Ref{is_mut: true, inner: Box::new(Type::List(Box::new(item.clone())))};
+                (vec![list, item], Type::Nothing)
+            },
+            // `obter(lista, posição) -> item` returns a copy of the item, counting positions from 0
+            Builtin::Get => {
+                let item = match arg(0) {
+                    Some(Type::List(item)) => *item,
+                    _ => Type::Error,
+                };
+                let list = Type::Ref{is_mut: false, inner: Box::new(Type::List(Box::new(item.clone())))};
+                (vec![list, Type::Integer], item)
+            },
+            // `para_texto(valor) -> texto` converts any value to text
+            Builtin::ToText => (vec![arg(0).unwrap_or(Type::Integer)], Type::Text),
+        };
+
+        return Type::Function{params, return_type: Box::new(return_type)};
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+
+    #[test]
+    fn test_from_name() {
+
+        assert_eq!(Builtin::from_name("escrever"), Some(Builtin::Write));
+        assert_eq!(Builtin::from_name("print"), None);
+        assert_eq!(Builtin::Length.name(), "tamanho");
+    }
+
+    #[test]
+    fn test_signature() {
+
+        let list = Type::List(Box::new(Type::Decimal));
+        assert_eq!(Builtin::Append.signature(&[list, Type::Integer]).to_string(), "função(mut ref lista<decimal>, decimal) -> nada");
+        assert_eq!(Builtin::Length.signature(&[Type::Text]).to_string(), "função(ref texto) -> inteiro");
+        assert_eq!(Builtin::Get.signature(&[Type::List(Box::new(Type::Text))]).to_string(), "função(ref lista<texto>, inteiro) -> texto");
+        assert_eq!(Builtin::Write.signature(&[Type::Ref{is_mut: false, inner: Box::new(Type::Boolean)}]).to_string(), "função(lógico) -> nada");
+    }
+}
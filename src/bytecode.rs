@@ -0,0 +1,620 @@
+// std
+use std::rc::Rc;
+
+// local
+use crate::ast::{BinaryOprt, LexicalInfo, UnaryOprt};
+use crate::builtins::Builtin;
+use crate::ir::{self, BasicBlock, BlockID, Constant, Function, InstructionKind, Operand, Program, Temp, Terminator};
+use crate::runtime::{EnumLayout, StructLayout, Value};
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  bytecode                                  */
+/* -------------------------------------------------------------------------- */
+
+/// Instructions of a stack machine. Locals are numbered slots of the running subprogram, and
+/// jumps go to an index of the code of the same subprogram.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Instruction {
+    /// Pushes a copy of a value of the constant pool.
+    Constant(usize),
+    Nothing,
+    /// Pushes a copy of the value of a local.
+    LoadLocal(usize),
+    /// Pushes a reference to the storage of a local.
+    LocalRef(usize),
+    /// Pops a value into the storage of a local.
+    StoreLocal(usize),
+    /// Pops a value into a new storage for a local, every run of a declaration creates a new one.
+    DeclareLocal(usize),
+    /// Pops a reference and pushes a copy of the value it points to.
+    Deref,
+    /// Pops a value and pushes a copy of it without references.
+    Dereference,
+    /// Pops a reference and then a value, and writes the value where the reference points.
+    StoreRef,
+    /// Pops a value and pushes a reference to a new storage with it.
+    Box,
+    /// Pops this many items and pushes a list with them.
+    MakeList(usize),
+    /// Pops the fields of a structure with the layout of this index, in the order of its
+    /// definition, and pushes the structure.
+    MakeStruct(usize),
+    /// Pops a reference to a structure, or to a reference to it, and pushes a reference to the
+    /// field with this position.
+    FieldRef(usize),
+    /// Pops the values of a variant and pushes a value of the enumeration with the layout of
+    /// index `enumeration`.
+    MakeVariant{enumeration: usize, variant: usize, count: usize},
+    /// Pops a value of an enumeration and pushes the position of its variant.
+    Tag,
+    /// Pops a value of an enumeration and pushes a copy of its value with this position.
+    Payload(usize),
+    Unary(UnaryOprt),
+    Binary(BinaryOprt),
+    Jump(usize),
+    /// Pops a `lógico`, and jumps when it is `falso`.
+    JumpIfFalse(usize),
+    /// Pops the arguments and calls a subprogram of the program by its index.
+    Call{function: usize, arg_count: usize},
+    CallBuiltin{builtin: Builtin, arg_count: usize},
+    /// Pops the return value and returns it to the caller.
+    Return,
+    Pop,
+    /// Pops a reference to a list or text, and pushes the items a `para cada` goes through.
+    Items,
+    /// Pushes the number of items kept in the slot.
+    ItemCount(usize),
+    /// Pops a position and pushes a reference to the storage of the item with it, of the items
+    /// kept in the slot.
+    ItemRef(usize),
+}
+
+/// The code of a subprogram.
+#[derive(Default)]
+pub struct Chunk {
+    pub name: String,
+    pub param_count: usize,
+    pub local_count: usize,
+    pub code: Vec<Instruction>,
+    pub constants: Vec<Value>,
+    /// Where each instruction comes from, for runtime errors.
+    pub lexical_infos: Vec<LexicalInfo>,
+}
+
+/// A compiled program, with a chunk for each subprogram in the order they are defined.
+pub struct Bytecode {
+    pub chunks: Vec<Chunk>,
+    /// Layout of each structure, in the order they are defined.
+    pub structs: Vec<Rc<StructLayout>>,
+    /// Layout of each enumeration, in the order they are defined.
+    pub enums: Vec<Rc<EnumLayout>>,
+    pub entry_point: Option<usize>,
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  compiler                                  */
+/* -------------------------------------------------------------------------- */
+
+/// Where the value of a temporary of the IR is kept.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TempPlace {
+    /// On the stack, from the instruction that computes it to the one that uses it.
+    Stack,
+    /// In a local slot, after the ones of the variables.
+    Slot(usize),
+    /// Nowhere, since nothing uses it.
+    Unused,
+}
+
+/// Operands an instruction pushes before it runs, in the order it pops them. The items of
+/// `ItemCount` and `ItemRef` are read from their slot.
+fn pushed_operands(kind: &InstructionKind) -> Vec<&Operand> {
+
+    return match kind {
+        InstructionKind::ItemCount{..} => Vec::new(),
+        InstructionKind::ItemRef{index, ..} => vec![index],
+        _ => kind.operands(),
+    };
+}
+
+/// Decides where each temporary of the function is kept. A temporary stays on the stack when
+/// its only use comes later in the same block, and everything pushed after it is popped before
+/// that use, so the instruction finds its operands in the order it pops them.
+fn temp_places(function: &Function) -> Vec<TempPlace> {
+
+    let blocks: Vec<&BasicBlock> = function.reverse_postorder().into_iter().map(|id| function.block(id)).collect();
+
+    let mut use_count = vec![0; function.temps.len()];
+    let mut defined_in = vec![None; function.temps.len()];
+    let mut used_in = vec![None; function.temps.len()];
+    let mut stackable = vec![true; function.temps.len()];
+    for (position, block) in blocks.iter().enumerate() {
+        for instruction in &block.instructions {
+            if let Some(dest) = instruction.kind.dest() {
+                defined_in[dest.0] = Some(position);
+            }
+            if let InstructionKind::ItemCount{items: Operand::Temp(items), ..} | InstructionKind::ItemRef{items: Operand::Temp(items), ..} = &instruction.kind {
+                stackable[items.0] = false;
+            }
+        }
+        let operands = block.instructions.iter().flat_map(|instruction| instruction.kind.operands()).chain(block.terminator.operands());
+        for operand in operands {
+            if let Operand::Temp(temp) = operand {
+                use_count[temp.0] += 1;
+                used_in[temp.0] = Some(position);
+            }
+        }
+    }
+
+    let mut on_stack: Vec<bool> = (0..function.temps.len())
+        .map(|temp| stackable[temp] && use_count[temp] == 1 && defined_in[temp].is_some() && defined_in[temp] == used_in[temp])
+        .collect();
+
+    // an operand that is not where it is expected goes to a slot instead, which changes what is
+    // below the others, so the blocks are checked again from the start
+    'check: loop {
+        for block in &blocks {
+            let mut stack: Vec<Temp> = Vec::new();
+            let steps = block.instructions.iter()
+                .map(|instruction| (pushed_operands(&instruction.kind), instruction.kind.dest()))
+                .chain(std::iter::once((block.terminator.operands(), None)));
+
+            for (operands, dest) in steps {
+                let popped: Vec<Temp> = operands.iter()
+                    .filter_map(|operand| match operand {
+                        Operand::Temp(temp) if on_stack[temp.0] => Some(*temp),
+                        _ => None,
+                    })
+                    .collect();
+
+                // the most operands that can stay, the others go to slots
+                let kept = (0..=popped.len()).rev()
+                    .find(|kept| {
+                        let in_order = operands[..*kept].iter().zip(&popped).all(|(operand, temp)| **operand == Operand::Temp(*temp));
+                        let remaining: Vec<Temp> = stack.iter().copied().filter(|temp| !popped[*kept..].contains(temp)).collect();
+                        in_order && remaining.ends_with(&popped[..*kept])
+                    })
+                    .expect("no operand on the stack is always possible");
+                if kept < popped.len() {
+                    for temp in &popped[kept..] {
+                        on_stack[temp.0] = false;
+                    }
+                    continue 'check;
+                }
+
+                stack.truncate(stack.len() - popped.len());
+                if let Some(dest) = dest.filter(|dest| on_stack[dest.0]) {
+                    stack.push(dest);
+                }
+            }
+        }
+        break;
+    }
+
+    let mut next_slot = function.locals.len();
+    return (0..function.temps.len())
+        .map(|temp| match (use_count[temp], on_stack[temp]) {
+            (0, _) => TempPlace::Unused,
+            (_, true) => TempPlace::Stack,
+            (_, false) => {
+                next_slot += 1;
+                TempPlace::Slot(next_slot - 1)
+            },
+        })
+        .collect();
+}
+
+struct Compiler<'a> {
+    places: &'a [TempPlace],
+    chunk: Chunk,
+    /// Jumps to a block, patched with its address once every block is compiled.
+    jumps: Vec<(usize, BlockID)>,
+}
+
+impl<'a> Compiler<'a> {
+
+    fn emit(&mut self, instruction: Instruction, lexical_info: LexicalInfo) -> usize {
+
+        self.chunk.code.push(instruction);
+        self.chunk.lexical_infos.push(lexical_info);
+        return self.chunk.code.len() - 1;
+    }
+
+    fn jump(&mut self, instruction: Instruction, target: BlockID, lexical_info: LexicalInfo) {
+
+        let jump = self.emit(instruction, lexical_info);
+        self.jumps.push((jump, target));
+    }
+
+    /// Pushes the value of an operand, a temporary on the stack is already there.
+    fn push(&mut self, operand: &Operand, lexical_info: LexicalInfo) {
+
+        match operand {
+            Operand::Constant(Constant::Nothing) => {
+                self.emit(Instruction::Nothing, lexical_info);
+            },
+            Operand::Constant(constant) => {
+                self.chunk.constants.push(constant.to_value());
+                let index = self.chunk.constants.len() - 1;
+                self.emit(Instruction::Constant(index), lexical_info);
+            },
+            Operand::Temp(temp) => {
+                if let TempPlace::Slot(slot) = self.places[temp.0] {
+                    self.emit(Instruction::LoadLocal(slot), lexical_info);
+                }
+            },
+        }
+    }
+
+    /// Pops the value just computed into where its temporary is kept.
+    fn assign(&mut self, dest: Temp, lexical_info: LexicalInfo) {
+
+        match self.places[dest.0] {
+            TempPlace::Stack => {},
+            TempPlace::Slot(slot) => {
+                self.emit(Instruction::StoreLocal(slot), lexical_info);
+            },
+            TempPlace::Unused => {
+                self.emit(Instruction::Pop, lexical_info);
+            },
+        }
+    }
+
+    fn items_slot(&self, items: &Operand) -> usize {
+
+        return match items {
+            Operand::Temp(temp) => match self.places[temp.0] {
+                TempPlace::Slot(slot) => slot,
+                other => unreachable!("items are always kept in a slot, found {:?}", other),
+            },
+            Operand::Constant(constant) => unreachable!("items are never constants, found {:?}", constant),
+        };
+    }
+
+    fn compile_instruction(&mut self, instruction: &ir::Instruction) {
+
+        let lexical_info = instruction.lexical_info;
+        for operand in pushed_operands(&instruction.kind) {
+            self.push(operand, lexical_info);
+        }
+
+        let compiled = match &instruction.kind {
+            InstructionKind::Copy{..} => None,
+            InstructionKind::LoadLocal{local, ..} => Some(Instruction::LoadLocal(local.0)),
+            InstructionKind::StoreLocal{local, ..} => Some(Instruction::StoreLocal(local.0)),
+            InstructionKind::DeclareLocal{local, value} => {
+                // a variable declared without a value holds `nada` until it is assigned
+                if value.is_none() {
+                    self.emit(Instruction::Nothing, lexical_info);
+                }
+                Some(Instruction::DeclareLocal(local.0))
+            },
+            InstructionKind::LocalRef{local, ..} => Some(Instruction::LocalRef(local.0)),
+            InstructionKind::Load{..} => Some(Instruction::Deref),
+            InstructionKind::Store{..} => Some(Instruction::StoreRef),
+            InstructionKind::Dereference{..} => Some(Instruction::Dereference),
+            InstructionKind::Box{..} => Some(Instruction::Box),
+            InstructionKind::MakeList{items, ..} => Some(Instruction::MakeList(items.len())),
+            InstructionKind::MakeStruct{structure, ..} => Some(Instruction::MakeStruct(structure.0)),
+            InstructionKind::FieldRef{index, ..} => Some(Instruction::FieldRef(*index)),
+            InstructionKind::MakeVariant{enumeration, variant, payload, ..} => {
+                Some(Instruction::MakeVariant{enumeration: enumeration.0, variant: *variant, count: payload.len()})
+            },
+            InstructionKind::Tag{..} => Some(Instruction::Tag),
+            InstructionKind::Payload{index, ..} => Some(Instruction::Payload(*index)),
+            InstructionKind::Unary{oprt, ..} => Some(Instruction::Unary(*oprt)),
+            InstructionKind::Binary{oprt, ..} => Some(Instruction::Binary(*oprt)),
+            InstructionKind::Call{function, args, ..} => Some(Instruction::Call{function: function.0, arg_count: args.len()}),
+            InstructionKind::CallBuiltin{builtin, args, ..} => Some(Instruction::CallBuiltin{builtin: *builtin, arg_count: args.len()}),
+            InstructionKind::Items{..} => Some(Instruction::Items),
+            InstructionKind::ItemCount{items, ..} => Some(Instruction::ItemCount(self.items_slot(items))),
+            InstructionKind::ItemRef{items, ..} => Some(Instruction::ItemRef(self.items_slot(items))),
+        };
+
+        if let Some(compiled) = compiled {
+            self.emit(compiled, lexical_info);
+        }
+        if let Some(dest) = instruction.kind.dest() {
+            self.assign(dest, lexical_info);
+        }
+    }
+
+    /// Compiles the end of a block, where `next` is the block compiled right after it.
+    fn compile_terminator(&mut self, block: &BasicBlock, next: Option<BlockID>) {
+
+        let lexical_info = block.terminator_info;
+
+        match &block.terminator {
+            Terminator::Jump(target) => {
+                if next != Some(*target) {
+                    self.jump(Instruction::Jump(0), *target, lexical_info);
+                }
+            },
+            Terminator::Branch{condition, then_block, else_block} => {
+                self.push(condition, lexical_info);
+                self.jump(Instruction::JumpIfFalse(0), *else_block, lexical_info);
+                if next != Some(*then_block) {
+                    self.jump(Instruction::Jump(0), *then_block, lexical_info);
+                }
+            },
+            Terminator::Return(value) => {
+                self.push(value, lexical_info);
+                self.emit(Instruction::Return, lexical_info);
+            },
+        }
+    }
+}
+
+/// Compiles a function of the IR, leaving out the blocks that can not run.
+fn compile_function(function: &Function) -> Chunk {
+
+    let places = temp_places(function);
+    let slot_count = places.iter().filter(|place| matches!(place, TempPlace::Slot(_))).count();
+
+    let mut compiler = Compiler{
+        places: &places,
+        chunk: Chunk{
+            name: function.name.clone(),
+            param_count: function.param_count,
+            local_count: function.locals.len() + slot_count,
+            ..Chunk::default()
+        },
+        jumps: Vec::new(),
+    };
+
+    let order = function.layout_order();
+    let mut addresses = vec![0; function.blocks.len()];
+
+    for (position, id) in order.iter().enumerate() {
+        addresses[id.0] = compiler.chunk.code.len();
+        let block = function.block(*id);
+        for instruction in &block.instructions {
+            compiler.compile_instruction(instruction);
+        }
+        compiler.compile_terminator(block, order.get(position + 1).copied());
+    }
+
+    for (jump, target) in std::mem::take(&mut compiler.jumps) {
+        match &mut compiler.chunk.code[jump] {
+            Instruction::Jump(to) | Instruction::JumpIfFalse(to) => *to = addresses[target.0],
+            other => unreachable!("only jumps go to blocks, found {:?}", other),
+        }
+    }
+
+    return compiler.chunk;
+}
+
+/// Compiles a lowered program to bytecode.
+pub fn compile(program: &Program) -> Bytecode {
+
+    return Bytecode{
+        chunks: program.functions.iter().map(compile_function).collect(),
+        structs: program.structs.iter().map(|definition| definition.layout.clone()).collect(),
+        enums: program.enums.iter().map(|definition| definition.layout.clone()).collect(),
+        entry_point: program.entry_point.map(|entry_point| entry_point.0),
+    };
+}
+
+
+
+/* -------------------------------------------------------------------------- */
+/*                                disassembler                                */
+/* -------------------------------------------------------------------------- */
+
+fn mnemonic(instruction: &Instruction) -> &'static str {
+
+    return match instruction {
+        Instruction::Constant(_) => "CONSTANT",
+        Instruction::Nothing => "NOTHING",
+        Instruction::LoadLocal(_) => "LOAD_LOCAL",
+        Instruction::LocalRef(_) => "LOCAL_REF",
+        Instruction::StoreLocal(_) => "STORE_LOCAL",
+        Instruction::DeclareLocal(_) => "DECLARE_LOCAL",
+        Instruction::Deref => "DEREF",
+        Instruction::Dereference => "DEREFERENCE",
+        Instruction::StoreRef => "STORE_REF",
+        Instruction::Box => "BOX",
+        Instruction::MakeList(_) => "MAKE_LIST",
+        Instruction::MakeStruct(_) => "MAKE_STRUCT",
+        Instruction::FieldRef(_) => "FIELD_REF",
+        Instruction::MakeVariant{..} => "MAKE_VARIANT",
+        Instruction::Tag => "TAG",
+        Instruction::Payload(_) => "PAYLOAD",
+        Instruction::Unary(_) => "UNARY",
+        Instruction::Binary(_) => "BINARY",
+        Instruction::Jump(_) => "JUMP",
+        Instruction::JumpIfFalse(_) => "JUMP_IF_FALSE",
+        Instruction::Call{..} => "CALL",
+        Instruction::CallBuiltin{..} => "CALL_BUILTIN",
+        Instruction::Return => "RETURN",
+        Instruction::Pop => "POP",
+        Instruction::Items => "ITEMS",
+        Instruction::ItemCount(_) => "ITEM_COUNT",
+        Instruction::ItemRef(_) => "ITEM_REF",
+    };
+}
+
+fn operands(bytecode: &Bytecode, chunk: &Chunk, instruction: &Instruction) -> String {
+
+    return match instruction {
+        Instruction::Constant(index) => format!("{} ({})", index, chunk.constants[*index].item_display()),
+        Instruction::LoadLocal(slot) |
+        Instruction::LocalRef(slot) |
+        Instruction::StoreLocal(slot) |
+        Instruction::DeclareLocal(slot) |
+        Instruction::ItemCount(slot) |
+        Instruction::ItemRef(slot) => slot.to_string(),
+        Instruction::MakeList(count) => count.to_string(),
+        Instruction::MakeStruct(index) => format!("{} ({})", index, bytecode.structs[*index].name),
+        Instruction::FieldRef(index) | Instruction::Payload(index) => index.to_string(),
+        Instruction::MakeVariant{enumeration, variant, count} => {
+            format!("{} ({}), {}", enumeration, bytecode.enums[*enumeration].variants[*variant], count)
+        },
+        Instruction::Unary(oprt) => oprt.symbol().to_string(),
+        Instruction::Binary(oprt) => oprt.symbol().to_string(),
+        Instruction::Jump(target) | Instruction::JumpIfFalse(target) => format!("-> {:04}", target),
+        Instruction::Call{function, arg_count} => format!("{} ({}), {}", function, bytecode.chunks[*function].name, arg_count),
+        Instruction::CallBuiltin{builtin, arg_count} => format!("{}, {}", builtin.name(), arg_count),
+        Instruction::Nothing |
+        Instruction::Deref |
+        Instruction::Dereference |
+        Instruction::StoreRef |
+        Instruction::Box |
+        Instruction::Tag |
+        Instruction::Items |
+        Instruction::Return |
+        Instruction::Pop => String::new(),
+    };
+}
+
+/// Lists the instructions of every subprogram, with the source line and column they come from:
+///
+/// ```text
+/// == 0 dobro (params: 1, locals: 1) ==
+/// 0000    2:14  LOAD_LOCAL     0
+/// 0001    2:18  CONSTANT       0 (2)
+/// ```
+pub fn disassemble(bytecode: &Bytecode) -> String {
+
+    let mut output = String::new();
+
+    for (index, chunk) in bytecode.chunks.iter().enumerate() {
+
+        output.push_str(&format!("== {} {} (params: {}, locals: {}) ==\n", index, chunk.name, chunk.param_count, chunk.local_count));
+        for (address, (instruction, lexical_info)) in chunk.code.iter().zip(&chunk.lexical_infos).enumerate() {
+            let position = format!("{}:{}", lexical_info.start.line + 1, lexical_info.start.column + 1);
+            let line = format!("{:04}  {:>6}  {:<14} {}", address, position, mnemonic(instruction), operands(bytecode, chunk, instruction));
+            output.push_str(line.trim_end());
+            output.push('\n');
+        }
+        output.push('\n');
+    }
+
+    return output;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::analysis::{analyze, AnalysisSettings};
+    use crate::tso_parser::parse_source;
+
+
+    fn compile_source(source_code: &str) -> Bytecode {
+
+        let output = parse_source(source_code).unwrap();
+        let analysis = analyze(&output.ast, &AnalysisSettings::default());
+        assert!(!output.has_errors() && !analysis.has_errors());
+
+        return compile(&ir::lower(&output.ast, &analysis.resolution, &analysis.types));
+    }
+
+    #[test]
+    fn test_disassemble() {
+
+        let source_code = "func dobro(x: inteiro) -> inteiro {\n    retornar x * 2;\n}";
+
+        let expected = [
+            "== 0 dobro (params: 1, locals: 1) ==",
+            "0000    2:14  LOAD_LOCAL     0",
+            "0001    2:14  CONSTANT       0 (2)",
+            "0002    2:14  BINARY         *",
+            "0003     2:5  RETURN",
+            "",
+            "",
+        ].join("\n");
+
+        assert_eq!(disassemble(&compile_source(source_code)), expected);
+    }
+
+    #[test]
+    fn test_temporaries() {
+
+        let source_code = r#"
+            estrutura Ponto { x: inteiro, y: inteiro }
+            func f(a: inteiro) -> Ponto {
+                retornar Ponto{y: a + 1, x: a * 2};
+            }
+        "#;
+
+        // the fields are computed in the order they are written, so the one of `y` waits in a
+        // slot while the one of `x` is pushed
+        let bytecode = compile_source(source_code);
+        assert_eq!(bytecode.chunks[0].local_count, 2);
+        assert_eq!(bytecode.chunks[0].code, vec![
+            Instruction::LoadLocal(0),
+            Instruction::Constant(0),
+            Instruction::Binary(BinaryOprt::Add),
+            Instruction::StoreLocal(1),
+            Instruction::LoadLocal(0),
+            Instruction::Constant(1),
+            Instruction::Binary(BinaryOprt::Mul),
+            Instruction::LoadLocal(1),
+            Instruction::MakeStruct(0),
+            Instruction::Return,
+        ]);
+    }
+
+    #[test]
+    fn test_else_if_jumps() {
+
+        let source_code = r#"
+            procedimento principal() {
+                se falso { 1; } senão se verdadeiro { 2; } senão { 3; }
+                se falso { 4; } senão se verdadeiro { 5; }
+            }
+        "#;
+
+        // each failed condition falls through to the next one, and the branches, which have no
+        // code, jump over the conditions after them
+        let bytecode = compile_source(source_code);
+        assert_eq!(bytecode.chunks[0].code, vec![
+            Instruction::Constant(0),
+            Instruction::JumpIfFalse(3),
+            Instruction::Jump(6),
+            Instruction::Constant(1),
+            Instruction::JumpIfFalse(6),
+            Instruction::Jump(6),
+            Instruction::Constant(2),
+            Instruction::JumpIfFalse(9),
+            Instruction::Jump(11),
+            Instruction::Constant(3),
+            Instruction::JumpIfFalse(11),
+            Instruction::Nothing,
+            Instruction::Return,
+        ]);
+    }
+
+    #[test]
+    fn test_loop_jumps() {
+
+        let source_code = r#"
+            procedimento principal() {
+                enquanto verdadeiro {
+                    se falso { continuar; }
+                    parar;
+                }
+            }
+        "#;
+
+        let bytecode = compile_source(source_code);
+        assert_eq!(bytecode.entry_point, Some(0));
+        assert_eq!(bytecode.chunks[0].code, vec![
+            Instruction::Constant(0),
+            Instruction::JumpIfFalse(5),
+            Instruction::Constant(1),
+            Instruction::JumpIfFalse(5),
+            Instruction::Jump(0),
+            Instruction::Nothing,
+            Instruction::Return,
+        ]);
+    }
+}
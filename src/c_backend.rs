@@ -0,0 +1,1175 @@
+// std
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::path::Path;
+use std::process::Command;
+
+// local
+use crate::ast::{BinaryOprt, LexicalInfo, UnaryOprt};
+use crate::builtins::Builtin;
+use crate::ir::{BlockID, Constant, Function, FunctionID, InstructionKind, LocalID, Operand, Program, Temp, Terminator};
+use crate::types::Type;
+
+// external
+use anyhow::{bail, Context, Result};
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   runtime                                  */
+/* -------------------------------------------------------------------------- */
+
+/// Runtime the generated code is compiled with, so the executables need nothing else.
+pub const RUNTIME_HEADER: &str = include_str!("../runtime/c/ila_runtime.h");
+pub const RUNTIME_SOURCE: &str = include_str!("../runtime/c/ila_runtime.c");
+
+/// C compiler used when the `CC` environment variable is not set.
+const DEFAULT_C_COMPILER: &str = "cc";
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  C types                                   */
+/* -------------------------------------------------------------------------- */
+
+fn c_type(ty: &Type) -> String {
+
+    return match ty {
+        Type::Integer => "ila_int".to_string(),
+        Type::Decimal => "ila_dec".to_string(),
+        Type::Text => "ila_text *".to_string(),
+        Type::Boolean => "bool".to_string(),
+        Type::Nothing => "ila_nothing".to_string(),
+        Type::List(_) => "ila_list *".to_string(),
+        Type::Comp{..} => "ila_box *".to_string(),
+        Type::Ref{inner, ..} => pointer_to(inner),
+        Type::Struct{..} | Type::Enum{..} => unreachable!("`compilar` rejects programs with structures and enumerations"),
+        Type::Function{..} | Type::Error => unreachable!("values of type `{}` are not generated", ty),
+    };
+}
+
+/// C type of a pointer to a value of the type.
+fn pointer_to(ty: &Type) -> String {
+
+    let pointed = c_type(ty);
+    return match pointed.ends_with('*') {
+        true => format!("{}*", pointed),
+        false => format!("{} *", pointed),
+    };
+}
+
+/// A C declaration of a name with the type, like `ila_text *v_nome`.
+fn declaration(ty: &Type, name: &str) -> String {
+
+    let c_type = c_type(ty);
+    return match c_type.ends_with('*') {
+        true => format!("{}{}", c_type, name),
+        false => format!("{} {}", c_type, name),
+    };
+}
+
+/// Types whose values own memory, copied and freed explicitly.
+fn owns_memory(ty: &Type) -> bool {
+    return matches!(ty, Type::Integer | Type::Decimal | Type::Text | Type::List(_) | Type::Comp{..});
+}
+
+/// Value of a variable before anything is assigned to it.
+fn zero_value(ty: &Type) -> &'static str {
+
+    return match ty {
+        Type::Integer => "ILA_INT(0)",
+        Type::Decimal => "ILA_DEC(0, 0)",
+        _ => "0",
+    };
+}
+
+fn copy_code(ty: &Type, code: &str) -> String {
+
+    return match ty {
+        Type::Integer => format!("ila_int_retain({})", code),
+        Type::Decimal => format!("ila_dec_retain({})", code),
+        Type::Text => format!("ila_text_retain({})", code),
+        Type::List(_) => format!("ila_list_copy({})", code),
+        Type::Comp{..} => format!("ila_box_retain({})", code),
+        _ => code.to_string(),
+    };
+}
+
+fn drop_code(ty: &Type, code: &str) -> Option<String> {
+
+    return match ty {
+        Type::Integer => Some(format!("ila_int_release({});", code)),
+        Type::Decimal => Some(format!("ila_dec_release({});", code)),
+        Type::Text => Some(format!("ila_text_release({});", code)),
+        Type::List(_) => Some(format!("ila_list_free({});", code)),
+        Type::Comp{..} => Some(format!("ila_box_release({});", code)),
+        _ => None,
+    };
+}
+
+fn is_reference(ty: &Type) -> bool {
+    return matches!(ty, Type::Ref{..} | Type::Comp{..});
+}
+
+/// A name of the program as a C identifier, escaping the characters C does not accept.
+pub fn mangle(name: &str) -> String {
+
+    let mut mangled = String::new();
+    for c in name.chars() {
+        match c.is_ascii_alphanumeric() || c == '_' {
+            true => mangled.push(c),
+            false => write!(mangled, "_u{:04x}", c as u32).expect("writing to a string does not fail"),
+        }
+    }
+
+    return mangled;
+}
+
+/// A C expression with the value of an `int64_t`.
+fn int64_literal(value: i64) -> String {
+
+    return match value {
+        // `-9223372036854775808` is the negation of a literal that does not fit in an `int64_t`
+        i64::MIN => "INT64_MIN".to_string(),
+        value => format!("INT64_C({})", value),
+    };
+}
+
+/// A C string literal with the bytes of the text.
+fn string_literal(text: &str) -> String {
+
+    let mut literal = String::from("\"");
+    for byte in text.bytes() {
+        match byte {
+            b'"' => literal.push_str("\\\""),
+            b'\\' => literal.push_str("\\\\"),
+            // `?` could start a trigraph
+            b' '..=b'~' if byte != b'?' => literal.push(byte as char),
+            _ => write!(literal, "\\{:03o}", byte).expect("writing to a string does not fail"),
+        }
+    }
+    literal.push('"');
+
+    return literal;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  generator                                 */
+/* -------------------------------------------------------------------------- */
+
+/// Operands the instruction gives to a new owner, which keeps them after it runs.
+fn consumed_operands<'k>(function: &Function, kind: &'k InstructionKind) -> Vec<&'k Operand> {
+
+    return match kind {
+        InstructionKind::Copy{value, ..} |
+        InstructionKind::StoreLocal{value, ..} |
+        InstructionKind::Store{value, ..} => vec![value],
+        InstructionKind::DeclareLocal{value, ..} => value.iter().collect(),
+        // a `comp` is created with a copy of the value, a storage for a `ref` keeps it
+        InstructionKind::Box{dest, value} if matches!(function.temp_type(*dest), Type::Ref{..}) => vec![value],
+        InstructionKind::Call{args, ..} => args.iter().collect(),
+        _ => Vec::new(),
+    };
+}
+
+/// Temporaries that own memory and are read once, by an instruction that keeps their value: they
+/// are moved there instead of copied, and never freed by the subprogram.
+fn moved_temps(function: &Function) -> Vec<bool> {
+
+    let mut uses = vec![0; function.temps.len()];
+    let mut consumed = vec![false; function.temps.len()];
+    for block in function.layout_order() {
+        let block = function.block(block);
+        for instruction in &block.instructions {
+            for operand in instruction.kind.operands() {
+                if let Operand::Temp(temp) = operand {
+                    uses[temp.0] += 1;
+                }
+            }
+            for operand in consumed_operands(function, &instruction.kind) {
+                if let Operand::Temp(temp) = operand {
+                    consumed[temp.0] = true;
+                }
+            }
+        }
+        for operand in block.terminator.operands() {
+            if let Operand::Temp(temp) = operand {
+                uses[temp.0] += 1;
+                consumed[temp.0] |= matches!(block.terminator, Terminator::Return(_));
+            }
+        }
+    }
+
+    // a `comp` given where a `ref` is expected is only pointed to, so `comp`s are never moved
+    return function.temps.iter().enumerate()
+        .map(|(index, ty)| owns_memory(ty) && !matches!(ty, Type::Comp{..}) && uses[index] == 1 && consumed[index])
+        .collect();
+}
+
+struct Generator<'a> {
+    program: &'a Program,
+    /// Descriptors of `ref` and `comp` types, in an order where each one comes after the
+    /// descriptor it points to.
+    descriptors: Vec<String>,
+    descriptor_names: HashSet<String>,
+    /// Text constants, created when the program starts, in the order they are found.
+    texts: Vec<String>,
+    text_names: HashMap<String, String>,
+    /// Number constants that do not fit in 64 bits, with their type and the code that creates
+    /// them when the program starts.
+    numbers: Vec<(Type, String)>,
+    number_names: HashMap<String, String>,
+    /* ---- current subprogram ---- */
+    function: &'a Function,
+    code: String,
+    moved: Vec<bool>,
+    /// Blocks some instruction jumps to, which get a label.
+    jump_targets: HashSet<BlockID>,
+}
+
+impl<'a> Generator<'a> {
+
+    fn line(&mut self, line: &str) {
+
+        self.code.push_str("    ");
+        self.code.push_str(line);
+        self.code.push('\n');
+    }
+
+    /// `line, column` of an instruction, for the runtime errors.
+    fn location(&self, lexical_info: &LexicalInfo) -> String {
+        return format!("{}, {}", lexical_info.start.line + 1, lexical_info.start.column + 1);
+    }
+
+    fn function_name(&self, id: FunctionID) -> String {
+        return format!("f_{}", mangle(&self.program.functions[id.0].name));
+    }
+
+    /// C name of a local, whose name is unique in its subprogram.
+    fn local_name(&self, local: LocalID) -> String {
+        return format!("v_{}", mangle(&self.function.locals[local.0].name));
+    }
+
+    fn label(block: BlockID) -> String {
+        return format!("b{}", block.0);
+    }
+
+    /// C expression of a descriptor of the type, a `const ila_type *`.
+    fn descriptor(&mut self, ty: &Type) -> String {
+
+        let (kind, inner) = match ty {
+            Type::Integer => return "&ila_type_integer".to_string(),
+            Type::Decimal => return "&ila_type_decimal".to_string(),
+            Type::Text => return "&ila_type_text".to_string(),
+            Type::Boolean => return "&ila_type_boolean".to_string(),
+            Type::Nothing => return "&ila_type_nothing".to_string(),
+            Type::List(_) => return "&ila_type_list".to_string(),
+            Type::Ref{inner, ..} => ("ref", inner),
+            Type::Comp{inner, ..} => ("comp", inner),
+            Type::Struct{..} | Type::Enum{..} => unreachable!("`compilar` rejects programs with structures and enumerations"),
+            Type::Function{..} | Type::Error => unreachable!("values of type `{}` are not generated", ty),
+        };
+
+        let inner_descriptor = self.descriptor(inner);
+        let name = format!("type_{}_{}", kind, inner_descriptor.trim_start_matches("&ila_type_").trim_start_matches("&type_"));
+        if self.descriptor_names.insert(name.clone()) {
+            let definition = format!(
+                "static const ila_type {name} = {{sizeof({c_type}), ila_{kind}_copy, ila_{kind}_drop, ila_{kind}_equals, ila_{kind}_write, {inner_descriptor}}};",
+                name = name, c_type = c_type(ty), kind = kind, inner_descriptor = inner_descriptor,
+            );
+            self.descriptors.push(definition);
+        }
+
+        return format!("&{}", name);
+    }
+
+    /// Name of the global with a text constant.
+    fn text(&mut self, value: &str) -> String {
+
+        if let Some(name) = self.text_names.get(value) {
+            return name.clone();
+        }
+
+        let name = format!("k{}", self.texts.len());
+        self.texts.push(value.to_string());
+        self.text_names.insert(value.to_string(), name.clone());
+        return name;
+    }
+
+    /// Name of the global with a number constant, created by `init`.
+    fn number(&mut self, ty: Type, init: String) -> String {
+
+        if let Some(name) = self.number_names.get(&init) {
+            return name.clone();
+        }
+
+        let name = format!("n{}", self.numbers.len());
+        self.number_names.insert(init.clone(), name.clone());
+        self.numbers.push((ty, init));
+        return name;
+    }
+
+    /* ---- operands ---- */
+
+    /// C expression with the value of an operand, which stays with its owner.
+    fn operand(&mut self, operand: &Operand) -> String {
+
+        return match operand {
+            Operand::Temp(temp) => format!("t{}", temp.0),
+            Operand::Constant(Constant::Integer(value)) => match value.to_i64() {
+                Some(value) => format!("ILA_INT({})", int64_literal(value)),
+                None => self.number(Type::Integer, format!("ila_int_parse(\"{}\")", value)),
+            },
+            Operand::Constant(Constant::Decimal(value)) => match value.digits().to_i64() {
+                Some(digits) => format!("ILA_DEC({}, {})", int64_literal(digits), value.scale()),
+                None => self.number(Type::Decimal, format!("ila_dec_parse(\"{}\", {})", value.digits(), value.scale())),
+            },
+            Operand::Constant(Constant::Text(value)) => self.text(value),
+            Operand::Constant(Constant::Boolean(value)) => value.to_string(),
+            Operand::Constant(Constant::Nothing) => "(ila_nothing)0".to_string(),
+        };
+    }
+
+    /// C expression with the value of an operand for a new owner of type `target`: temporaries
+    /// read once are moved, other values are copied, and a `comp` given where a `ref` is expected
+    /// becomes the address of its value.
+    fn owned_operand(&mut self, operand: &Operand, target: &Type) -> String {
+
+        let code = self.operand(operand);
+        return match (self.function.operand_type(operand), target) {
+            (Type::Comp{inner, ..}, Type::Ref{..}) => format!("({})ila_box_value({})", pointer_to(&inner), code),
+            _ => match operand {
+                Operand::Temp(temp) if self.moved[temp.0] => code,
+                _ => copy_code(&self.function.operand_type(operand), &code),
+            },
+        };
+    }
+
+    /// Address of the value of an operand, for the functions of the runtime that take any type.
+    fn operand_address(&mut self, operand: &Operand) -> String {
+
+        let code = self.operand(operand);
+        return match operand {
+            // number constants are compound literals or globals, which have an address
+            Operand::Temp(_) | Operand::Constant(Constant::Integer(_) | Constant::Decimal(_) | Constant::Text(_)) => format!("&{}", code),
+            Operand::Constant(constant) => format!("&({}){{{}}}", c_type(&constant.ty()), code),
+        };
+    }
+
+    /// Address of the value of an operand added to a list of `item_type`, which the list copies.
+    fn item_address(&mut self, operand: &Operand, item_type: &Type) -> String {
+
+        return match (self.function.operand_type(operand), item_type) {
+            (Type::Comp{inner, ..}, Type::Ref{..}) => {
+                let code = self.operand(operand);
+                format!("&({}){{ila_box_value({})}}", pointer_to(&inner), code)
+            },
+            _ => self.operand_address(operand),
+        };
+    }
+
+    /// The place a reference of type `ty` points to.
+    fn pointed_place(code: &str, ty: &Type) -> (String, Type) {
+
+        return match ty {
+            Type::Ref{inner, ..} => (format!("(*{})", code), inner.as_ref().clone()),
+            Type::Comp{inner, ..} => (format!("(*({})ila_box_value({}))", pointer_to(inner), code), inner.as_ref().clone()),
+            other => unreachable!("only references point to places, found {}", other),
+        };
+    }
+
+    /// The place of the value after following every reference of an operand.
+    fn dereferenced_place(&mut self, operand: &Operand) -> (String, Type) {
+
+        let mut code = self.operand(operand);
+        let mut ty = self.function.operand_type(operand);
+        while is_reference(&ty) {
+            (code, ty) = Self::pointed_place(&code, &ty);
+        }
+
+        return (code, ty);
+    }
+
+    /// Assigns a new value to a temporary, freeing the one it had when it runs again in a loop.
+    fn assign(&mut self, dest: Temp, init: &str) {
+
+        if !self.moved[dest.0] {
+            if let Some(drop) = drop_code(self.function.temp_type(dest), &format!("t{}", dest.0)) {
+                self.line(&drop);
+            }
+        }
+        self.line(&format!("t{} = {};", dest.0, init));
+    }
+
+    /// Replaces the value of a place that owns it.
+    fn replace(&mut self, place: &str, ty: &Type, value: &str) {
+
+        if let Some(drop) = drop_code(ty, place) {
+            self.line(&drop);
+        }
+        self.line(&format!("{} = {};", place, value));
+    }
+
+    /* ---- instructions ---- */
+
+    fn instruction(&mut self, kind: &InstructionKind, lexical_info: &LexicalInfo) {
+
+        let function = self.function;
+        match kind {
+            InstructionKind::Copy{dest, value} => {
+                let value = self.owned_operand(value, function.temp_type(*dest));
+                self.assign(*dest, &value);
+            },
+            InstructionKind::LoadLocal{dest, local} => {
+                let value = copy_code(&function.locals[local.0].ty, &self.local_name(*local));
+                self.assign(*dest, &value);
+            },
+            InstructionKind::StoreLocal{local, value} => {
+                let value = self.owned_operand(value, &function.locals[local.0].ty);
+                self.replace(&self.local_name(*local), &function.locals[local.0].ty, &value);
+            },
+            InstructionKind::DeclareLocal{local, value} => {
+                // every time the declaration runs, the variable starts again
+                let value = match value {
+                    Some(value) => self.owned_operand(value, &function.locals[local.0].ty),
+                    None => zero_value(&function.locals[local.0].ty).to_string(),
+                };
+                self.replace(&self.local_name(*local), &function.locals[local.0].ty, &value);
+            },
+            InstructionKind::LocalRef{dest, local} => self.assign(*dest, &format!("&{}", self.local_name(*local))),
+            InstructionKind::Load{dest, pointer} => {
+                let pointer_code = self.operand(pointer);
+                let (place, ty) = Self::pointed_place(&pointer_code, &function.operand_type(pointer));
+                self.assign(*dest, &copy_code(&ty, &place));
+            },
+            InstructionKind::Store{pointer, value} => {
+                let pointer_code = self.operand(pointer);
+                let (place, ty) = Self::pointed_place(&pointer_code, &function.operand_type(pointer));
+                let value = self.owned_operand(value, &ty);
+                self.replace(&place, &ty, &value);
+            },
+            InstructionKind::Dereference{dest, value} => {
+                let (place, ty) = self.dereferenced_place(value);
+                self.assign(*dest, &copy_code(&ty, &place));
+            },
+            InstructionKind::Box{dest, value} => match function.temp_type(*dest) {
+                Type::Comp{inner, ..} => {
+                    let init = format!("ila_box_new({}, {})", self.descriptor(inner), self.operand_address(value));
+                    self.assign(*dest, &init);
+                },
+                // the storage of a `ref` to a value that is not a place of the program
+                _ => {
+                    let value_code = self.owned_operand(value, &function.operand_type(value));
+                    let storage = format!("s{}", dest.0);
+                    self.replace(&storage, &function.operand_type(value), &value_code);
+                    self.assign(*dest, &format!("&{}", storage));
+                },
+            },
+            InstructionKind::MakeList{dest, items} => {
+                let item_type = match function.temp_type(*dest) {
+                    Type::List(item_type) => item_type.as_ref().clone(),
+                    other => unreachable!("lists have list types, found {}", other),
+                };
+                let init = format!("ila_list_new({})", self.descriptor(&item_type));
+                self.assign(*dest, &init);
+                for item in items {
+                    let item = self.item_address(item, &item_type);
+                    self.line(&format!("ila_list_push(t{}, {});", dest.0, item));
+                }
+            },
+            InstructionKind::Unary{dest, oprt, operand} => {
+                let value = self.operand(operand);
+                let init = match (oprt, function.temp_type(*dest)) {
+                    (UnaryOprt::Neg, Type::Integer) => format!("ila_neg({})", value),
+                    (UnaryOprt::Neg, _) => format!("ila_dec_neg({})", value),
+                    _ => format!("!{}", value),
+                };
+                self.assign(*dest, &init);
+            },
+            InstructionKind::Binary{dest, oprt, left, right} => {
+                let init = self.binary(*oprt, left, right, lexical_info);
+                self.assign(*dest, &init);
+            },
+            InstructionKind::Call{dest, function: callee, args} => {
+                let params = &self.program.functions[callee.0].locals;
+                let args: Vec<String> = args.iter().zip(params).map(|(arg, param)| self.owned_operand(arg, &param.ty)).collect();
+                self.line(&format!("ila_enter({});", self.location(lexical_info)));
+                self.assign(*dest, &format!("{}({})", self.function_name(*callee), args.join(", ")));
+                self.line("ila_leave();");
+            },
+            InstructionKind::CallBuiltin{dest, builtin, args} => self.call_builtin(*dest, *builtin, args, lexical_info),
+            InstructionKind::Items{dest, iterated} => {
+                // the items are the ones the value has when the loop starts
+                let (place, ty) = self.dereferenced_place(iterated);
+                let init = match ty {
+                    Type::Text => format!("ila_text_chars({})", place),
+                    _ => format!("ila_list_retain({})", place),
+                };
+                self.assign(*dest, &init);
+            },
+            InstructionKind::ItemCount{dest, items} => {
+                let items = self.operand(items);
+                self.assign(*dest, &format!("ila_list_length({})", items));
+            },
+            InstructionKind::ItemRef{dest, items, index} => {
+                let items = self.operand(items);
+                let index = self.operand(index);
+                // the position of an item always fits in 64 bits
+                self.assign(*dest, &format!("({})ila_list_item({}, (size_t){}.small)", c_type(function.temp_type(*dest)), items, index));
+            },
+            InstructionKind::MakeStruct{..} |
+            InstructionKind::FieldRef{..} |
+            InstructionKind::MakeVariant{..} |
+            InstructionKind::Tag{..} |
+            InstructionKind::Payload{..} => unreachable!("`compilar` rejects programs with structures and enumerations"),
+        }
+    }
+
+    fn binary(&mut self, oprt: BinaryOprt, left: &Operand, right: &Operand, lexical_info: &LexicalInfo) -> String {
+
+        let left_type = self.function.operand_type(left);
+        let right_type = self.function.operand_type(right);
+        let l = self.operand(left);
+        let r = self.operand(right);
+
+        // integers mixed with decimals are converted to decimals, and numbers are compared as
+        // decimals, like the interpreter
+        let both_integers = left_type == Type::Integer && right_type == Type::Integer;
+        let as_decimal = |code: &str, ty: &Type| match ty {
+            Type::Integer => format!("ila_dec_of({})", code),
+            _ => code.to_string(),
+        };
+        let (dl, dr) = (as_decimal(&l, &left_type), as_decimal(&r, &right_type));
+        let compare = match both_integers {
+            true => format!("ila_int_compare({}, {})", l, r),
+            false => format!("ila_dec_compare({}, {})", dl, dr),
+        };
+
+        return match oprt {
+            BinaryOprt::Eq | BinaryOprt::Neq => {
+                let equals = match &left_type {
+                    _ if both_integers => format!("ila_int_equals({}, {})", l, r),
+                    _ if left_type.is_numeric() => format!("{} == 0", compare),
+                    Type::Text => format!("ila_text_equals({}, {})", l, r),
+                    Type::List(_) => format!("ila_list_equals({}, {})", l, r),
+                    _ => format!("{} == {}", l, r),
+                };
+                match oprt {
+                    BinaryOprt::Eq => equals,
+                    _ => format!("!({})", equals),
+                }
+            },
+            BinaryOprt::Lt | BinaryOprt::Le | BinaryOprt::Gt | BinaryOprt::Ge => format!("{} {} 0", compare, oprt.symbol()),
+            BinaryOprt::Add if left_type == Type::Text => format!("ila_text_concat({}, {})", l, r),
+            BinaryOprt::Div if both_integers => format!("ila_div({}, {}, {})", l, r, self.location(lexical_info)),
+            BinaryOprt::Div => format!("ila_div_decimal({}, {}, {})", dl, dr, self.location(lexical_info)),
+            BinaryOprt::Add | BinaryOprt::Sub | BinaryOprt::Mul => {
+                let function = match oprt {
+                    BinaryOprt::Add => "add",
+                    BinaryOprt::Sub => "sub",
+                    _ => "mul",
+                };
+                match both_integers {
+                    true => format!("ila_{}({}, {})", function, l, r),
+                    false => format!("ila_dec_{}({}, {})", function, dl, dr),
+                }
+            },
+            BinaryOprt::And | BinaryOprt::Or | BinaryOprt::Assign => unreachable!("`{}` does not evaluate both operands first", oprt.symbol()),
+        };
+    }
+
+    /// Builtins only look at their arguments, which stay with their owners.
+    fn call_builtin(&mut self, dest: Temp, builtin: Builtin, args: &[Operand], lexical_info: &LexicalInfo) {
+
+        match builtin {
+            Builtin::Write => {
+                let descriptor = self.descriptor(&self.function.operand_type(&args[0]));
+                let value = self.operand_address(&args[0]);
+                self.line(&format!("ila_write({}, {});", descriptor, value));
+            },
+            Builtin::Read => self.assign(dest, "ila_read_line()"),
+            Builtin::Length => {
+                let (place, ty) = self.dereferenced_place(&args[0]);
+                let init = match ty {
+                    Type::Text => format!("ila_text_length({})", place),
+                    _ => format!("ila_list_length({})", place),
+                };
+                self.assign(dest, &init);
+            },
+            Builtin::Append => {
+                let (list, list_type) = self.dereferenced_place(&args[0]);
+                let item_type = match list_type {
+                    Type::List(item_type) => *item_type,
+                    other => unreachable!("`adicionar` adds to lists, found {}", other),
+                };
+                let item = self.item_address(&args[1], &item_type);
+                self.line(&format!("ila_list_push({}, {});", list, item));
+            },
+            Builtin::Get => {
+                let ty = self.function.temp_type(dest).clone();
+                let (list, _) = self.dereferenced_place(&args[0]);
+                let index = self.operand(&args[1]);
+                let item = format!("*({})ila_list_get({}, {}, {})", pointer_to(&ty), list, index, self.location(lexical_info));
+                self.assign(dest, &copy_code(&ty, &item));
+            },
+            Builtin::ToText => {
+                let descriptor = self.descriptor(&self.function.operand_type(&args[0]));
+                let value = self.operand_address(&args[0]);
+                self.assign(dest, &format!("ila_to_text({}, {})", descriptor, value));
+            },
+        }
+    }
+
+    /// Generates the end of a block, where `next` is the block generated after it.
+    fn terminator(&mut self, terminator: &Terminator, next: Option<BlockID>) {
+
+        match terminator {
+            Terminator::Jump(target) => {
+                if Some(*target) != next {
+                    self.jump_targets.insert(*target);
+                    self.line(&format!("goto {};", Self::label(*target)));
+                }
+            },
+            Terminator::Branch{condition, then_block, else_block} => {
+                let condition = self.operand(condition);
+                match Some(*then_block) == next {
+                    true => {
+                        self.jump_targets.insert(*else_block);
+                        self.line(&format!("if (!{}) goto {};", condition, Self::label(*else_block)));
+                    },
+                    false => {
+                        self.jump_targets.insert(*then_block);
+                        self.line(&format!("if ({}) goto {};", condition, Self::label(*then_block)));
+                        if Some(*else_block) != next {
+                            self.jump_targets.insert(*else_block);
+                            self.line(&format!("goto {};", Self::label(*else_block)));
+                        }
+                    },
+                }
+            },
+            Terminator::Return(value) => {
+                let value = self.owned_operand(value, &self.function.return_type);
+                self.line(&format!("result = {};", value));
+                if next.is_some() {
+                    self.line("goto end;");
+                }
+            },
+        }
+    }
+
+    /* ---- subprograms ---- */
+
+    fn signature(&self, id: FunctionID) -> String {
+
+        let function = &self.program.functions[id.0];
+        let params: Vec<String> = function.locals[..function.param_count].iter()
+            .map(|local| declaration(&local.ty, &format!("v_{}", mangle(&local.name))))
+            .collect();
+        let params = if params.is_empty() { "void".to_string() } else { params.join(", ") };
+
+        return format!("static {}({})", declaration(&function.return_type, &self.function_name(id)), params);
+    }
+
+    fn subprogram(&mut self, id: FunctionID) -> String {
+
+        let function = &self.program.functions[id.0];
+        self.function = function;
+        self.moved = moved_temps(function);
+        self.jump_targets.clear();
+
+        // each block is generated on its own, and gets a label when something jumps to it
+        let layout = function.layout_order();
+        let mut blocks = Vec::with_capacity(layout.len());
+        for (position, block_id) in layout.iter().enumerate() {
+            let block = function.block(*block_id);
+            self.code.clear();
+            for instruction in &block.instructions {
+                self.instruction(&instruction.kind, &instruction.lexical_info);
+            }
+            self.terminator(&block.terminator, layout.get(position + 1).copied());
+            blocks.push(std::mem::take(&mut self.code));
+        }
+
+        // every variable lives in the whole subprogram, and is freed when it returns
+        let mut declarations = String::new();
+        let mut drops = String::new();
+        let mut add_variable = |ty: &Type, name: String, is_param: bool, is_owner: bool| {
+            if !is_param {
+                writeln!(declarations, "    {} = {};", declaration(ty, &name), zero_value(ty)).expect("writing to a string does not fail");
+            }
+            if let Some(drop) = drop_code(ty, &name).filter(|_| is_owner) {
+                writeln!(drops, "    {}", drop).expect("writing to a string does not fail");
+            }
+        };
+        for (index, local) in function.locals.iter().enumerate() {
+            add_variable(&local.ty, format!("v_{}", mangle(&local.name)), index < function.param_count, true);
+        }
+        for (index, ty) in function.temps.iter().enumerate() {
+            add_variable(ty, format!("t{}", index), false, !self.moved[index]);
+        }
+        for block in &function.blocks {
+            for instruction in &block.instructions {
+                if let InstructionKind::Box{dest, value} = &instruction.kind {
+                    if matches!(function.temp_type(*dest), Type::Ref{..}) {
+                        add_variable(&function.operand_type(value), format!("s{}", dest.0), false, true);
+                    }
+                }
+            }
+        }
+
+        let mut body = String::new();
+        for (block_id, code) in layout.iter().zip(blocks) {
+            if self.jump_targets.contains(block_id) {
+                writeln!(body, "{}:", Self::label(*block_id)).expect("writing to a string does not fail");
+            }
+            body.push_str(&code);
+        }
+
+        return format!("{} {{\n\n{}    {} = {};\n{}end:\n{}    return result;\n}}\n",
+            self.signature(id), declarations, declaration(&function.return_type, "result"), zero_value(&function.return_type), body, drops);
+    }
+}
+
+/// Generates a C99 program from a lowered program without errors, starting at its entry point.
+/// It is compiled together with the runtime in `runtime/c`.
+pub fn generate(program: &Program, source_name: &str) -> String {
+
+    let entry_point = program.entry_point.expect("programs with an entry point are compiled");
+    let mut generator = Generator{
+        program,
+        descriptors: Vec::new(),
+        descriptor_names: HashSet::new(),
+        texts: Vec::new(),
+        text_names: HashMap::new(),
+        numbers: Vec::new(),
+        number_names: HashMap::new(),
+        function: &program.functions[entry_point.0],
+        code: String::new(),
+        moved: Vec::new(),
+        jump_targets: HashSet::new(),
+    };
+
+    let ids: Vec<FunctionID> = (0..program.functions.len()).map(FunctionID).collect();
+    let mut prototypes = String::new();
+    for id in &ids {
+        writeln!(prototypes, "{};", generator.signature(*id)).expect("writing to a string does not fail");
+    }
+    let functions: Vec<String> = ids.iter().map(|id| generator.subprogram(*id)).collect();
+
+    let mut output = String::new();
+    writeln!(output, "/* generated by `ila compilar` from {} */\n", source_name.replace("*/", "* /")).expect("writing to a string does not fail");
+    output.push_str("#include \"ila_runtime.h\"\n\n");
+    for descriptor in &generator.descriptors {
+        writeln!(output, "{}", descriptor).expect("writing to a string does not fail");
+    }
+    for (index, _) in generator.texts.iter().enumerate() {
+        writeln!(output, "static ila_text *k{};", index).expect("writing to a string does not fail");
+    }
+    for (index, (ty, _)) in generator.numbers.iter().enumerate() {
+        writeln!(output, "static {};", declaration(ty, &format!("n{}", index))).expect("writing to a string does not fail");
+    }
+    if !generator.descriptors.is_empty() || !generator.texts.is_empty() || !generator.numbers.is_empty() {
+        output.push('\n');
+    }
+    writeln!(output, "{}", prototypes).expect("writing to a string does not fail");
+    for function in functions {
+        writeln!(output, "{}", function).expect("writing to a string does not fail");
+    }
+    writeln!(output, "int main(void) {{\n\n    ila_start({});", string_literal(source_name)).expect("writing to a string does not fail");
+    for (index, text) in generator.texts.iter().enumerate() {
+        writeln!(output, "    k{} = ila_text_new({}, {});", index, string_literal(text), text.len()).expect("writing to a string does not fail");
+    }
+    for (index, (_, init)) in generator.numbers.iter().enumerate() {
+        writeln!(output, "    n{} = {};", index, init).expect("writing to a string does not fail");
+    }
+    writeln!(output, "    (void){}();\n    return ila_finish();\n}}", generator.function_name(entry_point)).expect("writing to a string does not fail");
+
+    return output;
+}
+
+/// Compiles a generated program with the C compiler of the system into an executable at `output`.
+pub fn build_executable(c_source: &str, output: &Path) -> Result<()> {
+
+    let directory = std::env::temp_dir().join(format!("ila-{}-{}", std::process::id(), output.file_name().and_then(|name| name.to_str()).unwrap_or("programa")));
+    std::fs::create_dir_all(&directory)
+        .with_context(|| format!("não foi possível criar a pasta temporária `{}`", directory.display()))?;
+
+    let result = compile_in(&directory, c_source, output);
+    let _ = std::fs::remove_dir_all(&directory);
+
+    return result;
+}
+
+fn compile_in(directory: &Path, c_source: &str, output: &Path) -> Result<()> {
+
+    let write = |name: &str, content: &str| {
+        std::fs::write(directory.join(name), content)
+            .with_context(|| format!("não foi possível escrever o arquivo `{}`", directory.join(name).display()))
+    };
+    write("programa.c", c_source)?;
+    write("ila_runtime.h", RUNTIME_HEADER)?;
+    write("ila_runtime.c", RUNTIME_SOURCE)?;
+
+    let compiler = std::env::var("CC").unwrap_or_else(|_| DEFAULT_C_COMPILER.to_string());
+    let result = Command::new(&compiler)
+        .args(["-std=c99", "-O2", "-o"])
+        .arg(output)
+        .arg(directory.join("programa.c"))
+        .arg(directory.join("ila_runtime.c"))
+        .arg("-lm")
+        .output()
+        .with_context(|| format!("não foi possível executar o compilador de C `{}`", compiler))?;
+
+    if !result.status.success() {
+        bail!("o compilador de C `{}` falhou:\n{}", compiler, String::from_utf8_lossy(&result.stderr));
+    }
+
+    return Ok(());
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::analysis::{analyze, AnalysisSettings};
+    use crate::optimizer::{self, OptimizationLevel};
+    use crate::tso_parser::parse_source;
+    use crate::{bytecode, ir, vm};
+    use std::io::Write;
+    use std::process::Stdio;
+
+
+    fn generate_source(source_code: &str) -> String {
+        return generate_optimized(source_code, OptimizationLevel::O0);
+    }
+
+    fn generate_optimized(source_code: &str, level: OptimizationLevel) -> String {
+
+        let output = parse_source(source_code).unwrap();
+        let analysis = analyze(&output.ast, &AnalysisSettings::default());
+        assert!(!output.has_errors() && !analysis.has_errors());
+
+        let mut program = ir::lower(&output.ast, &analysis.resolution, &analysis.types);
+        optimizer::optimize(&mut program, level);
+        return generate(&program, "teste.ila");
+    }
+
+    /// What the virtual machine writes when running the program, and the code of its error.
+    fn run_on_vm(source_code: &str, input: &str) -> (String, Option<&'static str>) {
+
+        let output = parse_source(source_code).unwrap();
+        let analysis = analyze(&output.ast, &AnalysisSettings::default());
+        let bytecode = bytecode::compile(&ir::lower(&output.ast, &analysis.resolution, &analysis.types));
+
+        let mut written = Vec::new();
+        let result = vm::execute(&bytecode, &mut input.as_bytes(), &mut written);
+        return (String::from_utf8(written).unwrap(), result.err().map(|error| error.to_diagnostic().code));
+    }
+
+    fn run_compiled(name: &str, source_code: &str, input: &str) -> Option<(String, Option<String>)> {
+        return run_generated(name, &generate_source(source_code), input);
+    }
+
+    /// What the program compiled from the generated code writes, and the code of its error, or
+    /// nothing when there is no C compiler and `ILA_SEM_COMPILADOR_C` asks to skip these tests.
+    fn run_generated(name: &str, code: &str, input: &str) -> Option<(String, Option<String>)> {
+
+        // skipping must be asked for, so a missing compiler does not make the tests pass unnoticed
+        let compiler = std::env::var("CC").unwrap_or_else(|_| DEFAULT_C_COMPILER.to_string());
+        if Command::new(&compiler).arg("--version").output().is_err() {
+            if std::env::var_os("ILA_SEM_COMPILADOR_C").is_some() {
+                eprintln!("sem compilador de C, o teste `{}` não foi executado", name);
+                return None;
+            }
+            panic!("the C compiler `{}` was not found: set `CC`, or `ILA_SEM_COMPILADOR_C=1` to skip the tests that need it", compiler);
+        }
+
+        let executable = std::env::temp_dir().join(format!("ila-teste-{}-{}", std::process::id(), name));
+        build_executable(code, &executable).unwrap();
+
+        let mut child = Command::new(&executable)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+        let result = child.wait_with_output().unwrap();
+        let _ = std::fs::remove_file(&executable);
+
+        let stderr = String::from_utf8(result.stderr).unwrap();
+        let error_code = match result.status.code() {
+            Some(0) => None,
+            Some(3) => Some(stderr[stderr.find('[').unwrap() + 1..stderr.find(']').unwrap()].to_string()),
+            other => panic!("the compiled program ended with {:?}: {}", other, stderr),
+        };
+
+        return Some((String::from_utf8(result.stdout).unwrap(), error_code));
+    }
+
+    fn assert_same_as_vm(name: &str, source_code: &str, input: &str) {
+
+        let Some((written, error_code)) = run_compiled(name, source_code, input) else { return };
+        let (expected, expected_error) = run_on_vm(source_code, input);
+        assert_eq!(written, expected);
+        assert_eq!(error_code.as_deref(), expected_error);
+    }
+
+    #[test]
+    fn test_generate() {
+
+        let source_code = "func média(a: inteiro, b: decimal) -> decimal { retornar a + b; } procedimento principal() {}";
+        let c_source = generate_source(source_code);
+
+        assert!(c_source.contains("static ila_dec f_m_u00e9dia(ila_int v_a, ila_dec v_b);"));
+        assert!(c_source.contains("static ila_nothing f_principal(void);"));
+        assert!(c_source.contains("(void)f_principal();"));
+    }
+
+    #[test]
+    fn test_values() {
+
+        let source_code = r#"
+            func soma(itens: ref lista<inteiro>) -> inteiro {
+                var mut total: inteiro = 0;
+                para cada item em itens { total = total + item; }
+                retornar total;
+            }
+
+            procedimento dobrar(mut itens: mut ref lista<inteiro>) {
+                para cada mut ref item em itens { *item = *item * 2; }
+            }
+
+            procedimento principal() {
+                var mut números: lista<inteiro> = [1, 2, 3];
+                var cópia: lista<inteiro> = números;
+                adicionar(números, 4);
+                dobrar(números);
+                escrever(números);
+                escrever(cópia);
+                escrever(soma(números));
+                escrever(obter(números, 3) / 3);
+                escrever(7 / 2.0);
+                escrever(1.0 / 8.0);
+                escrever(3 == 3.0);
+                escrever([["a", "b"], ["c"]]);
+                var mut frase: texto = "olá";
+                para cada letra em frase { frase = frase + letra; }
+                escrever(frase);
+                escrever(tamanho(frase));
+                escrever(para_texto(verdadeiro) + " " + para_texto(1.5));
+                var nome: texto = ler();
+                escrever("oi, " + nome);
+                escrever("{nome} tem {tamanho(nome)} letras\t\"{números}\" {1.5} \u{E9}\\ \{");
+            }
+        "#;
+
+        assert_same_as_vm("valores", source_code, "mundo\n");
+    }
+
+    #[test]
+    fn test_references() {
+
+        // a `comp` given where a `ref` is expected points to its value
+        let source_code = r#"
+            procedimento incrementar(mut valor: mut ref inteiro) {
+                *valor = *valor + 1;
+            }
+
+            func ler_valor(valor: comp inteiro) -> inteiro {
+                retornar *valor;
+            }
+
+            func somar(itens: ref lista<inteiro>) -> inteiro {
+                var mut total: inteiro = 0;
+                para cada item em itens { total = total + item; }
+                retornar total;
+            }
+
+            procedimento principal() {
+                var mut contador: inteiro = 0;
+                incrementar(contador);
+                incrementar(contador);
+                escrever(contador);
+
+                var compartilhado: mut comp inteiro = 10;
+                var outro: mut comp inteiro = compartilhado;
+                *outro = 20;
+                escrever(*compartilhado);
+                escrever(ler_valor(compartilhado));
+
+                var mut caixas: lista<comp texto> = ["a", "b"];
+                escrever(caixas);
+                escrever(verdadeiro e não falso ou falso);
+
+                var números: mut comp lista<inteiro> = [1, 2, 3];
+                escrever(somar(*números) + somar(números));
+                incrementar(compartilhado);
+                var lido: ref inteiro = compartilhado;
+                var mut lidos: lista<ref inteiro> = [compartilhado, *outro];
+                adicionar(lidos, compartilhado);
+                escrever(*lido);
+                escrever(lidos);
+            }
+        "#;
+
+        assert_same_as_vm("referencias", source_code, "");
+    }
+
+    #[test]
+    fn test_iteration() {
+
+        // the items are the ones the list has when the loop starts, and keep their storage
+        // while the list grows
+        let source_code = r#"
+            procedimento principal() {
+                var mut l: lista<inteiro> = [1, 2, 3];
+                para cada x em l { l = [9]; escrever(x); }
+                escrever(l);
+
+                var mut m: lista<inteiro> = [1, 2, 3];
+                para cada mut ref x em m { adicionar(m, 7); *x = *x * 10; }
+                escrever(m);
+
+                var mut aninhada: lista<lista<texto>> = [["a"], ["b"]];
+                para cada mut ref itens em aninhada { adicionar(*itens, "z"); adicionar(aninhada, ["w"]); }
+                escrever(aninhada);
+            }
+        "#;
+
+        assert_same_as_vm("iteracao", source_code, "");
+    }
+
+    #[test]
+    fn test_optimized_programs() {
+
+        let source_code = r#"
+            func dobro(x: inteiro) -> inteiro {
+                retornar x * 2;
+            }
+
+            procedimento acumular(mut total: mut ref inteiro, itens: lista<inteiro>) {
+                para cada item em itens {
+                    se item == 1 { continuar; }
+                    *total = *total + dobro(item);
+                }
+            }
+
+            procedimento principal() {
+                var mut total: inteiro = 2 * 3 - 6;
+                var mut itens: lista<inteiro> = [3, 1, 4];
+                var copia: lista<inteiro> = itens;
+                adicionar(itens, dobro(5));
+                acumular(total, itens);
+                escrever(total);
+                escrever(copia);
+                escrever("a" + "b" == "ab" e não falso);
+                escrever(total / (total - total));
+            }
+        "#;
+
+        let (expected, expected_error) = run_on_vm(source_code, "");
+        for level in [OptimizationLevel::O1, OptimizationLevel::O2] {
+            let name = format!("otimizado-{:?}", level);
+            let Some((written, error_code)) = run_generated(&name, &generate_optimized(source_code, level), "") else { return };
+            assert_eq!(written, expected, "{:?}", level);
+            assert_eq!(error_code.as_deref(), expected_error, "{:?}", level);
+        }
+    }
+
+    #[test]
+    fn test_numbers() {
+
+        // integers have no limit and decimals keep their digits, like in the interpreter
+        let source_code = r#"
+            func fatorial(n: inteiro) -> inteiro {
+                se n <= 1 { retornar 1; }
+                retornar n * fatorial(n - 1);
+            }
+
+            procedimento principal() {
+                escrever(fatorial(50));
+                escrever(0.1 + 0.2);
+                escrever(fatorial(30) / fatorial(28) - 9223372036854775807 * 2);
+                escrever(-9223372036854775808 - 1);
+                escrever(-fatorial(21) / 7);
+                escrever(99999999999999999999 == 99999999999999999999);
+                escrever(1.0 / 3.0);
+                escrever(2 / -3.0 * 1e40);
+                escrever(10 == 10.0);
+                escrever(fatorial(25) > 1.5e25);
+                escrever([123456789012345678901234567890, -1]);
+                escrever(para_texto(12.50) + " " + para_texto(-0.001));
+                var mut total: decimal = 0.0;
+                var mut i: inteiro = 0;
+                enquanto i < 10 { total = total + 0.1; i = i + 1; }
+                escrever(total);
+            }
+        "#;
+
+        let Some((written, error_code)) = run_compiled("numeros", source_code, "") else { return };
+        assert!(written.starts_with("30414093201713378043612608166064768844377641568960512000000000000\n0.3\n"), "{}", written);
+        assert_eq!(written, run_on_vm(source_code, "").0);
+        assert_eq!(error_code, None);
+    }
+
+    #[test]
+    fn test_runtime_errors() {
+
+        let division = "procedimento principal() { escrever(1); var zero: inteiro = 0; escrever(1 / zero); }";
+        assert_same_as_vm("divisao", division, "");
+
+        let index = "procedimento principal() { var itens: lista<inteiro> = [1]; escrever(obter(itens, 1)); }";
+        assert_same_as_vm("posicao", index, "");
+
+        let large_index = "procedimento principal() { var itens: lista<inteiro> = [1]; escrever(obter(itens, -99999999999999999999)); }";
+        assert_same_as_vm("posicao-grande", large_index, "");
+
+        let recursion = "procedimento infinito(n: inteiro) { infinito(n + 1); } procedimento principal() { infinito(0); }";
+        assert_same_as_vm("recursao", recursion, "");
+    }
+
+    #[test]
+    fn test_control_flow() {
+
+        let source_code = r#"
+            func fatorial(n: inteiro) -> inteiro {
+                se n <= 1 { retornar 1; }
+                retornar n * fatorial(n - 1);
+            }
+
+            func primeiro_par(itens: lista<inteiro>) -> inteiro {
+                para cada item em itens {
+                    se item / 2 * 2 == item { retornar item; }
+                }
+                retornar -1;
+            }
+
+            func classificar(n: inteiro) -> texto {
+                se n < 0 { retornar "negativo"; } senão se n == 0 { retornar "zero"; } senão { retornar "positivo"; }
+            }
+
+            procedimento principal() {
+                para cada n em [-1, 0, 1] { escrever(classificar(n)); }
+                var mut i: inteiro = 0;
+                enquanto i < 10 {
+                    i = i + 1;
+                    se i == 3 { continuar; }
+                    se i == 6 { parar; }
+                    escrever(fatorial(i));
+                }
+                escrever(primeiro_par([3, 5, 8, 10]));
+                para cada palavra em ["x", "y", "z"] {
+                    se palavra == "y" { parar; }
+                    escrever(palavra);
+                }
+            }
+        "#;
+
+        assert_same_as_vm("controle", source_code, "");
+    }
+}
@@ -0,0 +1,586 @@
+
+// local
+use crate::ast::LexicalInfo;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                 diagnostics                                */
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+
+    pub fn name(&self) -> &'static str {
+
+        return match self {
+            Severity::Error => "erro",
+            Severity::Warning => "aviso",
+        };
+    }
+}
+
+/// A span of the source code, optionally with a message explaining its role in the diagnostic.
+#[derive(Clone, Debug)]
+pub struct Label {
+    pub lexical_info: LexicalInfo,
+    pub message: Option<String>,
+}
+
+/// A change to the source code that would fix the diagnostic: `replacement` takes the place of
+/// the text covered by `lexical_info`, an empty span means an insertion.
+#[derive(Clone, Debug)]
+pub struct Suggestion {
+    pub message: String,
+    pub lexical_info: LexicalInfo,
+    pub replacement: String,
+}
+
+/// An error or warning about the program, with every message written for beginners.
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: &'static str,
+    pub message: String,
+    pub primary: Label,
+    pub secondary: Vec<Label>,
+    pub notes: Vec<String>,
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl Diagnostic {
+
+    pub fn new(severity: Severity, code: &'static str, message: impl Into<String>, lexical_info: LexicalInfo) -> Self {
+
+        return Diagnostic{
+            severity,
+            code,
+            message: message.into(),
+            primary: Label{lexical_info, message: None},
+            secondary: Vec::new(),
+            notes: Vec::new(),
+            suggestions: Vec::new(),
+        };
+    }
+
+    pub fn error(code: &'static str, message: impl Into<String>, lexical_info: LexicalInfo) -> Self {
+        return Diagnostic::new(Severity::Error, code, message, lexical_info);
+    }
+
+    pub fn warning(code: &'static str, message: impl Into<String>, lexical_info: LexicalInfo) -> Self {
+        return Diagnostic::new(Severity::Warning, code, message, lexical_info);
+    }
+
+    pub fn with_label(mut self, message: impl Into<String>) -> Self {
+
+        self.primary.message = Some(message.into());
+        return self;
+    }
+
+    pub fn with_secondary(mut self, lexical_info: LexicalInfo, message: impl Into<String>) -> Self {
+
+        self.secondary.push(Label{lexical_info, message: Some(message.into())});
+        return self;
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+
+        self.notes.push(note.into());
+        return self;
+    }
+
+    pub fn with_suggestion(mut self, message: impl Into<String>, lexical_info: LexicalInfo, replacement: impl Into<String>) -> Self {
+
+        self.suggestions.push(Suggestion{message: message.into(), lexical_info, replacement: replacement.into()});
+        return self;
+    }
+
+    pub fn is_error(&self) -> bool {
+        return self.severity == Severity::Error;
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{}[{}]: {}", self.severity.name(), self.code, self.message);
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                 suggestions                                */
+/* -------------------------------------------------------------------------- */
+
+/// Damerau-Levenshtein distance between two names, counted in characters: swapping two
+/// neighbouring characters, a common typo, counts as a single edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut before_previous: Vec<usize> = Vec::new();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for i in 0..a.len() {
+
+        let mut current = vec![i + 1];
+        for j in 0..b.len() {
+
+            let substitution = previous[j] + if a[i] == b[j] { 0 } else { 1 };
+            let mut distance = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+            if i > 0 && j > 0 && a[i] == b[j - 1] && a[i - 1] == b[j] {
+                distance = distance.min(before_previous[j - 1] + 1);
+            }
+            current.push(distance);
+        }
+
+        before_previous = std::mem::replace(&mut previous, current);
+    }
+
+    return previous[b.len()];
+}
+
+/// Returns the candidate most similar to `name`, when it is similar enough to be what the user
+/// meant to write, for "você quis dizer...?" suggestions.
+pub fn closest_name<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    return candidates.into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, candidate)| candidate);
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  messages                                  */
+/* -------------------------------------------------------------------------- */
+
+/// A count followed by the singular or plural form of what is counted, like "1 argumento" or
+/// "2 argumentos".
+pub fn count(count: usize, singular: &str, plural: &str) -> String {
+
+    let noun = if count == 1 { singular } else { plural };
+    return format!("{} {}", count, noun);
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  rendering                                 */
+/* -------------------------------------------------------------------------- */
+
+struct Renderer<'a> {
+    lines: Vec<&'a str>,
+    gutter_width: usize,
+    output: String,
+}
+
+impl<'a> Renderer<'a> {
+
+    fn gutter(&mut self, line_number: Option<usize>) {
+
+        let number = line_number.map(|number| number.to_string()).unwrap_or_default();
+        self.output.push_str(&format!("{:>width$} | ", number, width = self.gutter_width));
+    }
+
+    fn empty_gutter(&mut self) {
+
+        self.output.push_str(&format!("{:>width$} |\n", "", width = self.gutter_width));
+    }
+
+    fn source_line(&self, line: usize) -> &'a str {
+        return self.lines.get(line).copied().unwrap_or("");
+    }
+
+    /// Writes a line below a source line, with each text starting at its byte column of `line`
+    /// or, when the texts before it already reach that column, right after them.
+    fn annotation(&mut self, line: &str, texts: &[(usize, String)]) {
+
+        self.gutter(None);
+
+        let mut annotation = String::new();
+        let mut column = 0;
+        for (start_column, text) in texts {
+
+            let start_column = (*start_column).max(column);
+            annotation.push_str(&padding(line, column, start_column));
+            annotation.push_str(text);
+            column = advance(line, start_column, text.chars().count());
+        }
+
+        self.output.push_str(annotation.trim_end());
+        self.output.push('\n');
+    }
+
+    /// Writes a source line once and marks the spans of every label starting on it. Spans covering
+    /// several lines are marked up to the end of their first line. The rightmost message follows
+    /// the markers, the others hang below them, from right to left:
+    ///
+    /// ```text
+    /// 2 |     a + b
+    ///   |     - ^ - direita
+    ///   |     |
+    ///   |     esquerda
+    /// ```
+    fn labels(&mut self, line_number: usize, labels: &[(&Label, char)]) {
+
+        let line = self.source_line(line_number);
+        self.gutter(Some(line_number + 1));
+        self.output.push_str(line.trim_end());
+        self.output.push('\n');
+
+        let mut markers: Vec<(usize, String)> = labels.iter()
+            .map(|(label, marker)| {
+                let start = label.lexical_info.start;
+                let end = label.lexical_info.end;
+                let end_column = if end.line == start.line { end.column } else { line.len() };
+                let width = char_width(line, start.column, end_column).max(1);
+                (start.column, marker.to_string().repeat(width))
+            })
+            .collect();
+
+        let (last_label, _) = labels[labels.len() - 1];
+        if let (Some(message), Some((_, last_marker))) = (&last_label.message, markers.last_mut()) {
+            last_marker.push(' ');
+            last_marker.push_str(message);
+        }
+        self.annotation(line, &markers);
+
+        let hanging: Vec<(usize, &String)> = labels[..labels.len() - 1].iter()
+            .filter_map(|(label, _)| label.message.as_ref().map(|message| (label.lexical_info.start.column, message)))
+            .collect();
+        if hanging.is_empty() {
+            return;
+        }
+
+        let connectors: Vec<(usize, String)> = hanging.iter().map(|(column, _)| (*column, "|".to_string())).collect();
+        self.annotation(line, &connectors);
+        for (index, (column, message)) in hanging.iter().enumerate().rev() {
+
+            let mut texts = connectors[..index].to_vec();
+            texts.push((*column, message.to_string()));
+            self.annotation(line, &texts);
+        }
+    }
+
+    /// Writes the source line with the suggestion applied, marking the text inserted with `+`, or
+    /// with `~` when it replaces other text. Suggestions that only remove text mark it with `-` on
+    /// the current line instead.
+    fn suggestion(&mut self, suggestion: &Suggestion) {
+
+        self.output.push_str(&format!("{:>width$} = sugestão: {}\n", "", suggestion.message, width = self.gutter_width));
+
+        let start = suggestion.lexical_info.start;
+        let end = suggestion.lexical_info.end;
+        if start.line != end.line || suggestion.replacement.contains('\n') {
+            return;
+        }
+
+        let line = self.source_line(start.line);
+        let start_column = start.column.min(line.len());
+        let end_column = end.column.clamp(start_column, line.len());
+        if !line.is_char_boundary(start_column) || !line.is_char_boundary(end_column) {
+            return;
+        }
+
+        // only the characters that differ from the current text are marked
+        let replaced = &line[start_column..end_column];
+        let replacement = suggestion.replacement.as_str();
+        let prefix: usize = replaced.chars().zip(replacement.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+        let suffix: usize = replaced[prefix..].chars().rev().zip(replacement[prefix..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+        let removed = &replaced[prefix..replaced.len() - suffix];
+        let inserted = &replacement[prefix..replacement.len() - suffix];
+
+        if inserted.is_empty() && !removed.is_empty() {
+            self.gutter(Some(start.line + 1));
+            self.output.push_str(line.trim_end());
+            self.output.push('\n');
+            self.annotation(line, &[(start_column + prefix, "-".repeat(removed.chars().count()))]);
+            return;
+        }
+
+        let fixed_line = format!("{}{}{}", &line[..start_column], replacement, &line[end_column..]);
+        let marker = if removed.is_empty() { "+" } else { "~" };
+
+        self.gutter(Some(start.line + 1));
+        self.output.push_str(fixed_line.trim_end());
+        self.output.push('\n');
+        self.annotation(&fixed_line, &[(start_column + prefix, marker.repeat(inserted.chars().count().max(1)))]);
+    }
+}
+
+/// Whitespace that goes from byte column `from` to byte column `to` of `line`, keeping tabs so
+/// markers line up in any terminal.
+fn padding(line: &str, from: usize, to: usize) -> String {
+
+    let mut padding: String = line.char_indices()
+        .filter(|(index, _)| *index >= from && *index < to)
+        .map(|(_, c)| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    padding.push_str(&" ".repeat(to.saturating_sub(from.max(line.len()))));
+
+    return padding;
+}
+
+/// Byte column of `line` that is `chars` characters after `column`, counting one byte per
+/// character past the end of the line.
+fn advance(line: &str, column: usize, chars: usize) -> usize {
+
+    let in_line = line.char_indices()
+        .map(|(index, _)| index)
+        .filter(|index| *index >= column)
+        .nth(chars);
+
+    return in_line.unwrap_or(column.max(line.len()) + chars - char_width(line, column, line.len()));
+}
+
+/// Character column of a byte column of `line`, as editors count them.
+fn char_column(line: &str, column: usize) -> usize {
+    return char_width(line, 0, column) + column.saturating_sub(line.len());
+}
+
+/// Number of characters between two byte offsets of `line`.
+fn char_width(line: &str, start_column: usize, end_column: usize) -> usize {
+
+    return line.char_indices()
+        .filter(|(index, _)| *index >= start_column && *index < end_column)
+        .count();
+}
+
+/// Renders a diagnostic with the offending source lines, in the format:
+///
+/// ```text
+/// erro[E0001]: trecho de código inválido
+///  --> programa.ila:2:7
+///   |
+/// 2 |     1 +;
+///   |       ^ aqui
+/// ```
+pub fn render(diagnostic: &Diagnostic, source: &str, file_name: &str) -> String {
+
+    let mut labels: Vec<(&Label, char)> = vec![(&diagnostic.primary, '^')];
+    labels.extend(diagnostic.secondary.iter().map(|label| (label, '-')));
+    labels.sort_by_key(|(label, _)| (label.lexical_info.start.line, label.lexical_info.start.column));
+
+    let last_line = labels.iter()
+        .map(|(label, _)| label.lexical_info.start.line)
+        .chain(diagnostic.suggestions.iter().map(|suggestion| suggestion.lexical_info.start.line))
+        .max()
+        .unwrap_or(0);
+
+    let mut renderer = Renderer{
+        lines: source.lines().collect(),
+        gutter_width: (last_line + 1).to_string().len(),
+        output: String::new(),
+    };
+
+    let start = diagnostic.primary.lexical_info.start;
+    let column = char_column(renderer.source_line(start.line), start.column);
+    renderer.output.push_str(&format!("{}\n", diagnostic));
+    renderer.output.push_str(&format!("{:>width$}--> {}:{}:{}\n", "", file_name, start.line + 1, column + 1,
+        width = renderer.gutter_width));
+    renderer.empty_gutter();
+
+    for line_labels in labels.chunk_by(|(a, _), (b, _)| a.lexical_info.start.line == b.lexical_info.start.line) {
+        renderer.labels(line_labels[0].0.lexical_info.start.line, line_labels);
+    }
+
+    for note in &diagnostic.notes {
+        renderer.output.push_str(&format!("{:>width$} = nota: {}\n", "", note, width = renderer.gutter_width));
+    }
+
+    for suggestion in &diagnostic.suggestions {
+        renderer.suggestion(suggestion);
+    }
+
+    return renderer.output;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::ast::LexicalPosition;
+
+
+    fn span(line: usize, start_column: usize, end_column: usize) -> LexicalInfo {
+
+        return LexicalInfo{
+            start: LexicalPosition{line, column: start_column},
+            end: LexicalPosition{line, column: end_column},
+        };
+    }
+
+    #[test]
+    fn test_closest_name() {
+
+        assert_eq!(closest_name("contador", ["contadro", "total"]), Some("contadro"));
+        assert_eq!(closest_name("x", ["y", "xx"]), Some("xx"));
+        assert_eq!(closest_name("soma", ["total", "media"]), None);
+        assert_eq!(closest_name("texot", ["texto", "inteiro"]), Some("texto"));
+        assert_eq!(edit_distance("ab", "ba"), 1);
+        assert_eq!(edit_distance("ca", "abc"), 3);
+    }
+
+    #[test]
+    fn test_count() {
+
+        assert_eq!(count(0, "item", "itens"), "0 itens");
+        assert_eq!(count(1, "item", "itens"), "1 item");
+        assert_eq!(count(2, "valor", "valores"), "2 valores");
+    }
+
+    #[test]
+    fn test_render_primary_label() {
+
+        let source = "func foo() -> int {\n    1 +;\n}";
+        let diagnostic = Diagnostic::error("E0001", "trecho de código inválido", span(1, 6, 7))
+            .with_label("aqui");
+
+        let expected = [
+            "erro[E0001]: trecho de código inválido",
+            " --> teste.ila:2:7",
+            "  |",
+            "2 |     1 +;",
+            "  |       ^ aqui",
+            "",
+        ].join("\n");
+
+        assert_eq!(render(&diagnostic, source, "teste.ila"), expected);
+    }
+
+    #[test]
+    fn test_render_secondary_label_and_note() {
+
+        let source = "var a: int;\nvar a: int;";
+        let diagnostic = Diagnostic::error("E0101", "o nome `a` já foi definido", span(1, 4, 5))
+            .with_label("segunda definição")
+            .with_secondary(span(0, 4, 5), "primeira definição")
+            .with_note("cada nome só pode ser definido uma vez em um escopo");
+
+        let expected = [
+            "erro[E0101]: o nome `a` já foi definido",
+            " --> teste.ila:2:5",
+            "  |",
+            "1 | var a: int;",
+            "  |     - primeira definição",
+            "2 | var a: int;",
+            "  |     ^ segunda definição",
+            "  = nota: cada nome só pode ser definido uma vez em um escopo",
+            "",
+        ].join("\n");
+
+        assert_eq!(render(&diagnostic, source, "teste.ila"), expected);
+    }
+
+    #[test]
+    fn test_render_suggestion() {
+
+        let source = "    var a: int = 1";
+        let diagnostic = Diagnostic::warning("E0002", "está faltando um `;`", span(0, 18, 18))
+            .with_suggestion("adicione um `;` no final da linha", span(0, 18, 18), ";");
+
+        let expected = [
+            "aviso[E0002]: está faltando um `;`",
+            " --> teste.ila:1:19",
+            "  |",
+            "1 |     var a: int = 1",
+            "  |                   ^",
+            "  = sugestão: adicione um `;` no final da linha",
+            "1 |     var a: int = 1;",
+            "  |                   +",
+            "",
+        ].join("\n");
+
+        assert_eq!(render(&diagnostic, source, "teste.ila"), expected);
+    }
+
+    #[test]
+    fn test_render_multibyte_characters() {
+
+        let source = "se não ok {";
+        let diagnostic = Diagnostic::error("E0001", "trecho de código inválido", span(0, 3, 7));
+
+        let rendered = render(&diagnostic, source, "teste.ila");
+        assert!(rendered.contains(" --> teste.ila:1:4\n"), "{}", rendered);
+        assert!(rendered.contains("  |    ^^^\n"), "{}", rendered);
+    }
+
+    #[test]
+    fn test_render_labels_on_same_line() {
+
+        let source = "escrever(ação + y);";
+        let diagnostic = Diagnostic::error("E0301", "tipos incompatíveis", span(0, 18, 19))
+            .with_label("texto")
+            .with_secondary(span(0, 9, 15), "inteiro")
+            .with_secondary(span(0, 0, 8), "procedimento");
+
+        let expected = [
+            "erro[E0301]: tipos incompatíveis",
+            " --> teste.ila:1:17",
+            "  |",
+            "1 | escrever(ação + y);",
+            "  | -------- ----   ^ texto",
+            "  | |        |",
+            "  | |        inteiro",
+            "  | procedimento",
+            "",
+        ].join("\n");
+
+        assert_eq!(render(&diagnostic, source, "teste.ila"), expected);
+    }
+
+    #[test]
+    fn test_render_suggestion_changes() {
+
+        let source = "var a: inteir = 1;\nfunc f(b: ref inteiro) -> inteiro";
+        let diagnostic = Diagnostic::error("E0201", "o tipo `inteir` não existe", span(0, 7, 13))
+            .with_suggestion("você quis dizer `inteiro`?", span(0, 7, 13), "inteiro")
+            .with_suggestion("declare a referência com `mut`", span(1, 10, 21), "mut ref inteiro")
+            .with_suggestion("use `int`", span(0, 7, 13), "int")
+            .with_suggestion("use `real`", span(0, 7, 13), "real");
+
+        let expected = [
+            "erro[E0201]: o tipo `inteir` não existe",
+            " --> teste.ila:1:8",
+            "  |",
+            "1 | var a: inteir = 1;",
+            "  |        ^^^^^^",
+            "  = sugestão: você quis dizer `inteiro`?",
+            "1 | var a: inteiro = 1;",
+            "  |              +",
+            "  = sugestão: declare a referência com `mut`",
+            "2 | func f(b: mut ref inteiro) -> inteiro",
+            "  |           ++++",
+            "  = sugestão: use `int`",
+            "1 | var a: inteir = 1;",
+            "  |           ---",
+            "  = sugestão: use `real`",
+            "1 | var a: real = 1;",
+            "  |        ~~~~",
+            "",
+        ].join("\n");
+
+        assert_eq!(render(&diagnostic, source, "teste.ila"), expected);
+    }
+}
@@ -0,0 +1,250 @@
+// local
+use crate::ast::*;
+use crate::diagnostic::Diagnostic;
+use crate::type_checker::TypeTable;
+use crate::types::Type;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   checker                                  */
+/* -------------------------------------------------------------------------- */
+
+/// What is known about the loop around the statements being checked.
+struct LoopInfo {
+    /// Whether a `parar` that can run leaves the loop.
+    has_break: bool,
+}
+
+struct FlowChecker<'a> {
+    ast: &'a Ast,
+    diagnostics: &'a mut Vec<Diagnostic>,
+    /// Loops around the statements being checked, the innermost last.
+    loops: Vec<LoopInfo>,
+}
+
+impl<'a> FlowChecker<'a> {
+
+    /// Whether the loop never ends by its condition, like `enquanto verdadeiro`.
+    fn is_endless(&self, cond_expr: ExprID) -> bool {
+        return matches!(self.ast.get_expression(cond_expr), Expression::BooleanLiteral{value: true});
+    }
+
+    fn report_unreachable(&mut self, block: &[StmtID]) {
+
+        let start = self.ast.get_lexical_info(block[0]).start;
+        let end = self.ast.get_lexical_info(*block.last().expect("the block has the unreachable statement")).end;
+        let diagnostic = Diagnostic::warning("W0801", "código que nunca é executado", LexicalInfo{start, end})
+            .with_label("nenhum caminho chega até aqui")
+            .with_note("comandos depois de `retornar`, `parar` ou `continuar` no mesmo bloco nunca são executados");
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Checks the statements of a block, returning whether the execution can reach its end.
+    /// `reachable` tells whether the execution can reach its start.
+    fn check_block(&mut self, block: &[StmtID], reachable: bool) -> bool {
+
+        let mut reachable = reachable;
+        for (index, stmt) in block.iter().enumerate() {
+            let completes = self.check_stmt(*stmt, reachable);
+            // only the first statement that can not run is reported, with the rest of the block
+            if reachable && !completes && index + 1 < block.len() {
+                self.report_unreachable(&block[index + 1..]);
+            }
+            reachable = reachable && completes;
+        }
+
+        return reachable;
+    }
+
+    fn check_loop_body(&mut self, body_block: &[StmtID], reachable: bool) -> LoopInfo {
+
+        self.loops.push(LoopInfo{has_break: false});
+        self.check_block(body_block, reachable);
+        return self.loops.pop().expect("the loop was pushed");
+    }
+
+    /// Checks a statement, returning whether the execution can continue after it.
+    fn check_stmt(&mut self, id: StmtID, reachable: bool) -> bool {
+
+        return match self.ast.get_statement(id) {
+            Statement::Expression{..} |
+            Statement::VarDecl{..} |
+            Statement::Error => true,
+            Statement::If{branches, else_body} => {
+                let completes: Vec<bool> = branches.iter().map(|branch| self.check_block(&branch.body, reachable)).collect();
+                let else_completes = match else_body {
+                    Some(else_body) => self.check_block(else_body, reachable),
+                    None => true,
+                };
+                else_completes || completes.contains(&true)
+            },
+            Statement::Match{cases, ..} => {
+                // a missing case is reported by the type checker, so one of the cases always runs
+                let completes: Vec<bool> = cases.iter().map(|case| self.check_block(&case.body, reachable)).collect();
+                completes.is_empty() || completes.contains(&true)
+            },
+            Statement::While{cond_expr, body_block} => {
+                let info = self.check_loop_body(body_block, reachable);
+                // a loop that only ends by `parar` continues when it has one
+                !self.is_endless(*cond_expr) || info.has_break
+            },
+            Statement::ForEach{body_block, ..} => {
+                self.check_loop_body(body_block, reachable);
+                true
+            },
+            Statement::Return{..} => false,
+            Statement::Break | Statement::Continue => {
+                let is_break = matches!(self.ast.get_statement(id), Statement::Break);
+                match self.loops.last_mut() {
+                    Some(info) => info.has_break |= is_break && reachable,
+                    None => {
+                        let keyword = if is_break { "parar" } else { "continuar" };
+                        let diagnostic = Diagnostic::error("E0801", format!("`{}` fora de um laço", keyword), *self.ast.get_lexical_info(id))
+                            .with_label(format!("`{}` só pode ser usado dentro de `enquanto` ou `para cada`", keyword));
+                        self.diagnostics.push(diagnostic);
+                    },
+                }
+                false
+            },
+        };
+    }
+
+    fn check_subprogram(&mut self, id: ScopeDefID, types: &TypeTable) {
+
+        let scope_def = self.ast.get_scope_def(id);
+        let reaches_end = self.check_block(scope_def.body(), true);
+
+        let Type::Function{return_type, ..} = types.declaration_type(scope_def.name()) else { return };
+        if !reaches_end || matches!(**return_type, Type::Nothing | Type::Error) {
+            return;
+        }
+
+        let kind = if scope_def.is_procedure() { "o procedimento" } else { "a função" };
+        let name = self.ast.get_identifier(scope_def.name());
+        let diagnostic = Diagnostic::error("E0802", format!("{} `{}` pode terminar sem retornar um valor", kind, name), *self.ast.get_lexical_info(scope_def.name()))
+            .with_label(format!("deveria retornar `{}`", return_type))
+            .with_note("todo caminho até o fim do subprograma precisa de um `retornar` com um valor");
+        self.diagnostics.push(diagnostic);
+    }
+}
+
+/// Checks the control flow of the subprograms: `parar` and `continuar` are only used in loops,
+/// subprograms that return a value never reach their end without `retornar`, and statements that
+/// can never run are reported as warnings.
+pub fn check_control_flow(ast: &Ast, types: &TypeTable, diagnostics: &mut Vec<Diagnostic>) {
+
+    let mut checker = FlowChecker{ast, diagnostics, loops: Vec::new()};
+    for scope_def in ast.get_subprograms() {
+        checker.check_subprogram(scope_def, types);
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::builtin_types::TypeCatalogue;
+    use crate::resolver::resolve_names;
+    use crate::tso_parser::parse_source;
+    use crate::type_checker::check_types;
+
+
+    fn check(source_code: &str) -> Vec<&'static str> {
+
+        let output = parse_source(source_code).unwrap();
+        assert!(!output.has_errors());
+
+        let mut diagnostics = Vec::new();
+        let resolution = resolve_names(&output.ast, &mut diagnostics);
+        let types = check_types(&output.ast, &resolution, &TypeCatalogue::new(false), &mut diagnostics);
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics.iter().map(|diagnostic| &diagnostic.message).collect::<Vec<_>>());
+
+        check_control_flow(&output.ast, &types, &mut diagnostics);
+        return diagnostics.iter().map(|diagnostic| diagnostic.code).collect();
+    }
+
+    #[test]
+    fn test_returns() {
+
+        let source_code = r#"
+            func sinal(x: inteiro) -> inteiro {
+                se x < 0 { retornar -1; } senão { retornar 1; }
+            }
+            func comparar(a: inteiro, b: inteiro) -> inteiro {
+                se a < b { retornar -1; } senão se a > b { retornar 1; } senão { retornar 0; }
+            }
+            func busca(itens: ref lista<inteiro>) -> inteiro {
+                enquanto verdadeiro {
+                    se tamanho(itens) > 0 { retornar obter(itens, 0); }
+                }
+            }
+            procedimento nada_retorna(x: inteiro) {
+                se x > 0 { escrever(x); }
+            }
+        "#;
+        assert_eq!(check(source_code), Vec::<&str>::new());
+
+        let source_code = r#"
+            func positivo(x: inteiro) -> inteiro {
+                se x > 0 { retornar x; }
+            }
+            func sem_senao(x: inteiro) -> inteiro {
+                se x > 0 { retornar 1; } senão se x <= 0 { retornar 0; }
+            }
+            func ramo_sem_retorno(x: inteiro) -> inteiro {
+                se x > 0 { retornar 1; } senão se x < 0 { x + 1; } senão { retornar 0; }
+            }
+            func primeiro(itens: ref lista<inteiro>) -> inteiro {
+                para cada item em itens { retornar item; }
+            }
+            procedimento ler_numero() -> texto {
+                enquanto verdadeiro {
+                    var linha: texto = ler();
+                    se linha == "fim" { parar; }
+                }
+            }
+        "#;
+        assert_eq!(check(source_code), vec!["E0802", "E0802", "E0802", "E0802", "E0802"]);
+    }
+
+    #[test]
+    fn test_loop_statements() {
+
+        let source_code = r#"
+            procedimento principal() {
+                parar;
+            }
+            procedimento outro(x: inteiro) {
+                se x > 0 { continuar; }
+                enquanto x > 0 { parar; }
+            }
+        "#;
+        assert_eq!(check(source_code), vec!["E0801", "E0801"]);
+    }
+
+    #[test]
+    fn test_unreachable_code() {
+
+        let source_code = r#"
+            func dobro(x: inteiro) -> inteiro {
+                retornar x * 2;
+                var y: inteiro = x;
+                retornar y;
+            }
+            procedimento principal() {
+                enquanto verdadeiro {
+                    se verdadeiro { parar; escrever(1); } senão { continuar; }
+                    escrever(2);
+                }
+                enquanto verdadeiro {}
+                escrever(3);
+            }
+        "#;
+        assert_eq!(check(source_code), vec!["W0801", "W0801", "W0801", "W0801"]);
+    }
+}
@@ -0,0 +1,452 @@
+
+// std
+use std::collections::HashSet;
+
+// local
+use crate::ast::*;
+use crate::diagnostic::Diagnostic;
+use crate::resolver::{Resolution, Symbol};
+
+
+/* -------------------------------------------------------------------------- */
+/*                                flow state                                  */
+/* -------------------------------------------------------------------------- */
+
+/// Variables that are definitely initialized at a point of the function, identified by their
+/// declaration statement.
+#[derive(Clone)]
+struct InitState {
+    initialized: HashSet<StmtID>,
+    /// Set after `retornar`, `parar` and `continuar`: the following code never runs, so it
+    /// behaves as if every variable was initialized.
+    diverges: bool,
+}
+
+impl InitState {
+
+    fn new() -> Self {
+        return InitState{initialized: HashSet::new(), diverges: false};
+    }
+
+    fn is_initialized(&self, var: StmtID) -> bool {
+        return self.diverges || self.initialized.contains(&var);
+    }
+
+    /// State after two alternative paths join, a variable is initialized only if it is
+    /// initialized in both.
+    fn join(self, other: InitState) -> InitState {
+
+        if self.diverges {
+            return other;
+        }
+
+        if other.diverges {
+            return self;
+        }
+
+        let initialized = self.initialized.intersection(&other.initialized).copied().collect();
+        return InitState{initialized, diverges: false};
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   checker                                  */
+/* -------------------------------------------------------------------------- */
+
+struct InitChecker<'a> {
+    ast: &'a Ast,
+    resolution: &'a Resolution,
+    diagnostics: &'a mut Vec<Diagnostic>,
+    /// Variables already reported as read before initialization, reported only once each.
+    reported: HashSet<StmtID>,
+}
+
+fn is_before(a: &LexicalInfo, b: &LexicalInfo) -> bool {
+    return (a.start.line, a.start.column) < (b.start.line, b.start.column);
+}
+
+impl<'a> InitChecker<'a> {
+
+    fn variable_of(&self, name: IdentifierID) -> Option<StmtID> {
+
+        return match self.resolution.symbol(name) {
+            Some(Symbol::Var(stmt)) => Some(stmt),
+            _ => None,
+        };
+    }
+
+    fn check_read(&mut self, name: IdentifierID, state: &InitState) {
+
+        let Some(var) = self.variable_of(name) else { return };
+        if state.is_initialized(var) || self.reported.contains(&var) {
+            return;
+        }
+
+        self.reported.insert(var);
+
+        let name_str = self.ast.get_identifier(name);
+        let lexical_info = *self.ast.get_lexical_info(name);
+        let declaration_lexical_info = *self.ast.get_lexical_info(var);
+        self.diagnostics.push(
+            Diagnostic::error("E0202", format!("a variável `{}` é usada antes de receber um valor", name_str), lexical_info)
+                .with_label("aqui a variável pode ainda não ter um valor")
+                .with_secondary(declaration_lexical_info, "variável declarada aqui")
+                .with_note("dê um valor à variável antes de usá-la, por exemplo na sua declaração")
+        );
+    }
+
+    /// The spec lets a variable be used anywhere in its scope, but when the declaration gives it a
+    /// value, an earlier assignment would be silently overwritten when the declaration runs.
+    fn check_assignment_position(&mut self, name: IdentifierID) {
+
+        let Some(var) = self.variable_of(name) else { return };
+        let Statement::VarDecl{init_expr: Some(_), ..} = self.ast.get_statement(var) else { return };
+
+        let lexical_info = *self.ast.get_lexical_info(name);
+        let declaration_lexical_info = *self.ast.get_lexical_info(var);
+        if !is_before(&lexical_info, &declaration_lexical_info) {
+            return;
+        }
+
+        let name_str = self.ast.get_identifier(name);
+        self.diagnostics.push(
+            Diagnostic::error("E0201", format!("a variável `{}` recebe um valor antes da sua declaração", name_str), lexical_info)
+                .with_label("atribuição antes da declaração")
+                .with_secondary(declaration_lexical_info, "a declaração dá um valor à variável")
+                .with_note("como a variável recebe um valor na declaração, o valor atribuído antes seria perdido")
+        );
+    }
+
+    fn check_expr(&mut self, id: ExprID, state: &mut InitState) {
+
+        match self.ast.get_expression(id) {
+            Expression::IntegerLiteral{..} |
+            Expression::DecimalLiteral{..} |
+            Expression::StringLiteral{..} |
+            Expression::BooleanLiteral{..} |
+            Expression::Error => {},
+            Expression::ListLiteral{values} => {
+                for value in values {
+                    self.check_expr(*value, state);
+                }
+            },
+            Expression::InterpolatedString{parts} => {
+                for value in parts.iter().filter_map(StringPart::value) {
+                    self.check_expr(value, state);
+                }
+            },
+            Expression::Identifier{node_id} => {
+                self.check_read(*node_id, state);
+            },
+            Expression::Call{callee, args} => {
+                self.check_expr(*callee, state);
+                for arg in args {
+                    self.check_expr(*arg, state);
+                }
+            },
+            Expression::StructLiteral{fields, ..} => {
+                for field in fields {
+                    self.check_expr(field.value, state);
+                }
+            },
+            Expression::Access{object, ..} => {
+                self.check_expr(*object, state);
+            },
+            Expression::BinaryOprt{oprt: BinaryOprt::And | BinaryOprt::Or, left, right} => {
+                // the right operand may not be evaluated, so what it initializes is discarded
+                self.check_expr(*left, state);
+                let mut right_state = state.clone();
+                self.check_expr(*right, &mut right_state);
+            },
+            Expression::BinaryOprt{left, right, ..} => {
+                self.check_expr(*left, state);
+                self.check_expr(*right, state);
+            },
+            Expression::UnaryOprt{operand, ..} => {
+                self.check_expr(*operand, state);
+            },
+            Expression::Assign{target, value} => {
+                self.check_expr(*value, state);
+
+                if let Expression::Identifier{node_id} = self.ast.get_expression(*target) {
+
+                    self.check_assignment_position(*node_id);
+                    if let Some(var) = self.variable_of(*node_id) {
+                        state.initialized.insert(var);
+                    }
+                }
+                else {
+
+                    // assigning to a field or through a reference reads the target's object
+                    self.check_expr(*target, state);
+                }
+            },
+        }
+    }
+
+    fn check_block(&mut self, block: &[StmtID], state: &mut InitState) {
+
+        for stmt in block {
+            self.check_stmt(*stmt, state);
+        }
+    }
+
+    fn check_stmt(&mut self, id: StmtID, state: &mut InitState) {
+
+        match self.ast.get_statement(id) {
+            Statement::Expression{expr} => {
+                self.check_expr(*expr, state);
+            },
+            Statement::VarDecl{init_expr, ..} => {
+                if let Some(init_expr) = init_expr {
+                    self.check_expr(*init_expr, state);
+                    state.initialized.insert(id);
+                }
+            },
+            Statement::If{branches, else_body} => {
+                // each condition is only evaluated when the ones before it do not hold
+                let mut branch_states = Vec::new();
+                for branch in branches {
+                    self.check_expr(branch.cond_expr, state);
+                    let mut branch_state = state.clone();
+                    self.check_block(&branch.body, &mut branch_state);
+                    branch_states.push(branch_state);
+                }
+
+                if let Some(else_body) = else_body {
+                    self.check_block(else_body, state);
+                }
+
+                *state = branch_states.into_iter().fold(state.clone(), InitState::join);
+            },
+            Statement::Match{value, cases} => {
+                self.check_expr(*value, state);
+
+                // every variant has a case, so what all of them initialize is initialized after it
+                let mut joined: Option<InitState> = None;
+                for case in cases {
+                    let mut case_state = state.clone();
+                    self.check_block(&case.body, &mut case_state);
+                    joined = Some(match joined {
+                        Some(joined) => joined.join(case_state),
+                        None => case_state,
+                    });
+                }
+
+                if let Some(joined) = joined {
+                    *state = joined;
+                }
+            },
+            Statement::While{cond_expr, body_block} => {
+                // the body may run zero times, so it does not initialize anything for the code after it
+                self.check_expr(*cond_expr, state);
+                let mut body_state = state.clone();
+                self.check_block(body_block, &mut body_state);
+            },
+            Statement::ForEach{iter_expr, body_block, ..} => {
+                self.check_expr(*iter_expr, state);
+                let mut body_state = state.clone();
+                self.check_block(body_block, &mut body_state);
+            },
+            Statement::Return{expr} => {
+                if let Some(expr) = expr {
+                    self.check_expr(*expr, state);
+                }
+                state.diverges = true;
+            },
+            Statement::Continue |
+            Statement::Break => {
+                state.diverges = true;
+            },
+            Statement::Error => {},
+        }
+    }
+}
+
+/// Checks that no variable is read before it definitely has a value, and that variables
+/// initialized in their declaration are not assigned in code positioned before it.
+pub fn check_initialization(ast: &Ast, resolution: &Resolution, diagnostics: &mut Vec<Diagnostic>) {
+
+    let mut checker = InitChecker{ast, resolution, diagnostics, reported: HashSet::new()};
+
+    for scope_def in ast.get_subprograms() {
+        checker.check_block(ast.get_scope_def(scope_def).body(), &mut InitState::new());
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::resolver::resolve_names;
+    use crate::tso_parser::parse_source;
+
+
+    fn check(source_code: &str) -> Vec<&'static str> {
+
+        let output = parse_source(source_code).unwrap();
+        assert!(!output.has_errors());
+
+        let mut diagnostics = Vec::new();
+        let resolution = resolve_names(&output.ast, &mut diagnostics);
+        assert!(diagnostics.is_empty());
+
+        check_initialization(&output.ast, &resolution, &mut diagnostics);
+        return diagnostics.iter().map(|diagnostic| diagnostic.code).collect();
+    }
+
+    #[test]
+    fn test_initialized_variables() {
+
+        let source_code = r#"
+            func foo(a: int) -> int {
+                var b: int = a;
+                var c: int;
+                c = b;
+                retornar b + c;
+            }
+        "#;
+
+        assert!(check(source_code).is_empty());
+    }
+
+    #[test]
+    fn test_assignment_before_initialized_declaration() {
+
+        let source_code = r#"
+            func foo(a: int) -> int {
+                b = 1;
+                se a > 0 {
+                    b = 2;
+                }
+                var b: int = a;
+                b = 3;
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0201", "E0201"]);
+    }
+
+    #[test]
+    fn test_assignment_before_uninitialized_declaration() {
+
+        let source_code = r#"
+            func foo(a: int) -> int {
+                b = a;
+                var b: int;
+                retornar b;
+            }
+        "#;
+
+        assert!(check(source_code).is_empty());
+    }
+
+    #[test]
+    fn test_read_before_initialization() {
+
+        let source_code = r#"
+            func foo(a: int) -> int {
+                var b: int;
+                var c: int = b + c;
+                retornar b;
+            }
+        "#;
+
+        // each variable is reported only once
+        assert_eq!(check(source_code), vec!["E0202", "E0202"]);
+    }
+
+    #[test]
+    fn test_conditional_initialization() {
+
+        let both_branches = r#"
+            func foo(a: int) -> int {
+                var b: int;
+                se a > 0 { b = 1; } senão { b = 2; }
+                retornar b;
+            }
+        "#;
+        assert!(check(both_branches).is_empty());
+
+        let one_branch = r#"
+            func foo(a: int) -> int {
+                var b: int;
+                se a > 0 { b = 1; }
+                retornar b;
+            }
+        "#;
+        assert_eq!(check(one_branch), vec!["E0202"]);
+
+        let diverging_branch = r#"
+            func foo(a: int) -> int {
+                var b: int;
+                se a > 0 { b = 1; } senão { retornar 0; }
+                retornar b;
+            }
+        "#;
+        assert!(check(diverging_branch).is_empty());
+
+        let every_else_if = r#"
+            func foo(a: int) -> int {
+                var b: int;
+                se a > 0 { b = 1; } senão se a < 0 { b = 2; } senão { b = 3; }
+                retornar b;
+            }
+        "#;
+        assert!(check(every_else_if).is_empty());
+
+        let missing_else_if = r#"
+            func foo(a: int) -> int {
+                var b: int;
+                se a > 0 { b = 1; } senão se a < 0 { } senão { b = 3; }
+                retornar b;
+            }
+        "#;
+        assert_eq!(check(missing_else_if), vec!["E0202"]);
+
+        let without_else = r#"
+            func foo(a: int) -> int {
+                var b: int;
+                se a > 0 { b = 1; } senão se a <= 0 { b = 2; }
+                retornar b;
+            }
+        "#;
+        assert_eq!(check(without_else), vec!["E0202"]);
+    }
+
+    #[test]
+    fn test_loop_initialization() {
+
+        let source_code = r#"
+            func foo(a: lista<int>) -> int {
+                var b: int;
+                var c: int;
+                enquanto verdadeiro { b = 1; }
+                para cada item em a { c = item; }
+                retornar b + c;
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0202", "E0202"]);
+    }
+
+    #[test]
+    fn test_short_circuit_initialization() {
+
+        let source_code = r#"
+            func foo(a: bool) -> int {
+                var b: bool;
+                a ou (b = verdadeiro);
+                retornar b;
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0202"]);
+    }
+}
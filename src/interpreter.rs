@@ -0,0 +1,660 @@
+// std
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::rc::Rc;
+
+// local
+use crate::ast::*;
+use crate::builtins::Builtin;
+use crate::resolver::{Resolution, Symbol};
+use crate::runtime::*;
+use crate::type_checker::TypeTable;
+use crate::types::Type;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                 interpreter                                */
+/* -------------------------------------------------------------------------- */
+
+/// Names of the subprogram where the execution starts.
+const ENTRY_POINT_NAMES: &[&str] = &["principal", "main"];
+
+/// How the execution continues after a statement.
+enum Flow {
+    Normal,
+    Break,
+    Continue,
+    Return(Value),
+}
+
+/// Variables of a running subprogram, by their declaration.
+struct Frame {
+    variables: HashMap<IdentifierID, Cell>,
+    return_type: Type,
+}
+
+struct Interpreter<'a> {
+    ast: &'a Ast,
+    resolution: &'a Resolution,
+    types: &'a TypeTable,
+    input: &'a mut dyn BufRead,
+    output: &'a mut dyn Write,
+    frames: Vec<Frame>,
+    structs: HashMap<ScopeDefID, Rc<StructLayout>>,
+    enums: HashMap<ScopeDefID, Rc<EnumLayout>>,
+}
+
+impl<'a> Interpreter<'a> {
+
+    fn error(&self, kind: RuntimeErrorKind, node: impl Into<NodeID>) -> RuntimeError {
+        return RuntimeError{kind, lexical_info: *self.ast.get_lexical_info(node)};
+    }
+
+    fn frame(&mut self) -> &mut Frame {
+        return self.frames.last_mut().expect("code only runs inside a subprogram");
+    }
+
+    fn declare(&mut self, name: IdentifierID, value: Value) {
+        self.frame().variables.insert(name, new_cell(value));
+    }
+
+    fn variable(&mut self, use_id: IdentifierID) -> Cell {
+
+        // a variable can receive a value before its declaration runs
+        let declaration = self.resolution.declaration_of(use_id).expect("names are resolved before the execution");
+        return self.frame().variables.entry(declaration)
+            .or_insert_with(|| new_cell(Value::Nothing))
+            .clone();
+    }
+
+    /* ---- expressions ---- */
+
+    /// Evaluates an expression where a value of type `target` is expected. References are created
+    /// from the places they point to, while other values are copied out of references.
+    fn eval_as(&mut self, id: ExprID, target: &Type) -> RuntimeResult<Value> {
+
+        let is_reference = matches!(self.types.expr_type(id), Type::Ref{..} | Type::Comp{..});
+
+        return match target {
+            Type::Ref{..} | Type::Comp{..} if is_reference => self.eval(id),
+            Type::Ref{..} => Ok(Value::Ref(self.eval_place(id)?)),
+            // a `comp` created from a value owns a copy of it
+            Type::Comp{..} => Ok(Value::Ref(new_cell(self.eval_value(id)?))),
+            _ => self.eval_value(id),
+        };
+    }
+
+    fn eval_value(&mut self, id: ExprID) -> RuntimeResult<Value> {
+        return Ok(self.eval(id)?.dereferenced());
+    }
+
+    /// The storage an expression refers to. Expressions that are not places are stored in a
+    /// new temporary cell.
+    fn eval_place(&mut self, id: ExprID) -> RuntimeResult<Cell> {
+
+        return match self.ast.get_expression(id) {
+            Expression::Identifier{node_id} if self.resolution.declaration_of(*node_id).is_some() => {
+                Ok(self.variable(*node_id))
+            },
+            Expression::UnaryOprt{oprt: UnaryOprt::Deref, operand} => {
+                match self.eval(*operand)? {
+                    Value::Ref(target) => Ok(target),
+                    other => unreachable!("the type checker only allows `*` on references, found {:?}", other),
+                }
+            },
+            Expression::Access{object, field_name} => {
+                // the fields of a structure a `ref` or `comp` points to are reached through it
+                let structure = follow_refs(self.eval_place(*object)?);
+                let index = self.types.field_index(*object, self.ast.get_identifier(*field_name))
+                    .expect("the type checker only allows fields the structure has");
+                let field = match &*structure.borrow() {
+                    Value::Struct{fields, ..} => fields[index].clone(),
+                    other => unreachable!("the type checker only allows fields of structures, found {:?}", other),
+                };
+                Ok(field)
+            },
+            _ => Ok(new_cell(self.eval_value(id)?)),
+        };
+    }
+
+    fn eval(&mut self, id: ExprID) -> RuntimeResult<Value> {
+
+        return match self.ast.get_expression(id) {
+            Expression::IntegerLiteral{value} => Ok(Value::Integer(value.clone())),
+            Expression::DecimalLiteral{value} => Ok(Value::Decimal(value.clone())),
+            Expression::StringLiteral{value} => Ok(Value::Text(value.clone())),
+            Expression::InterpolatedString{parts} => {
+                let mut text = String::new();
+                for part in parts {
+                    match part {
+                        StringPart::Text(part_text) => text.push_str(part_text),
+                        StringPart::Value(value) => text.push_str(&self.eval_value(*value)?.to_string()),
+                    }
+                }
+                Ok(Value::Text(text))
+            },
+            Expression::BooleanLiteral{value} => Ok(Value::Boolean(*value)),
+            Expression::ListLiteral{values} => {
+                let item_type = match self.types.expr_type(id) {
+                    Type::List(item_type) => item_type.as_ref().clone(),
+                    other => unreachable!("list literals have list types, found {}", other),
+                };
+                let mut items = Vec::with_capacity(values.len());
+                for value in values {
+                    items.push(new_cell(self.eval_as(*value, &item_type)?));
+                }
+                Ok(Value::List(items))
+            },
+            Expression::Identifier{node_id} => {
+                match self.resolution.symbol(*node_id) {
+                    Some(Symbol::Function(scope_def)) => Ok(Value::Function(scope_def)),
+                    Some(Symbol::Builtin(builtin)) => Ok(Value::Builtin(builtin)),
+                    Some(Symbol::Variant(enum_id, variant)) => Ok(self.variant_value(enum_id, variant, Vec::new())),
+                    _ => Ok(self.variable(*node_id).borrow().clone()),
+                }
+            },
+            Expression::Call{callee, args} => self.eval_call(id, *callee, args),
+            Expression::StructLiteral{fields, ..} => self.eval_struct_literal(id, fields),
+            Expression::Access{..} => Ok(self.eval_place(id)?.borrow().clone()),
+            Expression::BinaryOprt{oprt, left, right} => self.eval_binary_oprt(id, *oprt, *left, *right),
+            Expression::UnaryOprt{oprt, operand} => {
+                match oprt {
+                    UnaryOprt::Deref => Ok(self.eval_place(id)?.borrow().clone()),
+                    _ => {
+                        let operand = self.eval(*operand)?;
+                        unary_oprt(*oprt, operand).map_err(|kind| self.error(kind, id))
+                    },
+                }
+            },
+            Expression::Assign{target, value} => {
+                // assigning to a `ref` or `comp` variable makes it point to another value
+                let target_type = self.types.expr_type(*target).clone();
+                let value = self.eval_as(*value, &target_type)?;
+                let place = self.eval_place(*target)?;
+                *place.borrow_mut() = value;
+                Ok(Value::Nothing)
+            },
+            Expression::Error => unreachable!("programs with errors are not executed"),
+        };
+    }
+
+    fn eval_struct_literal(&mut self, id: ExprID, fields: &[FieldInit]) -> RuntimeResult<Value> {
+
+        let Type::Struct{id: struct_id, ..} = self.types.expr_type(id) else {
+            unreachable!("struct literals have struct types");
+        };
+        let field_types = self.types.fields(*struct_id);
+
+        // the fields are evaluated in the order they are written, and kept in the order of the definition
+        let mut values = vec![None; field_types.len()];
+        for field in fields {
+            let field_name = self.ast.get_identifier(field.name);
+            let index = field_types.iter().position(|field_type| field_type.name == *field_name)
+                .expect("the type checker only allows fields the structure has");
+            values[index] = Some(new_cell(self.eval_as(field.value, &field_types[index].ty)?));
+        }
+
+        let fields = values.into_iter()
+            .map(|value| value.expect("the type checker requires a value for every field"))
+            .collect();
+        return Ok(Value::Struct{layout: self.structs[struct_id].clone(), fields});
+    }
+
+    fn variant_value(&self, enum_id: ScopeDefID, variant: usize, payload: Vec<Value>) -> Value {
+        return Value::Enum{layout: self.enums[&enum_id].clone(), variant, payload: payload.into_iter().map(new_cell).collect()};
+    }
+
+    fn eval_binary_oprt(&mut self, id: ExprID, oprt: BinaryOprt, left: ExprID, right: ExprID) -> RuntimeResult<Value> {
+
+        // `e` and `ou` only evaluate the right side when it decides the result
+        match oprt {
+            BinaryOprt::And => {
+                let result = self.eval_value(left)?.as_boolean() && self.eval_value(right)?.as_boolean();
+                return Ok(Value::Boolean(result));
+            },
+            BinaryOprt::Or => {
+                let result = self.eval_value(left)?.as_boolean() || self.eval_value(right)?.as_boolean();
+                return Ok(Value::Boolean(result));
+            },
+            _ => {},
+        }
+
+        let left_value = self.eval(left)?;
+        let right_value = self.eval(right)?;
+
+        // errors of a division are caused by the divisor
+        let location = if oprt == BinaryOprt::Div { right } else { id };
+        return binary_oprt(oprt, left_value, right_value).map_err(|kind| self.error(kind, location));
+    }
+
+    fn eval_call(&mut self, id: ExprID, callee: ExprID, args: &[ExprID]) -> RuntimeResult<Value> {
+
+        let params = match self.types.expr_type(callee).dereferenced() {
+            Type::Function{params, ..} => params.clone(),
+            other => unreachable!("the type checker only allows calling subprograms, found {}", other),
+        };
+
+        let mut arg_values = Vec::with_capacity(args.len());
+        for (arg, param) in args.iter().zip(&params) {
+            arg_values.push(self.eval_as(*arg, param)?);
+        }
+
+        // a variant with values is created like a call
+        if let Expression::Identifier{node_id} = self.ast.get_expression(callee) {
+            if let Some(Symbol::Variant(enum_id, variant)) = self.resolution.symbol(*node_id) {
+                return Ok(self.variant_value(enum_id, variant, arg_values));
+            }
+        }
+
+        return match self.eval_value(callee)? {
+            Value::Function(scope_def) => self.call(id, scope_def, arg_values),
+            Value::Builtin(builtin) => self.call_builtin(id, builtin, args, arg_values),
+            other => unreachable!("the type checker only allows calling subprograms, found {:?}", other),
+        };
+    }
+
+    fn call(&mut self, call_site: impl Into<NodeID>, scope_def: ScopeDefID, args: Vec<Value>) -> RuntimeResult<Value> {
+
+        if self.frames.len() >= MAX_CALL_DEPTH {
+            return Err(self.error(RuntimeErrorKind::CallDepthExceeded, call_site));
+        }
+
+        let definition = self.ast.get_scope_def(scope_def);
+        let return_type = match self.types.declaration_type(definition.name()) {
+            Type::Function{return_type, ..} => return_type.as_ref().clone(),
+            other => unreachable!("subprograms have function types, found {}", other),
+        };
+
+        let mut variables = HashMap::new();
+        for (param, arg) in definition.params().iter().zip(args) {
+            variables.insert(self.ast.get_function_param(*param).name, new_cell(arg));
+        }
+
+        self.frames.push(Frame{variables, return_type});
+        let flow = self.exec_block(definition.body());
+        self.frames.pop();
+
+        return match flow? {
+            Flow::Return(value) => Ok(value),
+            _ => Ok(Value::Nothing),
+        };
+    }
+
+    fn call_builtin(&mut self, id: ExprID, builtin: Builtin, args: &[ExprID], arg_values: Vec<Value>) -> RuntimeResult<Value> {
+
+        return call_builtin(builtin, &arg_values, self.input, self.output).map_err(|kind| {
+            // a position that does not exist is the fault of the argument
+            let location = match kind {
+                RuntimeErrorKind::IndexOutOfBounds{..} => args[1],
+                _ => id,
+            };
+            self.error(kind, location)
+        });
+    }
+
+    /* ---- statements ---- */
+
+    fn exec_block(&mut self, block: &[StmtID]) -> RuntimeResult<Flow> {
+
+        for stmt in block {
+            match self.exec_stmt(*stmt)? {
+                Flow::Normal => {},
+                flow => return Ok(flow),
+            }
+        }
+
+        return Ok(Flow::Normal);
+    }
+
+    /// Runs a loop body for one iteration, telling whether the loop continues and how.
+    fn exec_loop_body(&mut self, body_block: &[StmtID]) -> RuntimeResult<Option<Flow>> {
+
+        return match self.exec_block(body_block)? {
+            Flow::Normal | Flow::Continue => Ok(None),
+            Flow::Break => Ok(Some(Flow::Normal)),
+            flow => Ok(Some(flow)),
+        };
+    }
+
+    fn exec_stmt(&mut self, id: StmtID) -> RuntimeResult<Flow> {
+
+        match self.ast.get_statement(id) {
+            Statement::Expression{expr} => {
+                self.eval(*expr)?;
+            },
+            Statement::VarDecl{name, init_expr, ..} => {
+                // every time the declaration runs, the variable starts again with a new storage
+                let value = match init_expr {
+                    Some(init_expr) => {
+                        let declared = self.types.declaration_type(*name).clone();
+                        self.eval_as(*init_expr, &declared)?
+                    },
+                    None => Value::Nothing,
+                };
+                self.declare(*name, value);
+            },
+            Statement::If{branches, else_body} => {
+                for branch in branches {
+                    if self.eval_value(branch.cond_expr)?.as_boolean() {
+                        return self.exec_block(&branch.body);
+                    }
+                }
+                if let Some(else_body) = else_body {
+                    return self.exec_block(else_body);
+                }
+            },
+            Statement::Match{value, cases} => {
+                let Value::Enum{variant, payload, ..} = self.eval_value(*value)? else {
+                    unreachable!("the type checker only allows choosing between the variants of an enumeration");
+                };
+
+                let case = cases.iter()
+                    .find(|case| matches!(self.resolution.symbol(case.variant), Some(Symbol::Variant(_, index)) if index == variant))
+                    .expect("the type checker requires a case for every variant");
+
+                // the value was already copied, so the bindings own its values
+                for (binding, cell) in case.bindings.iter().zip(payload) {
+                    self.frame().variables.insert(*binding, cell);
+                }
+                return self.exec_block(&case.body);
+            },
+            Statement::While{cond_expr, body_block} => {
+                while self.eval_value(*cond_expr)?.as_boolean() {
+                    if let Some(flow) = self.exec_loop_body(body_block)? {
+                        return Ok(flow);
+                    }
+                }
+            },
+            Statement::ForEach{item, iter_expr, body_block} => {
+                let for_each_decl = self.ast.get_for_each_decl(*item);
+
+                let items = iteration_items(self.eval_place(*iter_expr)?);
+                for item_cell in items {
+                    let item_value = match for_each_decl.is_ref {
+                        true => Value::Ref(item_cell),
+                        false => item_cell.borrow().clone(),
+                    };
+                    self.declare(for_each_decl.name, item_value);
+
+                    if let Some(flow) = self.exec_loop_body(body_block)? {
+                        return Ok(flow);
+                    }
+                }
+            },
+            Statement::Return{expr} => {
+                let value = match expr {
+                    Some(expr) => {
+                        let return_type = self.frame().return_type.clone();
+                        self.eval_as(*expr, &return_type)?
+                    },
+                    None => Value::Nothing,
+                };
+                return Ok(Flow::Return(value));
+            },
+            Statement::Continue => return Ok(Flow::Continue),
+            Statement::Break => return Ok(Flow::Break),
+            Statement::Error => unreachable!("programs with errors are not executed"),
+        }
+
+        return Ok(Flow::Normal);
+    }
+}
+
+/// The subprogram where the execution starts: `principal`, or `main`, without parameters.
+pub fn find_entry_point(ast: &Ast) -> Option<ScopeDefID> {
+
+    return ENTRY_POINT_NAMES.iter().find_map(|entry_name| {
+        ast.get_subprograms().into_iter().find(|scope_def| {
+            let definition = ast.get_scope_def(*scope_def);
+            ast.get_identifier(definition.name()) == entry_name && definition.params().is_empty()
+        })
+    });
+}
+
+/// Runs a program without errors from its entry point, reading and writing the terminal
+/// through `input` and `output`.
+pub fn execute(ast: &Ast, resolution: &Resolution, types: &TypeTable, entry_point: ScopeDefID,
+    input: &mut dyn BufRead, output: &mut dyn Write) -> RuntimeResult<()> {
+
+    let structs = struct_layouts(ast, types);
+    let enums = enum_layouts(ast, types);
+    let mut interpreter = Interpreter{ast, resolution, types, input, output, frames: Vec::new(), structs, enums};
+    let entry_name = ast.get_scope_def(entry_point).name();
+
+    let result = interpreter.call(entry_name, entry_point, Vec::new());
+    interpreter.output.flush().map_err(|error| RuntimeError{kind: RuntimeErrorKind::Io(error.to_string()), lexical_info: *ast.get_lexical_info(entry_name)})?;
+
+    return result.map(|_| ());
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::analysis::{analyze, AnalysisSettings};
+    use crate::tso_parser::parse_source;
+
+
+    /// Runs the program with `input` typed in the terminal, returning what it wrote.
+    fn run_with_input(source_code: &str, input: &str) -> RuntimeResult<String> {
+
+        let source_code = source_code.to_string();
+        let input = input.to_string();
+
+        // deep recursions need more stack than the test threads have
+        let execution = std::thread::Builder::new().stack_size(512 * 1024 * 1024).spawn(move || {
+            let output = parse_source(&source_code).unwrap();
+            assert!(!output.has_errors());
+
+            let analysis = analyze(&output.ast, &AnalysisSettings::default());
+            let messages: Vec<&String> = analysis.diagnostics.iter().map(|diagnostic| &diagnostic.message).collect();
+            assert!(!analysis.has_errors(), "{:?}", messages);
+
+            let entry_point = find_entry_point(&output.ast).unwrap();
+            let mut written = Vec::new();
+            execute(&output.ast, &analysis.resolution, &analysis.types, entry_point, &mut input.as_bytes(), &mut written)?;
+            return Ok(String::from_utf8(written).unwrap());
+        });
+
+        return execution.unwrap().join().unwrap();
+    }
+
+    fn run(source_code: &str) -> RuntimeResult<String> {
+        return run_with_input(source_code, "");
+    }
+
+    fn error_code(source_code: &str) -> &'static str {
+        return run(source_code).unwrap_err().to_diagnostic().code;
+    }
+
+    #[test]
+    fn test_entry_point() {
+
+        let output = parse_source("procedimento main() {} procedimento principal() {}").unwrap();
+        let entry_point = find_entry_point(&output.ast).unwrap();
+        assert_eq!(output.ast.get_identifier(output.ast.get_scope_def(entry_point).name()), "principal");
+
+        let output = parse_source("procedimento principal(a: inteiro) {}").unwrap();
+        assert_eq!(find_entry_point(&output.ast), None);
+    }
+
+    #[test]
+    fn test_expressions() {
+
+        let source_code = r#"
+            func media(a: inteiro, b: decimal) -> decimal {
+                retornar (a + b) / 2;
+            }
+
+            procedimento principal() {
+                escrever(1 + 2 * 3 - 10 / 3);
+                escrever(media(2, 4.0));
+                escrever(-5 < 3 e não (1 == 1.0) ou "a" + "b" == "ab");
+                escrever([[1, 2], [3]] == [[1, 2], [3]]);
+                escrever(["um", "dois"]);
+                escrever(para_texto(falso) + "!");
+            }
+        "#;
+
+        assert_eq!(run(source_code).unwrap(), "4\n3.0\nverdadeiro\nverdadeiro\n[\"um\", \"dois\"]\nfalso!\n");
+    }
+
+    #[test]
+    fn test_strings() {
+
+        let source_code = r#"
+            func dobro(x: inteiro) -> inteiro {
+                retornar x * 2;
+            }
+
+            procedimento principal() {
+                var nome: texto = "Ana";
+                escrever("Olá, {nome}! {dobro(21)} {[1, 2]} {verdadeiro}");
+                escrever("\"aspas\"\tbarra \\ \{chave} \u{1F600}\nfim");
+                escrever("{nome + "!"}");
+            }
+        "#;
+
+        assert_eq!(run(source_code).unwrap(), "Olá, Ana! 42 [1, 2] verdadeiro\n\"aspas\"\tbarra \\ {chave} 😀\nfim\nAna!\n");
+    }
+
+    #[test]
+    fn test_large_numbers() {
+
+        let source_code = r#"
+            func fatorial(n: inteiro) -> inteiro {
+                se n <= 1 { retornar 1; }
+                retornar n * fatorial(n - 1);
+            }
+
+            procedimento principal() {
+                escrever(fatorial(50));
+                escrever(fatorial(30) / fatorial(28) == 870);
+                escrever(-9223372036854775807 - 2 < -9223372036854775808);
+                escrever(0.1 + 0.2 == 0.3);
+                escrever(1.05 * 3);
+                escrever(10 / 4.0);
+                escrever(2.0 / 3.0);
+            }
+        "#;
+
+        let expected = [
+            "30414093201713378043612608166064768844377641568960512000000000000",
+            "verdadeiro",
+            "verdadeiro",
+            "verdadeiro",
+            "3.15",
+            "2.5",
+            "0.666666666666666666666666666667",
+            "",
+        ];
+        assert_eq!(run(source_code).unwrap(), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_control_flow() {
+
+        let source_code = r#"
+            func primeiro_par(itens: ref lista<inteiro>) -> inteiro {
+                para cada item em itens {
+                    se item / 2 * 2 == item { retornar item; }
+                }
+                retornar -1;
+            }
+
+            procedimento principal() {
+                var mut i: inteiro = 0;
+                var mut vistos: lista<inteiro> = [];
+                enquanto i < 10 {
+                    i = i + 1;
+                    se i == 3 { continuar; }
+                    se i == 6 { parar; }
+                    adicionar(vistos, i);
+                }
+                escrever(vistos);
+                escrever(primeiro_par([3, 5, 8, 10]));
+                para cada letra em "olá" { escrever(letra); }
+            }
+        "#;
+
+        assert_eq!(run(source_code).unwrap(), "[1, 2, 4, 5]\n8\no\nl\ná\n");
+    }
+
+    #[test]
+    fn test_assigned_before_declaration() {
+
+        let source_code = r#"
+            procedimento principal() {
+                a = 1;
+                escrever(a);
+                var a: inteiro;
+            }
+        "#;
+
+        assert_eq!(run(source_code).unwrap(), "1\n");
+    }
+
+    #[test]
+    fn test_references() {
+
+        let source_code = r#"
+            procedimento incrementar(valor: mut ref inteiro) {
+                *valor = valor + 1;
+            }
+
+            procedimento principal() {
+                var mut a: inteiro = 1;
+                var mut b: inteiro = 10;
+                var mut r: mut ref inteiro = a;
+                incrementar(r);
+                r = b;
+                *r = 20;
+
+                var mut itens: lista<inteiro> = [1, 2];
+                var cópia: lista<inteiro> = itens;
+                para cada mut ref item em itens { *item = item * 10; }
+
+                var c: comp inteiro = 5;
+                var d: comp inteiro = c;
+                escrever(para_texto(a) + " " + para_texto(b) + " " + para_texto(d));
+                escrever(itens);
+                escrever(cópia);
+            }
+        "#;
+
+        assert_eq!(run(source_code).unwrap(), "2 20 5\n[10, 20]\n[1, 2]\n");
+    }
+
+    #[test]
+    fn test_read() {
+
+        let source_code = r#"
+            procedimento principal() {
+                var nome: texto = ler();
+                escrever("Olá, " + nome + "!");
+                escrever(tamanho(ler()));
+            }
+        "#;
+
+        assert_eq!(run_with_input(source_code, "Ana\r\nabc\n").unwrap(), "Olá, Ana!\n3\n");
+    }
+
+    #[test]
+    fn test_runtime_errors() {
+
+        assert_eq!(error_code("procedimento principal() { var a: inteiro = 0; escrever(1 / a); }"), "E0701");
+        assert_eq!(error_code("procedimento principal() { escrever(1.5 / 0); }"), "E0701");
+        assert_eq!(error_code("procedimento principal() { escrever(obter([1, 2], 2)); }"), "E0702");
+        assert_eq!(error_code("procedimento principal() { escrever(obter([1, 2], -1)); }"), "E0702");
+        assert_eq!(error_code("func f(a: inteiro) -> inteiro { retornar f(a); } procedimento principal() { f(1); }"), "E0704");
+
+        let error = run("procedimento principal() {\n    escrever(obter([1], 5));\n}").unwrap_err();
+        assert_eq!((error.lexical_info.start.line, error.lexical_info.start.column), (1, 24));
+        assert_eq!(error.to_diagnostic().message, "a posição 5 não existe em uma lista com 1 item");
+    }
+}
@@ -0,0 +1,1560 @@
+// std
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Write as _;
+use std::rc::Rc;
+
+// local
+use crate::ast::*;
+use crate::builtins::Builtin;
+use crate::interpreter::find_entry_point;
+use crate::numbers::{Decimal, Integer};
+use crate::resolver::{Resolution, Symbol};
+use crate::runtime::{enum_layouts, struct_layouts, EnumLayout, StructLayout, Value};
+use crate::type_checker::TypeTable;
+use crate::types::Type;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                     IR                                     */
+/* -------------------------------------------------------------------------- */
+
+/// A temporary, assigned by a single instruction of its subprogram.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Temp(pub usize);
+
+/// A variable of a subprogram, read and written by instructions. The parameters are the first ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LocalID(pub usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BlockID(pub usize);
+
+/// A subprogram by its position in `Program::functions`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FunctionID(pub usize);
+
+/// A structure by its position in `Program::structs`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StructID(pub usize);
+
+/// An enumeration by its position in `Program::enums`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EnumID(pub usize);
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Constant {
+    Integer(Integer),
+    Decimal(Decimal),
+    Text(String),
+    Boolean(bool),
+    Nothing,
+}
+
+impl Constant {
+
+    pub fn ty(&self) -> Type {
+
+        return match self {
+            Constant::Integer(_) => Type::Integer,
+            Constant::Decimal(_) => Type::Decimal,
+            Constant::Text(_) => Type::Text,
+            Constant::Boolean(_) => Type::Boolean,
+            Constant::Nothing => Type::Nothing,
+        };
+    }
+
+    pub fn to_value(&self) -> Value {
+
+        return match self {
+            Constant::Integer(value) => Value::Integer(value.clone()),
+            Constant::Decimal(value) => Value::Decimal(value.clone()),
+            Constant::Text(value) => Value::Text(value.clone()),
+            Constant::Boolean(value) => Value::Boolean(*value),
+            Constant::Nothing => Value::Nothing,
+        };
+    }
+
+    /// The constant of a value, when it has one.
+    pub fn from_value(value: Value) -> Option<Constant> {
+
+        return match value {
+            Value::Integer(value) => Some(Constant::Integer(value)),
+            Value::Decimal(value) => Some(Constant::Decimal(value)),
+            Value::Text(value) => Some(Constant::Text(value)),
+            Value::Boolean(value) => Some(Constant::Boolean(value)),
+            Value::Nothing => Some(Constant::Nothing),
+            _ => None,
+        };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Operand {
+    Temp(Temp),
+    Constant(Constant),
+}
+
+/// What an instruction does. `dest` is the temporary it assigns.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InstructionKind {
+    Copy{dest: Temp, value: Operand},
+    /// Reads a copy of the value of a local.
+    LoadLocal{dest: Temp, local: LocalID},
+    StoreLocal{local: LocalID, value: Operand},
+    /// Gives a local a new storage, every run of a declaration creates a new one. Without a
+    /// value, the local is not initialized.
+    DeclareLocal{local: LocalID, value: Option<Operand>},
+    /// A reference to the storage of a local.
+    LocalRef{dest: Temp, local: LocalID},
+    /// Reads a copy of the value a reference points to.
+    Load{dest: Temp, pointer: Operand},
+    /// Writes a value where a reference points.
+    Store{pointer: Operand, value: Operand},
+    /// A copy of the value after following every reference.
+    Dereference{dest: Temp, value: Operand},
+    /// A reference to a new storage with the value.
+    Box{dest: Temp, value: Operand},
+    MakeList{dest: Temp, items: Vec<Operand>},
+    /// The fields are in the order of the definition of the structure.
+    MakeStruct{dest: Temp, structure: StructID, fields: Vec<Operand>},
+    /// A reference to the storage of a field of the structure `pointer` points to, following
+    /// every reference on the way.
+    FieldRef{dest: Temp, pointer: Operand, index: usize},
+    /// A value of an enumeration, `variant` is the position of the variant in its definition.
+    MakeVariant{dest: Temp, enumeration: EnumID, variant: usize, payload: Vec<Operand>},
+    /// The position of the variant of a value of an enumeration, as an `inteiro`.
+    Tag{dest: Temp, value: Operand},
+    /// A copy of the value with position `index` of a value of an enumeration, which is `variant`.
+    Payload{dest: Temp, value: Operand, variant: usize, index: usize},
+    Unary{dest: Temp, oprt: UnaryOprt, operand: Operand},
+    /// Every operator that evaluates both of its operands, that is, except `e`, `ou` and `=`.
+    Binary{dest: Temp, oprt: BinaryOprt, left: Operand, right: Operand},
+    Call{dest: Temp, function: FunctionID, args: Vec<Operand>},
+    CallBuiltin{dest: Temp, builtin: Builtin, args: Vec<Operand>},
+    /// The items a `para cada` goes through: the storage of the items the list, or the
+    /// characters the text, has when the loop starts. `iterated` is a reference to it.
+    Items{dest: Temp, iterated: Operand},
+    ItemCount{dest: Temp, items: Operand},
+    /// A reference to the storage of one of the `Items`.
+    ItemRef{dest: Temp, items: Operand, index: Operand},
+}
+
+impl InstructionKind {
+
+    pub fn dest(&self) -> Option<Temp> {
+
+        return match self {
+            InstructionKind::Copy{dest, ..} |
+            InstructionKind::LoadLocal{dest, ..} |
+            InstructionKind::LocalRef{dest, ..} |
+            InstructionKind::Load{dest, ..} |
+            InstructionKind::Dereference{dest, ..} |
+            InstructionKind::Box{dest, ..} |
+            InstructionKind::MakeList{dest, ..} |
+            InstructionKind::MakeStruct{dest, ..} |
+            InstructionKind::FieldRef{dest, ..} |
+            InstructionKind::MakeVariant{dest, ..} |
+            InstructionKind::Tag{dest, ..} |
+            InstructionKind::Payload{dest, ..} |
+            InstructionKind::Unary{dest, ..} |
+            InstructionKind::Binary{dest, ..} |
+            InstructionKind::Call{dest, ..} |
+            InstructionKind::CallBuiltin{dest, ..} |
+            InstructionKind::Items{dest, ..} |
+            InstructionKind::ItemCount{dest, ..} |
+            InstructionKind::ItemRef{dest, ..} => Some(*dest),
+            InstructionKind::StoreLocal{..} |
+            InstructionKind::DeclareLocal{..} |
+            InstructionKind::Store{..} => None,
+        };
+    }
+
+    /// The operands the instruction reads, in the order they are evaluated.
+    pub fn operands(&self) -> Vec<&Operand> {
+
+        return match self {
+            InstructionKind::LoadLocal{..} | InstructionKind::LocalRef{..} => Vec::new(),
+            InstructionKind::DeclareLocal{value, ..} => value.iter().collect(),
+            InstructionKind::Copy{value, ..} |
+            InstructionKind::StoreLocal{value, ..} |
+            InstructionKind::Dereference{value, ..} |
+            InstructionKind::Box{value, ..} |
+            InstructionKind::Tag{value, ..} |
+            InstructionKind::Payload{value, ..} => vec![value],
+            InstructionKind::Load{pointer, ..} | InstructionKind::FieldRef{pointer, ..} => vec![pointer],
+            InstructionKind::Store{pointer, value} => vec![value, pointer],
+            InstructionKind::MakeList{items, ..} |
+            InstructionKind::MakeStruct{fields: items, ..} |
+            InstructionKind::MakeVariant{payload: items, ..} => items.iter().collect(),
+            InstructionKind::Unary{operand, ..} => vec![operand],
+            InstructionKind::Binary{left, right, ..} => vec![left, right],
+            InstructionKind::Call{args, ..} | InstructionKind::CallBuiltin{args, ..} => args.iter().collect(),
+            InstructionKind::Items{iterated, ..} => vec![iterated],
+            InstructionKind::ItemCount{items, ..} => vec![items],
+            InstructionKind::ItemRef{items, index, ..} => vec![items, index],
+        };
+    }
+
+    pub fn operands_mut(&mut self) -> Vec<&mut Operand> {
+
+        return match self {
+            InstructionKind::LoadLocal{..} | InstructionKind::LocalRef{..} => Vec::new(),
+            InstructionKind::DeclareLocal{value, ..} => value.iter_mut().collect(),
+            InstructionKind::Copy{value, ..} |
+            InstructionKind::StoreLocal{value, ..} |
+            InstructionKind::Dereference{value, ..} |
+            InstructionKind::Box{value, ..} |
+            InstructionKind::Tag{value, ..} |
+            InstructionKind::Payload{value, ..} => vec![value],
+            InstructionKind::Load{pointer, ..} | InstructionKind::FieldRef{pointer, ..} => vec![pointer],
+            InstructionKind::Store{pointer, value} => vec![value, pointer],
+            InstructionKind::MakeList{items, ..} |
+            InstructionKind::MakeStruct{fields: items, ..} |
+            InstructionKind::MakeVariant{payload: items, ..} => items.iter_mut().collect(),
+            InstructionKind::Unary{operand, ..} => vec![operand],
+            InstructionKind::Binary{left, right, ..} => vec![left, right],
+            InstructionKind::Call{args, ..} | InstructionKind::CallBuiltin{args, ..} => args.iter_mut().collect(),
+            InstructionKind::Items{iterated, ..} => vec![iterated],
+            InstructionKind::ItemCount{items, ..} => vec![items],
+            InstructionKind::ItemRef{items, index, ..} => vec![items, index],
+        };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instruction {
+    pub kind: InstructionKind,
+    /// Where the instruction comes from, for runtime errors.
+    pub lexical_info: LexicalInfo,
+}
+
+/// How a basic block ends.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Terminator {
+    Jump(BlockID),
+    Branch{condition: Operand, then_block: BlockID, else_block: BlockID},
+    Return(Operand),
+}
+
+impl Terminator {
+
+    pub fn successors(&self) -> Vec<BlockID> {
+
+        return match self {
+            Terminator::Jump(target) => vec![*target],
+            Terminator::Branch{then_block, else_block, ..} => vec![*then_block, *else_block],
+            Terminator::Return(_) => Vec::new(),
+        };
+    }
+
+    pub fn successors_mut(&mut self) -> Vec<&mut BlockID> {
+
+        return match self {
+            Terminator::Jump(target) => vec![target],
+            Terminator::Branch{then_block, else_block, ..} => vec![then_block, else_block],
+            Terminator::Return(_) => Vec::new(),
+        };
+    }
+
+    pub fn operands(&self) -> Vec<&Operand> {
+
+        return match self {
+            Terminator::Jump(_) => Vec::new(),
+            Terminator::Branch{condition, ..} => vec![condition],
+            Terminator::Return(value) => vec![value],
+        };
+    }
+
+    pub fn operands_mut(&mut self) -> Vec<&mut Operand> {
+
+        return match self {
+            Terminator::Jump(_) => Vec::new(),
+            Terminator::Branch{condition, ..} => vec![condition],
+            Terminator::Return(value) => vec![value],
+        };
+    }
+}
+
+/// Instructions that run in sequence, the only jumps are the ones of the terminator.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BasicBlock {
+    pub instructions: Vec<Instruction>,
+    pub terminator: Terminator,
+    pub terminator_info: LexicalInfo,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Local {
+    pub name: String,
+    pub ty: Type,
+}
+
+/// A subprogram as a control-flow graph of basic blocks, starting at the first one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Function {
+    pub name: String,
+    pub param_count: usize,
+    pub return_type: Type,
+    /// Whether the subprogram is a function, which can not change values outside of it.
+    pub is_pure: bool,
+    pub locals: Vec<Local>,
+    /// Types of the temporaries, by their number.
+    pub temps: Vec<Type>,
+    pub blocks: Vec<BasicBlock>,
+    pub lexical_info: LexicalInfo,
+}
+
+/// A structure of the program, with the types of its fields in the order they are defined.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StructDef {
+    pub id: ScopeDefID,
+    pub layout: Rc<StructLayout>,
+    pub field_types: Vec<Type>,
+}
+
+/// An enumeration of the program, with the types of the values of each variant.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumDef {
+    pub id: ScopeDefID,
+    pub layout: Rc<EnumLayout>,
+    pub payload_types: Vec<Vec<Type>>,
+}
+
+/// A lowered program, with a function for each subprogram and a definition for each structure
+/// and enumeration, in the order they are defined.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Program {
+    pub functions: Vec<Function>,
+    pub structs: Vec<StructDef>,
+    pub enums: Vec<EnumDef>,
+    pub entry_point: Option<FunctionID>,
+}
+
+impl Program {
+
+    /// The definition of a structure type.
+    pub fn struct_def(&self, id: ScopeDefID) -> Option<&StructDef> {
+        return self.structs.iter().find(|definition| definition.id == id);
+    }
+
+    /// The definition of an enumeration type.
+    pub fn enum_def(&self, id: ScopeDefID) -> Option<&EnumDef> {
+        return self.enums.iter().find(|definition| definition.id == id);
+    }
+}
+
+pub const ENTRY_BLOCK: BlockID = BlockID(0);
+
+
+/* -------------------------------------------------------------------------- */
+/*                             control-flow graph                             */
+/* -------------------------------------------------------------------------- */
+
+impl Function {
+
+    pub fn block(&self, id: BlockID) -> &BasicBlock {
+        return &self.blocks[id.0];
+    }
+
+    pub fn temp_type(&self, temp: Temp) -> &Type {
+        return &self.temps[temp.0];
+    }
+
+    pub fn operand_type(&self, operand: &Operand) -> Type {
+
+        return match operand {
+            Operand::Temp(temp) => self.temp_type(*temp).clone(),
+            Operand::Constant(constant) => constant.ty(),
+        };
+    }
+
+    pub fn predecessors(&self) -> Vec<Vec<BlockID>> {
+
+        let mut predecessors = vec![Vec::new(); self.blocks.len()];
+        for (index, block) in self.blocks.iter().enumerate() {
+            for successor in block.terminator.successors() {
+                predecessors[successor.0].push(BlockID(index));
+            }
+        }
+
+        return predecessors;
+    }
+
+    /// The blocks reachable from the entry, in reverse postorder: every block comes before its
+    /// successors, except along the edges that go back to the start of a loop.
+    pub fn reverse_postorder(&self) -> Vec<BlockID> {
+
+        let mut visited = vec![false; self.blocks.len()];
+        let mut postorder = Vec::new();
+        // blocks with the position of the next successor to visit
+        let mut stack = vec![(ENTRY_BLOCK, 0)];
+        visited[ENTRY_BLOCK.0] = true;
+
+        while let Some((block, next)) = stack.last_mut() {
+            let successors = self.block(*block).terminator.successors();
+            match successors.get(*next) {
+                Some(successor) => {
+                    *next += 1;
+                    if !visited[successor.0] {
+                        visited[successor.0] = true;
+                        stack.push((*successor, 0));
+                    }
+                },
+                None => {
+                    postorder.push(*block);
+                    stack.pop();
+                },
+            }
+        }
+
+        postorder.reverse();
+        return postorder;
+    }
+
+    /// The reachable blocks in an order close to the one of the program, for the code generated
+    /// from them: a block comes after every block that jumps to it, except along the edges that
+    /// go back to the start of a loop, and the blocks created first come first.
+    pub fn layout_order(&self) -> Vec<BlockID> {
+
+        let order = self.reverse_postorder();
+        let mut position = vec![usize::MAX; self.blocks.len()];
+        for (index, block) in order.iter().enumerate() {
+            position[block.0] = index;
+        }
+
+        // the edges that go back to the start of a loop go to an earlier block of the reverse postorder
+        let mut waiting_for = vec![0; self.blocks.len()];
+        for block in &order {
+            for successor in self.block(*block).terminator.successors() {
+                if position[successor.0] > position[block.0] {
+                    waiting_for[successor.0] += 1;
+                }
+            }
+        }
+
+        let mut ready = BinaryHeap::from([Reverse(ENTRY_BLOCK)]);
+        let mut layout = Vec::with_capacity(order.len());
+        while let Some(Reverse(block)) = ready.pop() {
+            layout.push(block);
+            for successor in self.block(block).terminator.successors() {
+                if position[successor.0] > position[block.0] {
+                    waiting_for[successor.0] -= 1;
+                    if waiting_for[successor.0] == 0 {
+                        ready.push(Reverse(successor));
+                    }
+                }
+            }
+        }
+
+        return layout;
+    }
+
+    pub fn reachable(&self) -> Vec<bool> {
+
+        let mut reachable = vec![false; self.blocks.len()];
+        for block in self.reverse_postorder() {
+            reachable[block.0] = true;
+        }
+
+        return reachable;
+    }
+
+    /// The immediate dominator of each reachable block: the last block that every path from the
+    /// entry goes through before reaching it. The entry and unreachable blocks have none.
+    pub fn immediate_dominators(&self) -> Vec<Option<BlockID>> {
+
+        // "A Simple, Fast Dominance Algorithm", by Cooper, Harvey and Kennedy
+        let order = self.reverse_postorder();
+        let mut position = vec![usize::MAX; self.blocks.len()];
+        for (index, block) in order.iter().enumerate() {
+            position[block.0] = index;
+        }
+
+        let predecessors = self.predecessors();
+        let mut dominators: Vec<Option<BlockID>> = vec![None; self.blocks.len()];
+        dominators[ENTRY_BLOCK.0] = Some(ENTRY_BLOCK);
+
+        let intersect = |dominators: &[Option<BlockID>], mut a: BlockID, mut b: BlockID| {
+            while a != b {
+                while position[a.0] > position[b.0] {
+                    a = dominators[a.0].expect("processed blocks have a dominator");
+                }
+                while position[b.0] > position[a.0] {
+                    b = dominators[b.0].expect("processed blocks have a dominator");
+                }
+            }
+            return a;
+        };
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for block in order.iter().skip(1) {
+                let mut dominator = None;
+                for predecessor in &predecessors[block.0] {
+                    if dominators[predecessor.0].is_none() {
+                        continue;
+                    }
+                    dominator = Some(match dominator {
+                        None => *predecessor,
+                        Some(dominator) => intersect(&dominators, *predecessor, dominator),
+                    });
+                }
+                if dominator.is_some() && dominators[block.0] != dominator {
+                    dominators[block.0] = dominator;
+                    changed = true;
+                }
+            }
+        }
+
+        dominators[ENTRY_BLOCK.0] = None;
+        return dominators;
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  lowering                                  */
+/* -------------------------------------------------------------------------- */
+
+/// Targets of `continuar` and `parar` in the innermost loop being lowered.
+struct LoopTargets {
+    continue_target: BlockID,
+    break_target: BlockID,
+}
+
+struct Lowering<'a> {
+    ast: &'a Ast,
+    resolution: &'a Resolution,
+    types: &'a TypeTable,
+    functions: HashMap<ScopeDefID, FunctionID>,
+    structs: HashMap<ScopeDefID, StructID>,
+    enums: HashMap<ScopeDefID, EnumID>,
+    function: Function,
+    current: BlockID,
+    /// Locals of the variables of the program, by their declaration.
+    locals: HashMap<IdentifierID, LocalID>,
+    loops: Vec<LoopTargets>,
+}
+
+fn is_reference(ty: &Type) -> bool {
+    return matches!(ty, Type::Ref{..} | Type::Comp{..});
+}
+
+fn reference_to(ty: &Type) -> Type {
+    return Type::Ref{is_mut: true, inner: Box::new(ty.clone())};
+}
+
+impl<'a> Lowering<'a> {
+
+    fn lexical_info(&self, node: impl Into<NodeID>) -> LexicalInfo {
+        return *self.ast.get_lexical_info(node);
+    }
+
+    fn emit(&mut self, kind: InstructionKind, node: impl Into<NodeID>) {
+
+        let lexical_info = self.lexical_info(node);
+        self.function.blocks[self.current.0].instructions.push(Instruction{kind, lexical_info});
+    }
+
+    /// Emits an instruction that assigns a new temporary of the type, returning the temporary.
+    fn define(&mut self, ty: Type, node: impl Into<NodeID>, kind: impl FnOnce(Temp) -> InstructionKind) -> Operand {
+
+        self.function.temps.push(ty);
+        let temp = Temp(self.function.temps.len() - 1);
+        self.emit(kind(temp), node);
+        return Operand::Temp(temp);
+    }
+
+    fn new_block(&mut self) -> BlockID {
+
+        let lexical_info = self.function.lexical_info;
+        self.function.blocks.push(BasicBlock{
+            instructions: Vec::new(),
+            terminator: Terminator::Return(Operand::Constant(Constant::Nothing)),
+            terminator_info: lexical_info,
+        });
+        return BlockID(self.function.blocks.len() - 1);
+    }
+
+    /// Ends the current block, and continues in `next`.
+    fn terminate(&mut self, terminator: Terminator, node: impl Into<NodeID>, next: BlockID) {
+
+        let lexical_info = self.lexical_info(node);
+        let block = &mut self.function.blocks[self.current.0];
+        block.terminator = terminator;
+        block.terminator_info = lexical_info;
+        self.current = next;
+    }
+
+    /// Ends the current block with a jump or return. What comes after it in the same block of
+    /// the program can not run, so it goes to a new block that nothing jumps to.
+    fn terminate_unreachable(&mut self, terminator: Terminator, node: impl Into<NodeID>) {
+
+        let next = self.new_block();
+        self.terminate(terminator, node, next);
+    }
+
+    fn new_local(&mut self, name: String, ty: Type) -> LocalID {
+
+        self.function.locals.push(Local{name, ty});
+        return LocalID(self.function.locals.len() - 1);
+    }
+
+    /// A local that does not exist in the program, named so it can not clash with one.
+    fn hidden_local(&mut self, name: &str, ty: Type) -> LocalID {
+
+        let name = format!("${}{}", name, self.function.locals.len());
+        return self.new_local(name, ty);
+    }
+
+    /// Local of a declared variable. A variable can be used before its declaration statement,
+    /// so its local is created on the first use.
+    fn declaration_local(&mut self, declaration: IdentifierID) -> LocalID {
+
+        if let Some(local) = self.locals.get(&declaration) {
+            return *local;
+        }
+
+        // variables with the same name in different blocks get different names
+        let base = self.ast.get_identifier(declaration).clone();
+        let mut name = base.clone();
+        let mut count = 1;
+        while self.function.locals.iter().any(|local| local.name == name) {
+            count += 1;
+            name = format!("{}.{}", base, count);
+        }
+
+        let local = self.new_local(name, self.types.declaration_type(declaration).clone());
+        self.locals.insert(declaration, local);
+        return local;
+    }
+
+    fn use_local(&mut self, use_id: IdentifierID) -> Option<LocalID> {
+
+        return match self.resolution.symbol(use_id)? {
+            Symbol::Var(_) | Symbol::Param(_) | Symbol::ForEachItem(_) | Symbol::CaseBinding(_) => {
+                let declaration = self.resolution.declaration_of(use_id)?;
+                Some(self.declaration_local(declaration))
+            },
+            Symbol::Function(_) | Symbol::Builtin(_) | Symbol::Struct(_) | Symbol::Enum(_) | Symbol::Variant(..) => None,
+        };
+    }
+
+    fn expr_type(&self, id: ExprID) -> Type {
+        return self.types.expr_type(id).clone();
+    }
+
+    /* ---- expressions ---- */
+
+    /// Lowers an expression where a value of type `target` is expected, like `eval_as` in the
+    /// interpreter: references are created from places, other values are copied out of references.
+    fn lower_as(&mut self, id: ExprID, target: &Type) -> Operand {
+
+        return match target {
+            Type::Ref{..} | Type::Comp{..} if is_reference(&self.expr_type(id)) => self.lower_expr(id),
+            Type::Ref{..} => self.lower_place(id),
+            Type::Comp{..} => {
+                let value = self.lower_value(id);
+                self.define(target.clone(), id, |dest| InstructionKind::Box{dest, value})
+            },
+            _ => self.lower_value(id),
+        };
+    }
+
+    fn lower_value(&mut self, id: ExprID) -> Operand {
+
+        let ty = self.expr_type(id);
+        let value = self.lower_expr(id);
+        return match is_reference(&ty) {
+            true => self.define(ty.dereferenced().clone(), id, |dest| InstructionKind::Dereference{dest, value}),
+            false => value,
+        };
+    }
+
+    /// A reference to the storage of an expression, temporary when it is not a place.
+    fn lower_place(&mut self, id: ExprID) -> Operand {
+
+        return match self.ast.get_expression(id) {
+            Expression::Identifier{node_id} => {
+                let local = self.use_local(*node_id).expect("only variables are used as places");
+                let ty = reference_to(&self.function.locals[local.0].ty);
+                self.define(ty, id, |dest| InstructionKind::LocalRef{dest, local})
+            },
+            Expression::UnaryOprt{oprt: UnaryOprt::Deref, operand} => self.lower_expr(*operand),
+            Expression::Access{object, field_name} => {
+                let index = self.types.field_index(*object, self.ast.get_identifier(*field_name))
+                    .expect("the type checker only allows accessing fields that exist");
+                let ty = reference_to(self.types.expr_type(id));
+                let pointer = self.lower_place(*object);
+                self.define(ty, id, |dest| InstructionKind::FieldRef{dest, pointer, index})
+            },
+            _ => {
+                let ty = reference_to(self.types.expr_type(id).dereferenced());
+                let value = self.lower_value(id);
+                self.define(ty, id, |dest| InstructionKind::Box{dest, value})
+            },
+        };
+    }
+
+    fn lower_expr(&mut self, id: ExprID) -> Operand {
+
+        let ty = self.expr_type(id);
+
+        return match self.ast.get_expression(id) {
+            Expression::IntegerLiteral{value} => Operand::Constant(Constant::Integer(value.clone())),
+            Expression::DecimalLiteral{value} => Operand::Constant(Constant::Decimal(value.clone())),
+            Expression::StringLiteral{value} => Operand::Constant(Constant::Text(value.clone())),
+            Expression::InterpolatedString{parts} => {
+                // the parts are joined like `"Olá, " + para_texto(nome)`
+                let mut text = None;
+                for part in parts {
+                    let part = match part {
+                        StringPart::Text(part_text) => Operand::Constant(Constant::Text(part_text.clone())),
+                        StringPart::Value(value) => {
+                            let args = vec![self.lower_value(*value)];
+                            self.define(Type::Text, *value, |dest| InstructionKind::CallBuiltin{dest, builtin: Builtin::ToText, args})
+                        },
+                    };
+                    text = Some(match text {
+                        Some(left) => self.define(Type::Text, id, |dest| InstructionKind::Binary{dest, oprt: BinaryOprt::Add, left, right: part}),
+                        None => part,
+                    });
+                }
+                text.expect("interpolated strings have at least one value")
+            },
+            Expression::BooleanLiteral{value} => Operand::Constant(Constant::Boolean(*value)),
+            Expression::ListLiteral{values} => {
+                let item_type = match &ty {
+                    Type::List(item_type) => item_type.as_ref().clone(),
+                    other => unreachable!("list literals have list types, found {}", other),
+                };
+                let items = values.iter().map(|value| self.lower_as(*value, &item_type)).collect();
+                self.define(ty, id, |dest| InstructionKind::MakeList{dest, items})
+            },
+            Expression::Identifier{node_id} if matches!(self.resolution.symbol(*node_id), Some(Symbol::Variant(..))) => {
+                self.lower_variant(id, *node_id, Vec::new())
+            },
+            Expression::Identifier{node_id} => {
+                let local = self.use_local(*node_id).expect("subprograms are only used as callees");
+                let ty = self.function.locals[local.0].ty.clone();
+                self.define(ty, id, |dest| InstructionKind::LoadLocal{dest, local})
+            },
+            Expression::Call{callee, args} => self.lower_call(id, *callee, args),
+            Expression::StructLiteral{fields, ..} => self.lower_struct_literal(id, fields),
+            Expression::Access{..} => {
+                let pointer = self.lower_place(id);
+                self.define(ty, id, |dest| InstructionKind::Load{dest, pointer})
+            },
+            Expression::BinaryOprt{oprt: oprt @ (BinaryOprt::And | BinaryOprt::Or), left, right} => {
+                // the right side is only evaluated when the left one does not decide the result
+                let name = if *oprt == BinaryOprt::And { "e" } else { "ou" };
+                let result = self.hidden_local(name, Type::Boolean);
+                let left = self.lower_value(*left);
+                self.emit(InstructionKind::DeclareLocal{local: result, value: Some(left.clone())}, id);
+
+                let right_block = self.new_block();
+                let end = self.new_block();
+                let (then_block, else_block) = match oprt {
+                    BinaryOprt::And => (right_block, end),
+                    _ => (end, right_block),
+                };
+                self.terminate(Terminator::Branch{condition: left, then_block, else_block}, id, right_block);
+
+                let right = self.lower_value(*right);
+                self.emit(InstructionKind::StoreLocal{local: result, value: right}, id);
+                self.terminate(Terminator::Jump(end), id, end);
+
+                self.define(Type::Boolean, id, |dest| InstructionKind::LoadLocal{dest, local: result})
+            },
+            Expression::BinaryOprt{oprt, left, right} => {
+                let (oprt, right_id) = (*oprt, *right);
+                let left = self.lower_value(*left);
+                let right = self.lower_value(*right);
+                // errors of a division are caused by the divisor
+                let location = if oprt == BinaryOprt::Div { right_id } else { id };
+                self.define(ty, location, |dest| InstructionKind::Binary{dest, oprt, left, right})
+            },
+            Expression::UnaryOprt{oprt: UnaryOprt::Deref, operand} => {
+                let pointer = self.lower_expr(*operand);
+                self.define(ty, id, |dest| InstructionKind::Load{dest, pointer})
+            },
+            Expression::UnaryOprt{oprt, operand} => {
+                let oprt = *oprt;
+                let operand = self.lower_value(*operand);
+                self.define(ty, id, |dest| InstructionKind::Unary{dest, oprt, operand})
+            },
+            Expression::Assign{target, value} => {
+                // assigning to a `ref` or `comp` variable makes it point to another value
+                let target_type = self.expr_type(*target);
+                let value = self.lower_as(*value, &target_type);
+
+                let local = match self.ast.get_expression(*target) {
+                    Expression::Identifier{node_id} => self.use_local(*node_id),
+                    _ => None,
+                };
+                match local {
+                    Some(local) => self.emit(InstructionKind::StoreLocal{local, value}, id),
+                    None => {
+                        let pointer = self.lower_place(*target);
+                        self.emit(InstructionKind::Store{pointer, value}, id);
+                    },
+                }
+                Operand::Constant(Constant::Nothing)
+            },
+            Expression::Error => unreachable!("programs with errors are not lowered"),
+        };
+    }
+
+    fn lower_struct_literal(&mut self, id: ExprID, fields: &[FieldInit]) -> Operand {
+
+        let ty = self.expr_type(id);
+        let Type::Struct{id: struct_id, ..} = &ty else {
+            unreachable!("structure literals have structure types, found {}", ty);
+        };
+        let definition = self.types.fields(*struct_id);
+
+        // the values are evaluated in the order they are written, and stored in the order of the
+        // definition
+        let mut values = vec![Operand::Constant(Constant::Nothing); definition.len()];
+        for field in fields {
+            let name = self.ast.get_identifier(field.name);
+            let index = definition.iter().position(|field| field.name == *name)
+                .expect("the type checker only allows fields that exist");
+            values[index] = self.lower_as(field.value, &definition[index].ty);
+        }
+
+        let structure = self.structs[struct_id];
+        return self.define(ty, id, |dest| InstructionKind::MakeStruct{dest, structure, fields: values});
+    }
+
+    /// A value of the variant `name` refers to.
+    fn lower_variant(&mut self, id: ExprID, name: IdentifierID, payload: Vec<Operand>) -> Operand {
+
+        let Some(Symbol::Variant(enum_id, variant)) = self.resolution.symbol(name) else {
+            unreachable!("only variants are lowered as variants");
+        };
+        let enumeration = self.enums[&enum_id];
+        return self.define(self.expr_type(id), id, |dest| InstructionKind::MakeVariant{dest, enumeration, variant, payload});
+    }
+
+    fn lower_call(&mut self, id: ExprID, callee: ExprID, args: &[ExprID]) -> Operand {
+
+        let params = match self.types.expr_type(callee).dereferenced() {
+            Type::Function{params, ..} => params.clone(),
+            other => unreachable!("the type checker only allows calling subprograms, found {}", other),
+        };
+
+        let arg_values: Vec<Operand> = args.iter().zip(&params).map(|(arg, param)| self.lower_as(*arg, param)).collect();
+
+        let Expression::Identifier{node_id} = self.ast.get_expression(callee) else {
+            unreachable!("subprograms are only called by their names");
+        };
+
+        let ty = self.expr_type(id);
+        return match self.resolution.symbol(*node_id) {
+            Some(Symbol::Function(scope_def)) => {
+                let function = self.functions[&scope_def];
+                self.define(ty, id, |dest| InstructionKind::Call{dest, function, args: arg_values})
+            },
+            Some(Symbol::Builtin(builtin)) => {
+                // a position that does not exist is the fault of the argument
+                let location = if builtin == Builtin::Get { args[1] } else { id };
+                self.define(ty, location, |dest| InstructionKind::CallBuiltin{dest, builtin, args: arg_values})
+            },
+            Some(Symbol::Variant(..)) => self.lower_variant(id, *node_id, arg_values),
+            _ => unreachable!("the type checker only allows calling subprograms"),
+        };
+    }
+
+    /* ---- statements ---- */
+
+    fn lower_block(&mut self, block: &[StmtID]) {
+
+        for stmt in block {
+            self.lower_stmt(*stmt);
+        }
+    }
+
+    fn lower_loop_body(&mut self, targets: LoopTargets, body_block: &[StmtID], id: StmtID) {
+
+        let continue_target = targets.continue_target;
+        self.loops.push(targets);
+        self.lower_block(body_block);
+        self.loops.pop();
+        self.terminate_unreachable(Terminator::Jump(continue_target), id);
+    }
+
+    fn lower_stmt(&mut self, id: StmtID) {
+
+        match self.ast.get_statement(id) {
+            Statement::Expression{expr} => {
+                self.lower_expr(*expr);
+            },
+            Statement::VarDecl{name, init_expr, ..} => {
+                let declared = self.types.declaration_type(*name).clone();
+                let value = init_expr.map(|init_expr| self.lower_as(init_expr, &declared));
+                let local = self.declaration_local(*name);
+                self.emit(InstructionKind::DeclareLocal{local, value}, id);
+            },
+            Statement::If{branches, else_body} => {
+                let mut end = None;
+                for (index, branch) in branches.iter().enumerate() {
+                    let condition = self.lower_value(branch.cond_expr);
+                    let then_id = self.new_block();
+                    let end_id = *end.get_or_insert_with(|| self.new_block());
+
+                    // a failed condition moves on to the next `senão se`, then the `senão`
+                    let is_last = index + 1 == branches.len();
+                    let next = match (is_last, else_body) {
+                        (true, None) => end_id,
+                        _ => self.new_block(),
+                    };
+                    self.terminate(Terminator::Branch{condition, then_block: then_id, else_block: next}, id, then_id);
+
+                    self.lower_block(&branch.body);
+                    self.terminate(Terminator::Jump(end_id), id, next);
+                }
+                let end = end.expect("a conditional has at least one branch");
+                if let Some(else_body) = else_body {
+                    self.lower_block(else_body);
+                    self.terminate(Terminator::Jump(end), id, end);
+                }
+            },
+            Statement::Match{value, cases} => self.lower_match(id, *value, cases),
+            Statement::While{cond_expr, body_block} => {
+                let header = self.new_block();
+                self.terminate(Terminator::Jump(header), id, header);
+
+                let condition = self.lower_value(*cond_expr);
+                let body = self.new_block();
+                let exit = self.new_block();
+                self.terminate(Terminator::Branch{condition, then_block: body, else_block: exit}, id, body);
+
+                self.lower_loop_body(LoopTargets{continue_target: header, break_target: exit}, body_block, id);
+                self.current = exit;
+            },
+            Statement::ForEach{item, iter_expr, body_block} => self.lower_for_each(id, *item, *iter_expr, body_block),
+            Statement::Return{expr} => {
+                let value = match expr {
+                    Some(expr) => {
+                        let return_type = self.function.return_type.clone();
+                        self.lower_as(*expr, &return_type)
+                    },
+                    None => Operand::Constant(Constant::Nothing),
+                };
+                self.terminate_unreachable(Terminator::Return(value), id);
+            },
+            Statement::Continue => {
+                let target = self.loops.last().expect("`continuar` is only allowed in loops").continue_target;
+                self.terminate_unreachable(Terminator::Jump(target), id);
+            },
+            Statement::Break => {
+                let target = self.loops.last().expect("`parar` is only allowed in loops").break_target;
+                self.terminate_unreachable(Terminator::Jump(target), id);
+            },
+            Statement::Error => unreachable!("programs with errors are not lowered"),
+        }
+    }
+
+    /// Lowers an `escolha` as a chain of branches on the variant of the value.
+    fn lower_match(&mut self, id: StmtID, value_id: ExprID, cases: &[MatchCase]) {
+
+        let value = self.lower_value(value_id);
+        let Type::Enum{id: enum_id, ..} = self.expr_type(value_id).dereferenced().clone() else {
+            unreachable!("the type checker only allows choosing between the variants of an enumeration");
+        };
+        let tag = {
+            let value = value.clone();
+            self.define(Type::Integer, value_id, |dest| InstructionKind::Tag{dest, value})
+        };
+        let end = self.new_block();
+
+        for (index, case) in cases.iter().enumerate() {
+
+            let Some(Symbol::Variant(_, variant)) = self.resolution.symbol(case.variant) else {
+                unreachable!("the type checker only allows variants in cases");
+            };
+
+            // every variant has a case, so the last one runs without checking
+            let next = match index + 1 == cases.len() {
+                true => end,
+                false => {
+                    let tag = tag.clone();
+                    let condition = self.define(Type::Boolean, case.variant, |dest| InstructionKind::Binary{
+                        dest, oprt: BinaryOprt::Eq, left: tag, right: Operand::Constant(Constant::Integer(Integer::from(variant))),
+                    });
+                    let case_block = self.new_block();
+                    let next = self.new_block();
+                    self.terminate(Terminator::Branch{condition, then_block: case_block, else_block: next}, case.variant, case_block);
+                    next
+                },
+            };
+
+            for (position, binding) in case.bindings.iter().enumerate() {
+                let ty = self.types.variants(enum_id)[variant].payload[position].clone();
+                let value = value.clone();
+                let payload = self.define(ty, *binding, |dest| InstructionKind::Payload{dest, value, variant, index: position});
+                let local = self.declaration_local(*binding);
+                self.emit(InstructionKind::DeclareLocal{local, value: Some(payload)}, *binding);
+            }
+
+            self.lower_block(&case.body);
+            self.terminate(Terminator::Jump(end), id, next);
+        }
+    }
+
+    fn lower_for_each(&mut self, id: StmtID, item: ForEachDeclID, iter_expr: ExprID, body_block: &[StmtID]) {
+
+        let for_each_decl = self.ast.get_for_each_decl(item);
+        let item_type = match self.types.expr_type(iter_expr).dereferenced() {
+            Type::List(item_type) => item_type.as_ref().clone(),
+            _ => Type::Text,
+        };
+
+        let iterated_type = reference_to(self.types.expr_type(iter_expr).dereferenced());
+        let iterated = self.lower_as(iter_expr, &iterated_type);
+        let items = self.define(Type::List(Box::new(item_type.clone())), id, |dest| InstructionKind::Items{dest, iterated});
+        let count = {
+            let items = items.clone();
+            self.define(Type::Integer, id, |dest| InstructionKind::ItemCount{dest, items})
+        };
+        let position = self.hidden_local("i", Type::Integer);
+        self.emit(InstructionKind::DeclareLocal{local: position, value: Some(Operand::Constant(Constant::Integer(Integer::from(0))))}, id);
+
+        let header = self.new_block();
+        let body = self.new_block();
+        let latch = self.new_block();
+        let exit = self.new_block();
+        self.terminate(Terminator::Jump(header), id, header);
+
+        let index = self.define(Type::Integer, id, |dest| InstructionKind::LoadLocal{dest, local: position});
+        let condition = {
+            let index = index.clone();
+            self.define(Type::Boolean, id, |dest| InstructionKind::Binary{dest, oprt: BinaryOprt::Lt, left: index, right: count})
+        };
+        self.terminate(Terminator::Branch{condition, then_block: body, else_block: exit}, id, body);
+
+        let item_ref = self.define(reference_to(&item_type), for_each_decl.name, |dest| InstructionKind::ItemRef{dest, items, index});
+        let value = match for_each_decl.is_ref {
+            true => item_ref,
+            false => self.define(item_type, for_each_decl.name, |dest| InstructionKind::Load{dest, pointer: item_ref}),
+        };
+        let local = self.declaration_local(for_each_decl.name);
+        self.emit(InstructionKind::DeclareLocal{local, value: Some(value)}, for_each_decl.name);
+        self.lower_loop_body(LoopTargets{continue_target: latch, break_target: exit}, body_block, id);
+
+        self.current = latch;
+        let index = self.define(Type::Integer, id, |dest| InstructionKind::LoadLocal{dest, local: position});
+        let next = self.define(Type::Integer, id, |dest| InstructionKind::Binary{
+            dest, oprt: BinaryOprt::Add, left: index, right: Operand::Constant(Constant::Integer(Integer::from(1))),
+        });
+        self.emit(InstructionKind::StoreLocal{local: position, value: next}, id);
+        self.terminate(Terminator::Jump(header), id, exit);
+    }
+
+    /* ---- subprograms ---- */
+
+    fn lower_subprogram(&mut self, id: ScopeDefID) -> Function {
+
+        let definition = self.ast.get_scope_def(id);
+        let return_type = match self.types.declaration_type(definition.name()) {
+            Type::Function{return_type, ..} => return_type.as_ref().clone(),
+            other => unreachable!("subprograms have function types, found {}", other),
+        };
+
+        self.function = Function{
+            name: self.ast.get_identifier(definition.name()).clone(),
+            param_count: definition.params().len(),
+            return_type,
+            is_pure: !definition.is_procedure(),
+            locals: Vec::new(),
+            temps: Vec::new(),
+            blocks: Vec::new(),
+            lexical_info: self.lexical_info(id),
+        };
+        self.locals.clear();
+        self.current = self.new_block();
+
+        // the parameters are the first locals
+        for param in definition.params() {
+            self.declaration_local(self.ast.get_function_param(*param).name);
+        }
+
+        self.lower_block(definition.body());
+        // a procedure that reaches its end without `retornar` returns `nada`, subprograms that
+        // return values never reach it
+        self.terminate(Terminator::Return(Operand::Constant(Constant::Nothing)), id, ENTRY_BLOCK);
+
+        return std::mem::replace(&mut self.function, empty_function());
+    }
+}
+
+fn empty_function() -> Function {
+
+    let position = LexicalPosition{line: 0, column: 0};
+    return Function{
+        name: String::new(),
+        param_count: 0,
+        return_type: Type::Nothing,
+        is_pure: false,
+        locals: Vec::new(),
+        temps: Vec::new(),
+        blocks: Vec::new(),
+        lexical_info: LexicalInfo{start: position, end: position},
+    };
+}
+
+/// Lowers a program without errors to the IR.
+pub fn lower(ast: &Ast, resolution: &Resolution, types: &TypeTable) -> Program {
+
+    let subprograms = ast.get_subprograms();
+    let functions = subprograms.iter().enumerate().map(|(index, scope_def)| (*scope_def, FunctionID(index))).collect();
+
+    let mut layouts = struct_layouts(ast, types);
+    let structs: Vec<StructDef> = ast.get_structs().into_iter()
+        .map(|id| StructDef{
+            id,
+            layout: layouts.remove(&id).expect("every structure has a layout"),
+            field_types: types.fields(id).iter().map(|field| field.ty.clone()).collect(),
+        })
+        .collect();
+
+    let mut layouts = enum_layouts(ast, types);
+    let enums: Vec<EnumDef> = ast.get_enums().into_iter()
+        .map(|id| EnumDef{
+            id,
+            layout: layouts.remove(&id).expect("every enumeration has a layout"),
+            payload_types: types.variants(id).iter().map(|variant| variant.payload.clone()).collect(),
+        })
+        .collect();
+
+    let mut lowering = Lowering{
+        ast,
+        resolution,
+        types,
+        functions,
+        structs: structs.iter().enumerate().map(|(index, definition)| (definition.id, StructID(index))).collect(),
+        enums: enums.iter().enumerate().map(|(index, definition)| (definition.id, EnumID(index))).collect(),
+        function: empty_function(),
+        current: ENTRY_BLOCK,
+        locals: HashMap::new(),
+        loops: Vec::new(),
+    };
+
+    let functions = subprograms.iter().map(|scope_def| lowering.lower_subprogram(*scope_def)).collect();
+    let entry_point = find_entry_point(ast).map(|entry_point| lowering.functions[&entry_point]);
+
+    return Program{functions, structs, enums, entry_point};
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   printer                                  */
+/* -------------------------------------------------------------------------- */
+
+impl std::fmt::Display for Temp {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "%{}", self.0);
+    }
+}
+
+impl std::fmt::Display for BlockID {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "bb{}", self.0);
+    }
+}
+
+impl std::fmt::Display for Operand {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+        return match self {
+            Operand::Temp(temp) => write!(f, "{}", temp),
+            Operand::Constant(constant) => write!(f, "{}", constant.to_value().item_display()),
+        };
+    }
+}
+
+fn operand_list(operands: &[Operand]) -> String {
+    return operands.iter().map(|operand| operand.to_string()).collect::<Vec<String>>().join(", ");
+}
+
+fn print_instruction(program: &Program, function: &Function, kind: &InstructionKind) -> String {
+
+    let local = |local: &LocalID| &function.locals[local.0].name;
+
+    let operation = match kind {
+        InstructionKind::Copy{value, ..} => format!("copy {}", value),
+        InstructionKind::LoadLocal{local: id, ..} => format!("load {}", local(id)),
+        InstructionKind::StoreLocal{local: id, value} => format!("store {}, {}", local(id), value),
+        InstructionKind::DeclareLocal{local: id, value: Some(value)} => format!("declare {}, {}", local(id), value),
+        InstructionKind::DeclareLocal{local: id, value: None} => format!("declare {}", local(id)),
+        InstructionKind::LocalRef{local: id, ..} => format!("ref {}", local(id)),
+        InstructionKind::Load{pointer, ..} => format!("load *{}", pointer),
+        InstructionKind::Store{pointer, value} => format!("store *{}, {}", pointer, value),
+        InstructionKind::Dereference{value, ..} => format!("value {}", value),
+        InstructionKind::Box{value, ..} => format!("box {}", value),
+        InstructionKind::MakeList{items, ..} => format!("list [{}]", operand_list(items)),
+        InstructionKind::MakeStruct{structure, fields, ..} => {
+            let layout = &program.structs[structure.0].layout;
+            let fields: Vec<String> = layout.fields.iter().zip(fields).map(|(name, value)| format!("{}: {}", name, value)).collect();
+            format!("struct {} {{{}}}", layout.name, fields.join(", "))
+        },
+        InstructionKind::FieldRef{pointer, index, ..} => {
+            let field = match function.operand_type(pointer).dereferenced() {
+                Type::Struct{id, ..} => program.struct_def(*id).and_then(|definition| definition.layout.fields.get(*index)).cloned(),
+                _ => None,
+            };
+            format!("field_ref {}.{}", pointer, field.unwrap_or_else(|| index.to_string()))
+        },
+        InstructionKind::MakeVariant{enumeration, variant, payload, ..} => {
+            let layout = &program.enums[enumeration.0].layout;
+            match payload.is_empty() {
+                true => format!("variant {}.{}", layout.name, layout.variants[*variant]),
+                false => format!("variant {}.{}({})", layout.name, layout.variants[*variant], operand_list(payload)),
+            }
+        },
+        InstructionKind::Tag{value, ..} => format!("tag {}", value),
+        InstructionKind::Payload{value, variant, index, ..} => {
+            let name = match function.operand_type(value).dereferenced() {
+                Type::Enum{id, ..} => program.enum_def(*id).and_then(|definition| definition.layout.variants.get(*variant)).cloned(),
+                _ => None,
+            };
+            format!("payload {}.{}.{}", value, name.unwrap_or_else(|| variant.to_string()), index)
+        },
+        InstructionKind::Unary{oprt, operand, ..} => format!("{} {}", oprt.symbol(), operand),
+        InstructionKind::Binary{oprt, left, right, ..} => format!("{} {} {}", left, oprt.symbol(), right),
+        InstructionKind::Call{function, args, ..} => format!("call {}({})", program.functions[function.0].name, operand_list(args)),
+        InstructionKind::CallBuiltin{builtin, args, ..} => format!("builtin {}({})", builtin.name(), operand_list(args)),
+        InstructionKind::Items{iterated, ..} => format!("items {}", iterated),
+        InstructionKind::ItemCount{items, ..} => format!("count {}", items),
+        InstructionKind::ItemRef{items, index, ..} => format!("item_ref {}[{}]", items, index),
+    };
+
+    return match kind.dest() {
+        Some(dest) => format!("{}: {} = {}", dest, function.temp_type(dest), operation),
+        None => operation,
+    };
+}
+
+fn print_terminator(terminator: &Terminator) -> String {
+
+    return match terminator {
+        Terminator::Jump(target) => format!("jump {}", target),
+        Terminator::Branch{condition, then_block, else_block} => format!("branch {}, {}, {}", condition, then_block, else_block),
+        Terminator::Return(value) => format!("return {}", value),
+    };
+}
+
+/// Writes a function in the text format of the IR, like:
+///
+/// ```text
+/// fn dobro(x: inteiro) -> inteiro
+/// bb0:
+///     %0: inteiro = load x
+///     %1: inteiro = %0 * 2
+///     return %1
+/// ```
+pub fn print_function(program: &Program, function: &Function) -> String {
+
+    let mut output = String::new();
+
+    let params: Vec<String> = function.locals[..function.param_count].iter()
+        .map(|local| format!("{}: {}", local.name, local.ty))
+        .collect();
+    writeln!(output, "fn {}({}) -> {}", function.name, params.join(", "), function.return_type).expect("writing to a string does not fail");
+    for local in &function.locals[function.param_count..] {
+        writeln!(output, "    local {}: {}", local.name, local.ty).expect("writing to a string does not fail");
+    }
+
+    for (index, block) in function.blocks.iter().enumerate() {
+        writeln!(output, "{}:", BlockID(index)).expect("writing to a string does not fail");
+        for instruction in &block.instructions {
+            writeln!(output, "    {}", print_instruction(program, function, &instruction.kind)).expect("writing to a string does not fail");
+        }
+        writeln!(output, "    {}", print_terminator(&block.terminator)).expect("writing to a string does not fail");
+    }
+
+    return output;
+}
+
+/// Writes every function of the program in the text format of the IR.
+pub fn print_program(program: &Program) -> String {
+
+    return program.functions.iter()
+        .map(|function| print_function(program, function))
+        .collect::<Vec<String>>()
+        .join("\n");
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::analysis::{analyze, AnalysisSettings};
+    use crate::tso_parser::parse_source;
+
+
+    fn lower_source(source_code: &str) -> Program {
+
+        let output = parse_source(source_code).unwrap();
+        let analysis = analyze(&output.ast, &AnalysisSettings::default());
+        assert!(!output.has_errors() && !analysis.has_errors());
+
+        return lower(&output.ast, &analysis.resolution, &analysis.types);
+    }
+
+    #[test]
+    fn test_print() {
+
+        let source_code = r#"
+            func dobro(x: inteiro) -> inteiro {
+                retornar x * 2;
+            }
+
+            procedimento principal() {
+                var mut contador: inteiro = 0;
+                enquanto contador < 3 e verdadeiro {
+                    contador = contador + 1;
+                }
+                escrever(dobro(contador));
+            }
+        "#;
+
+        let expected = [
+            "fn dobro(x: inteiro) -> inteiro",
+            "bb0:",
+            "    %0: inteiro = load x",
+            "    %1: inteiro = %0 * 2",
+            "    return %1",
+            "bb1:",
+            "    return nada",
+            "",
+            "fn principal() -> nada",
+            "    local contador: inteiro",
+            "    local $e1: lógico",
+            "bb0:",
+            "    declare contador, 0",
+            "    jump bb1",
+            "bb1:",
+            "    %0: inteiro = load contador",
+            "    %1: lógico = %0 < 3",
+            "    declare $e1, %1",
+            "    branch %1, bb2, bb3",
+            "bb2:",
+            "    store $e1, verdadeiro",
+            "    jump bb3",
+            "bb3:",
+            "    %2: lógico = load $e1",
+            "    branch %2, bb4, bb5",
+            "bb4:",
+            "    %3: inteiro = load contador",
+            "    %4: inteiro = %3 + 1",
+            "    store contador, %4",
+            "    jump bb1",
+            "bb5:",
+            "    %5: inteiro = load contador",
+            "    %6: inteiro = call dobro(%5)",
+            "    %7: nada = builtin escrever(%6)",
+            "    return nada",
+            "bb6:",
+            "    return nada",
+            "",
+        ].join("\n");
+
+        assert_eq!(print_program(&lower_source(source_code)), expected);
+    }
+
+    #[test]
+    fn test_else_if() {
+
+        let source_code = r#"
+            func sinal(x: inteiro) -> inteiro {
+                var s: inteiro;
+                se x < 0 { s = -1; } senão se x > 0 { s = 1; } senão { s = 0; }
+                retornar s;
+            }
+        "#;
+
+        let expected = [
+            "fn sinal(x: inteiro) -> inteiro",
+            "    local s: inteiro",
+            "bb0:",
+            "    declare s",
+            "    %0: inteiro = load x",
+            "    %1: lógico = %0 < 0",
+            "    branch %1, bb1, bb3",
+            "bb1:",
+            "    store s, -1",
+            "    jump bb2",
+            "bb2:",
+            "    %4: inteiro = load s",
+            "    return %4",
+            "bb3:",
+            "    %2: inteiro = load x",
+            "    %3: lógico = %2 > 0",
+            "    branch %3, bb4, bb5",
+            "bb4:",
+            "    store s, 1",
+            "    jump bb2",
+            "bb5:",
+            "    store s, 0",
+            "    jump bb2",
+            "bb6:",
+            "    return nada",
+            "",
+        ].join("\n");
+
+        assert_eq!(print_program(&lower_source(source_code)), expected);
+    }
+
+    #[test]
+    fn test_for_each() {
+
+        let source_code = r#"
+            procedimento dobrar(mut itens: mut ref lista<inteiro>) {
+                para cada mut ref item em itens { *item = *item * 2; }
+            }
+        "#;
+
+        let expected = [
+            "fn dobrar(itens: mut ref lista<inteiro>) -> nada",
+            "    local $i1: inteiro",
+            "    local item: mut ref inteiro",
+            "bb0:",
+            "    %0: mut ref lista<inteiro> = load itens",
+            "    %1: lista<inteiro> = items %0",
+            "    %2: inteiro = count %1",
+            "    declare $i1, 0",
+            "    jump bb1",
+            "bb1:",
+            "    %3: inteiro = load $i1",
+            "    %4: lógico = %3 < %2",
+            "    branch %4, bb2, bb4",
+            "bb2:",
+            "    %5: mut ref inteiro = item_ref %1[%3]",
+            "    declare item, %5",
+            "    %6: mut ref inteiro = load item",
+            "    %7: inteiro = load *%6",
+            "    %8: inteiro = %7 * 2",
+            "    %9: mut ref inteiro = load item",
+            "    store *%9, %8",
+            "    jump bb3",
+            "bb3:",
+            "    %10: inteiro = load $i1",
+            "    %11: inteiro = %10 + 1",
+            "    store $i1, %11",
+            "    jump bb1",
+            "bb4:",
+            "    return nada",
+            "bb5:",
+            "    return nada",
+            "",
+        ].join("\n");
+
+        assert_eq!(print_program(&lower_source(source_code)), expected);
+    }
+
+    #[test]
+    fn test_structs() {
+
+        let source_code = r#"
+            estrutura Ponto { x: inteiro, y: inteiro }
+
+            func girar(p: ref Ponto) -> Ponto {
+                retornar Ponto { y: p.x, x: -p.y };
+            }
+        "#;
+
+        let expected = [
+            "fn girar(p: ref Ponto) -> Ponto",
+            "bb0:",
+            "    %0: mut ref ref Ponto = ref p",
+            "    %1: mut ref inteiro = field_ref %0.x",
+            "    %2: inteiro = load *%1",
+            "    %3: mut ref ref Ponto = ref p",
+            "    %4: mut ref inteiro = field_ref %3.y",
+            "    %5: inteiro = load *%4",
+            "    %6: inteiro = - %5",
+            "    %7: Ponto = struct Ponto {x: %6, y: %2}",
+            "    return %7",
+            "bb1:",
+            "    return nada",
+            "",
+        ].join("\n");
+
+        assert_eq!(print_program(&lower_source(source_code)), expected);
+    }
+
+    #[test]
+    fn test_enums() {
+
+        let source_code = r#"
+            enumeração Forma { Círculo(decimal), Vazio }
+
+            func área(f: Forma) -> decimal {
+                escolha f {
+                    caso Círculo(r) => { retornar r * r; }
+                    caso Vazio => { retornar 0.0; }
+                }
+            }
+        "#;
+
+        let expected = [
+            "fn área(f: Forma) -> decimal",
+            "    local r: decimal",
+            "bb0:",
+            "    %0: Forma = load f",
+            "    %1: inteiro = tag %0",
+            "    %2: lógico = %1 == 0",
+            "    branch %2, bb2, bb3",
+            "bb1:",
+            "    return nada",
+            "bb2:",
+            "    %3: decimal = payload %0.Círculo.0",
+            "    declare r, %3",
+            "    %4: decimal = load r",
+            "    %5: decimal = load r",
+            "    %6: decimal = %4 * %5",
+            "    return %6",
+            "bb3:",
+            "    return 0.0",
+            "bb4:",
+            "    jump bb1",
+            "bb5:",
+            "    jump bb1",
+            "",
+        ].join("\n");
+
+        assert_eq!(print_program(&lower_source(source_code)), expected);
+    }
+
+    #[test]
+    fn test_dominators() {
+
+        let source_code = r#"
+            func sinal(x: inteiro) -> inteiro {
+                se x < 0 { retornar -1; } senão { retornar 1; }
+            }
+        "#;
+
+        let program = lower_source(source_code);
+        let function = &program.functions[0];
+
+        // bb0 branches to bb1 (then) and bb3 (else), which return; bb2 joins them but nothing
+        // reaches it
+        assert_eq!(function.reverse_postorder(), vec![BlockID(0), BlockID(3), BlockID(1)]);
+        assert_eq!(function.reachable(), vec![true, true, false, true, false, false]);
+        assert_eq!(function.immediate_dominators(), vec![None, Some(BlockID(0)), None, Some(BlockID(0)), None, None]);
+        assert_eq!(function.predecessors()[2], vec![BlockID(4), BlockID(5)]);
+    }
+}
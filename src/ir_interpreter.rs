@@ -0,0 +1,420 @@
+// std
+use std::io::{BufRead, Write};
+
+// local
+use crate::ir::*;
+use crate::numbers::Integer;
+use crate::runtime::*;
+
+
+/* -------------------------------------------------------------------------- */
+/*                               IR interpreter                               */
+/* -------------------------------------------------------------------------- */
+
+/// A running function.
+struct Frame {
+    function: FunctionID,
+    block: BlockID,
+    /// Position of the next instruction in the block, the terminator after the last one.
+    index: usize,
+    /// Storage of the locals, created when first used.
+    locals: Vec<Option<Cell>>,
+    temps: Vec<Option<Value>>,
+    /// Temporary of the caller that receives the returned value.
+    result: Option<Temp>,
+}
+
+impl Frame {
+
+    fn new(function: FunctionID, definition: &Function, args: Vec<Value>, result: Option<Temp>) -> Frame {
+
+        let mut locals = vec![None; definition.locals.len()];
+        for (slot, arg) in args.into_iter().enumerate() {
+            locals[slot] = Some(new_cell(arg));
+        }
+
+        return Frame{function, block: ENTRY_BLOCK, index: 0, locals, temps: vec![None; definition.temps.len()], result};
+    }
+
+    /// Storage of a local, a variable can receive a value before its declaration runs.
+    fn local(&mut self, local: LocalID) -> Cell {
+        return self.locals[local.0].get_or_insert_with(|| new_cell(Value::Nothing)).clone();
+    }
+
+    fn temp(&self, temp: Temp) -> &Value {
+        return self.temps[temp.0].as_ref().expect("the verifier checks that temporaries are assigned before they are used");
+    }
+
+    fn value(&self, operand: &Operand) -> Value {
+
+        return match operand {
+            Operand::Temp(temp) => self.temp(*temp).clone(),
+            Operand::Constant(constant) => constant.to_value(),
+        };
+    }
+
+    /// The storage of `Items`, read without copying it.
+    fn items(&self, operand: &Operand) -> &[Cell] {
+
+        return match operand {
+            Operand::Temp(temp) => match self.temp(*temp) {
+                Value::List(items) => items,
+                other => unreachable!("items are kept in a list, found {:?}", other),
+            },
+            Operand::Constant(constant) => unreachable!("items are never constants, found {:?}", constant),
+        };
+    }
+
+    fn pointer(&self, operand: &Operand) -> Cell {
+
+        return match self.value(operand) {
+            Value::Ref(target) => target,
+            other => unreachable!("the verifier only allows references here, found {:?}", other),
+        };
+    }
+}
+
+struct Interpreter<'a> {
+    program: &'a Program,
+    frames: Vec<Frame>,
+    input: &'a mut dyn BufRead,
+    output: &'a mut dyn Write,
+}
+
+impl<'a> Interpreter<'a> {
+
+    fn execute_instruction(&mut self, instruction: &Instruction) -> RuntimeResult<()> {
+
+        let error = |kind: RuntimeErrorKind| RuntimeError{kind, lexical_info: instruction.lexical_info};
+        let depth = self.frames.len();
+        let frame = self.frames.last_mut().expect("instructions only run inside a function");
+
+        let value = match &instruction.kind {
+            InstructionKind::Copy{value, ..} => frame.value(value),
+            InstructionKind::LoadLocal{local, ..} => frame.local(*local).borrow().clone(),
+            InstructionKind::StoreLocal{local, value} => {
+                let value = frame.value(value);
+                *frame.local(*local).borrow_mut() = value;
+                return Ok(());
+            },
+            InstructionKind::DeclareLocal{local, value} => {
+                let value = value.as_ref().map(|value| frame.value(value)).unwrap_or(Value::Nothing);
+                frame.locals[local.0] = Some(new_cell(value));
+                return Ok(());
+            },
+            InstructionKind::LocalRef{local, ..} => Value::Ref(frame.local(*local)),
+            InstructionKind::Load{pointer, ..} => frame.pointer(pointer).borrow().clone(),
+            InstructionKind::Store{pointer, value} => {
+                let value = frame.value(value);
+                *frame.pointer(pointer).borrow_mut() = value;
+                return Ok(());
+            },
+            InstructionKind::Dereference{value, ..} => frame.value(value).dereferenced(),
+            InstructionKind::Box{value, ..} => Value::Ref(new_cell(frame.value(value))),
+            InstructionKind::MakeList{items, ..} => Value::List(items.iter().map(|item| new_cell(frame.value(item))).collect()),
+            InstructionKind::MakeStruct{structure, fields, ..} => Value::Struct{
+                layout: self.program.structs[structure.0].layout.clone(),
+                fields: fields.iter().map(|field| new_cell(frame.value(field))).collect(),
+            },
+            InstructionKind::FieldRef{pointer, index, ..} => match &*follow_refs(frame.pointer(pointer)).borrow() {
+                Value::Struct{fields, ..} => Value::Ref(fields[*index].clone()),
+                other => unreachable!("the verifier only allows references to structures here, found {:?}", other),
+            },
+            InstructionKind::MakeVariant{enumeration, variant, payload, ..} => Value::Enum{
+                layout: self.program.enums[enumeration.0].layout.clone(),
+                variant: *variant,
+                payload: payload.iter().map(|value| new_cell(frame.value(value))).collect(),
+            },
+            InstructionKind::Tag{value, ..} => match frame.value(value).dereferenced() {
+                Value::Enum{variant, ..} => Value::Integer(Integer::from(variant)),
+                other => unreachable!("the verifier only allows values of enumerations here, found {:?}", other),
+            },
+            InstructionKind::Payload{value, index, ..} => match frame.value(value).dereferenced() {
+                Value::Enum{payload, ..} => payload[*index].borrow().clone(),
+                other => unreachable!("the verifier only allows values of enumerations here, found {:?}", other),
+            },
+            InstructionKind::Unary{oprt, operand, ..} => unary_oprt(*oprt, frame.value(operand)).map_err(error)?,
+            InstructionKind::Binary{oprt, left, right, ..} => binary_oprt(*oprt, frame.value(left), frame.value(right)).map_err(error)?,
+            InstructionKind::Call{dest, function, args} => {
+                if depth >= MAX_CALL_DEPTH {
+                    return Err(error(RuntimeErrorKind::CallDepthExceeded));
+                }
+                let args = args.iter().map(|arg| frame.value(arg)).collect();
+                let callee = Frame::new(*function, &self.program.functions[function.0], args, Some(*dest));
+                self.frames.push(callee);
+                return Ok(());
+            },
+            InstructionKind::CallBuiltin{builtin, args, ..} => {
+                let args: Vec<Value> = args.iter().map(|arg| frame.value(arg)).collect();
+                call_builtin(*builtin, &args, self.input, self.output).map_err(error)?
+            },
+            InstructionKind::Items{iterated, ..} => Value::List(iteration_items(frame.pointer(iterated))),
+            InstructionKind::ItemCount{items, ..} => Value::Integer(Integer::from(frame.items(items).len())),
+            InstructionKind::ItemRef{items, index, ..} => {
+                let index = frame.value(index).as_integer().to_usize().expect("item positions are never negative");
+                Value::Ref(frame.items(items)[index].clone())
+            },
+        };
+
+        let dest = instruction.kind.dest().expect("instructions without a temporary return early");
+        frame.temps[dest.0] = Some(value);
+        return Ok(());
+    }
+
+    fn execute_terminator(&mut self, terminator: &Terminator) {
+
+        let frame = self.frames.last_mut().expect("terminators only run inside a function");
+
+        let target = match terminator {
+            Terminator::Jump(target) => *target,
+            Terminator::Branch{condition, then_block, else_block} => {
+                match frame.value(condition).as_boolean() {
+                    true => *then_block,
+                    false => *else_block,
+                }
+            },
+            Terminator::Return(value) => {
+                let value = frame.value(value);
+                let result = frame.result;
+                self.frames.pop();
+                if let (Some(caller), Some(result)) = (self.frames.last_mut(), result) {
+                    caller.temps[result.0] = Some(value);
+                }
+                return;
+            },
+        };
+
+        frame.block = target;
+        frame.index = 0;
+    }
+
+    fn run(&mut self) -> RuntimeResult<()> {
+
+        let program = self.program;
+
+        while let Some(frame) = self.frames.last_mut() {
+
+            let block = program.functions[frame.function.0].block(frame.block);
+            let index = frame.index;
+            frame.index += 1;
+
+            match block.instructions.get(index) {
+                Some(instruction) => self.execute_instruction(instruction)?,
+                None => self.execute_terminator(&block.terminator),
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+/// Runs a lowered program from its entry point, reading and writing the terminal through
+/// `input` and `output`.
+pub fn execute(program: &Program, input: &mut dyn BufRead, output: &mut dyn Write) -> RuntimeResult<()> {
+
+    let entry_point = program.entry_point.expect("programs are only executed when they have an entry point");
+    let definition = &program.functions[entry_point.0];
+    let mut interpreter = Interpreter{program, frames: vec![Frame::new(entry_point, definition, Vec::new(), None)], input, output};
+
+    let result = interpreter.run();
+
+    interpreter.output.flush().map_err(|error| RuntimeError{
+        kind: RuntimeErrorKind::Io(error.to_string()),
+        lexical_info: definition.lexical_info,
+    })?;
+
+    return result;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::analysis::{analyze, AnalysisSettings};
+    use crate::ast::{LexicalInfo, LexicalPosition};
+    use crate::bytecode::compile;
+    use crate::ir_verifier::verify;
+    use crate::tso_parser::parse_source;
+    use crate::vm;
+
+
+    /// Runs the program on the IR, checking that the lowering is valid and that the virtual
+    /// machine gives the same result.
+    fn run_with_input(source_code: &str, input: &str) -> RuntimeResult<String> {
+
+        let output = parse_source(source_code).unwrap();
+        assert!(!output.has_errors());
+        let analysis = analyze(&output.ast, &AnalysisSettings::default());
+        assert!(!analysis.has_errors());
+
+        let program = lower(&output.ast, &analysis.resolution, &analysis.types);
+        assert_eq!(verify(&program), Vec::new());
+
+        let mut written = Vec::new();
+        let result = execute(&program, &mut input.as_bytes(), &mut written).map(|()| String::from_utf8(written).unwrap());
+
+        let bytecode = compile(&program);
+        let mut written = Vec::new();
+        let expected = vm::execute(&bytecode, &mut input.as_bytes(), &mut written).map(|()| String::from_utf8(written).unwrap());
+        assert_eq!(result, expected);
+
+        return result;
+    }
+
+    fn run(source_code: &str) -> RuntimeResult<String> {
+        return run_with_input(source_code, "");
+    }
+
+    fn location(line: usize, start: usize, end: usize) -> LexicalInfo {
+        return LexicalInfo{start: LexicalPosition{line, column: start}, end: LexicalPosition{line, column: end}};
+    }
+
+    #[test]
+    fn test_values() {
+
+        let source_code = r#"
+            func fatorial(n: inteiro) -> inteiro {
+                se n <= 1 { retornar 1; }
+                retornar n * fatorial(n - 1);
+            }
+
+            procedimento principal() {
+                var nome: texto = ler();
+                var itens: lista<decimal> = [1.0, 3.0];
+                escrever("olá, " + nome);
+                escrever(fatorial(10));
+                escrever(fatorial(25));
+                escrever(itens);
+                escrever(-obter(itens, 1) * 2.0);
+                escrever(tamanho("ação") == 4 e (1 < 2 ou 1 / 0 == 0));
+            }
+        "#;
+
+        assert_eq!(run_with_input(source_code, "ila\n").unwrap(), "olá, ila\n3628800\n15511210043330985984000000\n[1.0, 3.0]\n-6.0\nverdadeiro\n");
+    }
+
+    #[test]
+    fn test_else_if() {
+
+        let source_code = r#"
+            func comparar(a: inteiro, b: inteiro) -> texto {
+                var resultado: texto;
+                se a < b { resultado = "menor"; } senão se a > b { resultado = "maior"; } senão { resultado = "igual"; }
+                retornar resultado;
+            }
+
+            procedimento principal() {
+                escrever(comparar(1, 2));
+                escrever(comparar(2, 1));
+                escrever(comparar(3, 3));
+                se falso { escrever("a"); } senão se falso { escrever("b"); }
+            }
+        "#;
+
+        assert_eq!(run(source_code).unwrap(), "menor\nmaior\nigual\n");
+    }
+
+    #[test]
+    fn test_references() {
+
+        let source_code = r#"
+            procedimento dobrar(mut itens: mut ref lista<inteiro>) {
+                para cada mut ref item em itens {
+                    *item = *item * 2;
+                    se *item > 4 { parar; }
+                }
+            }
+
+            procedimento principal() {
+                var mut itens: lista<inteiro> = [1, 2, 3, 4];
+                var copia: lista<inteiro> = itens;
+                var c: comp lista<inteiro> = itens;
+                dobrar(itens);
+                para cada item em itens {
+                    se item == 4 { continuar; }
+                    adicionar(itens, item);
+                }
+                var mut contador: inteiro = 0;
+                enquanto contador < 2 { contador = contador + 1; }
+                escrever(itens);
+                escrever(copia);
+                escrever(obter(c, 0) + contador);
+            }
+        "#;
+
+        assert_eq!(run(source_code).unwrap(), "[2, 4, 6, 4, 2, 6]\n[1, 2, 3, 4]\n3\n");
+    }
+
+    #[test]
+    fn test_structs() {
+
+        let source_code = r#"
+            estrutura Conta { nome: texto, saldos: lista<inteiro> }
+
+            func abrir(nome: texto) -> Conta {
+                retornar Conta { saldos: [tamanho(nome)], nome: nome };
+            }
+
+            procedimento principal() {
+                var mut contas: lista<Conta> = [abrir("ana"), abrir("bia")];
+                para cada mut ref conta em contas {
+                    adicionar(conta.saldos, 10);
+                }
+                var cópia: Conta = obter(contas, 0);
+                escrever(contas);
+                escrever(cópia.saldos);
+                escrever("{cópia.nome}: {obter(cópia.saldos, 1)}");
+            }
+        "#;
+
+        let expected = "[Conta { nome: \"ana\", saldos: [3, 10] }, Conta { nome: \"bia\", saldos: [3, 10] }]\n[3, 10]\nana: 10\n";
+        assert_eq!(run(source_code).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_enums() {
+
+        let source_code = r#"
+            enumeração Resultado { Valor(inteiro), Erro(texto) }
+
+            func dividir(a: inteiro, b: inteiro) -> Resultado {
+                se b == 0 { retornar Erro("divisão por zero"); }
+                retornar Valor(a / b);
+            }
+
+            procedimento principal() {
+                var mut total: inteiro;
+                para cada b em [2, 0, 5] {
+                    escolha dividir(10, b) {
+                        caso Erro(mensagem) => { escrever(mensagem); total = 0; }
+                        caso Valor(v) => { total = v; }
+                    }
+                    escrever(total);
+                }
+            }
+        "#;
+
+        assert_eq!(run(source_code).unwrap(), "5\ndivisão por zero\n0\n2\n");
+    }
+
+    #[test]
+    fn test_runtime_errors() {
+
+        let division = "procedimento principal() {\n    escrever(1 / (2 - 2));\n}";
+        let error = run(division).unwrap_err();
+        assert_eq!((error.kind, error.lexical_info), (RuntimeErrorKind::DivisionByZero, location(1, 18, 23)));
+
+        let index = "procedimento principal() {\n    escrever(obter([1], 3));\n}";
+        let error = run(index).unwrap_err();
+        assert_eq!(error.kind, RuntimeErrorKind::IndexOutOfBounds{index: Integer::from(3), length: 1});
+        assert_eq!(error.lexical_info, location(1, 24, 25));
+
+        let recursion = "procedimento repetir() { repetir(); }\nprocedimento principal() { repetir(); }";
+        assert_eq!(run(recursion).unwrap_err().kind, RuntimeErrorKind::CallDepthExceeded);
+    }
+}
@@ -0,0 +1,544 @@
+// std
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+// local
+use crate::ast::BinaryOprt;
+use crate::ir::*;
+use crate::types::Type;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  verifier                                  */
+/* -------------------------------------------------------------------------- */
+
+/// A broken rule of the IR, caused by a bug in the lowering or in a pass, never by the program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationError {
+    pub function: String,
+    pub block: Option<BlockID>,
+    pub message: String,
+}
+
+impl std::fmt::Display for VerificationError {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+        return match self.block {
+            Some(block) => write!(f, "fn {}, {}: {}", self.function, block, self.message),
+            None => write!(f, "fn {}: {}", self.function, self.message),
+        };
+    }
+}
+
+/// Whether values of the types have the same representation. Mutability only matters to the
+/// checkers, and a `ref` and a `comp` are both references at runtime.
+fn compatible(expected: &Type, found: &Type) -> bool {
+
+    return match (expected, found) {
+        (Type::Ref{inner: expected, ..} | Type::Comp{inner: expected, ..}, Type::Ref{inner: found, ..} | Type::Comp{inner: found, ..}) => {
+            compatible(expected, found)
+        },
+        (Type::List(expected), Type::List(found)) => compatible(expected, found),
+        (Type::Function{params: expected_params, return_type: expected_return}, Type::Function{params: found_params, return_type: found_return}) => {
+            expected_params.len() == found_params.len()
+                && expected_params.iter().zip(found_params).all(|(expected, found)| compatible(expected, found))
+                && compatible(expected_return, found_return)
+        },
+        _ => expected == found,
+    };
+}
+
+fn pointee(ty: &Type) -> Option<&Type> {
+
+    return match ty {
+        Type::Ref{inner, ..} | Type::Comp{inner, ..} => Some(inner),
+        _ => None,
+    };
+}
+
+/// Type of an operand, or `Type::Error` for temporaries without a type, which are reported apart.
+fn operand_type(function: &Function, operand: &Operand) -> Type {
+
+    return match operand {
+        Operand::Temp(temp) if temp.0 >= function.temps.len() => Type::Error,
+        _ => function.operand_type(operand),
+    };
+}
+
+fn list_item(ty: &Type) -> Option<&Type> {
+
+    return match ty {
+        Type::List(item) => Some(item),
+        _ => None,
+    };
+}
+
+struct FunctionVerifier<'a> {
+    program: &'a Program,
+    function: &'a Function,
+    block: Option<BlockID>,
+    errors: Vec<VerificationError>,
+}
+
+impl<'a> FunctionVerifier<'a> {
+
+    fn error(&mut self, message: String) {
+        self.errors.push(VerificationError{function: self.function.name.clone(), block: self.block, message});
+    }
+
+    fn expect_type(&mut self, what: &str, expected: &Type, found: &Type) {
+
+        if !compatible(expected, found) {
+            self.error(format!("{} has type `{}`, expected `{}`", what, found, expected));
+        }
+    }
+
+    fn check_local(&mut self, local: LocalID) -> Option<Type> {
+
+        return match self.function.locals.get(local.0) {
+            Some(local) => Some(local.ty.clone()),
+            None => {
+                self.error(format!("local {} does not exist", local.0));
+                None
+            },
+        };
+    }
+
+    fn check_target(&mut self, target: BlockID) {
+
+        if target.0 >= self.function.blocks.len() {
+            self.error(format!("jump to {}, which does not exist", target));
+        }
+    }
+
+    /// Where each temporary is assigned, checking that it is assigned only once.
+    fn definitions(&mut self) -> HashMap<Temp, (BlockID, usize)> {
+
+        let mut definitions = HashMap::new();
+        for (block_index, block) in self.function.blocks.iter().enumerate() {
+            for (index, instruction) in block.instructions.iter().enumerate() {
+                let Some(dest) = instruction.kind.dest() else { continue };
+                self.block = Some(BlockID(block_index));
+                if dest.0 >= self.function.temps.len() {
+                    self.error(format!("{} has no type", dest));
+                } else if let Entry::Vacant(entry) = definitions.entry(dest) {
+                    entry.insert((BlockID(block_index), index));
+                } else {
+                    self.error(format!("{} is assigned more than once", dest));
+                }
+            }
+        }
+
+        return definitions;
+    }
+
+    /// Checks that every temporary used is assigned before, in every path that reaches the use.
+    fn check_dominance(&mut self, definitions: &HashMap<Temp, (BlockID, usize)>) {
+
+        let reachable = self.function.reachable();
+        let dominators = self.function.immediate_dominators();
+        let dominates = |dominator: BlockID, mut block: BlockID| {
+            loop {
+                if block == dominator {
+                    return true;
+                }
+                match dominators[block.0] {
+                    Some(next) => block = next,
+                    None => return false,
+                }
+            }
+        };
+
+        for (block_index, block) in self.function.blocks.iter().enumerate() {
+            let block_id = BlockID(block_index);
+            let mut uses: Vec<(usize, &Operand)> = Vec::new();
+            for (index, instruction) in block.instructions.iter().enumerate() {
+                uses.extend(instruction.kind.operands().into_iter().map(|operand| (index, operand)));
+            }
+            // the terminator runs after every instruction
+            uses.extend(block.terminator.operands().into_iter().map(|operand| (block.instructions.len(), operand)));
+
+            self.block = Some(block_id);
+            for (index, operand) in uses {
+                let Operand::Temp(temp) = operand else { continue };
+                match definitions.get(temp) {
+                    None => self.error(format!("{} is used but never assigned", temp)),
+                    // what can not run can not read unassigned temporaries
+                    Some(_) if !reachable[block_index] => {},
+                    Some((definition_block, definition_index)) => {
+                        let is_before = match *definition_block == block_id {
+                            true => *definition_index < index,
+                            false => dominates(*definition_block, block_id),
+                        };
+                        if !is_before {
+                            self.error(format!("{} is used where it may not be assigned", temp));
+                        }
+                    },
+                }
+            }
+        }
+    }
+
+    fn check_instruction(&mut self, kind: &InstructionKind) {
+
+        let function = self.function;
+        let dest_type = kind.dest().map(|dest| operand_type(function, &Operand::Temp(dest))).unwrap_or(Type::Nothing);
+        let ty = |operand: &Operand| operand_type(function, operand);
+
+        match kind {
+            InstructionKind::Copy{value, ..} => self.expect_type("the copy", &dest_type, &ty(value)),
+            InstructionKind::LoadLocal{local, ..} => {
+                if let Some(local_type) = self.check_local(*local) {
+                    self.expect_type("the loaded value", &dest_type, &local_type);
+                }
+            },
+            InstructionKind::StoreLocal{local, value} | InstructionKind::DeclareLocal{local, value: Some(value)} => {
+                if let Some(local_type) = self.check_local(*local) {
+                    self.expect_type("the stored value", &local_type, &ty(value));
+                }
+            },
+            InstructionKind::DeclareLocal{local, value: None} => {
+                self.check_local(*local);
+            },
+            InstructionKind::LocalRef{local, ..} => {
+                if let Some(local_type) = self.check_local(*local) {
+                    self.expect_type("the reference", &dest_type, &Type::Ref{is_mut: true, inner: Box::new(local_type)});
+                }
+            },
+            InstructionKind::Load{pointer, ..} => match pointee(&ty(pointer)) {
+                Some(inner) => self.expect_type("the loaded value", &dest_type, inner),
+                None => self.error(format!("load from `{}`, which is not a reference", ty(pointer))),
+            },
+            InstructionKind::Store{pointer, value} => match pointee(&ty(pointer)) {
+                Some(inner) => self.expect_type("the stored value", inner, &ty(value)),
+                None => self.error(format!("store to `{}`, which is not a reference", ty(pointer))),
+            },
+            InstructionKind::Dereference{value, ..} => self.expect_type("the value", &dest_type, ty(value).dereferenced()),
+            InstructionKind::Box{value, ..} => match pointee(&dest_type) {
+                Some(inner) => self.expect_type("the boxed value", inner, &ty(value)),
+                None => self.error(format!("box of type `{}`, which is not a reference", dest_type)),
+            },
+            InstructionKind::MakeList{items, ..} => match list_item(&dest_type) {
+                Some(item_type) => {
+                    for item in items {
+                        self.expect_type("the item", item_type, &ty(item));
+                    }
+                },
+                None => self.error(format!("list of type `{}`", dest_type)),
+            },
+            InstructionKind::MakeStruct{structure, fields, ..} => {
+                let Some(definition) = self.program.structs.get(structure.0) else {
+                    self.error(format!("structure {}, which does not exist", structure.0));
+                    return;
+                };
+                if !matches!(&dest_type, Type::Struct{id, ..} if *id == definition.id) {
+                    self.error(format!("structure `{}` of type `{}`", definition.layout.name, dest_type));
+                }
+                if fields.len() != definition.field_types.len() {
+                    self.error(format!("structure `{}` with {} field(s), expected {}", definition.layout.name, fields.len(), definition.field_types.len()));
+                    return;
+                }
+                for ((field, field_type), name) in fields.iter().zip(&definition.field_types).zip(&definition.layout.fields) {
+                    self.expect_type(&format!("the field `{}`", name), field_type, &ty(field));
+                }
+            },
+            InstructionKind::FieldRef{pointer, index, ..} => {
+                let field_type = match pointee(&ty(pointer)).map(Type::dereferenced) {
+                    Some(Type::Struct{id, ..}) => self.program.struct_def(*id).and_then(|definition| definition.field_types.get(*index)).cloned(),
+                    _ => None,
+                };
+                match field_type {
+                    Some(field_type) => self.expect_type("the reference", &dest_type, &Type::Ref{is_mut: true, inner: Box::new(field_type)}),
+                    None => self.error(format!("field {} of `{}`, which is not a reference to a structure with it", index, ty(pointer))),
+                }
+            },
+            InstructionKind::MakeVariant{enumeration, variant, payload, ..} => {
+                let Some(definition) = self.program.enums.get(enumeration.0) else {
+                    self.error(format!("enumeration {}, which does not exist", enumeration.0));
+                    return;
+                };
+                if !matches!(&dest_type, Type::Enum{id, ..} if *id == definition.id) {
+                    self.error(format!("enumeration `{}` of type `{}`", definition.layout.name, dest_type));
+                }
+                let Some(payload_types) = definition.payload_types.get(*variant) else {
+                    self.error(format!("variant {} of `{}`, which does not exist", variant, definition.layout.name));
+                    return;
+                };
+                let name = &definition.layout.variants[*variant];
+                if payload.len() != payload_types.len() {
+                    self.error(format!("variant `{}` with {} value(s), expected {}", name, payload.len(), payload_types.len()));
+                    return;
+                }
+                for (value, value_type) in payload.iter().zip(payload_types) {
+                    self.expect_type(&format!("a value of `{}`", name), value_type, &ty(value));
+                }
+            },
+            InstructionKind::Tag{value, ..} => {
+                if !matches!(ty(value).dereferenced(), Type::Enum{..}) {
+                    self.error(format!("tag of `{}`, which is not an enumeration", ty(value)));
+                }
+                self.expect_type("the tag", &dest_type, &Type::Integer);
+            },
+            InstructionKind::Payload{value, variant, index, ..} => {
+                let value_type = match ty(value).dereferenced() {
+                    Type::Enum{id, ..} => self.program.enum_def(*id)
+                        .and_then(|definition| definition.payload_types.get(*variant))
+                        .and_then(|payload_types| payload_types.get(*index))
+                        .cloned(),
+                    _ => None,
+                };
+                match value_type {
+                    Some(value_type) => self.expect_type("the value", &dest_type, &value_type),
+                    None => self.error(format!("value {} of variant {} of `{}`, which does not have it", index, variant, ty(value))),
+                }
+            },
+            InstructionKind::Unary{operand, ..} => self.expect_type("the operand", &dest_type, &ty(operand)),
+            InstructionKind::Binary{oprt, left, right, ..} => {
+                let is_comparison = matches!(oprt, BinaryOprt::Eq | BinaryOprt::Neq | BinaryOprt::Lt | BinaryOprt::Le | BinaryOprt::Gt | BinaryOprt::Ge);
+                let is_valid = match is_comparison {
+                    true => dest_type == Type::Boolean,
+                    false => dest_type.is_numeric() || (*oprt == BinaryOprt::Add && dest_type == Type::Text),
+                };
+                if !is_valid {
+                    self.error(format!("`{} {} {}` can not have type `{}`", ty(left), oprt.symbol(), ty(right), dest_type));
+                }
+            },
+            InstructionKind::Call{function, args, ..} => {
+                let Some(callee) = self.program.functions.get(function.0) else {
+                    self.error(format!("call of function {}, which does not exist", function.0));
+                    return;
+                };
+                if args.len() != callee.param_count {
+                    self.error(format!("call of `{}` with {} argument(s), expected {}", callee.name, args.len(), callee.param_count));
+                    return;
+                }
+                for (arg, param) in args.iter().zip(&callee.locals) {
+                    self.expect_type(&format!("the argument `{}` of `{}`", param.name, callee.name), &param.ty, &ty(arg));
+                }
+                self.expect_type("the result", &dest_type, &callee.return_type);
+            },
+            InstructionKind::CallBuiltin{builtin, args, ..} => {
+                let arg_types: Vec<Type> = args.iter().map(ty).collect();
+                let Type::Function{params, return_type} = builtin.signature(&arg_types) else {
+                    unreachable!("builtins have function types");
+                };
+                if args.len() != params.len() {
+                    self.error(format!("call of `{}` with {} argument(s), expected {}", builtin.name(), args.len(), params.len()));
+                    return;
+                }
+                for (arg_type, param) in arg_types.iter().zip(&params) {
+                    self.expect_type(&format!("an argument of `{}`", builtin.name()), param, arg_type);
+                }
+                self.expect_type("the result", &dest_type, &return_type);
+            },
+            InstructionKind::Items{iterated, ..} => {
+                let item_type = match pointee(&ty(iterated)).map(Type::dereferenced) {
+                    Some(Type::List(item)) => item.as_ref().clone(),
+                    Some(Type::Text) => Type::Text,
+                    _ => {
+                        self.error(format!("items of `{}`, which is not a reference to a list or a text", ty(iterated)));
+                        return;
+                    },
+                };
+                self.expect_type("the items", &dest_type, &Type::List(Box::new(item_type)));
+            },
+            InstructionKind::ItemCount{items, ..} => {
+                if list_item(&ty(items)).is_none() {
+                    self.error(format!("count of `{}`, which are not items", ty(items)));
+                }
+                self.expect_type("the count", &dest_type, &Type::Integer);
+            },
+            InstructionKind::ItemRef{items, index, ..} => {
+                match list_item(&ty(items)) {
+                    Some(item) => self.expect_type("the reference", &dest_type, &Type::Ref{is_mut: true, inner: Box::new(item.clone())}),
+                    None => self.error(format!("item of `{}`, which are not items", ty(items))),
+                }
+                self.expect_type("the position", &Type::Integer, &ty(index));
+            },
+        }
+    }
+
+    fn check_terminator(&mut self, terminator: &Terminator) {
+
+        for target in terminator.successors() {
+            self.check_target(target);
+        }
+
+        match terminator {
+            Terminator::Jump(_) => {},
+            Terminator::Branch{condition, ..} => self.expect_type("the condition", &Type::Boolean, &operand_type(self.function, condition)),
+            // the end of every subprogram returns `nada`, even where the flow checker makes sure it
+            // is never reached
+            Terminator::Return(Operand::Constant(Constant::Nothing)) => {},
+            Terminator::Return(value) => self.expect_type("the returned value", &self.function.return_type, &operand_type(self.function, value)),
+        }
+    }
+
+    fn verify(&mut self) {
+
+        if self.function.blocks.is_empty() {
+            self.error("the function has no blocks".to_string());
+            return;
+        }
+        if self.function.param_count > self.function.locals.len() {
+            self.error(format!("{} parameter(s) but {} local(s)", self.function.param_count, self.function.locals.len()));
+            return;
+        }
+
+        for (index, block) in self.function.blocks.iter().enumerate() {
+            self.block = Some(BlockID(index));
+            for instruction in &block.instructions {
+                self.check_instruction(&instruction.kind);
+            }
+            self.check_terminator(&block.terminator);
+        }
+
+        // the graph can only be walked when every jump goes to a block
+        if self.errors.iter().any(|error| error.message.starts_with("jump to")) {
+            return;
+        }
+
+        let definitions = self.definitions();
+        self.check_dominance(&definitions);
+    }
+}
+
+/// Checks the rules every program in the IR follows: jumps go to blocks of the same function,
+/// temporaries are assigned once and before they are used, and the operands of every instruction
+/// have the types it expects.
+pub fn verify(program: &Program) -> Vec<VerificationError> {
+
+    let mut errors = Vec::new();
+    for function in &program.functions {
+        let mut verifier = FunctionVerifier{program, function, block: None, errors: Vec::new()};
+        verifier.verify();
+        errors.extend(verifier.errors);
+    }
+
+    if let Some(entry_point) = program.entry_point {
+        if entry_point.0 >= program.functions.len() {
+            errors.push(VerificationError{
+                function: String::new(),
+                block: None,
+                message: format!("the entry point is function {}, which does not exist", entry_point.0),
+            });
+        }
+    }
+
+    return errors;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::analysis::{analyze, AnalysisSettings};
+    use crate::tso_parser::parse_source;
+
+
+    fn lower_source(source_code: &str) -> Program {
+
+        let output = parse_source(source_code).unwrap();
+        let analysis = analyze(&output.ast, &AnalysisSettings::default());
+        assert!(!output.has_errors() && !analysis.has_errors());
+
+        return lower(&output.ast, &analysis.resolution, &analysis.types);
+    }
+
+    fn messages(program: &Program) -> Vec<String> {
+        return verify(program).iter().map(|error| error.to_string()).collect();
+    }
+
+    const PROGRAM: &str = r#"
+        func soma(itens: ref lista<inteiro>) -> inteiro {
+            var mut total: inteiro = 0;
+            para cada item em itens {
+                se item < 0 ou item > 100 { continuar; }
+                total = total + item;
+            }
+            retornar total;
+        }
+
+        procedimento dobrar(mut itens: mut ref lista<inteiro>) {
+            para cada mut ref item em itens { *item = *item * 2; }
+        }
+
+        procedimento principal() {
+            var mut itens: lista<inteiro> = [1, 2, 3];
+            var c: comp lista<inteiro> = [4];
+            dobrar(itens);
+            adicionar(itens, obter(c, 0));
+            enquanto verdadeiro {
+                se tamanho(itens) > 5 { parar; }
+                adicionar(itens, soma(itens) / 2);
+            }
+            para cada letra em "ila" { escrever(letra + "!"); }
+            escrever(itens);
+            escrever(-1.5 * 2.0);
+            escrever(não falso);
+        }
+    "#;
+
+    #[test]
+    fn test_lowered_programs() {
+        assert_eq!(messages(&lower_source(PROGRAM)), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_broken_programs() {
+
+        let program = lower_source(PROGRAM);
+
+        // a jump to a block that does not exist
+        let mut broken = program.clone();
+        broken.functions[0].blocks[0].terminator = Terminator::Jump(BlockID(99));
+        assert_eq!(messages(&broken), vec!["fn soma, bb0: jump to bb99, which does not exist"]);
+
+        // a temporary assigned twice
+        let mut broken = program.clone();
+        let instruction = broken.functions[1].blocks[0].instructions[0].clone();
+        broken.functions[1].blocks[0].instructions.push(instruction);
+        assert_eq!(messages(&broken), vec!["fn dobrar, bb0: %0 is assigned more than once"]);
+
+        // a temporary used in the block that assigns it, before the assignment
+        let mut broken = program.clone();
+        broken.functions[1].blocks[0].instructions.swap(0, 1);
+        assert_eq!(messages(&broken), vec!["fn dobrar, bb0: %0 is used where it may not be assigned"]);
+
+        // a temporary used after the loop, where the loop may not have run
+        let mut broken = program.clone();
+        let loop_temp = broken.functions[1].temps.len() - 1;
+        broken.functions[1].blocks[4].terminator = Terminator::Return(Operand::Temp(Temp(loop_temp)));
+        let messages = messages(&broken);
+        assert!(messages.contains(&format!("fn dobrar, bb4: %{} is used where it may not be assigned", loop_temp)), "{:?}", messages);
+
+        // operands of the wrong type
+        let mut broken = program.clone();
+        broken.functions[0].blocks[0].instructions[0].kind = InstructionKind::DeclareLocal{
+            local: LocalID(1),
+            value: Some(Operand::Constant(Constant::Text("zero".to_string()))),
+        };
+        assert_eq!(verify(&broken).iter().map(|error| error.to_string()).collect::<Vec<String>>(), vec![
+            "fn soma, bb0: the stored value has type `texto`, expected `inteiro`",
+        ]);
+
+        // a call with missing arguments
+        let mut broken = program.clone();
+        for block in &mut broken.functions[2].blocks {
+            for instruction in &mut block.instructions {
+                if let InstructionKind::Call{args, ..} = &mut instruction.kind {
+                    args.clear();
+                }
+            }
+        }
+        let errors = verify(&broken);
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|error| error.message.ends_with("with 0 argument(s), expected 1")));
+    }
+}
@@ -1,11 +1,591 @@
 #![allow(dead_code)] // TODO: reactivate this later
+#![allow(clippy::needless_return)] // explicit returns are the code style of this crate
 
+mod analysis;
 mod ast;
+mod ast_printer;
+mod builtin_types;
+mod builtins;
+mod bytecode;
+mod c_backend;
+mod diagnostic;
+mod flow_checker;
+mod init_checker;
+mod interpreter;
+mod ir;
+mod ir_interpreter;
+mod ir_verifier;
+mod mutability_checker;
+mod numbers;
+mod optimizer;
+mod ownership_checker;
+mod purity_checker;
+mod resolver;
+mod runtime;
 mod tso_parser;
+mod type_checker;
+mod types;
+mod vm;
+mod wasm_backend;
 
+// std
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
+// local
+use analysis::AnalysisSettings;
+use diagnostic::Diagnostic;
+use optimizer::OptimizationLevel;
 
-fn main() {
+// external
+use anyhow::{Context, Result};
 
-    println!("Hello, world!");
+
+/* -------------------------------------------------------------------------- */
+/*                                command line                                */
+/* -------------------------------------------------------------------------- */
+
+const USAGE: &str = "\
+uso: ila <comando> [opções] [arquivo.ila]
+
+comandos:
+    executar    verifica e executa o programa
+    compilar    gera um executável do programa, usando o compilador de C do sistema,
+                ou um módulo de WebAssembly com `--alvo`
+    verificar   verifica o programa sem executá-lo
+    ast         mostra a árvore sintática do programa
+    bytecode    mostra as instruções geradas para a máquina virtual
+    ir          mostra a representação intermediária do programa, com os blocos
+                de cada subprograma
+    ajuda       mostra esta mensagem
+
+opções:
+    --tipos-em-ingles   aceita os nomes em inglês dos tipos, como `int` para `inteiro`
+    --interpretar-ast   executa o programa percorrendo a árvore sintática, em vez da
+                        máquina virtual
+    --interpretar-ir    executa a representação intermediária do programa, em vez
+                        da máquina virtual
+    -O0, -O1, -O2       otimizações da representação intermediária, antes de
+                        executar, compilar ou mostrar o programa: nenhuma (o
+                        padrão), simplificações, ou também a expansão de funções
+                        pequenas nas chamadas; não se aplicam a `--interpretar-ast`
+    -o <saída>          caminho do arquivo gerado por `compilar`, por padrão o nome
+                        do arquivo sem a extensão, ou com `.wasm` ou `.wat`
+    --alvo <alvo>       o que `compilar` gera: `nativo` (executável, o padrão),
+                        `wasm` (módulo de WebAssembly) ou `wat` (o mesmo módulo
+                        em texto)
+
+quando o arquivo não é informado, ou é `-`, o programa é lido da entrada padrão.
+`compilar` usa o compilador indicado pela variável de ambiente `CC`, ou `cc`.
+os módulos de WebAssembly rodam no navegador com `runtime/wasm/ila.js`.";
+
+/// The program was checked or executed without errors.
+const EXIT_SUCCESS: u8 = 0;
+/// The program contains errors.
+const EXIT_PROGRAM_ERROR: u8 = 1;
+/// The command line is invalid, the input could not be read or the output could not be built.
+const EXIT_USAGE_ERROR: u8 = 2;
+/// The execution of the program stopped because of an error.
+const EXIT_RUNTIME_ERROR: u8 = 3;
+/// The compiler failed because of a bug in it, not in the program.
+const EXIT_INTERNAL_ERROR: u8 = 4;
+
+/// Stack of the thread that executes programs, deep enough for the interpreter call limit.
+const INTERPRETER_STACK_SIZE: usize = 512 * 1024 * 1024;
+/// Stack of the thread that checks and compiles programs, deep enough for the nesting limit of the
+/// parser, where long chains of operators only count a level every few operators.
+const COMPILER_STACK_SIZE: usize = 256 * 1024 * 1024;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Run,
+    Compile,
+    Check,
+    Ast,
+    Bytecode,
+    Ir,
+    Help,
+}
+
+/// How `executar` runs the program.
+#[derive(Debug, PartialEq, Eq)]
+enum Engine {
+    /// Compiles the program to bytecode and runs it on the virtual machine.
+    Vm,
+    /// Evaluates the syntax tree directly, slower but simpler.
+    AstInterpreter,
+    /// Lowers the program to the IR and runs its basic blocks.
+    IrInterpreter,
+}
+
+/// What `compilar` generates.
+#[derive(Debug, PartialEq, Eq)]
+enum Target {
+    /// An executable, through the C compiler of the system.
+    Native,
+    /// A WebAssembly module in the binary format, to run in browsers.
+    Wasm,
+    /// The same WebAssembly module in the text format.
+    Wat,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Input {
+    File(String),
+    Stdin,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Options {
+    command: Command,
+    input: Input,
+    settings: AnalysisSettings,
+    engine: Engine,
+    /// Where `compilar` writes what it generates.
+    output: Option<String>,
+    target: Target,
+    optimization: OptimizationLevel,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+
+    let command = match args.first().map(|arg| arg.as_str()) {
+        Some("executar") => Command::Run,
+        Some("compilar") => Command::Compile,
+        Some("verificar") => Command::Check,
+        Some("ast") => Command::Ast,
+        Some("bytecode") => Command::Bytecode,
+        Some("ir") => Command::Ir,
+        Some("ajuda") | Some("-h") | Some("--help") => Command::Help,
+        Some(other) => return Err(format!("comando desconhecido `{}`", other)),
+        None => return Err("nenhum comando informado".to_string()),
+    };
+
+    let mut settings = AnalysisSettings::default();
+    let mut engine = Engine::Vm;
+    let mut output = None;
+    let mut target = Target::Native;
+    let mut optimization = None;
+    let mut paths = Vec::new();
+    let mut rest = args[1..].iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--tipos-em-ingles" => settings.english_type_aliases = true,
+            "--interpretar-ast" => engine = Engine::AstInterpreter,
+            "--interpretar-ir" => engine = Engine::IrInterpreter,
+            "-O0" => optimization = Some(OptimizationLevel::O0),
+            "-O1" => optimization = Some(OptimizationLevel::O1),
+            "-O2" => optimization = Some(OptimizationLevel::O2),
+            "-o" if command != Command::Compile => return Err("a opção `-o` só pode ser usada com `compilar`".to_string()),
+            "-o" => match rest.next() {
+                Some(path) => output = Some(path.clone()),
+                None => return Err("a opção `-o` precisa do caminho do executável".to_string()),
+            },
+            "--alvo" if command != Command::Compile => return Err("a opção `--alvo` só pode ser usada com `compilar`".to_string()),
+            "--alvo" => target = match rest.next().map(|target| target.as_str()) {
+                Some("nativo") => Target::Native,
+                Some("wasm") => Target::Wasm,
+                Some("wat") => Target::Wat,
+                Some(other) => return Err(format!("alvo desconhecido `{}`, use `nativo`, `wasm` ou `wat`", other)),
+                None => return Err("a opção `--alvo` precisa de um alvo: `nativo`, `wasm` ou `wat`".to_string()),
+            },
+            flag if flag.starts_with("-O") => return Err(format!("nível de otimização desconhecido `{}`, use `-O0`, `-O1` ou `-O2`", flag)),
+            flag if flag.starts_with("--") => return Err(format!("opção desconhecida `{}`", flag)),
+            path => paths.push(path.to_string()),
+        }
+    }
+
+    let input = match paths.as_slice() {
+        [] => Input::Stdin,
+        [path] if path == "-" => Input::Stdin,
+        [path] => Input::File(path.clone()),
+        [_, extra, ..] => return Err(format!("argumento inesperado `{}`", extra)),
+    };
+
+    // the AST interpreter does not use the intermediate representation, the other engines and backends do
+    let uses_ir = match command {
+        Command::Run => engine != Engine::AstInterpreter,
+        Command::Compile | Command::Bytecode | Command::Ir => true,
+        Command::Check | Command::Ast | Command::Help => false,
+    };
+    if optimization.is_some() && !uses_ir {
+        return Err("as opções `-O` só podem ser usadas com `executar`, `compilar`, `bytecode` ou `ir`, e não com `--interpretar-ast`".to_string());
+    }
+    let optimization = optimization.unwrap_or(OptimizationLevel::O0);
+
+    return Ok(Options{command, input, settings, engine, output, target, optimization});
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  pipeline                                  */
+/* -------------------------------------------------------------------------- */
+
+struct SourceFile {
+    name: String,
+    content: String,
+}
+
+fn read_source(input: &Input) -> Result<SourceFile> {
+
+    return match input {
+        Input::File(path) => {
+            let content = std::fs::read_to_string(path)
+                .with_context(|| format!("não foi possível ler o arquivo `{}`", path))?;
+            Ok(SourceFile{name: path.clone(), content})
+        },
+        Input::Stdin => {
+            let mut content = String::new();
+            std::io::stdin().read_to_string(&mut content)
+                .context("não foi possível ler a entrada padrão")?;
+            Ok(SourceFile{name: "<entrada>".to_string(), content})
+        },
+    };
+}
+
+fn report_diagnostics(source: &SourceFile, diagnostics: &[Diagnostic]) {
+
+    for diagnostic in diagnostics {
+        eprintln!("{}", diagnostic::render(diagnostic, &source.content, &source.name));
+    }
+
+    let error_count = diagnostics.iter().filter(|diagnostic| diagnostic.is_error()).count();
+    match error_count {
+        0 => {},
+        1 => eprintln!("{}: 1 erro encontrado", source.name),
+        _ => eprintln!("{}: {} erros encontrados", source.name, error_count),
+    }
+}
+
+const MISSING_ENTRY_POINT: &str = "o programa não tem um procedimento `principal` sem parâmetros, por onde a execução começa";
+
+fn run_program(ast: &ast::Ast, analysis: &analysis::Analysis, options: &Options, source: &SourceFile) -> u8 {
+
+    let Some(entry_point) = interpreter::find_entry_point(ast) else {
+        eprintln!("ila: {}", MISSING_ENTRY_POINT);
+        return EXIT_PROGRAM_ERROR;
+    };
+
+    let result = match options.engine {
+        Engine::Vm => {
+            let program = match lower_program(ast, analysis, options.optimization) {
+                Ok(program) => program,
+                Err(exit_code) => return exit_code,
+            };
+            vm::execute(&bytecode::compile(&program), &mut std::io::stdin().lock(), &mut std::io::stdout().lock())
+        },
+        // each call of the program uses several frames of the interpreter
+        Engine::AstInterpreter => std::thread::scope(|scope| {
+            let execution = std::thread::Builder::new()
+                .stack_size(INTERPRETER_STACK_SIZE)
+                .spawn_scoped(scope, || {
+                    let mut input = std::io::stdin().lock();
+                    let mut output = std::io::stdout().lock();
+                    interpreter::execute(ast, &analysis.resolution, &analysis.types, entry_point, &mut input, &mut output)
+                })
+                .expect("the interpreter thread can be created");
+            execution.join().expect("the interpreter does not panic")
+        }),
+        Engine::IrInterpreter => {
+            let program = match lower_program(ast, analysis, options.optimization) {
+                Ok(program) => program,
+                Err(exit_code) => return exit_code,
+            };
+            ir_interpreter::execute(&program, &mut std::io::stdin().lock(), &mut std::io::stdout().lock())
+        },
+    };
+
+    return match result {
+        Ok(()) => EXIT_SUCCESS,
+        Err(error) => {
+            eprintln!("{}", diagnostic::render(&error.to_diagnostic(), &source.content, &source.name));
+            EXIT_RUNTIME_ERROR
+        },
+    };
+}
+
+/// Lowers the program to the IR and optimizes it, checking the result with the verifier. A
+/// program the verifier rejects is a bug of the lowering or of a pass, reported before anything runs.
+fn lower_program(ast: &ast::Ast, analysis: &analysis::Analysis, optimization: OptimizationLevel) -> Result<ir::Program, u8> {
+
+    let mut program = ir::lower(ast, &analysis.resolution, &analysis.types);
+    optimizer::optimize(&mut program, optimization);
+    let errors = ir_verifier::verify(&program);
+    if errors.is_empty() {
+        return Ok(program);
+    }
+
+    eprintln!("ila: erro interno: o compilador gerou uma representação intermediária inválida para este programa");
+    for error in errors {
+        eprintln!("ila:     {}", error);
+    }
+    eprintln!("ila: o erro está no compilador, não no programa");
+    return Err(EXIT_INTERNAL_ERROR);
+}
+
+/// Where `compilar` writes what it generates when `-o` is not given: next to the program, with
+/// the extension of the target.
+fn default_output_path(source: &SourceFile, target: &Target) -> PathBuf {
+
+    let extension = match target {
+        Target::Native => "",
+        Target::Wasm => "wasm",
+        Target::Wat => "wat",
+    };
+
+    return match source.name.as_str() {
+        "<entrada>" => Path::new("programa").with_extension(extension),
+        name => Path::new(name).with_extension(extension),
+    };
+}
+
+fn compile_program(ast: &ast::Ast, analysis: &analysis::Analysis, options: &Options, source: &SourceFile) -> u8 {
+
+    if interpreter::find_entry_point(ast).is_none() {
+        eprintln!("ila: {}", MISSING_ENTRY_POINT);
+        return EXIT_PROGRAM_ERROR;
+    }
+
+    if !ast.get_structs().is_empty() || !ast.get_enums().is_empty() {
+        eprintln!("ila: `compilar` ainda não suporta estruturas e enumerações, use `executar` para rodar o programa");
+        return EXIT_USAGE_ERROR;
+    }
+
+    let output = match &options.output {
+        Some(path) => PathBuf::from(path),
+        None => default_output_path(source, &options.target),
+    };
+
+    let result = match options.target {
+        Target::Native => {
+            let program = match lower_program(ast, analysis, options.optimization) {
+                Ok(program) => program,
+                Err(exit_code) => return exit_code,
+            };
+            c_backend::build_executable(&c_backend::generate(&program, &source.name), &output)
+        },
+        Target::Wasm | Target::Wat => {
+            let program = match lower_program(ast, analysis, options.optimization) {
+                Ok(program) => program,
+                Err(exit_code) => return exit_code,
+            };
+            let wat = wasm_backend::generate_wat(&program, &source.name);
+            let content = match options.target {
+                Target::Wasm => wasm_backend::assemble(&wat),
+                _ => Ok(wat.into_bytes()),
+            };
+            content.and_then(|content| std::fs::write(&output, content)
+                .with_context(|| format!("não foi possível escrever o arquivo `{}`", output.display())))
+        },
+    };
+
+    return match result {
+        Ok(()) => EXIT_SUCCESS,
+        Err(error) => {
+            eprintln!("ila: {:#}", error);
+            EXIT_USAGE_ERROR
+        },
+    };
+}
+
+fn run_command(options: &Options, source: &SourceFile) -> u8 {
+
+    let command = &options.command;
+
+    let output = match tso_parser::parse_source(&source.content) {
+        Ok(output) => output,
+        Err(error) => {
+            eprintln!("ila: {:#}", error);
+            return EXIT_USAGE_ERROR;
+        }
+    };
+
+    report_diagnostics(source, &output.diagnostics());
+
+    // the partial ast is still shown, since it helps to understand how the errors were recovered
+    if *command == Command::Ast {
+
+        print!("{}", ast_printer::print_ast(&output.ast));
+    }
+
+    if output.has_errors() {
+
+        return EXIT_PROGRAM_ERROR;
+    }
+
+    if *command == Command::Ast {
+
+        return EXIT_SUCCESS;
+    }
+
+    let analysis = analysis::analyze(&output.ast, &options.settings);
+    report_diagnostics(source, &analysis.diagnostics);
+
+    if analysis.has_errors() {
+
+        return EXIT_PROGRAM_ERROR;
+    }
+
+    match command {
+        Command::Check => {
+            println!("{}: nenhum erro encontrado", source.name);
+            return EXIT_SUCCESS;
+        },
+        Command::Run => return run_program(&output.ast, &analysis, options, source),
+        Command::Compile => return compile_program(&output.ast, &analysis, options, source),
+        Command::Bytecode => {
+            return match lower_program(&output.ast, &analysis, options.optimization) {
+                Ok(program) => {
+                    print!("{}", bytecode::disassemble(&bytecode::compile(&program)));
+                    EXIT_SUCCESS
+                },
+                Err(exit_code) => exit_code,
+            };
+        },
+        Command::Ir => {
+            return match lower_program(&output.ast, &analysis, options.optimization) {
+                Ok(program) => {
+                    print!("{}", ir::print_program(&program));
+                    EXIT_SUCCESS
+                },
+                Err(exit_code) => exit_code,
+            };
+        },
+        Command::Ast | Command::Help => unreachable!("handled before the analysis"),
+    }
+}
+
+fn main() -> ExitCode {
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("ila: {}\n\n{}", message, USAGE);
+            return ExitCode::from(EXIT_USAGE_ERROR);
+        }
+    };
+
+    if options.command == Command::Help {
+
+        println!("{}", USAGE);
+        return ExitCode::from(EXIT_SUCCESS);
+    }
+
+    let source = match read_source(&options.input) {
+        Ok(source) => source,
+        Err(error) => {
+            eprintln!("ila: {:#}", error);
+            return ExitCode::from(EXIT_USAGE_ERROR);
+        }
+    };
+
+    // the passes after the parser walk the ast recursively
+    let exit_code = std::thread::Builder::new()
+        .stack_size(COMPILER_STACK_SIZE)
+        .spawn(move || run_command(&options, &source))
+        .expect("the compiler thread can be created")
+        .join()
+        .expect("the compiler does not panic");
+
+    return ExitCode::from(exit_code);
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+
+    fn args(args: &[&str]) -> Vec<String> {
+        return args.iter().map(|arg| arg.to_string()).collect();
+    }
+
+    #[test]
+    fn test_parse_args_with_file() {
+
+        let options = parse_args(&args(&["executar", "programa.ila"])).unwrap();
+        assert_eq!(options.command, Command::Run);
+        assert_eq!(options.input, Input::File("programa.ila".to_string()));
+    }
+
+    #[test]
+    fn test_parse_args_with_stdin() {
+
+        let options = parse_args(&args(&["verificar"])).unwrap();
+        assert_eq!((options.command, options.input), (Command::Check, Input::Stdin));
+
+        let options = parse_args(&args(&["ast", "-"])).unwrap();
+        assert_eq!((options.command, options.input), (Command::Ast, Input::Stdin));
+
+        let options = parse_args(&args(&["ir"])).unwrap();
+        assert_eq!((options.command, options.input), (Command::Ir, Input::Stdin));
+    }
+
+    #[test]
+    fn test_parse_args_with_settings() {
+
+        let options = parse_args(&args(&["verificar", "--tipos-em-ingles", "a.ila"])).unwrap();
+        assert!(options.settings.english_type_aliases);
+        assert_eq!(options.input, Input::File("a.ila".to_string()));
+
+        assert!(!parse_args(&args(&["verificar", "a.ila"])).unwrap().settings.english_type_aliases);
+        assert_eq!(parse_args(&args(&["executar", "a.ila"])).unwrap().engine, Engine::Vm);
+        assert_eq!(parse_args(&args(&["executar", "--interpretar-ast", "a.ila"])).unwrap().engine, Engine::AstInterpreter);
+        assert_eq!(parse_args(&args(&["executar", "--interpretar-ir", "a.ila"])).unwrap().engine, Engine::IrInterpreter);
+        assert_eq!(parse_args(&args(&["ir", "a.ila"])).unwrap().optimization, OptimizationLevel::O0);
+        assert_eq!(parse_args(&args(&["ir", "-O2", "a.ila"])).unwrap().optimization, OptimizationLevel::O2);
+        assert_eq!(parse_args(&args(&["executar", "-O1", "--interpretar-ir", "a.ila"])).unwrap().optimization, OptimizationLevel::O1);
+        assert_eq!(parse_args(&args(&["executar", "-O2", "a.ila"])).unwrap().optimization, OptimizationLevel::O2);
+        assert_eq!(parse_args(&args(&["compilar", "-O1", "--alvo", "wasm", "a.ila"])).unwrap().optimization, OptimizationLevel::O1);
+        assert_eq!(parse_args(&args(&["bytecode", "-O2", "a.ila"])).unwrap().optimization, OptimizationLevel::O2);
+        assert!(parse_args(&args(&["verificar", "--desconhecida"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_compile() {
+
+        let options = parse_args(&args(&["compilar", "-o", "saída", "programa.ila"])).unwrap();
+        assert_eq!(options.command, Command::Compile);
+        assert_eq!(options.output, Some("saída".to_string()));
+        assert_eq!(options.input, Input::File("programa.ila".to_string()));
+        assert_eq!(options.target, Target::Native);
+
+        let options = parse_args(&args(&["compilar", "--alvo", "wasm", "programa.ila"])).unwrap();
+        assert_eq!(options.target, Target::Wasm);
+
+        let source = SourceFile{name: "exemplos/programa.ila".to_string(), content: String::new()};
+        assert_eq!(default_output_path(&source, &Target::Native), PathBuf::from("exemplos/programa"));
+        assert_eq!(default_output_path(&source, &Target::Wat), PathBuf::from("exemplos/programa.wat"));
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+
+        assert!(parse_args(&args(&[])).is_err());
+        assert!(parse_args(&args(&["rodar", "a.ila"])).is_err());
+        assert!(parse_args(&args(&["executar", "-o", "a", "a.ila"])).is_err());
+        assert!(parse_args(&args(&["compilar", "a.ila", "-o"])).is_err());
+        assert!(parse_args(&args(&["compilar", "--alvo", "js", "a.ila"])).is_err());
+        assert!(parse_args(&args(&["verificar", "--alvo", "wasm", "a.ila"])).is_err());
+        assert!(parse_args(&args(&["executar", "a.ila", "b.ila"])).is_err());
+        assert!(parse_args(&args(&["executar", "-O2", "--interpretar-ast", "a.ila"])).is_err());
+        assert!(parse_args(&args(&["verificar", "-O1", "a.ila"])).is_err());
+        assert!(parse_args(&args(&["ir", "-O3", "a.ila"])).is_err());
+    }
+
+    #[test]
+    fn test_syntax_error_position() {
+
+        let source = SourceFile{name: "teste.ila".to_string(), content: "func foo() -> int {\n    1 +;\n}".to_string()};
+        let output = tso_parser::parse_source(&source.content).unwrap();
+        assert_eq!(output.errors.len(), 1);
+        assert_eq!(output.errors[0].lexical_info().start.line, 1);
+    }
 }
@@ -0,0 +1,577 @@
+
+// std
+use std::collections::HashSet;
+
+// local
+use crate::ast::*;
+use crate::diagnostic::Diagnostic;
+use crate::resolver::{Resolution, Symbol};
+use crate::type_checker::TypeTable;
+use crate::types::Type;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                 flow state                                 */
+/* -------------------------------------------------------------------------- */
+
+/// Variables declared without `mut` and without a value that may already have received one,
+/// identified by their declaration statement. They can receive a value only once.
+#[derive(Clone)]
+struct AssignState {
+    maybe_assigned: HashSet<StmtID>,
+    /// Set after `retornar`, the following code never runs.
+    diverges: bool,
+}
+
+impl AssignState {
+
+    fn new() -> Self {
+        return AssignState{maybe_assigned: HashSet::new(), diverges: false};
+    }
+
+    /// State after two alternative paths join, a variable may be assigned if it may be in any.
+    fn join(self, other: AssignState) -> AssignState {
+
+        if self.diverges {
+            return other;
+        }
+
+        if other.diverges {
+            return self;
+        }
+
+        let maybe_assigned = self.maybe_assigned.union(&other.maybe_assigned).copied().collect();
+        return AssignState{maybe_assigned, diverges: false};
+    }
+}
+
+/// Why a place must be changeable.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Change {
+    /// The place receives a value with `=`.
+    Assign,
+    /// A `mut ref` to the place is created.
+    Borrow,
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   checker                                  */
+/* -------------------------------------------------------------------------- */
+
+struct MutabilityChecker<'a> {
+    ast: &'a Ast,
+    resolution: &'a Resolution,
+    types: &'a TypeTable,
+    diagnostics: &'a mut Vec<Diagnostic>,
+    /// Expressions already reported, loop bodies are checked twice.
+    reported: HashSet<ExprID>,
+}
+
+impl<'a> MutabilityChecker<'a> {
+
+    fn report(&mut self, expr: ExprID, diagnostic: Diagnostic) {
+
+        if self.reported.insert(expr) {
+            self.diagnostics.push(diagnostic);
+        }
+    }
+
+    /// Suggestion that adds `mut` before a declared name.
+    fn add_mut(&self, diagnostic: Diagnostic, declaration: IdentifierID, message: &str) -> Diagnostic {
+
+        let name = self.ast.get_identifier(declaration);
+        let lexical_info = *self.ast.get_lexical_info(declaration);
+        return diagnostic
+            .with_secondary(lexical_info, "declaração")
+            .with_suggestion(message, lexical_info, format!("mut {}", name));
+    }
+
+    /* ---- places ---- */
+
+    fn check_changeable(&mut self, place: ExprID, change: Change, state: &mut AssignState) {
+
+        match self.ast.get_expression(place) {
+            Expression::Identifier{node_id} => self.check_changeable_name(place, *node_id, change, state),
+            Expression::Access{object, ..} => {
+                match self.types.expr_type(*object) {
+                    Type::Ref{..} | Type::Comp{..} => self.check_changeable_target(place, *object),
+                    _ => self.check_changeable(*object, change, state),
+                }
+            },
+            Expression::UnaryOprt{oprt: UnaryOprt::Deref, operand} => self.check_changeable_target(place, *operand),
+            _ => {},
+        }
+    }
+
+    fn check_changeable_name(&mut self, place: ExprID, name: IdentifierID, change: Change, state: &mut AssignState) {
+
+        let Some(declaration) = self.resolution.declaration_of(name) else { return };
+        let name_str = self.ast.get_identifier(name);
+        let lexical_info = *self.ast.get_lexical_info(place);
+
+        // `variável` is feminine, `parâmetro` and `item` are masculine
+        let action = |ending: &str| match change {
+            Change::Assign => format!("alterad{}", ending),
+            Change::Borrow => format!("alterad{} por uma `mut ref`", ending),
+        };
+
+        match self.resolution.symbol(declaration) {
+            Some(Symbol::Var(stmt)) => {
+                let Statement::VarDecl{is_mut, init_expr, ..} = self.ast.get_statement(stmt) else { return };
+                if *is_mut {
+                    return;
+                }
+
+                // a variable declared without a value can receive its first value later
+                if change == Change::Assign && init_expr.is_none() && !state.maybe_assigned.contains(&stmt) {
+                    state.maybe_assigned.insert(stmt);
+                    return;
+                }
+
+                let message = match (change, init_expr) {
+                    (Change::Assign, None) => format!("a variável `{}` pode receber um valor mais de uma vez, mas não foi declarada com `mut`", name_str),
+                    _ => format!("a variável `{}` não pode ser {}, pois não foi declarada com `mut`", name_str, action("a")),
+                };
+                let diagnostic = Diagnostic::error("E0501", message, lexical_info)
+                    .with_label(format!("alteração de `{}`", name_str));
+                let diagnostic = self.add_mut(diagnostic, declaration, "declare a variável com `mut`");
+                self.report(place, diagnostic);
+            },
+            Some(Symbol::Param(param)) => {
+                if self.ast.get_function_param(param).is_mut {
+                    return;
+                }
+
+                let message = format!("o parâmetro `{}` não pode ser {}, pois não foi declarado com `mut`", name_str, action("o"));
+                let diagnostic = Diagnostic::error("E0501", message, lexical_info)
+                    .with_label(format!("alteração de `{}`", name_str))
+                    .with_note("sem `mut`, o parâmetro guarda sempre o valor recebido na chamada");
+                let diagnostic = self.add_mut(diagnostic, declaration, "declare o parâmetro com `mut`");
+                self.report(place, diagnostic);
+            },
+            Some(Symbol::ForEachItem(item)) => {
+                let for_each_decl = self.ast.get_for_each_decl(item);
+
+                if for_each_decl.is_ref && change == Change::Assign {
+                    let diagnostic = Diagnostic::error("E0501", format!("o item `{}` de um `para cada ref` não pode apontar para outro valor", name_str), lexical_info)
+                        .with_label(format!("alteração de `{}`", name_str))
+                        .with_note(format!("para alterar o item da lista, use `*{} = ...` e declare o item com `mut ref`", name_str));
+                    self.report(place, diagnostic);
+                    return;
+                }
+
+                if for_each_decl.is_mut {
+                    return;
+                }
+
+                let message = format!("o item `{}` do `para cada` não pode ser {}, pois não foi declarado com `mut`", name_str, action("o"));
+                let diagnostic = Diagnostic::error("E0501", message, lexical_info)
+                    .with_label(format!("alteração de `{}`", name_str));
+                let diagnostic = self.add_mut(diagnostic, declaration, "declare o item com `mut`");
+                self.report(place, diagnostic);
+            },
+            Some(Symbol::CaseBinding(_)) => {
+                let message = format!("o valor `{}` de um caso não pode ser {}", name_str, action("o"));
+                let diagnostic = Diagnostic::error("E0501", message, lexical_info)
+                    .with_label(format!("alteração de `{}`", name_str))
+                    .with_note("copie o valor para uma variável declarada com `mut`");
+                self.report(place, diagnostic);
+            },
+            Some(Symbol::Function(_) | Symbol::Struct(_) | Symbol::Enum(_) | Symbol::Variant(..) | Symbol::Builtin(_)) | None => {},
+        }
+    }
+
+    /// Checks that the value pointed to by `pointer`, a `ref` or `comp`, can be changed.
+    fn check_changeable_target(&mut self, place: ExprID, pointer: ExprID) {
+
+        let pointer_type = self.types.expr_type(pointer);
+        let is_mut = match pointer_type {
+            Type::Ref{is_mut, ..} | Type::Comp{is_mut, ..} => *is_mut,
+            _ => return,
+        };
+
+        if is_mut {
+            return;
+        }
+
+        let (pointer_description, kind) = match pointer_type {
+            Type::Ref{..} => ("referência", "ref"),
+            _ => ("`comp`", "comp"),
+        };
+        let mut diagnostic = Diagnostic::error("E0502", format!("o valor apontado por esta {} não pode ser alterado", pointer_description), *self.ast.get_lexical_info(place))
+            .with_label(format!("`{}` não é `mut {}`", pointer_type, kind));
+
+        // the type in the declaration of the pointer is where `mut` is missing
+        if let Expression::Identifier{node_id} = self.ast.get_expression(pointer) {
+            if let Some(type_expr) = self.resolution.declaration_of(*node_id).and_then(|declaration| self.declared_type_expr(declaration)) {
+                let mutable_type = match pointer_type {
+                    Type::Ref{inner, ..} => Type::Ref{is_mut: true, inner: inner.clone()},
+                    Type::Comp{inner, ..} => Type::Comp{is_mut: true, inner: inner.clone()},
+                    _ => unreachable!("checked above"),
+                };
+                diagnostic = diagnostic.with_suggestion(format!("declare o tipo como `mut {}`", kind), *self.ast.get_lexical_info(type_expr), mutable_type.to_string());
+            }
+        }
+
+        self.report(place, diagnostic);
+    }
+
+    fn declared_type_expr(&self, declaration: IdentifierID) -> Option<TypeExprID> {
+
+        return match self.resolution.symbol(declaration)? {
+            Symbol::Var(stmt) => match self.ast.get_statement(stmt) {
+                Statement::VarDecl{type_expr, ..} => Some(*type_expr),
+                _ => None,
+            },
+            Symbol::Param(param) => Some(self.ast.get_function_param(param).type_expr),
+            Symbol::Function(_) |
+            Symbol::Struct(_) |
+            Symbol::Enum(_) |
+            Symbol::Variant(..) |
+            Symbol::ForEachItem(_) |
+            Symbol::CaseBinding(_) |
+            Symbol::Builtin(_) => None,
+        };
+    }
+
+    /// A `mut ref` created from `value` needs the value to be changeable.
+    fn check_borrow(&mut self, expected: &Type, value: ExprID, state: &mut AssignState) {
+
+        if !matches!(expected, Type::Ref{is_mut: true, ..}) {
+            return;
+        }
+
+        // references received as values were already checked by their types
+        if matches!(self.types.expr_type(value), Type::Ref{..} | Type::Comp{..}) {
+            return;
+        }
+
+        self.check_changeable(value, Change::Borrow, state);
+    }
+
+    /* ---- traversal ---- */
+
+    fn check_expr(&mut self, id: ExprID, state: &mut AssignState) {
+
+        match self.ast.get_expression(id) {
+            Expression::IntegerLiteral{..} |
+            Expression::DecimalLiteral{..} |
+            Expression::StringLiteral{..} |
+            Expression::BooleanLiteral{..} |
+            Expression::Identifier{..} |
+            Expression::Error => {},
+            Expression::ListLiteral{values} => {
+                for value in values {
+                    self.check_expr(*value, state);
+                }
+            },
+            Expression::InterpolatedString{parts} => {
+                for value in parts.iter().filter_map(StringPart::value) {
+                    self.check_expr(value, state);
+                }
+            },
+            Expression::Call{callee, args} => {
+                self.check_expr(*callee, state);
+                for arg in args {
+                    self.check_expr(*arg, state);
+                }
+
+                if let Type::Function{params, ..} = self.types.expr_type(*callee).dereferenced() {
+                    for (param, arg) in params.iter().zip(args) {
+                        self.check_borrow(param, *arg, state);
+                    }
+                }
+            },
+            Expression::StructLiteral{fields, ..} => {
+                for field in fields {
+                    self.check_expr(field.value, state);
+                }
+
+                if let Type::Struct{id: struct_id, ..} = self.types.expr_type(id) {
+                    let field_types = self.types.fields(*struct_id);
+                    for field in fields {
+                        let field_name = self.ast.get_identifier(field.name);
+                        if let Some(field_type) = field_types.iter().find(|field_type| field_type.name == *field_name) {
+                            self.check_borrow(&field_type.ty, field.value, state);
+                        }
+                    }
+                }
+            },
+            Expression::Access{object, ..} => {
+                self.check_expr(*object, state);
+            },
+            Expression::BinaryOprt{left, right, ..} => {
+                self.check_expr(*left, state);
+                self.check_expr(*right, state);
+            },
+            Expression::UnaryOprt{operand, ..} => {
+                self.check_expr(*operand, state);
+            },
+            Expression::Assign{target, value} => {
+                self.check_expr(*value, state);
+                self.check_changeable(*target, Change::Assign, state);
+                let target_type = self.types.expr_type(*target);
+                self.check_borrow(target_type, *value, state);
+            },
+        }
+    }
+
+    fn check_block(&mut self, block: &[StmtID], state: &mut AssignState) {
+
+        for stmt in block {
+            self.check_stmt(*stmt, state);
+        }
+    }
+
+    /// Checks a loop body twice, so values assigned in one iteration are seen by the next.
+    fn check_loop_body(&mut self, cond_expr: Option<ExprID>, body_block: &[StmtID], state: &mut AssignState) {
+
+        let mut body_state = state.clone();
+        self.check_block(body_block, &mut body_state);
+
+        let mut second_state = state.clone().join(body_state);
+        if let Some(cond_expr) = cond_expr {
+            self.check_expr(cond_expr, &mut second_state);
+        }
+        self.check_block(body_block, &mut second_state);
+
+        *state = state.clone().join(second_state);
+    }
+
+    fn check_stmt(&mut self, id: StmtID, state: &mut AssignState) {
+
+        match self.ast.get_statement(id) {
+            Statement::Expression{expr} => {
+                self.check_expr(*expr, state);
+            },
+            Statement::VarDecl{name, init_expr, ..} => {
+                // every time the declaration runs, the variable starts again without a value
+                state.maybe_assigned.remove(&id);
+
+                if let Some(init_expr) = init_expr {
+                    self.check_expr(*init_expr, state);
+                    let declared = self.types.declaration_type(*name);
+                    self.check_borrow(declared, *init_expr, state);
+                }
+            },
+            Statement::If{branches, else_body} => {
+                // each condition is only evaluated when the ones before it do not hold
+                let mut branch_states = Vec::new();
+                for branch in branches {
+                    self.check_expr(branch.cond_expr, state);
+                    let mut branch_state = state.clone();
+                    self.check_block(&branch.body, &mut branch_state);
+                    branch_states.push(branch_state);
+                }
+
+                if let Some(else_body) = else_body {
+                    self.check_block(else_body, state);
+                }
+
+                *state = branch_states.into_iter().fold(state.clone(), AssignState::join);
+            },
+            Statement::Match{value, cases} => {
+                self.check_expr(*value, state);
+
+                // every variant has a case, so one of them always runs
+                let mut joined: Option<AssignState> = None;
+                for case in cases {
+                    let mut case_state = state.clone();
+                    self.check_block(&case.body, &mut case_state);
+                    joined = Some(match joined {
+                        Some(joined) => joined.join(case_state),
+                        None => case_state,
+                    });
+                }
+
+                if let Some(joined) = joined {
+                    *state = joined;
+                }
+            },
+            Statement::While{cond_expr, body_block} => {
+                self.check_expr(*cond_expr, state);
+                self.check_loop_body(Some(*cond_expr), body_block, state);
+            },
+            Statement::ForEach{item, iter_expr, body_block} => {
+                self.check_expr(*iter_expr, state);
+
+                let for_each_decl = self.ast.get_for_each_decl(*item);
+                if for_each_decl.is_ref && for_each_decl.is_mut {
+                    let item_type = self.types.declaration_type(for_each_decl.name).clone();
+                    self.check_borrow(&item_type, *iter_expr, state);
+                }
+
+                self.check_loop_body(None, body_block, state);
+            },
+            Statement::Return{expr} => {
+                if let Some(expr) = expr {
+                    self.check_expr(*expr, state);
+                }
+                state.diverges = true;
+            },
+            // what was assigned before them still reaches the code after the loop or the next iteration
+            Statement::Continue |
+            Statement::Break |
+            Statement::Error => {},
+        }
+    }
+}
+
+/// Checks that only bindings declared with `mut` are changed, that values are only changed
+/// through a `mut ref` or `mut comp`, and that a `mut ref` is only created to changeable values.
+pub fn check_mutability(ast: &Ast, resolution: &Resolution, types: &TypeTable, diagnostics: &mut Vec<Diagnostic>) {
+
+    let mut checker = MutabilityChecker{ast, resolution, types, diagnostics, reported: HashSet::new()};
+
+    for scope_def in ast.get_subprograms() {
+        checker.check_block(ast.get_scope_def(scope_def).body(), &mut AssignState::new());
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::builtin_types::TypeCatalogue;
+    use crate::resolver::resolve_names;
+    use crate::tso_parser::parse_source;
+    use crate::type_checker::check_types;
+
+
+    fn check_diagnostics(source_code: &str) -> Vec<Diagnostic> {
+
+        let output = parse_source(source_code).unwrap();
+        assert!(!output.has_errors());
+
+        let mut diagnostics = Vec::new();
+        let resolution = resolve_names(&output.ast, &mut diagnostics);
+        let types = check_types(&output.ast, &resolution, &TypeCatalogue::new(false), &mut diagnostics);
+        assert!(diagnostics.is_empty());
+
+        check_mutability(&output.ast, &resolution, &types, &mut diagnostics);
+        return diagnostics;
+    }
+
+    fn check(source_code: &str) -> Vec<&'static str> {
+        return check_diagnostics(source_code).iter().map(|diagnostic| diagnostic.code).collect();
+    }
+
+    #[test]
+    fn test_mutable_bindings() {
+
+        let source_code = r#"
+            func foo(mut a: inteiro, b: mut ref inteiro) -> inteiro {
+                var mut c: inteiro = 1;
+                var d: inteiro;
+                se a > 0 { d = 1; } senão { d = 2; }
+                a = 2;
+                c = a;
+                *b = c;
+                var e: mut ref inteiro = c;
+                para cada mut item em [1, 2] {
+                    item = 3;
+                }
+                enquanto c > 0 {
+                    var f: inteiro;
+                    f = c;
+                    c = c - f;
+                }
+                retornar d;
+            }
+        "#;
+
+        assert!(check(source_code).is_empty());
+    }
+
+    #[test]
+    fn test_immutable_bindings() {
+
+        let source_code = r#"
+            func foo(a: inteiro, itens: lista<inteiro>) -> nada {
+                var b: inteiro = 1;
+                a = 2;
+                b = 3;
+                para cada item em itens {
+                    item = 4;
+                }
+                para cada ref item em itens {
+                    item = 5;
+                }
+            }
+        "#;
+
+        let diagnostics = check_diagnostics(source_code);
+        let codes: Vec<&str> = diagnostics.iter().map(|diagnostic| diagnostic.code).collect();
+        assert_eq!(codes, vec!["E0501", "E0501", "E0501", "E0501"]);
+
+        let replacements: Vec<Option<&str>> = diagnostics.iter()
+            .map(|diagnostic| diagnostic.suggestions.first().map(|suggestion| suggestion.replacement.as_str()))
+            .collect();
+        assert_eq!(replacements, vec![Some("mut a"), Some("mut b"), Some("mut item"), None]);
+    }
+
+    #[test]
+    fn test_assigned_more_than_once() {
+
+        let source_code = r#"
+            func foo(a: inteiro) -> nada {
+                var b: inteiro;
+                var c: inteiro;
+                var d: inteiro;
+                b = 1;
+                b = 2;
+                se a > 0 { c = 1; }
+                c = 2;
+                enquanto a > 0 {
+                    d = 1;
+                }
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0501", "E0501", "E0501"]);
+    }
+
+    #[test]
+    fn test_change_through_references() {
+
+        let source_code = r#"
+            func foo(a: ref inteiro, b: comp inteiro, c: mut comp inteiro) -> nada {
+                *a = 1;
+                *b = 2;
+                *c = 3;
+            }
+        "#;
+
+        let diagnostics = check_diagnostics(source_code);
+        let codes: Vec<&str> = diagnostics.iter().map(|diagnostic| diagnostic.code).collect();
+        assert_eq!(codes, vec!["E0502", "E0502"]);
+        assert_eq!(diagnostics[0].suggestions[0].replacement, "mut ref inteiro");
+    }
+
+    #[test]
+    fn test_mutable_borrows() {
+
+        let source_code = r#"
+            func incrementa(a: mut ref inteiro) -> nada {
+                *a = *a + 1;
+            }
+
+            func foo(a: inteiro, mut b: inteiro, itens: lista<inteiro>) -> nada {
+                incrementa(a);
+                incrementa(b);
+                var r: mut ref inteiro = a;
+                para cada mut ref item em itens {
+                    *item = 0;
+                }
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0501", "E0501", "E0501"]);
+    }
+}
@@ -0,0 +1,482 @@
+// std
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+// external
+use num_bigint::{BigInt, Sign};
+use num_traits::{Signed, ToPrimitive, Zero};
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   integer                                  */
+/* -------------------------------------------------------------------------- */
+
+/// An `inteiro` of any size. Values that fit in an `i64` are kept in one, so the usual numbers
+/// do not allocate.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Integer(IntegerRepr);
+
+/// `Big` is only used for values outside the `i64` range, so each value has a single representation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum IntegerRepr {
+    Small(i64),
+    Big(BigInt),
+}
+
+impl Integer {
+
+    /// Reads the digits of an integer, with an optional `-` before them.
+    pub fn parse(text: &str) -> Option<Integer> {
+
+        if let Ok(value) = text.parse::<i64>() {
+            return Some(Integer::from(value));
+        }
+
+        let digits = text.strip_prefix('-').unwrap_or(text);
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+
+        return BigInt::from_str(text).ok().map(Integer::from);
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return matches!(self.0, IntegerRepr::Small(0));
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+
+        return match &self.0 {
+            IntegerRepr::Small(value) => Some(*value),
+            IntegerRepr::Big(_) => None,
+        };
+    }
+
+    pub fn to_usize(&self) -> Option<usize> {
+        return self.to_i64().and_then(|value| usize::try_from(value).ok());
+    }
+
+    /// Whether it is negative, and its magnitude in base 2^32 from the least significant limb,
+    /// for the compiled programs.
+    pub fn to_limbs(&self) -> (bool, Vec<u32>) {
+
+        let (sign, limbs) = self.to_big().to_u32_digits();
+        return (sign == Sign::Minus, limbs);
+    }
+
+    fn to_big(&self) -> BigInt {
+
+        return match &self.0 {
+            IntegerRepr::Small(value) => BigInt::from(*value),
+            IntegerRepr::Big(value) => value.clone(),
+        };
+    }
+
+    /// Division rounded toward zero, or `None` when dividing by zero.
+    pub fn checked_div(&self, other: &Integer) -> Option<Integer> {
+
+        if other.is_zero() {
+            return None;
+        }
+
+        if let (IntegerRepr::Small(a), IntegerRepr::Small(b)) = (&self.0, &other.0) {
+            if let Some(result) = a.checked_div(*b) {
+                return Some(Integer::from(result));
+            }
+        }
+
+        return Some(Integer::from(self.to_big() / other.to_big()));
+    }
+}
+
+impl From<i64> for Integer {
+    fn from(value: i64) -> Self {
+        return Integer(IntegerRepr::Small(value));
+    }
+}
+
+impl From<i32> for Integer {
+    fn from(value: i32) -> Self {
+        return Integer::from(i64::from(value));
+    }
+}
+
+impl From<usize> for Integer {
+
+    fn from(value: usize) -> Self {
+
+        return match i64::try_from(value) {
+            Ok(value) => Integer::from(value),
+            Err(_) => Integer::from(BigInt::from(value)),
+        };
+    }
+}
+
+impl From<BigInt> for Integer {
+
+    fn from(value: BigInt) -> Self {
+
+        return match value.to_i64() {
+            Some(value) => Integer(IntegerRepr::Small(value)),
+            None => Integer(IntegerRepr::Big(value)),
+        };
+    }
+}
+
+/// Implements an operator with a fast path for two `i64`s that falls back to `BigInt` when the
+/// result does not fit.
+macro_rules! integer_operator {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        impl $trait for &Integer {
+            type Output = Integer;
+
+            fn $method(self, other: &Integer) -> Integer {
+
+                if let (IntegerRepr::Small(a), IntegerRepr::Small(b)) = (&self.0, &other.0) {
+                    if let Some(result) = a.$checked(*b) {
+                        return Integer::from(result);
+                    }
+                }
+
+                return Integer::from(self.to_big().$method(other.to_big()));
+            }
+        }
+    };
+}
+
+integer_operator!(Add, add, checked_add);
+integer_operator!(Sub, sub, checked_sub);
+integer_operator!(Mul, mul, checked_mul);
+
+impl Neg for &Integer {
+    type Output = Integer;
+
+    fn neg(self) -> Integer {
+
+        return match &self.0 {
+            IntegerRepr::Small(value) => match value.checked_neg() {
+                Some(result) => Integer::from(result),
+                None => Integer::from(-BigInt::from(*value)),
+            },
+            IntegerRepr::Big(value) => Integer::from(-value),
+        };
+    }
+}
+
+impl Ord for Integer {
+
+    fn cmp(&self, other: &Self) -> Ordering {
+
+        return match (&self.0, &other.0) {
+            (IntegerRepr::Small(a), IntegerRepr::Small(b)) => a.cmp(b),
+            _ => self.to_big().cmp(&other.to_big()),
+        };
+    }
+}
+
+impl PartialOrd for Integer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl std::fmt::Display for Integer {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+        return match &self.0 {
+            IntegerRepr::Small(value) => write!(f, "{}", value),
+            IntegerRepr::Big(value) => write!(f, "{}", value),
+        };
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   decimal                                  */
+/* -------------------------------------------------------------------------- */
+
+/// Significant digits kept by a division whose result has no exact decimal representation,
+/// like `1.0 / 3.0`. Every other operation is exact.
+pub const DIVISION_PRECISION: usize = 30;
+
+/// Largest exponent of a number in scientific notation, so a literal like `1e999999999` does
+/// not use up the memory.
+pub const MAX_EXPONENT: i64 = 10_000;
+
+/// A `decimal` written in base 10 without losing digits: `digits * 10^-scale`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    digits: BigInt,
+    /// Digits after the point. The last one is never a `0`, so each value has a single representation.
+    scale: u32,
+}
+
+fn power_of_ten(exponent: u32) -> BigInt {
+    return BigInt::from(10).pow(exponent);
+}
+
+fn digit_count(value: &BigInt) -> usize {
+    return value.magnitude().to_string().len();
+}
+
+impl Decimal {
+
+    fn new(digits: BigInt, scale: u32) -> Decimal {
+
+        let (mut digits, mut scale) = (digits, scale);
+        let ten = BigInt::from(10);
+        while scale > 0 && !digits.is_zero() && (&digits % &ten).is_zero() {
+            digits /= &ten;
+            scale -= 1;
+        }
+        if digits.is_zero() {
+            scale = 0;
+        }
+
+        return Decimal{digits, scale};
+    }
+
+    pub fn from_integer(value: &Integer) -> Decimal {
+        return Decimal::new(value.to_big(), 0);
+    }
+
+    /// Reads a decimal like `-12.05` or `6.02e23`, keeping every digit.
+    pub fn parse(text: &str) -> Option<Decimal> {
+
+        let unsigned = text.strip_prefix('-').unwrap_or(text);
+        let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+            None => (unsigned, None),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+        let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+        if integer.is_empty() || !is_digits(integer) || !is_digits(fraction) {
+            return None;
+        }
+
+        let exponent = match exponent {
+            Some(exponent) => {
+                let exponent_digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+                if exponent_digits.is_empty() || !is_digits(exponent_digits) {
+                    return None;
+                }
+                exponent.parse::<i64>().ok().filter(|exponent| exponent.abs() <= MAX_EXPONENT)?
+            },
+            None => 0,
+        };
+
+        let mut digits = BigInt::from_str(&format!("{}{}", integer, fraction)).ok()?;
+        if unsigned.len() != text.len() {
+            digits = -digits;
+        }
+
+        // the exponent moves the point: to the left adds digits after it, to the right adds zeros
+        let scale = i64::try_from(fraction.len()).ok()? - exponent;
+        return match u32::try_from(scale) {
+            Ok(scale) => Some(Decimal::new(digits, scale)),
+            Err(_) => Some(Decimal::new(digits * power_of_ten(u32::try_from(-scale).ok()?), 0)),
+        };
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.digits.is_zero();
+    }
+
+    /// Digits without the point, the last `scale` of them after it.
+    pub fn digits(&self) -> Integer {
+        return Integer::from(self.digits.clone());
+    }
+
+    pub fn scale(&self) -> u32 {
+        return self.scale;
+    }
+
+    /// Both digits with the same scale, the largest of the two.
+    fn aligned(&self, other: &Decimal) -> (BigInt, BigInt, u32) {
+
+        let scale = self.scale.max(other.scale);
+        let left = &self.digits * power_of_ten(scale - self.scale);
+        let right = &other.digits * power_of_ten(scale - other.scale);
+        return (left, right, scale);
+    }
+
+    /// Division rounded to `DIVISION_PRECISION` significant digits, half away from zero, or
+    /// `None` when dividing by zero.
+    pub fn checked_div(&self, other: &Decimal) -> Option<Decimal> {
+
+        if other.is_zero() {
+            return None;
+        }
+
+        // enough extra digits in the dividend for the quotient to have the precision
+        let extra = (DIVISION_PRECISION + digit_count(&other.digits)).saturating_sub(digit_count(&self.digits)) as u32;
+        let dividend = &self.digits * power_of_ten(extra);
+        let mut quotient = &dividend / &other.digits;
+        let remainder = &dividend % &other.digits;
+        if remainder.magnitude() * 2u32 >= *other.digits.magnitude() {
+            match dividend.is_negative() != other.digits.is_negative() {
+                true => quotient -= 1,
+                false => quotient += 1,
+            }
+        }
+
+        // the quotient has `self.scale + extra - other.scale` digits after the point
+        let scale = i64::from(self.scale) + i64::from(extra) - i64::from(other.scale);
+        return match u32::try_from(scale) {
+            Ok(scale) => Some(Decimal::new(quotient, scale)),
+            Err(_) => Some(Decimal::new(quotient * power_of_ten((-scale) as u32), 0)),
+        };
+    }
+}
+
+impl Add for &Decimal {
+    type Output = Decimal;
+
+    fn add(self, other: &Decimal) -> Decimal {
+        let (left, right, scale) = self.aligned(other);
+        return Decimal::new(left + right, scale);
+    }
+}
+
+impl Sub for &Decimal {
+    type Output = Decimal;
+
+    fn sub(self, other: &Decimal) -> Decimal {
+        let (left, right, scale) = self.aligned(other);
+        return Decimal::new(left - right, scale);
+    }
+}
+
+impl Mul for &Decimal {
+    type Output = Decimal;
+
+    fn mul(self, other: &Decimal) -> Decimal {
+        return Decimal::new(&self.digits * &other.digits, self.scale + other.scale);
+    }
+}
+
+impl Neg for &Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        return Decimal{digits: -&self.digits, scale: self.scale};
+    }
+}
+
+impl Ord for Decimal {
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (left, right, _) = self.aligned(other);
+        return left.cmp(&right);
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+/// Decimals are always written with a fractional part, so `2.0` is not read as `2`.
+impl std::fmt::Display for Decimal {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+        let scale = self.scale as usize;
+        let digits = format!("{:0>width$}", self.digits.magnitude(), width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        let sign = if self.digits.is_negative() { "-" } else { "" };
+
+        return match fraction.is_empty() {
+            true => write!(f, "{}{}.0", sign, integer),
+            false => write!(f, "{}{}.{}", sign, integer, fraction),
+        };
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+
+    fn integer(text: &str) -> Integer {
+        return Integer::parse(text).unwrap();
+    }
+
+    fn decimal(text: &str) -> Decimal {
+        return Decimal::parse(text).unwrap();
+    }
+
+    #[test]
+    fn test_integers() {
+
+        let max = Integer::from(i64::MAX);
+        assert_eq!((&max + &Integer::from(1)).to_string(), "9223372036854775808");
+        assert_eq!(&(&max + &Integer::from(1)) - &Integer::from(1), max);
+        assert_eq!((-&Integer::from(i64::MIN)).to_string(), "9223372036854775808");
+        assert_eq!(Integer::from(i64::MIN).checked_div(&Integer::from(-1)), Some(integer("9223372036854775808")));
+        assert_eq!(integer("-7").checked_div(&integer("2")), Some(integer("-3")));
+        assert_eq!(integer("123456789012345678901234567890").checked_div(&Integer::from(0)), None);
+
+        // values back in the `i64` range compare equal to the ones that never left it
+        let big = &integer("100000000000000000000") * &integer("100000000000000000000");
+        assert_eq!(big.to_string(), "10000000000000000000000000000000000000000");
+        assert_eq!(big.checked_div(&integer("100000000000000000000000000000000000000")), Some(Integer::from(100)));
+        assert!(integer("-99999999999999999999") < Integer::from(i64::MIN));
+        assert_eq!(integer("-18446744073709551617").to_limbs(), (true, vec![1, 0, 1]));
+        assert_eq!(Integer::parse("12a"), None);
+        assert_eq!(Integer::parse("-"), None);
+    }
+
+    #[test]
+    fn test_decimals() {
+
+        assert_eq!(decimal("1.05").to_string(), "1.05");
+        assert_eq!(decimal("-0.50").to_string(), "-0.5");
+        assert_eq!(decimal("3").to_string(), "3.0");
+        assert_eq!(decimal("-0.0"), decimal("0"));
+        assert_eq!((&decimal("0.1") + &decimal("0.2")).to_string(), "0.3");
+        assert_eq!((&decimal("1.5") - &decimal("2.25")).to_string(), "-0.75");
+        assert_eq!((&decimal("1.5") * &decimal("-0.2")).to_string(), "-0.3");
+        assert!(decimal("2.5") > decimal("2.49999999999999999999999"));
+        assert_eq!((decimal("-12.50").digits(), decimal("-12.50").scale()), (Integer::from(-125), 1));
+        assert_eq!(Decimal::parse("1."), Some(decimal("1")));
+        assert_eq!(Decimal::parse(".5"), None);
+    }
+
+    #[test]
+    fn test_scientific_notation() {
+
+        assert_eq!(decimal("6.02e23").to_string(), "602000000000000000000000.0");
+        assert_eq!(decimal("-1.5E+2").to_string(), "-150.0");
+        assert_eq!(decimal("25e-4").to_string(), "0.0025");
+        assert_eq!(decimal("0.0e10"), decimal("0"));
+        assert_eq!(decimal("1e10000").to_string().len(), 10003);
+        assert_eq!(Decimal::parse("1e10001"), None);
+        assert_eq!(Decimal::parse("1e"), None);
+        assert_eq!(Decimal::parse("1e+-2"), None);
+        assert_eq!(Decimal::parse("1.5e2.5"), None);
+    }
+
+    #[test]
+    fn test_decimal_division() {
+
+        let divide = |a: &str, b: &str| decimal(a).checked_div(&decimal(b)).map(|result| result.to_string());
+        assert_eq!(divide("7", "2").as_deref(), Some("3.5"));
+        assert_eq!(divide("1", "3").as_deref(), Some("0.333333333333333333333333333333"));
+        assert_eq!(divide("2", "3").as_deref(), Some("0.666666666666666666666666666667"));
+        assert_eq!(divide("-2", "3").as_deref(), Some("-0.666666666666666666666666666667"));
+        assert_eq!(divide("1000000", "0.001").as_deref(), Some("1000000000.0"));
+        assert_eq!(divide("0.0001", "8").as_deref(), Some("0.0000125"));
+        assert_eq!(divide("1", "0.0"), None);
+    }
+}
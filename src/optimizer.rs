@@ -0,0 +1,937 @@
+// std
+use std::collections::{HashMap, HashSet};
+
+// local
+use crate::ast::{BinaryOprt, UnaryOprt};
+use crate::builtins::Builtin;
+use crate::ir::*;
+use crate::runtime::{binary_oprt, unary_oprt};
+use crate::types::Type;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                pass manager                                */
+/* -------------------------------------------------------------------------- */
+
+/// How much the IR is optimized, like the `-O` options of C compilers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptimizationLevel {
+    /// The IR as lowered.
+    O0,
+    /// Simplifications that never make the program bigger.
+    O1,
+    /// Also inlines small functions.
+    O2,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pass {
+    /// Computes operations on constants, and branches on constant conditions.
+    ConstantFolding,
+    /// Uses the source of a copy in place of the copy, and a value stored in a local in place of
+    /// reading it back.
+    CopyPropagation,
+    /// Removes what can not run, or whose results are never used.
+    DeadCodeElimination,
+    /// Replaces calls of small functions by their bodies.
+    Inlining,
+}
+
+/// Times the passes run, at most, waiting for one of them to find something to change.
+const MAX_ROUNDS: usize = 8;
+
+impl OptimizationLevel {
+
+    pub fn passes(self) -> &'static [Pass] {
+
+        return match self {
+            OptimizationLevel::O0 => &[],
+            OptimizationLevel::O1 => &[Pass::ConstantFolding, Pass::CopyPropagation, Pass::DeadCodeElimination],
+            OptimizationLevel::O2 => &[Pass::Inlining, Pass::ConstantFolding, Pass::CopyPropagation, Pass::DeadCodeElimination],
+        };
+    }
+}
+
+impl Pass {
+
+    /// Runs the pass on every function, returning whether it changed something.
+    pub fn run(self, program: &mut Program) -> bool {
+
+        if self == Pass::Inlining {
+            return inline_calls(program);
+        }
+
+        let mut changed = false;
+        for function in &mut program.functions {
+            changed |= match self {
+                Pass::ConstantFolding => fold_constants(function),
+                Pass::CopyPropagation => propagate_copies(function),
+                Pass::DeadCodeElimination => eliminate_dead_code(function),
+                Pass::Inlining => unreachable!("inlining works on the whole program"),
+            };
+        }
+
+        return changed;
+    }
+}
+
+/// Runs the passes of the level until they stop changing the program. Each pass may find work
+/// for the others, like a constant folded into a condition, which makes a block unreachable.
+pub fn optimize(program: &mut Program, level: OptimizationLevel) {
+
+    for _ in 0..MAX_ROUNDS {
+        let mut changed = false;
+        for pass in level.passes() {
+            changed |= pass.run(program);
+        }
+        if !changed {
+            break;
+        }
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              constant folding                              */
+/* -------------------------------------------------------------------------- */
+
+fn fold_constants(function: &mut Function) -> bool {
+
+    let mut changed = false;
+
+    for block in &mut function.blocks {
+        for instruction in &mut block.instructions {
+            // operations that fail, like a division by zero, are left to fail when the program runs
+            let folded = match &instruction.kind {
+                InstructionKind::Unary{dest, oprt, operand: Operand::Constant(operand)} => {
+                    unary_oprt(*oprt, operand.to_value()).ok().and_then(Constant::from_value).map(|value| (*dest, value))
+                },
+                InstructionKind::Binary{dest, oprt, left: Operand::Constant(left), right: Operand::Constant(right)} => {
+                    binary_oprt(*oprt, left.to_value(), right.to_value()).ok().and_then(Constant::from_value).map(|value| (*dest, value))
+                },
+                _ => None,
+            };
+
+            if let Some((dest, value)) = folded {
+                instruction.kind = InstructionKind::Copy{dest, value: Operand::Constant(value)};
+                changed = true;
+            }
+        }
+
+        if let Terminator::Branch{condition: Operand::Constant(Constant::Boolean(condition)), then_block, else_block} = block.terminator {
+            block.terminator = Terminator::Jump(if condition { then_block } else { else_block });
+            changed = true;
+        }
+    }
+
+    return changed;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              copy propagation                              */
+/* -------------------------------------------------------------------------- */
+
+/// Replaces reads of locals by the value stored in them before, in the same block. Only locals
+/// that are never referenced can not change between the store and the read, and lists are left to
+/// the reads, since each read makes a copy that the program may then change.
+fn forward_stores(function: &mut Function) -> bool {
+
+    let mut referenced = HashSet::new();
+    for block in &function.blocks {
+        for instruction in &block.instructions {
+            if let InstructionKind::LocalRef{local, ..} = instruction.kind {
+                referenced.insert(local);
+            }
+        }
+    }
+
+    let locals = &function.locals;
+    let is_forwarded = |local: &LocalID| {
+        let ty = &locals[local.0].ty;
+        return !referenced.contains(local) && matches!(ty, Type::Integer | Type::Decimal | Type::Text | Type::Boolean);
+    };
+
+    let mut changed = false;
+    for block in &mut function.blocks {
+        let mut stored: HashMap<LocalID, Operand> = HashMap::new();
+        for instruction in &mut block.instructions {
+            match &instruction.kind {
+                InstructionKind::StoreLocal{local, value} | InstructionKind::DeclareLocal{local, value: Some(value)} if is_forwarded(local) => {
+                    stored.insert(*local, value.clone());
+                },
+                InstructionKind::DeclareLocal{local, value: None} => {
+                    stored.remove(local);
+                },
+                InstructionKind::LoadLocal{dest, local} => {
+                    if let Some(value) = stored.get(local) {
+                        instruction.kind = InstructionKind::Copy{dest: *dest, value: value.clone()};
+                        changed = true;
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    return changed;
+}
+
+fn propagate_copies(function: &mut Function) -> bool {
+
+    let mut changed = forward_stores(function);
+
+    let mut sources: HashMap<Temp, Operand> = HashMap::new();
+    for block in &function.blocks {
+        for instruction in &block.instructions {
+            if let InstructionKind::Copy{dest, value} = &instruction.kind {
+                sources.insert(*dest, value.clone());
+            }
+        }
+    }
+
+    // the source of a copy of a copy is the source of the first one
+    let source = |operand: &Operand| {
+        let mut source = None;
+        while let Some(Operand::Temp(temp)) = source.as_ref().or(Some(operand)) {
+            match sources.get(temp) {
+                Some(next) => source = Some(next.clone()),
+                None => break,
+            }
+        }
+        return source;
+    };
+
+    for block in &mut function.blocks {
+        let operands = block.instructions.iter_mut()
+            .flat_map(|instruction| instruction.kind.operands_mut())
+            .chain(block.terminator.operands_mut());
+        for operand in operands {
+            if let Some(source) = source(operand) {
+                *operand = source;
+                changed = true;
+            }
+        }
+    }
+
+    return changed;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                           dead code elimination                            */
+/* -------------------------------------------------------------------------- */
+
+/// Whether the instruction can be removed when its result is not used: it does not change values,
+/// use the terminal or fail.
+fn has_no_effects(kind: &InstructionKind) -> bool {
+
+    return match kind {
+        InstructionKind::Copy{..} |
+        InstructionKind::LoadLocal{..} |
+        InstructionKind::LocalRef{..} |
+        InstructionKind::Load{..} |
+        InstructionKind::Dereference{..} |
+        InstructionKind::Box{..} |
+        InstructionKind::MakeList{..} |
+        InstructionKind::MakeStruct{..} |
+        InstructionKind::FieldRef{..} |
+        InstructionKind::MakeVariant{..} |
+        InstructionKind::Tag{..} |
+        InstructionKind::Payload{..} |
+        InstructionKind::Items{..} |
+        InstructionKind::ItemCount{..} |
+        InstructionKind::ItemRef{..} => true,
+        InstructionKind::Unary{oprt, ..} => *oprt == UnaryOprt::Not,
+        // arithmetic may overflow or divide by zero
+        InstructionKind::Binary{oprt, ..} => matches!(oprt, BinaryOprt::Eq | BinaryOprt::Neq | BinaryOprt::Lt | BinaryOprt::Le | BinaryOprt::Gt | BinaryOprt::Ge),
+        InstructionKind::CallBuiltin{builtin, ..} => matches!(builtin, Builtin::Length | Builtin::ToText),
+        // a call may go past the limit of calls, even of a function
+        InstructionKind::Call{..} |
+        InstructionKind::StoreLocal{..} |
+        InstructionKind::DeclareLocal{..} |
+        InstructionKind::Store{..} => false,
+    };
+}
+
+/// Removes the blocks that can not be reached from the entry, like the code after `retornar`.
+fn remove_unreachable_blocks(function: &mut Function) -> bool {
+
+    let reachable = function.reachable();
+    if reachable.iter().all(|reachable| *reachable) {
+        return false;
+    }
+
+    let mut new_ids = HashMap::new();
+    let blocks = std::mem::take(&mut function.blocks);
+    for (index, block) in blocks.into_iter().enumerate() {
+        if reachable[index] {
+            new_ids.insert(BlockID(index), BlockID(function.blocks.len()));
+            function.blocks.push(block);
+        }
+    }
+
+    for block in &mut function.blocks {
+        for target in block.terminator.successors_mut() {
+            *target = new_ids[target];
+        }
+    }
+
+    return true;
+}
+
+/// Joins a block that only jumps to another with that one, when nothing else jumps to it.
+fn merge_blocks(function: &mut Function) -> bool {
+
+    let mut changed = false;
+    loop {
+        let predecessors = function.predecessors();
+        let merged = function.blocks.iter().enumerate().find_map(|(index, block)| match block.terminator {
+            Terminator::Jump(target) if target != ENTRY_BLOCK && target.0 != index && predecessors[target.0].len() == 1 => Some((BlockID(index), target)),
+            _ => None,
+        });
+
+        let Some((block, target)) = merged else { break };
+
+        // the target is left without predecessors, and removed with the unreachable blocks
+        let target = &mut function.blocks[target.0];
+        let mut instructions = std::mem::take(&mut target.instructions);
+        let terminator = std::mem::replace(&mut target.terminator, Terminator::Return(Operand::Constant(Constant::Nothing)));
+        let terminator_info = target.terminator_info;
+
+        let block = &mut function.blocks[block.0];
+        block.instructions.append(&mut instructions);
+        block.terminator = terminator;
+        block.terminator_info = terminator_info;
+
+        remove_unreachable_blocks(function);
+        changed = true;
+    }
+
+    return changed;
+}
+
+fn remove_unused_instructions(function: &mut Function) -> bool {
+
+    let mut changed = false;
+    loop {
+        let mut used = HashSet::new();
+        for block in &function.blocks {
+            let operands = block.instructions.iter()
+                .flat_map(|instruction| instruction.kind.operands())
+                .chain(block.terminator.operands());
+            for operand in operands {
+                if let Operand::Temp(temp) = operand {
+                    used.insert(*temp);
+                }
+            }
+        }
+
+        let mut removed = false;
+        for block in &mut function.blocks {
+            let count = block.instructions.len();
+            block.instructions.retain(|instruction| match instruction.kind.dest() {
+                Some(dest) => used.contains(&dest) || !has_no_effects(&instruction.kind),
+                None => true,
+            });
+            removed |= block.instructions.len() != count;
+        }
+
+        if !removed {
+            break;
+        }
+        changed = true;
+    }
+
+    return changed;
+}
+
+/// Removes the locals that are never read, with the values stored in them.
+fn remove_unused_locals(function: &mut Function) -> bool {
+
+    let mut used = HashSet::new();
+    for block in &function.blocks {
+        for instruction in &block.instructions {
+            if let InstructionKind::LoadLocal{local, ..} | InstructionKind::LocalRef{local, ..} = instruction.kind {
+                used.insert(local);
+            }
+        }
+    }
+
+    let mut changed = false;
+    for block in &mut function.blocks {
+        let count = block.instructions.len();
+        block.instructions.retain(|instruction| match &instruction.kind {
+            InstructionKind::StoreLocal{local, ..} | InstructionKind::DeclareLocal{local, ..} => used.contains(local),
+            _ => true,
+        });
+        changed |= block.instructions.len() != count;
+    }
+
+    // the parameters stay, since they receive the arguments
+    let param_count = function.param_count;
+    let is_kept = |local: LocalID| local.0 < param_count || used.contains(&local);
+    if (0..function.locals.len()).all(|index| is_kept(LocalID(index))) {
+        return changed;
+    }
+
+    let mut new_ids = HashMap::new();
+    let locals = std::mem::take(&mut function.locals);
+    for (index, local) in locals.into_iter().enumerate() {
+        if is_kept(LocalID(index)) {
+            new_ids.insert(LocalID(index), LocalID(function.locals.len()));
+            function.locals.push(local);
+        }
+    }
+
+    for block in &mut function.blocks {
+        for instruction in &mut block.instructions {
+            remap_ids(&mut instruction.kind, &|temp| temp, &|local| new_ids[&local]);
+        }
+    }
+
+    return true;
+}
+
+fn eliminate_dead_code(function: &mut Function) -> bool {
+
+    let mut changed = remove_unreachable_blocks(function);
+    changed |= merge_blocks(function);
+    changed |= remove_unused_instructions(function);
+    changed |= remove_unused_locals(function);
+    return changed;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  inlining                                  */
+/* -------------------------------------------------------------------------- */
+
+/// Instructions a function has, at most, to be inlined.
+const INLINE_THRESHOLD: usize = 16;
+
+/// Renames the temporaries and locals of an instruction.
+fn remap_ids(kind: &mut InstructionKind, temps: &dyn Fn(Temp) -> Temp, locals: &dyn Fn(LocalID) -> LocalID) {
+
+    for operand in kind.operands_mut() {
+        if let Operand::Temp(temp) = operand {
+            *temp = temps(*temp);
+        }
+    }
+
+    match kind {
+        InstructionKind::LoadLocal{dest, local} | InstructionKind::LocalRef{dest, local} => {
+            *dest = temps(*dest);
+            *local = locals(*local);
+        },
+        InstructionKind::StoreLocal{local, ..} | InstructionKind::DeclareLocal{local, ..} => *local = locals(*local),
+        InstructionKind::Store{..} => {},
+        InstructionKind::Copy{dest, ..} |
+        InstructionKind::Load{dest, ..} |
+        InstructionKind::Dereference{dest, ..} |
+        InstructionKind::Box{dest, ..} |
+        InstructionKind::MakeList{dest, ..} |
+        InstructionKind::MakeStruct{dest, ..} |
+        InstructionKind::FieldRef{dest, ..} |
+        InstructionKind::MakeVariant{dest, ..} |
+        InstructionKind::Tag{dest, ..} |
+        InstructionKind::Payload{dest, ..} |
+        InstructionKind::Unary{dest, ..} |
+        InstructionKind::Binary{dest, ..} |
+        InstructionKind::Call{dest, ..} |
+        InstructionKind::CallBuiltin{dest, ..} |
+        InstructionKind::Items{dest, ..} |
+        InstructionKind::ItemCount{dest, ..} |
+        InstructionKind::ItemRef{dest, ..} => *dest = temps(*dest),
+    }
+}
+
+/// Whether calls of the function can be replaced by its body: it is small, can not change values
+/// outside of it, does not call other subprograms, which also keeps recursive functions out, and
+/// always reaches a `retornar` with a value.
+fn is_inlinable(function: &Function) -> bool {
+
+    let reachable = function.reachable();
+    let blocks = || function.blocks.iter().enumerate().filter(|(index, _)| reachable[*index]).map(|(_, block)| block);
+    let instructions = || blocks().flat_map(|block| &block.instructions);
+
+    let returns_value = |block: &BasicBlock| match &block.terminator {
+        Terminator::Return(Operand::Constant(Constant::Nothing)) => function.return_type == Type::Nothing,
+        _ => true,
+    };
+
+    return function.is_pure
+        && instructions().count() <= INLINE_THRESHOLD
+        && !instructions().any(|instruction| matches!(instruction.kind, InstructionKind::Call{..}))
+        && blocks().all(returns_value);
+}
+
+fn unique_local_name(function: &Function, base: String) -> String {
+
+    let mut name = base.clone();
+    let mut count = 1;
+    while function.locals.iter().any(|local| local.name == name) {
+        count += 1;
+        name = format!("{}.{}", base, count);
+    }
+
+    return name;
+}
+
+/// Replaces the call at the position of the block by the blocks of the callee. The block is split
+/// at the call: the instructions before it give the arguments to the parameters, now locals of the
+/// caller, and jump to the body, whose returns store the result and jump to the instructions after
+/// the call.
+fn inline_call(caller: &mut Function, block: BlockID, index: usize, callee: &Function) {
+
+    let call = &mut caller.blocks[block.0].instructions;
+    let after_call = call.split_off(index + 1);
+    let call = call.pop().expect("the call is in the block");
+    let InstructionKind::Call{dest, args, ..} = call.kind else { unreachable!("only calls are inlined") };
+
+    let local_offset = caller.locals.len();
+    for local in &callee.locals {
+        let name = unique_local_name(caller, format!("{}.{}", callee.name, local.name));
+        caller.locals.push(Local{name, ty: local.ty.clone()});
+    }
+    let result = LocalID(caller.locals.len());
+    let name = unique_local_name(caller, format!("{}.$retorno", callee.name));
+    caller.locals.push(Local{name, ty: callee.return_type.clone()});
+
+    let temp_offset = caller.temps.len();
+    caller.temps.extend(callee.temps.iter().cloned());
+
+    // the instructions after the call start by reading the result, and the body comes after them
+    let continuation_id = BlockID(caller.blocks.len());
+    let block_offset = continuation_id.0 + 1;
+    let original = &mut caller.blocks[block.0];
+    let mut continuation = BasicBlock{
+        instructions: vec![Instruction{kind: InstructionKind::LoadLocal{dest, local: result}, lexical_info: call.lexical_info}],
+        terminator: std::mem::replace(&mut original.terminator, Terminator::Jump(BlockID(block_offset))),
+        terminator_info: std::mem::replace(&mut original.terminator_info, call.lexical_info),
+    };
+    continuation.instructions.extend(after_call);
+    for (param, arg) in args.into_iter().enumerate() {
+        let kind = InstructionKind::DeclareLocal{local: LocalID(local_offset + param), value: Some(arg)};
+        original.instructions.push(Instruction{kind, lexical_info: call.lexical_info});
+    }
+    caller.blocks.push(continuation);
+
+    let temps = |temp: Temp| Temp(temp.0 + temp_offset);
+    let locals = |local: LocalID| LocalID(local.0 + local_offset);
+    for callee_block in &callee.blocks {
+        let mut block = callee_block.clone();
+        for instruction in &mut block.instructions {
+            remap_ids(&mut instruction.kind, &temps, &locals);
+        }
+        for operand in block.terminator.operands_mut() {
+            if let Operand::Temp(temp) = operand {
+                *temp = temps(*temp);
+            }
+        }
+        for target in block.terminator.successors_mut() {
+            *target = BlockID(target.0 + block_offset);
+        }
+        if let Terminator::Return(value) = &block.terminator {
+            let kind = InstructionKind::StoreLocal{local: result, value: value.clone()};
+            block.instructions.push(Instruction{kind, lexical_info: block.terminator_info});
+            block.terminator = Terminator::Jump(continuation_id);
+        }
+        caller.blocks.push(block);
+    }
+}
+
+fn inline_calls(program: &mut Program) -> bool {
+
+    let callees: Vec<Option<Function>> = program.functions.iter()
+        .map(|function| is_inlinable(function).then(|| function.clone()))
+        .collect();
+
+    let mut changed = false;
+    for caller in &mut program.functions {
+        loop {
+            // the inlined bodies have no calls, so this ends
+            let call = caller.blocks.iter().enumerate().find_map(|(block, basic_block)| {
+                basic_block.instructions.iter().enumerate().find_map(|(index, instruction)| match &instruction.kind {
+                    InstructionKind::Call{function, ..} => callees[function.0].as_ref().map(|callee| (BlockID(block), index, callee)),
+                    _ => None,
+                })
+            });
+
+            let Some((block, index, callee)) = call else { break };
+            inline_call(caller, block, index, callee);
+            changed = true;
+        }
+    }
+
+    return changed;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::analysis::{analyze, AnalysisSettings};
+    use crate::bytecode::compile;
+    use crate::ir_interpreter;
+    use crate::ir_verifier::verify;
+    use crate::tso_parser::parse_source;
+    use crate::vm;
+
+
+    fn lower_source(source_code: &str) -> Program {
+
+        let output = parse_source(source_code).unwrap();
+        let analysis = analyze(&output.ast, &AnalysisSettings::default());
+        assert!(!output.has_errors() && !analysis.has_errors());
+
+        return lower(&output.ast, &analysis.resolution, &analysis.types);
+    }
+
+    /// Runs the passes once, checking the IR before and after them against the snapshots.
+    fn assert_passes(source_code: &str, passes: &[Pass], before: &[&str], after: &[&str]) {
+
+        let mut program = lower_source(source_code);
+        assert_eq!(print_program(&program), before.join("\n"));
+
+        for pass in passes {
+            pass.run(&mut program);
+            assert_eq!(verify(&program), Vec::new(), "after {:?}", pass);
+        }
+        assert_eq!(print_program(&program), after.join("\n"));
+    }
+
+    #[test]
+    fn test_constant_folding() {
+
+        let source_code = r#"
+            procedimento principal() {
+                se 2 * 3 > 5 { escrever(-(1 + 2)); }
+                escrever(1 / 0);
+            }
+        "#;
+
+        let before = [
+            "fn principal() -> nada",
+            "bb0:",
+            "    %0: inteiro = 2 * 3",
+            "    %1: lógico = %0 > 5",
+            "    branch %1, bb1, bb2",
+            "bb1:",
+            "    %2: inteiro = 1 + 2",
+            "    %3: inteiro = - %2",
+            "    %4: nada = builtin escrever(%3)",
+            "    jump bb2",
+            "bb2:",
+            "    %5: inteiro = 1 / 0",
+            "    %6: nada = builtin escrever(%5)",
+            "    return nada",
+            "",
+        ];
+
+        // the division by zero is left to fail when the program runs
+        let after = [
+            "fn principal() -> nada",
+            "bb0:",
+            "    %0: inteiro = copy 6",
+            "    %1: lógico = copy verdadeiro",
+            "    jump bb1",
+            "bb1:",
+            "    %2: inteiro = copy 3",
+            "    %3: inteiro = copy -3",
+            "    %4: nada = builtin escrever(-3)",
+            "    jump bb2",
+            "bb2:",
+            "    %5: inteiro = 1 / 0",
+            "    %6: nada = builtin escrever(%5)",
+            "    return nada",
+            "",
+        ];
+
+        let passes = [Pass::ConstantFolding, Pass::CopyPropagation, Pass::ConstantFolding, Pass::CopyPropagation, Pass::ConstantFolding];
+        assert_passes(source_code, &passes, &before, &after);
+    }
+
+    #[test]
+    fn test_folding_large_numbers() {
+
+        let source_code = r#"
+            procedimento principal() {
+                escrever(9223372036854775807 * 2);
+                escrever(0.1 + 0.2 == 0.3);
+            }
+        "#;
+
+        let before = [
+            "fn principal() -> nada",
+            "bb0:",
+            "    %0: inteiro = 9223372036854775807 * 2",
+            "    %1: nada = builtin escrever(%0)",
+            "    %2: decimal = 0.1 + 0.2",
+            "    %3: lógico = %2 == 0.3",
+            "    %4: nada = builtin escrever(%3)",
+            "    return nada",
+            "",
+        ];
+
+        let after = [
+            "fn principal() -> nada",
+            "bb0:",
+            "    %0: inteiro = copy 18446744073709551614",
+            "    %1: nada = builtin escrever(18446744073709551614)",
+            "    %2: decimal = copy 0.3",
+            "    %3: lógico = copy verdadeiro",
+            "    %4: nada = builtin escrever(verdadeiro)",
+            "    return nada",
+            "",
+        ];
+
+        let passes = [Pass::ConstantFolding, Pass::CopyPropagation, Pass::ConstantFolding, Pass::CopyPropagation];
+        assert_passes(source_code, &passes, &before, &after);
+    }
+
+    #[test]
+    fn test_copy_propagation() {
+
+        let source_code = r#"
+            procedimento principal() {
+                var mut x: inteiro = 2;
+                x = x + 1;
+                escrever(x * x);
+            }
+        "#;
+
+        let before = [
+            "fn principal() -> nada",
+            "    local x: inteiro",
+            "bb0:",
+            "    declare x, 2",
+            "    %0: inteiro = load x",
+            "    %1: inteiro = %0 + 1",
+            "    store x, %1",
+            "    %2: inteiro = load x",
+            "    %3: inteiro = load x",
+            "    %4: inteiro = %2 * %3",
+            "    %5: nada = builtin escrever(%4)",
+            "    return nada",
+            "",
+        ];
+
+        let after = [
+            "fn principal() -> nada",
+            "    local x: inteiro",
+            "bb0:",
+            "    declare x, 2",
+            "    %0: inteiro = copy 2",
+            "    %1: inteiro = 2 + 1",
+            "    store x, %1",
+            "    %2: inteiro = copy %1",
+            "    %3: inteiro = copy %1",
+            "    %4: inteiro = %1 * %1",
+            "    %5: nada = builtin escrever(%4)",
+            "    return nada",
+            "",
+        ];
+
+        assert_passes(source_code, &[Pass::CopyPropagation], &before, &after);
+    }
+
+    #[test]
+    fn test_dead_code_elimination() {
+
+        let source_code = r#"
+            func primeiro(itens: ref lista<inteiro>) -> inteiro {
+                var mut nao_usado: inteiro = 0;
+                enquanto verdadeiro {
+                    parar;
+                    nao_usado = 1;
+                }
+                retornar obter(itens, 0);
+                var vazia: lógico = tamanho(itens) == 0;
+            }
+        "#;
+
+        let before = [
+            "fn primeiro(itens: ref lista<inteiro>) -> inteiro",
+            "    local nao_usado: inteiro",
+            "    local vazia: lógico",
+            "bb0:",
+            "    declare nao_usado, 0",
+            "    jump bb1",
+            "bb1:",
+            "    branch verdadeiro, bb2, bb3",
+            "bb2:",
+            "    jump bb3",
+            "bb3:",
+            "    %0: ref lista<inteiro> = load itens",
+            "    %1: inteiro = builtin obter(%0, 0)",
+            "    return %1",
+            "bb4:",
+            "    store nao_usado, 1",
+            "    jump bb1",
+            "bb5:",
+            "    return nada",
+            "bb6:",
+            "    %2: ref lista<inteiro> = load itens",
+            "    %3: inteiro = builtin tamanho(%2)",
+            "    %4: lógico = %3 == 0",
+            "    declare vazia, %4",
+            "    return nada",
+            "",
+        ];
+
+        // the code after `parar` was the only way back to the start of the loop, which then joins the entry
+        let after = [
+            "fn primeiro(itens: ref lista<inteiro>) -> inteiro",
+            "bb0:",
+            "    branch verdadeiro, bb1, bb2",
+            "bb1:",
+            "    jump bb2",
+            "bb2:",
+            "    %0: ref lista<inteiro> = load itens",
+            "    %1: inteiro = builtin obter(%0, 0)",
+            "    return %1",
+            "",
+        ];
+
+        assert_passes(source_code, &[Pass::DeadCodeElimination], &before, &after);
+    }
+
+    #[test]
+    fn test_inlining() {
+
+        let source_code = r#"
+            func dobro(x: inteiro) -> inteiro {
+                retornar x * 2;
+            }
+
+            procedimento principal() {
+                escrever(dobro(dobro(3)) + 1);
+            }
+        "#;
+
+        let before = [
+            "fn dobro(x: inteiro) -> inteiro",
+            "bb0:",
+            "    %0: inteiro = load x",
+            "    %1: inteiro = %0 * 2",
+            "    return %1",
+            "bb1:",
+            "    return nada",
+            "",
+            "fn principal() -> nada",
+            "bb0:",
+            "    %0: inteiro = call dobro(3)",
+            "    %1: inteiro = call dobro(%0)",
+            "    %2: inteiro = %1 + 1",
+            "    %3: nada = builtin escrever(%2)",
+            "    return nada",
+            "",
+        ];
+
+        let after = [
+            "fn dobro(x: inteiro) -> inteiro",
+            "bb0:",
+            "    %0: inteiro = load x",
+            "    %1: inteiro = %0 * 2",
+            "    return %1",
+            "",
+            "fn principal() -> nada",
+            "bb0:",
+            "    %3: nada = builtin escrever(13)",
+            "    return nada",
+            "",
+        ];
+
+        let mut program = lower_source(source_code);
+        assert_eq!(print_program(&program), before.join("\n"));
+        optimize(&mut program, OptimizationLevel::O2);
+        assert_eq!(verify(&program), Vec::new());
+        assert_eq!(print_program(&program), after.join("\n"));
+
+        // without inlining, only the dead block goes away
+        let mut program = lower_source(source_code);
+        optimize(&mut program, OptimizationLevel::O1);
+        assert!(print_program(&program).contains("call dobro(3)"));
+    }
+
+    #[test]
+    fn test_optimized_programs_behave_the_same() {
+
+        let programs = [
+            r#"
+                func fatorial(n: inteiro) -> inteiro {
+                    se n <= 1 { retornar 1; }
+                    retornar n * fatorial(n - 1);
+                }
+                func maior(a: inteiro, b: inteiro) -> inteiro {
+                    se a > b { retornar a; } senão { retornar b; }
+                }
+                procedimento principal() {
+                    var mut total: inteiro = 0;
+                    para cada item em [3, 1, 4, 1, 5] {
+                        se item == 1 { continuar; }
+                        total = maior(total, item) + fatorial(3);
+                    }
+                    escrever(total);
+                    escrever(verdadeiro e (1 > 2 ou 2 > 1));
+                }
+            "#,
+            r#"
+                procedimento dobrar(mut itens: mut ref lista<inteiro>) {
+                    para cada mut ref item em itens { *item = *item * 2; }
+                }
+                procedimento principal() {
+                    var mut itens: lista<inteiro> = [1, 2];
+                    var copia: lista<inteiro> = itens;
+                    dobrar(itens);
+                    adicionar(itens, 10 / 2);
+                    escrever(itens);
+                    escrever(copia);
+                    escrever(1 / (1 - 1));
+                }
+            "#,
+            r#"
+                enumeração Forma { Quadrado(inteiro), Retângulo(inteiro, inteiro), Vazio }
+                func área(f: Forma) -> inteiro {
+                    escolha f {
+                        caso Quadrado(lado) => { retornar lado * lado; }
+                        caso Retângulo(a, b) => { retornar a * b; }
+                        caso Vazio => { retornar 0; }
+                    }
+                }
+                procedimento principal() {
+                    escrever(área(Quadrado(3)) + área(Retângulo(2, 1 + 1)) + área(Vazio));
+                    escrever([Vazio, Quadrado(2 * 2)]);
+                }
+            "#,
+        ];
+
+        for source_code in programs {
+            let output = parse_source(source_code).unwrap();
+            let analysis = analyze(&output.ast, &AnalysisSettings::default());
+            let bytecode = compile(&lower(&output.ast, &analysis.resolution, &analysis.types));
+            let mut written = Vec::new();
+            let expected = vm::execute(&bytecode, &mut "".as_bytes(), &mut written).map(|()| String::from_utf8(written).unwrap());
+
+            for level in [OptimizationLevel::O0, OptimizationLevel::O1, OptimizationLevel::O2] {
+                let mut program = lower(&output.ast, &analysis.resolution, &analysis.types);
+                optimize(&mut program, level);
+                assert_eq!(verify(&program), Vec::new(), "{:?}", level);
+
+                let mut written = Vec::new();
+                let result = ir_interpreter::execute(&program, &mut "".as_bytes(), &mut written).map(|()| String::from_utf8(written).unwrap());
+                assert_eq!(result, expected, "{:?}", level);
+
+                let mut written = Vec::new();
+                let result = vm::execute(&compile(&program), &mut "".as_bytes(), &mut written).map(|()| String::from_utf8(written).unwrap());
+                assert_eq!(result, expected, "{:?}", level);
+            }
+        }
+    }
+}
@@ -0,0 +1,769 @@
+
+// std
+use std::collections::HashMap;
+
+// local
+use crate::ast::*;
+use crate::diagnostic::Diagnostic;
+use crate::resolver::{Resolution, Symbol};
+use crate::type_checker::TypeTable;
+use crate::types::Type;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   origins                                  */
+/* -------------------------------------------------------------------------- */
+
+/// Who owns a value that a `ref` points to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Owner {
+    /// An `auto` or `comp` variable, parameter or `para cada` item, identified by its declaration.
+    Variable(IdentifierID),
+    /// A value computed by an expression, freed at the end of the statement.
+    Temporary(ExprID),
+    /// A temporary value iterated by a `para cada`, freed at the end of the loop.
+    LoopTemporary(ExprID),
+}
+
+/// What a `ref` points to, and so for how long it is valid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Origin {
+    /// A value received from the caller, valid during the whole function.
+    Caller,
+    /// A value freed when the scope with this depth ends.
+    Scope{depth: usize, owner: Owner},
+}
+
+impl Origin {
+
+    fn temporary(expr: ExprID) -> Origin {
+        return Origin::Scope{depth: usize::MAX, owner: Owner::Temporary(expr)};
+    }
+
+    /// Whether a `ref` with this origin stays valid while a scope of depth `depth` exists.
+    fn outlives(&self, depth: usize) -> bool {
+
+        return match self {
+            Origin::Caller => true,
+            Origin::Scope{depth: origin_depth, ..} => *origin_depth <= depth,
+        };
+    }
+
+    /// The origin that ends first.
+    fn shortest(self, other: Origin) -> Origin {
+
+        return match (self, other) {
+            (Origin::Caller, other) => other,
+            (origin, Origin::Caller) => origin,
+            (Origin::Scope{depth: a, ..}, Origin::Scope{depth: b, ..}) => if b > a { other } else { self },
+        };
+    }
+}
+
+/// Where a `ref` is being stored.
+#[derive(Clone, Copy)]
+enum Holder {
+    /// A variable, or the value it owns when it is changed through a `ref`.
+    Variable(IdentifierID),
+    /// A value owned by the caller, changed through a `mut ref` parameter.
+    Caller,
+    Return,
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   checker                                  */
+/* -------------------------------------------------------------------------- */
+
+struct OwnershipChecker<'a> {
+    ast: &'a Ast,
+    resolution: &'a Resolution,
+    types: &'a TypeTable,
+    diagnostics: &'a mut Vec<Diagnostic>,
+    /// Shortest-lived origin of each `ref` variable, parameter and `para cada` item, seen so far.
+    ref_origins: HashMap<IdentifierID, Origin>,
+    /// Return type of the function being checked.
+    return_type: Type,
+    /// Whether the subprogram being checked is a procedure, for the messages.
+    is_procedure: bool,
+}
+
+impl<'a> OwnershipChecker<'a> {
+
+    fn is_ref(&self, expr: ExprID) -> bool {
+        return matches!(self.types.expr_type(expr), Type::Ref{..});
+    }
+
+    /// Whether `expr` is a `ref` or a structure with `ref` fields.
+    fn holds_ref(&self, expr: ExprID) -> bool {
+        return self.types.holds_ref(self.types.expr_type(expr));
+    }
+
+    fn declaration_depth(&self, declaration: IdentifierID) -> usize {
+        return self.resolution.scope_depth(declaration).unwrap_or(0);
+    }
+
+    /// Origin of a `ref` created from the value of `expr`.
+    fn borrow_origin(&self, expr: ExprID) -> Origin {
+
+        if self.is_ref(expr) {
+            return self.ref_origin(expr);
+        }
+
+        return self.storage_origin(expr);
+    }
+
+    /// Where the value of `expr`, which is not a `ref`, is stored.
+    fn storage_origin(&self, expr: ExprID) -> Origin {
+
+        return match self.ast.get_expression(expr) {
+            Expression::Identifier{node_id} => {
+                match self.resolution.declaration_of(*node_id) {
+                    Some(declaration) if !matches!(self.resolution.symbol(declaration), Some(Symbol::Function(_) | Symbol::Variant(..))) => {
+                        Origin::Scope{depth: self.declaration_depth(declaration), owner: Owner::Variable(declaration)}
+                    },
+                    _ => Origin::Caller,
+                }
+            },
+            Expression::Access{object, ..} => self.borrow_origin(*object),
+            Expression::UnaryOprt{oprt: UnaryOprt::Deref, operand} => self.borrow_origin(*operand),
+            Expression::Error => Origin::Caller,
+            _ => Origin::temporary(expr),
+        };
+    }
+
+    /// Origin of the `ref`s kept in a place of type `holder_type` when `value` is stored in it:
+    /// a new `ref` to the value, or the `ref`s the value already holds.
+    fn stored_origin(&self, holder_type: &Type, value: ExprID) -> Origin {
+
+        return match holder_type {
+            Type::Ref{..} => self.borrow_origin(value),
+            _ => self.ref_origin(value),
+        };
+    }
+
+    /// What the `ref` produced by `expr`, or the `ref`s in the structure it produces, point to.
+    fn ref_origin(&self, expr: ExprID) -> Origin {
+
+        return match self.ast.get_expression(expr) {
+            Expression::Identifier{node_id} => {
+                self.resolution.declaration_of(*node_id)
+                    .and_then(|declaration| self.ref_origins.get(&declaration).copied())
+                    .unwrap_or(Origin::Caller)
+            },
+            Expression::Call{callee, args} => {
+                // the returned `ref` may point to any value passed to a `ref` parameter
+                let Type::Function{params, ..} = self.types.expr_type(*callee).dereferenced() else {
+                    return Origin::Caller;
+                };
+
+                params.iter().zip(args)
+                    .filter(|(param, _)| self.types.holds_ref(param))
+                    .map(|(param, arg)| self.stored_origin(param, *arg))
+                    .fold(Origin::Caller, Origin::shortest)
+            },
+            Expression::ListLiteral{values} => {
+                let Type::List(item) = self.types.expr_type(expr) else {
+                    return Origin::Caller;
+                };
+
+                match self.types.holds_ref(item) {
+                    true => values.iter().map(|value| self.stored_origin(item, *value)).fold(Origin::Caller, Origin::shortest),
+                    false => Origin::Caller,
+                }
+            },
+            Expression::StructLiteral{fields, ..} => {
+                let Type::Struct{id, ..} = self.types.expr_type(expr) else {
+                    return Origin::Caller;
+                };
+
+                let field_types = self.types.fields(*id);
+                fields.iter()
+                    .filter_map(|field| {
+                        let field_name = self.ast.get_identifier(field.name);
+                        let field_type = &field_types.iter().find(|field_type| field_type.name == *field_name)?.ty;
+                        self.types.holds_ref(field_type).then(|| self.stored_origin(field_type, field.value))
+                    })
+                    .fold(Origin::Caller, Origin::shortest)
+            },
+            // the `ref`s in a structure point to values that live at least as long as it does
+            Expression::Access{object, ..} if self.holds_ref(*object) => self.ref_origin(*object),
+            Expression::Access{object, ..} => self.borrow_origin(*object),
+            // the `ref`s in a value live at least as long as a `ref` to it
+            Expression::UnaryOprt{oprt: UnaryOprt::Deref, operand} => self.ref_origin(*operand),
+            _ => Origin::Caller,
+        };
+    }
+
+    /* ---- reports ---- */
+
+    fn owner_name(&self, owner: Owner) -> Option<&'a str> {
+
+        return match owner {
+            Owner::Variable(declaration) => Some(self.ast.get_identifier(declaration).as_str()),
+            Owner::Temporary(_) | Owner::LoopTemporary(_) => None,
+        };
+    }
+
+    fn report_dangling(&mut self, holder: Holder, origin: Origin, value: ExprID) {
+
+        let Origin::Scope{owner, ..} = origin else { return };
+        let lexical_info = *self.ast.get_lexical_info(value);
+
+        let diagnostic = match (holder, owner) {
+            (_, Owner::Temporary(_)) => {
+                Diagnostic::error("E0403", "a referência aponta para um valor temporário", lexical_info)
+                    .with_label("este valor deixa de existir no fim da instrução")
+                    .with_note("guarde o valor em uma variável e crie a referência a partir dela")
+            },
+            (Holder::Caller, _) => {
+                let message = match self.owner_name(owner) {
+                    Some(name) => format!("uma referência para `{}` é guardada em um valor de quem chamou, que continua existindo depois que `{}` deixar de existir", name, name),
+                    None => "uma referência é guardada em um valor de quem chamou, que continua existindo depois que o valor percorrido deixar de existir".to_string(),
+                };
+                Diagnostic::error("E0404", message, lexical_info)
+                    .with_label("a referência ficaria apontando para um valor já liberado")
+                    .with_note("um valor recebido por uma `mut ref` só pode guardar referências para valores que também vieram de quem chamou")
+            },
+            (Holder::Return, _) => {
+                let kind = if self.is_procedure { "o procedimento" } else { "a função" };
+                let message = match self.owner_name(owner) {
+                    Some(name) => format!("{} retorna uma referência para `{}`, que deixa de existir quando {} termina", kind, name, kind),
+                    None => format!("{} retorna uma referência para um valor que deixa de existir quando {} termina", kind, kind),
+                };
+                Diagnostic::error("E0402", message, lexical_info)
+                    .with_label("a referência ficaria apontando para um valor já liberado")
+                    .with_note("retorne o próprio valor, trocando o tipo de retorno `ref T` por `T`, ou use `comp T` para compartilhar o valor")
+            },
+            (Holder::Variable(reference), owner) => {
+                let reference_name = self.ast.get_identifier(reference);
+                // a list or structure keeps the `ref`, instead of being one
+                let holder = match self.types.declaration_type(reference) {
+                    Type::Ref{..} => format!("a referência `{}`", reference_name),
+                    _ => format!("a referência guardada em `{}`", reference_name),
+                };
+                let (message, label) = match self.owner_name(owner) {
+                    Some(name) => (
+                        format!("{} pode continuar existindo depois que `{}` deixar de existir", holder, name),
+                        format!("`{}` é liberada no fim do bloco em que foi declarada", name),
+                    ),
+                    None => (
+                        format!("{} pode continuar existindo depois que o valor percorrido deixar de existir", holder),
+                        "este valor é liberado no fim do `para cada`".to_string(),
+                    ),
+                };
+                Diagnostic::error("E0401", message, lexical_info)
+                    .with_label(label)
+                    .with_secondary(*self.ast.get_lexical_info(reference), format!("`{}` declarada aqui", reference_name))
+                    .with_note("uma `ref` não é dona do valor: quando o valor é liberado, a referência ficaria apontando para nada")
+                    .with_note(format!("declare `{}` no mesmo bloco do valor, ou use `comp` para compartilhar o valor", reference_name))
+            },
+        };
+
+        self.diagnostics.push(diagnostic);
+    }
+
+    /* ---- traversal ---- */
+
+    /// Checks that the `ref`s with this origin, kept from `value` in `holder`, never outlive
+    /// what they point to.
+    fn check_store(&mut self, holder: Holder, origin: Origin, value: ExprID) {
+
+        let holder_depth = match holder {
+            Holder::Variable(declaration) => self.declaration_depth(declaration),
+            Holder::Caller | Holder::Return => 0,
+        };
+
+        if !origin.outlives(holder_depth) {
+            self.report_dangling(holder, origin, value);
+        }
+    }
+
+    fn store_ref(&mut self, holder: Holder, origin: Origin, value: ExprID) {
+
+        self.check_store(holder, origin, value);
+        if let Holder::Variable(declaration) = holder {
+            let previous = self.ref_origins.get(&declaration).copied().unwrap_or(Origin::Caller);
+            self.ref_origins.insert(declaration, previous.shortest(origin));
+        }
+    }
+
+    /// Who owns the value changed by assigning to `place`: the variable itself, the structure
+    /// that owns the field, or whoever owns the value a `ref` points to.
+    fn place_holder(&self, place: ExprID) -> Option<Holder> {
+
+        return match self.ast.get_expression(place) {
+            Expression::Identifier{node_id} => self.resolution.declaration_of(*node_id).map(Holder::Variable),
+            Expression::Access{object, ..} if matches!(self.types.expr_type(*object), Type::Struct{..}) => self.place_holder(*object),
+            Expression::Access{object, ..} => self.pointee_holder(*object),
+            Expression::UnaryOprt{oprt: UnaryOprt::Deref, operand} => self.pointee_holder(*operand),
+            _ => None,
+        };
+    }
+
+    /// Who owns the value the `ref` produced by `expr` points to.
+    fn pointee_holder(&self, expr: ExprID) -> Option<Holder> {
+
+        return match self.ref_origin(expr) {
+            Origin::Caller => Some(Holder::Caller),
+            Origin::Scope{owner: Owner::Variable(declaration), ..} => Some(Holder::Variable(declaration)),
+            // a temporary value ends with the statement, along with the `ref`s stored in it
+            Origin::Scope{..} => None,
+        };
+    }
+
+    /// Who owns the value changed through a `mut ref` argument, which is borrowed from `arg`
+    /// unless it already is a `ref`.
+    fn borrowed_holder(&self, arg: ExprID) -> Option<Holder> {
+
+        return match self.is_ref(arg) {
+            true => self.pointee_holder(arg),
+            false => self.place_holder(arg),
+        };
+    }
+
+    /// A call may store the `ref`s received by the other parameters in the values behind its
+    /// `mut ref` parameters, as `adicionar(lista, item)` does.
+    fn check_call_stores(&mut self, callee: ExprID, args: &[ExprID]) {
+
+        let types = self.types;
+        let Type::Function{params, ..} = types.expr_type(callee).dereferenced() else {
+            return;
+        };
+
+        for (index, (param, arg)) in params.iter().zip(args).enumerate() {
+
+            let Type::Ref{is_mut: true, inner} = param else { continue };
+            if !types.holds_ref(inner) {
+                continue;
+            }
+            let Some(holder) = self.borrowed_holder(*arg) else { continue };
+
+            for (other_index, (other_param, other_arg)) in params.iter().zip(args).enumerate() {
+                if other_index != index && types.holds_ref(other_param) {
+                    let origin = self.stored_origin(other_param, *other_arg);
+                    self.store_ref(holder, origin, *other_arg);
+                }
+            }
+        }
+    }
+
+    fn check_expr(&mut self, id: ExprID) {
+
+        match self.ast.get_expression(id) {
+            Expression::IntegerLiteral{..} |
+            Expression::DecimalLiteral{..} |
+            Expression::StringLiteral{..} |
+            Expression::BooleanLiteral{..} |
+            Expression::Identifier{..} |
+            Expression::Error => {},
+            Expression::ListLiteral{values} => {
+                for value in values {
+                    self.check_expr(*value);
+                }
+            },
+            Expression::InterpolatedString{parts} => {
+                for value in parts.iter().filter_map(StringPart::value) {
+                    self.check_expr(value);
+                }
+            },
+            Expression::Call{callee, args} => {
+                self.check_expr(*callee);
+                for arg in args {
+                    self.check_expr(*arg);
+                }
+                self.check_call_stores(*callee, args);
+            },
+            Expression::StructLiteral{fields, ..} => {
+                for field in fields {
+                    self.check_expr(field.value);
+                }
+            },
+            Expression::Access{object, ..} => {
+                self.check_expr(*object);
+            },
+            Expression::BinaryOprt{left, right, ..} => {
+                self.check_expr(*left);
+                self.check_expr(*right);
+            },
+            Expression::UnaryOprt{operand, ..} => {
+                self.check_expr(*operand);
+            },
+            Expression::Assign{target, value} => {
+                self.check_expr(*target);
+                self.check_expr(*value);
+
+                let target_type = self.types.expr_type(*target);
+                if !self.types.holds_ref(target_type) {
+                    return;
+                }
+
+                if let Some(holder) = self.place_holder(*target) {
+                    let origin = self.stored_origin(target_type, *value);
+                    self.store_ref(holder, origin, *value);
+                }
+            },
+        }
+    }
+
+    fn check_block(&mut self, block: &[StmtID]) {
+
+        for stmt in block {
+            self.check_stmt(*stmt);
+        }
+    }
+
+    fn check_stmt(&mut self, id: StmtID) {
+
+        match self.ast.get_statement(id) {
+            Statement::Expression{expr} => {
+                self.check_expr(*expr);
+            },
+            Statement::VarDecl{name, init_expr, ..} => {
+                let Some(init_expr) = init_expr else { return };
+                self.check_expr(*init_expr);
+
+                let declared = self.types.declaration_type(*name);
+                if self.types.holds_ref(declared) {
+                    let origin = self.stored_origin(declared, *init_expr);
+                    self.store_ref(Holder::Variable(*name), origin, *init_expr);
+                }
+            },
+            Statement::If{branches, else_body} => {
+                for branch in branches {
+                    self.check_expr(branch.cond_expr);
+                    self.check_block(&branch.body);
+                }
+                if let Some(else_body) = else_body {
+                    self.check_block(else_body);
+                }
+            },
+            Statement::Match{value, cases} => {
+                self.check_expr(*value);
+                for case in cases {
+                    self.check_block(&case.body);
+                }
+            },
+            Statement::While{cond_expr, body_block} => {
+                self.check_expr(*cond_expr);
+                self.check_block(body_block);
+            },
+            Statement::ForEach{item, iter_expr, body_block} => {
+                self.check_expr(*iter_expr);
+
+                let for_each_decl = self.ast.get_for_each_decl(*item);
+                if for_each_decl.is_ref {
+
+                    // the items live in the iterated value, a temporary one lives until the loop ends
+                    let origin = match self.borrow_origin(*iter_expr) {
+                        Origin::Scope{owner: Owner::Temporary(expr), ..} => {
+                            Origin::Scope{depth: self.declaration_depth(for_each_decl.name), owner: Owner::LoopTemporary(expr)}
+                        },
+                        origin => origin,
+                    };
+                    self.ref_origins.insert(for_each_decl.name, origin);
+                } else if self.types.holds_ref(self.types.declaration_type(for_each_decl.name)) {
+                    // a copy of an item keeps the `ref`s the item holds
+                    let origin = self.ref_origin(*iter_expr);
+                    self.ref_origins.insert(for_each_decl.name, origin);
+                }
+
+                self.check_block(body_block);
+            },
+            Statement::Return{expr} => {
+                let Some(expr) = expr else { return };
+                self.check_expr(*expr);
+
+                if self.types.holds_ref(&self.return_type) {
+                    let origin = self.stored_origin(&self.return_type, *expr);
+                    self.check_store(Holder::Return, origin, *expr);
+                }
+            },
+            Statement::Continue |
+            Statement::Break |
+            Statement::Error => {},
+        }
+    }
+
+    fn check_function(&mut self, id: ScopeDefID) {
+
+        let scope_def = self.ast.get_scope_def(id);
+
+        // `ref` parameters, and the `ref`s in structure parameters, point to values owned by the caller
+        for param in scope_def.params() {
+            let param_name = self.ast.get_function_param(*param).name;
+            if self.types.holds_ref(self.types.declaration_type(param_name)) {
+                self.ref_origins.insert(param_name, Origin::Caller);
+            }
+        }
+
+        self.is_procedure = scope_def.is_procedure();
+        self.return_type = match self.types.declaration_type(scope_def.name()) {
+            Type::Function{return_type, ..} => return_type.as_ref().clone(),
+            _ => Type::Error,
+        };
+        self.check_block(scope_def.body());
+    }
+}
+
+/// Checks that no `ref` outlives the value it points to: when it is returned, stored in a
+/// variable declared in an outer block, or points to the items iterated by a `para cada ref`.
+pub fn check_ownership(ast: &Ast, resolution: &Resolution, types: &TypeTable, diagnostics: &mut Vec<Diagnostic>) {
+
+    let mut checker = OwnershipChecker{ast, resolution, types, diagnostics, ref_origins: HashMap::new(), return_type: Type::Error, is_procedure: false};
+
+    for scope_def in ast.get_subprograms() {
+        checker.check_function(scope_def);
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::builtin_types::TypeCatalogue;
+    use crate::resolver::resolve_names;
+    use crate::tso_parser::parse_source;
+    use crate::type_checker::check_types;
+
+
+    fn check(source_code: &str) -> Vec<&'static str> {
+
+        let output = parse_source(source_code).unwrap();
+        assert!(!output.has_errors());
+
+        let mut diagnostics = Vec::new();
+        let resolution = resolve_names(&output.ast, &mut diagnostics);
+        let types = check_types(&output.ast, &resolution, &TypeCatalogue::new(false), &mut diagnostics);
+        assert!(diagnostics.is_empty());
+
+        check_ownership(&output.ast, &resolution, &types, &mut diagnostics);
+        return diagnostics.iter().map(|diagnostic| diagnostic.code).collect();
+    }
+
+    #[test]
+    fn test_valid_references() {
+
+        let source_code = r#"
+            func maior(a: ref inteiro, b: ref inteiro) -> ref inteiro {
+                se a > b { retornar a; }
+                retornar b;
+            }
+
+            func foo(itens: ref lista<inteiro>, b: ref inteiro) -> ref inteiro {
+                var x: inteiro = 1;
+                var r: ref inteiro = x;
+                se x > 0 {
+                    var mut s: ref inteiro = r;
+                    var y: inteiro = 2;
+                    s = y;
+                }
+                var m: ref inteiro = maior(x, r);
+                para cada ref item em itens {
+                    retornar item;
+                }
+                retornar maior(b, b);
+            }
+        "#;
+
+        assert!(check(source_code).is_empty());
+    }
+
+    #[test]
+    fn test_returned_reference() {
+
+        let source_code = r#"
+            func foo(a: inteiro, b: ref inteiro) -> ref inteiro {
+                var c: inteiro = a;
+                var r: ref inteiro = c;
+                se a > 0 { retornar c; }
+                se a > 1 { retornar a; }
+                se a > 2 { retornar r; }
+                retornar b;
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0402", "E0402", "E0402"]);
+    }
+
+    #[test]
+    fn test_reference_stored_in_outer_variable() {
+
+        let source_code = r#"
+            func foo(a: inteiro) -> nada {
+                var mut r: ref inteiro = a;
+                se a > 0 {
+                    var b: inteiro = 1;
+                    r = b;
+                }
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0401"]);
+    }
+
+    #[test]
+    fn test_reference_to_temporary() {
+
+        let source_code = r#"
+            func maior(a: ref inteiro, b: ref inteiro) -> ref inteiro {
+                retornar a;
+            }
+
+            func foo(a: inteiro) -> nada {
+                var r: ref inteiro = a + 1;
+                var s: ref inteiro = maior(a, 2);
+                maior(a, 3);
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0403", "E0403"]);
+    }
+
+    #[test]
+    fn test_struct_ref_fields() {
+
+        let source_code = r#"
+            estrutura Alvo { r: ref inteiro, valor: inteiro }
+            estrutura Caixa { alvo: Alvo }
+
+            func primeiro(a: ref inteiro) -> Alvo {
+                retornar Alvo { r: a, valor: 1 };
+            }
+
+            func local(a: inteiro) -> Caixa {
+                var x: inteiro = a;
+                var alvo: Alvo = Alvo { r: x, valor: a };
+                var y: ref inteiro = alvo.valor;
+                retornar Caixa { alvo: alvo };
+            }
+
+            func temporario(a: inteiro) -> nada {
+                var alvo: Alvo = Alvo { r: a + 1, valor: a };
+                var copia: Alvo = primeiro(a);
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0402", "E0403"]);
+    }
+
+    #[test]
+    fn test_for_each_ref_item() {
+
+        let source_code = r#"
+            func dobro(a: inteiro) -> lista<inteiro> {
+                retornar [a, a];
+            }
+
+            func foo(a: inteiro) -> ref inteiro {
+                var mut r: ref inteiro = a;
+                para cada ref item em dobro(a) {
+                    r = item;
+                }
+                var itens: lista<inteiro> = [1, 2];
+                para cada ref item em itens {
+                    retornar item;
+                }
+                retornar a;
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0401", "E0402", "E0402"]);
+    }
+
+    #[test]
+    fn test_reference_stored_in_list() {
+
+        let source_code = r#"
+            procedimento principal() {
+                var mut i: inteiro = 0;
+                var mut l: lista<ref inteiro> = [];
+                enquanto i < 3 {
+                    var a: inteiro = i * 100;
+                    adicionar(l, a);
+                    i = i + 1;
+                }
+                var b: inteiro = 1;
+                adicionar(l, b);
+                se i > 0 {
+                    var c: inteiro = 2;
+                    l = [b, c];
+                    var locais: lista<ref inteiro> = [c];
+                    para cada r em l { adicionar(locais, r); }
+                }
+                escrever(l);
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0401", "E0401"]);
+    }
+
+    #[test]
+    fn test_reference_stored_through_mut_ref() {
+
+        let source_code = r#"
+            estrutura S { r: ref inteiro }
+
+            procedimento guarda(mut l: mut ref lista<ref inteiro>, x: ref inteiro) {
+                var a: inteiro = 42;
+                adicionar(l, a);
+                adicionar(l, x);
+                *l = [a];
+                *l = [x];
+            }
+
+            procedimento troca(mut r: mut ref ref inteiro, mut s: mut ref S, x: ref inteiro) {
+                var a: inteiro = 1;
+                *r = a;
+                s.r = a;
+                *r = x;
+                s.r = x;
+            }
+
+            procedimento principal() {
+                var mut l: lista<ref inteiro> = [];
+                var b: inteiro = 1;
+                guarda(l, b);
+                se b > 0 {
+                    var c: inteiro = 2;
+                    guarda(l, c);
+                    var mut r: mut ref lista<ref inteiro> = l;
+                    adicionar(r, c);
+                }
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0404", "E0404", "E0404", "E0404", "E0401", "E0401"]);
+    }
+
+    #[test]
+    fn test_returned_reference_message() {
+
+        let source_code = r#"
+            func foo(a: inteiro) -> ref inteiro {
+                retornar a;
+            }
+
+            procedimento bar(a: inteiro) -> ref inteiro {
+                retornar a;
+            }
+        "#;
+
+        let output = parse_source(source_code).unwrap();
+        let mut diagnostics = Vec::new();
+        let resolution = resolve_names(&output.ast, &mut diagnostics);
+        let types = check_types(&output.ast, &resolution, &TypeCatalogue::new(false), &mut diagnostics);
+        check_ownership(&output.ast, &resolution, &types, &mut diagnostics);
+
+        let messages: Vec<&str> = diagnostics.iter().map(|diagnostic| diagnostic.message.as_str()).collect();
+        assert_eq!(messages, vec![
+            "a função retorna uma referência para `a`, que deixa de existir quando a função termina",
+            "o procedimento retorna uma referência para `a`, que deixa de existir quando o procedimento termina",
+        ]);
+    }
+}
@@ -0,0 +1,437 @@
+// std
+use std::collections::{HashMap, HashSet};
+
+// local
+use crate::ast::*;
+use crate::builtins::Builtin;
+use crate::diagnostic::Diagnostic;
+use crate::resolver::{Resolution, Symbol};
+use crate::type_checker::TypeTable;
+use crate::types::Type;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   checker                                  */
+/* -------------------------------------------------------------------------- */
+
+struct PurityChecker<'a> {
+    ast: &'a Ast,
+    resolution: &'a Resolution,
+    types: &'a TypeTable,
+    diagnostics: &'a mut Vec<Diagnostic>,
+    /// The function being checked.
+    function: ScopeDefID,
+    /// Values iterated by each `para cada` item of the function.
+    iterated_values: HashMap<IdentifierID, ExprID>,
+    /// `ref` and `comp` variables of the function, and the ones that may have been given a value
+    /// outside of it.
+    reference_locals: HashSet<IdentifierID>,
+    outside_locals: HashSet<IdentifierID>,
+    /// Whether effects are reported, or the function is only walked to find `outside_locals`.
+    reporting: bool,
+}
+
+impl<'a> PurityChecker<'a> {
+
+    fn function_name(&self) -> &String {
+        return self.ast.get_identifier(self.ast.get_scope_def(self.function).name());
+    }
+
+    /// Every effect has the same fix, turning the function into a procedure.
+    fn report(&mut self, diagnostic: Diagnostic) {
+
+        if !self.reporting {
+            return;
+        }
+
+        let name = self.ast.get_scope_def(self.function).name();
+        let diagnostic = diagnostic
+            .with_secondary(*self.ast.get_lexical_info(name), "função")
+            .with_note(format!("funções não podem ter efeitos colaterais; se `{}` precisa deles, declare-a com `procedimento`", self.function_name()));
+        self.diagnostics.push(diagnostic);
+    }
+
+    /* ---- effects ---- */
+
+    /// Whether a value pointed to by `pointer` may exist outside of the function, which is only
+    /// known for variables: the storage they point to comes from a parameter or from the function.
+    fn points_outside(&self, pointer: ExprID) -> bool {
+
+        if !matches!(self.types.expr_type(pointer), Type::Ref{..} | Type::Comp{..}) {
+            return false;
+        }
+
+        return match self.ast.get_expression(pointer) {
+            Expression::Identifier{node_id} => match self.resolution.declaration_of(*node_id) {
+                // the items of a local value iterated by `para cada ref` belong to the function
+                Some(declaration) if self.iterated_values.contains_key(&declaration) => self.is_outside(self.iterated_values[&declaration]),
+                Some(declaration) if self.reference_locals.contains(&declaration) => self.outside_locals.contains(&declaration),
+                _ => true,
+            },
+            // the `ref` or `comp` returned by a call may be one of its arguments
+            Expression::Call{callee, args} => {
+                let Type::Function{params, ..} = self.types.expr_type(*callee).dereferenced() else {
+                    return true;
+                };
+                params.iter().zip(args).any(|(param, arg)| self.stored_outside(param, *arg))
+            },
+            _ => true,
+        };
+    }
+
+    /// Whether a place of type `holder_type` may point outside of the function after `value` is
+    /// stored in it: a `ref` to a place points where the place is, a new `comp` is created from
+    /// a value that is not a reference.
+    fn stored_outside(&self, holder_type: &Type, value: ExprID) -> bool {
+
+        return match holder_type {
+            Type::Ref{..} => self.points_outside(value) || self.is_outside(value),
+            Type::Comp{..} => self.points_outside(value),
+            _ => false,
+        };
+    }
+
+    /// Records the value a `ref` or `comp` variable is given, which it may point to afterwards.
+    fn give_value(&mut self, declaration: IdentifierID, value: ExprID) {
+
+        if self.reference_locals.contains(&declaration) && self.stored_outside(self.types.declaration_type(declaration), value) {
+            self.outside_locals.insert(declaration);
+        }
+    }
+
+    /// Whether changing this place may change a value outside of the function.
+    fn is_outside(&self, place: ExprID) -> bool {
+
+        return match self.ast.get_expression(place) {
+            Expression::Identifier{..} => self.points_outside(place),
+            Expression::Access{object, ..} => self.points_outside(*object) || self.is_outside(*object),
+            Expression::UnaryOprt{oprt: UnaryOprt::Deref, operand} => self.points_outside(*operand) || self.is_outside(*operand),
+            _ => false,
+        };
+    }
+
+    fn check_assign(&mut self, target: ExprID, value: ExprID) {
+
+        // assigning to a `ref` or `comp` variable only makes it point to another value
+        let writes_through = match self.ast.get_expression(target) {
+            Expression::Identifier{node_id} => {
+                if let Some(declaration) = self.resolution.declaration_of(*node_id) {
+                    self.give_value(declaration, value);
+                }
+                false
+            },
+            _ => self.is_outside(target),
+        };
+
+        if writes_through {
+            let diagnostic = Diagnostic::error("E0603", format!("a função `{}` altera um valor através de uma referência", self.function_name()), *self.ast.get_lexical_info(target))
+                .with_label("o valor alterado pode pertencer a quem chamou a função");
+            self.report(diagnostic);
+        }
+    }
+
+    fn check_call(&mut self, id: ExprID, callee: ExprID, args: &[ExprID]) {
+
+        let Expression::Identifier{node_id} = self.ast.get_expression(callee) else { return };
+        let callee_name = self.ast.get_identifier(*node_id);
+        let lexical_info = *self.ast.get_lexical_info(id);
+
+        match self.resolution.symbol(*node_id) {
+            Some(Symbol::Function(scope_def)) if self.ast.get_scope_def(scope_def).is_procedure() => {
+                let diagnostic = Diagnostic::error("E0604", format!("a função `{}` chama o procedimento `{}`", self.function_name(), callee_name), lexical_info)
+                    .with_label("procedimentos podem ter efeitos colaterais")
+                    .with_secondary(*self.ast.get_lexical_info(self.ast.get_scope_def(scope_def).name()), "procedimento");
+                self.report(diagnostic);
+            },
+            Some(Symbol::Builtin(builtin)) if builtin.info().performs_io => {
+                let diagnostic = Diagnostic::error("E0605", format!("a função `{}` lê ou escreve no terminal", self.function_name()), lexical_info)
+                    .with_label(format!("`{}` é um procedimento de entrada e saída", callee_name));
+                self.report(diagnostic);
+            },
+            // adding to a list of the function builds a value, adding to one of the caller changes it
+            Some(Symbol::Builtin(Builtin::Append)) if args.first().is_some_and(|list| self.stored_outside(&Type::Ref{is_mut: true, inner: Box::new(Type::Error)}, *list)) => {
+                let diagnostic = Diagnostic::error("E0603", format!("a função `{}` altera um valor através de uma referência", self.function_name()), *self.ast.get_lexical_info(args[0]))
+                    .with_label("a lista alterada pode pertencer a quem chamou a função");
+                self.report(diagnostic);
+            },
+            Some(Symbol::Builtin(builtin)) if builtin.info().is_procedure => {
+                let diagnostic = Diagnostic::error("E0604", format!("a função `{}` chama o procedimento `{}`", self.function_name(), callee_name), lexical_info)
+                    .with_label("procedimentos podem ter efeitos colaterais");
+                self.report(diagnostic);
+            },
+            _ => {},
+        }
+    }
+
+    /* ---- traversal ---- */
+
+    fn check_expr(&mut self, id: ExprID) {
+
+        match self.ast.get_expression(id) {
+            Expression::IntegerLiteral{..} |
+            Expression::DecimalLiteral{..} |
+            Expression::StringLiteral{..} |
+            Expression::BooleanLiteral{..} |
+            Expression::Identifier{..} |
+            Expression::Error => {},
+            Expression::ListLiteral{values} => {
+                for value in values {
+                    self.check_expr(*value);
+                }
+            },
+            Expression::InterpolatedString{parts} => {
+                for value in parts.iter().filter_map(StringPart::value) {
+                    self.check_expr(value);
+                }
+            },
+            Expression::Call{callee, args} => {
+                self.check_call(id, *callee, args);
+                self.check_expr(*callee);
+                for arg in args {
+                    self.check_expr(*arg);
+                }
+            },
+            Expression::StructLiteral{fields, ..} => {
+                for field in fields {
+                    self.check_expr(field.value);
+                }
+            },
+            Expression::Access{object, ..} => {
+                self.check_expr(*object);
+            },
+            Expression::BinaryOprt{left, right, ..} => {
+                self.check_expr(*left);
+                self.check_expr(*right);
+            },
+            Expression::UnaryOprt{operand, ..} => {
+                self.check_expr(*operand);
+            },
+            Expression::Assign{target, value} => {
+                self.check_assign(*target, *value);
+                self.check_expr(*target);
+                self.check_expr(*value);
+            },
+        }
+    }
+
+    fn check_block(&mut self, block: &[StmtID]) {
+
+        for stmt in block {
+            self.check_stmt(*stmt);
+        }
+    }
+
+    fn check_stmt(&mut self, id: StmtID) {
+
+        match self.ast.get_statement(id) {
+            Statement::Expression{expr} => self.check_expr(*expr),
+            Statement::VarDecl{name, init_expr, ..} => {
+                if matches!(self.types.declaration_type(*name), Type::Ref{..} | Type::Comp{..}) {
+                    self.reference_locals.insert(*name);
+                }
+                if let Some(init_expr) = init_expr {
+                    self.check_expr(*init_expr);
+                    self.give_value(*name, *init_expr);
+                }
+            },
+            Statement::If{branches, else_body} => {
+                for branch in branches {
+                    self.check_expr(branch.cond_expr);
+                    self.check_block(&branch.body);
+                }
+                if let Some(else_body) = else_body {
+                    self.check_block(else_body);
+                }
+            },
+            Statement::Match{value, cases} => {
+                self.check_expr(*value);
+                for case in cases {
+                    self.check_block(&case.body);
+                }
+            },
+            Statement::While{cond_expr, body_block} => {
+                self.check_expr(*cond_expr);
+                self.check_block(body_block);
+            },
+            Statement::ForEach{item, iter_expr, body_block} => {
+                self.check_expr(*iter_expr);
+                self.iterated_values.insert(self.ast.get_for_each_decl(*item).name, *iter_expr);
+                self.check_block(body_block);
+            },
+            Statement::Return{expr} => {
+                if let Some(expr) = expr {
+                    self.check_expr(*expr);
+                }
+            },
+            Statement::Continue |
+            Statement::Break |
+            Statement::Error => {},
+        }
+    }
+
+    /// A function is only useful for the value it returns, computed from its parameters.
+    fn check_signature(&mut self) {
+
+        let scope_def = self.ast.get_scope_def(self.function);
+        let name_info = *self.ast.get_lexical_info(scope_def.name());
+
+        if scope_def.params().is_empty() {
+            let diagnostic = Diagnostic::error("E0601", format!("a função `{}` não tem parâmetros", self.function_name()), name_info)
+                .with_label("funções precisam de ao menos um parâmetro")
+                .with_note("sem parâmetros, uma função sempre retorna o mesmo valor; use uma variável ou declare um `procedimento`");
+            self.diagnostics.push(diagnostic);
+        }
+
+        if let Type::Function{return_type, ..} = self.types.declaration_type(scope_def.name()) {
+            if **return_type == Type::Nothing {
+                let type_info = *self.ast.get_lexical_info(scope_def.return_type().expect("functions have a return type"));
+                let diagnostic = Diagnostic::error("E0602", format!("a função `{}` não retorna um valor", self.function_name()), type_info)
+                    .with_label("funções precisam retornar um valor diferente de `nada`")
+                    .with_note("subprogramas que não retornam valores devem ser declarados com `procedimento`");
+                self.diagnostics.push(diagnostic);
+            }
+        }
+    }
+}
+
+/// Checks that functions have no side effects: they receive parameters, return a value, and never
+/// change values outside of them, call procedures or use the terminal.
+pub fn check_purity(ast: &Ast, resolution: &Resolution, types: &TypeTable, diagnostics: &mut Vec<Diagnostic>) {
+
+    for function in ast.get_subprograms() {
+        if ast.get_scope_def(function).is_procedure() {
+            continue;
+        }
+
+        let mut checker = PurityChecker{
+            ast,
+            resolution,
+            types,
+            diagnostics,
+            function,
+            iterated_values: HashMap::new(),
+            reference_locals: HashSet::new(),
+            outside_locals: HashSet::new(),
+            reporting: false,
+        };
+        checker.check_signature();
+
+        // a variable may be given a value outside of the function after it is used, in a later
+        // iteration of a loop, so the function is walked until no more of them are found
+        let body = ast.get_scope_def(function).body();
+        loop {
+            let found = checker.outside_locals.len();
+            checker.check_block(body);
+            if checker.outside_locals.len() == found {
+                break;
+            }
+        }
+        checker.reporting = true;
+        checker.check_block(body);
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::builtin_types::TypeCatalogue;
+    use crate::resolver::resolve_names;
+    use crate::tso_parser::parse_source;
+    use crate::type_checker::check_types;
+
+
+    fn check(source_code: &str) -> Vec<&'static str> {
+
+        let output = parse_source(source_code).unwrap();
+        assert!(!output.has_errors());
+
+        let mut diagnostics = Vec::new();
+        let resolution = resolve_names(&output.ast, &mut diagnostics);
+        let types = check_types(&output.ast, &resolution, &TypeCatalogue::new(false), &mut diagnostics);
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics.iter().map(|diagnostic| &diagnostic.message).collect::<Vec<_>>());
+
+        check_purity(&output.ast, &resolution, &types, &mut diagnostics);
+        return diagnostics.iter().map(|diagnostic| diagnostic.code).collect();
+    }
+
+    #[test]
+    fn test_pure_functions() {
+
+        let source_code = r#"
+            func soma(valores: ref lista<inteiro>) -> inteiro {
+                var mut total: inteiro = 0;
+                para cada valor em valores { total = total + valor; }
+                var mut cópia: lista<inteiro> = [1, 2];
+                para cada mut ref item em cópia { *item = *item + 1; }
+                var mut r: ref inteiro = total;
+                r = total;
+                retornar total + tamanho(valores);
+            }
+            procedimento mostrar(x: mut ref inteiro) {
+                *x = soma([1]);
+                escrever(x);
+                adicionar([1], 2);
+            }
+        "#;
+        assert_eq!(check(source_code), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_function_signatures() {
+
+        assert_eq!(check("func um() -> inteiro { retornar 1; }"), vec!["E0601"]);
+        assert_eq!(check("func nenhum(a: inteiro) -> nada { retornar; }"), vec!["E0602"]);
+    }
+
+    #[test]
+    fn test_side_effects() {
+
+        let source_code = r#"
+            func foo(a: mut ref inteiro, b: mut ref lista<inteiro>) -> inteiro {
+                *a = 1;
+                para cada mut ref item em b { *item = 0; }
+                bar();
+                adicionar(b, 1);
+                escrever(*a);
+                retornar *a;
+            }
+            procedimento bar() {}
+        "#;
+        assert_eq!(check(source_code), vec!["E0603", "E0603", "E0604", "E0603", "E0605"]);
+    }
+
+    #[test]
+    fn test_local_storage() {
+
+        let source_code = r#"
+            func caixa(a: inteiro) -> inteiro {
+                var c: mut comp inteiro = a;
+                *c = 2;
+                retornar *c;
+            }
+            func lista_local(n: inteiro) -> lista<inteiro> {
+                var mut l: lista<inteiro> = [];
+                adicionar(l, n);
+                var r: mut ref lista<inteiro> = l;
+                adicionar(r, n);
+                retornar l;
+            }
+            func depois(a: mut ref inteiro) -> inteiro {
+                var mut x: inteiro = 0;
+                var mut r: mut ref inteiro = x;
+                enquanto x < 2 {
+                    *r = 1;
+                    r = a;
+                }
+                retornar x;
+            }
+        "#;
+        assert_eq!(check(source_code), vec!["E0603"]);
+    }
+}
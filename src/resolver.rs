@@ -0,0 +1,593 @@
+
+// std
+use std::collections::HashMap;
+
+// local
+use crate::ast::*;
+use crate::builtins::{Builtin, BUILTINS};
+use crate::diagnostic::{closest_name, Diagnostic};
+
+// external
+use slotmap::SlotMap;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                 resolution                                 */
+/* -------------------------------------------------------------------------- */
+
+slotmap::new_key_type! {
+    pub struct ScopeID;
+}
+
+/// What a declared name refers to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symbol {
+    Function(ScopeDefID),
+    Struct(ScopeDefID),
+    Enum(ScopeDefID),
+    /// A variant of an enumeration, by its position in the definition.
+    Variant(ScopeDefID, usize),
+    Param(FunctionParamID),
+    Var(StmtID),
+    ForEachItem(ForEachDeclID),
+    /// A value of a variant, named in a case of the `escolha`.
+    CaseBinding(StmtID),
+    Builtin(Builtin),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScopeKind {
+    Global,
+    Function(ScopeDefID),
+    Block,
+}
+
+pub struct Scope {
+    pub kind: ScopeKind,
+    pub parent: Option<ScopeID>,
+    /// Nesting level of the scope, the global scope has depth 0.
+    pub depth: usize,
+    /// Names declared directly in this scope, mapped to the identifier of their declaration.
+    pub symbols: HashMap<String, IdentifierID>,
+}
+
+/// Result of the name resolution. Declarations and uses are both identified by the
+/// `IdentifierID` of the name as it appears in the source.
+pub struct Resolution {
+    pub scopes: SlotMap<ScopeID, Scope>,
+    pub global_scope: ScopeID,
+    /// Every identifier used in an expression, mapped to the identifier of its declaration.
+    pub uses: HashMap<IdentifierID, IdentifierID>,
+    /// Every declared identifier, mapped to what it declares.
+    pub declarations: HashMap<IdentifierID, Symbol>,
+    /// Every declared identifier, mapped to the scope it was declared in.
+    pub declaration_scopes: HashMap<IdentifierID, ScopeID>,
+    /// Identifiers that refer to a builtin subprogram, which has no declaration.
+    pub builtin_uses: HashMap<IdentifierID, Builtin>,
+}
+
+impl Resolution {
+
+    /// The symbol an identifier refers to, either as a use or as a declaration.
+    pub fn symbol(&self, id: IdentifierID) -> Option<Symbol> {
+
+        if let Some(builtin) = self.builtin_uses.get(&id) {
+            return Some(Symbol::Builtin(*builtin));
+        }
+
+        let declaration = self.uses.get(&id).copied().unwrap_or(id);
+        return self.declarations.get(&declaration).copied();
+    }
+
+    /// The symbol a name refers to in the global scope, where subprograms and types are.
+    pub fn global_symbol(&self, name: &str) -> Option<Symbol> {
+
+        let declaration = self.scopes[self.global_scope].symbols.get(name)?;
+        return self.declarations.get(declaration).copied();
+    }
+
+    pub fn declaration_of(&self, use_id: IdentifierID) -> Option<IdentifierID> {
+        return self.uses.get(&use_id).copied();
+    }
+
+    pub fn scope_depth(&self, declaration: IdentifierID) -> Option<usize> {
+
+        let scope = self.declaration_scopes.get(&declaration)?;
+        return Some(self.scopes[*scope].depth);
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  resolver                                  */
+/* -------------------------------------------------------------------------- */
+
+struct Resolver<'a> {
+    ast: &'a Ast,
+    resolution: Resolution,
+    diagnostics: &'a mut Vec<Diagnostic>,
+    current_scope: ScopeID,
+}
+
+impl<'a> Resolver<'a> {
+
+    fn enter_scope(&mut self, kind: ScopeKind) -> ScopeID {
+
+        let depth = self.resolution.scopes[self.current_scope].depth + 1;
+        let scope = Scope{kind, parent: Some(self.current_scope), depth, symbols: HashMap::new()};
+        let scope_id = self.resolution.scopes.insert(scope);
+        self.current_scope = scope_id;
+        return scope_id;
+    }
+
+    fn exit_scope(&mut self) {
+
+        self.current_scope = self.resolution.scopes[self.current_scope].parent.expect("cannot exit the global scope");
+    }
+
+    fn declare(&mut self, name: IdentifierID, symbol: Symbol) {
+
+        let name_str = self.ast.get_identifier(name);
+        let scope = &mut self.resolution.scopes[self.current_scope];
+
+        if let Some(previous) = scope.symbols.get(name_str) {
+
+            let lexical_info = *self.ast.get_lexical_info(name);
+            let previous_lexical_info = *self.ast.get_lexical_info(*previous);
+            self.diagnostics.push(
+                Diagnostic::error("E0102", format!("o nome `{}` já foi definido", name_str), lexical_info)
+                    .with_label("segunda definição")
+                    .with_secondary(previous_lexical_info, "primeira definição")
+                    .with_note("cada nome só pode ser definido uma vez no mesmo bloco, escolha outro nome")
+            );
+        }
+        else {
+
+            scope.symbols.insert(name_str.clone(), name);
+        }
+
+        self.resolution.declarations.insert(name, symbol);
+        self.resolution.declaration_scopes.insert(name, self.current_scope);
+    }
+
+    fn lookup(&self, name: &str) -> Option<IdentifierID> {
+
+        let mut scope_id = Some(self.current_scope);
+
+        while let Some(id) = scope_id {
+
+            let scope = &self.resolution.scopes[id];
+            if let Some(declaration) = scope.symbols.get(name) {
+                return Some(*declaration);
+            }
+
+            scope_id = scope.parent;
+        }
+
+        return None;
+    }
+
+    fn visible_names(&self) -> Vec<&str> {
+
+        let mut names = Vec::new();
+        let mut scope_id = Some(self.current_scope);
+
+        while let Some(id) = scope_id {
+
+            let scope = &self.resolution.scopes[id];
+            names.extend(scope.symbols.keys().map(|name| name.as_str()));
+            scope_id = scope.parent;
+        }
+
+        return names;
+    }
+
+    fn resolve_use(&mut self, name: IdentifierID) {
+
+        let name_str = self.ast.get_identifier(name);
+
+        if let Some(declaration) = self.lookup(name_str) {
+
+            self.resolution.uses.insert(name, declaration);
+            return;
+        }
+
+        // names defined by the program hide the builtins
+        if let Some(builtin) = Builtin::from_name(name_str) {
+
+            self.resolution.builtin_uses.insert(name, builtin);
+            return;
+        }
+
+        let lexical_info = *self.ast.get_lexical_info(name);
+        let mut diagnostic = Diagnostic::error("E0101", format!("o nome `{}` não foi definido", name_str), lexical_info)
+            .with_label("nome desconhecido");
+
+        let builtin_names = BUILTINS.iter().map(|info| info.name);
+        if let Some(suggestion) = closest_name(name_str, self.visible_names().into_iter().chain(builtin_names)) {
+            diagnostic = diagnostic.with_suggestion(format!("você quis dizer `{}`?", suggestion), lexical_info, suggestion);
+        }
+
+        self.diagnostics.push(diagnostic);
+    }
+
+    fn resolve_expr(&mut self, id: ExprID) {
+
+        match self.ast.get_expression(id) {
+            Expression::IntegerLiteral{..} |
+            Expression::DecimalLiteral{..} |
+            Expression::StringLiteral{..} |
+            Expression::BooleanLiteral{..} |
+            Expression::Error => {},
+            Expression::ListLiteral{values} => {
+                for value in values {
+                    self.resolve_expr(*value);
+                }
+            },
+            Expression::InterpolatedString{parts} => {
+                for value in parts.iter().filter_map(StringPart::value) {
+                    self.resolve_expr(value);
+                }
+            },
+            Expression::Identifier{node_id} => {
+                self.resolve_use(*node_id);
+            },
+            Expression::Call{callee, args} => {
+                self.resolve_expr(*callee);
+                for arg in args {
+                    self.resolve_expr(*arg);
+                }
+            },
+            Expression::StructLiteral{type_name, fields} => {
+                // like in an access, the type checker finds the fields in the definition of the structure
+                self.resolve_use(*type_name);
+                for field in fields {
+                    self.resolve_expr(field.value);
+                }
+            },
+            Expression::Access{object, ..} => {
+                // field names depend on the type of the object, so they are not resolved here
+                self.resolve_expr(*object);
+            },
+            Expression::BinaryOprt{left, right, ..} => {
+                self.resolve_expr(*left);
+                self.resolve_expr(*right);
+            },
+            Expression::UnaryOprt{operand, ..} => {
+                self.resolve_expr(*operand);
+            },
+            Expression::Assign{target, value} => {
+                self.resolve_expr(*target);
+                self.resolve_expr(*value);
+            },
+        }
+    }
+
+    /// Resolves a block in a new scope. Since ILA is "two pass", every variable declared in the
+    /// block is visible from its start, so the declarations are collected before any statement
+    /// is resolved. `declare_extra` declares names that belong to the block but are not
+    /// statements of it, like the item of a `para cada`.
+    fn resolve_block(&mut self, block: &[StmtID], declare_extra: impl FnOnce(&mut Self)) {
+
+        self.enter_scope(ScopeKind::Block);
+        declare_extra(self);
+
+        for stmt in block {
+
+            if let Statement::VarDecl{name, ..} = self.ast.get_statement(*stmt) {
+                self.declare(*name, Symbol::Var(*stmt));
+            }
+        }
+
+        for stmt in block {
+            self.resolve_stmt(*stmt);
+        }
+
+        self.exit_scope();
+    }
+
+    fn resolve_stmt(&mut self, id: StmtID) {
+
+        match self.ast.get_statement(id) {
+            Statement::Expression{expr} => {
+                self.resolve_expr(*expr);
+            },
+            Statement::VarDecl{init_expr, ..} => {
+                if let Some(init_expr) = init_expr {
+                    self.resolve_expr(*init_expr);
+                }
+            },
+            Statement::If{branches, else_body} => {
+                for branch in branches {
+                    self.resolve_expr(branch.cond_expr);
+                    self.resolve_block(&branch.body, |_| {});
+                }
+                if let Some(else_body) = else_body {
+                    self.resolve_block(else_body, |_| {});
+                }
+            },
+            Statement::While{cond_expr, body_block} => {
+                self.resolve_expr(*cond_expr);
+                self.resolve_block(body_block, |_| {});
+            },
+            Statement::ForEach{item, iter_expr, body_block} => {
+                self.resolve_expr(*iter_expr);
+                let item = *item;
+                self.resolve_block(body_block, |resolver| {
+                    let name = resolver.ast.get_for_each_decl(item).name;
+                    resolver.declare(name, Symbol::ForEachItem(item));
+                });
+            },
+            Statement::Match{value, cases} => {
+                self.resolve_expr(*value);
+                for case in cases {
+                    // the variants are global names, checked against the value by the type checker
+                    self.resolve_use(case.variant);
+                    self.resolve_block(&case.body, |resolver| {
+                        for binding in &case.bindings {
+                            resolver.declare(*binding, Symbol::CaseBinding(id));
+                        }
+                    });
+                }
+            },
+            Statement::Return{expr} => {
+                if let Some(expr) = expr {
+                    self.resolve_expr(*expr);
+                }
+            },
+            Statement::Continue |
+            Statement::Break |
+            Statement::Error => {},
+        }
+    }
+
+    fn resolve_scope_def(&mut self, id: ScopeDefID) {
+
+        let scope_def = self.ast.get_scope_def(id);
+        self.enter_scope(ScopeKind::Function(id));
+
+        for param in scope_def.params() {
+            let name = self.ast.get_function_param(*param).name;
+            self.declare(name, Symbol::Param(*param));
+        }
+
+        self.resolve_block(scope_def.body(), |_| {});
+        self.exit_scope();
+    }
+
+    fn resolve_program(&mut self) {
+
+        let global_defs = &self.ast.get_program().global_defs;
+
+        for scope_def in global_defs {
+
+            let definition = self.ast.get_scope_def(*scope_def);
+            let symbol = match definition {
+                ScopeDef::Struct{..} => Symbol::Struct(*scope_def),
+                ScopeDef::Enum{..} => Symbol::Enum(*scope_def),
+                ScopeDef::Function{..} | ScopeDef::Procedure{..} => Symbol::Function(*scope_def),
+            };
+            self.declare(definition.name(), symbol);
+
+            // variants are used without the name of the enumeration, like `Vazio`
+            for (index, variant) in definition.variants().iter().enumerate() {
+                self.declare(variant.name, Symbol::Variant(*scope_def, index));
+            }
+        }
+
+        // the types of the fields are names of types, which the type checker resolves
+        for scope_def in self.ast.get_subprograms() {
+            self.resolve_scope_def(scope_def);
+        }
+    }
+}
+
+/// Builds the scope tree of the program and maps every identifier used in an expression to
+/// its declaration, reporting undefined and duplicated names.
+pub fn resolve_names(ast: &Ast, diagnostics: &mut Vec<Diagnostic>) -> Resolution {
+
+    let mut scopes = SlotMap::with_key();
+    let global_scope = scopes.insert(Scope{kind: ScopeKind::Global, parent: None, depth: 0, symbols: HashMap::new()});
+
+    let resolution = Resolution{
+        scopes,
+        global_scope,
+        uses: HashMap::new(),
+        declarations: HashMap::new(),
+        declaration_scopes: HashMap::new(),
+        builtin_uses: HashMap::new(),
+    };
+
+    let mut resolver = Resolver{ast, resolution, diagnostics, current_scope: global_scope};
+    resolver.resolve_program();
+    return resolver.resolution;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::tso_parser::parse_source;
+
+
+    fn resolve(source_code: &str) -> (Ast, Resolution, Vec<Diagnostic>) {
+
+        let output = parse_source(source_code).unwrap();
+        assert!(!output.has_errors());
+
+        let mut diagnostics = Vec::new();
+        let resolution = resolve_names(&output.ast, &mut diagnostics);
+        return (output.ast, resolution, diagnostics);
+    }
+
+    /// Symbols of every use of `name` in the program, in no particular order.
+    fn symbols_used_as(ast: &Ast, resolution: &Resolution, name: &str) -> Vec<Symbol> {
+
+        return resolution.uses.keys()
+            .filter(|use_id| ast.get_identifier(**use_id) == name)
+            .map(|use_id| resolution.symbol(*use_id).unwrap())
+            .collect();
+    }
+
+    fn codes(diagnostics: &[Diagnostic]) -> Vec<&str> {
+        return diagnostics.iter().map(|diagnostic| diagnostic.code).collect();
+    }
+
+    #[test]
+    fn test_resolve_functions_and_params() {
+
+        let source_code = r#"
+            func foo(a: int) -> int { retornar bar(a); }
+            func bar(b: int) -> int { retornar b; }
+        "#;
+
+        let (ast, resolution, diagnostics) = resolve(source_code);
+        assert!(diagnostics.is_empty());
+        assert!(matches!(symbols_used_as(&ast, &resolution, "bar")[..], [Symbol::Function(_)]));
+        assert!(matches!(symbols_used_as(&ast, &resolution, "a")[..], [Symbol::Param(_)]));
+        assert!(matches!(symbols_used_as(&ast, &resolution, "b")[..], [Symbol::Param(_)]));
+    }
+
+    #[test]
+    fn test_variable_visible_before_declaration() {
+
+        let source_code = r#"
+            func foo(a: int) -> int {
+                b = a;
+                var b: int;
+                retornar b;
+            }
+        "#;
+
+        let (ast, resolution, diagnostics) = resolve(source_code);
+        assert!(diagnostics.is_empty());
+        assert!(matches!(symbols_used_as(&ast, &resolution, "b")[..], [Symbol::Var(_), Symbol::Var(_)]));
+    }
+
+    #[test]
+    fn test_block_scopes() {
+
+        let source_code = r#"
+            func foo(a: int) -> int {
+                se a > 0 {
+                    var b: int = 1;
+                }
+                retornar b;
+            }
+        "#;
+
+        let (_, _, diagnostics) = resolve(source_code);
+        assert_eq!(codes(&diagnostics), vec!["E0101"]);
+    }
+
+    #[test]
+    fn test_for_each_item() {
+
+        let source_code = r#"
+            func foo(a: lista<int>) -> int {
+                para cada item em a {
+                    retornar item;
+                }
+                retornar item;
+            }
+        "#;
+
+        let (ast, resolution, diagnostics) = resolve(source_code);
+        assert_eq!(codes(&diagnostics), vec!["E0101"]);
+        assert!(matches!(symbols_used_as(&ast, &resolution, "item")[..], [Symbol::ForEachItem(_)]));
+    }
+
+    #[test]
+    fn test_undefined_name_suggestion() {
+
+        let source_code = r#"
+            func foo(contador: int) -> int { retornar contadro; }
+        "#;
+
+        let (_, _, diagnostics) = resolve(source_code);
+        assert_eq!(codes(&diagnostics), vec!["E0101"]);
+        assert_eq!(diagnostics[0].suggestions[0].replacement, "contador");
+    }
+
+    #[test]
+    fn test_duplicate_names() {
+
+        let source_code = r#"
+            func foo(a: int, a: int) -> int {
+                var b: int;
+                var b: int;
+                se verdadeiro {
+                    var b: int;
+                }
+            }
+            func foo(c: int) -> int {}
+        "#;
+
+        let (_, _, diagnostics) = resolve(source_code);
+        assert_eq!(codes(&diagnostics), vec!["E0102", "E0102", "E0102"]);
+    }
+
+    #[test]
+    fn test_structs() {
+
+        let source_code = r#"
+            estrutura Ponto { x: int, y: int }
+            func foo(a: int) -> Ponto { retornar Ponto { x: a, y: b }; }
+            func Ponto() -> int { retornar 0; }
+        "#;
+
+        let (ast, resolution, diagnostics) = resolve(source_code);
+        assert_eq!(codes(&diagnostics), vec!["E0102", "E0101"]);
+        assert!(matches!(symbols_used_as(&ast, &resolution, "Ponto")[..], [Symbol::Struct(_)]));
+        assert!(matches!(resolution.global_symbol("Ponto"), Some(Symbol::Struct(_))));
+        assert!(matches!(resolution.global_symbol("foo"), Some(Symbol::Function(_))));
+    }
+
+    #[test]
+    fn test_enums() {
+
+        let source_code = r#"
+            enumeração Forma { Círculo(decimal), Vazio, Vazio }
+            func foo(f: Forma) -> decimal {
+                escolha f {
+                    caso Círculo(r) => { retornar r; }
+                    caso Cheio => { retornar r; }
+                }
+            }
+        "#;
+
+        let (ast, resolution, diagnostics) = resolve(source_code);
+        assert_eq!(codes(&diagnostics), vec!["E0102", "E0101", "E0101"]);
+        assert!(matches!(symbols_used_as(&ast, &resolution, "Círculo")[..], [Symbol::Variant(_, 0)]));
+        assert!(matches!(symbols_used_as(&ast, &resolution, "r")[..], [Symbol::CaseBinding(_)]));
+        assert!(matches!(resolution.global_symbol("Forma"), Some(Symbol::Enum(_))));
+        assert!(matches!(resolution.global_symbol("Vazio"), Some(Symbol::Variant(_, 1))));
+    }
+
+    #[test]
+    fn test_scope_depth() {
+
+        let source_code = r#"
+            func foo(a: int) -> int {
+                var b: int;
+                enquanto verdadeiro { var c: int; }
+            }
+        "#;
+
+        let (ast, resolution, _) = resolve(source_code);
+        let depth_of = |name: &str| {
+            let declaration = resolution.declarations.keys().find(|id| ast.get_identifier(**id) == name).unwrap();
+            return resolution.scope_depth(*declaration).unwrap();
+        };
+
+        assert_eq!(depth_of("foo"), 0);
+        assert_eq!(depth_of("a"), 1);
+        assert_eq!(depth_of("b"), 2);
+        assert_eq!(depth_of("c"), 3);
+    }
+}
@@ -0,0 +1,406 @@
+// std
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::rc::Rc;
+
+// local
+use crate::ast::{Ast, BinaryOprt, LexicalInfo, ScopeDefID, UnaryOprt};
+use crate::builtins::Builtin;
+use crate::diagnostic::{count, Diagnostic};
+use crate::numbers::{Decimal, Integer};
+use crate::type_checker::TypeTable;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   values                                   */
+/* -------------------------------------------------------------------------- */
+
+/// Storage of a single value: a variable, a list item or the target of a `comp`.
+pub type Cell = Rc<RefCell<Value>>;
+
+pub fn new_cell(value: Value) -> Cell {
+    return Rc::new(RefCell::new(value));
+}
+
+#[derive(Debug)]
+pub enum Value {
+    Integer(Integer),
+    Decimal(Decimal),
+    Text(String),
+    Boolean(bool),
+    Nothing,
+    List(Vec<Cell>),
+    /// The fields are in the order of the definition of the structure.
+    Struct{layout: Rc<StructLayout>, fields: Vec<Cell>},
+    /// `variant` is the position of the variant in the definition of the enumeration.
+    Enum{layout: Rc<EnumLayout>, variant: usize, payload: Vec<Cell>},
+    /// A `ref` or a `comp`. The value pointed to by a `comp` lives while some `comp` points to it.
+    Ref(Cell),
+    Function(ScopeDefID),
+    Builtin(Builtin),
+}
+
+/// What a structure value needs to be written: its name and the names of its fields.
+#[derive(Debug, PartialEq, Eq)]
+pub struct StructLayout {
+    pub name: String,
+    pub fields: Vec<String>,
+}
+
+/// Layout of every structure of the program.
+pub fn struct_layouts(ast: &Ast, types: &TypeTable) -> HashMap<ScopeDefID, Rc<StructLayout>> {
+
+    return ast.get_structs().into_iter()
+        .map(|id| {
+            let name = ast.get_identifier(ast.get_scope_def(id).name()).clone();
+            let fields = types.fields(id).iter().map(|field| field.name.clone()).collect();
+            (id, Rc::new(StructLayout{name, fields}))
+        })
+        .collect();
+}
+
+/// What a value of an enumeration needs to be written: the names of its variants.
+#[derive(Debug, PartialEq, Eq)]
+pub struct EnumLayout {
+    pub name: String,
+    pub variants: Vec<String>,
+}
+
+/// Layout of every enumeration of the program.
+pub fn enum_layouts(ast: &Ast, types: &TypeTable) -> HashMap<ScopeDefID, Rc<EnumLayout>> {
+
+    return ast.get_enums().into_iter()
+        .map(|id| {
+            let name = ast.get_identifier(ast.get_scope_def(id).name()).clone();
+            let variants = types.variants(id).iter().map(|variant| variant.name.clone()).collect();
+            (id, Rc::new(EnumLayout{name, variants}))
+        })
+        .collect();
+}
+
+/// Cloning a value copies it, like assigning it to another variable: list items and fields are
+/// copied, while references keep pointing to the same value.
+impl Clone for Value {
+
+    fn clone(&self) -> Self {
+
+        return match self {
+            Value::Integer(value) => Value::Integer(value.clone()),
+            Value::Decimal(value) => Value::Decimal(value.clone()),
+            Value::Text(value) => Value::Text(value.clone()),
+            Value::Boolean(value) => Value::Boolean(*value),
+            Value::Nothing => Value::Nothing,
+            Value::List(items) => Value::List(items.iter().map(|item| new_cell(item.borrow().clone())).collect()),
+            Value::Struct{layout, fields} => Value::Struct{
+                layout: layout.clone(),
+                fields: fields.iter().map(|field| new_cell(field.borrow().clone())).collect(),
+            },
+            Value::Enum{layout, variant, payload} => Value::Enum{
+                layout: layout.clone(),
+                variant: *variant,
+                payload: payload.iter().map(|value| new_cell(value.borrow().clone())).collect(),
+            },
+            Value::Ref(target) => Value::Ref(target.clone()),
+            Value::Function(id) => Value::Function(*id),
+            Value::Builtin(builtin) => Value::Builtin(*builtin),
+        };
+    }
+}
+
+impl Value {
+
+    /// Copy of the value, or of the value pointed to, when it is a reference.
+    pub fn dereferenced(&self) -> Value {
+
+        return match self {
+            Value::Ref(target) => target.borrow().dereferenced(),
+            other => other.clone(),
+        };
+    }
+
+    pub fn equals(&self, other: &Value) -> bool {
+
+        return match (self.dereferenced(), other.dereferenced()) {
+            (Value::Integer(a), Value::Integer(b)) => a == b,
+            (Value::Integer(a), Value::Decimal(b)) => Decimal::from_integer(&a) == b,
+            (Value::Decimal(a), Value::Integer(b)) => a == Decimal::from_integer(&b),
+            (Value::Decimal(a), Value::Decimal(b)) => a == b,
+            (Value::Text(a), Value::Text(b)) => a == b,
+            (Value::Boolean(a), Value::Boolean(b)) => a == b,
+            (Value::Nothing, Value::Nothing) => true,
+            (Value::List(a), Value::List(b)) => {
+                a.len() == b.len() && a.iter().zip(&b).all(|(a, b)| a.borrow().equals(&b.borrow()))
+            },
+            (Value::Struct{layout: a_layout, fields: a}, Value::Struct{layout: b_layout, fields: b}) => {
+                a_layout == b_layout && a.iter().zip(&b).all(|(a, b)| a.borrow().equals(&b.borrow()))
+            },
+            (Value::Enum{layout: a_layout, variant: a_variant, payload: a}, Value::Enum{layout: b_layout, variant: b_variant, payload: b}) => {
+                a_layout == b_layout && a_variant == b_variant && a.iter().zip(&b).all(|(a, b)| a.borrow().equals(&b.borrow()))
+            },
+            (Value::Function(a), Value::Function(b)) => a == b,
+            (Value::Builtin(a), Value::Builtin(b)) => a == b,
+            _ => false,
+        };
+    }
+
+    pub fn as_integer(&self) -> Integer {
+
+        return match self.dereferenced() {
+            Value::Integer(value) => value,
+            other => unreachable!("the type checker only allows integers here, found {:?}", other),
+        };
+    }
+
+    pub fn as_decimal(&self) -> Decimal {
+
+        return match self.dereferenced() {
+            Value::Integer(value) => Decimal::from_integer(&value),
+            Value::Decimal(value) => value,
+            other => unreachable!("the type checker only allows numbers here, found {:?}", other),
+        };
+    }
+
+    pub fn as_boolean(&self) -> bool {
+
+        return match self.dereferenced() {
+            Value::Boolean(value) => value,
+            other => unreachable!("the type checker only allows `lógico` here, found {:?}", other),
+        };
+    }
+
+    /// How the value is written inside a list, where texts are quoted.
+    pub fn item_display(&self) -> String {
+
+        return match self.dereferenced() {
+            Value::Text(value) => format!("{:?}", value),
+            other => other.to_string(),
+        };
+    }
+}
+
+impl std::fmt::Display for Value {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+        return match self {
+            Value::Integer(value) => write!(f, "{}", value),
+            Value::Decimal(value) => write!(f, "{}", value),
+            Value::Text(value) => write!(f, "{}", value),
+            Value::Boolean(true) => write!(f, "verdadeiro"),
+            Value::Boolean(false) => write!(f, "falso"),
+            Value::Nothing => write!(f, "nada"),
+            Value::List(items) => {
+                let items: Vec<String> = items.iter().map(|item| item.borrow().item_display()).collect();
+                write!(f, "[{}]", items.join(", "))
+            },
+            Value::Struct{layout, fields} => {
+                let fields: Vec<String> = layout.fields.iter().zip(fields)
+                    .map(|(name, field)| format!("{}: {}", name, field.borrow().item_display()))
+                    .collect();
+                write!(f, "{} {{ {} }}", layout.name, fields.join(", "))
+            },
+            Value::Enum{layout, variant, payload} if payload.is_empty() => write!(f, "{}", layout.variants[*variant]),
+            Value::Enum{layout, variant, payload} => {
+                let payload: Vec<String> = payload.iter().map(|value| value.borrow().item_display()).collect();
+                write!(f, "{}({})", layout.variants[*variant], payload.join(", "))
+            },
+            Value::Ref(target) => write!(f, "{}", target.borrow()),
+            Value::Function(_) | Value::Builtin(_) => write!(f, "<subprograma>"),
+        };
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                               runtime errors                               */
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, PartialEq)]
+pub enum RuntimeErrorKind {
+    DivisionByZero,
+    IndexOutOfBounds{index: Integer, length: usize},
+    /// Too many calls that have not returned yet, usually a recursion without an end.
+    CallDepthExceeded,
+    Io(String),
+}
+
+/// Most calls that may be in progress at the same time, so a recursion without an end stops
+/// with an error instead of exhausting the memory.
+pub const MAX_CALL_DEPTH: usize = 10_000;
+
+/// An error that stops the execution, at the node whose evaluation failed.
+#[derive(Debug, PartialEq)]
+pub struct RuntimeError {
+    pub kind: RuntimeErrorKind,
+    pub lexical_info: LexicalInfo,
+}
+
+impl RuntimeError {
+
+    pub fn to_diagnostic(&self) -> Diagnostic {
+
+        let diagnostic = match &self.kind {
+            RuntimeErrorKind::DivisionByZero => {
+                Diagnostic::error("E0701", "divisão por zero", self.lexical_info)
+                    .with_label("o divisor é zero")
+            },
+            RuntimeErrorKind::IndexOutOfBounds{index, length} => {
+                Diagnostic::error("E0702", format!("a posição {} não existe em uma lista com {}", index, count(*length, "item", "itens")), self.lexical_info)
+                    .with_label("posição inválida")
+                    .with_note("as posições de uma lista começam em 0 e vão até o tamanho da lista menos 1")
+            },
+            RuntimeErrorKind::CallDepthExceeded => {
+                Diagnostic::error("E0704", format!("mais de {} chamadas em andamento ao mesmo tempo", MAX_CALL_DEPTH), self.lexical_info)
+                    .with_label("chamada que passou do limite")
+                    .with_note("isso acontece quando um subprograma chama a si mesmo sem nunca parar")
+            },
+            RuntimeErrorKind::Io(message) => {
+                Diagnostic::error("E0705", format!("erro ao usar o terminal: {}", message), self.lexical_info)
+            },
+        };
+
+        return diagnostic;
+    }
+}
+
+pub type RuntimeResult<T> = Result<T, RuntimeError>;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                 operations                                 */
+/* -------------------------------------------------------------------------- */
+
+/// The storage a reference points to, after following references to references.
+pub fn follow_refs(cell: Cell) -> Cell {
+
+    let target = match &*cell.borrow() {
+        Value::Ref(target) => target.clone(),
+        _ => return cell.clone(),
+    };
+
+    return follow_refs(target);
+}
+
+/// Items a `para cada` goes through, the ones the value had when the loop started. List items are
+/// the storage of the list, so `para cada ref` can change them.
+pub fn iteration_items(iterated: Cell) -> Vec<Cell> {
+
+    return match &*follow_refs(iterated).borrow() {
+        Value::List(items) => items.clone(),
+        Value::Text(text) => text.chars().map(|c| new_cell(Value::Text(c.to_string()))).collect(),
+        other => unreachable!("the type checker only allows iterating lists and texts, found {:?}", other),
+    };
+}
+
+pub fn unary_oprt(oprt: UnaryOprt, operand: Value) -> Result<Value, RuntimeErrorKind> {
+
+    return match (oprt, operand.dereferenced()) {
+        (UnaryOprt::Neg, Value::Integer(value)) => Ok(Value::Integer(-&value)),
+        (UnaryOprt::Neg, Value::Decimal(value)) => Ok(Value::Decimal(-&value)),
+        (UnaryOprt::Not, Value::Boolean(value)) => Ok(Value::Boolean(!value)),
+        (oprt, operand) => unreachable!("the type checker does not allow `{}` with {:?}", oprt.symbol(), operand),
+    };
+}
+
+/// Applies an operator that evaluates both of its operands, every one except `e`, `ou` and `=`.
+pub fn binary_oprt(oprt: BinaryOprt, left: Value, right: Value) -> Result<Value, RuntimeErrorKind> {
+
+    let (left, right) = (left.dereferenced(), right.dereferenced());
+
+    let result = match oprt {
+        BinaryOprt::Eq => Value::Boolean(left.equals(&right)),
+        BinaryOprt::Neq => Value::Boolean(!left.equals(&right)),
+        BinaryOprt::Lt => Value::Boolean(compare_numbers(&left, &right).is_lt()),
+        BinaryOprt::Le => Value::Boolean(compare_numbers(&left, &right).is_le()),
+        BinaryOprt::Gt => Value::Boolean(compare_numbers(&left, &right).is_gt()),
+        BinaryOprt::Ge => Value::Boolean(compare_numbers(&left, &right).is_ge()),
+        BinaryOprt::Add | BinaryOprt::Sub | BinaryOprt::Mul | BinaryOprt::Div => return arithmetic(oprt, left, right),
+        BinaryOprt::And | BinaryOprt::Or | BinaryOprt::Assign => unreachable!("`{}` does not evaluate both operands first", oprt.symbol()),
+    };
+
+    return Ok(result);
+}
+
+fn compare_numbers(left: &Value, right: &Value) -> std::cmp::Ordering {
+
+    return match (left, right) {
+        (Value::Integer(left), Value::Integer(right)) => left.cmp(right),
+        _ => left.as_decimal().cmp(&right.as_decimal()),
+    };
+}
+
+fn arithmetic(oprt: BinaryOprt, left: Value, right: Value) -> Result<Value, RuntimeErrorKind> {
+
+    match (&left, &right) {
+        (Value::Text(left), Value::Text(right)) => return Ok(Value::Text(format!("{}{}", left, right))),
+        (Value::Integer(left), Value::Integer(right)) => {
+            let result = match oprt {
+                BinaryOprt::Add => left + right,
+                BinaryOprt::Sub => left - right,
+                BinaryOprt::Mul => left * right,
+                _ => left.checked_div(right).ok_or(RuntimeErrorKind::DivisionByZero)?,
+            };
+            return Ok(Value::Integer(result));
+        },
+        _ => {},
+    }
+
+    // an integer mixed with a decimal is converted to a decimal
+    let (left, right) = (left.as_decimal(), right.as_decimal());
+    let result = match oprt {
+        BinaryOprt::Add => &left + &right,
+        BinaryOprt::Sub => &left - &right,
+        BinaryOprt::Mul => &left * &right,
+        _ => left.checked_div(&right).ok_or(RuntimeErrorKind::DivisionByZero)?,
+    };
+
+    return Ok(Value::Decimal(result));
+}
+
+/// Runs a builtin with the arguments already converted to its parameter types.
+pub fn call_builtin(builtin: Builtin, args: &[Value], input: &mut dyn BufRead, output: &mut dyn Write) -> Result<Value, RuntimeErrorKind> {
+
+    let io_error = |error: std::io::Error| RuntimeErrorKind::Io(error.to_string());
+
+    let result = match builtin {
+        Builtin::Write => {
+            writeln!(output, "{}", args[0]).map_err(io_error)?;
+            Value::Nothing
+        },
+        Builtin::Read => {
+            output.flush().map_err(io_error)?;
+            let mut line = String::new();
+            input.read_line(&mut line).map_err(io_error)?;
+            let line = line.strip_suffix('\n').unwrap_or(&line);
+            Value::Text(line.strip_suffix('\r').unwrap_or(line).to_string())
+        },
+        Builtin::Length => {
+            let length = match args[0].dereferenced() {
+                Value::List(items) => items.len(),
+                Value::Text(text) => text.chars().count(),
+                other => unreachable!("`tamanho` only receives lists and texts, found {:?}", other),
+            };
+            Value::Integer(Integer::from(length))
+        },
+        Builtin::Append => {
+            let Value::Ref(list) = &args[0] else { unreachable!("`adicionar` receives a `mut ref` to the list") };
+            match &mut *follow_refs(list.clone()).borrow_mut() {
+                Value::List(items) => items.push(new_cell(args[1].clone())),
+                other => unreachable!("`adicionar` only receives lists, found {:?}", other),
+            }
+            Value::Nothing
+        },
+        Builtin::Get => {
+            let Value::List(items) = args[0].dereferenced() else { unreachable!("`obter` only receives lists") };
+            let index = args[1].as_integer();
+            match index.to_usize().and_then(|index| items.get(index)) {
+                Some(item) => item.borrow().clone(),
+                None => return Err(RuntimeErrorKind::IndexOutOfBounds{index, length: items.len()}),
+            }
+        },
+        Builtin::ToText => Value::Text(args[0].to_string()),
+    };
+
+    return Ok(result);
+}
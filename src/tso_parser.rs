@@ -1,15 +1,171 @@
 
 // local
 use crate::ast::*;
+use crate::diagnostic::Diagnostic;
+use crate::numbers::{Decimal, Integer, MAX_EXPONENT};
 
 // external
-use anyhow::Result;
+use anyhow::Context;
+use tree_sitter_ila_lang as ila_lang;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   errors                                   */
+/* -------------------------------------------------------------------------- */
+
+/// Every way the conversion of the tree-sitter output into an `Ast` can fail. None of them
+/// aborts the parsing: the invalid part of the source is replaced by a placeholder node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// An `ERROR` node, produced by tree-sitter for source it could not match to the grammar.
+    InvalidSyntax{lexical_info: LexicalInfo},
+    /// A `MISSING` node, a token tree-sitter inserted to recover from an error.
+    MissingToken{token: String, lexical_info: LexicalInfo},
+    /// A field the grammar declares as obligatory is absent from a node.
+    MissingField{node_kind: String, field_name: &'static str, lexical_info: LexicalInfo},
+    /// A node of a kind the parser does not expect in that position.
+    UnexpectedNode{expected: &'static str, found: String, lexical_info: LexicalInfo},
+    UnknownOperator{oprt: String, lexical_info: LexicalInfo},
+    InvalidBoolean{value: String, lexical_info: LexicalInfo},
+    InvalidNumber{text: String, lexical_info: LexicalInfo},
+    InvalidText{lexical_info: LexicalInfo},
+    InvalidEscape{escape: String, lexical_info: LexicalInfo},
+    /// Expressions, statements or types nested deeper than `MAX_NESTING_DEPTH`.
+    TooDeep{lexical_info: LexicalInfo},
+}
+
+impl ParseError {
+
+    pub fn lexical_info(&self) -> LexicalInfo {
+
+        return match self {
+            ParseError::InvalidSyntax{lexical_info} => *lexical_info,
+            ParseError::MissingToken{lexical_info, ..} => *lexical_info,
+            ParseError::MissingField{lexical_info, ..} => *lexical_info,
+            ParseError::UnexpectedNode{lexical_info, ..} => *lexical_info,
+            ParseError::UnknownOperator{lexical_info, ..} => *lexical_info,
+            ParseError::InvalidBoolean{lexical_info, ..} => *lexical_info,
+            ParseError::InvalidNumber{lexical_info, ..} => *lexical_info,
+            ParseError::InvalidText{lexical_info} => *lexical_info,
+            ParseError::InvalidEscape{lexical_info, ..} => *lexical_info,
+            ParseError::TooDeep{lexical_info} => *lexical_info,
+        };
+    }
+
+    /// Whether the error comes from an `ERROR` or `MISSING` node, which are reported by
+    /// `collect_syntax_errors` before the ast is built.
+    fn is_syntax_error(&self) -> bool {
+        return matches!(self, ParseError::InvalidSyntax{..} | ParseError::MissingToken{..});
+    }
+
+    pub fn to_diagnostic(&self) -> Diagnostic {
+
+        let lexical_info = self.lexical_info();
+
+        return match self {
+            ParseError::InvalidSyntax{..} => {
+                Diagnostic::error("E0001", "trecho de código inválido", lexical_info)
+                    .with_label("o compilador não conseguiu entender este trecho")
+                    .with_note("verifique se não há símbolos faltando ou sobrando, como `;`, `(`, `)`, `{` ou `}`")
+            },
+            ParseError::MissingToken{token, ..} => {
+                let description = describe_missing_token(token);
+                let diagnostic = Diagnostic::error("E0002", format!("está faltando {}", description), lexical_info)
+                    .with_label(format!("era esperado {} aqui", description));
+
+                if token.chars().any(|c| c.is_alphanumeric() || c == '_') {
+                    diagnostic
+                }
+                else {
+                    diagnostic.with_suggestion(format!("adicione {}", description), lexical_info, token.as_str())
+                }
+            },
+            ParseError::MissingField{node_kind, field_name, ..} => {
+                Diagnostic::error("E0003", "trecho de código incompleto", lexical_info)
+                    .with_label(format!("falta a parte `{}` de `{}`", field_name, node_kind))
+            },
+            ParseError::UnexpectedNode{expected, found, ..} => {
+                Diagnostic::error("E0004", "trecho de código inesperado", lexical_info)
+                    .with_label(format!("era esperado `{}`, mas foi encontrado `{}`", expected, found))
+            },
+            ParseError::UnknownOperator{oprt, ..} => {
+                Diagnostic::error("E0005", format!("o operador `{}` não existe", oprt), lexical_info)
+            },
+            ParseError::InvalidBoolean{value, ..} => {
+                Diagnostic::error("E0006", format!("`{}` não é um valor lógico", value), lexical_info)
+                    .with_note("os valores lógicos são `verdadeiro` e `falso`")
+            },
+            ParseError::InvalidNumber{text, ..} => {
+                Diagnostic::error("E0007", format!("`{}` não é um número válido", text), lexical_info)
+                    .with_note(format!("o expoente de um número vai de -{} até {}", MAX_EXPONENT, MAX_EXPONENT))
+            },
+            ParseError::InvalidText{..} => {
+                Diagnostic::error("E0008", "o texto deste trecho não é válido", lexical_info)
+                    .with_note("o arquivo deve estar codificado em UTF-8")
+            },
+            ParseError::InvalidEscape{escape, ..} => {
+                Diagnostic::error("E0009", format!("`{}` não é uma sequência de escape válida", escape), lexical_info)
+                    .with_label("sequência de escape desconhecida")
+                    .with_note("as sequências válidas são `\\n`, `\\t`, `\\\"`, `\\\\`, `\\{` e `\\u{...}`, com o código hexadecimal de um caractere")
+            },
+            ParseError::TooDeep{..} => {
+                Diagnostic::error("E0010", "o código está aninhado demais", lexical_info)
+                    .with_label(format!("este trecho passa do limite de {} níveis de aninhamento", MAX_NESTING_DEPTH))
+                    .with_note("divida o trecho em partes menores, guardando os resultados intermediários em variáveis")
+            },
+        };
+    }
+}
+
+impl std::fmt::Display for ParseError {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{}", self.to_diagnostic());
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+type Result<T> = std::result::Result<T, ParseError>;
+
+/// How a token expected by the grammar is called in error messages.
+fn describe_missing_token(kind: &str) -> String {
+
+    return match kind {
+        "identifier" => "um nome".to_string(),
+        "integer_literal" | "fractional_literal" => "um número".to_string(),
+        "string_content" => "um texto".to_string(),
+        // tree-sitter completes a missing expression with the shortest token that fits
+        "verdadeiro" | "falso" => "uma expressão".to_string(),
+        _ => format!("um `{}`", kind),
+    };
+}
+
+fn syntax_error(node: &tree_sitter::Node) -> ParseError {
+
+    let lexical_info = parse_node_lexical_info(node);
+
+    if node.is_missing() {
+
+        return ParseError::MissingToken{token: node.kind().to_string(), lexical_info};
+    }
+
+    return ParseError::InvalidSyntax{lexical_info};
+}
 
 
 /* -------------------------------------------------------------------------- */
 /*                                    utils                                   */
 /* -------------------------------------------------------------------------- */
 
+/// How many expressions, statements and types can be nested inside each other. The parser and
+/// every later pass walk the ast recursively, so deeper code could overflow the stack.
+pub const MAX_NESTING_DEPTH: usize = 256;
+
+/// How many operators of a chain like `1 + 2 + 3` count as one level of nesting, so long sums are
+/// not limited like nested parentheses.
+pub const CHAINED_OPERATORS_PER_LEVEL: usize = 64;
+
 fn parse_node_lexical_info(node: &tree_sitter::Node) -> LexicalInfo {
 
     let start_point = node.start_position();
@@ -30,29 +186,148 @@ fn check_invalid_node(node: &tree_sitter::Node) -> Result<()> {
 
     if node.is_error() || node.is_missing() {
 
-        let node_start = node.start_position();
-        let node_end = node.end_position();
-        return Err(anyhow::anyhow!("Invalid node, start: ({}, {}), end: ({}, {})",
-            node_start.row, node_start.column, node_end.row, node_end.column));
+        return Err(syntax_error(node));
     }
     
     return Ok(());
 }
 
+fn check_node_kind(node: &tree_sitter::Node, kind: &'static str) -> Result<()> {
+
+    check_invalid_node(node)?;
+
+    if node.kind() != kind {
+
+        let lexical_info = parse_node_lexical_info(node);
+        return Err(ParseError::UnexpectedNode{expected: kind, found: node.kind().to_string(), lexical_info});
+    }
+
+    return Ok(());
+}
+
+fn required_field<'a>(node: &tree_sitter::Node<'a>, field_name: &'static str) -> Result<tree_sitter::Node<'a>> {
+
+    return match node.child_by_field_name(field_name) {
+        Some(field) => Ok(field),
+        None => {
+            let lexical_info = parse_node_lexical_info(node);
+            Err(ParseError::MissingField{node_kind: node.kind().to_string(), field_name, lexical_info})
+        }
+    };
+}
+
+fn node_text<'a>(node: &tree_sitter::Node, state: &TsoParserState<'a>) -> Result<&'a str> {
+
+    check_invalid_node(node)?;
+    return node.utf8_text(state.source).map_err(|_| ParseError::InvalidText{lexical_info: parse_node_lexical_info(node)});
+}
+
+fn unexpected_node(node: &tree_sitter::Node, expected: &'static str) -> ParseError {
+
+    let lexical_info = parse_node_lexical_info(node);
+    return ParseError::UnexpectedNode{expected, found: node.kind().to_string(), lexical_info};
+}
+
+/// Moves the cursor to the next node after the current one and its descendants, returning
+/// `false` once the whole tree was visited.
+fn skip_descendants(cursor: &mut tree_sitter::TreeCursor) -> bool {
+
+    while !cursor.goto_next_sibling() {
+
+        if !cursor.goto_parent() {
+
+            return false;
+        }
+    }
+
+    return true;
+}
+
+/// Records an error for every `ERROR` and `MISSING` node of the tree. Nested invalid nodes are
+/// not reported, since they are part of the error already reported for their ancestor. The tree
+/// is walked with a cursor, since it may be too deep to recurse on.
+fn collect_syntax_errors(root: &tree_sitter::Node, errors: &mut Vec<ParseError>) {
+
+    let mut cursor = root.walk();
+    loop {
+
+        let node = cursor.node();
+        if node.is_error() || node.is_missing() {
+
+            errors.push(syntax_error(&node));
+        }
+        else if node.has_error() && cursor.goto_first_child() {
+
+            continue;
+        }
+
+        if !skip_descendants(&mut cursor) {
+
+            return;
+        }
+    }
+}
+
+/// Parses a node nested in the one being parsed, failing instead of going deeper than
+/// `MAX_NESTING_DEPTH`.
+fn parse_nested<T>(node: &tree_sitter::Node, state: &mut TsoParserState, parse: impl FnOnce(&tree_sitter::Node, &mut TsoParserState) -> Result<T>) -> Result<T> {
+
+    if state.depth >= MAX_NESTING_DEPTH {
+
+        return Err(ParseError::TooDeep{lexical_info: parse_node_lexical_info(node)});
+    }
+
+    state.depth += 1;
+    let result = parse(node, state);
+    state.depth -= 1;
+
+    // the next node at the limit is in another part of the code, so it is reported again
+    if state.depth + 1 == MAX_NESTING_DEPTH {
+
+        state.reported_too_deep = false;
+    }
+
+    return result;
+}
+
 fn for_every_child_with_field_name(node: &tree_sitter::Node, field_name: &str, mut f: impl FnMut(&tree_sitter::Node) -> Result<()>) -> Result<()> {
 
-    for child in 0..(node.child_count()) {
+    // a cursor visits the children in linear time, `child(index)` walks from the first one
+    let mut cursor = node.walk();
+    let mut children = Vec::new();
+    let mut has_child = cursor.goto_first_child();
+    while has_child {
 
-        if let Some(child_field_name) = node.field_name_for_child(child as u32) {
-        
-            if child_field_name != field_name {
-                
-                continue;
-            }
+        children.push((cursor.field_name(), cursor.node()));
+        has_child = cursor.goto_next_sibling();
+    }
 
-            let child_node = node.child(child).unwrap();
-            f(&child_node)?;
+    // an `ERROR` node in the middle of a field has no field name, but is part of that field
+    let mut next_field = None;
+    for (child_field_name, child_node) in children.iter_mut().rev() {
+
+        match child_field_name {
+            Some(name) => next_field = Some(*name),
+            None if child_node.is_error() => *child_field_name = next_field,
+            None => {},
+        }
+    }
+
+    for (child_field_name, child_node) in children {
+
+        if child_field_name != Some(field_name) {
+
+            continue;
+        }
+
+        // tree-sitter may label comments with the field of the node they
+        // follow; they are attached separately
+        if is_comment(&child_node) {
+
+            continue;
         }
+
+        f(&child_node)?;
     }
 
     return Ok(());
@@ -65,10 +340,9 @@ fn for_every_child_with_field_name(node: &tree_sitter::Node, field_name: &str, m
 
 fn parse_identifier(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<IdentifierID> {
     
-    assert_eq!(node.kind(), "identifier");
-    check_invalid_node(node)?;
+    check_node_kind(node, "identifier")?;
 
-    let name = node.utf8_text(state.source).unwrap().to_string();
+    let name = node_text(node, state)?.to_string();
     let lexical_info = parse_node_lexical_info(node);
     let id = state.ast.add_identifier_node(name, lexical_info);
     return Ok(id);
@@ -77,10 +351,9 @@ fn parse_identifier(node: &tree_sitter::Node, state: &mut TsoParserState) -> Res
 
 fn parse_raw_type(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<TypeExprID> {
 
-    assert_eq!(node.kind(), "raw_type");
-    check_invalid_node(node)?;
+    check_node_kind(node, "raw_type")?;
 
-    let name_field = node.child_by_field_name("name").expect("missing obligatory field");
+    let name_field = required_field(node, "name")?;
     let name = parse_identifier(&name_field, state)?;
 
     let type_expr = TypeExpr::RawType{type_name: name};
@@ -91,25 +364,21 @@ fn parse_raw_type(node: &tree_sitter::Node, state: &mut TsoParserState) -> Resul
 
 fn parse_template_type(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<TypeExprID> {
 
-    assert_eq!(node.kind(), "template_type");
-    check_invalid_node(node)?;
+    check_node_kind(node, "template_type")?;
 
-    let name_field = node.child_by_field_name("name").expect("missing obligatory field");
+    let name_field = required_field(node, "name")?;
     let name = parse_identifier(&name_field, state)?;
 
+    // the grammar puts the `arg` field on the whole argument list, separators included
     let mut args = Vec::new();
-    for child in 0..(node.child_count()) {
-
-        let child_node = node.child(child).unwrap();
+    for_every_child_with_field_name(node, "arg", |child_node| {
 
-        if child_node.kind() != "arg" {
-            
-            continue;
+        if child_node.is_named() {
+            args.push(parse_type_expr(child_node, state)?);
         }
 
-        let arg_id = parse_type_expr(&child_node, state)?;
-        args.push(arg_id);
-    }
+        return Ok(());
+    })?;
 
     let type_expr = TypeExpr::TemplateType{type_name: name, type_args: args};
     let lexical_info = parse_node_lexical_info(node);
@@ -119,12 +388,11 @@ fn parse_template_type(node: &tree_sitter::Node, state: &mut TsoParserState) ->
 
 fn parse_ref_type(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<TypeExprID> {
 
-    assert_eq!(node.kind(), "ref_type");
-    check_invalid_node(node)?;
+    check_node_kind(node, "ref_type")?;
 
     let is_mut = node.child_by_field_name("is_mut").is_some();
 
-    let type_field = node.child_by_field_name("type").expect("missing obligatory field");
+    let type_field = required_field(node, "type")?;
     let type_expr = parse_type_expr(&type_field, state)?;
 
     let type_expr = TypeExpr::RefType{is_mut, type_expr};
@@ -135,12 +403,11 @@ fn parse_ref_type(node: &tree_sitter::Node, state: &mut TsoParserState) -> Resul
 
 fn parse_comp_type(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<TypeExprID> {
 
-    assert_eq!(node.kind(), "comp_type");
-    check_invalid_node(node)?;
+    check_node_kind(node, "comp_type")?;
 
     let is_mut = node.child_by_field_name("is_mut").is_some();
 
-    let type_field = node.child_by_field_name("type").expect("missing obligatory field");
+    let type_field = required_field(node, "type")?;
     let type_expr = parse_type_expr(&type_field, state)?;
 
     let type_expr = TypeExpr::CompType{is_mut, type_expr};
@@ -150,6 +417,10 @@ fn parse_comp_type(node: &tree_sitter::Node, state: &mut TsoParserState) -> Resu
 }
 
 fn parse_type_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<TypeExprID> {
+    return parse_nested(node, state, parse_type_expr_node);
+}
+
+fn parse_type_expr_node(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<TypeExprID> {
 
     let node_type = node.kind();
 
@@ -167,14 +438,14 @@ fn parse_type_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Resu
             return parse_comp_type(node, state);
         },
         _ => {
-            panic!("Unknown or unexpected node type: {}", node_type);
+            return Err(unexpected_node(node, "type"));
         }
     }
 }
 
-fn parse_binary_oprt_str(oprt: &str) -> BinaryOprt {
+fn parse_binary_oprt_str(oprt: &str, lexical_info: LexicalInfo) -> Result<BinaryOprt> {
 
-    match oprt {
+    let oprt = match oprt {
         
         "=" => BinaryOprt::Assign,
         "e" => BinaryOprt::And,
@@ -189,52 +460,95 @@ fn parse_binary_oprt_str(oprt: &str) -> BinaryOprt {
         "-" => BinaryOprt::Sub,
         "*" => BinaryOprt::Mul,
         "/" => BinaryOprt::Div,
-        _ => panic!("Unexpected binary operator: {}", oprt),
-    }
+        _ => return Err(ParseError::UnknownOperator{oprt: oprt.to_string(), lexical_info}),
+    };
+
+    return Ok(oprt);
 }
 
 fn parse_binary_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
 
-    assert_eq!(node.kind(), "binary_expr");
-    check_invalid_node(node)?;
+    check_node_kind(node, "binary_expr")?;
+
+    // chains like `1 + 2 + 3` nest to the left, so they are parsed from the innermost operation
+    // without recursion, and only count a level of nesting every `CHAINED_OPERATORS_PER_LEVEL`
+    let mut chain = vec![*node];
+    loop {
+        let lhs_field = required_field(&chain[chain.len() - 1], "lhs")?;
+        if lhs_field.kind() != "binary_expr" || check_invalid_node(&lhs_field).is_err() {
+            break;
+        }
+        chain.push(lhs_field);
+    }
+
+    let levels = chain.len().div_ceil(CHAINED_OPERATORS_PER_LEVEL) - 1;
+    if state.depth + levels >= MAX_NESTING_DEPTH {
+
+        return Err(ParseError::TooDeep{lexical_info: parse_node_lexical_info(node)});
+    }
+
+    state.depth += levels;
+    let innermost = required_field(&chain[chain.len() - 1], "lhs");
+    let mut lhs = innermost.and_then(|lhs_field| parse_expr(&lhs_field, state));
+    for link in chain.iter().rev() {
+
+        lhs = match lhs.and_then(|lhs| parse_binary_link(link, lhs, state)) {
+            Ok(id) => Ok(id),
+            Err(error) if link.id() != node.id() => {
+                state.record_error(error);
+                let lexical_info = parse_node_lexical_info(link);
+                Ok(state.ast.add_expression_node(Expression::Error, lexical_info))
+            },
+            Err(error) => Err(error),
+        };
+    }
+    state.depth -= levels;
+
+    return lhs;
+}
 
-    let oprt_field = node.child_by_field_name("oprt").expect("missing obligatory field");
-    let oprt_str = oprt_field.utf8_text(state.source).unwrap();
-    let oprt = parse_binary_oprt_str(oprt_str);
+/// Parses the operator and right operand of a `binary_expr` whose left operand is `lhs`.
+fn parse_binary_link(node: &tree_sitter::Node, lhs: ExprID, state: &mut TsoParserState) -> Result<ExprID> {
 
-    let lhs_field = node.child_by_field_name("lhs").expect("missing obligatory field");
-    let lhs = parse_expr(&lhs_field, state)?;
+    let oprt_field = required_field(node, "oprt")?;
+    let oprt_str = node_text(&oprt_field, state)?;
+    let oprt = parse_binary_oprt_str(oprt_str, parse_node_lexical_info(&oprt_field))?;
 
-    let rhs_field = node.child_by_field_name("rhs").expect("missing obligatory field");
+    let rhs_field = required_field(node, "rhs")?;
     let rhs = parse_expr(&rhs_field, state)?;
 
-    let expr = Expression::BinaryOprt{oprt, left: lhs, right: rhs};
+    // the grammar parses assignments as binary expressions, but the ast has a node for them
+    let expr = match oprt {
+        BinaryOprt::Assign => Expression::Assign{target: lhs, value: rhs},
+        _ => Expression::BinaryOprt{oprt, left: lhs, right: rhs},
+    };
     let lexical_info = parse_node_lexical_info(node);
     let id = state.ast.add_expression_node(expr, lexical_info);
     return Ok(id);
 }
 
-fn parse_unary_oprt_str(oprt: &str) -> UnaryOprt {
+fn parse_unary_oprt_str(oprt: &str, lexical_info: LexicalInfo) -> Result<UnaryOprt> {
 
-    match oprt {
+    let oprt = match oprt {
         
         "*" => UnaryOprt::Deref,
         "não" => UnaryOprt::Not,
         "-" => UnaryOprt::Neg,    
-        _ => panic!("Unexpected unary operator: {}", oprt),
-    }
+        _ => return Err(ParseError::UnknownOperator{oprt: oprt.to_string(), lexical_info}),
+    };
+
+    return Ok(oprt);
 }
 
 fn parse_unary_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
 
-    assert_eq!(node.kind(), "unary_expr");
-    check_invalid_node(node)?;
+    check_node_kind(node, "unary_expr")?;
 
-    let oprt_field = node.child_by_field_name("oprt").expect("missing obligatory field");
-    let oprt_str = oprt_field.utf8_text(state.source).unwrap();
-    let oprt = parse_unary_oprt_str(oprt_str);
+    let oprt_field = required_field(node, "oprt")?;
+    let oprt_str = node_text(&oprt_field, state)?;
+    let oprt = parse_unary_oprt_str(oprt_str, parse_node_lexical_info(&oprt_field))?;
 
-    let value_field = node.child_by_field_name("value").expect("missing obligatory field");
+    let value_field = required_field(node, "value")?;
     let value = parse_expr(&value_field, state)?;
 
     let expr = Expression::UnaryOprt{oprt, operand: value};
@@ -245,16 +559,49 @@ fn parse_unary_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Res
 
 fn parse_access_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
 
-    assert_eq!(node.kind(), "access_expr");
-    check_invalid_node(node)?;
+    check_node_kind(node, "access_expr")?;
 
-    let object_field = node.child_by_field_name("object").expect("missing obligatory field");
+    let object_field = required_field(node, "object")?;
     let object = parse_expr(&object_field, state)?;
 
-    let item_field = node.child_by_field_name("item").expect("missing obligatory field");
+    let item_field = required_field(node, "item")?;
     let item = parse_identifier(&item_field, state)?;
 
-    let expr = Expression::Access{object: object, field_name: item};
+    let expr = Expression::Access{object, field_name: item};
+    let lexical_info = parse_node_lexical_info(node);
+    let id = state.ast.add_expression_node(expr, lexical_info);
+    return Ok(id);
+}
+
+fn parse_field_init(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<FieldInit> {
+
+    check_node_kind(node, "field_init")?;
+
+    let name_field = required_field(node, "name")?;
+    let name = parse_identifier(&name_field, state)?;
+
+    let value_field = required_field(node, "value")?;
+    let value = parse_expr(&value_field, state)?;
+
+    return Ok(FieldInit{name, value});
+}
+
+fn parse_struct_literal(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
+
+    check_node_kind(node, "struct_literal")?;
+
+    let name_field = required_field(node, "name")?;
+    let type_name = parse_identifier(&name_field, state)?;
+
+    let mut fields = Vec::new();
+    for_every_child_with_field_name(node, "field", |child_node| {
+
+        let field = parse_field_init(child_node, state)?;
+        fields.push(field);
+        return Ok(());
+    })?;
+
+    let expr = Expression::StructLiteral{type_name, fields};
     let lexical_info = parse_node_lexical_info(node);
     let id = state.ast.add_expression_node(expr, lexical_info);
     return Ok(id);
@@ -262,16 +609,15 @@ fn parse_access_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Re
 
 fn parse_call_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
 
-    assert_eq!(node.kind(), "call_expr");
-    check_invalid_node(node)?;
+    check_node_kind(node, "call_expr")?;
 
-    let function_field = node.child_by_field_name("function").expect("missing obligatory field");
+    let function_field = required_field(node, "function")?;
     let function = parse_expr(&function_field, state)?;
 
     let mut args = Vec::new();
     for_every_child_with_field_name(node, "arg", |child_node| {
         
-        let arg_id = parse_expr(&child_node, state)?;
+        let arg_id = parse_expr(child_node, state)?;
         args.push(arg_id);
         return Ok(());
     })?;
@@ -284,10 +630,9 @@ fn parse_call_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Resu
 
 fn parse_parem_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
 
-    assert_eq!(node.kind(), "parem_expr");
-    check_invalid_node(node)?;
+    check_node_kind(node, "parem_expr")?;
 
-    let expr_field = node.child_by_field_name("expression").expect("missing obligatory field");
+    let expr_field = required_field(node, "expression")?;
     let expr = parse_expr(&expr_field, state)?;
 
     return Ok(expr);
@@ -295,10 +640,9 @@ fn parse_parem_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Res
 
 fn parse_identifier_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
 
-    assert_eq!(node.kind(), "identifier");
-    check_invalid_node(node)?;
+    check_node_kind(node, "identifier")?;
 
-    let expr = parse_identifier(&node, state)?;
+    let expr = parse_identifier(node, state)?;
     let lexical_info = parse_node_lexical_info(node);
     let id = state.ast.add_expression_node(Expression::Identifier{node_id: expr}, lexical_info);
     return Ok(id);
@@ -306,20 +650,23 @@ fn parse_identifier_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -
 
 fn parse_decimal_literal(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
 
-    assert_eq!(node.kind(), "decimal");
-    check_invalid_node(node)?;
-
-    let integer_field = node.child_by_field_name("integer").expect("missing obligatory field");
-    let integer = integer_field.utf8_text(state.source).unwrap().parse::<i64>().unwrap();
+    check_node_kind(node, "decimal")?;
 
-    let fraction_field = node.child_by_field_name("fraction").expect("missing obligatory field");
-    let mut fraction = fraction_field.utf8_text(state.source).unwrap().parse::<i64>().unwrap();
+    // the digits are read from the text, so none of them is lost, not even leading zeros
+    let integer_field = required_field(node, "integer")?;
+    let mut text = node_text(&integer_field, state)?.to_string();
 
-    while fraction >= 1 {
-        fraction /= 10;
+    if let Some(fraction_field) = node.child_by_field_name("fraction") {
+        text.push('.');
+        text.push_str(node_text(&fraction_field, state)?);
+    }
+    if let Some(exponent_field) = node.child_by_field_name("exponent") {
+        text.push_str(node_text(&exponent_field, state)?);
     }
 
-    let value = integer as f64 + fraction as f64;
+    let value = Decimal::parse(&text).ok_or_else(|| {
+        ParseError::InvalidNumber{text: text.clone(), lexical_info: parse_node_lexical_info(node)}
+    })?;
 
     let expr = Expression::DecimalLiteral{value};
     let lexical_info = parse_node_lexical_info(node);
@@ -329,11 +676,13 @@ fn parse_decimal_literal(node: &tree_sitter::Node, state: &mut TsoParserState) -
 
 fn parse_integer_literal(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
 
-    assert_eq!(node.kind(), "integer");
-    check_invalid_node(node)?;
+    check_node_kind(node, "integer")?;
 
-    let value_field = node.child_by_field_name("value").expect("missing obligatory field");
-    let value = value_field.utf8_text(state.source).unwrap().parse::<i64>().unwrap();
+    let value_field = required_field(node, "value")?;
+    let text = node_text(&value_field, state)?;
+    let value = Integer::parse(text).ok_or_else(|| {
+        ParseError::InvalidNumber{text: text.to_string(), lexical_info: parse_node_lexical_info(&value_field)}
+    })?;
 
     let expr = Expression::IntegerLiteral {value};
     let lexical_info = parse_node_lexical_info(node);
@@ -341,15 +690,68 @@ fn parse_integer_literal(node: &tree_sitter::Node, state: &mut TsoParserState) -
     return Ok(id);
 }
 
-fn parse_string_literal(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
+/// The character an escape sequence like `\n` or `\u{E9}` stands for.
+fn parse_escape_sequence(escape: &str) -> Option<char> {
+
+    return match &escape[1..] {
+        "n" => Some('\n'),
+        "t" => Some('\t'),
+        "\"" => Some('"'),
+        "\\" => Some('\\'),
+        "{" => Some('{'),
+        sequence => {
+            let code = sequence.strip_prefix("u{")?.strip_suffix('}')?;
+            if code.is_empty() || code.len() > 6 || !code.chars().all(|c| c.is_ascii_hexdigit()) {
+                return None;
+            }
+            char::from_u32(u32::from_str_radix(code, 16).ok()?)
+        },
+    };
+}
 
-    assert_eq!(node.kind(), "string");
-    check_invalid_node(node)?;
+fn parse_string_literal(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
 
-    let content_field = node.child_by_field_name("content").expect("missing obligatory field");
-    let content = content_field.utf8_text(state.source).unwrap().to_string();
+    check_node_kind(node, "string")?;
+
+    // text between the values is joined, so a string without values is a `StringLiteral`
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    for_every_child_with_field_name(node, "part", |child_node| {
+
+        match child_node.kind() {
+            "string_content" => text.push_str(node_text(child_node, state)?),
+            "escape_sequence" => {
+                let escape = node_text(child_node, state)?;
+                match parse_escape_sequence(escape) {
+                    Some(character) => text.push(character),
+                    // the other escapes of the string are still checked
+                    None => state.record_error(ParseError::InvalidEscape{
+                        escape: escape.to_string(),
+                        lexical_info: parse_node_lexical_info(child_node),
+                    }),
+                }
+            },
+            _ => {
+                check_node_kind(child_node, "interpolation")?;
+                if !text.is_empty() {
+                    parts.push(StringPart::Text(std::mem::take(&mut text)));
+                }
+                let expr_field = required_field(child_node, "expression")?;
+                parts.push(StringPart::Value(parse_expr(&expr_field, state)?));
+            },
+        }
+        return Ok(());
+    })?;
 
-    let expr = Expression::StringLiteral{value: content};
+    let expr = match parts.is_empty() {
+        true => Expression::StringLiteral{value: text},
+        false => {
+            if !text.is_empty() {
+                parts.push(StringPart::Text(text));
+            }
+            Expression::InterpolatedString{parts}
+        },
+    };
     let lexical_info = parse_node_lexical_info(node);
     let id = state.ast.add_expression_node(expr, lexical_info);
     return Ok(id);
@@ -357,16 +759,18 @@ fn parse_string_literal(node: &tree_sitter::Node, state: &mut TsoParserState) ->
 
 fn parse_bool_literal(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
 
-    assert_eq!(node.kind(), "boolean");
-    check_invalid_node(node)?;
+    check_node_kind(node, "boolean")?;
 
-    let value_field = node.child_by_field_name("value").expect("missing obligatory field");
-    let value = value_field.utf8_text(state.source).unwrap();
+    let value_field = required_field(node, "value")?;
+    let value = node_text(&value_field, state)?;
 
     let value = match value {
         "verdadeiro" => true,
         "falso" => false,
-        _ => panic!("Unexpected boolean value: {}", value),
+        _ => {
+            let lexical_info = parse_node_lexical_info(&value_field);
+            return Err(ParseError::InvalidBoolean{value: value.to_string(), lexical_info});
+        },
     };
 
     let expr = Expression::BooleanLiteral{value};
@@ -377,13 +781,12 @@ fn parse_bool_literal(node: &tree_sitter::Node, state: &mut TsoParserState) -> R
 
 fn parse_list_literal(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
 
-    assert_eq!(node.kind(), "list");
-    check_invalid_node(node)?;
+    check_node_kind(node, "list")?;
 
     let mut items = Vec::new();
     for_every_child_with_field_name(node, "item", |child_node| {
         
-        let expr_id = parse_expr(&child_node, state)?;
+        let expr_id = parse_expr(child_node, state)?;
         items.push(expr_id);
         return Ok(());
     })?;
@@ -397,6 +800,19 @@ fn parse_list_literal(node: &tree_sitter::Node, state: &mut TsoParserState) -> R
 
 fn parse_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
 
+    match parse_nested(node, state, parse_expr_node) {
+        Ok(id) => return Ok(id),
+        Err(error) => state.record_error(error),
+    }
+
+    let lexical_info = parse_node_lexical_info(node);
+    let id = state.ast.add_expression_node(Expression::Error, lexical_info);
+    return Ok(id);
+}
+
+fn parse_expr_node(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ExprID> {
+
+    check_invalid_node(node)?;
     let node_type = node.kind();
 
     match node_type {
@@ -412,6 +828,9 @@ fn parse_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<Ex
         "call_expr" => {
             return parse_call_expr(node, state);
         },
+        "struct_literal" => {
+            return parse_struct_literal(node, state);
+        },
         "parem_expr" => {
             return parse_parem_expr(node, state);
         },
@@ -434,15 +853,14 @@ fn parse_expr(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<Ex
             return parse_list_literal(node, state);
         },
         _ => {
-            panic!("Unknown or unexpected node type: {}", node_type);
+            return Err(unexpected_node(node, "expression"));
         }
     }
 }
 
 fn parse_break_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<StmtID> {
 
-    assert_eq!(node.kind(), "break_stmt");
-    check_invalid_node(node)?;
+    check_node_kind(node, "break_stmt")?;
 
     let stmt = Statement::Break;
     let lexical_info = parse_node_lexical_info(node);
@@ -452,8 +870,7 @@ fn parse_break_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Res
 
 fn parse_continue_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<StmtID> {
 
-    assert_eq!(node.kind(), "continue_stmt");
-    check_invalid_node(node)?;
+    check_node_kind(node, "continue_stmt")?;
 
     let stmt = Statement::Continue;
     let lexical_info = parse_node_lexical_info(node);
@@ -463,8 +880,7 @@ fn parse_continue_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) ->
 
 fn parse_return_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<StmtID> {
 
-    assert_eq!(node.kind(), "return_stmt");
-    check_invalid_node(node)?;
+    check_node_kind(node, "return_stmt")?;
 
     let mut return_expr = None;
     if let Some(expr_field) = node.child_by_field_name("return_expr") {
@@ -479,13 +895,12 @@ fn parse_return_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Re
 
 fn for_each_item(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ForEachDeclID> {
 
-    assert_eq!(node.kind(), "for_item_decl");
-    check_invalid_node(node)?;
+    check_node_kind(node, "for_item_decl")?;
 
     let is_mut = node.child_by_field_name("is_mut").is_some();
     let is_ref = node.child_by_field_name("is_ref").is_some();
 
-    let name_field = node.child_by_field_name("name").expect("missing obligatory field");
+    let name_field = required_field(node, "name")?;
     let name = parse_identifier(&name_field, state)?;
 
     let for_each_decl = ForEachDecl{is_mut, is_ref, name};
@@ -496,19 +911,18 @@ fn for_each_item(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result
 
 fn parse_for_each_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<StmtID> {
 
-    assert_eq!(node.kind(), "for_each_loop");
-    check_invalid_node(node)?;
+    check_node_kind(node, "for_each_loop")?;
 
-    let item_field = node.child_by_field_name("item").expect("missing obligatory field");
+    let item_field = required_field(node, "item")?;
     let item_id = for_each_item(&item_field, state)?;
 
-    let iterator_field = node.child_by_field_name("iterator").expect("missing obligatory field");
+    let iterator_field = required_field(node, "iterator")?;
     let iterator = parse_expr(&iterator_field, state)?;
 
     let mut body = Vec::new();
     for_every_child_with_field_name(node, "body", |child_node| {
         
-        let stmt_id = parse_stmt(&child_node, state)?;
+        let stmt_id = parse_stmt(child_node, state)?;
         body.push(stmt_id);
         return Ok(());
     })?;
@@ -519,18 +933,64 @@ fn parse_for_each_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) ->
     return Ok(id);
 }
 
+fn parse_match_case(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<MatchCase> {
+
+    check_node_kind(node, "match_case")?;
+
+    let variant_field = required_field(node, "variant")?;
+    let variant = parse_identifier(&variant_field, state)?;
+
+    let mut bindings = Vec::new();
+    for_every_child_with_field_name(node, "binding", |child_node| {
+
+        let binding = parse_identifier(child_node, state)?;
+        bindings.push(binding);
+        return Ok(());
+    })?;
+
+    let mut body = Vec::new();
+    for_every_child_with_field_name(node, "body", |child_node| {
+
+        let stmt_id = parse_stmt(child_node, state)?;
+        body.push(stmt_id);
+        return Ok(());
+    })?;
+
+    return Ok(MatchCase{variant, bindings, body});
+}
+
+fn parse_match_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<StmtID> {
+
+    check_node_kind(node, "match_stmt")?;
+
+    let value_field = required_field(node, "value")?;
+    let value = parse_expr(&value_field, state)?;
+
+    let mut cases = Vec::new();
+    for_every_child_with_field_name(node, "case", |child_node| {
+
+        let case = parse_match_case(child_node, state)?;
+        cases.push(case);
+        return Ok(());
+    })?;
+
+    let match_stmt = Statement::Match{value, cases};
+    let lexical_info = parse_node_lexical_info(node);
+    let id = state.ast.add_statement_node(match_stmt, lexical_info);
+    return Ok(id);
+}
+
 fn parse_while_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<StmtID> {
 
-    assert_eq!(node.kind(), "while_loop");
-    check_invalid_node(node)?;
+    check_node_kind(node, "while_loop")?;
 
-    let condition_field = node.child_by_field_name("condition").expect("missing obligatory field");
+    let condition_field = required_field(node, "condition")?;
     let condition = parse_expr(&condition_field, state)?;
 
     let mut body = Vec::new();
     for_every_child_with_field_name(node, "body", |child_node| {
         
-        let stmt_id = parse_stmt(&child_node, state)?;
+        let stmt_id = parse_stmt(child_node, state)?;
         body.push(stmt_id);
         return Ok(());
     })?;
@@ -541,28 +1001,41 @@ fn parse_while_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Res
     return Ok(id);
 }
 
-fn parse_conditional_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<StmtID> {
-
-    assert!(node.kind() == "conditional");
-    check_invalid_node(node)?;
+fn parse_if_branch(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<IfBranch> {
 
-    let condition_field = node.child_by_field_name("condition").expect("missing obligatory field");
-    let condition = parse_expr(&condition_field, state)?;
+    let condition_field = required_field(node, "condition")?;
+    let cond_expr = parse_expr(&condition_field, state)?;
 
     let mut body = Vec::new();
-    for_every_child_with_field_name(node, "then_body", |child_node| {
-        
-        let stmt_id = parse_stmt(&child_node, state)?;
+    for_every_child_with_field_name(node, "body", |child_node| {
+
+        let stmt_id = parse_stmt(child_node, state)?;
         body.push(stmt_id);
         return Ok(());
     })?;
 
+    return Ok(IfBranch{cond_expr, body});
+}
+
+fn parse_conditional_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<StmtID> {
+
+    check_node_kind(node, "conditional")?;
+
+    // `senão se` continues the chain of the first `se`, instead of nesting a new one
+    let mut branches = vec![parse_if_branch(node, state)?];
+    for_every_child_with_field_name(node, "else_if", |child_node| {
+
+        check_node_kind(child_node, "else_if")?;
+        branches.push(parse_if_branch(child_node, state)?);
+        return Ok(());
+    })?;
+
     let else_body = if node.child_by_field_name("has_else").is_some() {
 
         let mut else_body = Vec::new();
         for_every_child_with_field_name(node, "else_body", |child_node| {
             
-            let stmt_id = parse_stmt(&child_node, state)?;
+            let stmt_id = parse_stmt(child_node, state)?;
             else_body.push(stmt_id);
             return Ok(());
         })?;
@@ -573,7 +1046,7 @@ fn parse_conditional_stmt(node: &tree_sitter::Node, state: &mut TsoParserState)
         None
     };
 
-    let conditional = Statement::If{cond_expr: condition, then_block: body, else_body: else_body};
+    let conditional = Statement::If{branches, else_body};
     let lexical_info = parse_node_lexical_info(node);
     let id = state.ast.add_statement_node(conditional, lexical_info);
     return Ok(id);
@@ -581,15 +1054,14 @@ fn parse_conditional_stmt(node: &tree_sitter::Node, state: &mut TsoParserState)
 
 fn parse_var_decl_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<StmtID> {
 
-    assert_eq!(node.kind(), "variable_declaration");
-    check_invalid_node(node)?;
+    check_node_kind(node, "variable_declaration")?;
 
     let is_mut = node.child_by_field_name("is_mut").is_some();
 
-    let name_field = node.child_by_field_name("name").expect("missing obligatory field");
+    let name_field = required_field(node, "name")?;
     let name = parse_identifier(&name_field, state)?;
 
-    let type_field = node.child_by_field_name("type").expect("missing obligatory field");
+    let type_field = required_field(node, "type")?;
     let type_expr = parse_type_expr(&type_field, state)?;
 
     let initializer = if let Some(initializer_field) = node.child_by_field_name("initializer") {
@@ -606,10 +1078,9 @@ fn parse_var_decl_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) ->
 
 fn parse_expr_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<StmtID> {
 
-    assert_eq!(node.kind(), "expression_stmt");
-    check_invalid_node(node)?;
+    check_node_kind(node, "expression_stmt")?;
 
-    let expr_field = node.child_by_field_name("expression").expect("missing obligatory field");
+    let expr_field = required_field(node, "expression")?;
     let expr = parse_expr(&expr_field, state)?;
 
     let stmt = Statement::Expression{expr};
@@ -620,10 +1091,23 @@ fn parse_expr_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Resu
 
 fn parse_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<StmtID> {
 
-    let node_type = node.kind();
+    match parse_nested(node, state, parse_stmt_node) {
+        Ok(id) => return Ok(id),
+        Err(error) => state.record_error(error),
+    }
 
-    match node_type {
-        "break_stmt" => {
+    let lexical_info = parse_node_lexical_info(node);
+    let id = state.ast.add_statement_node(Statement::Error, lexical_info);
+    return Ok(id);
+}
+
+fn parse_stmt_node(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<StmtID> {
+
+    check_invalid_node(node)?;
+    let node_type = node.kind();
+
+    match node_type {
+        "break_stmt" => {
             return parse_break_stmt(node, state);
         },
         "continue_stmt" => {
@@ -638,6 +1122,9 @@ fn parse_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<St
         "while_loop" => {
             return parse_while_stmt(node, state);
         },
+        "match_stmt" => {
+            return parse_match_stmt(node, state);
+        },
         "conditional" => {
             return parse_conditional_stmt(node, state);
         },
@@ -648,22 +1135,21 @@ fn parse_stmt(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<St
             return parse_expr_stmt(node, state);
         },
         _ => {
-            panic!("Unknown or unexpected node type: {}", node_type);
+            return Err(unexpected_node(node, "statement"));
         }
     }
 }
 
 fn parse_function_param(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<FunctionParamID> {
 
-    assert_eq!(node.kind(), "function_param");
-    check_invalid_node(node)?;
+    check_node_kind(node, "function_param")?;
 
     let is_mut = node.child_by_field_name("is_mut").is_some();
 
-    let name_field = node.child_by_field_name("name").expect("missing obligatory field");
+    let name_field = required_field(node, "name")?;
     let name = parse_identifier(&name_field, state)?;
 
-    let type_field = node.child_by_field_name("type").expect("missing obligatory field");
+    let type_field = required_field(node, "type")?;
     let type_expr = parse_type_expr(&type_field, state)?;
 
     let function_param = FunctionParam{is_mut, name, type_expr};
@@ -672,43 +1158,128 @@ fn parse_function_param(node: &tree_sitter::Node, state: &mut TsoParserState) ->
     return Ok(id);
 }
 
-fn parse_function(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ScopeDefID> {
+/// Parses a `function` or a `procedure`, which differ only in the keyword and in the optional
+/// return type of procedures.
+fn parse_subprogram(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ScopeDefID> {
 
-    assert_eq!(node.kind(), "function");
-    check_invalid_node(node)?;
+    let is_procedure = node.kind() == "procedure";
+    if !is_procedure {
+        check_node_kind(node, "function")?;
+    }
 
     // parse name
-    let name_field = node.child_by_field_name("name").expect("missing obligatory field");
-    let name = parse_identifier(&name_field, state)?;
+    let name_field = required_field(node, "name")?;
+    let name = parse_identifier(&name_field, state);
 
 
     // parse parameters
     let mut params = Vec::new();
-    for_every_child_with_field_name(node, "param", |child_node| {
+    let params_result = for_every_child_with_field_name(node, "param", |child_node| {
         
-        let param_id = parse_function_param(&child_node, state)?;
+        let param_id = parse_function_param(child_node, state)?;
         params.push(param_id);
         return Ok(());
-    })?;
+    });
 
 
     // parse return type
-    let return_type_field = node.child_by_field_name("return_type").expect("missing obligatory field");
-    let return_type = parse_type_expr(&return_type_field, state)?;
+    let return_type = match is_procedure {
+        true => node.child_by_field_name("return_type").map(|return_type_field| parse_type_expr(&return_type_field, state)),
+        false => Some(parse_type_expr(&required_field(node, "return_type")?, state)),
+    };
 
 
-    // parse body
+    // parse body, even when the signature is invalid, so the errors inside it are reported
     let mut body = Vec::new();
     for_every_child_with_field_name(node, "body", |child_node| {
         
-        let stmt_id = parse_stmt(&child_node, state)?;
+        let stmt_id = parse_stmt(child_node, state)?;
         body.push(stmt_id);
         return Ok(());
     })?;
 
-    let function = ScopeDef::Function{name, params, return_type, body};
+    params_result?;
+    let return_type = return_type.transpose()?;
+    let scope_def = match (is_procedure, return_type) {
+        (true, return_type) => ScopeDef::Procedure{name: name?, params, return_type, body},
+        (false, Some(return_type)) => ScopeDef::Function{name: name?, params, return_type, body},
+        (false, None) => unreachable!("functions without a return type are rejected above"),
+    };
     let lexical_info = parse_node_lexical_info(node);
-    let id = state.ast.add_scope_def_node(function, lexical_info);
+    let id = state.ast.add_scope_def_node(scope_def, lexical_info);
+    return Ok(id);
+}
+
+fn parse_struct_field(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<StructField> {
+
+    check_node_kind(node, "struct_field")?;
+
+    let name_field = required_field(node, "name")?;
+    let name = parse_identifier(&name_field, state)?;
+
+    let type_field = required_field(node, "type")?;
+    let type_expr = parse_type_expr(&type_field, state)?;
+
+    return Ok(StructField{name, type_expr});
+}
+
+fn parse_struct(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ScopeDefID> {
+
+    check_node_kind(node, "struct")?;
+
+    let name_field = required_field(node, "name")?;
+    let name = parse_identifier(&name_field, state)?;
+
+    let mut fields = Vec::new();
+    for_every_child_with_field_name(node, "field", |child_node| {
+
+        let field = parse_struct_field(child_node, state)?;
+        fields.push(field);
+        return Ok(());
+    })?;
+
+    let scope_def = ScopeDef::Struct{name, fields};
+    let lexical_info = parse_node_lexical_info(node);
+    let id = state.ast.add_scope_def_node(scope_def, lexical_info);
+    return Ok(id);
+}
+
+fn parse_enum_variant(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<EnumVariant> {
+
+    check_node_kind(node, "enum_variant")?;
+
+    let name_field = required_field(node, "name")?;
+    let name = parse_identifier(&name_field, state)?;
+
+    let mut payload = Vec::new();
+    for_every_child_with_field_name(node, "payload", |child_node| {
+
+        let type_expr = parse_type_expr(child_node, state)?;
+        payload.push(type_expr);
+        return Ok(());
+    })?;
+
+    return Ok(EnumVariant{name, payload});
+}
+
+fn parse_enum(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<ScopeDefID> {
+
+    check_node_kind(node, "enum")?;
+
+    let name_field = required_field(node, "name")?;
+    let name = parse_identifier(&name_field, state)?;
+
+    let mut variants = Vec::new();
+    for_every_child_with_field_name(node, "variant", |child_node| {
+
+        let variant = parse_enum_variant(child_node, state)?;
+        variants.push(variant);
+        return Ok(());
+    })?;
+
+    let scope_def = ScopeDef::Enum{name, variants};
+    let lexical_info = parse_node_lexical_info(node);
+    let id = state.ast.add_scope_def_node(scope_def, lexical_info);
     return Ok(id);
 }
 
@@ -717,47 +1288,260 @@ fn parse_scope_def(node: &tree_sitter::Node, state: &mut TsoParserState) -> Resu
     let node_type = node.kind();
 
     match node_type {
-        "function" => {
-            return parse_function(node, state);
+        "function" | "procedure" => {
+            return parse_subprogram(node, state);
+        },
+        "struct" => {
+            return parse_struct(node, state);
+        },
+        "enum" => {
+            return parse_enum(node, state);
         },
         _ => {
-            panic!("Unknown or unexpected node type: {}", node_type);
+            return Err(unexpected_node(node, "definition"));
         }
     }
 }
 
-fn parse_program(node: &tree_sitter::Node, state: &mut TsoParserState) -> Result<()> {
-
-    assert_eq!(node.kind(), "program");
-    check_invalid_node(node)?;
+fn parse_program(node: &tree_sitter::Node, state: &mut TsoParserState) {
 
+    // when the whole source is invalid the root is an `ERROR` node, which may still contain
+    // valid definitions
     let mut declarations = Vec::new();
 
-    for child in 0..(node.child_count()) {
-        let child_node = node.child(child).unwrap();
-        let scope_def_id = parse_scope_def(&child_node, state)?;
-        declarations.push(scope_def_id);
+    let mut cursor = node.walk();
+    for child_node in node.children(&mut cursor) {
+
+        if child_node.is_error() || child_node.is_missing() || !child_node.is_named() || is_comment(&child_node) {
+
+            continue;
+        }
+
+        // definitions with an invalid signature are left out of the partial ast
+        match parse_scope_def(&child_node, state) {
+            Ok(scope_def_id) => declarations.push(scope_def_id),
+            Err(error) => state.record_error(error),
+        }
     }
 
     state.ast.set_program(Program{global_defs: declarations});
-    return Ok(());
 }
 
 struct TsoParserState<'a> {
     ast: Ast,
     source: &'a [u8],
+    errors: Vec<ParseError>,
+    /// How many expressions, statements and types enclose the node being parsed.
+    depth: usize,
+    /// Whether a node too deep inside the one at the limit was reported, so its siblings are not.
+    reported_too_deep: bool,
 }
 
-pub fn parse_tree_sitter_output(tree: &tree_sitter::Tree, source: &str) -> Result<Ast> {
+impl TsoParserState<'_> {
+
+    /// Records an error that made the parser drop part of the ast. Syntax errors were already
+    /// recorded by `collect_syntax_errors`.
+    fn record_error(&mut self, error: ParseError) {
+
+        if let ParseError::TooDeep{..} = error {
+
+            if self.reported_too_deep {
+
+                return;
+            }
+            self.reported_too_deep = true;
+        }
+
+        if !error.is_syntax_error() {
+
+            self.errors.push(error);
+        }
+    }
+}
+
+/// Result of the parsing: the ast is always built, with `Expression::Error` and
+/// `Statement::Error` nodes in place of the invalid parts of the source, and `errors` contains
+/// every error found, in the order they were found.
+pub struct ParseOutput {
+    pub ast: Ast,
+    pub errors: Vec<ParseError>,
+}
+
+impl ParseOutput {
+
+    pub fn has_errors(&self) -> bool {
+        return !self.errors.is_empty();
+    }
+
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        return self.errors.iter().map(|error| error.to_diagnostic()).collect();
+    }
+}
+
+pub fn parse_tree_sitter_output(tree: &tree_sitter::Tree, source: &str) -> ParseOutput {
 
     let mut parser_state = TsoParserState{
         ast: Ast::new(),
         source: source.as_bytes(),
+        errors: Vec::new(),
+        depth: 0,
+        reported_too_deep: false,
     };
 
     let root_node = tree.root_node();
-    parse_program(&root_node, &mut parser_state)?;
-    return Ok(parser_state.ast);
+    collect_syntax_errors(&root_node, &mut parser_state.errors);
+    parse_program(&root_node, &mut parser_state);
+    attach_comments(&root_node, &mut parser_state);
+
+    return ParseOutput{ast: parser_state.ast, errors: parser_state.errors};
+}
+
+/// Parses the source with the ILA tree-sitter grammar and converts the resulting tree.
+pub fn parse_source(source: &str) -> anyhow::Result<ParseOutput> {
+
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&ila_lang::language()).context("falha ao carregar a gramática da linguagem ILA")?;
+
+    let tree = parser.parse(source, None).context("falha ao analisar o programa")?;
+    return Ok(parse_tree_sitter_output(&tree, source));
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  comments                                  */
+/* -------------------------------------------------------------------------- */
+
+/// A statement or a definition comments can be attached to.
+struct CommentTarget {
+    id: NodeID,
+    lexical_info: LexicalInfo,
+    /// Position in the targets of the statement or definition around it, `None` for definitions.
+    parent: Option<usize>,
+}
+
+/// Adds the statements of a block and of the blocks inside it, each before the ones inside it.
+fn collect_comment_targets(ast: &Ast, block: &[StmtID], parent: Option<usize>, targets: &mut Vec<CommentTarget>) {
+
+    for stmt in block {
+
+        let index = targets.len();
+        targets.push(CommentTarget{id: (*stmt).into(), lexical_info: *ast.get_lexical_info(*stmt), parent});
+
+        match ast.get_statement(*stmt) {
+            Statement::If{branches, else_body} => {
+                for branch in branches {
+                    collect_comment_targets(ast, &branch.body, Some(index), targets);
+                }
+                if let Some(else_body) = else_body {
+                    collect_comment_targets(ast, else_body, Some(index), targets);
+                }
+            },
+            Statement::While{body_block, ..} | Statement::ForEach{body_block, ..} => {
+                collect_comment_targets(ast, body_block, Some(index), targets);
+            },
+            Statement::Match{cases, ..} => {
+                for case in cases {
+                    collect_comment_targets(ast, &case.body, Some(index), targets);
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+fn is_comment(node: &tree_sitter::Node) -> bool {
+    return matches!(node.kind(), "line_comment" | "block_comment");
+}
+
+fn collect_comment_nodes<'a>(root: &tree_sitter::Node<'a>, comments: &mut Vec<tree_sitter::Node<'a>>) {
+
+    let mut cursor = root.walk();
+    if !cursor.goto_first_child() {
+        return;
+    }
+
+    loop {
+        let node = cursor.node();
+        if is_comment(&node) {
+            comments.push(node);
+        } else if cursor.goto_first_child() {
+            continue;
+        }
+
+        if !skip_descendants(&mut cursor) {
+            return;
+        }
+    }
+}
+
+/// The node a comment belongs to: a statement or definition of the same block on the line before
+/// the comment, the next one, or the last one, in this order. A block without any of them keeps
+/// the comment in the statement or definition around it.
+fn comment_position(targets: &[CommentTarget], comment: &LexicalInfo) -> (Option<NodeID>, CommentPlacement) {
+
+    // targets around the comment come one inside the other, so the innermost is the last one
+    let parent = targets.iter().rposition(|target| {
+        target.lexical_info.start <= comment.start && comment.end <= target.lexical_info.end
+    });
+    let siblings = || targets.iter().filter(move |target| target.parent == parent);
+
+    let ends_on_its_line = |target: &&CommentTarget| {
+        target.lexical_info.end.line == comment.start.line && target.lexical_info.end <= comment.start
+    };
+    if let Some(target) = siblings().rfind(ends_on_its_line) {
+        return (Some(target.id), CommentPlacement::After);
+    }
+
+    // a comment in the header of a definition or a statement, such as between parameters, is
+    // not about the first statement of its block
+    if let Some(index) = parent.filter(|index| targets[*index].lexical_info.start.line == comment.start.line) {
+        return (Some(targets[index].id), CommentPlacement::Inside);
+    }
+
+    if let Some(target) = siblings().find(|target| target.lexical_info.start >= comment.end) {
+        return (Some(target.id), CommentPlacement::Before);
+    }
+
+    if let Some(target) = siblings().rfind(|target| target.lexical_info.end <= comment.start) {
+        return (Some(target.id), CommentPlacement::After);
+    }
+
+    return (parent.map(|index| targets[index].id), CommentPlacement::Inside);
+}
+
+/// Keeps the comments of the source in the ast, each attached to the nearest statement or
+/// definition, so tools that write the source back do not lose them.
+fn attach_comments(root: &tree_sitter::Node, state: &mut TsoParserState) {
+
+    let mut nodes = Vec::new();
+    collect_comment_nodes(root, &mut nodes);
+
+    let mut targets = Vec::new();
+    for scope_def in &state.ast.get_program().global_defs {
+        let index = targets.len();
+        targets.push(CommentTarget{id: (*scope_def).into(), lexical_info: *state.ast.get_lexical_info(*scope_def), parent: None});
+        collect_comment_targets(&state.ast, state.ast.get_scope_def(*scope_def).body(), Some(index), &mut targets);
+    }
+
+    for node in nodes {
+
+        let text = match node_text(&node, state) {
+            Ok(text) => text,
+            Err(error) => {
+                state.record_error(error);
+                continue;
+            },
+        };
+
+        let (kind, text) = match node.kind() {
+            "block_comment" => (CommentKind::Block, &text[2..text.len() - 2]),
+            _ => (CommentKind::Line, text[1..].strip_suffix('\r').unwrap_or(&text[1..])),
+        };
+
+        let lexical_info = parse_node_lexical_info(&node);
+        let (id, placement) = comment_position(&targets, &lexical_info);
+        state.ast.add_comment(id, Comment{text: text.to_string(), kind, placement, lexical_info});
+    }
 }
 
 
@@ -772,7 +1556,6 @@ mod tests {
 
     use super::*;
     use tree_sitter::Parser;
-    use tree_sitter_ila_lang as ila_lang;
 
 
     fn is_parsed_successfully(source_code: &str) -> bool {
@@ -782,7 +1565,15 @@ mod tests {
 
         let tree = parser.parse(source_code, None).unwrap();
         dbg!(tree.root_node().to_sexp());
-        return parse_tree_sitter_output(&tree, source_code).is_ok();
+        return !parse_tree_sitter_output(&tree, source_code).has_errors();
+    }
+
+    fn parse(source_code: &str) -> ParseOutput {
+        return parse_source(source_code).unwrap();
+    }
+
+    fn function_body(ast: &Ast, index: usize) -> &[StmtID] {
+        return ast.get_scope_def(ast.get_program().global_defs[index]).body();
     }
 
     #[test]
@@ -930,6 +1721,38 @@ mod tests {
         assert!(is_parsed_successfully(source_code));
     }
 
+    #[test]
+    fn test_else_if_chain() {
+
+        let source_code = r#"
+            func foo(a: int) -> int {
+                se a < 0 {
+                    1;
+                } senão se a == 0 {
+                    2;
+                    3;
+                } senão se a < 10 {
+                } senão {
+                    4;
+                }
+                se a > 0 { 5; } senão se a < 0 { 6; }
+            }
+        "#;
+
+        let output = parse(source_code);
+        assert!(output.errors.is_empty());
+
+        let body = function_body(&output.ast, 0);
+        let Statement::If{branches, else_body} = output.ast.get_statement(body[0]) else { panic!("expected a conditional") };
+        assert_eq!(branches.iter().map(|branch| branch.body.len()).collect::<Vec<_>>(), vec![1, 2, 0]);
+        assert!(matches!(output.ast.get_expression(branches[1].cond_expr), Expression::BinaryOprt{..}));
+        assert_eq!(else_body.as_ref().map(Vec::len), Some(1));
+
+        let Statement::If{branches, else_body} = output.ast.get_statement(body[1]) else { panic!("expected a conditional") };
+        assert_eq!(branches.len(), 2);
+        assert!(else_body.is_none());
+    }
+
     #[test]
     fn test_while_loop() {
 
@@ -1039,7 +1862,7 @@ mod tests {
 
         let source_code = r#"
             func foo() -> int {
-                var a: ref int; var b: ref mut int;
+                var a: ref int; var b: mut ref int;
             }
         "#;
         assert!(is_parsed_successfully(source_code));
@@ -1050,9 +1873,591 @@ mod tests {
 
         let source_code = r#"
             func foo() -> int {
-                var a: comp int; var b: comp mut int;
+                var a: comp int; var b: mut comp int;
             }
         "#;
         assert!(is_parsed_successfully(source_code));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_collects_every_syntax_error() {
+
+        let source_code = "func foo() -> int {\n    1 + ;\n    var a int = 2;\n    var b: = 2;\n    se { 3; }\n}\nfunc bar() -> int {}";
+
+        let output = parse(source_code);
+        let error_lines: Vec<usize> = output.errors.iter()
+            .map(|error| error.lexical_info().start.line)
+            .collect();
+
+        assert_eq!(error_lines, vec![1, 2, 3, 4]);
+        assert_eq!(output.ast.get_program().global_defs.len(), 2);
+    }
+
+    #[test]
+    fn test_error_placeholders() {
+
+        let source_code = "func foo() -> int {\n    1 + ;\n    var a int = 2;\n    var b: = 2;\n    se { 3; }\n}";
+
+        let output = parse(source_code);
+        let body = function_body(&output.ast, 0);
+        assert_eq!(body.len(), 5);
+
+        let Statement::Expression{expr} = output.ast.get_statement(body[0]) else { panic!("expected an expression statement") };
+        let Expression::BinaryOprt{right, ..} = output.ast.get_expression(*expr) else { panic!("expected a binary expression") };
+        assert!(matches!(output.ast.get_expression(*right), Expression::Error));
+
+        assert!(matches!(output.ast.get_statement(body[1]), Statement::Error));
+
+        let Statement::Expression{expr} = output.ast.get_statement(body[2]) else { panic!("expected an expression statement") };
+        assert!(matches!(output.ast.get_expression(*expr), Expression::IntegerLiteral{value} if *value == Integer::from(2)));
+
+        assert!(matches!(output.ast.get_statement(body[3]), Statement::Error));
+
+        let Statement::If{branches, ..} = output.ast.get_statement(body[4]) else { panic!("expected a conditional") };
+        assert!(matches!(output.ast.get_expression(branches[0].cond_expr), Expression::Error));
+        assert_eq!(branches[0].body.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_function_signature() {
+
+        let source_code = "func foo(a: ) -> int { 1 + ; }\nfunc bar() -> int {}";
+
+        let output = parse(source_code);
+        assert_eq!(output.errors.len(), 2);
+        assert_eq!(output.ast.get_program().global_defs.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_source() {
+
+        let output = parse("}}} func");
+        assert!(output.has_errors());
+        assert!(output.ast.get_program().global_defs.is_empty());
+    }
+
+    #[test]
+    fn test_assign_expr() {
+
+        let output = parse("func foo() -> int { a = 1 + 2; }");
+        let body = function_body(&output.ast, 0);
+
+        let Statement::Expression{expr} = output.ast.get_statement(body[0]) else { panic!("expected an expression statement") };
+        let Expression::Assign{target, value} = output.ast.get_expression(*expr) else { panic!("expected an assignment") };
+        assert!(matches!(output.ast.get_expression(*target), Expression::Identifier{..}));
+        assert!(matches!(output.ast.get_expression(*value), Expression::BinaryOprt{oprt: BinaryOprt::Add, ..}));
+    }
+
+    #[test]
+    fn test_procedure() {
+
+        let source_code = r#"
+            procedimento mostrar(a: int) {
+                retornar;
+            }
+
+            procedimento ler_numero() -> int {
+                retornar 1;
+            }
+        "#;
+
+        let output = parse(source_code);
+        assert!(!output.has_errors());
+
+        let global_defs = &output.ast.get_program().global_defs;
+        let ScopeDef::Procedure{params, return_type, ..} = output.ast.get_scope_def(global_defs[0]) else { panic!("expected a procedure") };
+        assert_eq!(params.len(), 1);
+        assert!(return_type.is_none());
+
+        let ScopeDef::Procedure{params, return_type, ..} = output.ast.get_scope_def(global_defs[1]) else { panic!("expected a procedure") };
+        assert!(params.is_empty());
+        assert!(return_type.is_some());
+    }
+
+    #[test]
+    fn test_struct() {
+
+        let source_code = r#"
+            estrutura Ponto {
+                x: decimal,
+                y: decimal,
+            }
+
+            func origem(a: int) -> Ponto {
+                se a {}
+                retornar Ponto { x: 0.0, y: a.y };
+            }
+        "#;
+
+        let output = parse(source_code);
+        assert!(!output.has_errors());
+
+        let global_defs = &output.ast.get_program().global_defs;
+        let ScopeDef::Struct{fields, ..} = output.ast.get_scope_def(global_defs[0]) else { panic!("expected a structure") };
+        assert_eq!(fields.len(), 2);
+        assert_eq!(output.ast.get_subprograms(), vec![global_defs[1]]);
+
+        // an empty pair of braces after a condition is its body, not a structure literal
+        let body = function_body(&output.ast, 1);
+        assert!(matches!(output.ast.get_statement(body[0]), Statement::If{..}));
+        let Statement::Return{expr: Some(expr)} = output.ast.get_statement(body[1]) else { panic!("expected a return") };
+        let Expression::StructLiteral{fields, ..} = output.ast.get_expression(*expr) else { panic!("expected a structure literal") };
+        assert_eq!(fields.len(), 2);
+    }
+
+    #[test]
+    fn test_enum() {
+
+        let source_code = r#"
+            enumeração Forma {
+                Círculo(decimal),
+                Retângulo(decimal, decimal),
+                Vazio,
+            }
+
+            func área(f: Forma) -> decimal {
+                escolha f {
+                    caso Círculo(r) => { retornar r * r; }
+                    caso Retângulo(l, a) => { retornar l * a; }
+                    caso Vazio => {}
+                }
+            }
+        "#;
+
+        let output = parse(source_code);
+        assert!(!output.has_errors());
+
+        let global_defs = &output.ast.get_program().global_defs;
+        let scope_def = output.ast.get_scope_def(global_defs[0]);
+        assert!(scope_def.is_enum());
+        let payload_counts: Vec<usize> = scope_def.variants().iter().map(|variant| variant.payload.len()).collect();
+        assert_eq!(payload_counts, vec![1, 2, 0]);
+        assert_eq!(output.ast.get_enums(), vec![global_defs[0]]);
+        assert_eq!(output.ast.get_subprograms(), vec![global_defs[1]]);
+
+        let body = function_body(&output.ast, 1);
+        let Statement::Match{cases, ..} = output.ast.get_statement(body[0]) else { panic!("expected an `escolha`") };
+        let binding_counts: Vec<usize> = cases.iter().map(|case| case.bindings.len()).collect();
+        assert_eq!(binding_counts, vec![1, 2, 0]);
+        assert_eq!(cases[2].body.len(), 0);
+        assert_eq!(output.ast.get_identifier(cases[1].variant), "Retângulo");
+    }
+
+    #[test]
+    fn test_enum_errors() {
+
+        assert!(parse("enumeração Cor { Vermelho Azul }").has_errors());
+        assert!(parse("enumeração Cor { Vermelho() }").has_errors());
+        assert!(parse("func foo() -> int { escolha a { caso B => 1; } }").has_errors());
+        assert!(parse("func foo() -> int { escolha a { B => {} } }").has_errors());
+    }
+
+    #[test]
+    fn test_function_requires_return_type() {
+
+        assert!(parse("func foo(a: int) { retornar; }").has_errors());
+    }
+
+    #[test]
+    fn test_template_type_args() {
+
+        let output = parse("func foo() -> int { var m: dict<int, list<string>>; }");
+        let body = function_body(&output.ast, 0);
+
+        let Statement::VarDecl{type_expr, ..} = output.ast.get_statement(body[0]) else { panic!("expected a declaration") };
+        let TypeExpr::TemplateType{type_args, ..} = output.ast.get_type_expr(*type_expr) else { panic!("expected a template type") };
+        assert_eq!(type_args.len(), 2);
+        assert!(matches!(output.ast.get_type_expr(type_args[0]), TypeExpr::RawType{..}));
+        assert!(matches!(output.ast.get_type_expr(type_args[1]), TypeExpr::TemplateType{..}));
+    }
+
+    #[test]
+    fn test_large_numbers() {
+
+        let output = parse("func foo() -> int { 99999999999999999999; -1.99999999999999999999; 0.05; }");
+        assert_eq!(output.errors, Vec::new());
+        let body = function_body(&output.ast, 0);
+
+        let literal = |index: usize| {
+            let Statement::Expression{expr} = output.ast.get_statement(body[index]) else { panic!("expected an expression statement") };
+            match output.ast.get_expression(*expr) {
+                Expression::IntegerLiteral{value} => value.to_string(),
+                Expression::DecimalLiteral{value} => value.to_string(),
+                _ => panic!("expected a number"),
+            }
+        };
+        assert_eq!(literal(0), "99999999999999999999");
+        assert_eq!(literal(1), "-1.99999999999999999999");
+        assert_eq!(literal(2), "0.05");
+    }
+
+    #[test]
+    fn test_string_literals() {
+
+        let output = parse(r#"func foo() -> int { "a\"b\\c\n\t\{\u{e9}"; ""; "Olá, {nome}!"; "{a}{b + 1}"; }"#);
+        assert_eq!(output.errors, Vec::new());
+        let body = function_body(&output.ast, 0);
+
+        let expr = |index: usize| {
+            let Statement::Expression{expr} = output.ast.get_statement(body[index]) else { panic!("expected an expression statement") };
+            output.ast.get_expression(*expr)
+        };
+        assert!(matches!(expr(0), Expression::StringLiteral{value} if value == "a\"b\\c\n\t{é"));
+        assert!(matches!(expr(1), Expression::StringLiteral{value} if value.is_empty()));
+
+        let Expression::InterpolatedString{parts} = expr(2) else { panic!("expected an interpolated string") };
+        assert!(matches!(&parts[..], [StringPart::Text(hello), StringPart::Value(_), StringPart::Text(end)] if hello == "Olá, " && end == "!"));
+
+        let Expression::InterpolatedString{parts} = expr(3) else { panic!("expected an interpolated string") };
+        assert!(matches!(&parts[..], [StringPart::Value(a), StringPart::Value(sum)]
+            if matches!(output.ast.get_expression(*a), Expression::Identifier{..}) && matches!(output.ast.get_expression(*sum), Expression::BinaryOprt{..})));
+    }
+
+    #[test]
+    fn test_invalid_escapes() {
+
+        let output = parse(r#"func foo() -> int { "\q \u{110000} \u{} \u41 \é"; "\u{1F600}"; }"#);
+        let escapes: Vec<&str> = output.errors.iter().map(|error| match error {
+            ParseError::InvalidEscape{escape, ..} => escape.as_str(),
+            other => panic!("unexpected error {:?}", other),
+        }).collect();
+        assert_eq!(escapes, vec!["\\q", "\\u{110000}", "\\u{}", "\\u", "\\é"]);
+        assert_eq!(function_body(&output.ast, 0).len(), 2);
+    }
+
+    #[test]
+    fn test_comments() {
+
+        let source_code = "# antes\nfunc foo() -> int {\n    var a: int = 1; # depois\n    #{ no meio }#\n    retornar \"# texto\";\n}";
+
+        let output = parse(source_code);
+        assert_eq!(output.errors, Vec::new());
+        let scope_def = output.ast.get_program().global_defs[0];
+        let body = function_body(&output.ast, 0);
+        assert_eq!(body.len(), 2);
+
+        let comments = output.ast.get_comments(scope_def);
+        assert_eq!(comments.len(), 1);
+        assert_eq!((comments[0].text.as_str(), comments[0].kind, comments[0].placement), (" antes", CommentKind::Line, CommentPlacement::Before));
+
+        let comments = output.ast.get_comments(body[0]);
+        assert_eq!(comments.len(), 1);
+        assert_eq!((comments[0].text.as_str(), comments[0].placement), (" depois", CommentPlacement::After));
+
+        let comments = output.ast.get_comments(body[1]);
+        assert_eq!(comments.len(), 1);
+        assert_eq!((comments[0].text.as_str(), comments[0].kind, comments[0].placement), (" no meio ", CommentKind::Block, CommentPlacement::Before));
+
+        let Statement::Return{expr: Some(expr)} = output.ast.get_statement(body[1]) else { panic!("expected a return statement") };
+        assert!(matches!(output.ast.get_expression(*expr), Expression::StringLiteral{value} if value == "# texto"));
+        assert!(output.ast.get_unattached_comments().is_empty());
+    }
+
+
+    /* -------------------------------- fuzzing -------------------------------- */
+
+    /// Xorshift generator, so the fuzz tests are reproducible without extra dependencies.
+    struct Rng(u64);
+
+    impl Rng {
+
+        fn next(&mut self) -> u64 {
+
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            return self.0;
+        }
+
+        fn below(&mut self, limit: usize) -> usize {
+            return (self.next() % limit as u64) as usize;
+        }
+
+        fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+            return &items[self.below(items.len())];
+        }
+    }
+
+    const FUZZ_TOKENS: &[&str] = &[
+        "func", "procedimento", "estrutura", "enumeração", "escolha", "caso", "=>", "var", "mut", "ref", "comp", "se", "senão", "enquanto", "para", "cada", "em",
+        "retornar", "continuar", "parar", "verdadeiro", "falso", "não", "e", "ou",
+        "(", ")", "{", "}", "[", "]", "<", ">", "<=", ">=", "==", "!=", "=", "+", "-", "*", "/",
+        ".", ",", ":", ";", "->", "\"", "\"texto\"", "\"{a}\"", "\\", "\\u{", "a", "b", "int", "lista",
+        "0", "1", "-5", "3.14", "0.0001", "99999999999999999999", "1.99999999999999999999", "6.02e23", "1E-3",
+        "#", "#{", "}#", "# nota", "ç", "\n",
+    ];
+
+    const FUZZ_PROGRAMS: &[&str] = &[
+        "func foo(a: int, mut b: lista<int>) -> int { var c: int = a + b.d(1, 2); retornar c; }",
+        "func foo() -> int { se a > 1 { 1; } senão se a < 0 { 3; } senão { 2; } enquanto falso { parar; } }",
+        "func foo() -> int { para cada mut ref i em [1, 2.5, \"a\"] { continuar; } }",
+        "procedimento foo(mut a: ref int) { *a = ler(); escrever(a); }",
+        "func foo() -> mut ref int { var a: comp int = *b; a = não -c; }",
+        "estrutura Ponto { x: int, y: ref int } func foo() -> Ponto { retornar Ponto { x: 1, y: b.c }; }",
+        "enumeração Forma { A(int), B } func foo() -> int { escolha A(1) { caso A(x) => { retornar x; } caso B => {} } }",
+    ];
+
+    fn assert_no_panic(source_code: &str) {
+
+        let result = std::panic::catch_unwind(|| parse(source_code));
+        assert!(result.is_ok(), "the parser panicked with the input: {:?}", source_code);
+    }
+
+    #[test]
+    fn test_fuzz_random_tokens() {
+
+        let mut rng = Rng(0x1234_5678_9abc_def0);
+
+        for _ in 0..2000 {
+
+            let length = rng.below(40);
+            let tokens: Vec<&str> = (0..length).map(|_| *rng.pick(FUZZ_TOKENS)).collect();
+            let separator = if rng.below(4) == 0 { "" } else { " " };
+
+            let source_code = tokens.join(separator);
+            assert_no_panic(&source_code);
+            assert_no_panic(&format!("func foo() -> int {{ {} }}", source_code));
+        }
+    }
+
+    #[test]
+    fn test_fuzz_mutated_programs() {
+
+        let mut rng = Rng(0x0fed_cba9_8765_4321);
+
+        for _ in 0..2000 {
+
+            let mut source_code: Vec<char> = rng.pick(FUZZ_PROGRAMS).chars().collect();
+
+            for _ in 0..(1 + rng.below(4)) {
+
+                let position = rng.below(source_code.len() + 1);
+                match rng.below(3) {
+                    0 => {
+                        let end = (position + rng.below(6)).min(source_code.len());
+                        source_code.drain(position..end);
+                    },
+                    1 => {
+                        let token = rng.pick(FUZZ_TOKENS);
+                        source_code.splice(position..position, token.chars());
+                    },
+                    _ => {
+                        let end = (position + rng.below(10)).min(source_code.len());
+                        let duplicated: Vec<char> = source_code[position..end].to_vec();
+                        source_code.splice(position..position, duplicated);
+                    },
+                }
+            }
+
+            let source_code: String = source_code.into_iter().collect();
+            assert_no_panic(&source_code);
+        }
+    }
+
+    #[test]
+    fn test_fuzz_random_characters() {
+
+        let mut rng = Rng(0x5555_aaaa_5555_aaaa);
+        let characters: Vec<char> = "func(){}[]<>=!+-*/.,:;\"#aeiouãçé0123456789 \n\t\0".chars().collect();
+
+        for _ in 0..1000 {
+
+            let length = rng.below(80);
+            let source_code: String = (0..length).map(|_| *rng.pick(&characters)).collect();
+            assert_no_panic(&source_code);
+        }
+    }
+
+    #[test]
+    fn test_fuzz_deep_nesting() {
+
+        let mut rng = Rng(0x0123_4567_89ab_cdef);
+        let depth = 20_000;
+
+        let terms = vec!["1"; depth].join("+");
+        let mut programs = vec![
+            format!("procedimento principal() {{ escrever({}); }}", terms),
+            format!("procedimento principal() {{ escrever({}1{}); }}", "(".repeat(depth), ")".repeat(depth)),
+            format!("procedimento principal() {{ escrever({}1); }}", "-".repeat(depth)),
+            format!("procedimento principal() {{ {}escrever(1);{} }}", "se verdadeiro { ".repeat(depth), " }".repeat(depth)),
+            format!("procedimento principal() {{ var x: {}inteiro{}; }}", "lista<".repeat(depth), ">".repeat(depth)),
+            format!("procedimento principal() {{ escrever({}1); }}", "(".repeat(depth)),
+        ];
+
+        // the same shapes, broken at random points
+        for _ in 0..20 {
+
+            let mut source_code: Vec<char> = rng.pick(&programs).chars().collect();
+            let position = rng.below(source_code.len());
+            let token = rng.pick(FUZZ_TOKENS);
+            source_code.splice(position..position, token.chars());
+            programs.push(source_code.into_iter().collect());
+        }
+
+        // the parser must not depend on a stack larger than the one of the main thread
+        let outputs = std::thread::Builder::new().stack_size(8 * 1024 * 1024)
+            .spawn(move || programs.iter().map(|source_code| parse(source_code).errors).collect::<Vec<_>>())
+            .unwrap()
+            .join()
+            .expect("the parser overflowed the stack");
+
+        for errors in &outputs[..5] {
+            let too_deep = errors.iter().filter(|error| matches!(error, ParseError::TooDeep{..})).count();
+            assert_eq!(too_deep, 1, "{:?}", errors);
+        }
+        assert!(outputs[5].iter().any(ParseError::is_syntax_error));
+    }
+
+    #[test]
+    fn test_nesting_depth_limit() {
+
+        let nested = |depth: usize| format!("func foo() -> int {{ retornar {}1{}; }}", "(".repeat(depth), ")".repeat(depth));
+
+        // the `retornar` and the literal take a level each, along with every parenthesis
+        assert!(parse(&nested(MAX_NESTING_DEPTH - 2)).errors.is_empty());
+
+        let output = parse(&nested(MAX_NESTING_DEPTH - 1));
+        assert!(matches!(output.errors[..], [ParseError::TooDeep{..}]));
+        assert_eq!(output.errors[0].to_diagnostic().code, "E0010");
+    }
+
+    #[test]
+    fn test_operator_chain_depth() {
+
+        let chain = |terms: usize| format!("func foo() -> int {{ retornar {}; }}", vec!["1"; terms].join(" + "));
+
+        // a chain only counts a level every `CHAINED_OPERATORS_PER_LEVEL` operators
+        let output = parse(&chain(300));
+        assert!(output.errors.is_empty());
+        let printed = crate::ast_printer::print_ast(&output.ast);
+        assert_eq!(printed.matches("left: BinaryOprt Add").count(), 298);
+
+        assert!(parse(&chain((MAX_NESTING_DEPTH - 2) * CHAINED_OPERATORS_PER_LEVEL)).errors.is_empty());
+
+        let output = parse(&chain((MAX_NESTING_DEPTH - 1) * CHAINED_OPERATORS_PER_LEVEL + 1));
+        assert!(matches!(output.errors[..], [ParseError::TooDeep{..}]));
+    }
+
+
+    /* ---------------------------- decimal literals ---------------------------- */
+
+    fn random_digits(rng: &mut Rng, fewest: usize, most: usize) -> String {
+
+        let count = fewest + rng.below(most - fewest + 1);
+        return (0..count).map(|_| char::from(b'0' + rng.below(10) as u8)).collect();
+    }
+
+    /// Text of a random decimal literal, with leading and trailing zeros, a sign or an exponent.
+    fn random_decimal_literal(rng: &mut Rng) -> String {
+
+        // the `-` belongs to the integer token, which has no leading zeros
+        let mut text = match rng.below(3) {
+            0 if rng.below(4) == 0 => "-0".to_string(),
+            0 => format!("-{}{}", 1 + rng.below(9), random_digits(rng, 0, 19)),
+            _ => random_digits(rng, 1, 20),
+        };
+
+        let has_exponent = rng.below(3) == 0;
+        if !has_exponent || rng.below(2) == 0 {
+            text.push('.');
+            text.push_str(&random_digits(rng, 1, 20));
+        }
+        if has_exponent {
+            text.push_str(rng.pick::<&str>(&["e", "E"]));
+            text.push_str(rng.pick::<&str>(&["", "+", "-"]));
+            text.push_str(&random_digits(rng, 1, 3));
+        }
+
+        return text;
+    }
+
+    /// How a decimal literal is written back, found by moving its point over the digits.
+    fn expected_decimal_text(literal: &str) -> String {
+
+        let unsigned = literal.strip_prefix('-').unwrap_or(literal);
+        let (mantissa, exponent) = unsigned.split_once(['e', 'E']).unwrap_or((unsigned, "0"));
+        let exponent: i64 = exponent.parse().unwrap();
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+        let mut digits = format!("{}{}", integer, fraction);
+        let mut point = integer.len() as i64 + exponent;
+        if point < 0 {
+            digits.insert_str(0, &"0".repeat(-point as usize));
+            point = 0;
+        }
+        if point as usize > digits.len() {
+            digits.push_str(&"0".repeat(point as usize - digits.len()));
+        }
+
+        let (integer, fraction) = digits.split_at(point as usize);
+        let integer = match integer.trim_start_matches('0') { "" => "0", integer => integer };
+        let fraction = match fraction.trim_end_matches('0') { "" => "0", fraction => fraction };
+        let is_zero = integer == "0" && fraction == "0";
+        let sign = if unsigned.len() != literal.len() && !is_zero { "-" } else { "" };
+
+        return format!("{}{}.{}", sign, integer, fraction);
+    }
+
+    fn parse_decimal(literal: &str) -> Decimal {
+
+        let output = parse(&format!("func foo() -> int {{ {}; }}", literal));
+        assert_eq!(output.errors, Vec::new(), "with the literal {:?}", literal);
+        let body = function_body(&output.ast, 0);
+
+        let Statement::Expression{expr} = output.ast.get_statement(body[0]) else { panic!("expected an expression statement") };
+        return match output.ast.get_expression(*expr) {
+            Expression::DecimalLiteral{value} => value.clone(),
+            _ => panic!("{:?} is not read as a decimal literal", literal),
+        };
+    }
+
+    #[test]
+    fn test_decimal_literals() {
+
+        let cases = [
+            ("3.14", "3.14"), ("1.05", "1.05"), ("0.001", "0.001"), ("007.50", "7.5"), ("-0.0", "0.0"),
+            ("6.02e23", "602000000000000000000000.0"), ("1E-3", "0.001"), ("-2.5e+1", "-25.0"), ("4e0", "4.0"),
+        ];
+        for (literal, expected) in cases {
+            assert_eq!(parse_decimal(literal).to_string(), expected);
+        }
+
+        let output = parse("func foo() -> int { 1e10001; }");
+        assert!(matches!(&output.errors[..], [ParseError::InvalidNumber{..}]));
+    }
+
+    #[test]
+    fn test_property_decimal_literal_digits() {
+
+        let mut rng = Rng(0x3141_5926_5358_9793);
+
+        for _ in 0..2000 {
+            let literal = random_decimal_literal(&mut rng);
+            assert_eq!(parse_decimal(&literal).to_string(), expected_decimal_text(&literal), "with the literal {:?}", literal);
+        }
+    }
+
+    #[test]
+    fn test_property_decimal_literal_round_trip() {
+
+        let mut rng = Rng(0x2718_2818_2845_9045);
+
+        for _ in 0..2000 {
+
+            let literal = random_decimal_literal(&mut rng);
+            let value = parse_decimal(&literal);
+
+            // the written value is read back as the same value
+            let written = value.to_string();
+            assert_eq!(parse_decimal(&written), value, "with the literal {:?}", literal);
+
+            // and so are its digits with the point moved into the exponent
+            let (sign, unsigned) = written.split_at(written.len() - written.trim_start_matches('-').len());
+            let (integer, fraction) = unsigned.split_once('.').unwrap();
+            let digits = format!("{}{}", integer, fraction);
+            let digits = match digits.trim_start_matches('0') { "" => "0", digits => digits };
+            let scientific = format!("{}{}e-{}", sign, digits, fraction.len());
+            assert_eq!(parse_decimal(&scientific), value, "with the literal {:?}", literal);
+        }
+    }
+}
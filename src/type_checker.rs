@@ -0,0 +1,1431 @@
+
+// std
+use std::collections::HashMap;
+
+// local
+use crate::ast::*;
+use crate::builtin_types::TypeCatalogue;
+use crate::builtins::Builtin;
+use crate::diagnostic::{closest_name, count, Diagnostic};
+use crate::resolver::{Resolution, Symbol};
+use crate::types::Type;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                 type table                                 */
+/* -------------------------------------------------------------------------- */
+
+/// Types found by the type checker, for the passes that run after it.
+pub struct TypeTable {
+    /// Type of every expression of the program.
+    pub expr_types: HashMap<ExprID, Type>,
+    /// Type of every declared name: functions, parameters, variables and `para cada` items.
+    pub declaration_types: HashMap<IdentifierID, Type>,
+    /// Fields of every structure, in the order they are defined.
+    pub struct_fields: HashMap<ScopeDefID, Vec<FieldType>>,
+    /// Variants of every enumeration, in the order they are defined.
+    pub enum_variants: HashMap<ScopeDefID, Vec<VariantType>>,
+}
+
+pub struct FieldType {
+    pub name: String,
+    pub ty: Type,
+}
+
+pub struct VariantType {
+    pub name: String,
+    pub payload: Vec<Type>,
+}
+
+impl TypeTable {
+
+    pub fn expr_type(&self, id: ExprID) -> &Type {
+        return self.expr_types.get(&id).unwrap_or(&Type::Error);
+    }
+
+    pub fn declaration_type(&self, id: IdentifierID) -> &Type {
+        return self.declaration_types.get(&id).unwrap_or(&Type::Error);
+    }
+
+    pub fn fields(&self, id: ScopeDefID) -> &[FieldType] {
+        return self.struct_fields.get(&id).map(Vec::as_slice).unwrap_or(&[]);
+    }
+
+    pub fn variants(&self, id: ScopeDefID) -> &[VariantType] {
+        return self.enum_variants.get(&id).map(Vec::as_slice).unwrap_or(&[]);
+    }
+
+    /// Position of a field in the structure `object` is, or points to.
+    pub fn field_index(&self, object: ExprID, field_name: &str) -> Option<usize> {
+
+        let Type::Struct{id, ..} = self.expr_type(object).dereferenced() else {
+            return None;
+        };
+
+        return self.fields(*id).iter().position(|field| field.name == field_name);
+    }
+
+    /// Whether a value of this type is a `ref`, or a list, shared value or structure with a `ref`
+    /// among the values it owns, which can not outlive the values they point to.
+    pub fn holds_ref(&self, ty: &Type) -> bool {
+        return self.holds_ref_visiting(ty, &mut Vec::new());
+    }
+
+    fn holds_ref_visiting(&self, ty: &Type, visiting: &mut Vec<ScopeDefID>) -> bool {
+
+        return match ty {
+            Type::Ref{..} => true,
+            Type::List(item) => self.holds_ref_visiting(item, visiting),
+            Type::Comp{inner, ..} => self.holds_ref_visiting(inner, visiting),
+            // a structure that contains itself was already reported
+            Type::Struct{id, ..} if !visiting.contains(id) => {
+                visiting.push(*id);
+                let holds_ref = self.fields(*id).iter().any(|field| self.holds_ref_visiting(&field.ty, visiting));
+                visiting.pop();
+                holds_ref
+            },
+            _ => false,
+        };
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   checker                                  */
+/* -------------------------------------------------------------------------- */
+
+struct TypeChecker<'a> {
+    ast: &'a Ast,
+    resolution: &'a Resolution,
+    diagnostics: &'a mut Vec<Diagnostic>,
+    catalogue: &'a TypeCatalogue,
+    table: TypeTable,
+    /// Resolved type expressions, so errors in them are reported only once.
+    type_exprs: HashMap<TypeExprID, Type>,
+    /// Return type of the function being checked.
+    return_type: Type,
+}
+
+impl<'a> TypeChecker<'a> {
+
+    fn lexical_info(&self, id: impl Into<NodeID>) -> LexicalInfo {
+        return *self.ast.get_lexical_info(id);
+    }
+
+    fn report(&mut self, diagnostic: Diagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    /* ---- type expressions ---- */
+
+    fn resolve_type_expr(&mut self, id: TypeExprID) -> Type {
+
+        if let Some(resolved) = self.type_exprs.get(&id) {
+            return resolved.clone();
+        }
+
+        let resolved = match self.ast.get_type_expr(id) {
+            TypeExpr::RawType{type_name} => self.resolve_type_name(*type_name, &[]),
+            TypeExpr::TemplateType{type_name, type_args} => self.resolve_type_name(*type_name, type_args),
+            TypeExpr::RefType{is_mut, type_expr} => {
+                Type::Ref{is_mut: *is_mut, inner: Box::new(self.resolve_type_expr(*type_expr))}
+            },
+            TypeExpr::CompType{is_mut, type_expr} => {
+                Type::Comp{is_mut: *is_mut, inner: Box::new(self.resolve_type_expr(*type_expr))}
+            },
+        };
+
+        self.type_exprs.insert(id, resolved.clone());
+        return resolved;
+    }
+
+    fn resolve_type_name(&mut self, type_name: IdentifierID, type_args: &[TypeExprID]) -> Type {
+
+        let name = self.ast.get_identifier(type_name);
+        let args: Vec<Type> = type_args.iter().map(|arg| self.resolve_type_expr(*arg)).collect();
+        let lexical_info = self.lexical_info(type_name);
+
+        let Some(info) = self.catalogue.lookup(name) else {
+
+            let defined = match self.resolution.global_symbol(name) {
+                Some(Symbol::Struct(id)) => Some(Type::Struct{id, name: name.clone()}),
+                Some(Symbol::Enum(id)) => Some(Type::Enum{id, name: name.clone()}),
+                _ => None,
+            };
+
+            if let Some(defined) = defined {
+                if !args.is_empty() {
+                    self.report(
+                        Diagnostic::error("E0302", format!("o tipo `{}` não recebe argumentos de tipo", name), lexical_info)
+                            .with_label(format!("use apenas `{}`", name))
+                    );
+                    return Type::Error;
+                }
+                return defined;
+            }
+
+            self.report_unknown_type(name, lexical_info);
+            return Type::Error;
+        };
+
+        if args.len() != info.type_params {
+
+            let message = match info.type_params {
+                0 => format!("o tipo `{}` não recebe argumentos de tipo", name),
+                1 => format!("o tipo `{}` precisa de 1 argumento de tipo, mas recebeu {}", name, args.len()),
+                count => format!("o tipo `{}` precisa de {} argumentos de tipo, mas recebeu {}", name, count, args.len()),
+            };
+            let label = match info.type_params {
+                0 => format!("use apenas `{}`", name),
+                _ => format!("por exemplo `{}<inteiro>`", name),
+            };
+            self.report(Diagnostic::error("E0302", message, lexical_info).with_label(label));
+            return Type::Error;
+        }
+
+        return info.builtin.instantiate(args);
+    }
+
+    fn report_unknown_type(&mut self, name: &str, lexical_info: LexicalInfo) {
+
+        let mut diagnostic = Diagnostic::error("E0301", format!("o tipo `{}` não existe", name), lexical_info)
+            .with_label("tipo desconhecido");
+
+        if let Some(info) = self.catalogue.lookup_english_name(name) {
+            diagnostic = diagnostic.with_suggestion(format!("use o nome em português `{}`", info.name), lexical_info, info.name);
+            if info.english_alias == name {
+                diagnostic = diagnostic.with_note(format!("o nome `{}` é aceito com a opção `--tipos-em-ingles`", name));
+            }
+        }
+        else if let Some(suggestion) = closest_name(name, self.catalogue.names().into_iter().chain(self.defined_type_names())) {
+            diagnostic = diagnostic.with_suggestion(format!("você quis dizer `{}`?", suggestion), lexical_info, suggestion);
+        }
+
+        self.report(diagnostic);
+    }
+
+    /// Names of the structures and enumerations of the program.
+    fn defined_type_names(&self) -> Vec<&'a str> {
+
+        return self.ast.get_program().global_defs.iter()
+            .map(|id| self.ast.get_scope_def(*id))
+            .filter(|scope_def| !scope_def.is_subprogram())
+            .map(|scope_def| self.ast.get_identifier(scope_def.name()).as_str())
+            .collect();
+    }
+
+    /* ---- declarations ---- */
+
+    /// Reports a type defined by the program with the name of a builtin type. `kind` is what
+    /// defines it, like "a estrutura".
+    fn check_type_name(&mut self, name: &str, lexical_info: LexicalInfo, kind: &str) {
+
+        if self.catalogue.lookup(name).is_some() {
+            self.report(
+                Diagnostic::error("E0315", format!("já existe um tipo chamado `{}`", name), lexical_info)
+                    .with_label("nome de um tipo embutido")
+                    .with_note(format!("escolha outro nome para {}", kind))
+            );
+        }
+    }
+
+    fn declare_struct(&mut self, id: ScopeDefID) {
+
+        let scope_def = self.ast.get_scope_def(id);
+        let name = self.ast.get_identifier(scope_def.name());
+        let lexical_info = self.lexical_info(scope_def.name());
+        self.check_type_name(name, lexical_info, "a estrutura");
+
+        if scope_def.fields().is_empty() {
+            self.report(
+                Diagnostic::error("E0321", format!("a estrutura `{}` não tem campos", name), lexical_info)
+                    .with_label("estrutura vazia")
+                    .with_note("defina pelo menos um campo, por exemplo `estrutura Ponto { x: decimal }`")
+            );
+        }
+
+        let mut fields: Vec<FieldType> = Vec::new();
+        for field in scope_def.fields() {
+
+            let field_name = self.ast.get_identifier(field.name);
+            let ty = self.resolve_type_expr(field.type_expr);
+
+            let first = scope_def.fields().iter()
+                .find(|other| self.ast.get_identifier(other.name) == field_name)
+                .expect("the field itself has this name");
+            if first.name != field.name {
+                self.report(
+                    Diagnostic::error("E0317", format!("o campo `{}` foi repetido", field_name), self.lexical_info(field.name))
+                        .with_label("segunda definição")
+                        .with_secondary(self.lexical_info(first.name), "primeira definição")
+                );
+                continue;
+            }
+
+            fields.push(FieldType{name: field_name.clone(), ty});
+        }
+
+        self.table.struct_fields.insert(id, fields);
+    }
+
+    fn declare_enum(&mut self, id: ScopeDefID) {
+
+        let scope_def = self.ast.get_scope_def(id);
+        let name = self.ast.get_identifier(scope_def.name());
+        let lexical_info = self.lexical_info(scope_def.name());
+        self.check_type_name(name, lexical_info, "a enumeração");
+
+        if scope_def.variants().is_empty() {
+            self.report(
+                Diagnostic::error("E0322", format!("a enumeração `{}` não tem variantes", name), lexical_info)
+                    .with_label("enumeração vazia")
+                    .with_note("defina pelo menos uma variante, por exemplo `enumeração Cor { Vermelho, Azul }`")
+            );
+        }
+
+        let enum_type = Type::Enum{id, name: name.clone()};
+        let mut variants = Vec::new();
+        for variant in scope_def.variants() {
+
+            let mut payload = Vec::new();
+            for type_expr in &variant.payload {
+
+                let ty = self.resolve_type_expr(*type_expr);
+                // a variant can be copied anywhere, so it can not point to values that may end
+                if self.table.holds_ref(&ty) {
+                    self.report(
+                        Diagnostic::error("E0323", "uma variante não pode guardar uma `ref`", self.lexical_info(*type_expr))
+                            .with_label(format!("`{}` é ou contém uma `ref`", ty))
+                            .with_note("guarde uma cópia do valor, ou use `comp` para compartilhá-lo")
+                    );
+                }
+                payload.push(ty);
+            }
+
+            // a variant without values is a value of the enumeration, the others are created by calls
+            let variant_type = match payload.is_empty() {
+                true => enum_type.clone(),
+                false => Type::Function{params: payload.clone(), return_type: Box::new(enum_type.clone())},
+            };
+            self.table.declaration_types.insert(variant.name, variant_type);
+            variants.push(VariantType{name: self.ast.get_identifier(variant.name).clone(), payload});
+        }
+
+        self.table.enum_variants.insert(id, variants);
+    }
+
+    /// Reports a structure that contains itself through fields that own their value, which
+    /// would never end. A `comp`, a `ref` or a `lista` of it is fine.
+    fn check_struct_size(&mut self, id: ScopeDefID) {
+
+        let mut path = vec![id];
+        if !self.contains_struct(id, &mut path) {
+            return;
+        }
+
+        let scope_def = self.ast.get_scope_def(id);
+        let name = self.ast.get_identifier(scope_def.name());
+        let names: Vec<String> = path.iter()
+            .map(|step| format!("`{}`", self.ast.get_identifier(self.ast.get_scope_def(*step).name())))
+            .collect();
+
+        self.report(
+            Diagnostic::error("E0320", format!("a estrutura `{}` contém a si mesma", name), self.lexical_info(scope_def.name()))
+                .with_label(format!("{}, um valor dela teria tamanho infinito", names.join(" contém ")))
+                .with_note(format!("use `comp {}` ou `lista<{}>` no campo para guardar o valor separadamente", name, name))
+        );
+    }
+
+    /// Whether the fields owned by the last structure of `path` lead back to `target`, keeping
+    /// in `path` the structures on the way.
+    fn contains_struct(&self, target: ScopeDefID, path: &mut Vec<ScopeDefID>) -> bool {
+
+        let current = *path.last().expect("the path starts at the target");
+        for field in self.table.fields(current) {
+
+            let Type::Struct{id, ..} = &field.ty else { continue };
+            if *id == target {
+                path.push(*id);
+                return true;
+            }
+
+            if path.contains(id) {
+                continue;
+            }
+
+            path.push(*id);
+            if self.contains_struct(target, path) {
+                return true;
+            }
+            path.pop();
+        }
+
+        return false;
+    }
+
+    fn declare_function(&mut self, id: ScopeDefID) {
+
+        let scope_def = self.ast.get_scope_def(id);
+
+        let mut param_types = Vec::new();
+        for param in scope_def.params() {
+            let function_param = self.ast.get_function_param(*param);
+            let param_type = self.resolve_type_expr(function_param.type_expr);
+            self.table.declaration_types.insert(function_param.name, param_type.clone());
+            param_types.push(param_type);
+        }
+
+        let return_type = Box::new(self.subprogram_return_type(id));
+        self.table.declaration_types.insert(scope_def.name(), Type::Function{params: param_types, return_type});
+    }
+
+    /// Procedures without a declared return type return `nada`.
+    fn subprogram_return_type(&mut self, id: ScopeDefID) -> Type {
+
+        return match self.ast.get_scope_def(id).return_type() {
+            Some(return_type) => self.resolve_type_expr(return_type),
+            None => Type::Nothing,
+        };
+    }
+
+    /// Type of a declared name. Variables can be used before their declaration statement,
+    /// so their type is resolved on the first use.
+    fn declaration_type(&mut self, declaration: IdentifierID) -> Type {
+
+        if let Some(declared) = self.table.declaration_types.get(&declaration) {
+            return declared.clone();
+        }
+
+        let Some(Symbol::Var(stmt)) = self.resolution.symbol(declaration) else {
+            return Type::Error;
+        };
+
+        let Statement::VarDecl{type_expr, ..} = self.ast.get_statement(stmt) else {
+            return Type::Error;
+        };
+
+        let declared = self.resolve_type_expr(*type_expr);
+        self.table.declaration_types.insert(declaration, declared.clone());
+        return declared;
+    }
+
+    /* ---- expressions ---- */
+
+    /// Reports an error if a value of type `found`, produced by `expr`, can not be used where
+    /// a `expected` is expected.
+    fn check_accepts(&mut self, expected: &Type, found: &Type, expr: ExprID) {
+
+        if expected.accepts(found) {
+            return;
+        }
+
+        self.report(
+            Diagnostic::error("E0304", format!("tipos incompatíveis: era esperado `{}`, mas foi encontrado `{}`", expected, found), self.lexical_info(expr))
+                .with_label(format!("este valor é do tipo `{}`", found))
+        );
+    }
+
+    fn check_expr(&mut self, id: ExprID, expected: Option<&Type>) -> Type {
+
+        let found = self.expr_type(id, expected);
+        self.table.expr_types.insert(id, found.clone());
+        return found;
+    }
+
+    fn expr_type(&mut self, id: ExprID, expected: Option<&Type>) -> Type {
+
+        return match self.ast.get_expression(id) {
+            Expression::IntegerLiteral{..} => Type::Integer,
+            Expression::DecimalLiteral{..} => Type::Decimal,
+            Expression::StringLiteral{..} => Type::Text,
+            Expression::InterpolatedString{parts} => {
+                // values of any type are converted to text, like `para_texto` does
+                for value in parts.iter().filter_map(StringPart::value) {
+                    self.check_expr(value, None);
+                }
+                Type::Text
+            },
+            Expression::BooleanLiteral{..} => Type::Boolean,
+            Expression::ListLiteral{values} => self.list_type(id, values, expected),
+            Expression::Identifier{node_id} => {
+                match (self.resolution.declaration_of(*node_id), self.resolution.symbol(*node_id)) {
+                    (_, Some(Symbol::Struct(_))) => {
+                        let name = self.ast.get_identifier(*node_id);
+                        self.report(
+                            Diagnostic::error("E0319", format!("`{}` é uma estrutura, não um valor", name), self.lexical_info(id))
+                                .with_label(format!("por exemplo `{} {{ ... }}`", name))
+                        );
+                        Type::Error
+                    },
+                    (_, Some(Symbol::Enum(enum_id))) => {
+                        let name = self.ast.get_identifier(*node_id);
+                        let label = match self.table.variants(enum_id).first() {
+                            Some(variant) => format!("use uma das suas variantes, por exemplo `{}`", variant.name),
+                            None => "use uma das suas variantes".to_string(),
+                        };
+                        self.report(
+                            Diagnostic::error("E0319", format!("`{}` é uma enumeração, não um valor", name), self.lexical_info(id))
+                                .with_label(label)
+                        );
+                        Type::Error
+                    },
+                    (Some(declaration), Some(Symbol::Variant(..))) => match self.declaration_type(declaration) {
+                        Type::Function{params, ..} => {
+                            let name = self.ast.get_identifier(*node_id);
+                            let params: Vec<String> = params.iter().map(|param| param.to_string()).collect();
+                            self.report(
+                                Diagnostic::error("E0324", format!("a variante `{}` precisa de valores", name), self.lexical_info(id))
+                                    .with_label(format!("valores do tipo {}, como em `{}(...)`", params.join(", "), name))
+                            );
+                            Type::Error
+                        },
+                        variant_type => variant_type,
+                    },
+                    (Some(declaration), _) => self.declaration_type(declaration),
+                    (None, Some(Symbol::Builtin(builtin))) => {
+                        // builtins have a signature only in calls, where the arguments are known
+                        self.report(
+                            Diagnostic::error("E0314", format!("`{}` só pode ser usado em uma chamada", builtin.name()), self.lexical_info(id))
+                                .with_label(format!("por exemplo `{}(...)`", builtin.name()))
+                        );
+                        Type::Error
+                    },
+                    (None, _) => Type::Error,
+                }
+            },
+            Expression::Call{callee, args} => self.call_type(id, *callee, args),
+            Expression::StructLiteral{type_name, fields} => self.struct_literal_type(id, *type_name, fields),
+            Expression::Access{object, field_name} => {
+                let object_type = self.check_expr(*object, None);
+                match object_type.dereferenced() {
+                    Type::Error => Type::Error,
+                    object_type => {
+                        let object_type = object_type.clone();
+                        self.field_type(&object_type, *field_name).unwrap_or(Type::Error)
+                    },
+                }
+            },
+            Expression::BinaryOprt{oprt, left, right} => self.binary_oprt_type(id, *oprt, *left, *right),
+            Expression::UnaryOprt{oprt, operand} => self.unary_oprt_type(id, *oprt, *operand),
+            Expression::Assign{target, value} => {
+                self.check_assign(*target, *value);
+                Type::Nothing
+            },
+            Expression::Error => Type::Error,
+        };
+    }
+
+    fn list_type(&mut self, id: ExprID, values: &[ExprID], expected: Option<&Type>) -> Type {
+
+        let expected_item = match expected.map(|expected| expected.dereferenced()) {
+            Some(Type::List(item)) => Some(item.as_ref().clone()),
+            _ => None,
+        };
+
+        let Some((first, rest)) = values.split_first() else {
+
+            if let Some(item) = expected_item {
+                return Type::List(Box::new(item));
+            }
+
+            self.report(
+                Diagnostic::error("E0303", "não é possível descobrir o tipo dos itens desta lista vazia", self.lexical_info(id))
+                    .with_label("lista vazia")
+                    .with_note("informe o tipo onde a lista é usada, por exemplo `var a: lista<inteiro> = [];`")
+            );
+            return Type::List(Box::new(Type::Error));
+        };
+
+        // without an expected type, the first item decides the type of the others
+        let item = match expected_item {
+            Some(item) => {
+                let first_type = self.check_expr(*first, Some(&item));
+                self.check_accepts(&item, &first_type, *first);
+                item
+            },
+            None => self.check_expr(*first, None).dereferenced().clone(),
+        };
+
+        for value in rest {
+            let value_type = self.check_expr(*value, Some(&item));
+            self.check_accepts(&item, &value_type, *value);
+        }
+
+        return Type::List(Box::new(item));
+    }
+
+    /// Type of a field of a value of type `object_type`, reporting the fields it does not have.
+    fn field_type(&mut self, object_type: &Type, field_name: IdentifierID) -> Option<Type> {
+
+        let field = self.ast.get_identifier(field_name);
+        let fields = match object_type {
+            Type::Struct{id, ..} => self.table.fields(*id),
+            _ => &[],
+        };
+
+        if let Some(found) = fields.iter().find(|candidate| candidate.name == *field) {
+            return Some(found.ty.clone());
+        }
+
+        let lexical_info = self.lexical_info(field_name);
+        let mut diagnostic = Diagnostic::error("E0311", format!("o tipo `{}` não tem o campo `{}`", object_type, field), lexical_info)
+            .with_label("campo desconhecido");
+
+        if let Some(suggestion) = closest_name(field, fields.iter().map(|candidate| candidate.name.as_str())) {
+            diagnostic = diagnostic.with_suggestion(format!("você quis dizer `{}`?", suggestion), lexical_info, suggestion);
+        }
+
+        self.report(diagnostic);
+        return None;
+    }
+
+    fn struct_literal_type(&mut self, id: ExprID, type_name: IdentifierID, fields: &[FieldInit]) -> Type {
+
+        let name = self.ast.get_identifier(type_name);
+        let struct_id = match self.resolution.symbol(type_name) {
+            Some(Symbol::Struct(struct_id)) => struct_id,
+            symbol => {
+                // an undefined name was already reported by the resolver
+                if symbol.is_some() {
+                    self.report(
+                        Diagnostic::error("E0318", format!("`{}` não é uma estrutura", name), self.lexical_info(type_name))
+                            .with_label("só estruturas podem ser criadas com `{ campo: valor }`")
+                    );
+                }
+                for field in fields {
+                    self.check_expr(field.value, None);
+                }
+                return Type::Error;
+            },
+        };
+
+        let struct_type = Type::Struct{id: struct_id, name: name.clone()};
+        let mut initialized: Vec<&str> = Vec::new();
+
+        for field in fields {
+
+            let field_name = self.ast.get_identifier(field.name);
+            let Some(field_type) = self.field_type(&struct_type, field.name) else {
+                self.check_expr(field.value, None);
+                continue;
+            };
+
+            if initialized.contains(&field_name.as_str()) {
+                self.report(
+                    Diagnostic::error("E0317", format!("o campo `{}` foi repetido", field_name), self.lexical_info(field.name))
+                        .with_label("o campo já recebeu um valor")
+                );
+            }
+            initialized.push(field_name);
+
+            let value_type = self.check_expr(field.value, Some(&field_type));
+            self.check_accepts(&field_type, &value_type, field.value);
+        }
+
+        let missing: Vec<String> = self.table.fields(struct_id).iter()
+            .filter(|field| !initialized.contains(&field.name.as_str()))
+            .map(|field| format!("`{}`", field.name))
+            .collect();
+
+        if !missing.is_empty() {
+            self.report(
+                Diagnostic::error("E0316", format!("faltam valores para campos de `{}`: {}", name, missing.join(", ")), self.lexical_info(id))
+                    .with_label("todos os campos precisam de um valor")
+            );
+        }
+
+        return struct_type;
+    }
+
+    fn builtin_callee(&self, callee: ExprID) -> Option<Builtin> {
+
+        return match self.ast.get_expression(callee) {
+            Expression::Identifier{node_id} => match self.resolution.symbol(*node_id) {
+                Some(Symbol::Builtin(builtin)) => Some(builtin),
+                _ => None,
+            },
+            _ => None,
+        };
+    }
+
+    /// Type of the variant a callee names: a function that creates the enumeration from the
+    /// values of the variant.
+    fn variant_callee(&self, callee: ExprID) -> Option<(IdentifierID, Type)> {
+
+        let Expression::Identifier{node_id} = self.ast.get_expression(callee) else {
+            return None;
+        };
+
+        return match self.resolution.symbol(*node_id) {
+            Some(Symbol::Variant(..)) => {
+                let declaration = self.resolution.declaration_of(*node_id)?;
+                Some((*node_id, self.table.declaration_type(declaration).clone()))
+            },
+            _ => None,
+        };
+    }
+
+    fn call_type(&mut self, id: ExprID, callee: ExprID, args: &[ExprID]) -> Type {
+
+        // the signature of a builtin depends on the arguments, which are then checked only once
+        let mut arg_types = None;
+        let callee_type = match (self.builtin_callee(callee), self.variant_callee(callee)) {
+            (Some(builtin), _) => {
+                let types: Vec<Type> = args.iter().map(|arg| self.check_expr(*arg, None)).collect();
+                let signature = builtin.signature(&types);
+                self.table.expr_types.insert(callee, signature.clone());
+                arg_types = Some(types);
+                signature
+            },
+            (None, Some((name, Type::Enum{..}))) => {
+                let name = self.ast.get_identifier(name);
+                self.report(
+                    Diagnostic::error("E0324", format!("a variante `{}` não guarda valores", name), self.lexical_info(id))
+                        .with_label(format!("use apenas `{}`", name))
+                );
+                for arg in args {
+                    self.check_expr(*arg, None);
+                }
+                return self.check_expr(callee, None);
+            },
+            (None, Some((_, variant_type))) => {
+                self.table.expr_types.insert(callee, variant_type.clone());
+                variant_type
+            },
+            (None, None) => self.check_expr(callee, None),
+        };
+
+        let (params, return_type) = match callee_type.dereferenced() {
+            Type::Function{params, return_type} => (params.clone(), return_type.as_ref().clone()),
+            Type::Error => {
+                for arg in args {
+                    self.check_expr(*arg, None);
+                }
+                return Type::Error;
+            },
+            other => {
+                let other = other.clone();
+                self.report(
+                    Diagnostic::error("E0306", format!("um valor do tipo `{}` não pode ser chamado", other), self.lexical_info(callee))
+                        .with_label("não é uma função")
+                );
+                for arg in args {
+                    self.check_expr(*arg, None);
+                }
+                return Type::Error;
+            },
+        };
+
+        if params.len() != args.len() {
+
+            let function_name = match self.ast.get_expression(callee) {
+                Expression::Identifier{node_id} => format!("`{}`", self.ast.get_identifier(*node_id)),
+                _ => "a função".to_string(),
+            };
+            self.report(
+                Diagnostic::error("E0305", format!("{} recebe {}, mas {}", function_name, count(params.len(), "argumento", "argumentos"), count(args.len(), "foi passado", "foram passados")), self.lexical_info(id))
+                    .with_label(match params.len() {
+                        1 => "esperado 1 argumento".to_string(),
+                        expected => format!("esperados {} argumentos", expected),
+                    })
+            );
+        }
+
+        for (index, arg) in args.iter().enumerate() {
+
+            let param = params.get(index);
+            let arg_type = match &arg_types {
+                Some(arg_types) => arg_types[index].clone(),
+                None => self.check_expr(*arg, param),
+            };
+            if let Some(param) = param {
+                self.check_accepts(param, &arg_type, *arg);
+            }
+        }
+
+        return return_type;
+    }
+
+    fn binary_oprt_type(&mut self, id: ExprID, oprt: BinaryOprt, left: ExprID, right: ExprID) -> Type {
+
+        let left_type = self.check_expr(left, None);
+        let right_type = self.check_expr(right, None);
+        let left_value = left_type.dereferenced();
+        let right_value = right_type.dereferenced();
+
+        let is_comparison = matches!(oprt,
+            BinaryOprt::Eq | BinaryOprt::Neq |
+            BinaryOprt::Lt | BinaryOprt::Le | BinaryOprt::Gt | BinaryOprt::Ge |
+            BinaryOprt::And | BinaryOprt::Or);
+
+        if left_value.is_error() || right_value.is_error() {
+            return if is_comparison { Type::Boolean } else { Type::Error };
+        }
+
+        let both_numeric = left_value.is_numeric() && right_value.is_numeric();
+        let result = match oprt {
+            BinaryOprt::Add | BinaryOprt::Sub | BinaryOprt::Mul | BinaryOprt::Div if both_numeric => {
+                // an integer mixed with a decimal is converted to a decimal
+                if *left_value == Type::Integer && *right_value == Type::Integer { Some(Type::Integer) } else { Some(Type::Decimal) }
+            },
+            BinaryOprt::Add if *left_value == Type::Text && *right_value == Type::Text => Some(Type::Text),
+            BinaryOprt::Lt | BinaryOprt::Le | BinaryOprt::Gt | BinaryOprt::Ge if both_numeric => Some(Type::Boolean),
+            BinaryOprt::Eq | BinaryOprt::Neq if both_numeric || left_value.same_as(right_value) => Some(Type::Boolean),
+            BinaryOprt::And | BinaryOprt::Or if *left_value == Type::Boolean && *right_value == Type::Boolean => Some(Type::Boolean),
+            _ => None,
+        };
+
+        if let Some(result) = result {
+            return result;
+        }
+
+        let message = format!("o operador `{}` não pode ser usado entre `{}` e `{}`", oprt.symbol(), left_value, right_value);
+        self.report(
+            Diagnostic::error("E0307", message, self.lexical_info(id))
+                .with_label("operação inválida")
+        );
+
+        return if is_comparison { Type::Boolean } else { Type::Error };
+    }
+
+    fn unary_oprt_type(&mut self, id: ExprID, oprt: UnaryOprt, operand: ExprID) -> Type {
+
+        let operand_type = self.check_expr(operand, None);
+        if operand_type.is_error() {
+            return Type::Error;
+        }
+
+        let result = match (oprt, &operand_type) {
+            (UnaryOprt::Deref, Type::Ref{inner, ..}) |
+            (UnaryOprt::Deref, Type::Comp{inner, ..}) => return inner.as_ref().clone(),
+            (UnaryOprt::Deref, _) => {
+                self.report(
+                    Diagnostic::error("E0308", format!("não é possível desreferenciar um valor do tipo `{}`", operand_type), self.lexical_info(id))
+                        .with_label("não é `ref` nem `comp`")
+                );
+                return Type::Error;
+            },
+            (UnaryOprt::Neg, operand_type) if operand_type.dereferenced().is_numeric() => operand_type.dereferenced().clone(),
+            (UnaryOprt::Not, operand_type) if *operand_type.dereferenced() == Type::Boolean => Type::Boolean,
+            _ => {
+                let message = format!("o operador `{}` não pode ser usado com `{}`", oprt.symbol(), operand_type.dereferenced());
+                self.report(
+                    Diagnostic::error("E0307", message, self.lexical_info(id))
+                        .with_label("operação inválida")
+                );
+                Type::Error
+            },
+        };
+
+        return result;
+    }
+
+    fn check_assign(&mut self, target: ExprID, value: ExprID) {
+
+        let is_place = match self.ast.get_expression(target) {
+            Expression::Identifier{node_id} => !matches!(
+                self.resolution.symbol(*node_id),
+                Some(Symbol::Function(_) | Symbol::Struct(_) | Symbol::Enum(_) | Symbol::Variant(..))
+            ),
+            Expression::Access{..} |
+            Expression::UnaryOprt{oprt: UnaryOprt::Deref, ..} |
+            Expression::Error => true,
+            _ => false,
+        };
+
+        let target_type = self.check_expr(target, None);
+
+        if !is_place {
+            self.report(
+                Diagnostic::error("E0312", "não é possível atribuir um valor a esta expressão", self.lexical_info(target))
+                    .with_label("não é uma variável")
+                    .with_note("o lado esquerdo de `=` precisa ser uma variável, um campo ou `*` de uma referência")
+            );
+            self.check_expr(value, None);
+            return;
+        }
+
+        // assigning to a `ref` or `comp` makes it point to another value, `*` changes the value itself
+        let value_type = self.check_expr(value, Some(&target_type));
+        self.check_accepts(&target_type, &value_type, value);
+    }
+
+    fn check_condition(&mut self, cond_expr: ExprID) {
+
+        let cond_type = self.check_expr(cond_expr, Some(&Type::Boolean));
+        let cond_value = cond_type.dereferenced();
+        if cond_value.is_error() || *cond_value == Type::Boolean {
+            return;
+        }
+
+        let message = format!("a condição precisa ser do tipo `lógico`, mas é do tipo `{}`", cond_value);
+        self.report(
+            Diagnostic::error("E0309", message, self.lexical_info(cond_expr))
+                .with_label("condição inválida")
+        );
+    }
+
+    /* ---- statements ---- */
+
+    fn check_block(&mut self, block: &[StmtID]) {
+
+        for stmt in block {
+            self.check_stmt(*stmt);
+        }
+    }
+
+    fn check_stmt(&mut self, id: StmtID) {
+
+        match self.ast.get_statement(id) {
+            Statement::Expression{expr} => {
+                self.check_expr(*expr, None);
+            },
+            Statement::VarDecl{name, init_expr, ..} => {
+                let declared = self.declaration_type(*name);
+                if let Some(init_expr) = init_expr {
+                    let init_type = self.check_expr(*init_expr, Some(&declared));
+                    self.check_accepts(&declared, &init_type, *init_expr);
+                }
+            },
+            Statement::If{branches, else_body} => {
+                for branch in branches {
+                    self.check_condition(branch.cond_expr);
+                    self.check_block(&branch.body);
+                }
+                if let Some(else_body) = else_body {
+                    self.check_block(else_body);
+                }
+            },
+            Statement::While{cond_expr, body_block} => {
+                self.check_condition(*cond_expr);
+                self.check_block(body_block);
+            },
+            Statement::ForEach{item, iter_expr, body_block} => {
+                let iter_type = self.check_expr(*iter_expr, None);
+                let item_type = match iter_type.dereferenced() {
+                    Type::List(item_type) => item_type.as_ref().clone(),
+                    Type::Text => Type::Text,
+                    Type::Error => Type::Error,
+                    other => {
+                        let other = other.clone();
+                        self.report(
+                            Diagnostic::error("E0310", format!("não é possível percorrer um valor do tipo `{}`", other), self.lexical_info(*iter_expr))
+                                .with_label("não é uma lista")
+                                .with_note("`para cada` percorre os itens de uma lista ou os caracteres de um texto")
+                        );
+                        Type::Error
+                    },
+                };
+
+                let for_each_decl = self.ast.get_for_each_decl(*item);
+                let item_type = match for_each_decl.is_ref {
+                    true => Type::Ref{is_mut: for_each_decl.is_mut, inner: Box::new(item_type)},
+                    false => item_type,
+                };
+                self.table.declaration_types.insert(for_each_decl.name, item_type);
+                self.check_block(body_block);
+            },
+            Statement::Match{value, cases} => self.check_match(*value, cases),
+            Statement::Return{expr} => {
+                let return_type = self.return_type.clone();
+                match expr {
+                    Some(expr) if return_type == Type::Nothing => {
+                        self.check_expr(*expr, None);
+                        self.report(
+                            Diagnostic::error("E0313", "esta função não retorna valor", self.lexical_info(*expr))
+                                .with_label("valor não esperado")
+                                .with_note("o tipo de retorno da função é `nada`")
+                        );
+                    },
+                    Some(expr) => {
+                        let found = self.check_expr(*expr, Some(&return_type));
+                        self.check_accepts(&return_type, &found, *expr);
+                    },
+                    None if return_type == Type::Nothing || return_type.is_error() => {},
+                    None => {
+                        self.report(
+                            Diagnostic::error("E0313", format!("a função precisa retornar um valor do tipo `{}`", return_type), self.lexical_info(id))
+                                .with_label("`retornar` sem valor")
+                        );
+                    },
+                }
+            },
+            Statement::Continue |
+            Statement::Break |
+            Statement::Error => {},
+        }
+    }
+
+    /// Position of the variant a case names, reporting names that are not variants of the
+    /// enumeration.
+    fn case_variant(&mut self, enum_id: ScopeDefID, enum_name: &str, case: &MatchCase) -> Option<usize> {
+
+        // an undefined name was already reported by the resolver
+        let symbol = self.resolution.symbol(case.variant)?;
+        if let Symbol::Variant(variant_enum, index) = symbol {
+            if variant_enum == enum_id {
+                return Some(index);
+            }
+        }
+
+        let name = self.ast.get_identifier(case.variant);
+        let lexical_info = self.lexical_info(case.variant);
+        let mut diagnostic = Diagnostic::error("E0326", format!("`{}` não é uma variante de `{}`", name, enum_name), lexical_info)
+            .with_label("variante desconhecida");
+
+        let variants = self.table.variants(enum_id);
+        if let Some(suggestion) = closest_name(name, variants.iter().map(|variant| variant.name.as_str())) {
+            diagnostic = diagnostic.with_suggestion(format!("você quis dizer `{}`?", suggestion), lexical_info, suggestion);
+        }
+
+        self.report(diagnostic);
+        return None;
+    }
+
+    fn check_match(&mut self, value: ExprID, cases: &[MatchCase]) {
+
+        let value_type = self.check_expr(value, None);
+        let (enum_id, enum_name) = match value_type.dereferenced() {
+            Type::Enum{id, name} => (Some(*id), name.clone()),
+            Type::Error => (None, String::new()),
+            other => {
+                let other = other.clone();
+                self.report(
+                    Diagnostic::error("E0325", format!("não é possível escolher entre os casos de um valor do tipo `{}`", other), self.lexical_info(value))
+                        .with_label("não é uma enumeração")
+                        .with_note("`escolha` trata cada variante de uma enumeração, use `se` para outros valores")
+                );
+                (None, String::new())
+            },
+        };
+
+        // the first case of each variant
+        let mut handled: Vec<(usize, IdentifierID)> = Vec::new();
+
+        for case in cases {
+
+            let index = enum_id.and_then(|enum_id| self.case_variant(enum_id, &enum_name, case));
+            let payload = match (enum_id, index) {
+                (Some(enum_id), Some(index)) => self.table.variants(enum_id)[index].payload.clone(),
+                _ => Vec::new(),
+            };
+
+            if let Some(index) = index {
+
+                let name = self.ast.get_identifier(case.variant);
+                if payload.len() != case.bindings.len() {
+                    let label = match payload.len() {
+                        0 => format!("use apenas `caso {}`", name),
+                        _ => "dê um nome para cada valor guardado".to_string(),
+                    };
+                    self.report(
+                        Diagnostic::error("E0327", format!("a variante `{}` guarda {}, mas o caso tem {}", name, count(payload.len(), "valor", "valores"), count(case.bindings.len(), "nome", "nomes")), self.lexical_info(case.variant))
+                            .with_label(label)
+                    );
+                }
+
+                match handled.iter().find(|(handled_index, _)| *handled_index == index) {
+                    Some((_, first)) => {
+                        let first = self.lexical_info(*first);
+                        self.report(
+                            Diagnostic::error("E0328", format!("o caso `{}` foi repetido", name), self.lexical_info(case.variant))
+                                .with_label("este caso nunca é executado")
+                                .with_secondary(first, "primeiro caso")
+                        );
+                    },
+                    None => handled.push((index, case.variant)),
+                }
+            }
+
+            // the values are copies, like the items of a `para cada` without `ref`
+            for (position, binding) in case.bindings.iter().enumerate() {
+                let binding_type = match index {
+                    Some(_) => payload.get(position).cloned().unwrap_or(Type::Error),
+                    None => Type::Error,
+                };
+                self.table.declaration_types.insert(*binding, binding_type);
+            }
+            self.check_block(&case.body);
+        }
+
+        let Some(enum_id) = enum_id else {
+            return;
+        };
+
+        let missing: Vec<&VariantType> = self.table.variants(enum_id).iter().enumerate()
+            .filter(|(index, _)| !handled.iter().any(|(handled_index, _)| handled_index == index))
+            .map(|(_, variant)| variant)
+            .collect();
+
+        let Some(first) = missing.first() else {
+            return;
+        };
+
+        let names: Vec<String> = missing.iter().map(|variant| format!("`{}`", variant.name)).collect();
+        let example = match first.payload.is_empty() {
+            true => format!("caso {} => {{ ... }}", first.name),
+            false => format!("caso {}(...) => {{ ... }}", first.name),
+        };
+        let message = match names.len() {
+            1 => format!("falta o caso da variante {} de `{}`", names[0], enum_name),
+            _ => format!("faltam os casos das variantes {} de `{}`", names.join(", "), enum_name),
+        };
+        self.report(
+            Diagnostic::error("E0329", message, self.lexical_info(value))
+                .with_label("todas as variantes precisam de um caso")
+                .with_note(format!("adicione, por exemplo, `{}`", example))
+        );
+    }
+
+    fn check_function(&mut self, id: ScopeDefID) {
+
+        self.return_type = self.subprogram_return_type(id);
+        self.check_block(self.ast.get_scope_def(id).body());
+    }
+}
+
+/// Assigns a type to every expression and declaration, and checks that operators, calls,
+/// assignments, conditions and returns are used with values of the right type.
+pub fn check_types(ast: &Ast, resolution: &Resolution, catalogue: &TypeCatalogue, diagnostics: &mut Vec<Diagnostic>) -> TypeTable {
+
+    let table = TypeTable{
+        expr_types: HashMap::new(),
+        declaration_types: HashMap::new(),
+        struct_fields: HashMap::new(),
+        enum_variants: HashMap::new(),
+    };
+    let mut checker = TypeChecker{ast, resolution, diagnostics, catalogue, table, type_exprs: HashMap::new(), return_type: Type::Error};
+
+    // every signature is known before the bodies are checked, so calls can come before definitions
+    for scope_def in ast.get_structs() {
+        checker.declare_struct(scope_def);
+    }
+
+    // after the structures, so it is known which of them hold a `ref`
+    for scope_def in ast.get_enums() {
+        checker.declare_enum(scope_def);
+    }
+
+    for scope_def in ast.get_structs() {
+        checker.check_struct_size(scope_def);
+    }
+
+    for scope_def in ast.get_subprograms() {
+        checker.declare_function(scope_def);
+    }
+
+    for scope_def in ast.get_subprograms() {
+        checker.check_function(scope_def);
+    }
+
+    return checker.table;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::resolver::resolve_names;
+    use crate::tso_parser::parse_source;
+
+
+    fn check_with_catalogue(source_code: &str, catalogue: &TypeCatalogue) -> Vec<Diagnostic> {
+
+        let output = parse_source(source_code).unwrap();
+        assert!(!output.has_errors());
+
+        let mut diagnostics = Vec::new();
+        let resolution = resolve_names(&output.ast, &mut diagnostics);
+        assert!(diagnostics.is_empty());
+
+        check_types(&output.ast, &resolution, catalogue, &mut diagnostics);
+        return diagnostics;
+    }
+
+    fn check(source_code: &str) -> Vec<&'static str> {
+
+        let diagnostics = check_with_catalogue(source_code, &TypeCatalogue::new(false));
+        return diagnostics.iter().map(|diagnostic| diagnostic.code).collect();
+    }
+
+    #[test]
+    fn test_well_typed_program() {
+
+        let source_code = r#"
+            func soma(itens: lista<inteiro>) -> inteiro {
+                var total: inteiro = 0;
+                para cada item em itens {
+                    total = total + item;
+                }
+                retornar total;
+            }
+
+            func media(itens: lista<inteiro>, quantidade: decimal) -> decimal {
+                var vazia: lista<texto> = [];
+                se quantidade > 0 e não (quantidade == 0) {
+                    retornar soma(itens) / quantidade;
+                }
+                retornar -1.5;
+            }
+        "#;
+
+        assert!(check(source_code).is_empty());
+    }
+
+    #[test]
+    fn test_expression_types() {
+
+        let source_code = r#"
+            func foo(a: inteiro, b: ref texto) -> nada {
+                var c: decimal = a + 1.5;
+                var d: texto = b + "!";
+                var e: lista<decimal> = [1.0, c];
+            }
+        "#;
+
+        let output = parse_source(source_code).unwrap();
+        let mut diagnostics = Vec::new();
+        let resolution = resolve_names(&output.ast, &mut diagnostics);
+        let table = check_types(&output.ast, &resolution, &TypeCatalogue::new(false), &mut diagnostics);
+        assert!(diagnostics.is_empty());
+
+        let mut types: Vec<String> = table.expr_types.values().map(|found| found.to_string()).collect();
+        types.sort();
+        let mut expected = vec!["inteiro", "decimal", "decimal", "ref texto", "texto", "texto", "decimal", "decimal", "lista<decimal>"];
+        expected.sort();
+        assert_eq!(types, expected);
+    }
+
+    #[test]
+    fn test_unknown_types() {
+
+        let source_code = r#"
+            func foo(a: int) -> lista<inteiro, texto> {
+                var b: inteiro<texto>;
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0301", "E0302", "E0302"]);
+    }
+
+    #[test]
+    fn test_unknown_type_suggestions() {
+
+        let source_code = r#"
+            func foo(a: int, b: inteiros, c: xyz, d: texot, e: Integer, f: lista<boolean>) -> lógico {
+                retornar verdadeiro;
+            }
+        "#;
+
+        let diagnostics = check_with_catalogue(source_code, &TypeCatalogue::new(false));
+        let replacements: Vec<Option<&str>> = diagnostics.iter()
+            .map(|diagnostic| diagnostic.suggestions.first().map(|suggestion| suggestion.replacement.as_str()))
+            .collect();
+        assert_eq!(replacements, vec![Some("inteiro"), Some("inteiro"), None, Some("texto"), Some("inteiro"), Some("lógico")]);
+    }
+
+    #[test]
+    fn test_english_aliases() {
+
+        let source_code = r#"
+            func foo(a: int, b: list<string>) -> bool {
+                var c: float = a + 0.5;
+                retornar c > 1.0;
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0301", "E0301", "E0301", "E0301", "E0301"]);
+        assert!(check_with_catalogue(source_code, &TypeCatalogue::new(true)).is_empty());
+    }
+
+    #[test]
+    fn test_structs() {
+
+        let source_code = r#"
+            estrutura Ponto { x: decimal, y: decimal }
+            estrutura Linha { inicio: Ponto, fim: comp Ponto, nome: texto }
+
+            func comprimento(linha: ref Linha) -> decimal {
+                var l: Linha = Linha { nome: "a", inicio: Ponto { x: 0.0, y: 0.0 }, fim: linha.inicio };
+                retornar linha.fim.x - linha.inicio.x + l.fim.y;
+            }
+        "#;
+
+        assert!(check(source_code).is_empty());
+
+        let source_code = r#"
+            estrutura texto { a: inteiro }
+            estrutura Vazia {}
+            estrutura Repetida { a: inteiro, a: texto }
+            estrutura Lista { proximo: Nó }
+            estrutura Nó { valor: inteiro, resto: Lista, outro: comp Nó }
+            estrutura Par { a: inteiro, b: texto }
+
+            func foo(p: Par, q: Par<inteiro>) -> nada {
+                var a: Par = Par { a: 1, c: 2 };
+                var b: Par = Par { a: 1, a: 2, b: "x" };
+                var c: Par = Par { a: 1 };
+                var d: inteiro = p.b;
+                p.d;
+                Par;
+                foo { a: 1 };
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec![
+            "E0315", "E0321", "E0317", "E0320", "E0320", "E0302",
+            "E0311", "E0316", "E0317", "E0316", "E0304", "E0311", "E0319", "E0318",
+        ]);
+    }
+
+    #[test]
+    fn test_enums() {
+
+        let source_code = r#"
+            estrutura Ponto { x: decimal, y: decimal }
+            enumeração Forma { Círculo(Ponto, decimal), Vazio }
+
+            func área(f: comp Forma) -> decimal {
+                escolha f {
+                    caso Círculo(centro, raio) => { retornar centro.x * raio; }
+                    caso Vazio => { retornar 0.0; }
+                }
+            }
+
+            func foo() -> Forma {
+                var formas: lista<Forma> = [Vazio, Círculo(Ponto { x: 0.0, y: 0.0 }, 1.0)];
+                retornar obter(formas, 0);
+            }
+        "#;
+
+        assert!(check(source_code).is_empty());
+
+        let source_code = r#"
+            enumeração texto { A }
+            enumeração Nada {}
+            enumeração Guarda { Valor(ref inteiro), Outro }
+            enumeração Forma { Círculo(decimal), Vazio, Quadrado(decimal) }
+
+            func foo(f: Forma, n: inteiro) -> nada {
+                var a: Forma = Círculo;
+                var b: Forma = Vazio(1);
+                var c: Forma = Forma;
+                var d: Forma = Círculo(1);
+                Vazio = Vazio;
+                escolha n { caso Vazio => {} }
+                escolha f {
+                    caso Círculo => {}
+                    caso Outro => {}
+                    caso Vazio => { var e: inteiro = 1; }
+                    caso Vazio => {}
+                }
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec![
+            "E0315", "E0322", "E0323", "E0324", "E0324", "E0319", "E0304",
+            "E0312", "E0325", "E0327", "E0326", "E0328", "E0329",
+        ]);
+
+        let diagnostics = check_with_catalogue(source_code, &TypeCatalogue::new(false));
+        let case_error = diagnostics.iter().find(|diagnostic| diagnostic.code == "E0327").unwrap();
+        assert_eq!(case_error.message, "a variante `Círculo` guarda 1 valor, mas o caso tem 0 nomes");
+    }
+
+    #[test]
+    fn test_operator_errors() {
+
+        let source_code = r#"
+            func foo(a: inteiro, b: texto) -> nada {
+                a + b;
+                a e verdadeiro;
+                não a;
+                *a;
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0307", "E0307", "E0307", "E0308"]);
+    }
+
+    #[test]
+    fn test_call_errors() {
+
+        let source_code = r#"
+            func foo(a: inteiro) -> inteiro {
+                foo(1, 2);
+                foo("um");
+                a(1);
+                retornar foo(foo(a));
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0305", "E0304", "E0306"]);
+
+        let diagnostics = check_with_catalogue(source_code, &TypeCatalogue::new(false));
+        assert_eq!(diagnostics[0].message, "`foo` recebe 1 argumento, mas 2 foram passados");
+        assert_eq!(diagnostics[0].primary.message.as_deref(), Some("esperado 1 argumento"));
+    }
+
+    #[test]
+    fn test_statement_errors() {
+
+        let source_code = r#"
+            func foo(a: inteiro) -> inteiro {
+                var b: texto = a;
+                se a { retornar; }
+                enquanto "sim" {}
+                para cada item em a {}
+                1 = a;
+                retornar "a";
+            }
+
+            func bar() -> nada {
+                var c: lista<inteiro> = [1, "dois"];
+                [];
+                retornar 1;
+            }
+        "#;
+
+        let codes = check(source_code);
+        assert_eq!(codes, vec!["E0304", "E0309", "E0313", "E0309", "E0310", "E0312", "E0304", "E0304", "E0303", "E0313"]);
+    }
+
+    #[test]
+    fn test_references() {
+
+        let source_code = r#"
+            func foo(a: mut ref inteiro, b: ref inteiro, c: mut comp lista<inteiro>) -> inteiro {
+                *a = b + 1;
+                *a = *b;
+                var d: mut ref inteiro = b;
+                var e: ref inteiro = a;
+                c = [1, 2];
+                retornar a;
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0304"]);
+    }
+
+    #[test]
+    fn test_builtin_calls() {
+
+        let source_code = r#"
+            procedimento foo(mut a: lista<inteiro>) {
+                escrever(tamanho(a) + 1);
+                adicionar(a, 2);
+                var b: texto = para_texto(1.5) + ler();
+                adicionar(a, "três");
+                tamanho(a, a);
+                escrever;
+            }
+        "#;
+
+        assert_eq!(check(source_code), vec!["E0304", "E0305", "E0314"]);
+    }
+}
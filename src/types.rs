@@ -0,0 +1,166 @@
+// local
+use crate::ast::ScopeDefID;
+
+
+/* -------------------------------------------------------------------------- */
+/*                                    types                                   */
+/* -------------------------------------------------------------------------- */
+
+/// Type of a value, as seen by the semantic passes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Type {
+    Integer,
+    Decimal,
+    Text,
+    Boolean,
+    /// Type of procedures without a return value and of expressions that produce no value.
+    Nothing,
+    List(Box<Type>),
+    Ref{is_mut: bool, inner: Box<Type>},
+    Comp{is_mut: bool, inner: Box<Type>},
+    Function{params: Vec<Type>, return_type: Box<Type>},
+    /// A structure defined by the program, the name is kept for the messages.
+    Struct{id: ScopeDefID, name: String},
+    /// An enumeration defined by the program, the name is kept for the messages.
+    Enum{id: ScopeDefID, name: String},
+    /// Type of expressions whose type could not be determined. An error was already reported
+    /// for them, so it is compatible with every other type to avoid cascading errors.
+    Error,
+}
+
+impl Type {
+
+    pub fn is_error(&self) -> bool {
+        return *self == Type::Error;
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        return matches!(self, Type::Integer | Type::Decimal);
+    }
+
+    /// Type of the value a `ref` or `comp` points to. Other types are returned unchanged.
+    pub fn dereferenced(&self) -> &Type {
+
+        return match self {
+            Type::Ref{inner, ..} | Type::Comp{inner, ..} => inner.dereferenced(),
+            _ => self,
+        };
+    }
+
+    /// Whether a value of type `value` can be stored where a `self` is expected: in a variable
+    /// declaration, an argument or a `retornar`.
+    pub fn accepts(&self, value: &Type) -> bool {
+
+        if self.is_error() || value.is_error() {
+            return true;
+        }
+
+        return match (self, value) {
+            // a `mut ref` can only be created from values that can be changed
+            (Type::Ref{is_mut: true, inner}, Type::Ref{is_mut, inner: value_inner}) |
+            (Type::Ref{is_mut: true, inner}, Type::Comp{is_mut, inner: value_inner}) => {
+                *is_mut && inner.same_as(value_inner)
+            },
+            (Type::Comp{is_mut: true, inner}, Type::Comp{is_mut, inner: value_inner}) => {
+                *is_mut && inner.same_as(value_inner)
+            },
+            (Type::Ref{inner, ..}, Type::Ref{inner: value_inner, ..}) |
+            (Type::Ref{inner, ..}, Type::Comp{inner: value_inner, ..}) |
+            (Type::Comp{inner, ..}, Type::Comp{inner: value_inner, ..}) => {
+                inner.same_as(value_inner)
+            },
+            // a new `comp` is created from the value, and a `ref` points to it
+            (Type::Ref{inner, ..}, value) |
+            (Type::Comp{inner, ..}, value) => inner.same_as(value),
+            // reading a `ref` or `comp` copies the value it points to
+            (expected, Type::Ref{inner, ..}) |
+            (expected, Type::Comp{inner, ..}) => expected.accepts(inner),
+            (expected, value) => expected.same_as(value),
+        };
+    }
+
+    /// Structural equality where `Error` matches anything.
+    pub fn same_as(&self, other: &Type) -> bool {
+
+        return match (self, other) {
+            (Type::Error, _) | (_, Type::Error) => true,
+            (Type::List(a), Type::List(b)) => a.same_as(b),
+            (Type::Ref{is_mut: a_mut, inner: a}, Type::Ref{is_mut: b_mut, inner: b}) |
+            (Type::Comp{is_mut: a_mut, inner: a}, Type::Comp{is_mut: b_mut, inner: b}) => a_mut == b_mut && a.same_as(b),
+            (Type::Function{params: a_params, return_type: a_return}, Type::Function{params: b_params, return_type: b_return}) => {
+                a_params.len() == b_params.len()
+                    && a_params.iter().zip(b_params).all(|(a, b)| a.same_as(b))
+                    && a_return.same_as(b_return)
+            },
+            (a, b) => a == b,
+        };
+    }
+}
+
+impl std::fmt::Display for Type {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+        return match self {
+            Type::Integer => write!(f, "inteiro"),
+            Type::Decimal => write!(f, "decimal"),
+            Type::Text => write!(f, "texto"),
+            Type::Boolean => write!(f, "lógico"),
+            Type::Nothing => write!(f, "nada"),
+            Type::List(item) => write!(f, "lista<{}>", item),
+            Type::Ref{is_mut, inner} => write!(f, "{}ref {}", if *is_mut { "mut " } else { "" }, inner),
+            Type::Comp{is_mut, inner} => write!(f, "{}comp {}", if *is_mut { "mut " } else { "" }, inner),
+            Type::Function{params, return_type} => {
+                let params: Vec<String> = params.iter().map(|param| param.to_string()).collect();
+                write!(f, "função({}) -> {}", params.join(", "), return_type)
+            },
+            Type::Struct{name, ..} | Type::Enum{name, ..} => write!(f, "{}", name),
+            Type::Error => write!(f, "?"),
+        };
+    }
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+
+    fn reference(is_mut: bool, inner: Type) -> Type {
+        return Type::Ref{is_mut, inner: Box::new(inner)};
+    }
+
+    fn comp(is_mut: bool, inner: Type) -> Type {
+        return Type::Comp{is_mut, inner: Box::new(inner)};
+    }
+
+    #[test]
+    fn test_display() {
+
+        assert_eq!(Type::List(Box::new(reference(true, Type::Boolean))).to_string(), "lista<mut ref lógico>");
+        let function = Type::Function{params: vec![Type::Integer, Type::Text], return_type: Box::new(Type::Nothing)};
+        assert_eq!(function.to_string(), "função(inteiro, texto) -> nada");
+    }
+
+    #[test]
+    fn test_accepts() {
+
+        assert!(Type::Integer.accepts(&Type::Integer));
+        assert!(!Type::Integer.accepts(&Type::Decimal));
+        assert!(Type::Integer.accepts(&reference(false, Type::Integer)));
+        assert!(Type::Integer.accepts(&Type::Error));
+
+        assert!(reference(false, Type::Integer).accepts(&Type::Integer));
+        assert!(reference(false, Type::Integer).accepts(&reference(true, Type::Integer)));
+        assert!(!reference(true, Type::Integer).accepts(&reference(false, Type::Integer)));
+        assert!(reference(true, Type::Integer).accepts(&comp(true, Type::Integer)));
+
+        assert!(comp(false, Type::Text).accepts(&Type::Text));
+        assert!(!comp(false, Type::Text).accepts(&reference(false, Type::Text)));
+    }
+}
@@ -0,0 +1,532 @@
+// std
+use std::io::{BufRead, Write};
+
+// local
+use crate::bytecode::{Bytecode, Instruction};
+use crate::numbers::Integer;
+use crate::runtime::*;
+
+
+/* -------------------------------------------------------------------------- */
+/*                               virtual machine                              */
+/* -------------------------------------------------------------------------- */
+
+/// A running subprogram.
+struct CallFrame {
+    chunk: usize,
+    ip: usize,
+    /// Index of the first local of the subprogram in `Vm::locals`.
+    base: usize,
+}
+
+struct Vm<'a> {
+    bytecode: &'a Bytecode,
+    stack: Vec<Value>,
+    /// Storage of the locals of every running subprogram, created when first used.
+    locals: Vec<Option<Cell>>,
+    frames: Vec<CallFrame>,
+    input: &'a mut dyn BufRead,
+    output: &'a mut dyn Write,
+}
+
+impl<'a> Vm<'a> {
+
+    fn pop(&mut self) -> Value {
+        return self.stack.pop().expect("instructions only pop values they pushed");
+    }
+
+    /// Storage of a local of the running subprogram, a variable can receive a value before its
+    /// declaration runs.
+    fn local(&mut self, base: usize, slot: usize) -> Cell {
+        return self.locals[base + slot].get_or_insert_with(|| new_cell(Value::Nothing)).clone();
+    }
+
+    /// Reads the items of a `para cada` kept in a slot, without copying them.
+    fn read_items<T>(&self, base: usize, slot: usize, read: impl FnOnce(&[Cell]) -> T) -> T {
+
+        return match &*self.locals[base + slot].as_ref().expect("items are kept before they are read").borrow() {
+            Value::List(items) => read(items),
+            other => unreachable!("items are kept in a list, found {:?}", other),
+        };
+    }
+
+    fn call(&mut self, function: usize, arg_count: usize) {
+
+        let chunk = &self.bytecode.chunks[function];
+        let base = self.locals.len();
+        self.locals.resize(base + chunk.local_count, None);
+
+        // the arguments are the first locals
+        let args = self.stack.split_off(self.stack.len() - arg_count);
+        for (slot, arg) in args.into_iter().enumerate() {
+            self.locals[base + slot] = Some(new_cell(arg));
+        }
+
+        self.frames.push(CallFrame{chunk: function, ip: 0, base});
+    }
+
+    fn run(&mut self) -> RuntimeResult<()> {
+
+        let bytecode = self.bytecode;
+
+        while let Some(frame) = self.frames.last_mut() {
+
+            let chunk = &bytecode.chunks[frame.chunk];
+            let ip = frame.ip;
+            let base = frame.base;
+            frame.ip += 1;
+
+            let error = |kind: RuntimeErrorKind| RuntimeError{kind, lexical_info: chunk.lexical_infos[ip]};
+
+            match chunk.code[ip] {
+                Instruction::Constant(index) => self.stack.push(chunk.constants[index].clone()),
+                Instruction::Nothing => self.stack.push(Value::Nothing),
+                Instruction::LoadLocal(slot) => {
+                    let value = self.local(base, slot).borrow().clone();
+                    self.stack.push(value);
+                },
+                Instruction::LocalRef(slot) => {
+                    let storage = self.local(base, slot);
+                    self.stack.push(Value::Ref(storage));
+                },
+                Instruction::StoreLocal(slot) => {
+                    let value = self.pop();
+                    *self.local(base, slot).borrow_mut() = value;
+                },
+                Instruction::DeclareLocal(slot) => {
+                    let value = self.pop();
+                    self.locals[base + slot] = Some(new_cell(value));
+                },
+                Instruction::Deref => {
+                    let Value::Ref(target) = self.pop() else { unreachable!("the type checker only allows `*` on references") };
+                    let value = target.borrow().clone();
+                    self.stack.push(value);
+                },
+                Instruction::Dereference => {
+                    let value = self.pop().dereferenced();
+                    self.stack.push(value);
+                },
+                Instruction::StoreRef => {
+                    let Value::Ref(target) = self.pop() else { unreachable!("values are only stored through references") };
+                    let value = self.pop();
+                    *target.borrow_mut() = value;
+                },
+                Instruction::Box => {
+                    let value = self.pop();
+                    self.stack.push(Value::Ref(new_cell(value)));
+                },
+                Instruction::MakeList(count) => {
+                    let items = self.stack.split_off(self.stack.len() - count);
+                    self.stack.push(Value::List(items.into_iter().map(new_cell).collect()));
+                },
+                Instruction::MakeStruct(index) => {
+                    let layout = bytecode.structs[index].clone();
+                    let fields = self.stack.split_off(self.stack.len() - layout.fields.len());
+                    self.stack.push(Value::Struct{layout, fields: fields.into_iter().map(new_cell).collect()});
+                },
+                Instruction::FieldRef(index) => {
+                    let Value::Ref(structure) = self.pop() else { unreachable!("fields are reached through a reference") };
+                    let field = match &*follow_refs(structure).borrow() {
+                        Value::Struct{fields, ..} => fields[index].clone(),
+                        other => unreachable!("the type checker only allows fields of structures, found {:?}", other),
+                    };
+                    self.stack.push(Value::Ref(field));
+                },
+                Instruction::MakeVariant{enumeration, variant, count} => {
+                    let payload = self.stack.split_off(self.stack.len() - count);
+                    let layout = bytecode.enums[enumeration].clone();
+                    self.stack.push(Value::Enum{layout, variant, payload: payload.into_iter().map(new_cell).collect()});
+                },
+                Instruction::Tag => {
+                    let variant = match self.pop().dereferenced() {
+                        Value::Enum{variant, ..} => variant,
+                        other => unreachable!("the type checker only allows cases of enumerations, found {:?}", other),
+                    };
+                    self.stack.push(Value::Integer(Integer::from(variant)));
+                },
+                Instruction::Payload(index) => {
+                    let value = match self.pop().dereferenced() {
+                        Value::Enum{payload, ..} => payload[index].borrow().clone(),
+                        other => unreachable!("the type checker only allows cases of enumerations, found {:?}", other),
+                    };
+                    self.stack.push(value);
+                },
+                Instruction::Unary(oprt) => {
+                    let operand = self.pop();
+                    self.stack.push(unary_oprt(oprt, operand).map_err(error)?);
+                },
+                Instruction::Binary(oprt) => {
+                    let right = self.pop();
+                    let left = self.pop();
+                    self.stack.push(binary_oprt(oprt, left, right).map_err(error)?);
+                },
+                Instruction::Jump(target) => self.jump(target),
+                Instruction::JumpIfFalse(target) => {
+                    if !self.pop().as_boolean() {
+                        self.jump(target);
+                    }
+                },
+                Instruction::Call{function, arg_count} => {
+                    if self.frames.len() >= MAX_CALL_DEPTH {
+                        return Err(error(RuntimeErrorKind::CallDepthExceeded));
+                    }
+                    self.call(function, arg_count);
+                },
+                Instruction::CallBuiltin{builtin, arg_count} => {
+                    let args = self.stack.split_off(self.stack.len() - arg_count);
+                    let result = call_builtin(builtin, &args, self.input, self.output).map_err(error)?;
+                    self.stack.push(result);
+                },
+                Instruction::Return => {
+                    self.frames.pop();
+                    self.locals.truncate(base);
+                },
+                Instruction::Pop => {
+                    self.pop();
+                },
+                Instruction::Items => {
+                    let Value::Ref(iterated) = self.pop() else { unreachable!("`para cada` starts with a reference to the value") };
+                    self.stack.push(Value::List(iteration_items(iterated)));
+                },
+                Instruction::ItemCount(slot) => {
+                    let count = self.read_items(base, slot, |items| items.len());
+                    self.stack.push(Value::Integer(Integer::from(count)));
+                },
+                Instruction::ItemRef(slot) => {
+                    let index = self.pop().as_integer().to_usize().expect("item positions are never negative");
+                    let item = self.read_items(base, slot, |items| items[index].clone());
+                    self.stack.push(Value::Ref(item));
+                },
+            }
+        }
+
+        return Ok(());
+    }
+
+    fn jump(&mut self, target: usize) {
+        self.frames.last_mut().expect("jumps only run inside a subprogram").ip = target;
+    }
+}
+
+/// Runs a compiled program from its entry point, reading and writing the terminal through
+/// `input` and `output`.
+pub fn execute(bytecode: &Bytecode, input: &mut dyn BufRead, output: &mut dyn Write) -> RuntimeResult<()> {
+
+    let entry_point = bytecode.entry_point.expect("programs are only executed when they have an entry point");
+    let mut vm = Vm{bytecode, stack: Vec::new(), locals: Vec::new(), frames: Vec::new(), input, output};
+
+    vm.call(entry_point, 0);
+    let result = vm.run();
+
+    let lexical_info = bytecode.chunks[entry_point].lexical_infos.last().copied();
+    vm.output.flush().map_err(|error| RuntimeError{
+        kind: RuntimeErrorKind::Io(error.to_string()),
+        lexical_info: lexical_info.expect("every chunk has code"),
+    })?;
+
+    return result;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::analysis::{analyze, Analysis, AnalysisSettings};
+    use crate::ast::Ast;
+    use crate::bytecode::compile;
+    use crate::interpreter;
+    use crate::ir;
+    use crate::tso_parser::parse_source;
+
+
+    fn analyzed(source_code: &str) -> (Ast, Analysis) {
+
+        let output = parse_source(source_code).unwrap();
+        assert!(!output.has_errors());
+
+        let analysis = analyze(&output.ast, &AnalysisSettings::default());
+        assert!(!analysis.has_errors());
+        return (output.ast, analysis);
+    }
+
+    fn run_vm(ast: &Ast, analysis: &Analysis, input: &str) -> RuntimeResult<String> {
+
+        let bytecode = compile(&ir::lower(ast, &analysis.resolution, &analysis.types));
+        let mut written = Vec::new();
+        execute(&bytecode, &mut input.as_bytes(), &mut written)?;
+        return Ok(String::from_utf8(written).unwrap());
+    }
+
+    fn run_interpreter(ast: &Ast, analysis: &Analysis, input: &str) -> RuntimeResult<String> {
+
+        let entry_point = interpreter::find_entry_point(ast).unwrap();
+        let mut written = Vec::new();
+        interpreter::execute(ast, &analysis.resolution, &analysis.types, entry_point, &mut input.as_bytes(), &mut written)?;
+        return Ok(String::from_utf8(written).unwrap());
+    }
+
+    /// Runs the program on the virtual machine, checking that the interpreter gives the same result.
+    fn run_with_input(source_code: &str, input: &str) -> RuntimeResult<String> {
+
+        let (source_code, input) = (source_code.to_string(), input.to_string());
+
+        // deep recursions need more stack in the interpreter than the test threads have
+        let execution = std::thread::Builder::new().stack_size(512 * 1024 * 1024).spawn(move || {
+            let (ast, analysis) = analyzed(&source_code);
+            let result = run_vm(&ast, &analysis, &input);
+            assert_eq!(result, run_interpreter(&ast, &analysis, &input));
+            return result;
+        });
+
+        return execution.unwrap().join().unwrap();
+    }
+
+    fn run(source_code: &str) -> RuntimeResult<String> {
+        return run_with_input(source_code, "");
+    }
+
+    #[test]
+    fn test_expressions_and_calls() {
+
+        let source_code = r#"
+            func fatorial(n: inteiro) -> inteiro {
+                se n <= 1 { retornar 1; }
+                retornar n * fatorial(n - 1);
+            }
+
+            procedimento principal() {
+                escrever(fatorial(10));
+                escrever(1 + 2 * 3 - 10 / 3);
+                escrever(-5 < 3 e não (1 == 1.0) ou "a" + "b" == "ab");
+                escrever(falso e 1 / 0 == 1);
+                escrever(para_texto([[1], []]) + "!");
+                escrever("Olá, " + ler());
+            }
+        "#;
+
+        assert_eq!(run_with_input(source_code, "Ana\n").unwrap(), "3628800\n4\nverdadeiro\nfalso\n[[1], []]!\nOlá, Ana\n");
+    }
+
+    #[test]
+    fn test_loops() {
+
+        let source_code = r#"
+            procedimento principal() {
+                var mut i: inteiro = 0;
+                var mut vistos: lista<inteiro> = [];
+                enquanto verdadeiro {
+                    i = i + 1;
+                    se i == 3 { continuar; }
+                    se i == 6 { parar; }
+                    adicionar(vistos, i);
+                }
+                para cada item em vistos {
+                    se item == 2 { continuar; }
+                    para cada letra em "ab" { escrever(para_texto(item) + letra); }
+                    se item == 4 { parar; }
+                }
+            }
+        "#;
+
+        assert_eq!(run(source_code).unwrap(), "1a\n1b\n4a\n4b\n");
+    }
+
+    #[test]
+    fn test_else_if() {
+
+        let source_code = r#"
+            func classificar(n: inteiro) -> texto {
+                se n < 0 {
+                    retornar "negativo";
+                } senão se n == 0 {
+                    retornar "zero";
+                } senão se n < 10 {
+                    retornar "pequeno";
+                }
+                retornar "grande";
+            }
+
+            procedimento principal() {
+                para cada n em [-5, 0, 3, 42] {
+                    escrever(classificar(n));
+                }
+                var mut total: inteiro = 0;
+                para cada n em [1, 2, 3, 4, 5, 6] {
+                    se n / 3 * 3 == n { total = total + 100; }
+                    senão se n / 2 * 2 == n { total = total + 10; }
+                    senão { total = total + 1; }
+                }
+                escrever(total);
+            }
+        "#;
+
+        assert_eq!(run(source_code).unwrap(), "negativo\nzero\npequeno\ngrande\n222\n");
+    }
+
+    #[test]
+    fn test_references() {
+
+        let source_code = r#"
+            procedimento dobrar(itens: mut ref lista<inteiro>) {
+                para cada mut ref item em itens { *item = *item * 2; }
+            }
+
+            procedimento principal() {
+                a = 1;
+                var mut a: inteiro;
+                var mut b: inteiro = 10;
+                var mut r: mut ref inteiro = a;
+                *r = 2;
+                r = b;
+                *r = r + 1;
+
+                var mut itens: lista<inteiro> = [1, 2];
+                var cópia: lista<inteiro> = itens;
+                dobrar(itens);
+
+                var c: comp inteiro = 5;
+                var d: comp inteiro = c;
+                escrever([a, b, d]);
+                escrever(itens);
+                escrever(cópia);
+            }
+        "#;
+
+        assert_eq!(run(source_code).unwrap(), "[2, 11, 5]\n[2, 4]\n[1, 2]\n");
+    }
+
+    #[test]
+    fn test_structs() {
+
+        let source_code = r#"
+            estrutura Ponto { x: decimal, y: decimal }
+            estrutura Caixa { canto: Ponto, contador: mut comp inteiro, alvo: mut ref inteiro }
+
+            procedimento mover(mut p: mut ref Ponto) {
+                p.x = p.x + 1.0;
+            }
+
+            procedimento principal() {
+                var mut n: inteiro = 1;
+                var mut a: Caixa = Caixa { alvo: n, canto: Ponto { x: 1.0, y: 2.0 }, contador: 5 };
+                var mut b: Caixa = a;
+                b.canto.y = 9.0;
+                *b.contador = 6;
+                *b.alvo = 7;
+                mover(a.canto);
+                escrever(a);
+                escrever(b.canto);
+                escrever(n);
+                escrever(a.canto == Ponto { x: 2.0, y: 2.0 });
+                escrever(b.canto == a.canto);
+            }
+        "#;
+
+        let expected = "Caixa { canto: Ponto { x: 2.0, y: 2.0 }, contador: 6, alvo: 7 }\nPonto { x: 1.0, y: 9.0 }\n7\nverdadeiro\nfalso\n";
+        assert_eq!(run(source_code).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_enums() {
+
+        let source_code = r#"
+            enumeração Lista { Nó(inteiro, Lista), Fim }
+            enumeração Evento { Clique(inteiro, inteiro), Tecla(texto), Contador(mut comp inteiro), Sair }
+
+            func soma(lista: Lista) -> inteiro {
+                escolha lista {
+                    caso Nó(valor, resto) => { retornar valor + soma(resto); }
+                    caso Fim => { retornar 0; }
+                }
+            }
+
+            procedimento principal() {
+                escrever(soma(Nó(1, Nó(2, Nó(3, Fim)))));
+
+                var mut n: mut comp inteiro = 0;
+                var eventos: lista<Evento> = [Tecla("a"), Clique(1, 2), Contador(n), Contador(n), Sair, Tecla("b")];
+                para cada evento em eventos {
+                    escolha evento {
+                        caso Clique(x, y) => { escrever("clique em {x}, {y}"); }
+                        caso Tecla(letra) => {
+                            se letra == "a" { continuar; }
+                            escrever(letra);
+                        }
+                        caso Contador(c) => { *c = c + 1; }
+                        caso Sair => { parar; }
+                    }
+                }
+                escrever(n);
+                escrever(eventos);
+                escrever(Clique(1, 2) == Clique(1, 2));
+                escrever(Clique(1, 2) != Clique(2, 1));
+            }
+        "#;
+
+        let expected = "6\nclique em 1, 2\n2\n[Tecla(\"a\"), Clique(1, 2), Contador(2), Contador(2), Sair, Tecla(\"b\")]\nverdadeiro\nverdadeiro\n";
+        assert_eq!(run(source_code).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_runtime_errors() {
+
+        let codes = [
+            "procedimento principal() { var a: inteiro = 0; escrever(1 / a); }",
+            "procedimento principal() { escrever(obter([1, 2], 2)); }",
+            "func f(a: inteiro) -> inteiro { retornar f(a); } procedimento principal() { f(1); }",
+        ].map(|source_code| run(source_code).unwrap_err().to_diagnostic().code);
+
+        assert_eq!(codes, ["E0701", "E0702", "E0704"]);
+    }
+
+    /// Compares the speed of the virtual machine and of the interpreter, run it with
+    /// `cargo test --release vm::tests::bench -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn bench_against_interpreter() {
+
+        let source_code = r#"
+            func fibonacci(n: inteiro) -> inteiro {
+                se n < 2 { retornar n; }
+                retornar fibonacci(n - 1) + fibonacci(n - 2);
+            }
+
+            procedimento principal() {
+                var mut total: inteiro = 0;
+                var mut i: inteiro = 0;
+                enquanto i < 200000 {
+                    se i / 2 * 2 == i { total = total + i; } senão { total = total - 1; }
+                    i = i + 1;
+                }
+                var mut itens: lista<inteiro> = [];
+                para cada letra em "abcdefghijklmnopqrstuvwxyz" { adicionar(itens, tamanho(letra)); }
+                escrever(total + tamanho(itens));
+                escrever(fibonacci(22));
+            }
+        "#;
+
+        let (ast, analysis) = analyzed(source_code);
+
+        let start = std::time::Instant::now();
+        let interpreted = std::thread::Builder::new().stack_size(512 * 1024 * 1024)
+            .spawn(move || {
+                let result = run_interpreter(&ast, &analysis, "");
+                (result, ast, analysis)
+            })
+            .unwrap().join().unwrap();
+        let interpreter_time = start.elapsed();
+        let (interpreted, ast, analysis) = interpreted;
+
+        let start = std::time::Instant::now();
+        let executed = run_vm(&ast, &analysis, "");
+        let vm_time = start.elapsed();
+
+        assert_eq!(executed, interpreted);
+        println!("interpreter: {:?}, vm: {:?}, speedup: {:.1}x", interpreter_time, vm_time, interpreter_time.as_secs_f64() / vm_time.as_secs_f64());
+    }
+}
@@ -0,0 +1,1225 @@
+// std
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+// local
+use crate::ast::{BinaryOprt, LexicalInfo, UnaryOprt};
+use crate::builtins::Builtin;
+use crate::c_backend::mangle;
+use crate::ir::{BlockID, Constant, Function, FunctionID, InstructionKind, LocalID, Operand, Program, Temp, Terminator, ENTRY_BLOCK};
+use crate::numbers::{Decimal, Integer};
+use crate::types::Type;
+
+// external
+use anyhow::{Context, Result};
+
+
+/* -------------------------------------------------------------------------- */
+/*                                   runtime                                  */
+/* -------------------------------------------------------------------------- */
+
+/// First fields of every generated module, with the imports of the host and the runtime functions.
+pub const RUNTIME: &str = include_str!("../runtime/wasm/ila_runtime.wat");
+
+/// Host of the generated modules in browsers.
+pub const BROWSER_HOST: &str = include_str!("../runtime/wasm/ila.js");
+
+/// Where the data of the program starts, after the data of the runtime.
+const PROGRAM_DATA_START: u32 = 1024;
+
+/// Descriptors of the types that are not references, placed in memory by the runtime.
+fn builtin_descriptor(ty: &Type) -> Option<u32> {
+
+    return match ty {
+        Type::Integer => Some(8),
+        Type::Decimal => Some(16),
+        Type::Text => Some(24),
+        Type::Boolean => Some(32),
+        Type::Nothing => Some(40),
+        Type::List(_) => Some(48),
+        _ => None,
+    };
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                 wasm types                                 */
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum WasmType {
+    I32,
+    I64,
+}
+
+impl WasmType {
+
+    fn name(self) -> &'static str {
+
+        return match self {
+            WasmType::I32 => "i32",
+            WasmType::I64 => "i64",
+        };
+    }
+}
+
+fn wasm_type(ty: &Type) -> WasmType {
+
+    return match ty {
+        Type::Integer => WasmType::I64,
+        Type::Decimal | Type::Text | Type::Boolean | Type::Nothing | Type::List(_) | Type::Ref{..} | Type::Comp{..} => WasmType::I32,
+        Type::Struct{..} | Type::Enum{..} => unreachable!("`compilar` rejects programs with structures and enumerations"),
+        Type::Function{..} | Type::Error => unreachable!("values of type `{}` are not generated", ty),
+    };
+}
+
+fn is_reference(ty: &Type) -> bool {
+    return matches!(ty, Type::Ref{..} | Type::Comp{..});
+}
+
+/// A WAT string with the bytes, for data segments.
+fn data_string(bytes: &[u8]) -> String {
+
+    let mut string = String::from("\"");
+    for byte in bytes {
+        match byte {
+            b' '..=b'~' if *byte != b'"' && *byte != b'\\' => string.push(*byte as char),
+            _ => write!(string, "\\{:02x}", byte).expect("writing to a string does not fail"),
+        }
+    }
+    string.push('"');
+
+    return string;
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                             structured control                             */
+/* -------------------------------------------------------------------------- */
+
+/// What is left to generate of the structured code of a subprogram, from "Beyond Relooper", by
+/// Norman Ramsey: each block is generated inside the block that dominates it, a block reached by
+/// more than one edge is placed right after a `block` its predecessors leave with `br`, and the
+/// start of a loop is inside a `loop` the edges that go back to it continue with `br`.
+enum Structure {
+    /// A block with the blocks it dominates.
+    Tree(BlockID),
+    /// The code of a block, inside `block`s followed by the blocks it dominates that are reached
+    /// by more than one edge, the last one outermost.
+    Within(BlockID, Vec<BlockID>),
+    /// An edge between two blocks.
+    Edge(BlockID, BlockID),
+    Else,
+    End,
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                                  generator                                 */
+/* -------------------------------------------------------------------------- */
+
+/// The operand whose value the instruction pushes first, before anything else.
+fn first_pushed(kind: &InstructionKind) -> Option<&Operand> {
+
+    return match kind {
+        InstructionKind::Copy{value, ..} |
+        InstructionKind::StoreLocal{value, ..} |
+        InstructionKind::Store{value, ..} |
+        InstructionKind::Dereference{value, ..} |
+        InstructionKind::Box{value, ..} => Some(value),
+        InstructionKind::DeclareLocal{value, ..} => value.as_ref(),
+        InstructionKind::Load{pointer, ..} => Some(pointer),
+        InstructionKind::Unary{operand, ..} => Some(operand),
+        InstructionKind::Binary{left, ..} => Some(left),
+        InstructionKind::Call{args, ..} | InstructionKind::CallBuiltin{args, ..} => args.first(),
+        InstructionKind::Items{iterated, ..} => Some(iterated),
+        InstructionKind::ItemCount{items, ..} | InstructionKind::ItemRef{items, ..} => Some(items),
+        _ => None,
+    };
+}
+
+/// Temporaries read once, by the value the next instruction of the block pushes first: they stay
+/// on the stack of the machine instead of a wasm local, which keeps the frames of deep recursions
+/// small.
+fn stacked_temps(function: &Function, uses: &[usize]) -> Vec<bool> {
+
+    let mut stacked = vec![false; function.temps.len()];
+    for block in &function.blocks {
+        for (index, instruction) in block.instructions.iter().enumerate() {
+            let dest = match instruction.kind {
+                // the list is pushed again for each item
+                InstructionKind::MakeList{..} => continue,
+                _ => match instruction.kind.dest() {
+                    Some(dest) => dest,
+                    None => continue,
+                },
+            };
+            let next = match block.instructions.get(index + 1) {
+                Some(next) => first_pushed(&next.kind),
+                None => match &block.terminator {
+                    Terminator::Branch{condition, ..} => Some(condition),
+                    Terminator::Return(value) => Some(value),
+                    Terminator::Jump(_) => None,
+                },
+            };
+            stacked[dest.0] = uses[dest.0] == 1 && next == Some(&Operand::Temp(dest));
+        }
+    }
+
+    return stacked;
+}
+
+struct Generator<'a> {
+    program: &'a Program,
+    /// Data segments of the program, by address.
+    data: Vec<(u32, Vec<u8>)>,
+    data_end: u32,
+    /// Addresses of the descriptors of `ref` and `comp` types, by their kind and inner type.
+    descriptors: HashMap<(u32, u32), u32>,
+    /// Addresses of the text constants, by their content.
+    texts: HashMap<String, u32>,
+    /// Addresses of the big integer and decimal constants, by their type and value.
+    numbers: HashMap<(bool, String), u32>,
+    /* ---- current subprogram ---- */
+    function: &'a Function,
+    code: String,
+    indent: usize,
+    /// Locals stored in memory instead of a wasm local, because some `ref` points to them.
+    boxed: Vec<bool>,
+    /// How many times each temporary is read.
+    uses: Vec<usize>,
+    stacked: Vec<bool>,
+}
+
+impl<'a> Generator<'a> {
+
+    fn line(&mut self, line: &str) {
+
+        for _ in 0..self.indent {
+            self.code.push_str("  ");
+        }
+        self.code.push_str(line);
+        self.code.push('\n');
+    }
+
+    /// Starts a `block`, `loop` or `if`, whose instructions are indented until `close`.
+    fn open(&mut self, line: &str) {
+
+        self.line(line);
+        self.indent += 1;
+    }
+
+    fn close(&mut self) {
+
+        self.indent -= 1;
+        self.line("end");
+    }
+
+    /// Pushes `line, column` of an instruction, for the runtime errors.
+    fn location(&mut self, lexical_info: &LexicalInfo) {
+
+        self.line(&format!("i32.const {}", lexical_info.start.line + 1));
+        self.line(&format!("i32.const {}", lexical_info.start.column + 1));
+    }
+
+    fn function_name(&self, id: FunctionID) -> String {
+        return format!("$f_{}", mangle(&self.program.functions[id.0].name));
+    }
+
+    /// Wasm name of a local, whose name is unique in its subprogram.
+    fn local_name(&self, local: LocalID) -> String {
+        return format!("$v_{}", mangle(&self.function.locals[local.0].name));
+    }
+
+    fn add_data(&mut self, bytes: Vec<u8>) -> u32 {
+
+        let address = (self.data_end + 7) & !7;
+        self.data_end = address + bytes.len() as u32;
+        self.data.push((address, bytes));
+        return address;
+    }
+
+    /// Address of a descriptor of the type, `{kind, descriptor of the inner type}`.
+    fn descriptor(&mut self, ty: &Type) -> u32 {
+
+        if let Some(address) = builtin_descriptor(ty) {
+            return address;
+        }
+
+        let (kind, inner) = match ty {
+            Type::Ref{inner, ..} => (6, inner),
+            Type::Comp{inner, ..} => (7, inner),
+            other => unreachable!("values of type `{}` are not generated", other),
+        };
+
+        let inner = self.descriptor(inner);
+        if let Some(address) = self.descriptors.get(&(kind, inner)) {
+            return *address;
+        }
+
+        let mut bytes = Vec::with_capacity(8);
+        bytes.extend_from_slice(&kind.to_le_bytes());
+        bytes.extend_from_slice(&inner.to_le_bytes());
+        let address = self.add_data(bytes);
+        self.descriptors.insert((kind, inner), address);
+        return address;
+    }
+
+    /// Address of a text with the content, `{length, bytes}`.
+    fn text(&mut self, value: &str) -> u32 {
+
+        if let Some(address) = self.texts.get(value) {
+            return *address;
+        }
+
+        let mut bytes = Vec::with_capacity(value.len() + 4);
+        bytes.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(value.as_bytes());
+        let address = self.add_data(bytes);
+        self.texts.insert(value.to_string(), address);
+        return address;
+    }
+
+    /// The i64 of an integer, which is the address of a big integer `{length, negative, limbs}`
+    /// past -2^63 when the value is below -2^63 + 2^32 or does not fit.
+    fn integer(&mut self, value: &Integer) -> i64 {
+
+        if let Some(value) = value.to_i64().filter(|value| *value >= i64::MIN + (1 << 32)) {
+            return value;
+        }
+
+        let key = (false, value.to_string());
+        let address = match self.numbers.get(&key) {
+            Some(address) => *address,
+            None => {
+                let (negative, limbs) = value.to_limbs();
+                let mut bytes = Vec::with_capacity(8 + limbs.len() * 4);
+                bytes.extend_from_slice(&(limbs.len() as u32).to_le_bytes());
+                bytes.extend_from_slice(&(negative as u32).to_le_bytes());
+                for limb in limbs {
+                    bytes.extend_from_slice(&limb.to_le_bytes());
+                }
+                let address = self.add_data(bytes);
+                self.numbers.insert(key, address);
+                address
+            },
+        };
+
+        return i64::MIN + address as i64;
+    }
+
+    /// Address of a decimal with the value, `{digits, scale}`.
+    fn decimal(&mut self, value: &Decimal) -> u32 {
+
+        let key = (true, value.to_string());
+        if let Some(address) = self.numbers.get(&key) {
+            return *address;
+        }
+
+        let mut bytes = Vec::with_capacity(16);
+        bytes.extend_from_slice(&self.integer(&value.digits()).to_le_bytes());
+        bytes.extend_from_slice(&value.scale().to_le_bytes());
+        bytes.extend_from_slice(&[0; 4]);
+        let address = self.add_data(bytes);
+        self.numbers.insert(key, address);
+        return address;
+    }
+
+    /* ---- values ---- */
+
+    fn push(&mut self, operand: &Operand) {
+
+        match operand {
+            Operand::Temp(temp) if self.stacked[temp.0] => {},
+            Operand::Temp(temp) => self.line(&format!("local.get $t{}", temp.0)),
+            Operand::Constant(Constant::Integer(value)) => {
+                let value = self.integer(value);
+                self.line(&format!("i64.const {}", value));
+            },
+            Operand::Constant(Constant::Decimal(value)) => {
+                let address = self.decimal(value);
+                self.line(&format!("i32.const {}", address));
+            },
+            Operand::Constant(Constant::Text(value)) => {
+                let address = self.text(value);
+                self.line(&format!("i32.const {}", address));
+            },
+            Operand::Constant(Constant::Boolean(value)) => self.line(&format!("i32.const {}", *value as u8)),
+            Operand::Constant(Constant::Nothing) => self.line("i32.const 0"),
+        }
+    }
+
+    /// Pushes an operand for a new owner, which keeps it: a list that is read again is copied.
+    fn push_owned(&mut self, operand: &Operand) {
+
+        self.push(operand);
+        let is_shared = match operand {
+            Operand::Temp(temp) => self.uses[temp.0] > 1,
+            Operand::Constant(_) => false,
+        };
+        if is_shared && matches!(self.function.operand_type(operand), Type::List(_)) {
+            self.line("call $list_copy");
+        }
+    }
+
+    /// Pushes the value after following every reference of an operand, the value of the place
+    /// when it is a list.
+    fn push_dereferenced(&mut self, operand: &Operand) -> Type {
+
+        self.push(operand);
+        let mut ty = self.function.operand_type(operand);
+        while let Type::Ref{inner, ..} | Type::Comp{inner, ..} = ty {
+            self.line(&format!("{}.load", wasm_type(&inner).name()));
+            ty = *inner;
+        }
+
+        return ty;
+    }
+
+    /// Copies the list on the stack, read from a place of the program.
+    fn copy_if_list(&mut self, ty: &Type) {
+
+        if matches!(ty, Type::List(_)) {
+            self.line("call $list_copy");
+        }
+    }
+
+    /// Converts the value of type `ty` on the stack to the 8 bytes it is stored in, as an i64.
+    fn convert_to_bits(&mut self, ty: &Type) {
+
+        match wasm_type(ty) {
+            WasmType::I32 => self.line("i64.extend_i32_u"),
+            WasmType::I64 => {},
+        }
+    }
+
+    fn set(&mut self, dest: Temp) {
+
+        if !self.stacked[dest.0] {
+            self.line(&format!("local.set $t{}", dest.0));
+        }
+    }
+
+    /* ---- instructions ---- */
+
+    fn instruction(&mut self, kind: &InstructionKind, lexical_info: &LexicalInfo) {
+
+        let function = self.function;
+        match kind {
+            InstructionKind::Copy{dest, value} => {
+                self.push_owned(value);
+                self.set(*dest);
+            },
+            InstructionKind::LoadLocal{dest, local} => {
+                let ty = &function.locals[local.0].ty;
+                self.line(&format!("local.get {}", self.local_name(*local)));
+                if self.boxed[local.0] {
+                    self.line(&format!("{}.load", wasm_type(ty).name()));
+                }
+                self.copy_if_list(ty);
+                self.set(*dest);
+            },
+            InstructionKind::StoreLocal{local, value} => {
+                self.push_owned(value);
+                match self.boxed[local.0] {
+                    true => {
+                        self.line(&format!("local.get {}", self.local_name(*local)));
+                        self.line(&format!("call $store_{}", wasm_type(&function.locals[local.0].ty).name()));
+                    },
+                    false => self.line(&format!("local.set {}", self.local_name(*local))),
+                }
+            },
+            InstructionKind::DeclareLocal{local, value} => {
+                // every time the declaration runs, the variable gets new storage, so references
+                // to the old one keep their value
+                let ty = wasm_type(&function.locals[local.0].ty);
+                match value {
+                    Some(value) => self.push_owned(value),
+                    None => self.line(&format!("{}.const 0", ty.name())),
+                }
+                if self.boxed[local.0] {
+                    self.line(&format!("call $box_{}", ty.name()));
+                }
+                self.line(&format!("local.set {}", self.local_name(*local)));
+            },
+            InstructionKind::LocalRef{dest, local} => {
+                self.line(&format!("local.get {}", self.local_name(*local)));
+                self.set(*dest);
+            },
+            InstructionKind::Load{dest, pointer} => {
+                let ty = function.temp_type(*dest);
+                self.push(pointer);
+                self.line(&format!("{}.load", wasm_type(ty).name()));
+                self.copy_if_list(ty);
+                self.set(*dest);
+            },
+            InstructionKind::Store{pointer, value} => {
+                self.push_owned(value);
+                self.push(pointer);
+                self.line(&format!("call $store_{}", wasm_type(&function.operand_type(value)).name()));
+            },
+            InstructionKind::Dereference{dest, value} => {
+                let ty = self.push_dereferenced(value);
+                self.copy_if_list(&ty);
+                self.set(*dest);
+            },
+            InstructionKind::Box{dest, value} => {
+                self.push_owned(value);
+                self.line(&format!("call $box_{}", wasm_type(&function.operand_type(value)).name()));
+                self.set(*dest);
+            },
+            InstructionKind::MakeList{dest, items} => {
+                let item_type = match function.temp_type(*dest) {
+                    Type::List(item_type) => item_type.as_ref().clone(),
+                    other => unreachable!("lists have list types, found {}", other),
+                };
+                let descriptor = self.descriptor(&item_type);
+                self.line(&format!("i32.const {}", descriptor));
+                self.line("call $list_new");
+                self.set(*dest);
+                for item in items {
+                    self.line(&format!("local.get $t{}", dest.0));
+                    self.push_owned(item);
+                    self.convert_to_bits(&item_type);
+                    self.line("call $list_push");
+                }
+            },
+            InstructionKind::Unary{dest, oprt, operand} => {
+                self.push(operand);
+                match (oprt, function.temp_type(*dest)) {
+                    (UnaryOprt::Neg, Type::Integer) => self.line("call $neg"),
+                    (UnaryOprt::Neg, _) => self.line("call $decimal_neg"),
+                    _ => self.line("i32.eqz"),
+                }
+                self.set(*dest);
+            },
+            InstructionKind::Binary{dest, oprt, left, right} => {
+                self.binary(*oprt, left, right, lexical_info);
+                self.set(*dest);
+            },
+            InstructionKind::Call{dest, function: callee, args} => {
+                for arg in args {
+                    self.push_owned(arg);
+                }
+                self.location(lexical_info);
+                self.line("call $enter");
+                self.line(&format!("call {}", self.function_name(*callee)));
+                self.line("call $leave");
+                self.set(*dest);
+            },
+            InstructionKind::CallBuiltin{dest, builtin, args} => self.call_builtin(*dest, *builtin, args, lexical_info),
+            InstructionKind::Items{dest, iterated} => {
+                // lists only grow, so the items the list has when the loop starts stay in it
+                if self.push_dereferenced(iterated) == Type::Text {
+                    self.line("call $text_chars");
+                }
+                self.set(*dest);
+            },
+            InstructionKind::ItemCount{dest, items} => {
+                self.push(items);
+                self.line("call $list_length");
+                self.set(*dest);
+            },
+            InstructionKind::ItemRef{dest, items, index} => {
+                self.push(items);
+                self.push(index);
+                self.line("i32.wrap_i64");
+                self.line("call $list_slot");
+                self.set(*dest);
+            },
+            InstructionKind::MakeStruct{..} |
+            InstructionKind::FieldRef{..} |
+            InstructionKind::MakeVariant{..} |
+            InstructionKind::Tag{..} |
+            InstructionKind::Payload{..} => unreachable!("`compilar` rejects programs with structures and enumerations"),
+        }
+    }
+
+    fn binary(&mut self, oprt: BinaryOprt, left: &Operand, right: &Operand, lexical_info: &LexicalInfo) {
+
+        let left_type = self.function.operand_type(left);
+        let right_type = self.function.operand_type(right);
+
+        // integers mixed with decimals are converted to decimals
+        let both_integers = left_type == Type::Integer && right_type == Type::Integer;
+        let as_decimals = left_type.is_numeric() && !both_integers;
+
+        self.push(left);
+        if as_decimals && left_type == Type::Integer {
+            self.line("call $decimal_of_integer");
+        }
+        self.push(right);
+        if as_decimals && right_type == Type::Integer {
+            self.line("call $decimal_of_integer");
+        }
+
+        // orderings compare the result of comparing the numbers with 0
+        if matches!(oprt, BinaryOprt::Lt | BinaryOprt::Le | BinaryOprt::Gt | BinaryOprt::Ge) {
+            self.line(if both_integers { "call $integer_compare" } else { "call $decimal_compare" });
+            self.line("i32.const 0");
+        }
+
+        match oprt {
+            BinaryOprt::Eq | BinaryOprt::Neq => {
+                match &left_type {
+                    _ if both_integers => self.line("call $integer_equals"),
+                    _ if as_decimals => self.line("call $decimal_equals"),
+                    Type::Text => self.line("call $text_equals"),
+                    Type::List(_) => self.line("call $list_equals"),
+                    _ => self.line("i32.eq"),
+                }
+                if oprt == BinaryOprt::Neq {
+                    self.line("i32.eqz");
+                }
+            },
+            BinaryOprt::Lt => self.line("i32.lt_s"),
+            BinaryOprt::Le => self.line("i32.le_s"),
+            BinaryOprt::Gt => self.line("i32.gt_s"),
+            BinaryOprt::Ge => self.line("i32.ge_s"),
+            BinaryOprt::Add if left_type == Type::Text => self.line("call $text_concat"),
+            BinaryOprt::Div => {
+                self.location(lexical_info);
+                self.line(if both_integers { "call $div" } else { "call $div_decimal" });
+            },
+            BinaryOprt::Add => self.line(if both_integers { "call $add" } else { "call $decimal_add" }),
+            BinaryOprt::Sub => self.line(if both_integers { "call $sub" } else { "call $decimal_sub" }),
+            BinaryOprt::Mul => self.line(if both_integers { "call $mul" } else { "call $decimal_mul" }),
+            BinaryOprt::And | BinaryOprt::Or | BinaryOprt::Assign => unreachable!("`{}` does not evaluate both operands first", oprt.symbol()),
+        }
+    }
+
+    /// Builtins only look at the values their references point to, so the arguments are passed
+    /// after following references.
+    fn call_builtin(&mut self, dest: Temp, builtin: Builtin, args: &[Operand], lexical_info: &LexicalInfo) {
+
+        match builtin {
+            Builtin::Write | Builtin::ToText => {
+                let ty = self.function.operand_type(&args[0]);
+                let descriptor = self.descriptor(&ty);
+                self.push(&args[0]);
+                self.convert_to_bits(&ty);
+                self.line(&format!("i32.const {}", descriptor));
+                match builtin {
+                    Builtin::Write => self.line("call $write"),
+                    _ => {
+                        self.line("call $to_text");
+                        self.set(dest);
+                    },
+                }
+            },
+            Builtin::Read => {
+                self.line("call $read_line");
+                self.set(dest);
+            },
+            Builtin::Length => {
+                match self.push_dereferenced(&args[0]) {
+                    Type::Text => self.line("call $text_length"),
+                    _ => self.line("call $list_length"),
+                }
+                self.set(dest);
+            },
+            Builtin::Append => {
+                let ty = self.function.operand_type(&args[1]);
+                self.push_dereferenced(&args[0]);
+                self.push_owned(&args[1]);
+                self.convert_to_bits(&ty);
+                self.line("call $list_push");
+            },
+            Builtin::Get => {
+                let ty = self.function.temp_type(dest).clone();
+                self.push_dereferenced(&args[0]);
+                self.push(&args[1]);
+                self.location(lexical_info);
+                self.line("call $list_get");
+                self.line(&format!("{}.load", wasm_type(&ty).name()));
+                self.copy_if_list(&ty);
+                self.set(dest);
+            },
+        }
+    }
+
+    /* ---- control ---- */
+
+    /// Generates the blocks of the subprogram as structured code, from the tree of their dominators.
+    fn blocks(&mut self) {
+
+        let function = self.function;
+        let order = function.reverse_postorder();
+        let mut position = vec![usize::MAX; function.blocks.len()];
+        for (index, block) in order.iter().enumerate() {
+            position[block.0] = index;
+        }
+
+        let mut is_loop_start = vec![false; function.blocks.len()];
+        let mut forward_edges = vec![0; function.blocks.len()];
+        for block in &order {
+            for successor in function.block(*block).terminator.successors() {
+                match position[successor.0] > position[block.0] {
+                    true => forward_edges[successor.0] += 1,
+                    false => is_loop_start[successor.0] = true,
+                }
+            }
+        }
+
+        // the blocks each block dominates, in reverse postorder
+        let mut dominated = vec![Vec::new(); function.blocks.len()];
+        let dominators = function.immediate_dominators();
+        for block in &order {
+            if let Some(dominator) = dominators[block.0] {
+                dominated[dominator.0].push(*block);
+            }
+        }
+
+        let mut work = vec![Structure::Tree(ENTRY_BLOCK)];
+        while let Some(structure) = work.pop() {
+            match structure {
+                Structure::Tree(block) => {
+                    let merges = dominated[block.0].iter().copied().filter(|child| forward_edges[child.0] > 1).collect();
+                    if is_loop_start[block.0] {
+                        self.open(&format!("loop $l{}", block.0));
+                        work.push(Structure::End);
+                    }
+                    work.push(Structure::Within(block, merges));
+                },
+                Structure::Within(block, mut merges) => match merges.pop() {
+                    Some(merge) => {
+                        self.open(&format!("block $b{}", merge.0));
+                        work.push(Structure::Tree(merge));
+                        work.push(Structure::End);
+                        work.push(Structure::Within(block, merges));
+                    },
+                    None => {
+                        let block_id = block;
+                        let block = function.block(block_id);
+                        for instruction in &block.instructions {
+                            self.instruction(&instruction.kind, &instruction.lexical_info);
+                        }
+                        match &block.terminator {
+                            Terminator::Jump(target) => work.push(Structure::Edge(block_id, *target)),
+                            Terminator::Branch{condition, then_block, else_block} => {
+                                self.push(condition);
+                                self.open("if");
+                                work.push(Structure::End);
+                                work.push(Structure::Edge(block_id, *else_block));
+                                work.push(Structure::Else);
+                                work.push(Structure::Edge(block_id, *then_block));
+                            },
+                            Terminator::Return(value) => {
+                                self.push_owned(value);
+                                self.line("return");
+                            },
+                        }
+                    },
+                },
+                Structure::Edge(source, target) => {
+                    if position[target.0] <= position[source.0] {
+                        self.line(&format!("br $l{}", target.0));
+                    } else if forward_edges[target.0] > 1 {
+                        self.line(&format!("br $b{}", target.0));
+                    } else {
+                        work.push(Structure::Tree(target));
+                    }
+                },
+                Structure::Else => {
+                    self.indent -= 1;
+                    self.line("else");
+                    self.indent += 1;
+                },
+                Structure::End => self.close(),
+            }
+        }
+    }
+
+    /* ---- subprograms ---- */
+
+    fn subprogram(&mut self, id: FunctionID) -> String {
+
+        let function = &self.program.functions[id.0];
+        self.function = function;
+
+        // a local some `ref` points to is stored in memory
+        self.boxed = vec![false; function.locals.len()];
+        self.uses = vec![0; function.temps.len()];
+        for block in &function.blocks {
+            for instruction in &block.instructions {
+                if let InstructionKind::LocalRef{local, ..} = instruction.kind {
+                    self.boxed[local.0] = true;
+                }
+                for operand in instruction.kind.operands() {
+                    if let Operand::Temp(temp) = operand {
+                        self.uses[temp.0] += 1;
+                    }
+                }
+            }
+            for operand in block.terminator.operands() {
+                if let Operand::Temp(temp) = operand {
+                    self.uses[temp.0] += 1;
+                }
+            }
+        }
+
+        self.stacked = stacked_temps(function, &self.uses);
+
+        self.code.clear();
+        self.indent = 2;
+        for (index, local) in function.locals[..function.param_count].iter().enumerate() {
+            self.line(&format!("local.get $arg{}", index));
+            if self.boxed[index] {
+                self.line(&format!("call $box_{}", wasm_type(&local.ty).name()));
+            }
+            self.line(&format!("local.set {}", self.local_name(LocalID(index))));
+        }
+        self.blocks();
+        // every path ends with `return`
+        self.line("unreachable");
+        let body = std::mem::take(&mut self.code);
+
+        let mut output = format!("  (func {}", self.function_name(id));
+        for (index, local) in function.locals[..function.param_count].iter().enumerate() {
+            write!(output, " (param $arg{} {})", index, wasm_type(&local.ty).name()).expect("writing to a string does not fail");
+        }
+        writeln!(output, " (result {})", wasm_type(&function.return_type).name()).expect("writing to a string does not fail");
+        for (index, local) in function.locals.iter().enumerate() {
+            let ty = match self.boxed[index] {
+                true => WasmType::I32,
+                false => wasm_type(&local.ty),
+            };
+            writeln!(output, "    (local {} {})", self.local_name(LocalID(index)), ty.name()).expect("writing to a string does not fail");
+        }
+        for (index, ty) in function.temps.iter().enumerate().filter(|(index, _)| !self.stacked[*index]) {
+            writeln!(output, "    (local $t{} {})", index, wasm_type(ty).name()).expect("writing to a string does not fail");
+        }
+        output.push_str(&body);
+        output.push_str("  )\n");
+
+        return output;
+    }
+}
+
+/// Generates a WebAssembly module in the text format from a lowered program without errors,
+/// starting at its entry point. It exports its memory and `_start`, which runs the program, and
+/// imports from the host the functions in `runtime/wasm/ila_runtime.wat`.
+pub fn generate_wat(program: &Program, source_name: &str) -> String {
+
+    let entry_point = program.entry_point.expect("programs with an entry point are compiled");
+    let mut generator = Generator{
+        program,
+        data: Vec::new(),
+        data_end: PROGRAM_DATA_START,
+        descriptors: HashMap::new(),
+        texts: HashMap::new(),
+        numbers: HashMap::new(),
+        function: &program.functions[entry_point.0],
+        code: String::new(),
+        indent: 0,
+        boxed: Vec::new(),
+        uses: Vec::new(),
+        stacked: Vec::new(),
+    };
+
+    let functions: Vec<String> = (0..program.functions.len()).map(|index| generator.subprogram(FunctionID(index))).collect();
+
+    let mut output = String::new();
+    writeln!(output, ";; generated by `ila compilar` from {}", source_name.replace('\n', " ")).expect("writing to a string does not fail");
+    output.push_str("(module\n");
+    output.push_str(RUNTIME);
+    output.push_str("\n  ;; ---- program ----\n\n");
+    for (address, bytes) in &generator.data {
+        writeln!(output, "  (data (i32.const {}) {})", address, data_string(bytes)).expect("writing to a string does not fail");
+    }
+    if !generator.data.is_empty() {
+        output.push('\n');
+    }
+    for function in functions {
+        writeln!(output, "{}", function).expect("writing to a string does not fail");
+    }
+    writeln!(output, "  (func (export \"_start\")\n    i32.const {}\n    global.set $heap\n    call {}\n    drop\n  )\n)",
+        (generator.data_end + 7) & !7, generator.function_name(entry_point)).expect("writing to a string does not fail");
+
+    return output;
+}
+
+/// Assembles a module in the text format into the binary format of WebAssembly.
+pub fn assemble(wat: &str) -> Result<Vec<u8>> {
+    return wat::parse_str(wat).context("o módulo de WebAssembly gerado é inválido");
+}
+
+
+/* -------------------------------------------------------------------------- */
+/*                              simple test cases                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::analysis::{analyze, AnalysisSettings};
+    use crate::ast::LexicalPosition;
+    use crate::numbers::Integer;
+    use crate::runtime::{RuntimeError, RuntimeErrorKind};
+    use crate::tso_parser::parse_source;
+    use crate::optimizer::{self, OptimizationLevel};
+    use crate::{bytecode, ir, vm};
+    use wasmi::{Caller, Config, Engine, Extern, Linker, Memory, Module, StackLimits, Store};
+
+
+    fn generate_source(source_code: &str) -> String {
+        return generate_optimized(source_code, OptimizationLevel::O0);
+    }
+
+    fn generate_optimized(source_code: &str, level: OptimizationLevel) -> String {
+
+        let output = parse_source(source_code).unwrap();
+        let analysis = analyze(&output.ast, &AnalysisSettings::default());
+        assert!(!output.has_errors() && !analysis.has_errors());
+
+        let mut program = ir::lower(&output.ast, &analysis.resolution, &analysis.types);
+        optimizer::optimize(&mut program, level);
+        return generate_wat(&program, "teste.ila");
+    }
+
+    /// What the virtual machine writes when running the program, and the code of its error.
+    fn run_on_vm(source_code: &str, input: &str) -> (String, Option<&'static str>) {
+
+        let output = parse_source(source_code).unwrap();
+        let analysis = analyze(&output.ast, &AnalysisSettings::default());
+        let bytecode = bytecode::compile(&ir::lower(&output.ast, &analysis.resolution, &analysis.types));
+
+        let mut written = Vec::new();
+        let result = vm::execute(&bytecode, &mut input.as_bytes(), &mut written);
+        return (String::from_utf8(written).unwrap(), result.err().map(|error| error.to_diagnostic().code));
+    }
+
+    /// A host like `ila.js`, with the input and output in memory.
+    #[derive(Default)]
+    struct Host {
+        input: Vec<u8>,
+        line: Vec<u8>,
+        output: Vec<u8>,
+        error: Option<RuntimeError>,
+    }
+
+    fn memory(caller: &Caller<'_, Host>) -> Memory {
+        return caller.get_export("memory").and_then(Extern::into_memory).unwrap();
+    }
+
+    fn run_on_wasm(source_code: &str, input: &str) -> (String, Option<&'static str>) {
+        return run_module(&generate_source(source_code), input);
+    }
+
+    /// What the module writes when running its program, and the code of its error.
+    fn run_module(wat: &str, input: &str) -> (String, Option<&'static str>) {
+
+        let wasm = assemble(wat).unwrap();
+
+        // the call depth allowed by the runtime is deeper than the default of wasmi
+        let mut config = Config::default();
+        config.set_stack_limits(StackLimits::new(1 << 16, 1 << 24, 4 * crate::runtime::MAX_CALL_DEPTH).unwrap());
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, &wasm[..]).unwrap();
+        let mut store = Store::new(&engine, Host{input: input.as_bytes().to_vec(), ..Host::default()});
+
+        let mut linker = Linker::<Host>::new(&engine);
+        linker.func_wrap("ila", "write", |mut caller: Caller<'_, Host>, bytes: i32, length: i32| {
+            let memory = memory(&caller);
+            let written = memory.data(&caller)[bytes as usize..][..length as usize].to_vec();
+            caller.data_mut().output.extend(written);
+        }).unwrap();
+        linker.func_wrap("ila", "read_line", |mut caller: Caller<'_, Host>| -> i32 {
+            let host = caller.data_mut();
+            let end = host.input.iter().position(|byte| *byte == b'\n').map_or(host.input.len(), |end| end + 1);
+            let mut line: Vec<u8> = host.input.drain(..end).collect();
+            if line.last() == Some(&b'\n') {
+                line.pop();
+            }
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+            host.line = line;
+            host.line.len() as i32
+        }).unwrap();
+        linker.func_wrap("ila", "read_bytes", |mut caller: Caller<'_, Host>, destination: i32| {
+            let memory = memory(&caller);
+            let (memory, host) = memory.data_and_store_mut(&mut caller);
+            memory[destination as usize..][..host.line.len()].copy_from_slice(&host.line);
+        }).unwrap();
+        linker.func_wrap("ila", "fail", |mut caller: Caller<'_, Host>, kind: i32, line: i32, column: i32, index: i32, length: i64| -> Result<(), wasmi::Error> {
+            let kind = match kind {
+                1 => RuntimeErrorKind::DivisionByZero,
+                2 => {
+                    // the position is a text, since it may not fit in 64 bits
+                    let memory = memory(&caller);
+                    let text = &memory.data(&caller)[index as usize..];
+                    let text = std::str::from_utf8(&text[4..][..u32::from_le_bytes(text[..4].try_into().unwrap()) as usize]).unwrap();
+                    RuntimeErrorKind::IndexOutOfBounds{index: Integer::parse(text).unwrap(), length: length as usize}
+                },
+                _ => RuntimeErrorKind::CallDepthExceeded,
+            };
+            let position = LexicalPosition{line: line as usize - 1, column: column as usize - 1};
+            caller.data_mut().error = Some(RuntimeError{kind, lexical_info: LexicalInfo{start: position, end: position}});
+            Err(wasmi::Error::new("erro de execução"))
+        }).unwrap();
+
+        let instance = linker.instantiate(&mut store, &module).unwrap().start(&mut store).unwrap();
+        let start = instance.get_typed_func::<(), ()>(&store, "_start").unwrap();
+        let result = start.call(&mut store, ());
+
+        let host = store.into_data();
+        assert_eq!(result.is_err(), host.error.is_some(), "{:?}", result);
+        return (String::from_utf8(host.output).unwrap(), host.error.map(|error| error.to_diagnostic().code));
+    }
+
+    fn assert_same_as_vm(source_code: &str, input: &str) {
+        assert_eq!(run_on_wasm(source_code, input), run_on_vm(source_code, input));
+    }
+
+    #[test]
+    fn test_generate() {
+
+        let source_code = r#"func média(a: inteiro, b: decimal) -> decimal { retornar a + b; } procedimento principal() { escrever("oi"); }"#;
+        let wat = generate_source(source_code);
+
+        assert!(wat.contains("(func $f_m_u00e9dia (param $arg0 i64) (param $arg1 i32) (result i32)"));
+        assert!(wat.contains("(func (export \"_start\")"));
+        assert!(wat.contains("(data (i32.const 1024) \"\\02\\00\\00\\00oi\")"));
+        assert!(assemble(&wat).unwrap().starts_with(b"\0asm"));
+    }
+
+    #[test]
+    fn test_values() {
+
+        let source_code = r#"
+            func soma(itens: ref lista<inteiro>) -> inteiro {
+                var mut total: inteiro = 0;
+                para cada item em itens { total = total + item; }
+                retornar total;
+            }
+
+            procedimento dobrar(mut itens: mut ref lista<inteiro>) {
+                para cada mut ref item em itens { *item = *item * 2; }
+            }
+
+            procedimento principal() {
+                var mut números: lista<inteiro> = [1, 2, 3];
+                var cópia: lista<inteiro> = números;
+                adicionar(números, 4);
+                dobrar(números);
+                escrever(números);
+                escrever(cópia);
+                escrever(soma(números));
+                escrever(obter(números, 3) / 3);
+                escrever(7 / 2.0);
+                escrever(1.0 / 8.0);
+                escrever(3 == 3.0);
+                escrever(2 < 2.5);
+                escrever([["a", "b"], ["c"]]);
+                var mut frase: texto = "olá";
+                para cada letra em frase { frase = frase + letra; }
+                escrever(frase);
+                escrever(tamanho(frase));
+                escrever(para_texto(verdadeiro) + " " + para_texto(1.5) + " " + para_texto(-42));
+                var nome: texto = ler();
+                escrever("oi, " + nome);
+                escrever("{nome} tem {tamanho(nome)} letras\t\"{números}\" {1.5} \u{E9}\\ \{");
+                escrever(ler() == "");
+            }
+        "#;
+
+        assert_same_as_vm(source_code, "mundo\r\n");
+    }
+
+    #[test]
+    fn test_references() {
+
+        let source_code = r#"
+            procedimento incrementar(mut valor: mut ref inteiro) {
+                *valor = *valor + 1;
+            }
+
+            func ler_valor(valor: comp inteiro) -> inteiro {
+                retornar *valor;
+            }
+
+            procedimento principal() {
+                var mut contador: inteiro = 0;
+                incrementar(contador);
+                incrementar(contador);
+                escrever(contador);
+
+                var compartilhado: mut comp inteiro = 10;
+                var outro: mut comp inteiro = compartilhado;
+                *outro = 20;
+                escrever(*compartilhado);
+                escrever(ler_valor(compartilhado));
+
+                var mut caixas: lista<comp texto> = ["a", "b"];
+                escrever(caixas);
+                escrever(verdadeiro e não falso ou falso);
+
+                var mut i: inteiro = 0;
+                enquanto i < 3 {
+                    var mut local: decimal = 0.0;
+                    var r: mut ref decimal = local;
+                    *r = *r + 1.0;
+                    escrever(local);
+                    i = i + 1;
+                }
+            }
+        "#;
+
+        assert_same_as_vm(source_code, "");
+    }
+
+    #[test]
+    fn test_iteration() {
+
+        // the items are the ones the list has when the loop starts, and keep their storage
+        // while the list grows
+        let source_code = r#"
+            procedimento principal() {
+                var mut l: lista<inteiro> = [1, 2, 3];
+                para cada x em l { l = [9]; escrever(x); }
+                escrever(l);
+
+                var mut m: lista<inteiro> = [1, 2, 3];
+                para cada mut ref x em m { adicionar(m, 7); *x = *x * 10; }
+                escrever(m);
+
+                var mut aninhada: lista<lista<texto>> = [["a"], ["b"]];
+                para cada mut ref itens em aninhada { adicionar(*itens, "z"); adicionar(aninhada, ["w"]); }
+                escrever(aninhada);
+
+                var copia: lista<lista<texto>> = aninhada;
+                adicionar(aninhada, ["v"]);
+                escrever(copia);
+            }
+        "#;
+
+        assert_same_as_vm(source_code, "");
+    }
+
+    #[test]
+    fn test_optimized_programs() {
+
+        let source_code = r#"
+            func dobro(x: inteiro) -> inteiro {
+                retornar x * 2;
+            }
+
+            procedimento acumular(mut total: mut ref inteiro, itens: lista<inteiro>) {
+                para cada item em itens {
+                    se item == 1 { continuar; }
+                    *total = *total + dobro(item);
+                }
+            }
+
+            procedimento principal() {
+                var mut total: inteiro = 2 * 3 - 6;
+                var mut itens: lista<inteiro> = [3, 1, 4];
+                var copia: lista<inteiro> = itens;
+                adicionar(itens, dobro(5));
+                acumular(total, itens);
+                escrever(total);
+                escrever(copia);
+                escrever("a" + "b" == "ab" e não falso);
+                escrever(total / (total - total));
+            }
+        "#;
+
+        let expected = run_on_vm(source_code, "");
+        for level in [OptimizationLevel::O1, OptimizationLevel::O2] {
+            assert_eq!(run_module(&generate_optimized(source_code, level), ""), expected, "{:?}", level);
+        }
+    }
+
+    #[test]
+    fn test_numbers() {
+
+        // integers have no limit and decimals keep their digits, like in the interpreter
+        let source_code = r#"
+            func fatorial(n: inteiro) -> inteiro {
+                se n <= 1 { retornar 1; }
+                retornar n * fatorial(n - 1);
+            }
+
+            procedimento principal() {
+                escrever(fatorial(50));
+                escrever(0.1 + 0.2);
+                escrever(fatorial(30) / fatorial(28) - 9223372036854775807 * 2);
+                escrever(-9223372036854775808 - 1);
+                escrever(-fatorial(21) / 7);
+                escrever(99999999999999999999 == 99999999999999999999);
+                escrever(1.0 / 3.0);
+                escrever(2 / -3.0 * 1e40);
+                escrever(10 == 10.0);
+                escrever(fatorial(25) > 1.5e25);
+                escrever([123456789012345678901234567890, -1]);
+                escrever(para_texto(12.50) + " " + para_texto(-0.001));
+                var mut total: decimal = 0.0;
+                var mut i: inteiro = 0;
+                enquanto i < 10 { total = total + 0.1; i = i + 1; }
+                escrever(total);
+            }
+        "#;
+
+        let (written, error_code) = run_on_wasm(source_code, "");
+        assert!(written.starts_with("30414093201713378043612608166064768844377641568960512000000000000\n0.3\n"), "{}", written);
+        assert_eq!((written, error_code), run_on_vm(source_code, ""));
+    }
+
+    #[test]
+    fn test_runtime_errors() {
+
+        let division = "procedimento principal() { escrever(1); var zero: inteiro = 0; escrever(1 / zero); }";
+        assert_same_as_vm(division, "");
+
+        let index = "procedimento principal() { var itens: lista<inteiro> = [1]; escrever(obter(itens, 1)); }";
+        assert_same_as_vm(index, "");
+
+        let large_index = "procedimento principal() { var itens: lista<inteiro> = [1]; escrever(obter(itens, -99999999999999999999)); }";
+        assert_same_as_vm(large_index, "");
+
+        let recursion = "procedimento infinito(n: inteiro) { infinito(n + 1); } procedimento principal() { infinito(0); }";
+        assert_same_as_vm(recursion, "");
+    }
+
+    #[test]
+    fn test_control_flow() {
+
+        let source_code = r#"
+            func fatorial(n: inteiro) -> inteiro {
+                se n <= 1 { retornar 1; }
+                retornar n * fatorial(n - 1);
+            }
+
+            func primeiro_par(itens: lista<inteiro>) -> inteiro {
+                para cada item em itens {
+                    se item / 2 * 2 == item { retornar item; }
+                }
+                retornar -1;
+            }
+
+            func classificar(n: inteiro) -> texto {
+                se n < 0 { retornar "negativo"; } senão se n == 0 { retornar "zero"; } senão { retornar "positivo"; }
+            }
+
+            procedimento principal() {
+                para cada n em [-1, 0, 1] { escrever(classificar(n)); }
+                var mut i: inteiro = 0;
+                enquanto i < 10 {
+                    i = i + 1;
+                    se i == 3 { continuar; }
+                    se i == 6 { parar; }
+                    escrever(fatorial(i));
+                }
+                escrever(primeiro_par([3, 5, 8, 10]));
+                para cada palavra em ["x", "y", "z"] {
+                    se palavra == "y" { continuar; }
+                    escrever(palavra);
+                }
+            }
+        "#;
+
+        assert_same_as_vm(source_code, "");
+    }
+}
@@ -5,7 +5,7 @@ module.exports = grammar({
 
     rules: {
 
-        program: $ => repeat($.function),
+        program: $ => repeat(choice($.function, $.procedure, $.struct, $.enum)),
         
         function: $ => seq(
             'func',
@@ -20,6 +20,58 @@ module.exports = grammar({
             '}',
         ),
 
+        procedure: $ => seq(
+            'procedimento',
+            field('name', $.identifier),
+            '(',
+            optional(seq(field('param', $.function_param), repeat(seq(',', field('param', $.function_param))))),
+            ')',
+            optional(seq('->', field('return_type', $._type))),
+            '{',
+            field('body', repeat($._statement)),
+            '}',
+        ),
+
+        struct: $ => seq(
+            'estrutura',
+            field('name', $.identifier),
+            '{',
+            optional(seq(
+                field('field', $.struct_field),
+                repeat(seq(',', field('field', $.struct_field))),
+                optional(','),
+            )),
+            '}',
+        ),
+
+        struct_field: $ => seq(
+            field('name', $.identifier),
+            ':',
+            field('type', $._type),
+        ),
+
+        enum: $ => seq(
+            'enumeração',
+            field('name', $.identifier),
+            '{',
+            optional(seq(
+                field('variant', $.enum_variant),
+                repeat(seq(',', field('variant', $.enum_variant))),
+                optional(','),
+            )),
+            '}',
+        ),
+
+        enum_variant: $ => seq(
+            field('name', $.identifier),
+            optional(seq(
+                '(',
+                field('payload', $._type),
+                repeat(seq(',', field('payload', $._type))),
+                ')',
+            )),
+        ),
+
         function_param: $ => seq(
             field('is_mut', optional('mut')),
             field('name', $.identifier),
@@ -33,6 +85,7 @@ module.exports = grammar({
             $.conditional,
             $.while_loop,
             $.for_each_loop,
+            $.match_stmt,
             $.return_stmt,
             $.continue_stmt,
             $.break_stmt,
@@ -48,6 +101,7 @@ module.exports = grammar({
             '{',
             field('body', repeat($._statement)),
             '}',
+            repeat(field('else_if', $.else_if)),
             optional(seq(
                 field('has_else', 'senão'),
                 '{',
@@ -56,6 +110,15 @@ module.exports = grammar({
             )),
         ),
 
+        else_if: $ => seq(
+            'senão',
+            'se',
+            field('condition', $._expression),
+            '{',
+            field('body', repeat($._statement)),
+            '}',
+        ),
+
         while_loop: $ => seq(
             'enquanto',
             field('condition', $._expression),
@@ -74,6 +137,29 @@ module.exports = grammar({
             '}',
         ),
 
+        match_stmt: $ => seq(
+            'escolha',
+            field('value', $._expression),
+            '{',
+            repeat(field('case', $.match_case)),
+            '}',
+        ),
+
+        match_case: $ => seq(
+            'caso',
+            field('variant', $.identifier),
+            optional(seq(
+                '(',
+                field('binding', $.identifier),
+                repeat(seq(',', field('binding', $.identifier))),
+                ')',
+            )),
+            '=>',
+            '{',
+            field('body', repeat($._statement)),
+            '}',
+        ),
+
         for_item_decl: $ => seq(
             field('is_mut', optional('mut')),
             field('is_ref', optional('ref')),
@@ -105,6 +191,7 @@ module.exports = grammar({
             $.unary_expr,
             $.access_expr,
             $.call_expr,
+            $.struct_literal,
             $.parem_expr,
             $._value_expr,
         ),
@@ -144,6 +231,22 @@ module.exports = grammar({
             ')',
         )),
 
+        // at least one field, so `se a {}` stays a conditional with an empty body
+        struct_literal: $ => prec(1201, seq(
+            field('name', $.identifier),
+            '{',
+            field('field', $.field_init),
+            repeat(seq(',', field('field', $.field_init))),
+            optional(','),
+            '}',
+        )),
+
+        field_init: $ => seq(
+            field('name', $.identifier),
+            ':',
+            field('value', $._expression),
+        ),
+
         _value_expr: $ => prec(1301, choice(
             $.identifier,
             $._literal,
@@ -161,8 +264,19 @@ module.exports = grammar({
         ),
 
         integer: $ => field('value', $.integer_literal),
-        decimal: $ => prec(2000, seq(field('integer', choice($.integer_literal, $.fractional_literal)), '.', field('fraction', choice($.integer_literal, $.fractional_literal)))),
-        string: $ => seq('"', field('content', $.string_content), '"'),
+        decimal: $ => prec(2000, seq(
+            field('integer', choice($.integer_literal, $.fractional_literal)),
+            choice(
+                seq('.', field('fraction', choice($.integer_literal, $.fractional_literal)), optional(field('exponent', $.exponent_literal))),
+                field('exponent', $.exponent_literal),
+            ),
+        )),
+        string: $ => seq(
+            '"',
+            repeat(field('part', choice($.string_content, $.escape_sequence, $.interpolation))),
+            token.immediate('"'),
+        ),
+        interpolation: $ => seq(token.immediate('{'), field('expression', $._expression), '}'),
         boolean: $ => field('value', choice('verdadeiro', 'falso')),
         list: $ => seq('[', optional(seq(field('item', $._expression), repeat(seq(',', field('item', $._expression))))), ']'),
 
@@ -191,12 +305,20 @@ module.exports = grammar({
             /([1-9][0-9]*)|0/
         )),
         fractional_literal: $ => token(/[0-9]+/),
-        string_content: $ => token(/[^"]*/),
-        identifier: $ => /[a-zA-Z_][a-zA-Z0-9_]*/,
+        exponent_literal: $ => token.immediate(/[eE][+-]?[0-9]+/),
+
+        // `# ...` until the end of the line, `#{ ... }#` over any number of lines
+        line_comment: $ => token(seq('#', /[^\n]*/)),
+        block_comment: $ => token(prec(1, seq('#{', /[^}]*\}+([^}#][^}]*\}+)*/, '#'))),
+        string_content: $ => token.immediate(prec(2, /[^"\\{]+/)),
+        // invalid escapes are also matched, so the parser can report them
+        escape_sequence: $ => token.immediate(prec(2, /\\(u\{[^}"\n]*\}|[^\n])?/)),
+        identifier: $ => /[\p{L}_][\p{L}\p{N}_]*/,
     },
 
     extras: $ => [
         /\s/,
-        /#(!\n)*\n/
+        $.line_comment,
+        $.block_comment,
     ],
 });
\ No newline at end of file
@@ -5,15 +5,15 @@
 #endif
 
 #define LANGUAGE_VERSION 14
-#define STATE_COUNT 170
+#define STATE_COUNT 340
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 84
+#define SYMBOL_COUNT 115
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 47
+#define TOKEN_COUNT 59
 #define EXTERNAL_TOKEN_COUNT 0
-#define FIELD_COUNT 25
+#define FIELD_COUNT 32
 #define MAX_ALIAS_SEQUENCE_LENGTH 11
-#define PRODUCTION_ID_COUNT 48
+#define PRODUCTION_ID_COUNT 94
 
 enum ts_symbol_identifiers {
   sym_identifier = 1,
@@ -24,81 +24,112 @@ enum ts_symbol_identifiers {
   anon_sym_DASH_GT = 6,
   anon_sym_LBRACE = 7,
   anon_sym_RBRACE = 8,
-  anon_sym_mut = 9,
-  anon_sym_COLON = 10,
-  anon_sym_parar = 11,
-  anon_sym_SEMI = 12,
-  anon_sym_continuar = 13,
-  anon_sym_retornar = 14,
-  anon_sym_se = 15,
-  anon_sym_senu00e3o = 16,
-  anon_sym_enquanto = 17,
-  anon_sym_para = 18,
-  anon_sym_cada = 19,
-  anon_sym_em = 20,
-  anon_sym_ref = 21,
-  anon_sym_var = 22,
-  anon_sym_EQ = 23,
-  anon_sym_e = 24,
-  anon_sym_ou = 25,
-  anon_sym_EQ_EQ = 26,
-  anon_sym_BANG_EQ = 27,
-  anon_sym_LT = 28,
-  anon_sym_GT = 29,
-  anon_sym_LT_EQ = 30,
-  anon_sym_GT_EQ = 31,
-  anon_sym_PLUS = 32,
-  anon_sym_DASH = 33,
-  anon_sym_STAR = 34,
-  anon_sym_SLASH = 35,
-  anon_sym_nu00e3o = 36,
-  anon_sym_DOT = 37,
-  anon_sym_DQUOTE = 38,
-  anon_sym_verdadeiro = 39,
-  anon_sym_falso = 40,
-  anon_sym_LBRACK = 41,
-  anon_sym_RBRACK = 42,
-  anon_sym_comp = 43,
-  sym_integer_literal = 44,
-  sym_fractional_literal = 45,
-  sym_string_content = 46,
-  sym_program = 47,
-  sym_function = 48,
-  sym_function_param = 49,
-  sym__statement = 50,
-  sym_break_stmt = 51,
-  sym_continue_stmt = 52,
-  sym_return_stmt = 53,
-  sym_conditional = 54,
-  sym_while_loop = 55,
-  sym_for_each_loop = 56,
-  sym_for_item_decl = 57,
-  sym_variable_declaration = 58,
-  sym_expression_stmt = 59,
-  sym__expression = 60,
-  sym_binary_expr = 61,
-  sym_unary_expr = 62,
-  sym_access_expr = 63,
-  sym_call_expr = 64,
-  sym__value_expr = 65,
-  sym_parem_expr = 66,
-  sym__literal = 67,
-  sym_integer = 68,
-  sym_decimal = 69,
-  sym_string = 70,
-  sym_boolean = 71,
-  sym_list = 72,
-  sym__type = 73,
-  sym_template_type = 74,
-  sym_raw_type = 75,
-  sym_ref_type = 76,
-  sym_comp_type = 77,
-  aux_sym_program_repeat1 = 78,
-  aux_sym_function_repeat1 = 79,
-  aux_sym_function_repeat2 = 80,
-  aux_sym_call_expr_repeat1 = 81,
-  aux_sym_list_repeat1 = 82,
-  aux_sym_template_type_repeat1 = 83,
+  anon_sym_procedimento = 9,
+  anon_sym_estrutura = 10,
+  anon_sym_COLON = 11,
+  anon_sym_enumerau00e7u00e3o = 12,
+  anon_sym_mut = 13,
+  anon_sym_parar = 14,
+  anon_sym_SEMI = 15,
+  anon_sym_continuar = 16,
+  anon_sym_retornar = 17,
+  anon_sym_se = 18,
+  anon_sym_senu00e3o = 19,
+  anon_sym_enquanto = 20,
+  anon_sym_para = 21,
+  anon_sym_cada = 22,
+  anon_sym_em = 23,
+  anon_sym_escolha = 24,
+  anon_sym_caso = 25,
+  anon_sym_EQ_GT = 26,
+  anon_sym_ref = 27,
+  anon_sym_var = 28,
+  anon_sym_EQ = 29,
+  anon_sym_e = 30,
+  anon_sym_ou = 31,
+  anon_sym_EQ_EQ = 32,
+  anon_sym_BANG_EQ = 33,
+  anon_sym_LT = 34,
+  anon_sym_GT = 35,
+  anon_sym_LT_EQ = 36,
+  anon_sym_GT_EQ = 37,
+  anon_sym_PLUS = 38,
+  anon_sym_DASH = 39,
+  anon_sym_STAR = 40,
+  anon_sym_SLASH = 41,
+  anon_sym_nu00e3o = 42,
+  anon_sym_DOT = 43,
+  anon_sym_DQUOTE = 44,
+  anon_sym_DQUOTE2 = 45,
+  anon_sym_LBRACE2 = 46,
+  anon_sym_verdadeiro = 47,
+  anon_sym_falso = 48,
+  anon_sym_LBRACK = 49,
+  anon_sym_RBRACK = 50,
+  anon_sym_comp = 51,
+  sym_integer_literal = 52,
+  sym_fractional_literal = 53,
+  sym_exponent_literal = 54,
+  sym_line_comment = 55,
+  sym_block_comment = 56,
+  sym_string_content = 57,
+  sym_escape_sequence = 58,
+  sym_program = 59,
+  sym_function = 60,
+  sym_procedure = 61,
+  sym_struct = 62,
+  sym_struct_field = 63,
+  sym_enum = 64,
+  sym_enum_variant = 65,
+  sym_function_param = 66,
+  sym__statement = 67,
+  sym_break_stmt = 68,
+  sym_continue_stmt = 69,
+  sym_return_stmt = 70,
+  sym_conditional = 71,
+  sym_else_if = 72,
+  sym_while_loop = 73,
+  sym_for_each_loop = 74,
+  sym_match_stmt = 75,
+  sym_match_case = 76,
+  sym_for_item_decl = 77,
+  sym_variable_declaration = 78,
+  sym_expression_stmt = 79,
+  sym__expression = 80,
+  sym_binary_expr = 81,
+  sym_unary_expr = 82,
+  sym_access_expr = 83,
+  sym_call_expr = 84,
+  sym_struct_literal = 85,
+  sym_field_init = 86,
+  sym__value_expr = 87,
+  sym_parem_expr = 88,
+  sym__literal = 89,
+  sym_integer = 90,
+  sym_decimal = 91,
+  sym_string = 92,
+  sym_interpolation = 93,
+  sym_boolean = 94,
+  sym_list = 95,
+  sym__type = 96,
+  sym_template_type = 97,
+  sym_raw_type = 98,
+  sym_ref_type = 99,
+  sym_comp_type = 100,
+  aux_sym_program_repeat1 = 101,
+  aux_sym_function_repeat1 = 102,
+  aux_sym_function_repeat2 = 103,
+  aux_sym_struct_repeat1 = 104,
+  aux_sym_enum_repeat1 = 105,
+  aux_sym_enum_variant_repeat1 = 106,
+  aux_sym_conditional_repeat1 = 107,
+  aux_sym_match_stmt_repeat1 = 108,
+  aux_sym_match_case_repeat1 = 109,
+  aux_sym_call_expr_repeat1 = 110,
+  aux_sym_struct_literal_repeat1 = 111,
+  aux_sym_string_repeat1 = 112,
+  aux_sym_list_repeat1 = 113,
+  aux_sym_template_type_repeat1 = 114,
 };
 
 static const char * const ts_symbol_names[] = {
@@ -111,8 +142,11 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_DASH_GT] = "->",
   [anon_sym_LBRACE] = "{",
   [anon_sym_RBRACE] = "}",
-  [anon_sym_mut] = "mut",
+  [anon_sym_procedimento] = "procedimento",
+  [anon_sym_estrutura] = "estrutura",
   [anon_sym_COLON] = ":",
+  [anon_sym_enumerau00e7u00e3o] = "enumera\u00e7\u00e3o",
+  [anon_sym_mut] = "mut",
   [anon_sym_parar] = "parar",
   [anon_sym_SEMI] = ";",
   [anon_sym_continuar] = "continuar",
@@ -123,6 +157,9 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_para] = "para",
   [anon_sym_cada] = "cada",
   [anon_sym_em] = "em",
+  [anon_sym_escolha] = "escolha",
+  [anon_sym_caso] = "caso",
+  [anon_sym_EQ_GT] = "=>",
   [anon_sym_ref] = "ref",
   [anon_sym_var] = "var",
   [anon_sym_EQ] = "=",
@@ -141,6 +178,8 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_nu00e3o] = "n\u00e3o",
   [anon_sym_DOT] = ".",
   [anon_sym_DQUOTE] = "\"",
+  [anon_sym_DQUOTE2] = "\"",
+  [anon_sym_LBRACE2] = "{",
   [anon_sym_verdadeiro] = "verdadeiro",
   [anon_sym_falso] = "falso",
   [anon_sym_LBRACK] = "[",
@@ -148,17 +187,29 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_comp] = "comp",
   [sym_integer_literal] = "integer_literal",
   [sym_fractional_literal] = "fractional_literal",
+  [sym_exponent_literal] = "exponent_literal",
+  [sym_line_comment] = "line_comment",
+  [sym_block_comment] = "block_comment",
   [sym_string_content] = "string_content",
+  [sym_escape_sequence] = "escape_sequence",
   [sym_program] = "program",
   [sym_function] = "function",
+  [sym_procedure] = "procedure",
+  [sym_struct] = "struct",
+  [sym_struct_field] = "struct_field",
+  [sym_enum] = "enum",
+  [sym_enum_variant] = "enum_variant",
   [sym_function_param] = "function_param",
   [sym__statement] = "_statement",
   [sym_break_stmt] = "break_stmt",
   [sym_continue_stmt] = "continue_stmt",
   [sym_return_stmt] = "return_stmt",
   [sym_conditional] = "conditional",
+  [sym_else_if] = "else_if",
   [sym_while_loop] = "while_loop",
   [sym_for_each_loop] = "for_each_loop",
+  [sym_match_stmt] = "match_stmt",
+  [sym_match_case] = "match_case",
   [sym_for_item_decl] = "for_item_decl",
   [sym_variable_declaration] = "variable_declaration",
   [sym_expression_stmt] = "expression_stmt",
@@ -167,12 +218,15 @@ static const char * const ts_symbol_names[] = {
   [sym_unary_expr] = "unary_expr",
   [sym_access_expr] = "access_expr",
   [sym_call_expr] = "call_expr",
+  [sym_struct_literal] = "struct_literal",
+  [sym_field_init] = "field_init",
   [sym__value_expr] = "_value_expr",
   [sym_parem_expr] = "parem_expr",
   [sym__literal] = "_literal",
   [sym_integer] = "integer",
   [sym_decimal] = "decimal",
   [sym_string] = "string",
+  [sym_interpolation] = "interpolation",
   [sym_boolean] = "boolean",
   [sym_list] = "list",
   [sym__type] = "_type",
@@ -183,7 +237,15 @@ static const char * const ts_symbol_names[] = {
   [aux_sym_program_repeat1] = "program_repeat1",
   [aux_sym_function_repeat1] = "function_repeat1",
   [aux_sym_function_repeat2] = "function_repeat2",
+  [aux_sym_struct_repeat1] = "struct_repeat1",
+  [aux_sym_enum_repeat1] = "enum_repeat1",
+  [aux_sym_enum_variant_repeat1] = "enum_variant_repeat1",
+  [aux_sym_conditional_repeat1] = "conditional_repeat1",
+  [aux_sym_match_stmt_repeat1] = "match_stmt_repeat1",
+  [aux_sym_match_case_repeat1] = "match_case_repeat1",
   [aux_sym_call_expr_repeat1] = "call_expr_repeat1",
+  [aux_sym_struct_literal_repeat1] = "struct_literal_repeat1",
+  [aux_sym_string_repeat1] = "string_repeat1",
   [aux_sym_list_repeat1] = "list_repeat1",
   [aux_sym_template_type_repeat1] = "template_type_repeat1",
 };
@@ -198,8 +260,11 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_DASH_GT] = anon_sym_DASH_GT,
   [anon_sym_LBRACE] = anon_sym_LBRACE,
   [anon_sym_RBRACE] = anon_sym_RBRACE,
-  [anon_sym_mut] = anon_sym_mut,
+  [anon_sym_procedimento] = anon_sym_procedimento,
+  [anon_sym_estrutura] = anon_sym_estrutura,
   [anon_sym_COLON] = anon_sym_COLON,
+  [anon_sym_enumerau00e7u00e3o] = anon_sym_enumerau00e7u00e3o,
+  [anon_sym_mut] = anon_sym_mut,
   [anon_sym_parar] = anon_sym_parar,
   [anon_sym_SEMI] = anon_sym_SEMI,
   [anon_sym_continuar] = anon_sym_continuar,
@@ -210,6 +275,9 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_para] = anon_sym_para,
   [anon_sym_cada] = anon_sym_cada,
   [anon_sym_em] = anon_sym_em,
+  [anon_sym_escolha] = anon_sym_escolha,
+  [anon_sym_caso] = anon_sym_caso,
+  [anon_sym_EQ_GT] = anon_sym_EQ_GT,
   [anon_sym_ref] = anon_sym_ref,
   [anon_sym_var] = anon_sym_var,
   [anon_sym_EQ] = anon_sym_EQ,
@@ -228,6 +296,8 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_nu00e3o] = anon_sym_nu00e3o,
   [anon_sym_DOT] = anon_sym_DOT,
   [anon_sym_DQUOTE] = anon_sym_DQUOTE,
+  [anon_sym_DQUOTE2] = anon_sym_DQUOTE,
+  [anon_sym_LBRACE2] = anon_sym_LBRACE,
   [anon_sym_verdadeiro] = anon_sym_verdadeiro,
   [anon_sym_falso] = anon_sym_falso,
   [anon_sym_LBRACK] = anon_sym_LBRACK,
@@ -235,17 +305,29 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_comp] = anon_sym_comp,
   [sym_integer_literal] = sym_integer_literal,
   [sym_fractional_literal] = sym_fractional_literal,
+  [sym_exponent_literal] = sym_exponent_literal,
+  [sym_line_comment] = sym_line_comment,
+  [sym_block_comment] = sym_block_comment,
   [sym_string_content] = sym_string_content,
+  [sym_escape_sequence] = sym_escape_sequence,
   [sym_program] = sym_program,
   [sym_function] = sym_function,
+  [sym_procedure] = sym_procedure,
+  [sym_struct] = sym_struct,
+  [sym_struct_field] = sym_struct_field,
+  [sym_enum] = sym_enum,
+  [sym_enum_variant] = sym_enum_variant,
   [sym_function_param] = sym_function_param,
   [sym__statement] = sym__statement,
   [sym_break_stmt] = sym_break_stmt,
   [sym_continue_stmt] = sym_continue_stmt,
   [sym_return_stmt] = sym_return_stmt,
   [sym_conditional] = sym_conditional,
+  [sym_else_if] = sym_else_if,
   [sym_while_loop] = sym_while_loop,
   [sym_for_each_loop] = sym_for_each_loop,
+  [sym_match_stmt] = sym_match_stmt,
+  [sym_match_case] = sym_match_case,
   [sym_for_item_decl] = sym_for_item_decl,
   [sym_variable_declaration] = sym_variable_declaration,
   [sym_expression_stmt] = sym_expression_stmt,
@@ -254,12 +336,15 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_unary_expr] = sym_unary_expr,
   [sym_access_expr] = sym_access_expr,
   [sym_call_expr] = sym_call_expr,
+  [sym_struct_literal] = sym_struct_literal,
+  [sym_field_init] = sym_field_init,
   [sym__value_expr] = sym__value_expr,
   [sym_parem_expr] = sym_parem_expr,
   [sym__literal] = sym__literal,
   [sym_integer] = sym_integer,
   [sym_decimal] = sym_decimal,
   [sym_string] = sym_string,
+  [sym_interpolation] = sym_interpolation,
   [sym_boolean] = sym_boolean,
   [sym_list] = sym_list,
   [sym__type] = sym__type,
@@ -270,7 +355,15 @@ static const TSSymbol ts_symbol_map[] = {
   [aux_sym_program_repeat1] = aux_sym_program_repeat1,
   [aux_sym_function_repeat1] = aux_sym_function_repeat1,
   [aux_sym_function_repeat2] = aux_sym_function_repeat2,
+  [aux_sym_struct_repeat1] = aux_sym_struct_repeat1,
+  [aux_sym_enum_repeat1] = aux_sym_enum_repeat1,
+  [aux_sym_enum_variant_repeat1] = aux_sym_enum_variant_repeat1,
+  [aux_sym_conditional_repeat1] = aux_sym_conditional_repeat1,
+  [aux_sym_match_stmt_repeat1] = aux_sym_match_stmt_repeat1,
+  [aux_sym_match_case_repeat1] = aux_sym_match_case_repeat1,
   [aux_sym_call_expr_repeat1] = aux_sym_call_expr_repeat1,
+  [aux_sym_struct_literal_repeat1] = aux_sym_struct_literal_repeat1,
+  [aux_sym_string_repeat1] = aux_sym_string_repeat1,
   [aux_sym_list_repeat1] = aux_sym_list_repeat1,
   [aux_sym_template_type_repeat1] = aux_sym_template_type_repeat1,
 };
@@ -312,7 +405,11 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_mut] = {
+  [anon_sym_procedimento] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_estrutura] = {
     .visible = true,
     .named = false,
   },
@@ -320,6 +417,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_enumerau00e7u00e3o] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_mut] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_parar] = {
     .visible = true,
     .named = false,
@@ -360,6 +465,18 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_escolha] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_caso] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ_GT] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_ref] = {
     .visible = true,
     .named = false,
@@ -432,6 +549,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_DQUOTE2] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACE2] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_verdadeiro] = {
     .visible = true,
     .named = false,
@@ -460,10 +585,26 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_exponent_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_line_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_block_comment] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_string_content] = {
     .visible = true,
     .named = true,
   },
+  [sym_escape_sequence] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_program] = {
     .visible = true,
     .named = true,
@@ -472,6 +613,26 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_procedure] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_struct] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_struct_field] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum_variant] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_function_param] = {
     .visible = true,
     .named = true,
@@ -496,6 +657,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_else_if] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_while_loop] = {
     .visible = true,
     .named = true,
@@ -504,6 +669,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_match_stmt] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_match_case] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_for_item_decl] = {
     .visible = true,
     .named = true,
@@ -536,6 +709,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_struct_literal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_field_init] = {
+    .visible = true,
+    .named = true,
+  },
   [sym__value_expr] = {
     .visible = false,
     .named = true,
@@ -560,6 +741,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_interpolation] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_boolean] = {
     .visible = true,
     .named = true,
@@ -600,10 +785,42 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_struct_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_enum_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_enum_variant_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_conditional_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_match_stmt_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_match_case_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_call_expr_repeat1] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_struct_literal_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_string_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_list_repeat1] = {
     .visible = false,
     .named = false,
@@ -616,40 +833,51 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
 
 enum ts_field_identifiers {
   field_arg = 1,
-  field_body = 2,
-  field_condition = 3,
-  field_content = 4,
-  field_else_body = 5,
-  field_expression = 6,
-  field_fraction = 7,
-  field_function = 8,
-  field_has_else = 9,
-  field_initializer = 10,
-  field_integer = 11,
-  field_is_mut = 12,
-  field_is_ref = 13,
-  field_item = 14,
-  field_iterator = 15,
-  field_lhs = 16,
-  field_name = 17,
-  field_object = 18,
-  field_oprt = 19,
-  field_param = 20,
-  field_return_expr = 21,
-  field_return_type = 22,
-  field_rhs = 23,
-  field_type = 24,
-  field_value = 25,
+  field_binding = 2,
+  field_body = 3,
+  field_case = 4,
+  field_condition = 5,
+  field_else_body = 6,
+  field_else_if = 7,
+  field_exponent = 8,
+  field_expression = 9,
+  field_field = 10,
+  field_fraction = 11,
+  field_function = 12,
+  field_has_else = 13,
+  field_initializer = 14,
+  field_integer = 15,
+  field_is_mut = 16,
+  field_is_ref = 17,
+  field_item = 18,
+  field_iterator = 19,
+  field_lhs = 20,
+  field_name = 21,
+  field_object = 22,
+  field_oprt = 23,
+  field_param = 24,
+  field_part = 25,
+  field_payload = 26,
+  field_return_expr = 27,
+  field_return_type = 28,
+  field_rhs = 29,
+  field_type = 30,
+  field_value = 31,
+  field_variant = 32,
 };
 
 static const char * const ts_field_names[] = {
   [0] = NULL,
   [field_arg] = "arg",
+  [field_binding] = "binding",
   [field_body] = "body",
+  [field_case] = "case",
   [field_condition] = "condition",
-  [field_content] = "content",
   [field_else_body] = "else_body",
+  [field_else_if] = "else_if",
+  [field_exponent] = "exponent",
   [field_expression] = "expression",
+  [field_field] = "field",
   [field_fraction] = "fraction",
   [field_function] = "function",
   [field_has_else] = "has_else",
@@ -664,216 +892,415 @@ static const char * const ts_field_names[] = {
   [field_object] = "object",
   [field_oprt] = "oprt",
   [field_param] = "param",
+  [field_part] = "part",
+  [field_payload] = "payload",
   [field_return_expr] = "return_expr",
   [field_return_type] = "return_type",
   [field_rhs] = "rhs",
   [field_type] = "type",
   [field_value] = "value",
+  [field_variant] = "variant",
 };
 
 static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
   [1] = {.index = 0, .length = 1},
-  [2] = {.index = 1, .length = 2},
-  [3] = {.index = 3, .length = 1},
+  [2] = {.index = 1, .length = 1},
+  [3] = {.index = 2, .length = 2},
   [4] = {.index = 4, .length = 2},
-  [5] = {.index = 6, .length = 1},
-  [6] = {.index = 7, .length = 3},
-  [7] = {.index = 10, .length = 2},
-  [8] = {.index = 12, .length = 2},
-  [9] = {.index = 14, .length = 1},
-  [10] = {.index = 15, .length = 2},
-  [11] = {.index = 17, .length = 2},
-  [12] = {.index = 19, .length = 1},
-  [13] = {.index = 20, .length = 3},
-  [14] = {.index = 23, .length = 3},
-  [15] = {.index = 26, .length = 3},
-  [16] = {.index = 29, .length = 1},
-  [17] = {.index = 30, .length = 1},
-  [18] = {.index = 31, .length = 1},
-  [19] = {.index = 32, .length = 1},
-  [20] = {.index = 33, .length = 2},
-  [21] = {.index = 35, .length = 1},
-  [22] = {.index = 36, .length = 3},
-  [23] = {.index = 39, .length = 2},
-  [24] = {.index = 41, .length = 4},
-  [25] = {.index = 45, .length = 4},
-  [26] = {.index = 49, .length = 1},
-  [27] = {.index = 50, .length = 2},
-  [28] = {.index = 52, .length = 2},
-  [29] = {.index = 54, .length = 2},
-  [30] = {.index = 56, .length = 2},
-  [31] = {.index = 58, .length = 2},
-  [32] = {.index = 60, .length = 5},
-  [33] = {.index = 65, .length = 2},
-  [34] = {.index = 67, .length = 3},
-  [35] = {.index = 70, .length = 2},
-  [36] = {.index = 72, .length = 1},
-  [37] = {.index = 73, .length = 3},
-  [38] = {.index = 76, .length = 2},
-  [39] = {.index = 78, .length = 3},
-  [40] = {.index = 81, .length = 2},
-  [41] = {.index = 83, .length = 2},
-  [42] = {.index = 85, .length = 3},
-  [43] = {.index = 88, .length = 3},
-  [44] = {.index = 91, .length = 3},
-  [45] = {.index = 94, .length = 3},
-  [46] = {.index = 97, .length = 4},
-  [47] = {.index = 101, .length = 4},
+  [5] = {.index = 6, .length = 2},
+  [6] = {.index = 8, .length = 1},
+  [7] = {.index = 9, .length = 2},
+  [8] = {.index = 11, .length = 1},
+  [9] = {.index = 12, .length = 1},
+  [10] = {.index = 13, .length = 3},
+  [11] = {.index = 16, .length = 2},
+  [12] = {.index = 18, .length = 1},
+  [13] = {.index = 19, .length = 3},
+  [14] = {.index = 22, .length = 2},
+  [15] = {.index = 24, .length = 1},
+  [16] = {.index = 25, .length = 3},
+  [17] = {.index = 28, .length = 2},
+  [18] = {.index = 30, .length = 1},
+  [19] = {.index = 31, .length = 2},
+  [20] = {.index = 33, .length = 1},
+  [21] = {.index = 34, .length = 2},
+  [22] = {.index = 36, .length = 2},
+  [23] = {.index = 38, .length = 2},
+  [24] = {.index = 40, .length = 2},
+  [25] = {.index = 42, .length = 2},
+  [26] = {.index = 44, .length = 1},
+  [27] = {.index = 45, .length = 1},
+  [28] = {.index = 46, .length = 1},
+  [29] = {.index = 47, .length = 2},
+  [30] = {.index = 49, .length = 1},
+  [31] = {.index = 50, .length = 2},
+  [32] = {.index = 52, .length = 1},
+  [33] = {.index = 53, .length = 3},
+  [34] = {.index = 56, .length = 2},
+  [35] = {.index = 58, .length = 3},
+  [36] = {.index = 61, .length = 3},
+  [37] = {.index = 64, .length = 1},
+  [38] = {.index = 65, .length = 3},
+  [39] = {.index = 68, .length = 2},
+  [40] = {.index = 70, .length = 2},
+  [41] = {.index = 72, .length = 3},
+  [42] = {.index = 75, .length = 3},
+  [43] = {.index = 78, .length = 1},
+  [44] = {.index = 79, .length = 2},
+  [45] = {.index = 81, .length = 2},
+  [46] = {.index = 83, .length = 1},
+  [47] = {.index = 84, .length = 1},
+  [48] = {.index = 85, .length = 2},
+  [49] = {.index = 87, .length = 2},
+  [50] = {.index = 89, .length = 3},
+  [51] = {.index = 92, .length = 2},
+  [52] = {.index = 94, .length = 2},
+  [53] = {.index = 96, .length = 4},
+  [54] = {.index = 100, .length = 3},
+  [55] = {.index = 103, .length = 4},
+  [56] = {.index = 107, .length = 4},
+  [57] = {.index = 111, .length = 1},
+  [58] = {.index = 112, .length = 2},
+  [59] = {.index = 114, .length = 2},
+  [60] = {.index = 116, .length = 3},
+  [61] = {.index = 119, .length = 2},
+  [62] = {.index = 121, .length = 2},
+  [63] = {.index = 123, .length = 2},
+  [64] = {.index = 125, .length = 2},
+  [65] = {.index = 127, .length = 3},
+  [66] = {.index = 130, .length = 1},
+  [67] = {.index = 131, .length = 3},
+  [68] = {.index = 134, .length = 2},
+  [69] = {.index = 136, .length = 5},
+  [70] = {.index = 141, .length = 2},
+  [71] = {.index = 143, .length = 3},
+  [72] = {.index = 146, .length = 3},
+  [73] = {.index = 149, .length = 2},
+  [74] = {.index = 151, .length = 2},
+  [75] = {.index = 153, .length = 3},
+  [76] = {.index = 156, .length = 3},
+  [77] = {.index = 159, .length = 3},
+  [78] = {.index = 162, .length = 3},
+  [79] = {.index = 165, .length = 3},
+  [80] = {.index = 168, .length = 4},
+  [81] = {.index = 172, .length = 1},
+  [82] = {.index = 173, .length = 4},
+  [83] = {.index = 177, .length = 4},
+  [84] = {.index = 181, .length = 4},
+  [85] = {.index = 185, .length = 1},
+  [86] = {.index = 186, .length = 2},
+  [87] = {.index = 188, .length = 2},
+  [88] = {.index = 190, .length = 2},
+  [89] = {.index = 192, .length = 5},
+  [90] = {.index = 197, .length = 2},
+  [91] = {.index = 199, .length = 3},
+  [92] = {.index = 202, .length = 3},
+  [93] = {.index = 205, .length = 4},
 };
 
 static const TSFieldMapEntry ts_field_map_entries[] = {
   [0] =
-    {field_name, 0},
+    {field_name, 1},
   [1] =
     {field_name, 0},
+  [2] =
+    {field_field, 3},
+    {field_name, 1},
+  [4] =
+    {field_name, 1},
+    {field_variant, 3},
+  [6] =
+    {field_name, 0},
     {field_type, 2},
-  [3] =
+  [8] =
     {field_param, 1},
-  [4] =
+  [9] =
     {field_param, 0, .inherited = true},
     {field_param, 1, .inherited = true},
-  [6] =
+  [11] =
+    {field_value, 0},
+  [12] =
+    {field_field, 1},
+  [13] =
+    {field_field, 3},
+    {field_field, 4, .inherited = true},
+    {field_name, 1},
+  [16] =
+    {field_field, 0, .inherited = true},
+    {field_field, 1, .inherited = true},
+  [18] =
+    {field_variant, 1},
+  [19] =
+    {field_name, 1},
+    {field_variant, 3},
+    {field_variant, 4, .inherited = true},
+  [22] =
+    {field_variant, 0, .inherited = true},
+    {field_variant, 1, .inherited = true},
+  [24] =
     {field_type, 1},
-  [7] =
+  [25] =
     {field_is_mut, 0},
     {field_name, 1},
     {field_type, 3},
-  [10] =
-    {field_is_mut, 0},
-    {field_type, 2},
-  [12] =
-    {field_name, 1},
-    {field_return_type, 5},
-  [14] =
-    {field_value, 0},
-  [15] =
-    {field_arg, 2},
-    {field_name, 0},
-  [17] =
+  [28] =
     {field_oprt, 0},
     {field_value, 1},
-  [19] =
+  [30] =
+    {field_part, 0},
+  [31] =
+    {field_exponent, 1},
+    {field_integer, 0},
+  [33] =
     {field_expression, 0},
-  [20] =
-    {field_body, 7},
+  [34] =
+    {field_body, 5},
     {field_name, 1},
-    {field_return_type, 5},
-  [23] =
+  [36] =
     {field_name, 1},
     {field_param, 3},
-    {field_return_type, 6},
-  [26] =
-    {field_arg, 2},
-    {field_arg, 3},
+  [38] =
     {field_name, 0},
-  [29] =
+    {field_payload, 2},
+  [40] =
+    {field_is_mut, 0},
+    {field_type, 2},
+  [42] =
+    {field_name, 1},
+    {field_return_type, 5},
+  [44] =
     {field_expression, 1},
-  [30] =
+  [45] =
     {field_return_expr, 1},
-  [31] =
-    {field_content, 1},
-  [32] =
+  [46] =
+    {field_part, 1, .inherited = true},
+  [47] =
+    {field_part, 0, .inherited = true},
+    {field_part, 1, .inherited = true},
+  [49] =
     {field_item, 1},
-  [33] =
+  [50] =
     {field_fraction, 2},
     {field_integer, 0},
-  [35] =
+  [52] =
     {field_function, 0},
-  [36] =
+  [53] =
     {field_lhs, 0},
     {field_oprt, 1},
     {field_rhs, 2},
-  [39] =
+  [56] =
     {field_item, 2},
     {field_object, 0},
-  [41] =
-    {field_body, 8},
+  [58] =
+    {field_body, 6},
     {field_name, 1},
     {field_param, 3},
-    {field_return_type, 6},
-  [45] =
+  [61] =
     {field_name, 1},
     {field_param, 3},
     {field_param, 4, .inherited = true},
-    {field_return_type, 7},
-  [49] =
+  [64] =
+    {field_payload, 1},
+  [65] =
+    {field_name, 0},
+    {field_payload, 2},
+    {field_payload, 3, .inherited = true},
+  [68] =
+    {field_payload, 0, .inherited = true},
+    {field_payload, 1, .inherited = true},
+  [70] =
+    {field_arg, 2},
+    {field_name, 0},
+  [72] =
+    {field_body, 7},
+    {field_name, 1},
+    {field_return_type, 5},
+  [75] =
+    {field_name, 1},
+    {field_param, 3},
+    {field_return_type, 6},
+  [78] =
     {field_condition, 1},
-  [50] =
+  [79] =
     {field_is_mut, 0},
     {field_name, 1},
-  [52] =
+  [81] =
     {field_is_ref, 0},
     {field_name, 1},
-  [54] =
+  [83] =
+    {field_value, 1},
+  [84] =
+    {field_case, 0},
+  [85] =
     {field_item, 1},
     {field_item, 2, .inherited = true},
-  [56] =
+  [87] =
     {field_item, 0, .inherited = true},
     {field_item, 1, .inherited = true},
-  [58] =
+  [89] =
+    {field_exponent, 3},
+    {field_fraction, 2},
+    {field_integer, 0},
+  [92] =
+    {field_field, 2},
+    {field_name, 0},
+  [94] =
     {field_arg, 2},
     {field_function, 0},
-  [60] =
-    {field_body, 9},
+  [96] =
+    {field_body, 7},
+    {field_name, 1},
+    {field_param, 3},
+    {field_param, 4, .inherited = true},
+  [100] =
+    {field_arg, 2},
+    {field_arg, 3},
+    {field_name, 0},
+  [103] =
+    {field_body, 8},
+    {field_name, 1},
+    {field_param, 3},
+    {field_return_type, 6},
+  [107] =
     {field_name, 1},
     {field_param, 3},
     {field_param, 4, .inherited = true},
     {field_return_type, 7},
-  [65] =
+  [111] =
+    {field_else_if, 0},
+  [112] =
+    {field_condition, 1},
+    {field_else_if, 4, .inherited = true},
+  [114] =
     {field_body, 3},
     {field_condition, 1},
-  [67] =
+  [116] =
     {field_is_mut, 0},
     {field_is_ref, 1},
     {field_name, 2},
-  [70] =
+  [119] =
+    {field_case, 3, .inherited = true},
+    {field_value, 1},
+  [121] =
+    {field_case, 0, .inherited = true},
+    {field_case, 1, .inherited = true},
+  [123] =
     {field_name, 1},
     {field_type, 3},
-  [72] =
+  [125] =
+    {field_name, 0},
+    {field_value, 2},
+  [127] =
+    {field_field, 2},
+    {field_field, 3, .inherited = true},
+    {field_name, 0},
+  [130] =
     {field_arg, 1},
-  [73] =
+  [131] =
     {field_arg, 2},
     {field_arg, 3, .inherited = true},
     {field_function, 0},
-  [76] =
+  [134] =
     {field_arg, 0, .inherited = true},
     {field_arg, 1, .inherited = true},
-  [78] =
+  [136] =
+    {field_body, 9},
+    {field_name, 1},
+    {field_param, 3},
+    {field_param, 4, .inherited = true},
+    {field_return_type, 7},
+  [141] =
+    {field_else_if, 0, .inherited = true},
+    {field_else_if, 1, .inherited = true},
+  [143] =
+    {field_body, 3},
+    {field_condition, 1},
+    {field_else_if, 5, .inherited = true},
+  [146] =
     {field_is_mut, 1},
     {field_name, 2},
     {field_type, 4},
-  [81] =
+  [149] =
     {field_condition, 1},
     {field_has_else, 4},
-  [83] =
+  [151] =
     {field_item, 2},
     {field_iterator, 4},
-  [85] =
+  [153] =
     {field_initializer, 5},
     {field_name, 1},
     {field_type, 3},
-  [88] =
+  [156] =
     {field_condition, 1},
     {field_else_body, 6},
     {field_has_else, 4},
-  [91] =
+  [159] =
+    {field_condition, 1},
+    {field_else_if, 4, .inherited = true},
+    {field_has_else, 5},
+  [162] =
     {field_body, 3},
     {field_condition, 1},
     {field_has_else, 5},
-  [94] =
+  [165] =
     {field_body, 6},
     {field_item, 2},
     {field_iterator, 4},
-  [97] =
+  [168] =
     {field_initializer, 6},
     {field_is_mut, 1},
     {field_name, 2},
     {field_type, 4},
-  [101] =
+  [172] =
+    {field_condition, 2},
+  [173] =
+    {field_condition, 1},
+    {field_else_body, 7},
+    {field_else_if, 4, .inherited = true},
+    {field_has_else, 5},
+  [177] =
     {field_body, 3},
     {field_condition, 1},
     {field_else_body, 7},
     {field_has_else, 5},
+  [181] =
+    {field_body, 3},
+    {field_condition, 1},
+    {field_else_if, 5, .inherited = true},
+    {field_has_else, 6},
+  [185] =
+    {field_binding, 1},
+  [186] =
+    {field_binding, 0, .inherited = true},
+    {field_binding, 1, .inherited = true},
+  [188] =
+    {field_body, 4},
+    {field_variant, 1},
+  [190] =
+    {field_body, 4},
+    {field_condition, 2},
+  [192] =
+    {field_body, 3},
+    {field_condition, 1},
+    {field_else_body, 8},
+    {field_else_if, 5, .inherited = true},
+    {field_has_else, 6},
+  [197] =
+    {field_binding, 3},
+    {field_variant, 1},
+  [199] =
+    {field_binding, 3},
+    {field_body, 7},
+    {field_variant, 1},
+  [202] =
+    {field_binding, 3},
+    {field_binding, 4, .inherited = true},
+    {field_variant, 1},
+  [205] =
+    {field_binding, 3},
+    {field_binding, 4, .inherited = true},
+    {field_body, 8},
+    {field_variant, 1},
 };
 
 static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
@@ -941,7 +1368,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [53] = 53,
   [54] = 54,
   [55] = 55,
-  [56] = 56,
+  [56] = 47,
   [57] = 57,
   [58] = 58,
   [59] = 59,
@@ -951,12 +1378,12 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [63] = 63,
   [64] = 64,
   [65] = 65,
-  [66] = 66,
-  [67] = 67,
-  [68] = 68,
-  [69] = 69,
-  [70] = 70,
-  [71] = 71,
+  [66] = 64,
+  [67] = 65,
+  [68] = 48,
+  [69] = 49,
+  [70] = 53,
+  [71] = 54,
   [72] = 72,
   [73] = 73,
   [74] = 74,
@@ -1021,7 +1448,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [133] = 133,
   [134] = 134,
   [135] = 135,
-  [136] = 136,
+  [136] = 118,
   [137] = 137,
   [138] = 138,
   [139] = 139,
@@ -1032,10 +1459,10 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [144] = 144,
   [145] = 145,
   [146] = 146,
-  [147] = 147,
-  [148] = 148,
-  [149] = 149,
-  [150] = 150,
+  [147] = 126,
+  [148] = 128,
+  [149] = 109,
+  [150] = 114,
   [151] = 151,
   [152] = 152,
   [153] = 153,
@@ -1055,6 +1482,296 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [167] = 167,
   [168] = 168,
   [169] = 169,
+  [170] = 170,
+  [171] = 171,
+  [172] = 172,
+  [173] = 173,
+  [174] = 174,
+  [175] = 175,
+  [176] = 176,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 180,
+  [181] = 181,
+  [182] = 182,
+  [183] = 183,
+  [184] = 184,
+  [185] = 185,
+  [186] = 186,
+  [187] = 187,
+  [188] = 188,
+  [189] = 189,
+  [190] = 190,
+  [191] = 191,
+  [192] = 192,
+  [193] = 193,
+  [194] = 194,
+  [195] = 195,
+  [196] = 196,
+  [197] = 197,
+  [198] = 198,
+  [199] = 199,
+  [200] = 200,
+  [201] = 201,
+  [202] = 202,
+  [203] = 203,
+  [204] = 204,
+  [205] = 205,
+  [206] = 206,
+  [207] = 207,
+  [208] = 208,
+  [209] = 209,
+  [210] = 210,
+  [211] = 211,
+  [212] = 212,
+  [213] = 213,
+  [214] = 214,
+  [215] = 215,
+  [216] = 216,
+  [217] = 217,
+  [218] = 218,
+  [219] = 219,
+  [220] = 220,
+  [221] = 221,
+  [222] = 222,
+  [223] = 223,
+  [224] = 224,
+  [225] = 225,
+  [226] = 226,
+  [227] = 227,
+  [228] = 228,
+  [229] = 229,
+  [230] = 230,
+  [231] = 231,
+  [232] = 232,
+  [233] = 233,
+  [234] = 234,
+  [235] = 235,
+  [236] = 236,
+  [237] = 237,
+  [238] = 238,
+  [239] = 239,
+  [240] = 240,
+  [241] = 241,
+  [242] = 242,
+  [243] = 243,
+  [244] = 244,
+  [245] = 245,
+  [246] = 246,
+  [247] = 247,
+  [248] = 248,
+  [249] = 249,
+  [250] = 250,
+  [251] = 251,
+  [252] = 252,
+  [253] = 253,
+  [254] = 254,
+  [255] = 255,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 259,
+  [260] = 260,
+  [261] = 261,
+  [262] = 262,
+  [263] = 263,
+  [264] = 264,
+  [265] = 265,
+  [266] = 266,
+  [267] = 267,
+  [268] = 268,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+  [272] = 272,
+  [273] = 273,
+  [274] = 274,
+  [275] = 275,
+  [276] = 276,
+  [277] = 277,
+  [278] = 278,
+  [279] = 279,
+  [280] = 280,
+  [281] = 281,
+  [282] = 282,
+  [283] = 283,
+  [284] = 284,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 289,
+  [290] = 290,
+  [291] = 291,
+  [292] = 292,
+  [293] = 293,
+  [294] = 294,
+  [295] = 295,
+  [296] = 296,
+  [297] = 297,
+  [298] = 298,
+  [299] = 299,
+  [300] = 300,
+  [301] = 301,
+  [302] = 302,
+  [303] = 303,
+  [304] = 304,
+  [305] = 305,
+  [306] = 306,
+  [307] = 307,
+  [308] = 308,
+  [309] = 309,
+  [310] = 310,
+  [311] = 311,
+  [312] = 312,
+  [313] = 313,
+  [314] = 314,
+  [315] = 315,
+  [316] = 316,
+  [317] = 317,
+  [318] = 318,
+  [319] = 319,
+  [320] = 320,
+  [321] = 321,
+  [322] = 322,
+  [323] = 323,
+  [324] = 324,
+  [325] = 325,
+  [326] = 326,
+  [327] = 327,
+  [328] = 328,
+  [329] = 329,
+  [330] = 330,
+  [331] = 331,
+  [332] = 332,
+  [333] = 333,
+  [334] = 334,
+  [335] = 335,
+  [336] = 336,
+  [337] = 337,
+  [338] = 338,
+  [339] = 339,
+};
+
+static TSCharacterRange sym_identifier_character_set_1[] = {
+  {'A', 'Z'}, {'_', '_'}, {'a', 'z'}, {0xaa, 0xaa}, {0xb5, 0xb5}, {0xba, 0xba}, {0xc0, 0xd6}, {0xd8, 0xf6},
+  {0xf8, 0x2c1}, {0x2c6, 0x2d1}, {0x2e0, 0x2e4}, {0x2ec, 0x2ec}, {0x2ee, 0x2ee}, {0x370, 0x374}, {0x376, 0x377}, {0x37a, 0x37d},
+  {0x37f, 0x37f}, {0x386, 0x386}, {0x388, 0x38a}, {0x38c, 0x38c}, {0x38e, 0x3a1}, {0x3a3, 0x3f5}, {0x3f7, 0x481}, {0x48a, 0x52f},
+  {0x531, 0x556}, {0x559, 0x559}, {0x560, 0x588}, {0x5d0, 0x5ea}, {0x5ef, 0x5f2}, {0x620, 0x64a}, {0x66e, 0x66f}, {0x671, 0x6d3},
+  {0x6d5, 0x6d5}, {0x6e5, 0x6e6}, {0x6ee, 0x6ef}, {0x6fa, 0x6fc}, {0x6ff, 0x6ff}, {0x710, 0x710}, {0x712, 0x72f}, {0x74d, 0x7a5},
+  {0x7b1, 0x7b1}, {0x7ca, 0x7ea}, {0x7f4, 0x7f5}, {0x7fa, 0x7fa}, {0x800, 0x815}, {0x81a, 0x81a}, {0x824, 0x824}, {0x828, 0x828},
+  {0x840, 0x858}, {0x860, 0x86a}, {0x870, 0x887}, {0x889, 0x88e}, {0x8a0, 0x8c9}, {0x904, 0x939}, {0x93d, 0x93d}, {0x950, 0x950},
+  {0x958, 0x961}, {0x971, 0x980}, {0x985, 0x98c}, {0x98f, 0x990}, {0x993, 0x9a8}, {0x9aa, 0x9b0}, {0x9b2, 0x9b2}, {0x9b6, 0x9b9},
+  {0x9bd, 0x9bd}, {0x9ce, 0x9ce}, {0x9dc, 0x9dd}, {0x9df, 0x9e1}, {0x9f0, 0x9f1}, {0x9fc, 0x9fc}, {0xa05, 0xa0a}, {0xa0f, 0xa10},
+  {0xa13, 0xa28}, {0xa2a, 0xa30}, {0xa32, 0xa33}, {0xa35, 0xa36}, {0xa38, 0xa39}, {0xa59, 0xa5c}, {0xa5e, 0xa5e}, {0xa72, 0xa74},
+  {0xa85, 0xa8d}, {0xa8f, 0xa91}, {0xa93, 0xaa8}, {0xaaa, 0xab0}, {0xab2, 0xab3}, {0xab5, 0xab9}, {0xabd, 0xabd}, {0xad0, 0xad0},
+  {0xae0, 0xae1}, {0xaf9, 0xaf9}, {0xb05, 0xb0c}, {0xb0f, 0xb10}, {0xb13, 0xb28}, {0xb2a, 0xb30}, {0xb32, 0xb33}, {0xb35, 0xb39},
+  {0xb3d, 0xb3d}, {0xb5c, 0xb5d}, {0xb5f, 0xb61}, {0xb71, 0xb71}, {0xb83, 0xb83}, {0xb85, 0xb8a}, {0xb8e, 0xb90}, {0xb92, 0xb95},
+  {0xb99, 0xb9a}, {0xb9c, 0xb9c}, {0xb9e, 0xb9f}, {0xba3, 0xba4}, {0xba8, 0xbaa}, {0xbae, 0xbb9}, {0xbd0, 0xbd0}, {0xc05, 0xc0c},
+  {0xc0e, 0xc10}, {0xc12, 0xc28}, {0xc2a, 0xc39}, {0xc3d, 0xc3d}, {0xc58, 0xc5a}, {0xc5d, 0xc5d}, {0xc60, 0xc61}, {0xc80, 0xc80},
+  {0xc85, 0xc8c}, {0xc8e, 0xc90}, {0xc92, 0xca8}, {0xcaa, 0xcb3}, {0xcb5, 0xcb9}, {0xcbd, 0xcbd}, {0xcdd, 0xcde}, {0xce0, 0xce1},
+  {0xcf1, 0xcf2}, {0xd04, 0xd0c}, {0xd0e, 0xd10}, {0xd12, 0xd3a}, {0xd3d, 0xd3d}, {0xd4e, 0xd4e}, {0xd54, 0xd56}, {0xd5f, 0xd61},
+  {0xd7a, 0xd7f}, {0xd85, 0xd96}, {0xd9a, 0xdb1}, {0xdb3, 0xdbb}, {0xdbd, 0xdbd}, {0xdc0, 0xdc6}, {0xe01, 0xe30}, {0xe32, 0xe33},
+  {0xe40, 0xe46}, {0xe81, 0xe82}, {0xe84, 0xe84}, {0xe86, 0xe8a}, {0xe8c, 0xea3}, {0xea5, 0xea5}, {0xea7, 0xeb0}, {0xeb2, 0xeb3},
+  {0xebd, 0xebd}, {0xec0, 0xec4}, {0xec6, 0xec6}, {0xedc, 0xedf}, {0xf00, 0xf00}, {0xf40, 0xf47}, {0xf49, 0xf6c}, {0xf88, 0xf8c},
+  {0x1000, 0x102a}, {0x103f, 0x103f}, {0x1050, 0x1055}, {0x105a, 0x105d}, {0x1061, 0x1061}, {0x1065, 0x1066}, {0x106e, 0x1070}, {0x1075, 0x1081},
+  {0x108e, 0x108e}, {0x10a0, 0x10c5}, {0x10c7, 0x10c7}, {0x10cd, 0x10cd}, {0x10d0, 0x10fa}, {0x10fc, 0x1248}, {0x124a, 0x124d}, {0x1250, 0x1256},
+  {0x1258, 0x1258}, {0x125a, 0x125d}, {0x1260, 0x1288}, {0x128a, 0x128d}, {0x1290, 0x12b0}, {0x12b2, 0x12b5}, {0x12b8, 0x12be}, {0x12c0, 0x12c0},
+  {0x12c2, 0x12c5}, {0x12c8, 0x12d6}, {0x12d8, 0x1310}, {0x1312, 0x1315}, {0x1318, 0x135a}, {0x1380, 0x138f}, {0x13a0, 0x13f5}, {0x13f8, 0x13fd},
+  {0x1401, 0x166c}, {0x166f, 0x167f}, {0x1681, 0x169a}, {0x16a0, 0x16ea}, {0x16f1, 0x16f8}, {0x1700, 0x1711}, {0x171f, 0x1731}, {0x1740, 0x1751},
+  {0x1760, 0x176c}, {0x176e, 0x1770}, {0x1780, 0x17b3}, {0x17d7, 0x17d7}, {0x17dc, 0x17dc}, {0x1820, 0x1878}, {0x1880, 0x1884}, {0x1887, 0x18a8},
+  {0x18aa, 0x18aa}, {0x18b0, 0x18f5}, {0x1900, 0x191e}, {0x1950, 0x196d}, {0x1970, 0x1974}, {0x1980, 0x19ab}, {0x19b0, 0x19c9}, {0x1a00, 0x1a16},
+  {0x1a20, 0x1a54}, {0x1aa7, 0x1aa7}, {0x1b05, 0x1b33}, {0x1b45, 0x1b4c}, {0x1b83, 0x1ba0}, {0x1bae, 0x1baf}, {0x1bba, 0x1be5}, {0x1c00, 0x1c23},
+  {0x1c4d, 0x1c4f}, {0x1c5a, 0x1c7d}, {0x1c80, 0x1c88}, {0x1c90, 0x1cba}, {0x1cbd, 0x1cbf}, {0x1ce9, 0x1cec}, {0x1cee, 0x1cf3}, {0x1cf5, 0x1cf6},
+  {0x1cfa, 0x1cfa}, {0x1d00, 0x1dbf}, {0x1e00, 0x1f15}, {0x1f18, 0x1f1d}, {0x1f20, 0x1f45}, {0x1f48, 0x1f4d}, {0x1f50, 0x1f57}, {0x1f59, 0x1f59},
+  {0x1f5b, 0x1f5b}, {0x1f5d, 0x1f5d}, {0x1f5f, 0x1f7d}, {0x1f80, 0x1fb4}, {0x1fb6, 0x1fbc}, {0x1fbe, 0x1fbe}, {0x1fc2, 0x1fc4}, {0x1fc6, 0x1fcc},
+  {0x1fd0, 0x1fd3}, {0x1fd6, 0x1fdb}, {0x1fe0, 0x1fec}, {0x1ff2, 0x1ff4}, {0x1ff6, 0x1ffc}, {0x2071, 0x2071}, {0x207f, 0x207f}, {0x2090, 0x209c},
+  {0x2102, 0x2102}, {0x2107, 0x2107}, {0x210a, 0x2113}, {0x2115, 0x2115}, {0x2119, 0x211d}, {0x2124, 0x2124}, {0x2126, 0x2126}, {0x2128, 0x2128},
+  {0x212a, 0x212d}, {0x212f, 0x2139}, {0x213c, 0x213f}, {0x2145, 0x2149}, {0x214e, 0x214e}, {0x2183, 0x2184}, {0x2c00, 0x2ce4}, {0x2ceb, 0x2cee},
+  {0x2cf2, 0x2cf3}, {0x2d00, 0x2d25}, {0x2d27, 0x2d27}, {0x2d2d, 0x2d2d}, {0x2d30, 0x2d67}, {0x2d6f, 0x2d6f}, {0x2d80, 0x2d96}, {0x2da0, 0x2da6},
+  {0x2da8, 0x2dae}, {0x2db0, 0x2db6}, {0x2db8, 0x2dbe}, {0x2dc0, 0x2dc6}, {0x2dc8, 0x2dce}, {0x2dd0, 0x2dd6}, {0x2dd8, 0x2dde}, {0x2e2f, 0x2e2f},
+  {0x3005, 0x3006}, {0x3031, 0x3035}, {0x303b, 0x303c}, {0x3041, 0x3096}, {0x309d, 0x309f}, {0x30a1, 0x30fa}, {0x30fc, 0x30ff}, {0x3105, 0x312f},
+  {0x3131, 0x318e}, {0x31a0, 0x31bf}, {0x31f0, 0x31ff}, {0x3400, 0x3400}, {0x4dbf, 0x4dbf}, {0x4e00, 0x4e00}, {0x9fff, 0xa48c}, {0xa4d0, 0xa4fd},
+  {0xa500, 0xa60c}, {0xa610, 0xa61f}, {0xa62a, 0xa62b}, {0xa640, 0xa66e}, {0xa67f, 0xa69d}, {0xa6a0, 0xa6e5}, {0xa717, 0xa71f}, {0xa722, 0xa788},
+  {0xa78b, 0xa7ca}, {0xa7d0, 0xa7d1}, {0xa7d3, 0xa7d3}, {0xa7d5, 0xa7d9}, {0xa7f2, 0xa801}, {0xa803, 0xa805}, {0xa807, 0xa80a}, {0xa80c, 0xa822},
+  {0xa840, 0xa873}, {0xa882, 0xa8b3}, {0xa8f2, 0xa8f7}, {0xa8fb, 0xa8fb}, {0xa8fd, 0xa8fe}, {0xa90a, 0xa925}, {0xa930, 0xa946}, {0xa960, 0xa97c},
+  {0xa984, 0xa9b2}, {0xa9cf, 0xa9cf}, {0xa9e0, 0xa9e4}, {0xa9e6, 0xa9ef}, {0xa9fa, 0xa9fe}, {0xaa00, 0xaa28}, {0xaa40, 0xaa42}, {0xaa44, 0xaa4b},
+  {0xaa60, 0xaa76}, {0xaa7a, 0xaa7a}, {0xaa7e, 0xaaaf}, {0xaab1, 0xaab1}, {0xaab5, 0xaab6}, {0xaab9, 0xaabd}, {0xaac0, 0xaac0}, {0xaac2, 0xaac2},
+  {0xaadb, 0xaadd}, {0xaae0, 0xaaea}, {0xaaf2, 0xaaf4}, {0xab01, 0xab06}, {0xab09, 0xab0e}, {0xab11, 0xab16}, {0xab20, 0xab26}, {0xab28, 0xab2e},
+  {0xab30, 0xab5a}, {0xab5c, 0xab69}, {0xab70, 0xabe2}, {0xac00, 0xac00}, {0xd7a3, 0xd7a3}, {0xd7b0, 0xd7c6}, {0xd7cb, 0xd7fb}, {0xf900, 0xfa6d},
+  {0xfa70, 0xfad9}, {0xfb00, 0xfb06}, {0xfb13, 0xfb17}, {0xfb1d, 0xfb1d}, {0xfb1f, 0xfb28}, {0xfb2a, 0xfb36}, {0xfb38, 0xfb3c}, {0xfb3e, 0xfb3e},
+  {0xfb40, 0xfb41}, {0xfb43, 0xfb44}, {0xfb46, 0xfbb1}, {0xfbd3, 0xfd3d}, {0xfd50, 0xfd8f}, {0xfd92, 0xfdc7}, {0xfdf0, 0xfdfb}, {0xfe70, 0xfe74},
+  {0xfe76, 0xfefc}, {0xff21, 0xff3a}, {0xff41, 0xff5a}, {0xff66, 0xffbe}, {0xffc2, 0xffc7}, {0xffca, 0xffcf}, {0xffd2, 0xffd7}, {0xffda, 0xffdc},
+  {0x10000, 0x1000b}, {0x1000d, 0x10026}, {0x10028, 0x1003a}, {0x1003c, 0x1003d}, {0x1003f, 0x1004d}, {0x10050, 0x1005d}, {0x10080, 0x100fa}, {0x10280, 0x1029c},
+  {0x102a0, 0x102d0}, {0x10300, 0x1031f}, {0x1032d, 0x10340}, {0x10342, 0x10349}, {0x10350, 0x10375}, {0x10380, 0x1039d}, {0x103a0, 0x103c3}, {0x103c8, 0x103cf},
+  {0x10400, 0x1049d}, {0x104b0, 0x104d3}, {0x104d8, 0x104fb}, {0x10500, 0x10527}, {0x10530, 0x10563}, {0x10570, 0x1057a}, {0x1057c, 0x1058a}, {0x1058c, 0x10592},
+  {0x10594, 0x10595}, {0x10597, 0x105a1}, {0x105a3, 0x105b1}, {0x105b3, 0x105b9}, {0x105bb, 0x105bc}, {0x10600, 0x10736}, {0x10740, 0x10755}, {0x10760, 0x10767},
+  {0x10780, 0x10785}, {0x10787, 0x107b0}, {0x107b2, 0x107ba}, {0x10800, 0x10805}, {0x10808, 0x10808}, {0x1080a, 0x10835}, {0x10837, 0x10838}, {0x1083c, 0x1083c},
+  {0x1083f, 0x10855}, {0x10860, 0x10876}, {0x10880, 0x1089e}, {0x108e0, 0x108f2}, {0x108f4, 0x108f5}, {0x10900, 0x10915}, {0x10920, 0x1092b},
+};
+
+static TSCharacterRange sym_identifier_character_set_2[] = {
+  {'0', '9'}, {'A', 'Z'}, {'_', '_'}, {'a', 'z'}, {0xaa, 0xaa}, {0xb2, 0xb3}, {0xb5, 0xb5}, {0xb9, 0xba},
+  {0xbc, 0xbe}, {0xc0, 0xd6}, {0xd8, 0xf6}, {0xf8, 0x2c1}, {0x2c6, 0x2d1}, {0x2e0, 0x2e4}, {0x2ec, 0x2ec}, {0x2ee, 0x2ee},
+  {0x370, 0x374}, {0x376, 0x377}, {0x37a, 0x37d}, {0x37f, 0x37f}, {0x386, 0x386}, {0x388, 0x38a}, {0x38c, 0x38c}, {0x38e, 0x3a1},
+  {0x3a3, 0x3f5}, {0x3f7, 0x481}, {0x48a, 0x52f}, {0x531, 0x556}, {0x559, 0x559}, {0x560, 0x588}, {0x5d0, 0x5ea}, {0x5ef, 0x5f2},
+  {0x620, 0x64a}, {0x660, 0x669}, {0x66e, 0x66f}, {0x671, 0x6d3}, {0x6d5, 0x6d5}, {0x6e5, 0x6e6}, {0x6ee, 0x6fc}, {0x6ff, 0x6ff},
+  {0x710, 0x710}, {0x712, 0x72f}, {0x74d, 0x7a5}, {0x7b1, 0x7b1}, {0x7c0, 0x7ea}, {0x7f4, 0x7f5}, {0x7fa, 0x7fa}, {0x800, 0x815},
+  {0x81a, 0x81a}, {0x824, 0x824}, {0x828, 0x828}, {0x840, 0x858}, {0x860, 0x86a}, {0x870, 0x887}, {0x889, 0x88e}, {0x8a0, 0x8c9},
+  {0x904, 0x939}, {0x93d, 0x93d}, {0x950, 0x950}, {0x958, 0x961}, {0x966, 0x96f}, {0x971, 0x980}, {0x985, 0x98c}, {0x98f, 0x990},
+  {0x993, 0x9a8}, {0x9aa, 0x9b0}, {0x9b2, 0x9b2}, {0x9b6, 0x9b9}, {0x9bd, 0x9bd}, {0x9ce, 0x9ce}, {0x9dc, 0x9dd}, {0x9df, 0x9e1},
+  {0x9e6, 0x9f1}, {0x9f4, 0x9f9}, {0x9fc, 0x9fc}, {0xa05, 0xa0a}, {0xa0f, 0xa10}, {0xa13, 0xa28}, {0xa2a, 0xa30}, {0xa32, 0xa33},
+  {0xa35, 0xa36}, {0xa38, 0xa39}, {0xa59, 0xa5c}, {0xa5e, 0xa5e}, {0xa66, 0xa6f}, {0xa72, 0xa74}, {0xa85, 0xa8d}, {0xa8f, 0xa91},
+  {0xa93, 0xaa8}, {0xaaa, 0xab0}, {0xab2, 0xab3}, {0xab5, 0xab9}, {0xabd, 0xabd}, {0xad0, 0xad0}, {0xae0, 0xae1}, {0xae6, 0xaef},
+  {0xaf9, 0xaf9}, {0xb05, 0xb0c}, {0xb0f, 0xb10}, {0xb13, 0xb28}, {0xb2a, 0xb30}, {0xb32, 0xb33}, {0xb35, 0xb39}, {0xb3d, 0xb3d},
+  {0xb5c, 0xb5d}, {0xb5f, 0xb61}, {0xb66, 0xb6f}, {0xb71, 0xb77}, {0xb83, 0xb83}, {0xb85, 0xb8a}, {0xb8e, 0xb90}, {0xb92, 0xb95},
+  {0xb99, 0xb9a}, {0xb9c, 0xb9c}, {0xb9e, 0xb9f}, {0xba3, 0xba4}, {0xba8, 0xbaa}, {0xbae, 0xbb9}, {0xbd0, 0xbd0}, {0xbe6, 0xbf2},
+  {0xc05, 0xc0c}, {0xc0e, 0xc10}, {0xc12, 0xc28}, {0xc2a, 0xc39}, {0xc3d, 0xc3d}, {0xc58, 0xc5a}, {0xc5d, 0xc5d}, {0xc60, 0xc61},
+  {0xc66, 0xc6f}, {0xc78, 0xc7e}, {0xc80, 0xc80}, {0xc85, 0xc8c}, {0xc8e, 0xc90}, {0xc92, 0xca8}, {0xcaa, 0xcb3}, {0xcb5, 0xcb9},
+  {0xcbd, 0xcbd}, {0xcdd, 0xcde}, {0xce0, 0xce1}, {0xce6, 0xcef}, {0xcf1, 0xcf2}, {0xd04, 0xd0c}, {0xd0e, 0xd10}, {0xd12, 0xd3a},
+  {0xd3d, 0xd3d}, {0xd4e, 0xd4e}, {0xd54, 0xd56}, {0xd58, 0xd61}, {0xd66, 0xd78}, {0xd7a, 0xd7f}, {0xd85, 0xd96}, {0xd9a, 0xdb1},
+  {0xdb3, 0xdbb}, {0xdbd, 0xdbd}, {0xdc0, 0xdc6}, {0xde6, 0xdef}, {0xe01, 0xe30}, {0xe32, 0xe33}, {0xe40, 0xe46}, {0xe50, 0xe59},
+  {0xe81, 0xe82}, {0xe84, 0xe84}, {0xe86, 0xe8a}, {0xe8c, 0xea3}, {0xea5, 0xea5}, {0xea7, 0xeb0}, {0xeb2, 0xeb3}, {0xebd, 0xebd},
+  {0xec0, 0xec4}, {0xec6, 0xec6}, {0xed0, 0xed9}, {0xedc, 0xedf}, {0xf00, 0xf00}, {0xf20, 0xf33}, {0xf40, 0xf47}, {0xf49, 0xf6c},
+  {0xf88, 0xf8c}, {0x1000, 0x102a}, {0x103f, 0x1049}, {0x1050, 0x1055}, {0x105a, 0x105d}, {0x1061, 0x1061}, {0x1065, 0x1066}, {0x106e, 0x1070},
+  {0x1075, 0x1081}, {0x108e, 0x108e}, {0x1090, 0x1099}, {0x10a0, 0x10c5}, {0x10c7, 0x10c7}, {0x10cd, 0x10cd}, {0x10d0, 0x10fa}, {0x10fc, 0x1248},
+  {0x124a, 0x124d}, {0x1250, 0x1256}, {0x1258, 0x1258}, {0x125a, 0x125d}, {0x1260, 0x1288}, {0x128a, 0x128d}, {0x1290, 0x12b0}, {0x12b2, 0x12b5},
+  {0x12b8, 0x12be}, {0x12c0, 0x12c0}, {0x12c2, 0x12c5}, {0x12c8, 0x12d6}, {0x12d8, 0x1310}, {0x1312, 0x1315}, {0x1318, 0x135a}, {0x1369, 0x137c},
+  {0x1380, 0x138f}, {0x13a0, 0x13f5}, {0x13f8, 0x13fd}, {0x1401, 0x166c}, {0x166f, 0x167f}, {0x1681, 0x169a}, {0x16a0, 0x16ea}, {0x16ee, 0x16f8},
+  {0x1700, 0x1711}, {0x171f, 0x1731}, {0x1740, 0x1751}, {0x1760, 0x176c}, {0x176e, 0x1770}, {0x1780, 0x17b3}, {0x17d7, 0x17d7}, {0x17dc, 0x17dc},
+  {0x17e0, 0x17e9}, {0x17f0, 0x17f9}, {0x1810, 0x1819}, {0x1820, 0x1878}, {0x1880, 0x1884}, {0x1887, 0x18a8}, {0x18aa, 0x18aa}, {0x18b0, 0x18f5},
+  {0x1900, 0x191e}, {0x1946, 0x196d}, {0x1970, 0x1974}, {0x1980, 0x19ab}, {0x19b0, 0x19c9}, {0x19d0, 0x19da}, {0x1a00, 0x1a16}, {0x1a20, 0x1a54},
+  {0x1a80, 0x1a89}, {0x1a90, 0x1a99}, {0x1aa7, 0x1aa7}, {0x1b05, 0x1b33}, {0x1b45, 0x1b4c}, {0x1b50, 0x1b59}, {0x1b83, 0x1ba0}, {0x1bae, 0x1be5},
+  {0x1c00, 0x1c23}, {0x1c40, 0x1c49}, {0x1c4d, 0x1c7d}, {0x1c80, 0x1c88}, {0x1c90, 0x1cba}, {0x1cbd, 0x1cbf}, {0x1ce9, 0x1cec}, {0x1cee, 0x1cf3},
+  {0x1cf5, 0x1cf6}, {0x1cfa, 0x1cfa}, {0x1d00, 0x1dbf}, {0x1e00, 0x1f15}, {0x1f18, 0x1f1d}, {0x1f20, 0x1f45}, {0x1f48, 0x1f4d}, {0x1f50, 0x1f57},
+  {0x1f59, 0x1f59}, {0x1f5b, 0x1f5b}, {0x1f5d, 0x1f5d}, {0x1f5f, 0x1f7d}, {0x1f80, 0x1fb4}, {0x1fb6, 0x1fbc}, {0x1fbe, 0x1fbe}, {0x1fc2, 0x1fc4},
+  {0x1fc6, 0x1fcc}, {0x1fd0, 0x1fd3}, {0x1fd6, 0x1fdb}, {0x1fe0, 0x1fec}, {0x1ff2, 0x1ff4}, {0x1ff6, 0x1ffc}, {0x2070, 0x2071}, {0x2074, 0x2079},
+  {0x207f, 0x2089}, {0x2090, 0x209c}, {0x2102, 0x2102}, {0x2107, 0x2107}, {0x210a, 0x2113}, {0x2115, 0x2115}, {0x2119, 0x211d}, {0x2124, 0x2124},
+  {0x2126, 0x2126}, {0x2128, 0x2128}, {0x212a, 0x212d}, {0x212f, 0x2139}, {0x213c, 0x213f}, {0x2145, 0x2149}, {0x214e, 0x214e}, {0x2150, 0x2189},
+  {0x2460, 0x249b}, {0x24ea, 0x24ff}, {0x2776, 0x2793}, {0x2c00, 0x2ce4}, {0x2ceb, 0x2cee}, {0x2cf2, 0x2cf3}, {0x2cfd, 0x2cfd}, {0x2d00, 0x2d25},
+  {0x2d27, 0x2d27}, {0x2d2d, 0x2d2d}, {0x2d30, 0x2d67}, {0x2d6f, 0x2d6f}, {0x2d80, 0x2d96}, {0x2da0, 0x2da6}, {0x2da8, 0x2dae}, {0x2db0, 0x2db6},
+  {0x2db8, 0x2dbe}, {0x2dc0, 0x2dc6}, {0x2dc8, 0x2dce}, {0x2dd0, 0x2dd6}, {0x2dd8, 0x2dde}, {0x2e2f, 0x2e2f}, {0x3005, 0x3007}, {0x3021, 0x3029},
+  {0x3031, 0x3035}, {0x3038, 0x303c}, {0x3041, 0x3096}, {0x309d, 0x309f}, {0x30a1, 0x30fa}, {0x30fc, 0x30ff}, {0x3105, 0x312f}, {0x3131, 0x318e},
+  {0x3192, 0x3195}, {0x31a0, 0x31bf}, {0x31f0, 0x31ff}, {0x3220, 0x3229}, {0x3248, 0x324f}, {0x3251, 0x325f}, {0x3280, 0x3289}, {0x32b1, 0x32bf},
+  {0x3400, 0x3400}, {0x4dbf, 0x4dbf}, {0x4e00, 0x4e00}, {0x9fff, 0xa48c}, {0xa4d0, 0xa4fd}, {0xa500, 0xa60c}, {0xa610, 0xa62b}, {0xa640, 0xa66e},
+  {0xa67f, 0xa69d}, {0xa6a0, 0xa6ef}, {0xa717, 0xa71f}, {0xa722, 0xa788}, {0xa78b, 0xa7ca}, {0xa7d0, 0xa7d1}, {0xa7d3, 0xa7d3}, {0xa7d5, 0xa7d9},
+  {0xa7f2, 0xa801}, {0xa803, 0xa805}, {0xa807, 0xa80a}, {0xa80c, 0xa822}, {0xa830, 0xa835}, {0xa840, 0xa873}, {0xa882, 0xa8b3}, {0xa8d0, 0xa8d9},
+  {0xa8f2, 0xa8f7}, {0xa8fb, 0xa8fb}, {0xa8fd, 0xa8fe}, {0xa900, 0xa925}, {0xa930, 0xa946}, {0xa960, 0xa97c}, {0xa984, 0xa9b2}, {0xa9cf, 0xa9d9},
+  {0xa9e0, 0xa9e4}, {0xa9e6, 0xa9fe}, {0xaa00, 0xaa28}, {0xaa40, 0xaa42}, {0xaa44, 0xaa4b}, {0xaa50, 0xaa59}, {0xaa60, 0xaa76}, {0xaa7a, 0xaa7a},
+  {0xaa7e, 0xaaaf}, {0xaab1, 0xaab1}, {0xaab5, 0xaab6}, {0xaab9, 0xaabd}, {0xaac0, 0xaac0}, {0xaac2, 0xaac2}, {0xaadb, 0xaadd}, {0xaae0, 0xaaea},
+  {0xaaf2, 0xaaf4}, {0xab01, 0xab06}, {0xab09, 0xab0e}, {0xab11, 0xab16}, {0xab20, 0xab26}, {0xab28, 0xab2e}, {0xab30, 0xab5a}, {0xab5c, 0xab69},
+  {0xab70, 0xabe2}, {0xabf0, 0xabf9}, {0xac00, 0xac00}, {0xd7a3, 0xd7a3}, {0xd7b0, 0xd7c6}, {0xd7cb, 0xd7fb}, {0xf900, 0xfa6d}, {0xfa70, 0xfad9},
+  {0xfb00, 0xfb06}, {0xfb13, 0xfb17}, {0xfb1d, 0xfb1d}, {0xfb1f, 0xfb28}, {0xfb2a, 0xfb36}, {0xfb38, 0xfb3c}, {0xfb3e, 0xfb3e}, {0xfb40, 0xfb41},
+  {0xfb43, 0xfb44}, {0xfb46, 0xfbb1}, {0xfbd3, 0xfd3d}, {0xfd50, 0xfd8f}, {0xfd92, 0xfdc7}, {0xfdf0, 0xfdfb}, {0xfe70, 0xfe74}, {0xfe76, 0xfefc},
+  {0xff10, 0xff19}, {0xff21, 0xff3a}, {0xff41, 0xff5a}, {0xff66, 0xffbe}, {0xffc2, 0xffc7}, {0xffca, 0xffcf}, {0xffd2, 0xffd7}, {0xffda, 0xffdc},
+  {0x10000, 0x1000b}, {0x1000d, 0x10026}, {0x10028, 0x1003a}, {0x1003c, 0x1003d}, {0x1003f, 0x1004d}, {0x10050, 0x1005d}, {0x10080, 0x100fa}, {0x10107, 0x10133},
+  {0x10140, 0x10178}, {0x1018a, 0x1018b}, {0x10280, 0x1029c}, {0x102a0, 0x102d0}, {0x102e1, 0x102fb}, {0x10300, 0x10323}, {0x1032d, 0x1034a}, {0x10350, 0x10375},
+  {0x10380, 0x1039d}, {0x103a0, 0x103c3}, {0x103c8, 0x103cf}, {0x103d1, 0x103d5}, {0x10400, 0x1049d}, {0x104a0, 0x104a9}, {0x104b0, 0x104d3}, {0x104d8, 0x104fb},
+  {0x10500, 0x10527}, {0x10530, 0x10563}, {0x10570, 0x1057a}, {0x1057c, 0x1058a}, {0x1058c, 0x10592}, {0x10594, 0x10595}, {0x10597, 0x105a1}, {0x105a3, 0x105b1},
+  {0x105b3, 0x105b9}, {0x105bb, 0x105bc}, {0x10600, 0x10736}, {0x10740, 0x10755}, {0x10760, 0x10767}, {0x10780, 0x10785}, {0x10787, 0x107b0}, {0x107b2, 0x107ba},
+  {0x10800, 0x10805}, {0x10808, 0x10808}, {0x1080a, 0x10835}, {0x10837, 0x10838}, {0x1083c, 0x1083c}, {0x1083f, 0x10855}, {0x10858, 0x10876}, {0x10879, 0x1089e},
+  {0x108a7, 0x108af}, {0x108e0, 0x108f2}, {0x108f4, 0x108f5}, {0x108fb, 0x1091b}, {0x10920, 0x1092b},
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -1063,132 +1780,198 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   switch (state) {
     case 0:
       if (eof) ADVANCE(16);
-      if (lookahead == '!') ADVANCE(10);
-      if (lookahead == '"') ADVANCE(44);
-      if (lookahead == '#') SKIP(14)
-      if (lookahead == '(') ADVANCE(17);
-      if (lookahead == ')') ADVANCE(19);
-      if (lookahead == '*') ADVANCE(40);
-      if (lookahead == '+') ADVANCE(36);
-      if (lookahead == ',') ADVANCE(18);
-      if (lookahead == '-') ADVANCE(38);
-      if (lookahead == '.') ADVANCE(43);
-      if (lookahead == '/') ADVANCE(41);
-      if (lookahead == '0') ADVANCE(48);
-      if (lookahead == ':') ADVANCE(23);
-      if (lookahead == ';') ADVANCE(24);
-      if (lookahead == '<') ADVANCE(31);
-      if (lookahead == '=') ADVANCE(27);
-      if (lookahead == '>') ADVANCE(33);
-      if (lookahead == '[') ADVANCE(45);
-      if (lookahead == ']') ADVANCE(46);
-      if (lookahead == 'n') ADVANCE(57);
-      if (lookahead == 's') ADVANCE(55);
-      if (lookahead == '{') ADVANCE(21);
-      if (lookahead == '}') ADVANCE(22);
+      ADVANCE_MAP(
+        '!', 6,
+        '"', 51,
+        '#', 63,
+        '(', 17,
+        ')', 19,
+        '*', 47,
+        '+', 43,
+        ',', 18,
+        '-', 45,
+        '.', 49,
+        '/', 48,
+        '0', 56,
+        ':', 23,
+        ';', 24,
+        '<', 38,
+        '=', 28,
+        '>', 40,
+        'E', 73,
+        '[', 53,
+        '\\', 70,
+        ']', 54,
+        'e', 30,
+        'o', 72,
+        '{', 52,
+        '}', 22,
+      );
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') SKIP(0)
-      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(49);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(59);
+          lookahead == ' ') SKIP(14);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(57);
+      if (set_contains(sym_identifier_character_set_1, 431, lookahead)) ADVANCE(74);
       END_STATE();
     case 1:
-      if (lookahead == '\n') SKIP(8)
-      if (lookahead == '!') SKIP(2)
+      ADVANCE_MAP(
+        '!', 6,
+        '#', 63,
+        '(', 17,
+        ')', 19,
+        '*', 47,
+        '+', 43,
+        ',', 18,
+        '-', 44,
+        '.', 49,
+        '/', 48,
+        ';', 24,
+        '<', 38,
+        '=', 27,
+        '>', 40,
+        'E', 12,
+        ']', 54,
+        'e', 31,
+        'o', 9,
+        '{', 21,
+        '}', 22,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(2);
       END_STATE();
     case 2:
-      if (lookahead == '\n') SKIP(1)
+      ADVANCE_MAP(
+        '!', 6,
+        '#', 63,
+        '(', 17,
+        ')', 19,
+        '*', 47,
+        '+', 43,
+        ',', 18,
+        '-', 44,
+        '.', 49,
+        '/', 48,
+        ';', 24,
+        '<', 38,
+        '=', 27,
+        '>', 40,
+        ']', 54,
+        'e', 29,
+        'o', 9,
+        '{', 21,
+        '}', 22,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(2);
       END_STATE();
     case 3:
-      if (lookahead == '\n') SKIP(7)
-      if (lookahead == '!') SKIP(5)
+      if (lookahead == '"') ADVANCE(51);
+      if (lookahead == '#') ADVANCE(66);
+      if (lookahead == '\\') ADVANCE(70);
+      if (lookahead == '{') ADVANCE(52);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(67);
+      if (lookahead != 0) ADVANCE(68);
       END_STATE();
     case 4:
-      if (lookahead == '\n') SKIP(9)
-      if (lookahead == '!') SKIP(6)
+      if (lookahead == '#') ADVANCE(63);
+      if (lookahead == '(') ADVANCE(17);
+      if (lookahead == '-') ADVANCE(7);
+      if (lookahead == '=') ADVANCE(8);
+      if (lookahead == '{') ADVANCE(21);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(4);
       END_STATE();
     case 5:
-      if (lookahead == '\n') SKIP(3)
+      if (lookahead == '#') ADVANCE(65);
+      if (lookahead == '}') ADVANCE(5);
+      if (lookahead != 0) ADVANCE(11);
       END_STATE();
     case 6:
-      if (lookahead == '\n') SKIP(4)
+      if (lookahead == '=') ADVANCE(36);
       END_STATE();
     case 7:
-      if (lookahead == '!') ADVANCE(10);
-      if (lookahead == '#') SKIP(3)
-      if (lookahead == '(') ADVANCE(17);
-      if (lookahead == ')') ADVANCE(19);
-      if (lookahead == '*') ADVANCE(40);
-      if (lookahead == '+') ADVANCE(36);
-      if (lookahead == ',') ADVANCE(18);
-      if (lookahead == '-') ADVANCE(37);
-      if (lookahead == '.') ADVANCE(43);
-      if (lookahead == '/') ADVANCE(41);
-      if (lookahead == ';') ADVANCE(24);
-      if (lookahead == '<') ADVANCE(31);
-      if (lookahead == '=') ADVANCE(27);
-      if (lookahead == '>') ADVANCE(33);
-      if (lookahead == ']') ADVANCE(46);
-      if (lookahead == '{') ADVANCE(21);
-      if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') SKIP(7)
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(59);
+      if (lookahead == '>') ADVANCE(20);
       END_STATE();
     case 8:
-      if (lookahead == '"') ADVANCE(44);
-      if (lookahead == '#') SKIP(1)
-      if (lookahead == '(') ADVANCE(17);
-      if (lookahead == ')') ADVANCE(19);
-      if (lookahead == '*') ADVANCE(40);
-      if (lookahead == ',') ADVANCE(18);
-      if (lookahead == '-') ADVANCE(39);
-      if (lookahead == '0') ADVANCE(48);
-      if (lookahead == ';') ADVANCE(24);
-      if (lookahead == '<') ADVANCE(30);
-      if (lookahead == '=') ADVANCE(26);
-      if (lookahead == '>') ADVANCE(32);
-      if (lookahead == '[') ADVANCE(45);
-      if (lookahead == ']') ADVANCE(46);
-      if (lookahead == 'n') ADVANCE(57);
-      if (lookahead == 's') ADVANCE(55);
-      if (lookahead == '{') ADVANCE(21);
-      if (lookahead == '}') ADVANCE(22);
-      if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') SKIP(8)
-      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(49);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(59);
+      if (lookahead == '>') ADVANCE(25);
       END_STATE();
     case 9:
-      if (lookahead == '#') SKIP(4)
-      if (lookahead == '-') ADVANCE(11);
-      if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') SKIP(9)
+      if (lookahead == 'u') ADVANCE(33);
       END_STATE();
     case 10:
-      if (lookahead == '=') ADVANCE(29);
+      if (lookahead == '}') ADVANCE(69);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '"') ADVANCE(10);
       END_STATE();
     case 11:
-      if (lookahead == '>') ADVANCE(20);
+      if (lookahead == '}') ADVANCE(5);
+      if (lookahead != 0) ADVANCE(11);
       END_STATE();
     case 12:
-      if (lookahead == 'o') ADVANCE(42);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(13);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(60);
       END_STATE();
     case 13:
-      if (lookahead == 'o') ADVANCE(25);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(60);
       END_STATE();
     case 14:
       if (eof) ADVANCE(16);
-      if (lookahead == '\n') SKIP(0)
-      if (lookahead == '!') SKIP(15)
+      ADVANCE_MAP(
+        '!', 6,
+        '"', 50,
+        '#', 63,
+        '(', 17,
+        ')', 19,
+        '*', 47,
+        '+', 43,
+        ',', 18,
+        '-', 45,
+        '.', 49,
+        '/', 48,
+        '0', 56,
+        ':', 23,
+        ';', 24,
+        '<', 38,
+        '=', 28,
+        '>', 40,
+        '[', 53,
+        ']', 54,
+        'e', 32,
+        'o', 72,
+        '{', 21,
+        '}', 22,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(14);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(57);
+      if (set_contains(sym_identifier_character_set_1, 431, lookahead)) ADVANCE(74);
       END_STATE();
     case 15:
       if (eof) ADVANCE(16);
-      if (lookahead == '\n') SKIP(14)
+      ADVANCE_MAP(
+        '"', 50,
+        '#', 63,
+        '(', 17,
+        ')', 19,
+        '*', 47,
+        ',', 18,
+        '-', 46,
+        '0', 56,
+        ';', 24,
+        '<', 37,
+        '=', 26,
+        '>', 39,
+        '[', 53,
+        ']', 54,
+        '{', 21,
+        '}', 22,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(15);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(57);
+      if (set_contains(sym_identifier_character_set_1, 431, lookahead)) ADVANCE(74);
       END_STATE();
     case 16:
       ACCEPT_TOKEN(ts_builtin_sym_end);
@@ -1218,158 +2001,219 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
       ACCEPT_TOKEN(anon_sym_SEMI);
       END_STATE();
     case 25:
-      ACCEPT_TOKEN(anon_sym_senu00e3o);
+      ACCEPT_TOKEN(anon_sym_EQ_GT);
       END_STATE();
     case 26:
       ACCEPT_TOKEN(anon_sym_EQ);
       END_STATE();
     case 27:
       ACCEPT_TOKEN(anon_sym_EQ);
-      if (lookahead == '=') ADVANCE(28);
+      if (lookahead == '=') ADVANCE(35);
       END_STATE();
     case 28:
-      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == '=') ADVANCE(35);
+      if (lookahead == '>') ADVANCE(25);
       END_STATE();
     case 29:
-      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      ACCEPT_TOKEN(anon_sym_e);
       END_STATE();
     case 30:
-      ACCEPT_TOKEN(anon_sym_LT);
+      ACCEPT_TOKEN(anon_sym_e);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(13);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(59);
+      if (set_contains(sym_identifier_character_set_2, 477, lookahead)) ADVANCE(74);
       END_STATE();
     case 31:
-      ACCEPT_TOKEN(anon_sym_LT);
-      if (lookahead == '=') ADVANCE(34);
+      ACCEPT_TOKEN(anon_sym_e);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(13);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(60);
       END_STATE();
     case 32:
-      ACCEPT_TOKEN(anon_sym_GT);
+      ACCEPT_TOKEN(anon_sym_e);
+      if (set_contains(sym_identifier_character_set_2, 477, lookahead)) ADVANCE(74);
       END_STATE();
     case 33:
-      ACCEPT_TOKEN(anon_sym_GT);
-      if (lookahead == '=') ADVANCE(35);
+      ACCEPT_TOKEN(anon_sym_ou);
       END_STATE();
     case 34:
-      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      ACCEPT_TOKEN(anon_sym_ou);
+      if (set_contains(sym_identifier_character_set_2, 477, lookahead)) ADVANCE(74);
       END_STATE();
     case 35:
-      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      ACCEPT_TOKEN(anon_sym_EQ_EQ);
       END_STATE();
     case 36:
-      ACCEPT_TOKEN(anon_sym_PLUS);
+      ACCEPT_TOKEN(anon_sym_BANG_EQ);
       END_STATE();
     case 37:
-      ACCEPT_TOKEN(anon_sym_DASH);
+      ACCEPT_TOKEN(anon_sym_LT);
       END_STATE();
     case 38:
-      ACCEPT_TOKEN(anon_sym_DASH);
-      if (lookahead == '0') ADVANCE(47);
-      if (lookahead == '>') ADVANCE(20);
-      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(49);
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '=') ADVANCE(41);
       END_STATE();
     case 39:
-      ACCEPT_TOKEN(anon_sym_DASH);
-      if (lookahead == '0') ADVANCE(47);
-      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(49);
+      ACCEPT_TOKEN(anon_sym_GT);
       END_STATE();
     case 40:
-      ACCEPT_TOKEN(anon_sym_STAR);
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(42);
       END_STATE();
     case 41:
-      ACCEPT_TOKEN(anon_sym_SLASH);
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
       END_STATE();
     case 42:
-      ACCEPT_TOKEN(anon_sym_nu00e3o);
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
       END_STATE();
     case 43:
-      ACCEPT_TOKEN(anon_sym_DOT);
+      ACCEPT_TOKEN(anon_sym_PLUS);
       END_STATE();
     case 44:
-      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      ACCEPT_TOKEN(anon_sym_DASH);
       END_STATE();
     case 45:
-      ACCEPT_TOKEN(anon_sym_LBRACK);
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '0') ADVANCE(55);
+      if (lookahead == '>') ADVANCE(20);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(57);
       END_STATE();
     case 46:
-      ACCEPT_TOKEN(anon_sym_RBRACK);
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '0') ADVANCE(55);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(57);
       END_STATE();
     case 47:
-      ACCEPT_TOKEN(sym_integer_literal);
+      ACCEPT_TOKEN(anon_sym_STAR);
       END_STATE();
     case 48:
-      ACCEPT_TOKEN(sym_integer_literal);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(50);
+      ACCEPT_TOKEN(anon_sym_SLASH);
       END_STATE();
     case 49:
-      ACCEPT_TOKEN(sym_integer_literal);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(49);
+      ACCEPT_TOKEN(anon_sym_DOT);
       END_STATE();
     case 50:
-      ACCEPT_TOKEN(sym_fractional_literal);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(50);
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
       END_STATE();
     case 51:
-      ACCEPT_TOKEN(sym_string_content);
-      if (lookahead == '\n') ADVANCE(53);
-      if (lookahead == '!') ADVANCE(52);
-      if (lookahead != 0 &&
-          lookahead != '"') ADVANCE(54);
+      ACCEPT_TOKEN(anon_sym_DQUOTE2);
       END_STATE();
     case 52:
+      ACCEPT_TOKEN(anon_sym_LBRACE2);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 55:
+      ACCEPT_TOKEN(sym_integer_literal);
+      END_STATE();
+    case 56:
+      ACCEPT_TOKEN(sym_integer_literal);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(58);
+      END_STATE();
+    case 57:
+      ACCEPT_TOKEN(sym_integer_literal);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(57);
+      END_STATE();
+    case 58:
+      ACCEPT_TOKEN(sym_fractional_literal);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(58);
+      END_STATE();
+    case 59:
+      ACCEPT_TOKEN(sym_exponent_literal);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(59);
+      if (set_contains(sym_identifier_character_set_2, 477, lookahead)) ADVANCE(74);
+      END_STATE();
+    case 60:
+      ACCEPT_TOKEN(sym_exponent_literal);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(60);
+      END_STATE();
+    case 61:
+      ACCEPT_TOKEN(sym_line_comment);
+      if (lookahead == '\n') ADVANCE(11);
+      if (lookahead == '#') ADVANCE(65);
+      if (lookahead == '}') ADVANCE(61);
+      if (lookahead != 0) ADVANCE(62);
+      END_STATE();
+    case 62:
+      ACCEPT_TOKEN(sym_line_comment);
+      if (lookahead == '\n') ADVANCE(11);
+      if (lookahead == '}') ADVANCE(61);
+      if (lookahead != 0) ADVANCE(62);
+      END_STATE();
+    case 63:
+      ACCEPT_TOKEN(sym_line_comment);
+      if (lookahead == '{') ADVANCE(62);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(64);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(sym_line_comment);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(64);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(sym_block_comment);
+      END_STATE();
+    case 66:
       ACCEPT_TOKEN(sym_string_content);
-      if (lookahead == '\n') ADVANCE(51);
+      if (lookahead == '\n') ADVANCE(68);
       if (lookahead != 0 &&
-          lookahead != '"') ADVANCE(54);
+          lookahead != '"' &&
+          lookahead != '\\' &&
+          lookahead != '{') ADVANCE(66);
       END_STATE();
-    case 53:
+    case 67:
       ACCEPT_TOKEN(sym_string_content);
-      if (lookahead == '#') ADVANCE(51);
+      if (lookahead == '#') ADVANCE(66);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(53);
+          lookahead == ' ') ADVANCE(67);
       if (lookahead != 0 &&
-          lookahead != '"') ADVANCE(54);
+          lookahead != '"' &&
+          lookahead != '#' &&
+          lookahead != '\\' &&
+          lookahead != '{') ADVANCE(68);
       END_STATE();
-    case 54:
+    case 68:
       ACCEPT_TOKEN(sym_string_content);
       if (lookahead != 0 &&
-          lookahead != '"') ADVANCE(54);
+          lookahead != '"' &&
+          lookahead != '\\' &&
+          lookahead != '{') ADVANCE(68);
       END_STATE();
-    case 55:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(56);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(59);
+    case 69:
+      ACCEPT_TOKEN(sym_escape_sequence);
       END_STATE();
-    case 56:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'n') ADVANCE(58);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(59);
+    case 70:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      if (lookahead == 'u') ADVANCE(71);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(69);
       END_STATE();
-    case 57:
+    case 71:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      if (lookahead == '{') ADVANCE(10);
+      END_STATE();
+    case 72:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 227) ADVANCE(12);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(59);
+      if (lookahead == 'u') ADVANCE(34);
+      if (set_contains(sym_identifier_character_set_2, 477, lookahead)) ADVANCE(74);
       END_STATE();
-    case 58:
+    case 73:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 227) ADVANCE(13);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(59);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(13);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(59);
+      if (set_contains(sym_identifier_character_set_2, 477, lookahead)) ADVANCE(74);
       END_STATE();
-    case 59:
+    case 74:
       ACCEPT_TOKEN(sym_identifier);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(59);
+      if (set_contains(sym_identifier_character_set_2, 477, lookahead)) ADVANCE(74);
       END_STATE();
     default:
       return false;
@@ -1381,226 +2225,339 @@ static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (lookahead == '#') SKIP(1)
-      if (lookahead == 'c') ADVANCE(2);
-      if (lookahead == 'e') ADVANCE(3);
-      if (lookahead == 'f') ADVANCE(4);
-      if (lookahead == 'm') ADVANCE(5);
-      if (lookahead == 'o') ADVANCE(6);
-      if (lookahead == 'p') ADVANCE(7);
-      if (lookahead == 'r') ADVANCE(8);
-      if (lookahead == 's') ADVANCE(9);
-      if (lookahead == 'v') ADVANCE(10);
+      ADVANCE_MAP(
+        'c', 1,
+        'e', 2,
+        'f', 3,
+        'm', 4,
+        'n', 5,
+        'p', 6,
+        'r', 7,
+        's', 8,
+        'v', 9,
+      );
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') SKIP(0)
+          lookahead == ' ') SKIP(0);
       END_STATE();
     case 1:
-      if (lookahead == '\n') SKIP(0)
-      if (lookahead == '!') SKIP(11)
+      if (lookahead == 'a') ADVANCE(10);
+      if (lookahead == 'o') ADVANCE(11);
       END_STATE();
     case 2:
-      if (lookahead == 'a') ADVANCE(12);
-      if (lookahead == 'o') ADVANCE(13);
+      if (lookahead == 'm') ADVANCE(12);
+      if (lookahead == 'n') ADVANCE(13);
+      if (lookahead == 's') ADVANCE(14);
       END_STATE();
     case 3:
-      ACCEPT_TOKEN(anon_sym_e);
-      if (lookahead == 'm') ADVANCE(14);
-      if (lookahead == 'n') ADVANCE(15);
+      if (lookahead == 'a') ADVANCE(15);
+      if (lookahead == 'u') ADVANCE(16);
       END_STATE();
     case 4:
-      if (lookahead == 'a') ADVANCE(16);
       if (lookahead == 'u') ADVANCE(17);
       END_STATE();
     case 5:
-      if (lookahead == 'u') ADVANCE(18);
+      if (lookahead == 0xe3) ADVANCE(18);
       END_STATE();
     case 6:
-      if (lookahead == 'u') ADVANCE(19);
+      if (lookahead == 'a') ADVANCE(19);
+      if (lookahead == 'r') ADVANCE(20);
       END_STATE();
     case 7:
-      if (lookahead == 'a') ADVANCE(20);
-      END_STATE();
-    case 8:
       if (lookahead == 'e') ADVANCE(21);
       END_STATE();
-    case 9:
+    case 8:
       if (lookahead == 'e') ADVANCE(22);
       END_STATE();
-    case 10:
+    case 9:
       if (lookahead == 'a') ADVANCE(23);
       if (lookahead == 'e') ADVANCE(24);
       END_STATE();
+    case 10:
+      if (lookahead == 'd') ADVANCE(25);
+      if (lookahead == 's') ADVANCE(26);
+      END_STATE();
     case 11:
-      if (lookahead == '\n') SKIP(1)
+      if (lookahead == 'm') ADVANCE(27);
+      if (lookahead == 'n') ADVANCE(28);
       END_STATE();
     case 12:
-      if (lookahead == 'd') ADVANCE(25);
+      ACCEPT_TOKEN(anon_sym_em);
       END_STATE();
     case 13:
-      if (lookahead == 'm') ADVANCE(26);
-      if (lookahead == 'n') ADVANCE(27);
+      if (lookahead == 'q') ADVANCE(29);
+      if (lookahead == 'u') ADVANCE(30);
       END_STATE();
     case 14:
-      ACCEPT_TOKEN(anon_sym_em);
+      if (lookahead == 'c') ADVANCE(31);
+      if (lookahead == 't') ADVANCE(32);
       END_STATE();
     case 15:
-      if (lookahead == 'q') ADVANCE(28);
+      if (lookahead == 'l') ADVANCE(33);
       END_STATE();
     case 16:
-      if (lookahead == 'l') ADVANCE(29);
+      if (lookahead == 'n') ADVANCE(34);
       END_STATE();
     case 17:
-      if (lookahead == 'n') ADVANCE(30);
+      if (lookahead == 't') ADVANCE(35);
       END_STATE();
     case 18:
-      if (lookahead == 't') ADVANCE(31);
+      if (lookahead == 'o') ADVANCE(36);
       END_STATE();
     case 19:
-      ACCEPT_TOKEN(anon_sym_ou);
+      if (lookahead == 'r') ADVANCE(37);
       END_STATE();
     case 20:
-      if (lookahead == 'r') ADVANCE(32);
+      if (lookahead == 'o') ADVANCE(38);
       END_STATE();
     case 21:
-      if (lookahead == 'f') ADVANCE(33);
-      if (lookahead == 't') ADVANCE(34);
+      if (lookahead == 'f') ADVANCE(39);
+      if (lookahead == 't') ADVANCE(40);
       END_STATE();
     case 22:
       ACCEPT_TOKEN(anon_sym_se);
+      if (lookahead == 'n') ADVANCE(41);
       END_STATE();
     case 23:
-      if (lookahead == 'r') ADVANCE(35);
+      if (lookahead == 'r') ADVANCE(42);
       END_STATE();
     case 24:
-      if (lookahead == 'r') ADVANCE(36);
+      if (lookahead == 'r') ADVANCE(43);
       END_STATE();
     case 25:
-      if (lookahead == 'a') ADVANCE(37);
+      if (lookahead == 'a') ADVANCE(44);
       END_STATE();
     case 26:
-      if (lookahead == 'p') ADVANCE(38);
+      if (lookahead == 'o') ADVANCE(45);
       END_STATE();
     case 27:
-      if (lookahead == 't') ADVANCE(39);
+      if (lookahead == 'p') ADVANCE(46);
       END_STATE();
     case 28:
-      if (lookahead == 'u') ADVANCE(40);
+      if (lookahead == 't') ADVANCE(47);
       END_STATE();
     case 29:
-      if (lookahead == 's') ADVANCE(41);
+      if (lookahead == 'u') ADVANCE(48);
       END_STATE();
     case 30:
-      if (lookahead == 'c') ADVANCE(42);
+      if (lookahead == 'm') ADVANCE(49);
       END_STATE();
     case 31:
-      ACCEPT_TOKEN(anon_sym_mut);
+      if (lookahead == 'o') ADVANCE(50);
       END_STATE();
     case 32:
-      if (lookahead == 'a') ADVANCE(43);
+      if (lookahead == 'r') ADVANCE(51);
       END_STATE();
     case 33:
-      ACCEPT_TOKEN(anon_sym_ref);
+      if (lookahead == 's') ADVANCE(52);
       END_STATE();
     case 34:
-      if (lookahead == 'o') ADVANCE(44);
+      if (lookahead == 'c') ADVANCE(53);
       END_STATE();
     case 35:
-      ACCEPT_TOKEN(anon_sym_var);
+      ACCEPT_TOKEN(anon_sym_mut);
       END_STATE();
     case 36:
-      if (lookahead == 'd') ADVANCE(45);
+      ACCEPT_TOKEN(anon_sym_nu00e3o);
       END_STATE();
     case 37:
-      ACCEPT_TOKEN(anon_sym_cada);
+      if (lookahead == 'a') ADVANCE(54);
       END_STATE();
     case 38:
-      ACCEPT_TOKEN(anon_sym_comp);
+      if (lookahead == 'c') ADVANCE(55);
       END_STATE();
     case 39:
-      if (lookahead == 'i') ADVANCE(46);
+      ACCEPT_TOKEN(anon_sym_ref);
       END_STATE();
     case 40:
-      if (lookahead == 'a') ADVANCE(47);
+      if (lookahead == 'o') ADVANCE(56);
       END_STATE();
     case 41:
-      if (lookahead == 'o') ADVANCE(48);
+      if (lookahead == 0xe3) ADVANCE(57);
       END_STATE();
     case 42:
-      ACCEPT_TOKEN(anon_sym_func);
+      ACCEPT_TOKEN(anon_sym_var);
       END_STATE();
     case 43:
-      ACCEPT_TOKEN(anon_sym_para);
-      if (lookahead == 'r') ADVANCE(49);
+      if (lookahead == 'd') ADVANCE(58);
       END_STATE();
     case 44:
-      if (lookahead == 'r') ADVANCE(50);
+      ACCEPT_TOKEN(anon_sym_cada);
       END_STATE();
     case 45:
-      if (lookahead == 'a') ADVANCE(51);
+      ACCEPT_TOKEN(anon_sym_caso);
       END_STATE();
     case 46:
-      if (lookahead == 'n') ADVANCE(52);
+      ACCEPT_TOKEN(anon_sym_comp);
       END_STATE();
     case 47:
-      if (lookahead == 'n') ADVANCE(53);
+      if (lookahead == 'i') ADVANCE(59);
       END_STATE();
     case 48:
-      ACCEPT_TOKEN(anon_sym_falso);
+      if (lookahead == 'a') ADVANCE(60);
       END_STATE();
     case 49:
-      ACCEPT_TOKEN(anon_sym_parar);
+      if (lookahead == 'e') ADVANCE(61);
       END_STATE();
     case 50:
-      if (lookahead == 'n') ADVANCE(54);
+      if (lookahead == 'l') ADVANCE(62);
       END_STATE();
     case 51:
-      if (lookahead == 'd') ADVANCE(55);
+      if (lookahead == 'u') ADVANCE(63);
       END_STATE();
     case 52:
-      if (lookahead == 'u') ADVANCE(56);
+      if (lookahead == 'o') ADVANCE(64);
       END_STATE();
     case 53:
-      if (lookahead == 't') ADVANCE(57);
+      ACCEPT_TOKEN(anon_sym_func);
       END_STATE();
     case 54:
-      if (lookahead == 'a') ADVANCE(58);
+      ACCEPT_TOKEN(anon_sym_para);
+      if (lookahead == 'r') ADVANCE(65);
       END_STATE();
     case 55:
-      if (lookahead == 'e') ADVANCE(59);
+      if (lookahead == 'e') ADVANCE(66);
       END_STATE();
     case 56:
-      if (lookahead == 'a') ADVANCE(60);
+      if (lookahead == 'r') ADVANCE(67);
       END_STATE();
     case 57:
-      if (lookahead == 'o') ADVANCE(61);
+      if (lookahead == 'o') ADVANCE(68);
       END_STATE();
     case 58:
-      if (lookahead == 'r') ADVANCE(62);
+      if (lookahead == 'a') ADVANCE(69);
       END_STATE();
     case 59:
-      if (lookahead == 'i') ADVANCE(63);
+      if (lookahead == 'n') ADVANCE(70);
       END_STATE();
     case 60:
-      if (lookahead == 'r') ADVANCE(64);
+      if (lookahead == 'n') ADVANCE(71);
       END_STATE();
     case 61:
-      ACCEPT_TOKEN(anon_sym_enquanto);
+      if (lookahead == 'r') ADVANCE(72);
       END_STATE();
     case 62:
-      ACCEPT_TOKEN(anon_sym_retornar);
+      if (lookahead == 'h') ADVANCE(73);
       END_STATE();
     case 63:
-      if (lookahead == 'r') ADVANCE(65);
+      if (lookahead == 't') ADVANCE(74);
       END_STATE();
     case 64:
-      ACCEPT_TOKEN(anon_sym_continuar);
+      ACCEPT_TOKEN(anon_sym_falso);
       END_STATE();
     case 65:
-      if (lookahead == 'o') ADVANCE(66);
+      ACCEPT_TOKEN(anon_sym_parar);
       END_STATE();
     case 66:
+      if (lookahead == 'd') ADVANCE(75);
+      END_STATE();
+    case 67:
+      if (lookahead == 'n') ADVANCE(76);
+      END_STATE();
+    case 68:
+      ACCEPT_TOKEN(anon_sym_senu00e3o);
+      END_STATE();
+    case 69:
+      if (lookahead == 'd') ADVANCE(77);
+      END_STATE();
+    case 70:
+      if (lookahead == 'u') ADVANCE(78);
+      END_STATE();
+    case 71:
+      if (lookahead == 't') ADVANCE(79);
+      END_STATE();
+    case 72:
+      if (lookahead == 'a') ADVANCE(80);
+      END_STATE();
+    case 73:
+      if (lookahead == 'a') ADVANCE(81);
+      END_STATE();
+    case 74:
+      if (lookahead == 'u') ADVANCE(82);
+      END_STATE();
+    case 75:
+      if (lookahead == 'i') ADVANCE(83);
+      END_STATE();
+    case 76:
+      if (lookahead == 'a') ADVANCE(84);
+      END_STATE();
+    case 77:
+      if (lookahead == 'e') ADVANCE(85);
+      END_STATE();
+    case 78:
+      if (lookahead == 'a') ADVANCE(86);
+      END_STATE();
+    case 79:
+      if (lookahead == 'o') ADVANCE(87);
+      END_STATE();
+    case 80:
+      if (lookahead == 0xe7) ADVANCE(88);
+      END_STATE();
+    case 81:
+      ACCEPT_TOKEN(anon_sym_escolha);
+      END_STATE();
+    case 82:
+      if (lookahead == 'r') ADVANCE(89);
+      END_STATE();
+    case 83:
+      if (lookahead == 'm') ADVANCE(90);
+      END_STATE();
+    case 84:
+      if (lookahead == 'r') ADVANCE(91);
+      END_STATE();
+    case 85:
+      if (lookahead == 'i') ADVANCE(92);
+      END_STATE();
+    case 86:
+      if (lookahead == 'r') ADVANCE(93);
+      END_STATE();
+    case 87:
+      ACCEPT_TOKEN(anon_sym_enquanto);
+      END_STATE();
+    case 88:
+      if (lookahead == 0xe3) ADVANCE(94);
+      END_STATE();
+    case 89:
+      if (lookahead == 'a') ADVANCE(95);
+      END_STATE();
+    case 90:
+      if (lookahead == 'e') ADVANCE(96);
+      END_STATE();
+    case 91:
+      ACCEPT_TOKEN(anon_sym_retornar);
+      END_STATE();
+    case 92:
+      if (lookahead == 'r') ADVANCE(97);
+      END_STATE();
+    case 93:
+      ACCEPT_TOKEN(anon_sym_continuar);
+      END_STATE();
+    case 94:
+      if (lookahead == 'o') ADVANCE(98);
+      END_STATE();
+    case 95:
+      ACCEPT_TOKEN(anon_sym_estrutura);
+      END_STATE();
+    case 96:
+      if (lookahead == 'n') ADVANCE(99);
+      END_STATE();
+    case 97:
+      if (lookahead == 'o') ADVANCE(100);
+      END_STATE();
+    case 98:
+      ACCEPT_TOKEN(anon_sym_enumerau00e7u00e3o);
+      END_STATE();
+    case 99:
+      if (lookahead == 't') ADVANCE(101);
+      END_STATE();
+    case 100:
       ACCEPT_TOKEN(anon_sym_verdadeiro);
       END_STATE();
+    case 101:
+      if (lookahead == 'o') ADVANCE(102);
+      END_STATE();
+    case 102:
+      ACCEPT_TOKEN(anon_sym_procedimento);
+      END_STATE();
     default:
       return false;
   }
@@ -1608,175 +2565,345 @@ static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
 
 static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [0] = {.lex_state = 0},
-  [1] = {.lex_state = 0},
-  [2] = {.lex_state = 8},
-  [3] = {.lex_state = 8},
-  [4] = {.lex_state = 8},
-  [5] = {.lex_state = 8},
-  [6] = {.lex_state = 8},
-  [7] = {.lex_state = 8},
-  [8] = {.lex_state = 8},
-  [9] = {.lex_state = 8},
-  [10] = {.lex_state = 8},
-  [11] = {.lex_state = 8},
-  [12] = {.lex_state = 8},
-  [13] = {.lex_state = 8},
-  [14] = {.lex_state = 8},
-  [15] = {.lex_state = 8},
-  [16] = {.lex_state = 8},
-  [17] = {.lex_state = 8},
-  [18] = {.lex_state = 8},
-  [19] = {.lex_state = 8},
-  [20] = {.lex_state = 8},
-  [21] = {.lex_state = 8},
-  [22] = {.lex_state = 8},
-  [23] = {.lex_state = 8},
-  [24] = {.lex_state = 8},
-  [25] = {.lex_state = 8},
-  [26] = {.lex_state = 8},
-  [27] = {.lex_state = 8},
-  [28] = {.lex_state = 8},
-  [29] = {.lex_state = 8},
-  [30] = {.lex_state = 8},
-  [31] = {.lex_state = 8},
-  [32] = {.lex_state = 8},
-  [33] = {.lex_state = 8},
-  [34] = {.lex_state = 8},
-  [35] = {.lex_state = 8},
-  [36] = {.lex_state = 8},
-  [37] = {.lex_state = 7},
-  [38] = {.lex_state = 7},
-  [39] = {.lex_state = 7},
-  [40] = {.lex_state = 7},
-  [41] = {.lex_state = 7},
-  [42] = {.lex_state = 7},
-  [43] = {.lex_state = 7},
-  [44] = {.lex_state = 7},
-  [45] = {.lex_state = 7},
-  [46] = {.lex_state = 8},
-  [47] = {.lex_state = 8},
-  [48] = {.lex_state = 7},
-  [49] = {.lex_state = 7},
-  [50] = {.lex_state = 7},
-  [51] = {.lex_state = 7},
-  [52] = {.lex_state = 7},
-  [53] = {.lex_state = 7},
-  [54] = {.lex_state = 7},
-  [55] = {.lex_state = 7},
-  [56] = {.lex_state = 7},
-  [57] = {.lex_state = 7},
-  [58] = {.lex_state = 8},
-  [59] = {.lex_state = 8},
-  [60] = {.lex_state = 8},
-  [61] = {.lex_state = 8},
-  [62] = {.lex_state = 8},
-  [63] = {.lex_state = 8},
-  [64] = {.lex_state = 8},
-  [65] = {.lex_state = 8},
-  [66] = {.lex_state = 8},
-  [67] = {.lex_state = 8},
-  [68] = {.lex_state = 8},
-  [69] = {.lex_state = 8},
-  [70] = {.lex_state = 8},
-  [71] = {.lex_state = 8},
-  [72] = {.lex_state = 8},
-  [73] = {.lex_state = 8},
-  [74] = {.lex_state = 8},
-  [75] = {.lex_state = 7},
-  [76] = {.lex_state = 7},
-  [77] = {.lex_state = 7},
-  [78] = {.lex_state = 7},
-  [79] = {.lex_state = 7},
-  [80] = {.lex_state = 7},
-  [81] = {.lex_state = 7},
-  [82] = {.lex_state = 7},
-  [83] = {.lex_state = 7},
-  [84] = {.lex_state = 7},
-  [85] = {.lex_state = 7},
-  [86] = {.lex_state = 7},
-  [87] = {.lex_state = 8},
-  [88] = {.lex_state = 0},
-  [89] = {.lex_state = 0},
-  [90] = {.lex_state = 0},
-  [91] = {.lex_state = 0},
-  [92] = {.lex_state = 0},
-  [93] = {.lex_state = 0},
-  [94] = {.lex_state = 0},
-  [95] = {.lex_state = 0},
-  [96] = {.lex_state = 0},
-  [97] = {.lex_state = 0},
-  [98] = {.lex_state = 0},
-  [99] = {.lex_state = 0},
-  [100] = {.lex_state = 8},
-  [101] = {.lex_state = 8},
-  [102] = {.lex_state = 8},
-  [103] = {.lex_state = 8},
-  [104] = {.lex_state = 8},
-  [105] = {.lex_state = 8},
-  [106] = {.lex_state = 8},
-  [107] = {.lex_state = 8},
-  [108] = {.lex_state = 0},
-  [109] = {.lex_state = 0},
-  [110] = {.lex_state = 0},
-  [111] = {.lex_state = 0},
-  [112] = {.lex_state = 0},
-  [113] = {.lex_state = 0},
-  [114] = {.lex_state = 0},
-  [115] = {.lex_state = 0},
-  [116] = {.lex_state = 0},
-  [117] = {.lex_state = 8},
-  [118] = {.lex_state = 0},
-  [119] = {.lex_state = 0},
-  [120] = {.lex_state = 0},
-  [121] = {.lex_state = 8},
-  [122] = {.lex_state = 8},
-  [123] = {.lex_state = 0},
-  [124] = {.lex_state = 0},
-  [125] = {.lex_state = 0},
-  [126] = {.lex_state = 0},
-  [127] = {.lex_state = 0},
-  [128] = {.lex_state = 0},
-  [129] = {.lex_state = 0},
-  [130] = {.lex_state = 0},
-  [131] = {.lex_state = 0},
-  [132] = {.lex_state = 0},
-  [133] = {.lex_state = 0},
-  [134] = {.lex_state = 0},
-  [135] = {.lex_state = 8},
-  [136] = {.lex_state = 0},
-  [137] = {.lex_state = 0},
-  [138] = {.lex_state = 0},
-  [139] = {.lex_state = 0},
-  [140] = {.lex_state = 0},
-  [141] = {.lex_state = 0},
-  [142] = {.lex_state = 0},
-  [143] = {.lex_state = 0},
-  [144] = {.lex_state = 0},
-  [145] = {.lex_state = 0},
-  [146] = {.lex_state = 0},
-  [147] = {.lex_state = 0},
-  [148] = {.lex_state = 0},
-  [149] = {.lex_state = 0},
-  [150] = {.lex_state = 0},
-  [151] = {.lex_state = 0},
-  [152] = {.lex_state = 0},
-  [153] = {.lex_state = 9},
-  [154] = {.lex_state = 9},
-  [155] = {.lex_state = 9},
-  [156] = {.lex_state = 0},
-  [157] = {.lex_state = 0},
-  [158] = {.lex_state = 0},
-  [159] = {.lex_state = 0},
-  [160] = {.lex_state = 0},
-  [161] = {.lex_state = 0},
-  [162] = {.lex_state = 0},
-  [163] = {.lex_state = 0},
-  [164] = {.lex_state = 0},
-  [165] = {.lex_state = 0},
-  [166] = {.lex_state = 0},
-  [167] = {.lex_state = 53},
-  [168] = {.lex_state = 0},
-  [169] = {.lex_state = 0},
+  [1] = {.lex_state = 15},
+  [2] = {.lex_state = 15},
+  [3] = {.lex_state = 15},
+  [4] = {.lex_state = 15},
+  [5] = {.lex_state = 15},
+  [6] = {.lex_state = 15},
+  [7] = {.lex_state = 15},
+  [8] = {.lex_state = 15},
+  [9] = {.lex_state = 15},
+  [10] = {.lex_state = 15},
+  [11] = {.lex_state = 15},
+  [12] = {.lex_state = 15},
+  [13] = {.lex_state = 15},
+  [14] = {.lex_state = 15},
+  [15] = {.lex_state = 15},
+  [16] = {.lex_state = 15},
+  [17] = {.lex_state = 15},
+  [18] = {.lex_state = 15},
+  [19] = {.lex_state = 15},
+  [20] = {.lex_state = 15},
+  [21] = {.lex_state = 15},
+  [22] = {.lex_state = 15},
+  [23] = {.lex_state = 15},
+  [24] = {.lex_state = 15},
+  [25] = {.lex_state = 15},
+  [26] = {.lex_state = 15},
+  [27] = {.lex_state = 15},
+  [28] = {.lex_state = 15},
+  [29] = {.lex_state = 15},
+  [30] = {.lex_state = 15},
+  [31] = {.lex_state = 15},
+  [32] = {.lex_state = 15},
+  [33] = {.lex_state = 15},
+  [34] = {.lex_state = 15},
+  [35] = {.lex_state = 15},
+  [36] = {.lex_state = 15},
+  [37] = {.lex_state = 15},
+  [38] = {.lex_state = 15},
+  [39] = {.lex_state = 15},
+  [40] = {.lex_state = 15},
+  [41] = {.lex_state = 15},
+  [42] = {.lex_state = 15},
+  [43] = {.lex_state = 15},
+  [44] = {.lex_state = 15},
+  [45] = {.lex_state = 15},
+  [46] = {.lex_state = 15},
+  [47] = {.lex_state = 15},
+  [48] = {.lex_state = 15},
+  [49] = {.lex_state = 15},
+  [50] = {.lex_state = 15},
+  [51] = {.lex_state = 15},
+  [52] = {.lex_state = 15},
+  [53] = {.lex_state = 15},
+  [54] = {.lex_state = 15},
+  [55] = {.lex_state = 15},
+  [56] = {.lex_state = 15},
+  [57] = {.lex_state = 15},
+  [58] = {.lex_state = 15},
+  [59] = {.lex_state = 15},
+  [60] = {.lex_state = 15},
+  [61] = {.lex_state = 15},
+  [62] = {.lex_state = 15},
+  [63] = {.lex_state = 15},
+  [64] = {.lex_state = 15},
+  [65] = {.lex_state = 15},
+  [66] = {.lex_state = 15},
+  [67] = {.lex_state = 15},
+  [68] = {.lex_state = 15},
+  [69] = {.lex_state = 15},
+  [70] = {.lex_state = 15},
+  [71] = {.lex_state = 15},
+  [72] = {.lex_state = 15},
+  [73] = {.lex_state = 15},
+  [74] = {.lex_state = 15},
+  [75] = {.lex_state = 15},
+  [76] = {.lex_state = 15},
+  [77] = {.lex_state = 1},
+  [78] = {.lex_state = 1},
+  [79] = {.lex_state = 15},
+  [80] = {.lex_state = 2},
+  [81] = {.lex_state = 2},
+  [82] = {.lex_state = 2},
+  [83] = {.lex_state = 2},
+  [84] = {.lex_state = 2},
+  [85] = {.lex_state = 2},
+  [86] = {.lex_state = 2},
+  [87] = {.lex_state = 2},
+  [88] = {.lex_state = 15},
+  [89] = {.lex_state = 2},
+  [90] = {.lex_state = 15},
+  [91] = {.lex_state = 2},
+  [92] = {.lex_state = 2},
+  [93] = {.lex_state = 2},
+  [94] = {.lex_state = 2},
+  [95] = {.lex_state = 2},
+  [96] = {.lex_state = 2},
+  [97] = {.lex_state = 2},
+  [98] = {.lex_state = 2},
+  [99] = {.lex_state = 2},
+  [100] = {.lex_state = 2},
+  [101] = {.lex_state = 2},
+  [102] = {.lex_state = 15},
+  [103] = {.lex_state = 15},
+  [104] = {.lex_state = 15},
+  [105] = {.lex_state = 15},
+  [106] = {.lex_state = 15},
+  [107] = {.lex_state = 15},
+  [108] = {.lex_state = 15},
+  [109] = {.lex_state = 2},
+  [110] = {.lex_state = 15},
+  [111] = {.lex_state = 15},
+  [112] = {.lex_state = 15},
+  [113] = {.lex_state = 15},
+  [114] = {.lex_state = 2},
+  [115] = {.lex_state = 15},
+  [116] = {.lex_state = 15},
+  [117] = {.lex_state = 15},
+  [118] = {.lex_state = 2},
+  [119] = {.lex_state = 15},
+  [120] = {.lex_state = 15},
+  [121] = {.lex_state = 15},
+  [122] = {.lex_state = 15},
+  [123] = {.lex_state = 15},
+  [124] = {.lex_state = 15},
+  [125] = {.lex_state = 15},
+  [126] = {.lex_state = 2},
+  [127] = {.lex_state = 15},
+  [128] = {.lex_state = 2},
+  [129] = {.lex_state = 15},
+  [130] = {.lex_state = 2},
+  [131] = {.lex_state = 2},
+  [132] = {.lex_state = 2},
+  [133] = {.lex_state = 2},
+  [134] = {.lex_state = 2},
+  [135] = {.lex_state = 2},
+  [136] = {.lex_state = 2},
+  [137] = {.lex_state = 2},
+  [138] = {.lex_state = 2},
+  [139] = {.lex_state = 2},
+  [140] = {.lex_state = 2},
+  [141] = {.lex_state = 2},
+  [142] = {.lex_state = 2},
+  [143] = {.lex_state = 2},
+  [144] = {.lex_state = 2},
+  [145] = {.lex_state = 2},
+  [146] = {.lex_state = 2},
+  [147] = {.lex_state = 2},
+  [148] = {.lex_state = 2},
+  [149] = {.lex_state = 2},
+  [150] = {.lex_state = 2},
+  [151] = {.lex_state = 15},
+  [152] = {.lex_state = 15},
+  [153] = {.lex_state = 15},
+  [154] = {.lex_state = 15},
+  [155] = {.lex_state = 15},
+  [156] = {.lex_state = 15},
+  [157] = {.lex_state = 15},
+  [158] = {.lex_state = 15},
+  [159] = {.lex_state = 15},
+  [160] = {.lex_state = 15},
+  [161] = {.lex_state = 15},
+  [162] = {.lex_state = 15},
+  [163] = {.lex_state = 15},
+  [164] = {.lex_state = 15},
+  [165] = {.lex_state = 15},
+  [166] = {.lex_state = 15},
+  [167] = {.lex_state = 15},
+  [168] = {.lex_state = 15},
+  [169] = {.lex_state = 15},
+  [170] = {.lex_state = 15},
+  [171] = {.lex_state = 15},
+  [172] = {.lex_state = 15},
+  [173] = {.lex_state = 15},
+  [174] = {.lex_state = 15},
+  [175] = {.lex_state = 15},
+  [176] = {.lex_state = 15},
+  [177] = {.lex_state = 15},
+  [178] = {.lex_state = 15},
+  [179] = {.lex_state = 15},
+  [180] = {.lex_state = 3},
+  [181] = {.lex_state = 3},
+  [182] = {.lex_state = 3},
+  [183] = {.lex_state = 15},
+  [184] = {.lex_state = 15},
+  [185] = {.lex_state = 15},
+  [186] = {.lex_state = 15},
+  [187] = {.lex_state = 15},
+  [188] = {.lex_state = 15},
+  [189] = {.lex_state = 15},
+  [190] = {.lex_state = 15},
+  [191] = {.lex_state = 15},
+  [192] = {.lex_state = 15},
+  [193] = {.lex_state = 15},
+  [194] = {.lex_state = 15},
+  [195] = {.lex_state = 15},
+  [196] = {.lex_state = 15},
+  [197] = {.lex_state = 15},
+  [198] = {.lex_state = 15},
+  [199] = {.lex_state = 15},
+  [200] = {.lex_state = 15},
+  [201] = {.lex_state = 15},
+  [202] = {.lex_state = 15},
+  [203] = {.lex_state = 15},
+  [204] = {.lex_state = 15},
+  [205] = {.lex_state = 15},
+  [206] = {.lex_state = 15},
+  [207] = {.lex_state = 15},
+  [208] = {.lex_state = 15},
+  [209] = {.lex_state = 15},
+  [210] = {.lex_state = 15},
+  [211] = {.lex_state = 3},
+  [212] = {.lex_state = 15},
+  [213] = {.lex_state = 15},
+  [214] = {.lex_state = 3},
+  [215] = {.lex_state = 15},
+  [216] = {.lex_state = 15},
+  [217] = {.lex_state = 15},
+  [218] = {.lex_state = 15},
+  [219] = {.lex_state = 0},
+  [220] = {.lex_state = 0},
+  [221] = {.lex_state = 0},
+  [222] = {.lex_state = 15},
+  [223] = {.lex_state = 15},
+  [224] = {.lex_state = 0},
+  [225] = {.lex_state = 15},
+  [226] = {.lex_state = 0},
+  [227] = {.lex_state = 0},
+  [228] = {.lex_state = 0},
+  [229] = {.lex_state = 15},
+  [230] = {.lex_state = 0},
+  [231] = {.lex_state = 15},
+  [232] = {.lex_state = 0},
+  [233] = {.lex_state = 15},
+  [234] = {.lex_state = 0},
+  [235] = {.lex_state = 0},
+  [236] = {.lex_state = 0},
+  [237] = {.lex_state = 15},
+  [238] = {.lex_state = 15},
+  [239] = {.lex_state = 0},
+  [240] = {.lex_state = 0},
+  [241] = {.lex_state = 0},
+  [242] = {.lex_state = 15},
+  [243] = {.lex_state = 0},
+  [244] = {.lex_state = 0},
+  [245] = {.lex_state = 0},
+  [246] = {.lex_state = 0},
+  [247] = {.lex_state = 15},
+  [248] = {.lex_state = 0},
+  [249] = {.lex_state = 0},
+  [250] = {.lex_state = 15},
+  [251] = {.lex_state = 0},
+  [252] = {.lex_state = 0},
+  [253] = {.lex_state = 15},
+  [254] = {.lex_state = 0},
+  [255] = {.lex_state = 0},
+  [256] = {.lex_state = 15},
+  [257] = {.lex_state = 1},
+  [258] = {.lex_state = 4},
+  [259] = {.lex_state = 15},
+  [260] = {.lex_state = 15},
+  [261] = {.lex_state = 15},
+  [262] = {.lex_state = 0},
+  [263] = {.lex_state = 15},
+  [264] = {.lex_state = 15},
+  [265] = {.lex_state = 15},
+  [266] = {.lex_state = 15},
+  [267] = {.lex_state = 15},
+  [268] = {.lex_state = 4},
+  [269] = {.lex_state = 0},
+  [270] = {.lex_state = 15},
+  [271] = {.lex_state = 4},
+  [272] = {.lex_state = 0},
+  [273] = {.lex_state = 4},
+  [274] = {.lex_state = 0},
+  [275] = {.lex_state = 0},
+  [276] = {.lex_state = 15},
+  [277] = {.lex_state = 15},
+  [278] = {.lex_state = 15},
+  [279] = {.lex_state = 0},
+  [280] = {.lex_state = 15},
+  [281] = {.lex_state = 15},
+  [282] = {.lex_state = 0},
+  [283] = {.lex_state = 15},
+  [284] = {.lex_state = 15},
+  [285] = {.lex_state = 15},
+  [286] = {.lex_state = 15},
+  [287] = {.lex_state = 15},
+  [288] = {.lex_state = 0},
+  [289] = {.lex_state = 0},
+  [290] = {.lex_state = 0},
+  [291] = {.lex_state = 0},
+  [292] = {.lex_state = 0},
+  [293] = {.lex_state = 15},
+  [294] = {.lex_state = 4},
+  [295] = {.lex_state = 0},
+  [296] = {.lex_state = 15},
+  [297] = {.lex_state = 0},
+  [298] = {.lex_state = 15},
+  [299] = {.lex_state = 15},
+  [300] = {.lex_state = 0},
+  [301] = {.lex_state = 15},
+  [302] = {.lex_state = 15},
+  [303] = {.lex_state = 15},
+  [304] = {.lex_state = 15},
+  [305] = {.lex_state = 0},
+  [306] = {.lex_state = 15},
+  [307] = {.lex_state = 15},
+  [308] = {.lex_state = 0},
+  [309] = {.lex_state = 0},
+  [310] = {.lex_state = 15},
+  [311] = {.lex_state = 15},
+  [312] = {.lex_state = 15},
+  [313] = {.lex_state = 4},
+  [314] = {.lex_state = 15},
+  [315] = {.lex_state = 15},
+  [316] = {.lex_state = 15},
+  [317] = {.lex_state = 15},
+  [318] = {.lex_state = 15},
+  [319] = {.lex_state = 15},
+  [320] = {.lex_state = 15},
+  [321] = {.lex_state = 15},
+  [322] = {.lex_state = 0},
+  [323] = {.lex_state = 0},
+  [324] = {.lex_state = 4},
+  [325] = {.lex_state = 0},
+  [326] = {.lex_state = 15},
+  [327] = {.lex_state = 15},
+  [328] = {.lex_state = 15},
+  [329] = {.lex_state = 4},
+  [330] = {.lex_state = 15},
+  [331] = {.lex_state = 15},
+  [332] = {.lex_state = 0},
+  [333] = {.lex_state = 15},
+  [334] = {.lex_state = 0},
+  [335] = {.lex_state = 15},
+  [336] = {.lex_state = 4},
+  [337] = {.lex_state = 15},
+  [338] = {.lex_state = 15},
+  [339] = {.lex_state = 15},
 };
 
 static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
@@ -1790,8 +2917,11 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_DASH_GT] = ACTIONS(1),
     [anon_sym_LBRACE] = ACTIONS(1),
     [anon_sym_RBRACE] = ACTIONS(1),
-    [anon_sym_mut] = ACTIONS(1),
+    [anon_sym_procedimento] = ACTIONS(1),
+    [anon_sym_estrutura] = ACTIONS(1),
     [anon_sym_COLON] = ACTIONS(1),
+    [anon_sym_enumerau00e7u00e3o] = ACTIONS(1),
+    [anon_sym_mut] = ACTIONS(1),
     [anon_sym_parar] = ACTIONS(1),
     [anon_sym_SEMI] = ACTIONS(1),
     [anon_sym_continuar] = ACTIONS(1),
@@ -1802,6 +2932,9 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_para] = ACTIONS(1),
     [anon_sym_cada] = ACTIONS(1),
     [anon_sym_em] = ACTIONS(1),
+    [anon_sym_escolha] = ACTIONS(1),
+    [anon_sym_caso] = ACTIONS(1),
+    [anon_sym_EQ_GT] = ACTIONS(1),
     [anon_sym_ref] = ACTIONS(1),
     [anon_sym_var] = ACTIONS(1),
     [anon_sym_EQ] = ACTIONS(1),
@@ -1820,6 +2953,8 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_nu00e3o] = ACTIONS(1),
     [anon_sym_DOT] = ACTIONS(1),
     [anon_sym_DQUOTE] = ACTIONS(1),
+    [anon_sym_DQUOTE2] = ACTIONS(1),
+    [anon_sym_LBRACE2] = ACTIONS(1),
     [anon_sym_verdadeiro] = ACTIONS(1),
     [anon_sym_falso] = ACTIONS(1),
     [anon_sym_LBRACK] = ACTIONS(1),
@@ -1827,55 +2962,73 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_comp] = ACTIONS(1),
     [sym_integer_literal] = ACTIONS(1),
     [sym_fractional_literal] = ACTIONS(1),
+    [sym_exponent_literal] = ACTIONS(1),
+    [sym_line_comment] = ACTIONS(3),
+    [sym_block_comment] = ACTIONS(5),
+    [sym_escape_sequence] = ACTIONS(1),
   },
   [1] = {
-    [sym_program] = STATE(166),
-    [sym_function] = STATE(109),
-    [aux_sym_program_repeat1] = STATE(109),
-    [ts_builtin_sym_end] = ACTIONS(3),
-    [anon_sym_func] = ACTIONS(5),
+    [sym_program] = STATE(309),
+    [sym_function] = STATE(152),
+    [sym_procedure] = STATE(152),
+    [sym_struct] = STATE(152),
+    [sym_enum] = STATE(152),
+    [aux_sym_program_repeat1] = STATE(152),
+    [ts_builtin_sym_end] = ACTIONS(7),
+    [anon_sym_func] = ACTIONS(9),
+    [anon_sym_procedimento] = ACTIONS(11),
+    [anon_sym_estrutura] = ACTIONS(13),
+    [anon_sym_enumerau00e7u00e3o] = ACTIONS(15),
+    [sym_line_comment] = ACTIONS(3),
+    [sym_block_comment] = ACTIONS(5),
   },
 };
 
 static const uint16_t ts_small_parse_table[] = {
-  [0] = 19,
-    ACTIONS(7), 1,
+  [0] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(11), 1,
+    ACTIONS(21), 1,
       anon_sym_RBRACE,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(17), 10,
+    STATE(10), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -1883,15 +3036,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -1900,44 +3055,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [81] = 19,
-    ACTIONS(7), 1,
+  [92] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(41), 1,
+    ACTIONS(53), 1,
       anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(10), 10,
+    STATE(20), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -1945,15 +3106,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -1962,44 +3125,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [162] = 19,
-    ACTIONS(7), 1,
+  [184] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
-      anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
-      anon_sym_DQUOTE,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
-      sym_integer_literal,
-    ACTIONS(39), 1,
-      sym_fractional_literal,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
     ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(55), 1,
       anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(14), 10,
+    STATE(7), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2007,15 +3176,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2024,44 +3195,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [243] = 19,
-    ACTIONS(7), 1,
+  [276] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(45), 1,
+    ACTIONS(57), 1,
       anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(10), 10,
+    STATE(8), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2069,15 +3246,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2086,44 +3265,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [324] = 19,
-    ACTIONS(7), 1,
+  [368] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(47), 1,
+    ACTIONS(59), 1,
       anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(3), 10,
+    STATE(12), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2131,15 +3316,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2148,44 +3335,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [405] = 19,
-    ACTIONS(7), 1,
+  [460] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(61), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(64), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(67), 1,
+      anon_sym_RBRACE,
+    ACTIONS(69), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(72), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(75), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(78), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(81), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(84), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(87), 1,
+      anon_sym_escolha,
+    ACTIONS(90), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(96), 1,
+      anon_sym_STAR,
+    ACTIONS(99), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(105), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(108), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(111), 1,
       sym_fractional_literal,
-    ACTIONS(49), 1,
-      anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(93), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(102), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(5), 10,
+    STATE(7), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2193,15 +3386,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2210,44 +3405,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [486] = 19,
-    ACTIONS(7), 1,
+  [552] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
-      sym_fractional_literal,
     ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(114), 1,
       anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(11), 10,
+    STATE(7), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2255,15 +3456,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2272,44 +3475,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [567] = 19,
-    ACTIONS(7), 1,
+  [644] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(53), 1,
+    ACTIONS(116), 1,
       anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(10), 10,
+    STATE(16), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2317,15 +3526,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2334,44 +3545,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [648] = 19,
-    ACTIONS(55), 1,
+  [736] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(58), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(61), 1,
-      anon_sym_RBRACE,
-    ACTIONS(63), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(66), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(69), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(72), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(75), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(78), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(81), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(84), 1,
-      anon_sym_DASH,
-    ACTIONS(90), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(96), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(99), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(102), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(87), 2,
-      anon_sym_STAR,
+    ACTIONS(118), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(93), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(10), 10,
+    STATE(7), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2379,15 +3596,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2396,44 +3615,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [729] = 19,
-    ACTIONS(7), 1,
+  [828] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(105), 1,
+    ACTIONS(120), 1,
       anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(10), 10,
+    STATE(17), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2441,15 +3666,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2458,44 +3685,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [810] = 19,
-    ACTIONS(7), 1,
+  [920] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
-      anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
-      anon_sym_DQUOTE,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
-      sym_integer_literal,
-    ACTIONS(39), 1,
-      sym_fractional_literal,
-    ACTIONS(107), 1,
-      anon_sym_RBRACE,
-    ACTIONS(29), 2,
+      anon_sym_var,
+    ACTIONS(41), 1,
       anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(122), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(13), 10,
+    STATE(7), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2503,15 +3736,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2520,44 +3755,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [891] = 19,
-    ACTIONS(7), 1,
+  [1012] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(109), 1,
+    ACTIONS(124), 1,
       anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(10), 10,
+    STATE(19), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2565,15 +3806,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2582,44 +3825,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [972] = 19,
-    ACTIONS(7), 1,
+  [1104] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(111), 1,
+    ACTIONS(126), 1,
       anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(10), 10,
+    STATE(42), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2627,15 +3876,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2644,44 +3895,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1053] = 19,
-    ACTIONS(7), 1,
+  [1196] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(113), 1,
+    ACTIONS(128), 1,
       anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(10), 10,
+    STATE(21), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2689,15 +3946,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2706,44 +3965,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1134] = 19,
-    ACTIONS(7), 1,
+  [1288] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(115), 1,
+    ACTIONS(130), 1,
       anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(15), 10,
+    STATE(7), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2751,15 +4016,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2768,44 +4035,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1215] = 19,
-    ACTIONS(7), 1,
+  [1380] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(117), 1,
+    ACTIONS(132), 1,
       anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(10), 10,
+    STATE(7), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2813,15 +4086,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2830,44 +4105,50 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1296] = 19,
-    ACTIONS(7), 1,
+  [1472] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
       sym_identifier,
-    ACTIONS(9), 1,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(13), 1,
+    ACTIONS(23), 1,
       anon_sym_parar,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
       anon_sym_continuar,
-    ACTIONS(17), 1,
+    ACTIONS(27), 1,
       anon_sym_retornar,
-    ACTIONS(19), 1,
+    ACTIONS(29), 1,
       anon_sym_se,
-    ACTIONS(21), 1,
+    ACTIONS(31), 1,
       anon_sym_enquanto,
-    ACTIONS(23), 1,
+    ACTIONS(33), 1,
       anon_sym_para,
-    ACTIONS(25), 1,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
       anon_sym_var,
-    ACTIONS(27), 1,
-      anon_sym_DASH,
-    ACTIONS(31), 1,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
       anon_sym_DQUOTE,
-    ACTIONS(35), 1,
+    ACTIONS(47), 1,
       anon_sym_LBRACK,
-    ACTIONS(37), 1,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(119), 1,
+    ACTIONS(134), 1,
       anon_sym_RBRACE,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(9), 10,
+    STATE(23), 11,
       sym__statement,
       sym_break_stmt,
       sym_continue_stmt,
@@ -2875,15 +4156,17 @@ static const uint16_t ts_small_parse_table[] = {
       sym_conditional,
       sym_while_loop,
       sym_for_each_loop,
+      sym_match_stmt,
       sym_variable_declaration,
       sym_expression_stmt,
       aux_sym_function_repeat2,
-    STATE(86), 13,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2892,35 +4175,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1377] = 11,
-    ACTIONS(9), 1,
+  [1564] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(121), 1,
-      sym_identifier,
-    ACTIONS(123), 1,
-      anon_sym_RBRACK,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(136), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(75), 13,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2929,35 +4245,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1425] = 11,
-    ACTIONS(9), 1,
+  [1656] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
-      sym_integer_literal,
-    ACTIONS(39), 1,
-      sym_fractional_literal,
-    ACTIONS(125), 1,
-      sym_identifier,
-    ACTIONS(127), 1,
-      anon_sym_RPAREN,
-    ACTIONS(29), 2,
+      anon_sym_var,
+    ACTIONS(41), 1,
       anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(138), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(76), 13,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -2966,35 +4315,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1473] = 11,
-    ACTIONS(9), 1,
+  [1748] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(129), 1,
-      sym_identifier,
-    ACTIONS(131), 1,
-      anon_sym_SEMI,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(140), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(81), 13,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3003,33 +4385,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1521] = 10,
-    ACTIONS(9), 1,
+  [1840] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(133), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(142), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(83), 13,
+    STATE(24), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3038,33 +4455,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1566] = 10,
-    ACTIONS(9), 1,
+  [1932] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(135), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(144), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(79), 13,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3073,33 +4525,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1611] = 10,
-    ACTIONS(9), 1,
+  [2024] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(137), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(146), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(49), 13,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3108,33 +4595,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1656] = 10,
-    ACTIONS(9), 1,
+  [2116] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(139), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(148), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(52), 13,
+    STATE(27), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3143,33 +4665,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1701] = 10,
-    ACTIONS(9), 1,
+  [2208] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(141), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(150), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(50), 13,
+    STATE(30), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3178,33 +4735,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1746] = 10,
-    ACTIONS(9), 1,
+  [2300] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(143), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(152), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(85), 13,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3213,33 +4805,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1791] = 10,
-    ACTIONS(9), 1,
+  [2392] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(145), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(154), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(80), 13,
+    STATE(33), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3248,33 +4875,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1836] = 10,
-    ACTIONS(9), 1,
+  [2484] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(147), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(156), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(51), 13,
+    STATE(34), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3283,33 +4945,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1881] = 10,
-    ACTIONS(9), 1,
+  [2576] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(149), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(158), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(57), 13,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3318,33 +5015,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1926] = 10,
-    ACTIONS(9), 1,
+  [2668] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(151), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(160), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(78), 13,
+    STATE(36), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3353,33 +5085,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [1971] = 10,
-    ACTIONS(9), 1,
+  [2760] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(153), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(162), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(77), 13,
+    STATE(37), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3388,33 +5155,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [2016] = 10,
-    ACTIONS(9), 1,
+  [2852] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
-      sym_integer_literal,
-    ACTIONS(39), 1,
-      sym_fractional_literal,
-    ACTIONS(155), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
+      anon_sym_var,
+    ACTIONS(41), 1,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
-    ACTIONS(33), 2,
-      anon_sym_verdadeiro,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(164), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(82), 13,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3423,33 +5225,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [2061] = 10,
-    ACTIONS(9), 1,
+  [2944] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(157), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(166), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(37), 13,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3458,33 +5295,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [2106] = 10,
-    ACTIONS(9), 1,
+  [3036] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(159), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(168), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(41), 13,
+    STATE(38), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3493,33 +5365,68 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [2151] = 10,
-    ACTIONS(9), 1,
+  [3128] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
     ACTIONS(27), 1,
-      anon_sym_DASH,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
     ACTIONS(31), 1,
-      anon_sym_DQUOTE,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
     ACTIONS(35), 1,
-      anon_sym_LBRACK,
+      anon_sym_escolha,
     ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
       sym_integer_literal,
-    ACTIONS(39), 1,
+    ACTIONS(51), 1,
       sym_fractional_literal,
-    ACTIONS(161), 1,
-      sym_identifier,
-    ACTIONS(29), 2,
-      anon_sym_STAR,
+    ACTIONS(170), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
-    ACTIONS(33), 2,
+    ACTIONS(45), 2,
       anon_sym_verdadeiro,
       anon_sym_falso,
-    STATE(84), 13,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
       sym__expression,
       sym_binary_expr,
       sym_unary_expr,
       sym_access_expr,
       sym_call_expr,
+      sym_struct_literal,
       sym__value_expr,
       sym_parem_expr,
       sym__literal,
@@ -3528,69 +5435,1865 @@ static const uint16_t ts_small_parse_table[] = {
       sym_string,
       sym_boolean,
       sym_list,
-  [2196] = 5,
-    ACTIONS(163), 1,
+  [3220] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-    ACTIONS(171), 1,
-      anon_sym_DOT,
-    ACTIONS(169), 2,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
+    ACTIONS(27), 1,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
+    ACTIONS(31), 1,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
       anon_sym_STAR,
-      anon_sym_SLASH,
-    ACTIONS(167), 3,
-      anon_sym_EQ,
-      anon_sym_LT,
-      anon_sym_GT,
-    ACTIONS(165), 13,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-      anon_sym_LBRACE,
-      anon_sym_SEMI,
-      anon_sym_e,
-      anon_sym_ou,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PLUS,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(172), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
       anon_sym_DASH,
-      anon_sym_RBRACK,
-  [2227] = 2,
-    ACTIONS(175), 3,
-      anon_sym_EQ,
-      anon_sym_LT,
-      anon_sym_GT,
-    ACTIONS(173), 17,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [3312] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
       anon_sym_LPAREN,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-      anon_sym_LBRACE,
-      anon_sym_SEMI,
-      anon_sym_e,
-      anon_sym_ou,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PLUS,
-      anon_sym_DASH,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
+    ACTIONS(27), 1,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
+    ACTIONS(31), 1,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
       anon_sym_STAR,
-      anon_sym_SLASH,
-      anon_sym_DOT,
-      anon_sym_RBRACK,
-  [2252] = 2,
-    ACTIONS(179), 3,
-      anon_sym_EQ,
-      anon_sym_LT,
-      anon_sym_GT,
-    ACTIONS(177), 17,
-      anon_sym_LPAREN,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-      anon_sym_LBRACE,
-      anon_sym_SEMI,
-      anon_sym_e,
-      anon_sym_ou,
-      anon_sym_EQ_EQ,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(174), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [3404] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
+    ACTIONS(27), 1,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
+    ACTIONS(31), 1,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(176), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(4), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [3496] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
+    ACTIONS(27), 1,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
+    ACTIONS(31), 1,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(178), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(41), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [3588] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
+    ACTIONS(27), 1,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
+    ACTIONS(31), 1,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(180), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [3680] = 22,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(23), 1,
+      anon_sym_parar,
+    ACTIONS(25), 1,
+      anon_sym_continuar,
+    ACTIONS(27), 1,
+      anon_sym_retornar,
+    ACTIONS(29), 1,
+      anon_sym_se,
+    ACTIONS(31), 1,
+      anon_sym_enquanto,
+    ACTIONS(33), 1,
+      anon_sym_para,
+    ACTIONS(35), 1,
+      anon_sym_escolha,
+    ACTIONS(37), 1,
+      anon_sym_var,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(182), 1,
+      anon_sym_RBRACE,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(7), 11,
+      sym__statement,
+      sym_break_stmt,
+      sym_continue_stmt,
+      sym_return_stmt,
+      sym_conditional,
+      sym_while_loop,
+      sym_for_each_loop,
+      sym_match_stmt,
+      sym_variable_declaration,
+      sym_expression_stmt,
+      aux_sym_function_repeat2,
+    STATE(137), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [3772] = 13,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(184), 1,
+      anon_sym_RBRACK,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(131), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [3827] = 13,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(186), 1,
+      anon_sym_RPAREN,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(130), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [3882] = 13,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(188), 1,
+      anon_sym_SEMI,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(143), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [3937] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(190), 1,
+      sym_identifier,
+    ACTIONS(194), 1,
+      anon_sym_STAR,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    ACTIONS(192), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    STATE(138), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [3989] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(194), 1,
+      anon_sym_STAR,
+    ACTIONS(196), 1,
+      sym_identifier,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    ACTIONS(192), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    STATE(82), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4041] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(109), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4093] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(114), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4145] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(194), 1,
+      anon_sym_STAR,
+    ACTIONS(198), 1,
+      sym_identifier,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    ACTIONS(192), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    STATE(144), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4197] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(132), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4249] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(134), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4301] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(118), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4353] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(89), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4405] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(139), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4457] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(82), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4509] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(194), 1,
+      anon_sym_STAR,
+    ACTIONS(200), 1,
+      sym_identifier,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    ACTIONS(192), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    STATE(140), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4561] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(194), 1,
+      anon_sym_STAR,
+    ACTIONS(202), 1,
+      sym_identifier,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    ACTIONS(192), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    STATE(145), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4613] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(141), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4665] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(142), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4717] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(194), 1,
+      anon_sym_STAR,
+    ACTIONS(204), 1,
+      sym_identifier,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    ACTIONS(192), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    STATE(146), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4769] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(135), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4821] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(133), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4873] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(126), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4925] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(17), 1,
+      sym_identifier,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(41), 1,
+      anon_sym_STAR,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(39), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    STATE(128), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [4977] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(194), 1,
+      anon_sym_STAR,
+    ACTIONS(206), 1,
+      sym_identifier,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    ACTIONS(192), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    STATE(147), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [5029] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(194), 1,
+      anon_sym_STAR,
+    ACTIONS(208), 1,
+      sym_identifier,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    ACTIONS(192), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    STATE(148), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [5081] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(194), 1,
+      anon_sym_STAR,
+    ACTIONS(210), 1,
+      sym_identifier,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    ACTIONS(192), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    STATE(149), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [5133] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(194), 1,
+      anon_sym_STAR,
+    ACTIONS(212), 1,
+      sym_identifier,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    ACTIONS(192), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    STATE(150), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [5185] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(194), 1,
+      anon_sym_STAR,
+    ACTIONS(214), 1,
+      sym_identifier,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    ACTIONS(192), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    STATE(136), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [5237] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(19), 1,
+      anon_sym_LPAREN,
+    ACTIONS(43), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(47), 1,
+      anon_sym_LBRACK,
+    ACTIONS(49), 1,
+      sym_integer_literal,
+    ACTIONS(51), 1,
+      sym_fractional_literal,
+    ACTIONS(194), 1,
+      anon_sym_STAR,
+    ACTIONS(216), 1,
+      sym_identifier,
+    ACTIONS(45), 2,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+    ACTIONS(192), 2,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+    STATE(89), 14,
+      sym__expression,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_access_expr,
+      sym_call_expr,
+      sym_struct_literal,
+      sym__value_expr,
+      sym_parem_expr,
+      sym__literal,
+      sym_integer,
+      sym_decimal,
+      sym_string,
+      sym_boolean,
+      sym_list,
+  [5289] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(222), 1,
+      anon_sym_senu00e3o,
+    STATE(72), 1,
+      aux_sym_conditional_repeat1,
+    STATE(79), 1,
+      sym_else_if,
+    ACTIONS(220), 5,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACK,
+    ACTIONS(218), 15,
+      anon_sym_parar,
+      anon_sym_continuar,
+      anon_sym_retornar,
+      anon_sym_se,
+      anon_sym_enquanto,
+      anon_sym_para,
+      anon_sym_escolha,
+      anon_sym_var,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+      sym_integer_literal,
+      sym_fractional_literal,
+      sym_identifier,
+  [5329] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(229), 1,
+      anon_sym_senu00e3o,
+    STATE(74), 1,
+      aux_sym_conditional_repeat1,
+    STATE(79), 1,
+      sym_else_if,
+    ACTIONS(227), 5,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACK,
+    ACTIONS(225), 15,
+      anon_sym_parar,
+      anon_sym_continuar,
+      anon_sym_retornar,
+      anon_sym_se,
+      anon_sym_enquanto,
+      anon_sym_para,
+      anon_sym_escolha,
+      anon_sym_var,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+      sym_integer_literal,
+      sym_fractional_literal,
+      sym_identifier,
+  [5369] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(235), 1,
+      anon_sym_senu00e3o,
+    STATE(72), 1,
+      aux_sym_conditional_repeat1,
+    STATE(79), 1,
+      sym_else_if,
+    ACTIONS(233), 5,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACK,
+    ACTIONS(231), 15,
+      anon_sym_parar,
+      anon_sym_continuar,
+      anon_sym_retornar,
+      anon_sym_se,
+      anon_sym_enquanto,
+      anon_sym_para,
+      anon_sym_escolha,
+      anon_sym_var,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+      sym_integer_literal,
+      sym_fractional_literal,
+      sym_identifier,
+  [5409] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(241), 1,
+      anon_sym_senu00e3o,
+    STATE(76), 1,
+      aux_sym_conditional_repeat1,
+    STATE(79), 1,
+      sym_else_if,
+    ACTIONS(239), 5,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACK,
+    ACTIONS(237), 15,
+      anon_sym_parar,
+      anon_sym_continuar,
+      anon_sym_retornar,
+      anon_sym_se,
+      anon_sym_enquanto,
+      anon_sym_para,
+      anon_sym_escolha,
+      anon_sym_var,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+      sym_integer_literal,
+      sym_fractional_literal,
+      sym_identifier,
+  [5449] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(247), 1,
+      anon_sym_senu00e3o,
+    STATE(72), 1,
+      aux_sym_conditional_repeat1,
+    STATE(79), 1,
+      sym_else_if,
+    ACTIONS(245), 5,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACK,
+    ACTIONS(243), 15,
+      anon_sym_parar,
+      anon_sym_continuar,
+      anon_sym_retornar,
+      anon_sym_se,
+      anon_sym_enquanto,
+      anon_sym_para,
+      anon_sym_escolha,
+      anon_sym_var,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+      sym_integer_literal,
+      sym_fractional_literal,
+      sym_identifier,
+  [5489] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(253), 1,
+      sym_exponent_literal,
+    ACTIONS(251), 4,
+      anon_sym_EQ,
+      anon_sym_e,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(249), 17,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_ou,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_RBRACK,
+  [5524] = 6,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(259), 1,
+      anon_sym_DOT,
+    ACTIONS(261), 1,
+      sym_exponent_literal,
+    ACTIONS(257), 4,
+      anon_sym_EQ,
+      anon_sym_e,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(255), 16,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_ou,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_RBRACK,
+  [5561] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(265), 5,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACK,
+    ACTIONS(263), 16,
+      anon_sym_parar,
+      anon_sym_continuar,
+      anon_sym_retornar,
+      anon_sym_se,
+      anon_sym_senu00e3o,
+      anon_sym_enquanto,
+      anon_sym_para,
+      anon_sym_escolha,
+      anon_sym_var,
+      anon_sym_DASH,
+      anon_sym_nu00e3o,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+      sym_integer_literal,
+      sym_fractional_literal,
+      sym_identifier,
+  [5593] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(269), 3,
+      anon_sym_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(267), 18,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_e,
+      anon_sym_ou,
+      anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
@@ -3600,16 +7303,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       anon_sym_RBRACK,
-  [2277] = 2,
-    ACTIONS(183), 3,
+  [5625] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(273), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(181), 17,
+    ACTIONS(271), 18,
       anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
@@ -3623,19 +7331,51 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       anon_sym_RBRACK,
-  [2302] = 4,
-    ACTIONS(163), 1,
+  [5657] = 6,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
       anon_sym_LPAREN,
-    ACTIONS(171), 1,
+    ACTIONS(281), 1,
       anon_sym_DOT,
-    ACTIONS(167), 3,
+    ACTIONS(279), 3,
+      anon_sym_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(277), 16,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_e,
+      anon_sym_ou,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_RBRACK,
+  [5693] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(285), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(165), 15,
+    ACTIONS(283), 18,
+      anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
@@ -3647,17 +7387,23 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_STAR,
       anon_sym_SLASH,
+      anon_sym_DOT,
       anon_sym_RBRACK,
-  [2331] = 2,
-    ACTIONS(187), 3,
+  [5725] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(289), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(185), 17,
+    ACTIONS(287), 18,
       anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
@@ -3671,16 +7417,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       anon_sym_RBRACK,
-  [2356] = 2,
-    ACTIONS(191), 3,
+  [5757] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(293), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(189), 17,
+    ACTIONS(291), 18,
       anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
@@ -3694,16 +7445,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       anon_sym_RBRACK,
-  [2381] = 2,
-    ACTIONS(195), 3,
+  [5789] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(297), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(193), 17,
+    ACTIONS(295), 18,
       anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
@@ -3717,16 +7473,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       anon_sym_RBRACK,
-  [2406] = 2,
-    ACTIONS(199), 3,
+  [5821] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(301), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(197), 17,
+    ACTIONS(299), 18,
       anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
@@ -3740,64 +7501,107 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       anon_sym_RBRACK,
-  [2431] = 3,
-    ACTIONS(205), 1,
-      anon_sym_senu00e3o,
-    ACTIONS(203), 6,
+  [5853] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(305), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(201), 13,
+    ACTIONS(303), 16,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
+      anon_sym_senu00e3o,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [2458] = 3,
-    ACTIONS(211), 1,
-      anon_sym_senu00e3o,
-    ACTIONS(209), 6,
+  [5885] = 6,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(309), 3,
+      anon_sym_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(307), 16,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_e,
+      anon_sym_ou,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_RBRACK,
+  [5921] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(313), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(207), 13,
+    ACTIONS(311), 16,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
+      anon_sym_senu00e3o,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [2485] = 2,
-    ACTIONS(215), 3,
+  [5953] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(317), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(213), 17,
+    ACTIONS(315), 18,
       anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
@@ -3811,50 +7615,49 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       anon_sym_RBRACK,
-  [2510] = 10,
-    ACTIONS(163), 1,
-      anon_sym_LPAREN,
-    ACTIONS(167), 1,
+  [5985] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(321), 3,
       anon_sym_EQ,
-    ACTIONS(171), 1,
-      anon_sym_DOT,
-    ACTIONS(169), 2,
-      anon_sym_STAR,
-      anon_sym_SLASH,
-    ACTIONS(217), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(319), 18,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
-    ACTIONS(219), 2,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
-      anon_sym_LT,
-      anon_sym_GT,
-    ACTIONS(223), 2,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(165), 5,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-      anon_sym_LBRACE,
-      anon_sym_SEMI,
-      anon_sym_RBRACK,
-  [2551] = 4,
-    ACTIONS(163), 1,
-      anon_sym_LPAREN,
-    ACTIONS(171), 1,
+      anon_sym_STAR,
+      anon_sym_SLASH,
       anon_sym_DOT,
-    ACTIONS(229), 3,
+      anon_sym_RBRACK,
+  [6017] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(325), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(227), 15,
+    ACTIONS(323), 18,
+      anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
@@ -3866,76 +7669,107 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_STAR,
       anon_sym_SLASH,
+      anon_sym_DOT,
       anon_sym_RBRACK,
-  [2580] = 9,
-    ACTIONS(163), 1,
-      anon_sym_LPAREN,
-    ACTIONS(167), 1,
+  [6049] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(329), 3,
       anon_sym_EQ,
-    ACTIONS(171), 1,
-      anon_sym_DOT,
-    ACTIONS(169), 2,
-      anon_sym_STAR,
-      anon_sym_SLASH,
-    ACTIONS(219), 2,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-    ACTIONS(225), 2,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-    ACTIONS(165), 7,
+    ACTIONS(327), 18,
+      anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
-      anon_sym_RBRACK,
-  [2619] = 8,
-    ACTIONS(163), 1,
-      anon_sym_LPAREN,
-    ACTIONS(167), 1,
-      anon_sym_EQ,
-    ACTIONS(171), 1,
-      anon_sym_DOT,
-    ACTIONS(169), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
       anon_sym_STAR,
       anon_sym_SLASH,
-    ACTIONS(221), 2,
+      anon_sym_DOT,
+      anon_sym_RBRACK,
+  [6081] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(333), 3,
+      anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
+    ACTIONS(331), 18,
+      anon_sym_LPAREN,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_e,
+      anon_sym_ou,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(165), 9,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_RBRACK,
+  [6113] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(337), 3,
+      anon_sym_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(335), 18,
+      anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_DOT,
       anon_sym_RBRACK,
-  [2656] = 2,
-    ACTIONS(233), 3,
+  [6145] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(341), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(231), 17,
+    ACTIONS(339), 18,
       anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
@@ -3949,16 +7783,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       anon_sym_RBRACK,
-  [2681] = 2,
-    ACTIONS(237), 3,
+  [6177] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(345), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(235), 17,
+    ACTIONS(343), 18,
       anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
@@ -3972,18 +7811,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       anon_sym_RBRACK,
-  [2706] = 3,
-    ACTIONS(243), 1,
-      anon_sym_DOT,
-    ACTIONS(241), 3,
+  [6209] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(349), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(239), 16,
+    ACTIONS(347), 18,
       anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
@@ -3995,17 +7837,23 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_STAR,
       anon_sym_SLASH,
+      anon_sym_DOT,
       anon_sym_RBRACK,
-  [2733] = 2,
-    ACTIONS(247), 3,
+  [6241] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(353), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(245), 17,
+    ACTIONS(351), 18,
       anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
@@ -4019,25 +7867,22 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_SLASH,
       anon_sym_DOT,
       anon_sym_RBRACK,
-  [2758] = 6,
-    ACTIONS(163), 1,
-      anon_sym_LPAREN,
-    ACTIONS(171), 1,
-      anon_sym_DOT,
-    ACTIONS(169), 2,
-      anon_sym_STAR,
-      anon_sym_SLASH,
-    ACTIONS(225), 2,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-    ACTIONS(167), 3,
+  [6273] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(357), 1,
+      anon_sym_LBRACE,
+    ACTIONS(359), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(165), 11,
+    ACTIONS(355), 17,
+      anon_sym_LPAREN,
       anon_sym_COMMA,
       anon_sym_RPAREN,
-      anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_e,
       anon_sym_ou,
@@ -4045,1680 +7890,4265 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+      anon_sym_DOT,
       anon_sym_RBRACK,
-  [2791] = 2,
-    ACTIONS(251), 6,
+  [6307] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(363), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(249), 13,
+    ACTIONS(361), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [2815] = 2,
-    ACTIONS(255), 6,
+  [6338] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(367), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(253), 13,
+    ACTIONS(365), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [2839] = 2,
-    ACTIONS(259), 6,
+  [6369] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(371), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(257), 13,
+    ACTIONS(369), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [2863] = 2,
-    ACTIONS(263), 6,
+  [6400] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(375), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(261), 13,
+    ACTIONS(373), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [2887] = 2,
-    ACTIONS(267), 6,
+  [6431] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(379), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(265), 13,
+    ACTIONS(377), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [2911] = 2,
-    ACTIONS(271), 6,
+  [6462] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(383), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(269), 13,
+    ACTIONS(381), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [2935] = 2,
-    ACTIONS(275), 6,
+  [6493] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(387), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(273), 13,
+    ACTIONS(385), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [2959] = 2,
-    ACTIONS(279), 6,
+  [6524] = 10,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(309), 1,
+      anon_sym_EQ,
+    ACTIONS(389), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(391), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(393), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(395), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(307), 9,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_e,
+      anon_sym_ou,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_RBRACK,
+  [6567] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(399), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(277), 13,
+    ACTIONS(397), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [2983] = 2,
-    ACTIONS(283), 6,
+  [6598] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(403), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACK,
+    ACTIONS(401), 15,
+      anon_sym_parar,
+      anon_sym_continuar,
+      anon_sym_retornar,
+      anon_sym_se,
+      anon_sym_enquanto,
+      anon_sym_para,
+      anon_sym_escolha,
+      anon_sym_var,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+      sym_integer_literal,
+      sym_fractional_literal,
+      sym_identifier,
+  [6629] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(407), 5,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(281), 13,
+    ACTIONS(405), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [3007] = 2,
-    ACTIONS(287), 6,
+  [6660] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(411), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACK,
+    ACTIONS(409), 15,
+      anon_sym_parar,
+      anon_sym_continuar,
+      anon_sym_retornar,
+      anon_sym_se,
+      anon_sym_enquanto,
+      anon_sym_para,
+      anon_sym_escolha,
+      anon_sym_var,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+      sym_integer_literal,
+      sym_fractional_literal,
+      sym_identifier,
+  [6691] = 8,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(393), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(395), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(309), 3,
+      anon_sym_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(307), 11,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_e,
+      anon_sym_ou,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_RBRACK,
+  [6730] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(415), 5,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(285), 13,
+    ACTIONS(413), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [3031] = 2,
-    ACTIONS(291), 6,
+  [6761] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(419), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACK,
+    ACTIONS(417), 15,
+      anon_sym_parar,
+      anon_sym_continuar,
+      anon_sym_retornar,
+      anon_sym_se,
+      anon_sym_enquanto,
+      anon_sym_para,
+      anon_sym_escolha,
+      anon_sym_var,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+      sym_integer_literal,
+      sym_fractional_literal,
+      sym_identifier,
+  [6792] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(423), 5,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(289), 13,
+    ACTIONS(421), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [3055] = 2,
-    ACTIONS(295), 6,
+  [6823] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(395), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(309), 3,
+      anon_sym_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(307), 13,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_e,
+      anon_sym_ou,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_RBRACK,
+  [6860] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(427), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACK,
+    ACTIONS(425), 15,
+      anon_sym_parar,
+      anon_sym_continuar,
+      anon_sym_retornar,
+      anon_sym_se,
+      anon_sym_enquanto,
+      anon_sym_para,
+      anon_sym_escolha,
+      anon_sym_var,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+      sym_integer_literal,
+      sym_fractional_literal,
+      sym_identifier,
+  [6891] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(431), 5,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(293), 13,
+    ACTIONS(429), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [3079] = 2,
-    ACTIONS(299), 6,
+  [6922] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(435), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACK,
+    ACTIONS(433), 15,
+      anon_sym_parar,
+      anon_sym_continuar,
+      anon_sym_retornar,
+      anon_sym_se,
+      anon_sym_enquanto,
+      anon_sym_para,
+      anon_sym_escolha,
+      anon_sym_var,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+      sym_integer_literal,
+      sym_fractional_literal,
+      sym_identifier,
+  [6953] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(439), 5,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(297), 13,
+    ACTIONS(437), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [3103] = 2,
-    ACTIONS(303), 6,
+  [6984] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(443), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACK,
+    ACTIONS(441), 15,
+      anon_sym_parar,
+      anon_sym_continuar,
+      anon_sym_retornar,
+      anon_sym_se,
+      anon_sym_enquanto,
+      anon_sym_para,
+      anon_sym_escolha,
+      anon_sym_var,
+      anon_sym_DASH,
       anon_sym_nu00e3o,
+      anon_sym_verdadeiro,
+      anon_sym_falso,
+      sym_integer_literal,
+      sym_fractional_literal,
+      sym_identifier,
+  [7015] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(447), 5,
+      anon_sym_LPAREN,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(301), 13,
+    ACTIONS(445), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [3127] = 2,
-    ACTIONS(307), 6,
+  [7046] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(451), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(305), 13,
+    ACTIONS(449), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [3151] = 2,
-    ACTIONS(311), 6,
+  [7077] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(309), 1,
+      anon_sym_EQ,
+    ACTIONS(389), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(391), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(393), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(395), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(453), 2,
+      anon_sym_e,
+      anon_sym_ou,
+    ACTIONS(455), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(307), 5,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_RBRACK,
+  [7124] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(459), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(309), 13,
+    ACTIONS(457), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [3175] = 2,
-    ACTIONS(315), 6,
+  [7155] = 11,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(309), 1,
+      anon_sym_EQ,
+    ACTIONS(389), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(391), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(393), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(395), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(455), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(307), 7,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_RBRACE,
+      anon_sym_SEMI,
+      anon_sym_e,
+      anon_sym_ou,
+      anon_sym_RBRACK,
+  [7200] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(463), 5,
       anon_sym_LPAREN,
       anon_sym_RBRACE,
       anon_sym_STAR,
-      anon_sym_nu00e3o,
       anon_sym_DQUOTE,
       anon_sym_LBRACK,
-    ACTIONS(313), 13,
+    ACTIONS(461), 15,
       anon_sym_parar,
       anon_sym_continuar,
       anon_sym_retornar,
       anon_sym_se,
       anon_sym_enquanto,
       anon_sym_para,
+      anon_sym_escolha,
       anon_sym_var,
       anon_sym_DASH,
+      anon_sym_nu00e3o,
       anon_sym_verdadeiro,
       anon_sym_falso,
       sym_integer_literal,
       sym_fractional_literal,
       sym_identifier,
-  [3199] = 12,
-    ACTIONS(163), 1,
+  [7231] = 14,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
       anon_sym_LPAREN,
-    ACTIONS(171), 1,
+    ACTIONS(281), 1,
       anon_sym_DOT,
-    ACTIONS(317), 1,
+    ACTIONS(465), 1,
       anon_sym_COMMA,
-    ACTIONS(319), 1,
+    ACTIONS(467), 1,
+      anon_sym_RPAREN,
+    ACTIONS(469), 1,
       anon_sym_EQ,
-    ACTIONS(321), 1,
-      anon_sym_RBRACK,
-    STATE(119), 1,
-      aux_sym_list_repeat1,
-    ACTIONS(169), 2,
+    STATE(230), 1,
+      aux_sym_call_expr_repeat1,
+    ACTIONS(389), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(391), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(393), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(395), 2,
       anon_sym_STAR,
       anon_sym_SLASH,
-    ACTIONS(217), 2,
+    ACTIONS(453), 2,
       anon_sym_e,
       anon_sym_ou,
-    ACTIONS(219), 2,
+    ACTIONS(455), 2,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
+  [7280] = 14,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(469), 1,
+      anon_sym_EQ,
+    ACTIONS(471), 1,
+      anon_sym_COMMA,
+    ACTIONS(473), 1,
+      anon_sym_RBRACK,
+    STATE(236), 1,
+      aux_sym_list_repeat1,
+    ACTIONS(389), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
+    ACTIONS(391), 2,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
+    ACTIONS(393), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-  [3242] = 12,
-    ACTIONS(163), 1,
-      anon_sym_LPAREN,
-    ACTIONS(171), 1,
-      anon_sym_DOT,
-    ACTIONS(319), 1,
-      anon_sym_EQ,
-    ACTIONS(323), 1,
-      anon_sym_COMMA,
-    ACTIONS(325), 1,
-      anon_sym_RPAREN,
-    STATE(116), 1,
-      aux_sym_call_expr_repeat1,
-    ACTIONS(169), 2,
+    ACTIONS(395), 2,
       anon_sym_STAR,
       anon_sym_SLASH,
-    ACTIONS(217), 2,
+    ACTIONS(453), 2,
       anon_sym_e,
       anon_sym_ou,
-    ACTIONS(219), 2,
+    ACTIONS(455), 2,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
+  [7329] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(469), 1,
+      anon_sym_EQ,
+    ACTIONS(389), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
+    ACTIONS(391), 2,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
+    ACTIONS(393), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-  [3285] = 10,
-    ACTIONS(163), 1,
+    ACTIONS(395), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(453), 2,
+      anon_sym_e,
+      anon_sym_ou,
+    ACTIONS(455), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(475), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [7373] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
       anon_sym_LPAREN,
-    ACTIONS(171), 1,
+    ACTIONS(281), 1,
       anon_sym_DOT,
-    ACTIONS(319), 1,
+    ACTIONS(469), 1,
       anon_sym_EQ,
-    ACTIONS(169), 2,
+    ACTIONS(389), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(391), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(393), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(395), 2,
       anon_sym_STAR,
       anon_sym_SLASH,
-    ACTIONS(217), 2,
+    ACTIONS(453), 2,
       anon_sym_e,
       anon_sym_ou,
-    ACTIONS(219), 2,
+    ACTIONS(455), 2,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
+    ACTIONS(477), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [7417] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(469), 1,
+      anon_sym_EQ,
+    ACTIONS(389), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
+    ACTIONS(391), 2,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
+    ACTIONS(393), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(327), 2,
+    ACTIONS(395), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(453), 2,
+      anon_sym_e,
+      anon_sym_ou,
+    ACTIONS(455), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(479), 2,
       anon_sym_COMMA,
       anon_sym_RPAREN,
-  [3323] = 10,
-    ACTIONS(163), 1,
+  [7461] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
       anon_sym_LPAREN,
-    ACTIONS(171), 1,
+    ACTIONS(281), 1,
       anon_sym_DOT,
-    ACTIONS(319), 1,
+    ACTIONS(469), 1,
       anon_sym_EQ,
-    ACTIONS(169), 2,
+    ACTIONS(481), 1,
+      anon_sym_RBRACE,
+    ACTIONS(389), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(391), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(393), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(395), 2,
       anon_sym_STAR,
       anon_sym_SLASH,
-    ACTIONS(217), 2,
+    ACTIONS(453), 2,
       anon_sym_e,
       anon_sym_ou,
-    ACTIONS(219), 2,
+    ACTIONS(455), 2,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
+  [7504] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(483), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(309), 3,
+      anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
+    ACTIONS(307), 9,
+      anon_sym_LBRACE,
+      anon_sym_e,
+      anon_sym_ou,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(329), 2,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
-  [3361] = 10,
-    ACTIONS(163), 1,
+  [7537] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
       anon_sym_LPAREN,
-    ACTIONS(171), 1,
+    ACTIONS(281), 1,
       anon_sym_DOT,
-    ACTIONS(319), 1,
+    ACTIONS(469), 1,
       anon_sym_EQ,
-    ACTIONS(331), 1,
+    ACTIONS(485), 1,
+      anon_sym_SEMI,
+    ACTIONS(389), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(391), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(393), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(395), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(453), 2,
+      anon_sym_e,
+      anon_sym_ou,
+    ACTIONS(455), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [7580] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(487), 1,
       anon_sym_LBRACE,
-    ACTIONS(169), 2,
+    ACTIONS(489), 1,
+      anon_sym_EQ,
+    ACTIONS(483), 2,
       anon_sym_STAR,
       anon_sym_SLASH,
-    ACTIONS(217), 2,
+    ACTIONS(491), 2,
       anon_sym_e,
       anon_sym_ou,
-    ACTIONS(219), 2,
+    ACTIONS(493), 2,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
+    ACTIONS(495), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(497), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(499), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [7623] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(469), 1,
+      anon_sym_EQ,
+    ACTIONS(501), 1,
+      anon_sym_SEMI,
+    ACTIONS(389), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
+    ACTIONS(391), 2,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
+    ACTIONS(393), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-  [3398] = 10,
-    ACTIONS(163), 1,
+    ACTIONS(395), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(453), 2,
+      anon_sym_e,
+      anon_sym_ou,
+    ACTIONS(455), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [7666] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
       anon_sym_LPAREN,
-    ACTIONS(171), 1,
+    ACTIONS(281), 1,
       anon_sym_DOT,
-    ACTIONS(319), 1,
+    ACTIONS(489), 1,
       anon_sym_EQ,
-    ACTIONS(333), 1,
+    ACTIONS(503), 1,
       anon_sym_LBRACE,
-    ACTIONS(169), 2,
+    ACTIONS(483), 2,
       anon_sym_STAR,
       anon_sym_SLASH,
-    ACTIONS(217), 2,
+    ACTIONS(491), 2,
       anon_sym_e,
       anon_sym_ou,
-    ACTIONS(219), 2,
+    ACTIONS(493), 2,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
+    ACTIONS(495), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
+    ACTIONS(497), 2,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
+    ACTIONS(499), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-  [3435] = 10,
-    ACTIONS(163), 1,
+  [7709] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
       anon_sym_LPAREN,
-    ACTIONS(171), 1,
+    ACTIONS(281), 1,
       anon_sym_DOT,
-    ACTIONS(319), 1,
+    ACTIONS(469), 1,
       anon_sym_EQ,
-    ACTIONS(335), 1,
-      anon_sym_SEMI,
-    ACTIONS(169), 2,
+    ACTIONS(505), 1,
+      anon_sym_RPAREN,
+    ACTIONS(389), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(391), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(393), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(395), 2,
       anon_sym_STAR,
       anon_sym_SLASH,
-    ACTIONS(217), 2,
+    ACTIONS(453), 2,
       anon_sym_e,
       anon_sym_ou,
-    ACTIONS(219), 2,
+    ACTIONS(455), 2,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
+  [7752] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(469), 1,
+      anon_sym_EQ,
+    ACTIONS(507), 1,
+      anon_sym_SEMI,
+    ACTIONS(389), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
+    ACTIONS(391), 2,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
+    ACTIONS(393), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-  [3472] = 10,
-    ACTIONS(163), 1,
+    ACTIONS(395), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(453), 2,
+      anon_sym_e,
+      anon_sym_ou,
+    ACTIONS(455), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [7795] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
       anon_sym_LPAREN,
-    ACTIONS(171), 1,
+    ACTIONS(281), 1,
       anon_sym_DOT,
-    ACTIONS(319), 1,
+    ACTIONS(469), 1,
       anon_sym_EQ,
-    ACTIONS(337), 1,
+    ACTIONS(509), 1,
       anon_sym_SEMI,
-    ACTIONS(169), 2,
+    ACTIONS(389), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(391), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(393), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(395), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(453), 2,
+      anon_sym_e,
+      anon_sym_ou,
+    ACTIONS(455), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [7838] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(489), 1,
+      anon_sym_EQ,
+    ACTIONS(511), 1,
+      anon_sym_LBRACE,
+    ACTIONS(483), 2,
       anon_sym_STAR,
       anon_sym_SLASH,
-    ACTIONS(217), 2,
+    ACTIONS(491), 2,
       anon_sym_e,
       anon_sym_ou,
-    ACTIONS(219), 2,
+    ACTIONS(493), 2,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
+    ACTIONS(495), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
+    ACTIONS(497), 2,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
+    ACTIONS(499), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-  [3509] = 10,
-    ACTIONS(163), 1,
+  [7881] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
       anon_sym_LPAREN,
-    ACTIONS(171), 1,
+    ACTIONS(281), 1,
       anon_sym_DOT,
-    ACTIONS(319), 1,
+    ACTIONS(489), 1,
       anon_sym_EQ,
-    ACTIONS(339), 1,
-      anon_sym_RPAREN,
-    ACTIONS(169), 2,
+    ACTIONS(513), 1,
+      anon_sym_LBRACE,
+    ACTIONS(483), 2,
       anon_sym_STAR,
       anon_sym_SLASH,
-    ACTIONS(217), 2,
+    ACTIONS(491), 2,
       anon_sym_e,
       anon_sym_ou,
-    ACTIONS(219), 2,
+    ACTIONS(493), 2,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
+    ACTIONS(495), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
+    ACTIONS(497), 2,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
+    ACTIONS(499), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-  [3546] = 10,
-    ACTIONS(163), 1,
+  [7924] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
       anon_sym_LPAREN,
-    ACTIONS(171), 1,
+    ACTIONS(281), 1,
       anon_sym_DOT,
-    ACTIONS(319), 1,
+    ACTIONS(489), 1,
       anon_sym_EQ,
-    ACTIONS(341), 1,
+    ACTIONS(515), 1,
       anon_sym_LBRACE,
-    ACTIONS(169), 2,
+    ACTIONS(483), 2,
       anon_sym_STAR,
       anon_sym_SLASH,
-    ACTIONS(217), 2,
+    ACTIONS(491), 2,
       anon_sym_e,
       anon_sym_ou,
-    ACTIONS(219), 2,
+    ACTIONS(493), 2,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
+    ACTIONS(495), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
+    ACTIONS(497), 2,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
+    ACTIONS(499), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-  [3583] = 10,
-    ACTIONS(163), 1,
+  [7967] = 12,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
       anon_sym_LPAREN,
-    ACTIONS(171), 1,
+    ACTIONS(281), 1,
       anon_sym_DOT,
-    ACTIONS(319), 1,
+    ACTIONS(307), 1,
+      anon_sym_LBRACE,
+    ACTIONS(309), 1,
       anon_sym_EQ,
-    ACTIONS(343), 1,
-      anon_sym_SEMI,
-    ACTIONS(169), 2,
+    ACTIONS(483), 2,
       anon_sym_STAR,
       anon_sym_SLASH,
-    ACTIONS(217), 2,
+    ACTIONS(491), 2,
       anon_sym_e,
       anon_sym_ou,
-    ACTIONS(219), 2,
+    ACTIONS(493), 2,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
+    ACTIONS(495), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
+    ACTIONS(497), 2,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
+    ACTIONS(499), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-  [3620] = 10,
-    ACTIONS(163), 1,
+  [8010] = 11,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
       anon_sym_LPAREN,
-    ACTIONS(171), 1,
+    ACTIONS(281), 1,
       anon_sym_DOT,
-    ACTIONS(319), 1,
+    ACTIONS(309), 1,
       anon_sym_EQ,
-    ACTIONS(345), 1,
-      anon_sym_SEMI,
-    ACTIONS(169), 2,
+    ACTIONS(483), 2,
       anon_sym_STAR,
       anon_sym_SLASH,
-    ACTIONS(217), 2,
-      anon_sym_e,
-      anon_sym_ou,
-    ACTIONS(219), 2,
+    ACTIONS(493), 2,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(221), 2,
+    ACTIONS(495), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(497), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(499), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(307), 3,
+      anon_sym_LBRACE,
+      anon_sym_e,
+      anon_sym_ou,
+  [8051] = 10,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(309), 1,
+      anon_sym_EQ,
+    ACTIONS(483), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(495), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(223), 2,
+    ACTIONS(497), 2,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-    ACTIONS(225), 2,
+    ACTIONS(499), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(307), 5,
+      anon_sym_LBRACE,
+      anon_sym_e,
+      anon_sym_ou,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [8090] = 8,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(281), 1,
+      anon_sym_DOT,
+    ACTIONS(483), 2,
+      anon_sym_STAR,
+      anon_sym_SLASH,
+    ACTIONS(499), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-  [3657] = 6,
-    ACTIONS(347), 1,
+    ACTIONS(309), 3,
+      anon_sym_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(307), 7,
+      anon_sym_LBRACE,
+      anon_sym_e,
+      anon_sym_ou,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [8125] = 8,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(517), 1,
+      ts_builtin_sym_end,
+    ACTIONS(519), 1,
+      anon_sym_func,
+    ACTIONS(522), 1,
+      anon_sym_procedimento,
+    ACTIONS(525), 1,
+      anon_sym_estrutura,
+    ACTIONS(528), 1,
+      anon_sym_enumerau00e7u00e3o,
+    STATE(151), 5,
+      sym_function,
+      sym_procedure,
+      sym_struct,
+      sym_enum,
+      aux_sym_program_repeat1,
+  [8154] = 8,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(9), 1,
+      anon_sym_func,
+    ACTIONS(11), 1,
+      anon_sym_procedimento,
+    ACTIONS(13), 1,
+      anon_sym_estrutura,
+    ACTIONS(15), 1,
+      anon_sym_enumerau00e7u00e3o,
+    ACTIONS(531), 1,
+      ts_builtin_sym_end,
+    STATE(151), 5,
+      sym_function,
+      sym_procedure,
+      sym_struct,
+      sym_enum,
+      aux_sym_program_repeat1,
+  [8183] = 8,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
       sym_identifier,
-    ACTIONS(349), 1,
+    ACTIONS(535), 1,
       anon_sym_mut,
-    ACTIONS(351), 1,
+    ACTIONS(537), 1,
       anon_sym_ref,
-    ACTIONS(353), 1,
+    ACTIONS(539), 1,
       anon_sym_GT,
-    ACTIONS(355), 1,
+    ACTIONS(541), 1,
+      anon_sym_comp,
+    STATE(253), 5,
+      sym__type,
+      sym_template_type,
+      sym_raw_type,
+      sym_ref_type,
+      sym_comp_type,
+  [8212] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
+      sym_identifier,
+    ACTIONS(535), 1,
+      anon_sym_mut,
+    ACTIONS(537), 1,
+      anon_sym_ref,
+    ACTIONS(541), 1,
+      anon_sym_comp,
+    STATE(339), 5,
+      sym__type,
+      sym_template_type,
+      sym_raw_type,
+      sym_ref_type,
+      sym_comp_type,
+  [8238] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
+      sym_identifier,
+    ACTIONS(535), 1,
+      anon_sym_mut,
+    ACTIONS(537), 1,
+      anon_sym_ref,
+    ACTIONS(541), 1,
+      anon_sym_comp,
+    STATE(276), 5,
+      sym__type,
+      sym_template_type,
+      sym_raw_type,
+      sym_ref_type,
+      sym_comp_type,
+  [8264] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
+      sym_identifier,
+    ACTIONS(535), 1,
+      anon_sym_mut,
+    ACTIONS(537), 1,
+      anon_sym_ref,
+    ACTIONS(541), 1,
+      anon_sym_comp,
+    STATE(338), 5,
+      sym__type,
+      sym_template_type,
+      sym_raw_type,
+      sym_ref_type,
+      sym_comp_type,
+  [8290] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
+      sym_identifier,
+    ACTIONS(535), 1,
+      anon_sym_mut,
+    ACTIONS(537), 1,
+      anon_sym_ref,
+    ACTIONS(541), 1,
+      anon_sym_comp,
+    STATE(307), 5,
+      sym__type,
+      sym_template_type,
+      sym_raw_type,
+      sym_ref_type,
+      sym_comp_type,
+  [8316] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
+      sym_identifier,
+    ACTIONS(535), 1,
+      anon_sym_mut,
+    ACTIONS(537), 1,
+      anon_sym_ref,
+    ACTIONS(541), 1,
+      anon_sym_comp,
+    STATE(282), 5,
+      sym__type,
+      sym_template_type,
+      sym_raw_type,
+      sym_ref_type,
+      sym_comp_type,
+  [8342] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
+      sym_identifier,
+    ACTIONS(535), 1,
+      anon_sym_mut,
+    ACTIONS(537), 1,
+      anon_sym_ref,
+    ACTIONS(541), 1,
       anon_sym_comp,
-    STATE(117), 5,
+    STATE(177), 5,
       sym__type,
       sym_template_type,
       sym_raw_type,
       sym_ref_type,
       sym_comp_type,
-  [3680] = 5,
-    ACTIONS(347), 1,
+  [8368] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
       sym_identifier,
-    ACTIONS(349), 1,
+    ACTIONS(535), 1,
       anon_sym_mut,
-    ACTIONS(351), 1,
+    ACTIONS(537), 1,
       anon_sym_ref,
-    ACTIONS(355), 1,
+    ACTIONS(541), 1,
       anon_sym_comp,
-    STATE(138), 5,
+    STATE(174), 5,
       sym__type,
       sym_template_type,
       sym_raw_type,
       sym_ref_type,
       sym_comp_type,
-  [3700] = 5,
-    ACTIONS(347), 1,
+  [8394] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
       sym_identifier,
-    ACTIONS(349), 1,
+    ACTIONS(535), 1,
       anon_sym_mut,
-    ACTIONS(351), 1,
+    ACTIONS(537), 1,
       anon_sym_ref,
-    ACTIONS(355), 1,
+    ACTIONS(541), 1,
       anon_sym_comp,
-    STATE(148), 5,
+    STATE(262), 5,
       sym__type,
       sym_template_type,
       sym_raw_type,
       sym_ref_type,
       sym_comp_type,
-  [3720] = 5,
-    ACTIONS(347), 1,
+  [8420] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
       sym_identifier,
-    ACTIONS(349), 1,
+    ACTIONS(535), 1,
       anon_sym_mut,
-    ACTIONS(351), 1,
+    ACTIONS(537), 1,
       anon_sym_ref,
-    ACTIONS(355), 1,
+    ACTIONS(541), 1,
       anon_sym_comp,
-    STATE(101), 5,
+    STATE(331), 5,
       sym__type,
       sym_template_type,
       sym_raw_type,
       sym_ref_type,
       sym_comp_type,
-  [3740] = 5,
-    ACTIONS(347), 1,
+  [8446] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
       sym_identifier,
-    ACTIONS(349), 1,
+    ACTIONS(535), 1,
       anon_sym_mut,
-    ACTIONS(351), 1,
+    ACTIONS(537), 1,
       anon_sym_ref,
-    ACTIONS(355), 1,
+    ACTIONS(541), 1,
       anon_sym_comp,
-    STATE(107), 5,
+    STATE(269), 5,
       sym__type,
       sym_template_type,
       sym_raw_type,
       sym_ref_type,
       sym_comp_type,
-  [3760] = 5,
-    ACTIONS(347), 1,
+  [8472] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
       sym_identifier,
-    ACTIONS(349), 1,
+    ACTIONS(535), 1,
       anon_sym_mut,
-    ACTIONS(351), 1,
+    ACTIONS(537), 1,
       anon_sym_ref,
-    ACTIONS(355), 1,
+    ACTIONS(541), 1,
       anon_sym_comp,
-    STATE(137), 5,
+    STATE(251), 5,
       sym__type,
       sym_template_type,
       sym_raw_type,
       sym_ref_type,
       sym_comp_type,
-  [3780] = 5,
-    ACTIONS(347), 1,
+  [8498] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
       sym_identifier,
-    ACTIONS(349), 1,
+    ACTIONS(535), 1,
       anon_sym_mut,
-    ACTIONS(351), 1,
+    ACTIONS(537), 1,
       anon_sym_ref,
-    ACTIONS(355), 1,
+    ACTIONS(541), 1,
       anon_sym_comp,
-    STATE(135), 5,
+    STATE(280), 5,
       sym__type,
       sym_template_type,
       sym_raw_type,
       sym_ref_type,
       sym_comp_type,
-  [3800] = 5,
-    ACTIONS(347), 1,
+  [8524] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
       sym_identifier,
-    ACTIONS(349), 1,
+    ACTIONS(535), 1,
       anon_sym_mut,
-    ACTIONS(351), 1,
+    ACTIONS(537), 1,
       anon_sym_ref,
-    ACTIONS(355), 1,
+    ACTIONS(541), 1,
       anon_sym_comp,
-    STATE(132), 5,
+    STATE(333), 5,
       sym__type,
       sym_template_type,
       sym_raw_type,
       sym_ref_type,
       sym_comp_type,
-  [3820] = 5,
-    ACTIONS(347), 1,
+  [8550] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
       sym_identifier,
-    ACTIONS(349), 1,
+    ACTIONS(535), 1,
       anon_sym_mut,
-    ACTIONS(351), 1,
+    ACTIONS(537), 1,
       anon_sym_ref,
-    ACTIONS(355), 1,
+    ACTIONS(541), 1,
       anon_sym_comp,
-    STATE(164), 5,
+    STATE(176), 5,
       sym__type,
       sym_template_type,
       sym_raw_type,
       sym_ref_type,
       sym_comp_type,
-  [3840] = 5,
-    ACTIONS(347), 1,
+  [8576] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
       sym_identifier,
-    ACTIONS(349), 1,
+    ACTIONS(535), 1,
       anon_sym_mut,
-    ACTIONS(351), 1,
+    ACTIONS(537), 1,
       anon_sym_ref,
-    ACTIONS(355), 1,
+    ACTIONS(541), 1,
       anon_sym_comp,
-    STATE(105), 5,
+    STATE(175), 5,
       sym__type,
       sym_template_type,
       sym_raw_type,
       sym_ref_type,
       sym_comp_type,
-  [3860] = 5,
-    ACTIONS(347), 1,
+  [8602] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
       sym_identifier,
-    ACTIONS(349), 1,
+    ACTIONS(535), 1,
       anon_sym_mut,
-    ACTIONS(351), 1,
+    ACTIONS(537), 1,
       anon_sym_ref,
-    ACTIONS(355), 1,
+    ACTIONS(541), 1,
       anon_sym_comp,
-    STATE(106), 5,
+    STATE(290), 5,
       sym__type,
       sym_template_type,
       sym_raw_type,
       sym_ref_type,
       sym_comp_type,
-  [3880] = 5,
-    ACTIONS(347), 1,
+  [8628] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
       sym_identifier,
-    ACTIONS(349), 1,
+    ACTIONS(535), 1,
       anon_sym_mut,
-    ACTIONS(351), 1,
+    ACTIONS(537), 1,
       anon_sym_ref,
-    ACTIONS(355), 1,
+    ACTIONS(541), 1,
       anon_sym_comp,
-    STATE(133), 5,
+    STATE(316), 5,
       sym__type,
       sym_template_type,
       sym_raw_type,
       sym_ref_type,
       sym_comp_type,
-  [3900] = 5,
-    ACTIONS(347), 1,
+  [8654] = 7,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(533), 1,
       sym_identifier,
-    ACTIONS(349), 1,
+    ACTIONS(535), 1,
       anon_sym_mut,
-    ACTIONS(351), 1,
+    ACTIONS(537), 1,
       anon_sym_ref,
-    ACTIONS(355), 1,
+    ACTIONS(541), 1,
       anon_sym_comp,
-    STATE(140), 5,
+    STATE(261), 5,
       sym__type,
       sym_template_type,
       sym_raw_type,
       sym_ref_type,
       sym_comp_type,
-  [3920] = 2,
-    ACTIONS(359), 1,
+  [8680] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(545), 1,
       anon_sym_LT,
-    ACTIONS(357), 6,
+    ACTIONS(543), 7,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_EQ,
       anon_sym_GT,
-  [3932] = 1,
-    ACTIONS(361), 6,
+  [8699] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(547), 7,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_EQ,
       anon_sym_GT,
-  [3941] = 1,
-    ACTIONS(363), 6,
+  [8715] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(549), 7,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_EQ,
       anon_sym_GT,
-  [3950] = 1,
-    ACTIONS(365), 6,
+  [8731] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(551), 7,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_EQ,
       anon_sym_GT,
-  [3959] = 1,
-    ACTIONS(367), 6,
+  [8747] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(553), 7,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_EQ,
       anon_sym_GT,
-  [3968] = 1,
-    ACTIONS(369), 6,
+  [8763] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(555), 7,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_EQ,
       anon_sym_GT,
-  [3977] = 1,
-    ACTIONS(371), 6,
+  [8779] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(557), 7,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_EQ,
       anon_sym_GT,
-  [3986] = 1,
-    ACTIONS(373), 6,
+  [8795] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(559), 7,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_LBRACE,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_EQ,
       anon_sym_GT,
-  [3995] = 4,
-    ACTIONS(375), 1,
-      sym_identifier,
-    ACTIONS(377), 1,
-      anon_sym_mut,
-    ACTIONS(379), 1,
-      anon_sym_ref,
-    STATE(144), 1,
-      sym_for_item_decl,
-  [4008] = 3,
+  [8811] = 6,
+    ACTIONS(561), 1,
+      anon_sym_DQUOTE2,
+    ACTIONS(563), 1,
+      anon_sym_LBRACE2,
+    STATE(181), 1,
+      aux_sym_string_repeat1,
+    STATE(214), 1,
+      sym_interpolation,
+    ACTIONS(3), 2,
+      sym_line_comment,
+      sym_block_comment,
+    ACTIONS(565), 2,
+      sym_string_content,
+      sym_escape_sequence,
+  [8832] = 6,
+    ACTIONS(563), 1,
+      anon_sym_LBRACE2,
+    ACTIONS(567), 1,
+      anon_sym_DQUOTE2,
+    STATE(182), 1,
+      aux_sym_string_repeat1,
+    STATE(214), 1,
+      sym_interpolation,
+    ACTIONS(3), 2,
+      sym_line_comment,
+      sym_block_comment,
+    ACTIONS(565), 2,
+      sym_string_content,
+      sym_escape_sequence,
+  [8853] = 6,
+    ACTIONS(569), 1,
+      anon_sym_DQUOTE2,
+    ACTIONS(571), 1,
+      anon_sym_LBRACE2,
+    STATE(182), 1,
+      aux_sym_string_repeat1,
+    STATE(214), 1,
+      sym_interpolation,
+    ACTIONS(3), 2,
+      sym_line_comment,
+      sym_block_comment,
+    ACTIONS(574), 2,
+      sym_string_content,
+      sym_escape_sequence,
+  [8874] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
     ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(577), 5,
+      ts_builtin_sym_end,
       anon_sym_func,
-    ACTIONS(381), 1,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [8888] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(579), 5,
       ts_builtin_sym_end,
-    STATE(110), 2,
-      sym_function,
-      aux_sym_program_repeat1,
-  [4019] = 3,
-    ACTIONS(383), 1,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [8902] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(581), 5,
       ts_builtin_sym_end,
-    ACTIONS(385), 1,
       anon_sym_func,
-    STATE(110), 2,
-      sym_function,
-      aux_sym_program_repeat1,
-  [4030] = 4,
-    ACTIONS(388), 1,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [8916] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(583), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [8930] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(585), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [8944] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(587), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [8958] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(589), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [8972] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(591), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [8986] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(593), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9000] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(595), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9014] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(597), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9028] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(599), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9042] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(601), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9056] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(603), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9070] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(605), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9084] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(607), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9098] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(609), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9112] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(611), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9126] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(613), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9140] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(615), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9154] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(617), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9168] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(619), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9182] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(621), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9196] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(623), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9210] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(625), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9224] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(627), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9238] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(629), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9252] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(631), 5,
+      ts_builtin_sym_end,
+      anon_sym_func,
+      anon_sym_procedimento,
+      anon_sym_estrutura,
+      anon_sym_enumerau00e7u00e3o,
+  [9266] = 2,
+    ACTIONS(3), 2,
+      sym_line_comment,
+      sym_block_comment,
+    ACTIONS(633), 4,
+      anon_sym_DQUOTE2,
+      anon_sym_LBRACE2,
+      sym_string_content,
+      sym_escape_sequence,
+  [9277] = 6,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(635), 1,
+      anon_sym_RBRACE,
+    ACTIONS(637), 1,
+      anon_sym_caso,
+    STATE(212), 1,
+      aux_sym_match_stmt_repeat1,
+    STATE(267), 1,
+      sym_match_case,
+  [9296] = 6,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(640), 1,
+      sym_identifier,
+    ACTIONS(642), 1,
+      anon_sym_mut,
+    ACTIONS(644), 1,
+      anon_sym_ref,
+    STATE(298), 1,
+      sym_for_item_decl,
+  [9315] = 2,
+    ACTIONS(3), 2,
+      sym_line_comment,
+      sym_block_comment,
+    ACTIONS(646), 4,
+      anon_sym_DQUOTE2,
+      anon_sym_LBRACE2,
+      sym_string_content,
+      sym_escape_sequence,
+  [9326] = 6,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(648), 1,
+      anon_sym_RBRACE,
+    ACTIONS(650), 1,
+      anon_sym_caso,
+    STATE(212), 1,
+      aux_sym_match_stmt_repeat1,
+    STATE(267), 1,
+      sym_match_case,
+  [9345] = 6,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(650), 1,
+      anon_sym_caso,
+    ACTIONS(652), 1,
+      anon_sym_RBRACE,
+    STATE(215), 1,
+      aux_sym_match_stmt_repeat1,
+    STATE(267), 1,
+      sym_match_case,
+  [9364] = 6,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(654), 1,
+      sym_identifier,
+    ACTIONS(656), 1,
+      anon_sym_RPAREN,
+    ACTIONS(658), 1,
+      anon_sym_mut,
+    STATE(239), 1,
+      sym_function_param,
+  [9383] = 6,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(654), 1,
+      sym_identifier,
+    ACTIONS(658), 1,
+      anon_sym_mut,
+    ACTIONS(660), 1,
+      anon_sym_RPAREN,
+    STATE(241), 1,
+      sym_function_param,
+  [9402] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(662), 1,
+      anon_sym_COMMA,
+    ACTIONS(665), 1,
+      anon_sym_RBRACE,
+    STATE(219), 1,
+      aux_sym_enum_repeat1,
+  [9418] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(667), 1,
+      anon_sym_COMMA,
+    ACTIONS(670), 1,
+      anon_sym_RPAREN,
+    STATE(220), 1,
+      aux_sym_call_expr_repeat1,
+  [9434] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(672), 1,
+      anon_sym_COMMA,
+    ACTIONS(674), 1,
+      anon_sym_RBRACE,
+    STATE(244), 1,
+      aux_sym_struct_repeat1,
+  [9450] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(676), 1,
+      anon_sym_COMMA,
+    ACTIONS(679), 1,
+      anon_sym_GT,
+    STATE(222), 1,
+      aux_sym_template_type_repeat1,
+  [9466] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(681), 1,
+      sym_identifier,
+    ACTIONS(683), 1,
+      anon_sym_RBRACE,
+    STATE(272), 1,
+      sym_enum_variant,
+  [9482] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(685), 1,
+      anon_sym_COMMA,
+    ACTIONS(688), 1,
+      anon_sym_RBRACK,
+    STATE(224), 1,
+      aux_sym_list_repeat1,
+  [9498] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(690), 1,
+      sym_identifier,
+    ACTIONS(692), 1,
+      anon_sym_RBRACE,
+    STATE(288), 1,
+      sym_field_init,
+  [9514] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(694), 1,
+      anon_sym_COMMA,
+    ACTIONS(697), 1,
+      anon_sym_RPAREN,
+    STATE(226), 1,
+      aux_sym_function_repeat1,
+  [9530] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(699), 1,
+      anon_sym_COMMA,
+    ACTIONS(701), 1,
+      anon_sym_RBRACE,
+    STATE(254), 1,
+      aux_sym_struct_literal_repeat1,
+  [9546] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(703), 1,
+      anon_sym_COMMA,
+    ACTIONS(705), 1,
+      anon_sym_RPAREN,
+    STATE(235), 1,
+      aux_sym_enum_variant_repeat1,
+  [9562] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(707), 1,
+      sym_identifier,
+    ACTIONS(709), 1,
+      anon_sym_RBRACE,
+    STATE(245), 1,
+      sym_struct_field,
+  [9578] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(465), 1,
+      anon_sym_COMMA,
+    ACTIONS(711), 1,
+      anon_sym_RPAREN,
+    STATE(220), 1,
+      aux_sym_call_expr_repeat1,
+  [9594] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(681), 1,
       sym_identifier,
-    ACTIONS(390), 1,
+    ACTIONS(713), 1,
+      anon_sym_RBRACE,
+    STATE(272), 1,
+      sym_enum_variant,
+  [9610] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(715), 1,
+      anon_sym_COMMA,
+    ACTIONS(717), 1,
       anon_sym_RPAREN,
-    ACTIONS(392), 1,
-      anon_sym_mut,
-    STATE(115), 1,
-      sym_function_param,
-  [4043] = 3,
-    ACTIONS(394), 1,
+    STATE(226), 1,
+      aux_sym_function_repeat1,
+  [9626] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(681), 1,
+      sym_identifier,
+    ACTIONS(719), 1,
+      anon_sym_RBRACE,
+    STATE(249), 1,
+      sym_enum_variant,
+  [9642] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(721), 1,
       anon_sym_COMMA,
-    ACTIONS(397), 1,
+    ACTIONS(723), 1,
       anon_sym_RPAREN,
-    STATE(112), 1,
-      aux_sym_call_expr_repeat1,
-  [4053] = 3,
-    ACTIONS(399), 1,
+    STATE(240), 1,
+      aux_sym_match_case_repeat1,
+  [9658] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(725), 1,
       anon_sym_COMMA,
-    ACTIONS(402), 1,
+    ACTIONS(728), 1,
       anon_sym_RPAREN,
-    STATE(113), 1,
-      aux_sym_function_repeat1,
-  [4063] = 3,
-    ACTIONS(404), 1,
+    STATE(235), 1,
+      aux_sym_enum_variant_repeat1,
+  [9674] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(471), 1,
       anon_sym_COMMA,
-    ACTIONS(407), 1,
+    ACTIONS(730), 1,
       anon_sym_RBRACK,
-    STATE(114), 1,
+    STATE(224), 1,
       aux_sym_list_repeat1,
-  [4073] = 3,
-    ACTIONS(409), 1,
+  [9690] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(707), 1,
+      sym_identifier,
+    ACTIONS(732), 1,
+      anon_sym_RBRACE,
+    STATE(279), 1,
+      sym_struct_field,
+  [9706] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(707), 1,
+      sym_identifier,
+    ACTIONS(734), 1,
+      anon_sym_RBRACE,
+    STATE(279), 1,
+      sym_struct_field,
+  [9722] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(715), 1,
       anon_sym_COMMA,
-    ACTIONS(411), 1,
+    ACTIONS(736), 1,
       anon_sym_RPAREN,
-    STATE(120), 1,
+    STATE(252), 1,
       aux_sym_function_repeat1,
-  [4083] = 3,
-    ACTIONS(323), 1,
+  [9738] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(721), 1,
       anon_sym_COMMA,
-    ACTIONS(413), 1,
+    ACTIONS(738), 1,
       anon_sym_RPAREN,
-    STATE(112), 1,
-      aux_sym_call_expr_repeat1,
-  [4093] = 3,
-    ACTIONS(415), 1,
+    STATE(246), 1,
+      aux_sym_match_case_repeat1,
+  [9754] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(715), 1,
+      anon_sym_COMMA,
+    ACTIONS(740), 1,
+      anon_sym_RPAREN,
+    STATE(232), 1,
+      aux_sym_function_repeat1,
+  [9770] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(742), 1,
       anon_sym_COMMA,
-    ACTIONS(417), 1,
+    ACTIONS(744), 1,
       anon_sym_GT,
-    STATE(122), 1,
+    STATE(222), 1,
       aux_sym_template_type_repeat1,
-  [4103] = 3,
-    ACTIONS(388), 1,
+  [9786] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(746), 1,
+      anon_sym_COMMA,
+    ACTIONS(748), 1,
+      anon_sym_RBRACE,
+    STATE(227), 1,
+      aux_sym_struct_literal_repeat1,
+  [9802] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(750), 1,
+      anon_sym_COMMA,
+    ACTIONS(753), 1,
+      anon_sym_RBRACE,
+    STATE(244), 1,
+      aux_sym_struct_repeat1,
+  [9818] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(755), 1,
+      anon_sym_COMMA,
+    ACTIONS(757), 1,
+      anon_sym_RBRACE,
+    STATE(221), 1,
+      aux_sym_struct_repeat1,
+  [9834] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(759), 1,
+      anon_sym_COMMA,
+    ACTIONS(762), 1,
+      anon_sym_RPAREN,
+    STATE(246), 1,
+      aux_sym_match_case_repeat1,
+  [9850] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(690), 1,
+      sym_identifier,
+    ACTIONS(764), 1,
+      anon_sym_RBRACE,
+    STATE(288), 1,
+      sym_field_init,
+  [9866] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(766), 1,
+      anon_sym_LPAREN,
+    ACTIONS(768), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [9880] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(770), 1,
+      anon_sym_COMMA,
+    ACTIONS(772), 1,
+      anon_sym_RBRACE,
+    STATE(255), 1,
+      aux_sym_enum_repeat1,
+  [9896] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(654), 1,
       sym_identifier,
-    ACTIONS(392), 1,
+    ACTIONS(658), 1,
       anon_sym_mut,
-    STATE(134), 1,
+    STATE(291), 1,
       sym_function_param,
-  [4113] = 3,
-    ACTIONS(317), 1,
+  [9912] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(703), 1,
       anon_sym_COMMA,
-    ACTIONS(419), 1,
-      anon_sym_RBRACK,
-    STATE(114), 1,
-      aux_sym_list_repeat1,
-  [4123] = 3,
-    ACTIONS(409), 1,
+    ACTIONS(774), 1,
+      anon_sym_RPAREN,
+    STATE(228), 1,
+      aux_sym_enum_variant_repeat1,
+  [9928] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(715), 1,
       anon_sym_COMMA,
-    ACTIONS(421), 1,
+    ACTIONS(776), 1,
       anon_sym_RPAREN,
-    STATE(113), 1,
+    STATE(226), 1,
       aux_sym_function_repeat1,
-  [4133] = 3,
-    ACTIONS(423), 1,
+  [9944] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(742), 1,
       anon_sym_COMMA,
-    ACTIONS(426), 1,
+    ACTIONS(778), 1,
       anon_sym_GT,
-    STATE(121), 1,
+    STATE(242), 1,
       aux_sym_template_type_repeat1,
-  [4143] = 3,
-    ACTIONS(415), 1,
+  [9960] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(780), 1,
+      anon_sym_COMMA,
+    ACTIONS(783), 1,
+      anon_sym_RBRACE,
+    STATE(254), 1,
+      aux_sym_struct_literal_repeat1,
+  [9976] = 5,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(785), 1,
+      anon_sym_COMMA,
+    ACTIONS(787), 1,
+      anon_sym_RBRACE,
+    STATE(219), 1,
+      aux_sym_enum_repeat1,
+  [9992] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(789), 1,
+      anon_sym_LBRACE,
+    ACTIONS(791), 1,
+      anon_sym_se,
+  [10005] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(259), 1,
+      anon_sym_DOT,
+    ACTIONS(261), 1,
+      sym_exponent_literal,
+  [10018] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(793), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(795), 1,
+      anon_sym_LBRACE,
+  [10031] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(791), 1,
+      anon_sym_se,
+    ACTIONS(797), 1,
+      anon_sym_LBRACE,
+  [10044] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(690), 1,
+      sym_identifier,
+    STATE(288), 1,
+      sym_field_init,
+  [10057] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(679), 2,
       anon_sym_COMMA,
-    ACTIONS(428), 1,
       anon_sym_GT,
-    STATE(121), 1,
-      aux_sym_template_type_repeat1,
-  [4153] = 1,
-    ACTIONS(430), 2,
-      ts_builtin_sym_end,
-      anon_sym_func,
-  [4158] = 1,
-    ACTIONS(432), 2,
-      ts_builtin_sym_end,
-      anon_sym_func,
-  [4163] = 2,
-    ACTIONS(434), 1,
+  [10068] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(799), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [10079] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(791), 1,
+      anon_sym_se,
+    ACTIONS(801), 1,
+      anon_sym_LBRACE,
+  [10092] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(681), 1,
+      sym_identifier,
+    STATE(272), 1,
+      sym_enum_variant,
+  [10105] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(803), 1,
       anon_sym_ref,
-    ACTIONS(436), 1,
+    ACTIONS(805), 1,
       anon_sym_comp,
-  [4170] = 1,
-    ACTIONS(438), 2,
-      ts_builtin_sym_end,
-      anon_sym_func,
-  [4175] = 2,
-    ACTIONS(440), 1,
-      sym_identifier,
-    ACTIONS(442), 1,
-      anon_sym_mut,
-  [4182] = 1,
-    ACTIONS(444), 2,
+  [10118] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(791), 1,
+      anon_sym_se,
+    ACTIONS(807), 1,
+      anon_sym_LBRACE,
+  [10131] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(809), 2,
+      anon_sym_RBRACE,
+      anon_sym_caso,
+  [10142] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(811), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(813), 1,
+      anon_sym_LBRACE,
+  [10155] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(815), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [10166] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(817), 2,
+      anon_sym_RBRACE,
+      anon_sym_caso,
+  [10177] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(819), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(821), 1,
+      anon_sym_LBRACE,
+  [10190] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(823), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [10201] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(825), 1,
+      anon_sym_LPAREN,
+    ACTIONS(827), 1,
+      anon_sym_EQ_GT,
+  [10214] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(829), 2,
       sym_integer_literal,
       sym_fractional_literal,
-  [4187] = 1,
-    ACTIONS(446), 2,
-      ts_builtin_sym_end,
-      anon_sym_func,
-  [4192] = 1,
-    ACTIONS(448), 2,
-      ts_builtin_sym_end,
-      anon_sym_func,
-  [4197] = 1,
-    ACTIONS(450), 2,
-      ts_builtin_sym_end,
-      anon_sym_func,
-  [4202] = 2,
-    ACTIONS(452), 1,
+  [10225] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(831), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [10236] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(833), 1,
+      anon_sym_SEMI,
+    ACTIONS(835), 1,
+      anon_sym_EQ,
+  [10249] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(837), 2,
+      anon_sym_RBRACE,
+      anon_sym_caso,
+  [10260] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(690), 1,
+      sym_identifier,
+    STATE(243), 1,
+      sym_field_init,
+  [10273] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(839), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [10284] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(841), 1,
       anon_sym_SEMI,
-    ACTIONS(454), 1,
+    ACTIONS(843), 1,
       anon_sym_EQ,
-  [4209] = 1,
-    ACTIONS(456), 2,
+  [10297] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(845), 2,
+      anon_sym_RBRACE,
+      anon_sym_caso,
+  [10308] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(847), 2,
       anon_sym_COMMA,
       anon_sym_RPAREN,
-  [4214] = 1,
-    ACTIONS(458), 2,
+  [10319] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(707), 1,
+      sym_identifier,
+    STATE(279), 1,
+      sym_struct_field,
+  [10332] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(849), 2,
+      anon_sym_RBRACE,
+      anon_sym_caso,
+  [10343] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(851), 2,
+      anon_sym_RBRACE,
+      anon_sym_caso,
+  [10354] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(853), 2,
+      anon_sym_RBRACE,
+      anon_sym_caso,
+  [10365] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(855), 1,
+      sym_identifier,
+    ACTIONS(857), 1,
+      anon_sym_mut,
+  [10378] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(859), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [10389] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(861), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [10400] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(863), 2,
       anon_sym_COMMA,
       anon_sym_RPAREN,
-  [4219] = 1,
-    ACTIONS(426), 2,
+  [10411] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(865), 2,
       anon_sym_COMMA,
-      anon_sym_GT,
-  [4224] = 2,
-    ACTIONS(460), 1,
+      anon_sym_RPAREN,
+  [10422] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(867), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [10433] = 4,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(869), 1,
       sym_identifier,
-    ACTIONS(462), 1,
+    ACTIONS(871), 1,
       anon_sym_ref,
-  [4231] = 1,
-    ACTIONS(464), 2,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-  [4236] = 2,
-    ACTIONS(466), 1,
-      anon_sym_SEMI,
-    ACTIONS(468), 1,
-      anon_sym_EQ,
-  [4243] = 1,
-    ACTIONS(470), 1,
-      anon_sym_SEMI,
-  [4247] = 1,
-    ACTIONS(472), 1,
-      anon_sym_LBRACE,
-  [4251] = 1,
-    ACTIONS(474), 1,
+  [10446] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(873), 1,
+      anon_sym_EQ_GT,
+  [10456] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(875), 1,
       anon_sym_COLON,
-  [4255] = 1,
-    ACTIONS(476), 1,
+  [10466] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(877), 1,
+      sym_identifier,
+  [10476] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(879), 1,
       anon_sym_COLON,
-  [4259] = 1,
-    ACTIONS(478), 1,
-      anon_sym_LBRACE,
-  [4263] = 1,
-    ACTIONS(480), 1,
-      anon_sym_em,
-  [4267] = 1,
-    ACTIONS(482), 1,
+  [10486] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(881), 1,
       anon_sym_em,
-  [4271] = 1,
-    ACTIONS(484), 1,
+  [10496] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(883), 1,
+      sym_identifier,
+  [10506] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(885), 1,
+      anon_sym_LPAREN,
+  [10516] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(887), 1,
+      sym_identifier,
+  [10526] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(889), 1,
+      anon_sym_LBRACE,
+  [10536] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(891), 1,
+      sym_identifier,
+  [10546] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(893), 1,
       anon_sym_em,
-  [4275] = 1,
-    ACTIONS(486), 1,
+  [10556] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(895), 1,
+      anon_sym_COLON,
+  [10566] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(897), 1,
       sym_identifier,
-  [4279] = 1,
-    ACTIONS(488), 1,
+  [10576] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(899), 1,
       anon_sym_LBRACE,
-  [4283] = 1,
-    ACTIONS(490), 1,
+  [10586] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(901), 1,
       anon_sym_COLON,
-  [4287] = 1,
-    ACTIONS(492), 1,
+  [10596] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(903), 1,
+      ts_builtin_sym_end,
+  [10606] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(905), 1,
       sym_identifier,
-  [4291] = 1,
-    ACTIONS(494), 1,
+  [10616] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(907), 1,
+      anon_sym_LBRACE,
+  [10626] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(909), 1,
+      anon_sym_LBRACE,
+  [10636] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(911), 1,
+      anon_sym_EQ_GT,
+  [10646] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(913), 1,
+      anon_sym_em,
+  [10656] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(915), 1,
+      anon_sym_LBRACE,
+  [10666] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(917), 1,
+      anon_sym_LBRACE,
+  [10676] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(919), 1,
       sym_identifier,
-  [4295] = 1,
-    ACTIONS(496), 1,
+  [10686] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(921), 1,
+      anon_sym_em,
+  [10696] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(923), 1,
       anon_sym_LBRACE,
-  [4299] = 1,
-    ACTIONS(498), 1,
-      anon_sym_DASH_GT,
-  [4303] = 1,
-    ACTIONS(500), 1,
-      anon_sym_DASH_GT,
-  [4307] = 1,
-    ACTIONS(502), 1,
+  [10706] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(791), 1,
+      anon_sym_se,
+  [10716] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(925), 1,
+      anon_sym_em,
+  [10726] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(927), 1,
+      anon_sym_SEMI,
+  [10736] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(929), 1,
+      anon_sym_SEMI,
+  [10746] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(931), 1,
       anon_sym_DASH_GT,
-  [4311] = 1,
-    ACTIONS(504), 1,
+  [10756] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(933), 1,
+      anon_sym_COLON,
+  [10766] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(935), 1,
+      anon_sym_cada,
+  [10776] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(937), 1,
       sym_identifier,
-  [4315] = 1,
-    ACTIONS(506), 1,
-      anon_sym_SEMI,
-  [4319] = 1,
-    ACTIONS(508), 1,
+  [10786] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(939), 1,
+      sym_identifier,
+  [10796] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(941), 1,
+      anon_sym_DASH_GT,
+  [10806] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(943), 1,
       sym_identifier,
-  [4323] = 1,
-    ACTIONS(510), 1,
+  [10816] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(945), 1,
+      anon_sym_LBRACE,
+  [10826] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(947), 1,
       anon_sym_LPAREN,
-  [4327] = 1,
-    ACTIONS(512), 1,
-      anon_sym_em,
-  [4331] = 1,
-    ACTIONS(514), 1,
-      anon_sym_DQUOTE,
-  [4335] = 1,
-    ACTIONS(516), 1,
+  [10836] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(949), 1,
+      anon_sym_LBRACE,
+  [10846] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(951), 1,
       anon_sym_COLON,
-  [4339] = 1,
-    ACTIONS(518), 1,
+  [10856] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(953), 1,
+      sym_identifier,
+  [10866] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(955), 1,
+      anon_sym_DASH_GT,
+  [10876] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(957), 1,
       sym_identifier,
-  [4343] = 1,
-    ACTIONS(520), 1,
+  [10886] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(959), 1,
+      anon_sym_LBRACE,
+  [10896] = 3,
+    ACTIONS(3), 1,
+      sym_line_comment,
+    ACTIONS(5), 1,
+      sym_block_comment,
+    ACTIONS(961), 1,
       anon_sym_LBRACE,
-  [4347] = 1,
-    ACTIONS(243), 1,
-      anon_sym_DOT,
-  [4351] = 1,
-    ACTIONS(522), 1,
-      ts_builtin_sym_end,
-  [4355] = 1,
-    ACTIONS(524), 1,
-      sym_string_content,
-  [4359] = 1,
-    ACTIONS(526), 1,
-      anon_sym_em,
-  [4363] = 1,
-    ACTIONS(528), 1,
-      anon_sym_cada,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(2)] = 0,
-  [SMALL_STATE(3)] = 81,
-  [SMALL_STATE(4)] = 162,
-  [SMALL_STATE(5)] = 243,
-  [SMALL_STATE(6)] = 324,
-  [SMALL_STATE(7)] = 405,
-  [SMALL_STATE(8)] = 486,
-  [SMALL_STATE(9)] = 567,
-  [SMALL_STATE(10)] = 648,
-  [SMALL_STATE(11)] = 729,
-  [SMALL_STATE(12)] = 810,
-  [SMALL_STATE(13)] = 891,
-  [SMALL_STATE(14)] = 972,
-  [SMALL_STATE(15)] = 1053,
-  [SMALL_STATE(16)] = 1134,
-  [SMALL_STATE(17)] = 1215,
-  [SMALL_STATE(18)] = 1296,
-  [SMALL_STATE(19)] = 1377,
-  [SMALL_STATE(20)] = 1425,
-  [SMALL_STATE(21)] = 1473,
-  [SMALL_STATE(22)] = 1521,
-  [SMALL_STATE(23)] = 1566,
-  [SMALL_STATE(24)] = 1611,
-  [SMALL_STATE(25)] = 1656,
-  [SMALL_STATE(26)] = 1701,
-  [SMALL_STATE(27)] = 1746,
-  [SMALL_STATE(28)] = 1791,
-  [SMALL_STATE(29)] = 1836,
-  [SMALL_STATE(30)] = 1881,
-  [SMALL_STATE(31)] = 1926,
-  [SMALL_STATE(32)] = 1971,
-  [SMALL_STATE(33)] = 2016,
-  [SMALL_STATE(34)] = 2061,
-  [SMALL_STATE(35)] = 2106,
-  [SMALL_STATE(36)] = 2151,
-  [SMALL_STATE(37)] = 2196,
-  [SMALL_STATE(38)] = 2227,
-  [SMALL_STATE(39)] = 2252,
-  [SMALL_STATE(40)] = 2277,
-  [SMALL_STATE(41)] = 2302,
-  [SMALL_STATE(42)] = 2331,
-  [SMALL_STATE(43)] = 2356,
-  [SMALL_STATE(44)] = 2381,
-  [SMALL_STATE(45)] = 2406,
-  [SMALL_STATE(46)] = 2431,
-  [SMALL_STATE(47)] = 2458,
-  [SMALL_STATE(48)] = 2485,
-  [SMALL_STATE(49)] = 2510,
-  [SMALL_STATE(50)] = 2551,
-  [SMALL_STATE(51)] = 2580,
-  [SMALL_STATE(52)] = 2619,
-  [SMALL_STATE(53)] = 2656,
-  [SMALL_STATE(54)] = 2681,
-  [SMALL_STATE(55)] = 2706,
-  [SMALL_STATE(56)] = 2733,
-  [SMALL_STATE(57)] = 2758,
-  [SMALL_STATE(58)] = 2791,
-  [SMALL_STATE(59)] = 2815,
-  [SMALL_STATE(60)] = 2839,
-  [SMALL_STATE(61)] = 2863,
-  [SMALL_STATE(62)] = 2887,
-  [SMALL_STATE(63)] = 2911,
-  [SMALL_STATE(64)] = 2935,
-  [SMALL_STATE(65)] = 2959,
-  [SMALL_STATE(66)] = 2983,
-  [SMALL_STATE(67)] = 3007,
-  [SMALL_STATE(68)] = 3031,
-  [SMALL_STATE(69)] = 3055,
-  [SMALL_STATE(70)] = 3079,
-  [SMALL_STATE(71)] = 3103,
-  [SMALL_STATE(72)] = 3127,
-  [SMALL_STATE(73)] = 3151,
-  [SMALL_STATE(74)] = 3175,
-  [SMALL_STATE(75)] = 3199,
-  [SMALL_STATE(76)] = 3242,
-  [SMALL_STATE(77)] = 3285,
-  [SMALL_STATE(78)] = 3323,
-  [SMALL_STATE(79)] = 3361,
-  [SMALL_STATE(80)] = 3398,
-  [SMALL_STATE(81)] = 3435,
-  [SMALL_STATE(82)] = 3472,
-  [SMALL_STATE(83)] = 3509,
-  [SMALL_STATE(84)] = 3546,
-  [SMALL_STATE(85)] = 3583,
-  [SMALL_STATE(86)] = 3620,
-  [SMALL_STATE(87)] = 3657,
-  [SMALL_STATE(88)] = 3680,
-  [SMALL_STATE(89)] = 3700,
-  [SMALL_STATE(90)] = 3720,
-  [SMALL_STATE(91)] = 3740,
-  [SMALL_STATE(92)] = 3760,
-  [SMALL_STATE(93)] = 3780,
-  [SMALL_STATE(94)] = 3800,
-  [SMALL_STATE(95)] = 3820,
-  [SMALL_STATE(96)] = 3840,
-  [SMALL_STATE(97)] = 3860,
-  [SMALL_STATE(98)] = 3880,
-  [SMALL_STATE(99)] = 3900,
-  [SMALL_STATE(100)] = 3920,
-  [SMALL_STATE(101)] = 3932,
-  [SMALL_STATE(102)] = 3941,
-  [SMALL_STATE(103)] = 3950,
-  [SMALL_STATE(104)] = 3959,
-  [SMALL_STATE(105)] = 3968,
-  [SMALL_STATE(106)] = 3977,
-  [SMALL_STATE(107)] = 3986,
-  [SMALL_STATE(108)] = 3995,
-  [SMALL_STATE(109)] = 4008,
-  [SMALL_STATE(110)] = 4019,
-  [SMALL_STATE(111)] = 4030,
-  [SMALL_STATE(112)] = 4043,
-  [SMALL_STATE(113)] = 4053,
-  [SMALL_STATE(114)] = 4063,
-  [SMALL_STATE(115)] = 4073,
-  [SMALL_STATE(116)] = 4083,
-  [SMALL_STATE(117)] = 4093,
-  [SMALL_STATE(118)] = 4103,
-  [SMALL_STATE(119)] = 4113,
-  [SMALL_STATE(120)] = 4123,
-  [SMALL_STATE(121)] = 4133,
-  [SMALL_STATE(122)] = 4143,
-  [SMALL_STATE(123)] = 4153,
-  [SMALL_STATE(124)] = 4158,
-  [SMALL_STATE(125)] = 4163,
-  [SMALL_STATE(126)] = 4170,
-  [SMALL_STATE(127)] = 4175,
-  [SMALL_STATE(128)] = 4182,
-  [SMALL_STATE(129)] = 4187,
-  [SMALL_STATE(130)] = 4192,
-  [SMALL_STATE(131)] = 4197,
-  [SMALL_STATE(132)] = 4202,
-  [SMALL_STATE(133)] = 4209,
-  [SMALL_STATE(134)] = 4214,
-  [SMALL_STATE(135)] = 4219,
-  [SMALL_STATE(136)] = 4224,
-  [SMALL_STATE(137)] = 4231,
-  [SMALL_STATE(138)] = 4236,
-  [SMALL_STATE(139)] = 4243,
-  [SMALL_STATE(140)] = 4247,
-  [SMALL_STATE(141)] = 4251,
-  [SMALL_STATE(142)] = 4255,
-  [SMALL_STATE(143)] = 4259,
-  [SMALL_STATE(144)] = 4263,
-  [SMALL_STATE(145)] = 4267,
-  [SMALL_STATE(146)] = 4271,
-  [SMALL_STATE(147)] = 4275,
-  [SMALL_STATE(148)] = 4279,
-  [SMALL_STATE(149)] = 4283,
-  [SMALL_STATE(150)] = 4287,
-  [SMALL_STATE(151)] = 4291,
-  [SMALL_STATE(152)] = 4295,
-  [SMALL_STATE(153)] = 4299,
-  [SMALL_STATE(154)] = 4303,
-  [SMALL_STATE(155)] = 4307,
-  [SMALL_STATE(156)] = 4311,
-  [SMALL_STATE(157)] = 4315,
-  [SMALL_STATE(158)] = 4319,
-  [SMALL_STATE(159)] = 4323,
-  [SMALL_STATE(160)] = 4327,
-  [SMALL_STATE(161)] = 4331,
-  [SMALL_STATE(162)] = 4335,
-  [SMALL_STATE(163)] = 4339,
-  [SMALL_STATE(164)] = 4343,
-  [SMALL_STATE(165)] = 4347,
-  [SMALL_STATE(166)] = 4351,
-  [SMALL_STATE(167)] = 4355,
-  [SMALL_STATE(168)] = 4359,
-  [SMALL_STATE(169)] = 4363,
+  [SMALL_STATE(3)] = 92,
+  [SMALL_STATE(4)] = 184,
+  [SMALL_STATE(5)] = 276,
+  [SMALL_STATE(6)] = 368,
+  [SMALL_STATE(7)] = 460,
+  [SMALL_STATE(8)] = 552,
+  [SMALL_STATE(9)] = 644,
+  [SMALL_STATE(10)] = 736,
+  [SMALL_STATE(11)] = 828,
+  [SMALL_STATE(12)] = 920,
+  [SMALL_STATE(13)] = 1012,
+  [SMALL_STATE(14)] = 1104,
+  [SMALL_STATE(15)] = 1196,
+  [SMALL_STATE(16)] = 1288,
+  [SMALL_STATE(17)] = 1380,
+  [SMALL_STATE(18)] = 1472,
+  [SMALL_STATE(19)] = 1564,
+  [SMALL_STATE(20)] = 1656,
+  [SMALL_STATE(21)] = 1748,
+  [SMALL_STATE(22)] = 1840,
+  [SMALL_STATE(23)] = 1932,
+  [SMALL_STATE(24)] = 2024,
+  [SMALL_STATE(25)] = 2116,
+  [SMALL_STATE(26)] = 2208,
+  [SMALL_STATE(27)] = 2300,
+  [SMALL_STATE(28)] = 2392,
+  [SMALL_STATE(29)] = 2484,
+  [SMALL_STATE(30)] = 2576,
+  [SMALL_STATE(31)] = 2668,
+  [SMALL_STATE(32)] = 2760,
+  [SMALL_STATE(33)] = 2852,
+  [SMALL_STATE(34)] = 2944,
+  [SMALL_STATE(35)] = 3036,
+  [SMALL_STATE(36)] = 3128,
+  [SMALL_STATE(37)] = 3220,
+  [SMALL_STATE(38)] = 3312,
+  [SMALL_STATE(39)] = 3404,
+  [SMALL_STATE(40)] = 3496,
+  [SMALL_STATE(41)] = 3588,
+  [SMALL_STATE(42)] = 3680,
+  [SMALL_STATE(43)] = 3772,
+  [SMALL_STATE(44)] = 3827,
+  [SMALL_STATE(45)] = 3882,
+  [SMALL_STATE(46)] = 3937,
+  [SMALL_STATE(47)] = 3989,
+  [SMALL_STATE(48)] = 4041,
+  [SMALL_STATE(49)] = 4093,
+  [SMALL_STATE(50)] = 4145,
+  [SMALL_STATE(51)] = 4197,
+  [SMALL_STATE(52)] = 4249,
+  [SMALL_STATE(53)] = 4301,
+  [SMALL_STATE(54)] = 4353,
+  [SMALL_STATE(55)] = 4405,
+  [SMALL_STATE(56)] = 4457,
+  [SMALL_STATE(57)] = 4509,
+  [SMALL_STATE(58)] = 4561,
+  [SMALL_STATE(59)] = 4613,
+  [SMALL_STATE(60)] = 4665,
+  [SMALL_STATE(61)] = 4717,
+  [SMALL_STATE(62)] = 4769,
+  [SMALL_STATE(63)] = 4821,
+  [SMALL_STATE(64)] = 4873,
+  [SMALL_STATE(65)] = 4925,
+  [SMALL_STATE(66)] = 4977,
+  [SMALL_STATE(67)] = 5029,
+  [SMALL_STATE(68)] = 5081,
+  [SMALL_STATE(69)] = 5133,
+  [SMALL_STATE(70)] = 5185,
+  [SMALL_STATE(71)] = 5237,
+  [SMALL_STATE(72)] = 5289,
+  [SMALL_STATE(73)] = 5329,
+  [SMALL_STATE(74)] = 5369,
+  [SMALL_STATE(75)] = 5409,
+  [SMALL_STATE(76)] = 5449,
+  [SMALL_STATE(77)] = 5489,
+  [SMALL_STATE(78)] = 5524,
+  [SMALL_STATE(79)] = 5561,
+  [SMALL_STATE(80)] = 5593,
+  [SMALL_STATE(81)] = 5625,
+  [SMALL_STATE(82)] = 5657,
+  [SMALL_STATE(83)] = 5693,
+  [SMALL_STATE(84)] = 5725,
+  [SMALL_STATE(85)] = 5757,
+  [SMALL_STATE(86)] = 5789,
+  [SMALL_STATE(87)] = 5821,
+  [SMALL_STATE(88)] = 5853,
+  [SMALL_STATE(89)] = 5885,
+  [SMALL_STATE(90)] = 5921,
+  [SMALL_STATE(91)] = 5953,
+  [SMALL_STATE(92)] = 5985,
+  [SMALL_STATE(93)] = 6017,
+  [SMALL_STATE(94)] = 6049,
+  [SMALL_STATE(95)] = 6081,
+  [SMALL_STATE(96)] = 6113,
+  [SMALL_STATE(97)] = 6145,
+  [SMALL_STATE(98)] = 6177,
+  [SMALL_STATE(99)] = 6209,
+  [SMALL_STATE(100)] = 6241,
+  [SMALL_STATE(101)] = 6273,
+  [SMALL_STATE(102)] = 6307,
+  [SMALL_STATE(103)] = 6338,
+  [SMALL_STATE(104)] = 6369,
+  [SMALL_STATE(105)] = 6400,
+  [SMALL_STATE(106)] = 6431,
+  [SMALL_STATE(107)] = 6462,
+  [SMALL_STATE(108)] = 6493,
+  [SMALL_STATE(109)] = 6524,
+  [SMALL_STATE(110)] = 6567,
+  [SMALL_STATE(111)] = 6598,
+  [SMALL_STATE(112)] = 6629,
+  [SMALL_STATE(113)] = 6660,
+  [SMALL_STATE(114)] = 6691,
+  [SMALL_STATE(115)] = 6730,
+  [SMALL_STATE(116)] = 6761,
+  [SMALL_STATE(117)] = 6792,
+  [SMALL_STATE(118)] = 6823,
+  [SMALL_STATE(119)] = 6860,
+  [SMALL_STATE(120)] = 6891,
+  [SMALL_STATE(121)] = 6922,
+  [SMALL_STATE(122)] = 6953,
+  [SMALL_STATE(123)] = 6984,
+  [SMALL_STATE(124)] = 7015,
+  [SMALL_STATE(125)] = 7046,
+  [SMALL_STATE(126)] = 7077,
+  [SMALL_STATE(127)] = 7124,
+  [SMALL_STATE(128)] = 7155,
+  [SMALL_STATE(129)] = 7200,
+  [SMALL_STATE(130)] = 7231,
+  [SMALL_STATE(131)] = 7280,
+  [SMALL_STATE(132)] = 7329,
+  [SMALL_STATE(133)] = 7373,
+  [SMALL_STATE(134)] = 7417,
+  [SMALL_STATE(135)] = 7461,
+  [SMALL_STATE(136)] = 7504,
+  [SMALL_STATE(137)] = 7537,
+  [SMALL_STATE(138)] = 7580,
+  [SMALL_STATE(139)] = 7623,
+  [SMALL_STATE(140)] = 7666,
+  [SMALL_STATE(141)] = 7709,
+  [SMALL_STATE(142)] = 7752,
+  [SMALL_STATE(143)] = 7795,
+  [SMALL_STATE(144)] = 7838,
+  [SMALL_STATE(145)] = 7881,
+  [SMALL_STATE(146)] = 7924,
+  [SMALL_STATE(147)] = 7967,
+  [SMALL_STATE(148)] = 8010,
+  [SMALL_STATE(149)] = 8051,
+  [SMALL_STATE(150)] = 8090,
+  [SMALL_STATE(151)] = 8125,
+  [SMALL_STATE(152)] = 8154,
+  [SMALL_STATE(153)] = 8183,
+  [SMALL_STATE(154)] = 8212,
+  [SMALL_STATE(155)] = 8238,
+  [SMALL_STATE(156)] = 8264,
+  [SMALL_STATE(157)] = 8290,
+  [SMALL_STATE(158)] = 8316,
+  [SMALL_STATE(159)] = 8342,
+  [SMALL_STATE(160)] = 8368,
+  [SMALL_STATE(161)] = 8394,
+  [SMALL_STATE(162)] = 8420,
+  [SMALL_STATE(163)] = 8446,
+  [SMALL_STATE(164)] = 8472,
+  [SMALL_STATE(165)] = 8498,
+  [SMALL_STATE(166)] = 8524,
+  [SMALL_STATE(167)] = 8550,
+  [SMALL_STATE(168)] = 8576,
+  [SMALL_STATE(169)] = 8602,
+  [SMALL_STATE(170)] = 8628,
+  [SMALL_STATE(171)] = 8654,
+  [SMALL_STATE(172)] = 8680,
+  [SMALL_STATE(173)] = 8699,
+  [SMALL_STATE(174)] = 8715,
+  [SMALL_STATE(175)] = 8731,
+  [SMALL_STATE(176)] = 8747,
+  [SMALL_STATE(177)] = 8763,
+  [SMALL_STATE(178)] = 8779,
+  [SMALL_STATE(179)] = 8795,
+  [SMALL_STATE(180)] = 8811,
+  [SMALL_STATE(181)] = 8832,
+  [SMALL_STATE(182)] = 8853,
+  [SMALL_STATE(183)] = 8874,
+  [SMALL_STATE(184)] = 8888,
+  [SMALL_STATE(185)] = 8902,
+  [SMALL_STATE(186)] = 8916,
+  [SMALL_STATE(187)] = 8930,
+  [SMALL_STATE(188)] = 8944,
+  [SMALL_STATE(189)] = 8958,
+  [SMALL_STATE(190)] = 8972,
+  [SMALL_STATE(191)] = 8986,
+  [SMALL_STATE(192)] = 9000,
+  [SMALL_STATE(193)] = 9014,
+  [SMALL_STATE(194)] = 9028,
+  [SMALL_STATE(195)] = 9042,
+  [SMALL_STATE(196)] = 9056,
+  [SMALL_STATE(197)] = 9070,
+  [SMALL_STATE(198)] = 9084,
+  [SMALL_STATE(199)] = 9098,
+  [SMALL_STATE(200)] = 9112,
+  [SMALL_STATE(201)] = 9126,
+  [SMALL_STATE(202)] = 9140,
+  [SMALL_STATE(203)] = 9154,
+  [SMALL_STATE(204)] = 9168,
+  [SMALL_STATE(205)] = 9182,
+  [SMALL_STATE(206)] = 9196,
+  [SMALL_STATE(207)] = 9210,
+  [SMALL_STATE(208)] = 9224,
+  [SMALL_STATE(209)] = 9238,
+  [SMALL_STATE(210)] = 9252,
+  [SMALL_STATE(211)] = 9266,
+  [SMALL_STATE(212)] = 9277,
+  [SMALL_STATE(213)] = 9296,
+  [SMALL_STATE(214)] = 9315,
+  [SMALL_STATE(215)] = 9326,
+  [SMALL_STATE(216)] = 9345,
+  [SMALL_STATE(217)] = 9364,
+  [SMALL_STATE(218)] = 9383,
+  [SMALL_STATE(219)] = 9402,
+  [SMALL_STATE(220)] = 9418,
+  [SMALL_STATE(221)] = 9434,
+  [SMALL_STATE(222)] = 9450,
+  [SMALL_STATE(223)] = 9466,
+  [SMALL_STATE(224)] = 9482,
+  [SMALL_STATE(225)] = 9498,
+  [SMALL_STATE(226)] = 9514,
+  [SMALL_STATE(227)] = 9530,
+  [SMALL_STATE(228)] = 9546,
+  [SMALL_STATE(229)] = 9562,
+  [SMALL_STATE(230)] = 9578,
+  [SMALL_STATE(231)] = 9594,
+  [SMALL_STATE(232)] = 9610,
+  [SMALL_STATE(233)] = 9626,
+  [SMALL_STATE(234)] = 9642,
+  [SMALL_STATE(235)] = 9658,
+  [SMALL_STATE(236)] = 9674,
+  [SMALL_STATE(237)] = 9690,
+  [SMALL_STATE(238)] = 9706,
+  [SMALL_STATE(239)] = 9722,
+  [SMALL_STATE(240)] = 9738,
+  [SMALL_STATE(241)] = 9754,
+  [SMALL_STATE(242)] = 9770,
+  [SMALL_STATE(243)] = 9786,
+  [SMALL_STATE(244)] = 9802,
+  [SMALL_STATE(245)] = 9818,
+  [SMALL_STATE(246)] = 9834,
+  [SMALL_STATE(247)] = 9850,
+  [SMALL_STATE(248)] = 9866,
+  [SMALL_STATE(249)] = 9880,
+  [SMALL_STATE(250)] = 9896,
+  [SMALL_STATE(251)] = 9912,
+  [SMALL_STATE(252)] = 9928,
+  [SMALL_STATE(253)] = 9944,
+  [SMALL_STATE(254)] = 9960,
+  [SMALL_STATE(255)] = 9976,
+  [SMALL_STATE(256)] = 9992,
+  [SMALL_STATE(257)] = 10005,
+  [SMALL_STATE(258)] = 10018,
+  [SMALL_STATE(259)] = 10031,
+  [SMALL_STATE(260)] = 10044,
+  [SMALL_STATE(261)] = 10057,
+  [SMALL_STATE(262)] = 10068,
+  [SMALL_STATE(263)] = 10079,
+  [SMALL_STATE(264)] = 10092,
+  [SMALL_STATE(265)] = 10105,
+  [SMALL_STATE(266)] = 10118,
+  [SMALL_STATE(267)] = 10131,
+  [SMALL_STATE(268)] = 10142,
+  [SMALL_STATE(269)] = 10155,
+  [SMALL_STATE(270)] = 10166,
+  [SMALL_STATE(271)] = 10177,
+  [SMALL_STATE(272)] = 10190,
+  [SMALL_STATE(273)] = 10201,
+  [SMALL_STATE(274)] = 10214,
+  [SMALL_STATE(275)] = 10225,
+  [SMALL_STATE(276)] = 10236,
+  [SMALL_STATE(277)] = 10249,
+  [SMALL_STATE(278)] = 10260,
+  [SMALL_STATE(279)] = 10273,
+  [SMALL_STATE(280)] = 10284,
+  [SMALL_STATE(281)] = 10297,
+  [SMALL_STATE(282)] = 10308,
+  [SMALL_STATE(283)] = 10319,
+  [SMALL_STATE(284)] = 10332,
+  [SMALL_STATE(285)] = 10343,
+  [SMALL_STATE(286)] = 10354,
+  [SMALL_STATE(287)] = 10365,
+  [SMALL_STATE(288)] = 10378,
+  [SMALL_STATE(289)] = 10389,
+  [SMALL_STATE(290)] = 10400,
+  [SMALL_STATE(291)] = 10411,
+  [SMALL_STATE(292)] = 10422,
+  [SMALL_STATE(293)] = 10433,
+  [SMALL_STATE(294)] = 10446,
+  [SMALL_STATE(295)] = 10456,
+  [SMALL_STATE(296)] = 10466,
+  [SMALL_STATE(297)] = 10476,
+  [SMALL_STATE(298)] = 10486,
+  [SMALL_STATE(299)] = 10496,
+  [SMALL_STATE(300)] = 10506,
+  [SMALL_STATE(301)] = 10516,
+  [SMALL_STATE(302)] = 10526,
+  [SMALL_STATE(303)] = 10536,
+  [SMALL_STATE(304)] = 10546,
+  [SMALL_STATE(305)] = 10556,
+  [SMALL_STATE(306)] = 10566,
+  [SMALL_STATE(307)] = 10576,
+  [SMALL_STATE(308)] = 10586,
+  [SMALL_STATE(309)] = 10596,
+  [SMALL_STATE(310)] = 10606,
+  [SMALL_STATE(311)] = 10616,
+  [SMALL_STATE(312)] = 10626,
+  [SMALL_STATE(313)] = 10636,
+  [SMALL_STATE(314)] = 10646,
+  [SMALL_STATE(315)] = 10656,
+  [SMALL_STATE(316)] = 10666,
+  [SMALL_STATE(317)] = 10676,
+  [SMALL_STATE(318)] = 10686,
+  [SMALL_STATE(319)] = 10696,
+  [SMALL_STATE(320)] = 10706,
+  [SMALL_STATE(321)] = 10716,
+  [SMALL_STATE(322)] = 10726,
+  [SMALL_STATE(323)] = 10736,
+  [SMALL_STATE(324)] = 10746,
+  [SMALL_STATE(325)] = 10756,
+  [SMALL_STATE(326)] = 10766,
+  [SMALL_STATE(327)] = 10776,
+  [SMALL_STATE(328)] = 10786,
+  [SMALL_STATE(329)] = 10796,
+  [SMALL_STATE(330)] = 10806,
+  [SMALL_STATE(331)] = 10816,
+  [SMALL_STATE(332)] = 10826,
+  [SMALL_STATE(333)] = 10836,
+  [SMALL_STATE(334)] = 10846,
+  [SMALL_STATE(335)] = 10856,
+  [SMALL_STATE(336)] = 10866,
+  [SMALL_STATE(337)] = 10876,
+  [SMALL_STATE(338)] = 10886,
+  [SMALL_STATE(339)] = 10896,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
   [0] = {.entry = {.count = 0, .reusable = false}},
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
-  [3] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_program, 0),
-  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
-  [7] = {.entry = {.count = 1, .reusable = false}}, SHIFT(86),
-  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
-  [13] = {.entry = {.count = 1, .reusable = false}}, SHIFT(157),
-  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(139),
-  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
-  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
-  [21] = {.entry = {.count = 1, .reusable = false}}, SHIFT(28),
-  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(169),
-  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(127),
-  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(26),
-  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
-  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
-  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(53),
-  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
-  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(55),
-  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(165),
-  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
-  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
-  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
-  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
-  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
-  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
-  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
-  [55] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(86),
-  [58] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(22),
-  [61] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_function_repeat2, 2),
-  [63] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(157),
-  [66] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(139),
-  [69] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(21),
-  [72] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(23),
-  [75] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(28),
-  [78] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(169),
-  [81] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(127),
-  [84] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(26),
-  [87] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(26),
-  [90] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(167),
-  [93] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(53),
-  [96] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(19),
-  [99] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(55),
-  [102] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2), SHIFT_REPEAT(165),
-  [105] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
-  [107] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
-  [109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
-  [111] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
-  [113] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
-  [115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
-  [117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
-  [119] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
-  [121] = {.entry = {.count = 1, .reusable = false}}, SHIFT(75),
-  [123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
-  [125] = {.entry = {.count = 1, .reusable = false}}, SHIFT(76),
-  [127] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
-  [129] = {.entry = {.count = 1, .reusable = false}}, SHIFT(81),
-  [131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
-  [133] = {.entry = {.count = 1, .reusable = false}}, SHIFT(83),
-  [135] = {.entry = {.count = 1, .reusable = false}}, SHIFT(79),
-  [137] = {.entry = {.count = 1, .reusable = false}}, SHIFT(49),
-  [139] = {.entry = {.count = 1, .reusable = false}}, SHIFT(52),
-  [141] = {.entry = {.count = 1, .reusable = false}}, SHIFT(50),
-  [143] = {.entry = {.count = 1, .reusable = false}}, SHIFT(85),
-  [145] = {.entry = {.count = 1, .reusable = false}}, SHIFT(80),
-  [147] = {.entry = {.count = 1, .reusable = false}}, SHIFT(51),
-  [149] = {.entry = {.count = 1, .reusable = false}}, SHIFT(57),
-  [151] = {.entry = {.count = 1, .reusable = false}}, SHIFT(78),
-  [153] = {.entry = {.count = 1, .reusable = false}}, SHIFT(77),
-  [155] = {.entry = {.count = 1, .reusable = false}}, SHIFT(82),
-  [157] = {.entry = {.count = 1, .reusable = false}}, SHIFT(37),
-  [159] = {.entry = {.count = 1, .reusable = false}}, SHIFT(41),
-  [161] = {.entry = {.count = 1, .reusable = false}}, SHIFT(84),
-  [163] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
-  [165] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expr, 3, .production_id = 22),
-  [167] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expr, 3, .production_id = 22),
-  [169] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
-  [171] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
-  [173] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parem_expr, 3, .production_id = 16),
-  [175] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parem_expr, 3, .production_id = 16),
-  [177] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list, 4, .production_id = 29),
-  [179] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list, 4, .production_id = 29),
-  [181] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_access_expr, 3, .production_id = 23),
-  [183] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_access_expr, 3, .production_id = 23),
-  [185] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 3, .production_id = 18),
-  [187] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 3, .production_id = 18),
-  [189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list, 3, .production_id = 19),
-  [191] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list, 3, .production_id = 19),
-  [193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call_expr, 4, .production_id = 31),
-  [195] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_call_expr, 4, .production_id = 31),
-  [197] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_decimal, 3, .production_id = 20),
-  [199] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_decimal, 3, .production_id = 20),
-  [201] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 5, .production_id = 33),
-  [203] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 5, .production_id = 33),
-  [205] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
-  [207] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 4, .production_id = 26),
-  [209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 4, .production_id = 26),
-  [211] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
-  [213] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call_expr, 3, .production_id = 21),
-  [215] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_call_expr, 3, .production_id = 21),
-  [217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
-  [219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
-  [221] = {.entry = {.count = 1, .reusable = false}}, SHIFT(30),
-  [223] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
-  [225] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
-  [227] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_expr, 2, .production_id = 11),
-  [229] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unary_expr, 2, .production_id = 11),
-  [231] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean, 1, .production_id = 9),
-  [233] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_boolean, 1, .production_id = 9),
-  [235] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call_expr, 5, .production_id = 37),
-  [237] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_call_expr, 5, .production_id = 37),
-  [239] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_integer, 1, .production_id = 9),
-  [241] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_integer, 1, .production_id = 9),
-  [243] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
-  [245] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list, 2),
-  [247] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list, 2),
-  [249] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_loop, 4, .production_id = 26),
-  [251] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_loop, 4, .production_id = 26),
-  [253] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_stmt, 2),
-  [255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_stmt, 2),
-  [257] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 9, .production_id = 47),
-  [259] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 9, .production_id = 47),
-  [261] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable_declaration, 8, .production_id = 46),
-  [263] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_declaration, 8, .production_id = 46),
-  [265] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_for_each_loop, 8, .production_id = 45),
-  [267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_each_loop, 8, .production_id = 45),
-  [269] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 8, .production_id = 44),
-  [271] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 8, .production_id = 44),
-  [273] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 8, .production_id = 43),
-  [275] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 8, .production_id = 43),
-  [277] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable_declaration, 6, .production_id = 39),
-  [279] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_declaration, 6, .production_id = 39),
-  [281] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable_declaration, 7, .production_id = 42),
-  [283] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_declaration, 7, .production_id = 42),
-  [285] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_loop, 5, .production_id = 33),
-  [287] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_loop, 5, .production_id = 33),
-  [289] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 7, .production_id = 40),
-  [291] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 7, .production_id = 40),
-  [293] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression_stmt, 2, .production_id = 12),
-  [295] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression_stmt, 2, .production_id = 12),
-  [297] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_for_each_loop, 7, .production_id = 41),
-  [299] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_each_loop, 7, .production_id = 41),
-  [301] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_break_stmt, 2),
-  [303] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_break_stmt, 2),
-  [305] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_continue_stmt, 2),
-  [307] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_continue_stmt, 2),
-  [309] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_stmt, 3, .production_id = 17),
-  [311] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_stmt, 3, .production_id = 17),
-  [313] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable_declaration, 5, .production_id = 35),
-  [315] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_declaration, 5, .production_id = 35),
-  [317] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
-  [319] = {.entry = {.count = 1, .reusable = false}}, SHIFT(24),
-  [321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
-  [323] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
-  [325] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
-  [327] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_call_expr_repeat1, 2, .production_id = 36),
-  [329] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_repeat1, 2, .production_id = 19),
-  [331] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
-  [333] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
-  [335] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
-  [337] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
-  [339] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
-  [341] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
-  [343] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
-  [345] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
-  [347] = {.entry = {.count = 1, .reusable = false}}, SHIFT(100),
-  [349] = {.entry = {.count = 1, .reusable = false}}, SHIFT(125),
-  [351] = {.entry = {.count = 1, .reusable = false}}, SHIFT(97),
-  [353] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
-  [355] = {.entry = {.count = 1, .reusable = false}}, SHIFT(96),
-  [357] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raw_type, 1, .production_id = 1),
-  [359] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
-  [361] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ref_type, 3, .production_id = 7),
-  [363] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_type, 3, .production_id = 1),
-  [365] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_type, 4, .production_id = 10),
-  [367] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_type, 5, .production_id = 15),
-  [369] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comp_type, 2, .production_id = 5),
-  [371] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ref_type, 2, .production_id = 5),
-  [373] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comp_type, 3, .production_id = 7),
-  [375] = {.entry = {.count = 1, .reusable = false}}, SHIFT(145),
-  [377] = {.entry = {.count = 1, .reusable = false}}, SHIFT(136),
-  [379] = {.entry = {.count = 1, .reusable = false}}, SHIFT(147),
-  [381] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_program, 1),
-  [383] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2),
-  [385] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2), SHIFT_REPEAT(158),
-  [388] = {.entry = {.count = 1, .reusable = false}}, SHIFT(149),
-  [390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
-  [392] = {.entry = {.count = 1, .reusable = false}}, SHIFT(151),
-  [394] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_call_expr_repeat1, 2, .production_id = 38), SHIFT_REPEAT(32),
-  [397] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_call_expr_repeat1, 2, .production_id = 38),
-  [399] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_repeat1, 2, .production_id = 4), SHIFT_REPEAT(118),
-  [402] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_function_repeat1, 2, .production_id = 4),
-  [404] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_repeat1, 2, .production_id = 30), SHIFT_REPEAT(31),
-  [407] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_repeat1, 2, .production_id = 30),
-  [409] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
-  [411] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
-  [413] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
-  [415] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
-  [417] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
-  [419] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
-  [421] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
-  [423] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_type_repeat1, 2), SHIFT_REPEAT(93),
-  [426] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_type_repeat1, 2),
-  [428] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
-  [430] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function, 8, .production_id = 8),
-  [432] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function, 11, .production_id = 32),
-  [434] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
-  [436] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
-  [438] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function, 10, .production_id = 24),
-  [440] = {.entry = {.count = 1, .reusable = false}}, SHIFT(162),
-  [442] = {.entry = {.count = 1, .reusable = false}}, SHIFT(163),
-  [444] = {.entry = {.count = 1, .reusable = false}}, SHIFT(45),
-  [446] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function, 9, .production_id = 13),
-  [448] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function, 9, .production_id = 14),
-  [450] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function, 10, .production_id = 25),
-  [452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
-  [454] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
-  [456] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_param, 3, .production_id = 2),
-  [458] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_function_repeat1, 2, .production_id = 3),
-  [460] = {.entry = {.count = 1, .reusable = false}}, SHIFT(168),
-  [462] = {.entry = {.count = 1, .reusable = false}}, SHIFT(150),
-  [464] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_param, 4, .production_id = 6),
-  [466] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
-  [468] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
-  [470] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
-  [472] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
-  [474] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
-  [476] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
-  [478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
-  [480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
-  [482] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_item_decl, 1, .production_id = 1),
-  [484] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_item_decl, 3, .production_id = 34),
-  [486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
-  [488] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
-  [490] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
-  [492] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
-  [494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
-  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
-  [498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
-  [500] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
-  [502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
-  [504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
-  [506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
-  [508] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
-  [510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
-  [512] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_item_decl, 2, .production_id = 28),
-  [514] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
-  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
-  [518] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
-  [520] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
-  [522] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [524] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
-  [526] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_item_decl, 2, .production_id = 27),
-  [528] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [3] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
+  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [7] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_program, 0, 0, 0),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(327),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
+  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(101),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(323),
+  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(322),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(45),
+  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(50),
+  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(58),
+  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(326),
+  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(61),
+  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(287),
+  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(56),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(98),
+  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(78),
+  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(257),
+  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [61] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(101),
+  [64] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(59),
+  [67] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0),
+  [69] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(323),
+  [72] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(322),
+  [75] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(45),
+  [78] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(50),
+  [81] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(58),
+  [84] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(326),
+  [87] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(61),
+  [90] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(287),
+  [93] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(56),
+  [96] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(56),
+  [99] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(180),
+  [102] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(98),
+  [105] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(43),
+  [108] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(78),
+  [111] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_repeat2, 2, 0, 0), SHIFT_REPEAT(257),
+  [114] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
+  [116] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [118] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [120] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [122] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
+  [124] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [126] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [128] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [130] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [132] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [134] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [136] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [138] = {.entry = {.count = 1, .reusable = true}}, SHIFT(286),
+  [140] = {.entry = {.count = 1, .reusable = true}}, SHIFT(198),
+  [142] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
+  [144] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
+  [146] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [148] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [152] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [156] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [158] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
+  [162] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [164] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [166] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [168] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
+  [172] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [174] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [176] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
+  [178] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
+  [180] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
+  [182] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [184] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [186] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [188] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [190] = {.entry = {.count = 1, .reusable = false}}, SHIFT(138),
+  [192] = {.entry = {.count = 1, .reusable = false}}, SHIFT(47),
+  [194] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [196] = {.entry = {.count = 1, .reusable = false}}, SHIFT(82),
+  [198] = {.entry = {.count = 1, .reusable = false}}, SHIFT(144),
+  [200] = {.entry = {.count = 1, .reusable = false}}, SHIFT(140),
+  [202] = {.entry = {.count = 1, .reusable = false}}, SHIFT(145),
+  [204] = {.entry = {.count = 1, .reusable = false}}, SHIFT(146),
+  [206] = {.entry = {.count = 1, .reusable = false}}, SHIFT(147),
+  [208] = {.entry = {.count = 1, .reusable = false}}, SHIFT(148),
+  [210] = {.entry = {.count = 1, .reusable = false}}, SHIFT(149),
+  [212] = {.entry = {.count = 1, .reusable = false}}, SHIFT(150),
+  [214] = {.entry = {.count = 1, .reusable = false}}, SHIFT(136),
+  [216] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
+  [218] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_conditional_repeat1, 2, 0, 70),
+  [220] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_conditional_repeat1, 2, 0, 70),
+  [222] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_conditional_repeat1, 2, 0, 70), SHIFT_REPEAT(320),
+  [225] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 4, 0, 43),
+  [227] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 4, 0, 43),
+  [229] = {.entry = {.count = 1, .reusable = false}}, SHIFT(266),
+  [231] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 5, 0, 58),
+  [233] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 5, 0, 58),
+  [235] = {.entry = {.count = 1, .reusable = false}}, SHIFT(259),
+  [237] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 5, 0, 59),
+  [239] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 5, 0, 59),
+  [241] = {.entry = {.count = 1, .reusable = false}}, SHIFT(256),
+  [243] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 6, 0, 71),
+  [245] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 6, 0, 71),
+  [247] = {.entry = {.count = 1, .reusable = false}}, SHIFT(263),
+  [249] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_decimal, 3, 0, 31),
+  [251] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_decimal, 3, 0, 31),
+  [253] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_integer, 1, 0, 8),
+  [257] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_integer, 1, 0, 8),
+  [259] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [261] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [263] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_conditional_repeat1, 1, 0, 57),
+  [265] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_conditional_repeat1, 1, 0, 57),
+  [267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parem_expr, 3, 0, 26),
+  [269] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parem_expr, 3, 0, 26),
+  [271] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 2, 0, 0),
+  [273] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 2, 0, 0),
+  [275] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_expr, 2, 0, 17),
+  [279] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unary_expr, 2, 0, 17),
+  [281] = {.entry = {.count = 1, .reusable = true}}, SHIFT(330),
+  [283] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_literal, 6, 0, 65),
+  [285] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_literal, 6, 0, 65),
+  [287] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 3, 0, 28),
+  [289] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 3, 0, 28),
+  [291] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_decimal, 4, 0, 50),
+  [293] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_decimal, 4, 0, 50),
+  [295] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list, 2, 0, 0),
+  [297] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list, 2, 0, 0),
+  [299] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list, 3, 0, 30),
+  [301] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list, 3, 0, 30),
+  [303] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_else_if, 5, 0, 81),
+  [305] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_if, 5, 0, 81),
+  [307] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expr, 3, 0, 33),
+  [309] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expr, 3, 0, 33),
+  [311] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_else_if, 6, 0, 88),
+  [313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_if, 6, 0, 88),
+  [315] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_access_expr, 3, 0, 34),
+  [317] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_access_expr, 3, 0, 34),
+  [319] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_decimal, 2, 0, 19),
+  [321] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_decimal, 2, 0, 19),
+  [323] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call_expr, 3, 0, 32),
+  [325] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_call_expr, 3, 0, 32),
+  [327] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_literal, 5, 0, 51),
+  [329] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_literal, 5, 0, 51),
+  [331] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_literal, 5, 0, 65),
+  [333] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_literal, 5, 0, 65),
+  [335] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call_expr, 5, 0, 67),
+  [337] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_call_expr, 5, 0, 67),
+  [339] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call_expr, 4, 0, 52),
+  [341] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_call_expr, 4, 0, 52),
+  [343] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean, 1, 0, 8),
+  [345] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_boolean, 1, 0, 8),
+  [347] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list, 4, 0, 48),
+  [349] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list, 4, 0, 48),
+  [351] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_literal, 4, 0, 51),
+  [353] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_literal, 4, 0, 51),
+  [355] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__value_expr, 1, 0, 0),
+  [357] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
+  [359] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__value_expr, 1, 0, 0),
+  [361] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 10, 0, 89),
+  [363] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 10, 0, 89),
+  [365] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_loop, 5, 0, 59),
+  [367] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_loop, 5, 0, 59),
+  [369] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_match_stmt, 5, 0, 61),
+  [371] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_stmt, 5, 0, 61),
+  [373] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable_declaration, 7, 0, 75),
+  [375] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_declaration, 7, 0, 75),
+  [377] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 8, 0, 76),
+  [379] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 8, 0, 76),
+  [381] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable_declaration, 5, 0, 63),
+  [383] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_declaration, 5, 0, 63),
+  [385] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 8, 0, 77),
+  [387] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 8, 0, 77),
+  [389] = {.entry = {.count = 1, .reusable = false}}, SHIFT(49),
+  [391] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [393] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [395] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [397] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 8, 0, 78),
+  [399] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 8, 0, 78),
+  [401] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_for_each_loop, 8, 0, 79),
+  [403] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_each_loop, 8, 0, 79),
+  [405] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_match_stmt, 4, 0, 46),
+  [407] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_stmt, 4, 0, 46),
+  [409] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable_declaration, 8, 0, 80),
+  [411] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_declaration, 8, 0, 80),
+  [413] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 9, 0, 82),
+  [415] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 9, 0, 82),
+  [417] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 9, 0, 83),
+  [419] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 9, 0, 83),
+  [421] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 9, 0, 84),
+  [423] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 9, 0, 84),
+  [425] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_break_stmt, 2, 0, 0),
+  [427] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_break_stmt, 2, 0, 0),
+  [429] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional, 7, 0, 73),
+  [431] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional, 7, 0, 73),
+  [433] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_continue_stmt, 2, 0, 0),
+  [435] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_continue_stmt, 2, 0, 0),
+  [437] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_stmt, 3, 0, 27),
+  [439] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_stmt, 3, 0, 27),
+  [441] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_stmt, 2, 0, 0),
+  [443] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_stmt, 2, 0, 0),
+  [445] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable_declaration, 6, 0, 72),
+  [447] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_declaration, 6, 0, 72),
+  [449] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression_stmt, 2, 0, 20),
+  [451] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression_stmt, 2, 0, 20),
+  [453] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [455] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [457] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_loop, 4, 0, 43),
+  [459] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_loop, 4, 0, 43),
+  [461] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_for_each_loop, 7, 0, 74),
+  [463] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_each_loop, 7, 0, 74),
+  [465] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [467] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [469] = {.entry = {.count = 1, .reusable = false}}, SHIFT(64),
+  [471] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
+  [473] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [475] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_init, 3, 0, 64),
+  [477] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_repeat1, 2, 0, 30),
+  [479] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_call_expr_repeat1, 2, 0, 66),
+  [481] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
+  [483] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [485] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [487] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [489] = {.entry = {.count = 1, .reusable = false}}, SHIFT(66),
+  [491] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [493] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [495] = {.entry = {.count = 1, .reusable = false}}, SHIFT(69),
+  [497] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [499] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [501] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [503] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [505] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [507] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [509] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [511] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [513] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [515] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
+  [517] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0),
+  [519] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(327),
+  [522] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(310),
+  [525] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(303),
+  [528] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(306),
+  [531] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_program, 1, 0, 0),
+  [533] = {.entry = {.count = 1, .reusable = false}}, SHIFT(172),
+  [535] = {.entry = {.count = 1, .reusable = false}}, SHIFT(265),
+  [537] = {.entry = {.count = 1, .reusable = false}}, SHIFT(167),
+  [539] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [541] = {.entry = {.count = 1, .reusable = false}}, SHIFT(168),
+  [543] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_raw_type, 1, 0, 2),
+  [545] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [547] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_type, 5, 0, 54),
+  [549] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ref_type, 3, 0, 24),
+  [551] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comp_type, 2, 0, 15),
+  [553] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ref_type, 2, 0, 15),
+  [555] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comp_type, 3, 0, 24),
+  [557] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_type, 3, 0, 2),
+  [559] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_template_type, 4, 0, 40),
+  [561] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [563] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [565] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [567] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [569] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 29),
+  [571] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 29), SHIFT_REPEAT(62),
+  [574] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 29), SHIFT_REPEAT(214),
+  [577] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function, 10, 0, 56),
+  [579] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_procedure, 8, 0, 36),
+  [581] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function, 9, 0, 41),
+  [583] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function, 9, 0, 42),
+  [585] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_procedure, 6, 0, 1),
+  [587] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_procedure, 9, 0, 41),
+  [589] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_procedure, 8, 0, 25),
+  [591] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_procedure, 9, 0, 42),
+  [593] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct, 5, 0, 3),
+  [595] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_procedure, 9, 0, 53),
+  [597] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function, 10, 0, 55),
+  [599] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_procedure, 7, 0, 22),
+  [601] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_procedure, 8, 0, 35),
+  [603] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct, 6, 0, 10),
+  [605] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum, 6, 0, 4),
+  [607] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_procedure, 10, 0, 55),
+  [609] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_procedure, 10, 0, 56),
+  [611] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function, 11, 0, 69),
+  [613] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum, 6, 0, 13),
+  [615] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum, 7, 0, 13),
+  [617] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_procedure, 11, 0, 69),
+  [619] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum, 5, 0, 4),
+  [621] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct, 7, 0, 10),
+  [623] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct, 4, 0, 1),
+  [625] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum, 4, 0, 1),
+  [627] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_procedure, 7, 0, 21),
+  [629] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function, 8, 0, 25),
+  [631] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct, 6, 0, 3),
+  [633] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interpolation, 3, 0, 26),
+  [635] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_match_stmt_repeat1, 2, 0, 62),
+  [637] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_match_stmt_repeat1, 2, 0, 62), SHIFT_REPEAT(337),
+  [640] = {.entry = {.count = 1, .reusable = false}}, SHIFT(314),
+  [642] = {.entry = {.count = 1, .reusable = false}}, SHIFT(293),
+  [644] = {.entry = {.count = 1, .reusable = false}}, SHIFT(335),
+  [646] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 1, 0, 18),
+  [648] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [650] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
+  [652] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [654] = {.entry = {.count = 1, .reusable = false}}, SHIFT(334),
+  [656] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
+  [658] = {.entry = {.count = 1, .reusable = false}}, SHIFT(328),
+  [660] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
+  [662] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_enum_repeat1, 2, 0, 14), SHIFT_REPEAT(264),
+  [665] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_repeat1, 2, 0, 14),
+  [667] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_call_expr_repeat1, 2, 0, 68), SHIFT_REPEAT(52),
+  [670] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_call_expr_repeat1, 2, 0, 68),
+  [672] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
+  [674] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [676] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_template_type_repeat1, 2, 0, 0), SHIFT_REPEAT(171),
+  [679] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_template_type_repeat1, 2, 0, 0),
+  [681] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
+  [683] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [685] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_repeat1, 2, 0, 49), SHIFT_REPEAT(63),
+  [688] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_repeat1, 2, 0, 49),
+  [690] = {.entry = {.count = 1, .reusable = true}}, SHIFT(295),
+  [692] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [694] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_repeat1, 2, 0, 7), SHIFT_REPEAT(250),
+  [697] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_function_repeat1, 2, 0, 7),
+  [699] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
+  [701] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [703] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [705] = {.entry = {.count = 1, .reusable = true}}, SHIFT(289),
+  [707] = {.entry = {.count = 1, .reusable = true}}, SHIFT(308),
+  [709] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [711] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [713] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
+  [715] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [717] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
+  [719] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
+  [721] = {.entry = {.count = 1, .reusable = true}}, SHIFT(299),
+  [723] = {.entry = {.count = 1, .reusable = true}}, SHIFT(294),
+  [725] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_enum_variant_repeat1, 2, 0, 39), SHIFT_REPEAT(158),
+  [728] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_variant_repeat1, 2, 0, 39),
+  [730] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [732] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [734] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [736] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [738] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
+  [740] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [742] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [744] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [746] = {.entry = {.count = 1, .reusable = true}}, SHIFT(225),
+  [748] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [750] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_struct_repeat1, 2, 0, 11), SHIFT_REPEAT(283),
+  [753] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_repeat1, 2, 0, 11),
+  [755] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [757] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [759] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_match_case_repeat1, 2, 0, 86), SHIFT_REPEAT(299),
+  [762] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_match_case_repeat1, 2, 0, 86),
+  [764] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [766] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [768] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_variant, 1, 0, 2),
+  [770] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [772] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [774] = {.entry = {.count = 1, .reusable = true}}, SHIFT(292),
+  [776] = {.entry = {.count = 1, .reusable = true}}, SHIFT(336),
+  [778] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [780] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_struct_literal_repeat1, 2, 0, 11), SHIFT_REPEAT(260),
+  [783] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_literal_repeat1, 2, 0, 11),
+  [785] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
+  [787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [789] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [793] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [795] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [797] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [799] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_param, 4, 0, 16),
+  [801] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [803] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [805] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [807] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [809] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_match_stmt_repeat1, 1, 0, 47),
+  [811] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
+  [813] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [815] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_field, 3, 0, 5),
+  [817] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_case, 5, 0, 12),
+  [819] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [821] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [823] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_repeat1, 2, 0, 12),
+  [825] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
+  [827] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
+  [829] = {.entry = {.count = 1, .reusable = false}}, SHIFT(77),
+  [831] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_match_case_repeat1, 2, 0, 85),
+  [833] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [835] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [837] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_case, 6, 0, 87),
+  [839] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_repeat1, 2, 0, 9),
+  [841] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [843] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [845] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_case, 8, 0, 90),
+  [847] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_variant_repeat1, 2, 0, 37),
+  [849] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_case, 9, 0, 91),
+  [851] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_case, 9, 0, 92),
+  [853] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_match_case, 10, 0, 93),
+  [855] = {.entry = {.count = 1, .reusable = false}}, SHIFT(297),
+  [857] = {.entry = {.count = 1, .reusable = false}}, SHIFT(296),
+  [859] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_literal_repeat1, 2, 0, 9),
+  [861] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_variant, 5, 0, 38),
+  [863] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_param, 3, 0, 5),
+  [865] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_function_repeat1, 2, 0, 6),
+  [867] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_variant, 4, 0, 23),
+  [869] = {.entry = {.count = 1, .reusable = false}}, SHIFT(318),
+  [871] = {.entry = {.count = 1, .reusable = false}}, SHIFT(317),
+  [873] = {.entry = {.count = 1, .reusable = true}}, SHIFT(312),
+  [875] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [877] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [879] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [881] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [883] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
+  [885] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
+  [887] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
+  [889] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [891] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
+  [893] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_item_decl, 3, 0, 60),
+  [895] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [897] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [899] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [901] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [903] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [905] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
+  [907] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [909] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [911] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
+  [913] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_item_decl, 1, 0, 2),
+  [915] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [917] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [919] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
+  [921] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_item_decl, 2, 0, 44),
+  [923] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [925] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_item_decl, 2, 0, 45),
+  [927] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [929] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [931] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [933] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
+  [935] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [937] = {.entry = {.count = 1, .reusable = true}}, SHIFT(332),
+  [939] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [941] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
+  [943] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [945] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [947] = {.entry = {.count = 1, .reusable = true}}, SHIFT(217),
+  [949] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [951] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [953] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
+  [955] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
+  [957] = {.entry = {.count = 1, .reusable = true}}, SHIFT(273),
+  [959] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [961] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
 };
 
 #ifdef __cplusplus
@@ -5732,7 +12162,7 @@ extern "C" {
 #define TS_PUBLIC __attribute__((visibility("default")))
 #endif
 
-TS_PUBLIC const TSLanguage *tree_sitter_ila_lang() {
+TS_PUBLIC const TSLanguage *tree_sitter_ila_lang(void) {
   static const TSLanguage language = {
     .version = LANGUAGE_VERSION,
     .symbol_count = SYMBOL_COUNT,